@@ -100,27 +100,109 @@ typedef struct UniffiForeignFutureStructVoid {
 } UniffiForeignFutureStructVoid;
 typedef void (*UniffiForeignFutureCompleteVoid)(
     uint64_t callback_data, UniffiForeignFutureStructVoid result);
+RustBuffer uniffi_slh_dsa_uniffi_fn_func_slh_dsa_cose_sign1_create(
+    RustBuffer param, RustBuffer signing_key, RustBuffer payload,
+    RustBuffer key_id, RustBuffer external_aad, int8_t detached,
+    RustCallStatus *uniffi_out_err);
+RustBuffer uniffi_slh_dsa_uniffi_fn_func_slh_dsa_cose_sign1_verify(
+    RustBuffer verifying_key, RustBuffer encoded, RustBuffer detached_payload,
+    RustBuffer external_aad, RustCallStatus *uniffi_out_err);
+RustBuffer uniffi_slh_dsa_uniffi_fn_func_slh_dsa_csr_create(
+    RustBuffer param, RustBuffer signing_key, RustBuffer subject,
+    RustBuffer subject_alt_names, RustBuffer extensions, int8_t pem,
+    RustCallStatus *uniffi_out_err);
+RustBuffer uniffi_slh_dsa_uniffi_fn_func_slh_dsa_csr_verify(
+    RustBuffer encoded, RustCallStatus *uniffi_out_err);
+RustBuffer uniffi_slh_dsa_uniffi_fn_func_slh_dsa_envelope_decode(
+    RustBuffer encoded, RustCallStatus *uniffi_out_err);
+RustBuffer uniffi_slh_dsa_uniffi_fn_func_slh_dsa_envelope_sign(
+    RustBuffer param, RustBuffer signing_key, RustBuffer payload,
+    RustBuffer ctx, RustBuffer key_id, RustBuffer mode, int8_t detached,
+    RustCallStatus *uniffi_out_err);
+RustBuffer uniffi_slh_dsa_uniffi_fn_func_slh_dsa_envelope_verify(
+    RustBuffer verifying_key, RustBuffer encoded, RustBuffer detached_payload,
+    RustCallStatus *uniffi_out_err);
+RustBuffer uniffi_slh_dsa_uniffi_fn_func_slh_dsa_fingerprint(
+    RustBuffer param, RustBuffer verifying_key, RustBuffer source,
+    RustCallStatus *uniffi_out_err);
+uint64_t uniffi_slh_dsa_uniffi_fn_func_slh_dsa_key_file_len(
+    RustBuffer param, RustCallStatus *uniffi_out_err);
+RustBuffer uniffi_slh_dsa_uniffi_fn_func_slh_dsa_keypair_from_seed(
+    RustBuffer param, RustBuffer sk_seed, RustBuffer sk_prf, RustBuffer pk_seed,
+    RustCallStatus *uniffi_out_err);
 RustBuffer uniffi_slh_dsa_uniffi_fn_func_slh_dsa_keypair_generate(
     RustBuffer param, RustCallStatus *uniffi_out_err);
 RustBuffer uniffi_slh_dsa_uniffi_fn_func_slh_dsa_parameter_name(
     RustBuffer param, RustCallStatus *uniffi_out_err);
+RustBuffer uniffi_slh_dsa_uniffi_fn_func_slh_dsa_parameter_set_from_name(
+    RustBuffer name, RustCallStatus *uniffi_out_err);
+RustBuffer uniffi_slh_dsa_uniffi_fn_func_slh_dsa_parameter_set_from_oid(
+    RustBuffer oid, RustCallStatus *uniffi_out_err);
+RustBuffer uniffi_slh_dsa_uniffi_fn_func_slh_dsa_parameter_sets(
+    RustCallStatus *uniffi_out_err);
+uint64_t uniffi_slh_dsa_uniffi_fn_func_slh_dsa_seed_len(
+    RustBuffer param, RustCallStatus *uniffi_out_err);
+void uniffi_slh_dsa_uniffi_fn_func_slh_dsa_set_verify_after_sign(
+    int8_t enabled, RustCallStatus *uniffi_out_err);
 RustBuffer uniffi_slh_dsa_uniffi_fn_func_slh_dsa_sign(
     RustBuffer param, RustBuffer signing_key, RustBuffer msg, RustBuffer ctx,
     RustCallStatus *uniffi_out_err);
+RustBuffer uniffi_slh_dsa_uniffi_fn_func_slh_dsa_sign_batch(
+    RustBuffer param, RustBuffer signing_key, RustBuffer msgs, RustBuffer ctx,
+    RustCallStatus *uniffi_out_err);
+RustBuffer uniffi_slh_dsa_uniffi_fn_func_slh_dsa_sign_checked(
+    RustBuffer param, RustBuffer signing_key, RustBuffer msg, RustBuffer ctx,
+    RustCallStatus *uniffi_out_err);
 RustBuffer uniffi_slh_dsa_uniffi_fn_func_slh_dsa_sign_deterministic(
     RustBuffer param, RustBuffer signing_key, RustBuffer msg, RustBuffer ctx,
     RustCallStatus *uniffi_out_err);
 uint64_t uniffi_slh_dsa_uniffi_fn_func_slh_dsa_signature_len(
     RustBuffer param, RustCallStatus *uniffi_out_err);
+RustBuffer uniffi_slh_dsa_uniffi_fn_func_slh_dsa_signing_key_from_cose_key(
+    RustBuffer encoded, RustCallStatus *uniffi_out_err);
+RustBuffer
+uniffi_slh_dsa_uniffi_fn_func_slh_dsa_signing_key_from_encrypted_pkcs8(
+    RustBuffer encoded, RustBuffer passphrase, RustCallStatus *uniffi_out_err);
+RustBuffer uniffi_slh_dsa_uniffi_fn_func_slh_dsa_signing_key_from_key_file(
+    RustBuffer encoded, RustBuffer passphrase, RustCallStatus *uniffi_out_err);
+RustBuffer uniffi_slh_dsa_uniffi_fn_func_slh_dsa_signing_key_from_pkcs8(
+    RustBuffer encoded, RustCallStatus *uniffi_out_err);
 uint64_t uniffi_slh_dsa_uniffi_fn_func_slh_dsa_signing_key_len(
     RustBuffer param, RustCallStatus *uniffi_out_err);
+RustBuffer uniffi_slh_dsa_uniffi_fn_func_slh_dsa_signing_key_to_cose_key(
+    RustBuffer param, RustBuffer signing_key, RustCallStatus *uniffi_out_err);
+RustBuffer uniffi_slh_dsa_uniffi_fn_func_slh_dsa_signing_key_to_encrypted_pkcs8(
+    RustBuffer param, RustBuffer signing_key, RustBuffer passphrase, int8_t pem,
+    RustCallStatus *uniffi_out_err);
+RustBuffer uniffi_slh_dsa_uniffi_fn_func_slh_dsa_signing_key_to_key_file(
+    RustBuffer param, RustBuffer signing_key, RustBuffer passphrase,
+    RustBuffer kdf, RustBuffer aead, RustCallStatus *uniffi_out_err);
+RustBuffer uniffi_slh_dsa_uniffi_fn_func_slh_dsa_signing_key_to_pkcs8(
+    RustBuffer param, RustBuffer signing_key, int8_t pem,
+    RustCallStatus *uniffi_out_err);
+RustBuffer uniffi_slh_dsa_uniffi_fn_func_slh_dsa_validate_signing_key(
+    RustBuffer param, RustBuffer signing_key, RustCallStatus *uniffi_out_err);
 RustBuffer uniffi_slh_dsa_uniffi_fn_func_slh_dsa_verify(
     RustBuffer param, RustBuffer verifying_key, RustBuffer msg, RustBuffer ctx,
     RustBuffer signature, RustCallStatus *uniffi_out_err);
+int8_t uniffi_slh_dsa_uniffi_fn_func_slh_dsa_verify_after_sign_enabled(
+    RustCallStatus *uniffi_out_err);
+RustBuffer uniffi_slh_dsa_uniffi_fn_func_slh_dsa_verifying_key_from_cose_key(
+    RustBuffer encoded, RustCallStatus *uniffi_out_err);
 RustBuffer uniffi_slh_dsa_uniffi_fn_func_slh_dsa_verifying_key_from_signing_key(
     RustBuffer param, RustBuffer signing_key, RustCallStatus *uniffi_out_err);
+RustBuffer
+uniffi_slh_dsa_uniffi_fn_func_slh_dsa_verifying_key_from_signing_key_validated(
+    RustBuffer param, RustBuffer signing_key, RustCallStatus *uniffi_out_err);
+RustBuffer uniffi_slh_dsa_uniffi_fn_func_slh_dsa_verifying_key_from_spki(
+    RustBuffer encoded, RustCallStatus *uniffi_out_err);
 uint64_t uniffi_slh_dsa_uniffi_fn_func_slh_dsa_verifying_key_len(
     RustBuffer param, RustCallStatus *uniffi_out_err);
+RustBuffer uniffi_slh_dsa_uniffi_fn_func_slh_dsa_verifying_key_to_cose_key(
+    RustBuffer param, RustBuffer verifying_key, RustCallStatus *uniffi_out_err);
+RustBuffer uniffi_slh_dsa_uniffi_fn_func_slh_dsa_verifying_key_to_spki(
+    RustBuffer param, RustBuffer verifying_key, int8_t pem,
+    RustCallStatus *uniffi_out_err);
 RustBuffer ffi_slh_dsa_uniffi_rustbuffer_alloc(uint64_t size,
                                                RustCallStatus *uniffi_out_err);
 RustBuffer
@@ -248,16 +330,56 @@ void ffi_slh_dsa_uniffi_rust_future_free_void(
     /*handle*/ uint64_t handle);
 void ffi_slh_dsa_uniffi_rust_future_complete_void(
     /*handle*/ uint64_t handle, RustCallStatus *uniffi_out_err);
+uint16_t uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_cose_sign1_create();
+uint16_t uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_cose_sign1_verify();
+uint16_t uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_csr_create();
+uint16_t uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_csr_verify();
+uint16_t uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_envelope_decode();
+uint16_t uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_envelope_sign();
+uint16_t uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_envelope_verify();
+uint16_t uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_fingerprint();
+uint16_t uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_key_file_len();
+uint16_t uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_keypair_from_seed();
 uint16_t uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_keypair_generate();
 uint16_t uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_parameter_name();
+uint16_t uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_parameter_set_from_name();
+uint16_t uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_parameter_set_from_oid();
+uint16_t uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_parameter_sets();
+uint16_t uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_seed_len();
+uint16_t uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_set_verify_after_sign();
 uint16_t uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_sign();
+uint16_t uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_sign_batch();
+uint16_t uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_sign_checked();
 uint16_t uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_sign_deterministic();
 uint16_t uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_signature_len();
+uint16_t
+uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_signing_key_from_cose_key();
+uint16_t
+uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_signing_key_from_encrypted_pkcs8();
+uint16_t
+uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_signing_key_from_key_file();
+uint16_t uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_signing_key_from_pkcs8();
 uint16_t uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_signing_key_len();
+uint16_t uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_signing_key_to_cose_key();
+uint16_t
+uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_signing_key_to_encrypted_pkcs8();
+uint16_t uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_signing_key_to_key_file();
+uint16_t uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_signing_key_to_pkcs8();
+uint16_t uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_validate_signing_key();
 uint16_t uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_verify();
 uint16_t
+uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_verify_after_sign_enabled();
+uint16_t
+uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_verifying_key_from_cose_key();
+uint16_t
 uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_verifying_key_from_signing_key();
+uint16_t
+uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_verifying_key_from_signing_key_validated();
+uint16_t uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_verifying_key_from_spki();
 uint16_t uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_verifying_key_len();
+uint16_t
+uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_verifying_key_to_cose_key();
+uint16_t uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_verifying_key_to_spki();
 uint32_t ffi_slh_dsa_uniffi_uniffi_contract_version();
 }
 
@@ -1686,308 +1808,1551 @@ NativeSlhDsaUniffi::NativeSlhDsaUniffi(
             return this->cpp_uniffi_internal_fn_func_ffi__arraybuffer_to_string(
                 rt, thisVal, args, count);
           });
-  props["ubrn_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_keypair_generate"] =
+  props["ubrn_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_cose_sign1_create"] =
       jsi::Function::createFromHostFunction(
           rt,
           jsi::PropNameID::forAscii(
               rt,
-              "ubrn_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_keypair_generate"),
-          1,
+              "ubrn_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_cose_sign1_create"),
+          6,
           [this](jsi::Runtime &rt, const jsi::Value &thisVal,
                  const jsi::Value *args, size_t count) -> jsi::Value {
             return this
-                ->cpp_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_keypair_generate(
+                ->cpp_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_cose_sign1_create(
                     rt, thisVal, args, count);
           });
-  props["ubrn_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_parameter_name"] =
+  props["ubrn_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_cose_sign1_verify"] =
       jsi::Function::createFromHostFunction(
           rt,
           jsi::PropNameID::forAscii(
-              rt, "ubrn_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_parameter_name"),
-          1,
+              rt,
+              "ubrn_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_cose_sign1_verify"),
+          4,
           [this](jsi::Runtime &rt, const jsi::Value &thisVal,
                  const jsi::Value *args, size_t count) -> jsi::Value {
             return this
-                ->cpp_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_parameter_name(
+                ->cpp_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_cose_sign1_verify(
                     rt, thisVal, args, count);
           });
-  props["ubrn_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_sign"] =
+  props["ubrn_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_csr_create"] =
       jsi::Function::createFromHostFunction(
           rt,
           jsi::PropNameID::forAscii(
-              rt, "ubrn_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_sign"),
-          4,
+              rt, "ubrn_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_csr_create"),
+          6,
           [this](jsi::Runtime &rt, const jsi::Value &thisVal,
                  const jsi::Value *args, size_t count) -> jsi::Value {
-            return this->cpp_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_sign(
+            return this->cpp_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_csr_create(
                 rt, thisVal, args, count);
           });
-  props["ubrn_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_sign_deterministic"] =
+  props["ubrn_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_csr_verify"] =
       jsi::Function::createFromHostFunction(
           rt,
           jsi::PropNameID::forAscii(
-              rt,
-              "ubrn_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_sign_deterministic"),
-          4,
+              rt, "ubrn_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_csr_verify"),
+          1,
+          [this](jsi::Runtime &rt, const jsi::Value &thisVal,
+                 const jsi::Value *args, size_t count) -> jsi::Value {
+            return this->cpp_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_csr_verify(
+                rt, thisVal, args, count);
+          });
+  props["ubrn_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_envelope_decode"] =
+      jsi::Function::createFromHostFunction(
+          rt,
+          jsi::PropNameID::forAscii(
+              rt, "ubrn_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_envelope_decode"),
+          1,
           [this](jsi::Runtime &rt, const jsi::Value &thisVal,
                  const jsi::Value *args, size_t count) -> jsi::Value {
             return this
-                ->cpp_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_sign_deterministic(
+                ->cpp_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_envelope_decode(
                     rt, thisVal, args, count);
           });
-  props["ubrn_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_signature_len"] =
+  props["ubrn_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_envelope_sign"] =
       jsi::Function::createFromHostFunction(
           rt,
           jsi::PropNameID::forAscii(
-              rt, "ubrn_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_signature_len"),
-          1,
+              rt, "ubrn_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_envelope_sign"),
+          7,
           [this](jsi::Runtime &rt, const jsi::Value &thisVal,
                  const jsi::Value *args, size_t count) -> jsi::Value {
             return this
-                ->cpp_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_signature_len(
+                ->cpp_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_envelope_sign(
                     rt, thisVal, args, count);
           });
-  props["ubrn_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_signing_key_len"] =
+  props["ubrn_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_envelope_verify"] =
       jsi::Function::createFromHostFunction(
           rt,
           jsi::PropNameID::forAscii(
-              rt, "ubrn_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_signing_key_len"),
-          1,
+              rt, "ubrn_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_envelope_verify"),
+          3,
           [this](jsi::Runtime &rt, const jsi::Value &thisVal,
                  const jsi::Value *args, size_t count) -> jsi::Value {
             return this
-                ->cpp_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_signing_key_len(
+                ->cpp_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_envelope_verify(
                     rt, thisVal, args, count);
           });
-  props["ubrn_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_verify"] =
+  props["ubrn_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_fingerprint"] =
       jsi::Function::createFromHostFunction(
           rt,
           jsi::PropNameID::forAscii(
-              rt, "ubrn_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_verify"),
-          5,
+              rt, "ubrn_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_fingerprint"),
+          3,
           [this](jsi::Runtime &rt, const jsi::Value &thisVal,
                  const jsi::Value *args, size_t count) -> jsi::Value {
-            return this->cpp_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_verify(
+            return this->cpp_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_fingerprint(
                 rt, thisVal, args, count);
           });
-  props["ubrn_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_verifying_key_from_signing_"
-        "key"] = jsi::Function::createFromHostFunction(
-      rt,
-      jsi::PropNameID::forAscii(rt, "ubrn_uniffi_slh_dsa_uniffi_fn_func_slh_"
-                                    "dsa_verifying_key_from_signing_key"),
-      2,
-      [this](jsi::Runtime &rt, const jsi::Value &thisVal,
-             const jsi::Value *args, size_t count) -> jsi::Value {
-        return this
-            ->cpp_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_verifying_key_from_signing_key(
+  props["ubrn_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_key_file_len"] =
+      jsi::Function::createFromHostFunction(
+          rt,
+          jsi::PropNameID::forAscii(
+              rt, "ubrn_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_key_file_len"),
+          1,
+          [this](jsi::Runtime &rt, const jsi::Value &thisVal,
+                 const jsi::Value *args, size_t count) -> jsi::Value {
+            return this->cpp_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_key_file_len(
                 rt, thisVal, args, count);
-      });
-  props["ubrn_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_verifying_key_len"] =
+          });
+  props["ubrn_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_keypair_from_seed"] =
       jsi::Function::createFromHostFunction(
           rt,
           jsi::PropNameID::forAscii(
               rt,
-              "ubrn_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_verifying_key_len"),
-          1,
+              "ubrn_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_keypair_from_seed"),
+          4,
           [this](jsi::Runtime &rt, const jsi::Value &thisVal,
                  const jsi::Value *args, size_t count) -> jsi::Value {
             return this
-                ->cpp_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_verifying_key_len(
+                ->cpp_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_keypair_from_seed(
                     rt, thisVal, args, count);
           });
-  props["ubrn_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_keypair_generate"] =
+  props["ubrn_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_keypair_generate"] =
       jsi::Function::createFromHostFunction(
           rt,
-          jsi::PropNameID::forAscii(rt, "ubrn_uniffi_slh_dsa_uniffi_checksum_"
-                                        "func_slh_dsa_keypair_generate"),
-          0,
+          jsi::PropNameID::forAscii(
+              rt,
+              "ubrn_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_keypair_generate"),
+          1,
           [this](jsi::Runtime &rt, const jsi::Value &thisVal,
                  const jsi::Value *args, size_t count) -> jsi::Value {
             return this
-                ->cpp_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_keypair_generate(
+                ->cpp_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_keypair_generate(
                     rt, thisVal, args, count);
           });
-  props["ubrn_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_parameter_name"] =
+  props["ubrn_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_parameter_name"] =
       jsi::Function::createFromHostFunction(
           rt,
-          jsi::PropNameID::forAscii(rt, "ubrn_uniffi_slh_dsa_uniffi_checksum_"
-                                        "func_slh_dsa_parameter_name"),
-          0,
+          jsi::PropNameID::forAscii(
+              rt, "ubrn_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_parameter_name"),
+          1,
           [this](jsi::Runtime &rt, const jsi::Value &thisVal,
                  const jsi::Value *args, size_t count) -> jsi::Value {
             return this
-                ->cpp_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_parameter_name(
+                ->cpp_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_parameter_name(
                     rt, thisVal, args, count);
           });
-  props["ubrn_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_sign"] =
+  props["ubrn_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_parameter_set_from_name"] =
       jsi::Function::createFromHostFunction(
           rt,
-          jsi::PropNameID::forAscii(
-              rt, "ubrn_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_sign"),
-          0,
+          jsi::PropNameID::forAscii(rt, "ubrn_uniffi_slh_dsa_uniffi_fn_func_"
+                                        "slh_dsa_parameter_set_from_name"),
+          1,
           [this](jsi::Runtime &rt, const jsi::Value &thisVal,
                  const jsi::Value *args, size_t count) -> jsi::Value {
-            return this->cpp_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_sign(
-                rt, thisVal, args, count);
+            return this
+                ->cpp_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_parameter_set_from_name(
+                    rt, thisVal, args, count);
           });
-  props["ubrn_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_sign_deterministic"] =
+  props["ubrn_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_parameter_set_from_oid"] =
       jsi::Function::createFromHostFunction(
           rt,
-          jsi::PropNameID::forAscii(rt, "ubrn_uniffi_slh_dsa_uniffi_checksum_"
-                                        "func_slh_dsa_sign_deterministic"),
-          0,
+          jsi::PropNameID::forAscii(rt, "ubrn_uniffi_slh_dsa_uniffi_fn_func_"
+                                        "slh_dsa_parameter_set_from_oid"),
+          1,
           [this](jsi::Runtime &rt, const jsi::Value &thisVal,
                  const jsi::Value *args, size_t count) -> jsi::Value {
             return this
-                ->cpp_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_sign_deterministic(
+                ->cpp_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_parameter_set_from_oid(
                     rt, thisVal, args, count);
           });
-  props["ubrn_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_signature_len"] =
+  props["ubrn_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_parameter_sets"] =
       jsi::Function::createFromHostFunction(
           rt,
           jsi::PropNameID::forAscii(
-              rt,
-              "ubrn_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_signature_len"),
+              rt, "ubrn_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_parameter_sets"),
           0,
           [this](jsi::Runtime &rt, const jsi::Value &thisVal,
                  const jsi::Value *args, size_t count) -> jsi::Value {
             return this
-                ->cpp_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_signature_len(
+                ->cpp_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_parameter_sets(
                     rt, thisVal, args, count);
           });
-  props["ubrn_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_signing_key_len"] =
+  props["ubrn_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_seed_len"] =
       jsi::Function::createFromHostFunction(
           rt,
-          jsi::PropNameID::forAscii(rt, "ubrn_uniffi_slh_dsa_uniffi_checksum_"
-                                        "func_slh_dsa_signing_key_len"),
-          0,
+          jsi::PropNameID::forAscii(
+              rt, "ubrn_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_seed_len"),
+          1,
+          [this](jsi::Runtime &rt, const jsi::Value &thisVal,
+                 const jsi::Value *args, size_t count) -> jsi::Value {
+            return this->cpp_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_seed_len(
+                rt, thisVal, args, count);
+          });
+  props["ubrn_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_set_verify_after_sign"] =
+      jsi::Function::createFromHostFunction(
+          rt,
+          jsi::PropNameID::forAscii(rt, "ubrn_uniffi_slh_dsa_uniffi_fn_func_"
+                                        "slh_dsa_set_verify_after_sign"),
+          1,
           [this](jsi::Runtime &rt, const jsi::Value &thisVal,
                  const jsi::Value *args, size_t count) -> jsi::Value {
             return this
-                ->cpp_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_signing_key_len(
+                ->cpp_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_set_verify_after_sign(
                     rt, thisVal, args, count);
           });
-  props["ubrn_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_verify"] =
+  props["ubrn_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_sign"] =
       jsi::Function::createFromHostFunction(
           rt,
           jsi::PropNameID::forAscii(
-              rt, "ubrn_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_verify"),
-          0,
+              rt, "ubrn_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_sign"),
+          4,
           [this](jsi::Runtime &rt, const jsi::Value &thisVal,
                  const jsi::Value *args, size_t count) -> jsi::Value {
-            return this->cpp_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_verify(
+            return this->cpp_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_sign(
                 rt, thisVal, args, count);
           });
-  props["ubrn_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_verifying_key_from_"
-        "signing_key"] = jsi::Function::createFromHostFunction(
+  props["ubrn_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_sign_batch"] =
+      jsi::Function::createFromHostFunction(
+          rt,
+          jsi::PropNameID::forAscii(
+              rt, "ubrn_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_sign_batch"),
+          4,
+          [this](jsi::Runtime &rt, const jsi::Value &thisVal,
+                 const jsi::Value *args, size_t count) -> jsi::Value {
+            return this->cpp_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_sign_batch(
+                rt, thisVal, args, count);
+          });
+  props["ubrn_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_sign_checked"] =
+      jsi::Function::createFromHostFunction(
+          rt,
+          jsi::PropNameID::forAscii(
+              rt, "ubrn_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_sign_checked"),
+          4,
+          [this](jsi::Runtime &rt, const jsi::Value &thisVal,
+                 const jsi::Value *args, size_t count) -> jsi::Value {
+            return this->cpp_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_sign_checked(
+                rt, thisVal, args, count);
+          });
+  props["ubrn_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_sign_deterministic"] =
+      jsi::Function::createFromHostFunction(
+          rt,
+          jsi::PropNameID::forAscii(
+              rt,
+              "ubrn_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_sign_deterministic"),
+          4,
+          [this](jsi::Runtime &rt, const jsi::Value &thisVal,
+                 const jsi::Value *args, size_t count) -> jsi::Value {
+            return this
+                ->cpp_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_sign_deterministic(
+                    rt, thisVal, args, count);
+          });
+  props["ubrn_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_signature_len"] =
+      jsi::Function::createFromHostFunction(
+          rt,
+          jsi::PropNameID::forAscii(
+              rt, "ubrn_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_signature_len"),
+          1,
+          [this](jsi::Runtime &rt, const jsi::Value &thisVal,
+                 const jsi::Value *args, size_t count) -> jsi::Value {
+            return this
+                ->cpp_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_signature_len(
+                    rt, thisVal, args, count);
+          });
+  props["ubrn_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_signing_key_from_cose_"
+        "key"] = jsi::Function::createFromHostFunction(
       rt,
-      jsi::PropNameID::forAscii(rt, "ubrn_uniffi_slh_dsa_uniffi_checksum_func_"
-                                    "slh_dsa_verifying_key_from_signing_key"),
-      0,
+      jsi::PropNameID::forAscii(rt, "ubrn_uniffi_slh_dsa_uniffi_fn_func_slh_"
+                                    "dsa_signing_key_from_cose_key"),
+      1,
       [this](jsi::Runtime &rt, const jsi::Value &thisVal,
              const jsi::Value *args, size_t count) -> jsi::Value {
         return this
-            ->cpp_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_verifying_key_from_signing_key(
+            ->cpp_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_signing_key_from_cose_key(
                 rt, thisVal, args, count);
       });
-  props["ubrn_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_verifying_key_len"] =
+  props["ubrn_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_signing_key_from_encrypted_"
+        "pkcs8"] = jsi::Function::createFromHostFunction(
+      rt,
+      jsi::PropNameID::forAscii(rt, "ubrn_uniffi_slh_dsa_uniffi_fn_func_slh_"
+                                    "dsa_signing_key_from_encrypted_pkcs8"),
+      2,
+      [this](jsi::Runtime &rt, const jsi::Value &thisVal,
+             const jsi::Value *args, size_t count) -> jsi::Value {
+        return this
+            ->cpp_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_signing_key_from_encrypted_pkcs8(
+                rt, thisVal, args, count);
+      });
+  props["ubrn_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_signing_key_from_key_"
+        "file"] = jsi::Function::createFromHostFunction(
+      rt,
+      jsi::PropNameID::forAscii(rt, "ubrn_uniffi_slh_dsa_uniffi_fn_func_slh_"
+                                    "dsa_signing_key_from_key_file"),
+      2,
+      [this](jsi::Runtime &rt, const jsi::Value &thisVal,
+             const jsi::Value *args, size_t count) -> jsi::Value {
+        return this
+            ->cpp_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_signing_key_from_key_file(
+                rt, thisVal, args, count);
+      });
+  props["ubrn_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_signing_key_from_pkcs8"] =
       jsi::Function::createFromHostFunction(
           rt,
-          jsi::PropNameID::forAscii(rt, "ubrn_uniffi_slh_dsa_uniffi_checksum_"
-                                        "func_slh_dsa_verifying_key_len"),
-          0,
+          jsi::PropNameID::forAscii(rt, "ubrn_uniffi_slh_dsa_uniffi_fn_func_"
+                                        "slh_dsa_signing_key_from_pkcs8"),
+          1,
           [this](jsi::Runtime &rt, const jsi::Value &thisVal,
                  const jsi::Value *args, size_t count) -> jsi::Value {
             return this
-                ->cpp_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_verifying_key_len(
+                ->cpp_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_signing_key_from_pkcs8(
                     rt, thisVal, args, count);
           });
-  props["ubrn_ffi_slh_dsa_uniffi_uniffi_contract_version"] =
+  props["ubrn_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_signing_key_len"] =
       jsi::Function::createFromHostFunction(
           rt,
           jsi::PropNameID::forAscii(
-              rt, "ubrn_ffi_slh_dsa_uniffi_uniffi_contract_version"),
-          0,
+              rt, "ubrn_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_signing_key_len"),
+          1,
           [this](jsi::Runtime &rt, const jsi::Value &thisVal,
                  const jsi::Value *args, size_t count) -> jsi::Value {
-            return this->cpp_ffi_slh_dsa_uniffi_uniffi_contract_version(
+            return this
+                ->cpp_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_signing_key_len(
+                    rt, thisVal, args, count);
+          });
+  props["ubrn_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_signing_key_to_cose_key"] =
+      jsi::Function::createFromHostFunction(
+          rt,
+          jsi::PropNameID::forAscii(rt, "ubrn_uniffi_slh_dsa_uniffi_fn_func_"
+                                        "slh_dsa_signing_key_to_cose_key"),
+          2,
+          [this](jsi::Runtime &rt, const jsi::Value &thisVal,
+                 const jsi::Value *args, size_t count) -> jsi::Value {
+            return this
+                ->cpp_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_signing_key_to_cose_key(
+                    rt, thisVal, args, count);
+          });
+  props["ubrn_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_signing_key_to_encrypted_"
+        "pkcs8"] = jsi::Function::createFromHostFunction(
+      rt,
+      jsi::PropNameID::forAscii(rt, "ubrn_uniffi_slh_dsa_uniffi_fn_func_slh_"
+                                    "dsa_signing_key_to_encrypted_pkcs8"),
+      4,
+      [this](jsi::Runtime &rt, const jsi::Value &thisVal,
+             const jsi::Value *args, size_t count) -> jsi::Value {
+        return this
+            ->cpp_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_signing_key_to_encrypted_pkcs8(
                 rt, thisVal, args, count);
+      });
+  props["ubrn_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_signing_key_to_key_file"] =
+      jsi::Function::createFromHostFunction(
+          rt,
+          jsi::PropNameID::forAscii(rt, "ubrn_uniffi_slh_dsa_uniffi_fn_func_"
+                                        "slh_dsa_signing_key_to_key_file"),
+          5,
+          [this](jsi::Runtime &rt, const jsi::Value &thisVal,
+                 const jsi::Value *args, size_t count) -> jsi::Value {
+            return this
+                ->cpp_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_signing_key_to_key_file(
+                    rt, thisVal, args, count);
           });
+  props["ubrn_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_signing_key_to_pkcs8"] =
+      jsi::Function::createFromHostFunction(
+          rt,
+          jsi::PropNameID::forAscii(rt, "ubrn_uniffi_slh_dsa_uniffi_fn_func_"
+                                        "slh_dsa_signing_key_to_pkcs8"),
+          3,
+          [this](jsi::Runtime &rt, const jsi::Value &thisVal,
+                 const jsi::Value *args, size_t count) -> jsi::Value {
+            return this
+                ->cpp_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_signing_key_to_pkcs8(
+                    rt, thisVal, args, count);
+          });
+  props["ubrn_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_validate_signing_key"] =
+      jsi::Function::createFromHostFunction(
+          rt,
+          jsi::PropNameID::forAscii(rt, "ubrn_uniffi_slh_dsa_uniffi_fn_func_"
+                                        "slh_dsa_validate_signing_key"),
+          2,
+          [this](jsi::Runtime &rt, const jsi::Value &thisVal,
+                 const jsi::Value *args, size_t count) -> jsi::Value {
+            return this
+                ->cpp_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_validate_signing_key(
+                    rt, thisVal, args, count);
+          });
+  props["ubrn_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_verify"] =
+      jsi::Function::createFromHostFunction(
+          rt,
+          jsi::PropNameID::forAscii(
+              rt, "ubrn_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_verify"),
+          5,
+          [this](jsi::Runtime &rt, const jsi::Value &thisVal,
+                 const jsi::Value *args, size_t count) -> jsi::Value {
+            return this->cpp_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_verify(
+                rt, thisVal, args, count);
+          });
+  props["ubrn_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_verify_after_sign_"
+        "enabled"] = jsi::Function::createFromHostFunction(
+      rt,
+      jsi::PropNameID::forAscii(rt, "ubrn_uniffi_slh_dsa_uniffi_fn_func_slh_"
+                                    "dsa_verify_after_sign_enabled"),
+      0,
+      [this](jsi::Runtime &rt, const jsi::Value &thisVal,
+             const jsi::Value *args, size_t count) -> jsi::Value {
+        return this
+            ->cpp_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_verify_after_sign_enabled(
+                rt, thisVal, args, count);
+      });
+  props["ubrn_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_verifying_key_from_cose_"
+        "key"] = jsi::Function::createFromHostFunction(
+      rt,
+      jsi::PropNameID::forAscii(rt, "ubrn_uniffi_slh_dsa_uniffi_fn_func_slh_"
+                                    "dsa_verifying_key_from_cose_key"),
+      1,
+      [this](jsi::Runtime &rt, const jsi::Value &thisVal,
+             const jsi::Value *args, size_t count) -> jsi::Value {
+        return this
+            ->cpp_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_verifying_key_from_cose_key(
+                rt, thisVal, args, count);
+      });
+  props["ubrn_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_verifying_key_from_signing_"
+        "key"] = jsi::Function::createFromHostFunction(
+      rt,
+      jsi::PropNameID::forAscii(rt, "ubrn_uniffi_slh_dsa_uniffi_fn_func_slh_"
+                                    "dsa_verifying_key_from_signing_key"),
+      2,
+      [this](jsi::Runtime &rt, const jsi::Value &thisVal,
+             const jsi::Value *args, size_t count) -> jsi::Value {
+        return this
+            ->cpp_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_verifying_key_from_signing_key(
+                rt, thisVal, args, count);
+      });
+  props["ubrn_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_verifying_key_from_signing_"
+        "key_validated"] = jsi::Function::createFromHostFunction(
+      rt,
+      jsi::PropNameID::forAscii(rt, "ubrn_uniffi_slh_dsa_uniffi_fn_func_slh_"
+                                    "dsa_verifying_key_from_signing_key_"
+                                    "validated"),
+      2,
+      [this](jsi::Runtime &rt, const jsi::Value &thisVal,
+             const jsi::Value *args, size_t count) -> jsi::Value {
+        return this
+            ->cpp_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_verifying_key_from_signing_key_validated(
+                rt, thisVal, args, count);
+      });
+  props["ubrn_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_verifying_key_from_spki"] =
+      jsi::Function::createFromHostFunction(
+          rt,
+          jsi::PropNameID::forAscii(rt, "ubrn_uniffi_slh_dsa_uniffi_fn_func_"
+                                        "slh_dsa_verifying_key_from_spki"),
+          1,
+          [this](jsi::Runtime &rt, const jsi::Value &thisVal,
+                 const jsi::Value *args, size_t count) -> jsi::Value {
+            return this
+                ->cpp_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_verifying_key_from_spki(
+                    rt, thisVal, args, count);
+          });
+  props["ubrn_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_verifying_key_len"] =
+      jsi::Function::createFromHostFunction(
+          rt,
+          jsi::PropNameID::forAscii(
+              rt,
+              "ubrn_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_verifying_key_len"),
+          1,
+          [this](jsi::Runtime &rt, const jsi::Value &thisVal,
+                 const jsi::Value *args, size_t count) -> jsi::Value {
+            return this
+                ->cpp_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_verifying_key_len(
+                    rt, thisVal, args, count);
+          });
+  props["ubrn_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_verifying_key_to_cose_"
+        "key"] = jsi::Function::createFromHostFunction(
+      rt,
+      jsi::PropNameID::forAscii(rt, "ubrn_uniffi_slh_dsa_uniffi_fn_func_slh_"
+                                    "dsa_verifying_key_to_cose_key"),
+      2,
+      [this](jsi::Runtime &rt, const jsi::Value &thisVal,
+             const jsi::Value *args, size_t count) -> jsi::Value {
+        return this
+            ->cpp_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_verifying_key_to_cose_key(
+                rt, thisVal, args, count);
+      });
+  props["ubrn_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_verifying_key_to_spki"] =
+      jsi::Function::createFromHostFunction(
+          rt,
+          jsi::PropNameID::forAscii(rt, "ubrn_uniffi_slh_dsa_uniffi_fn_func_"
+                                        "slh_dsa_verifying_key_to_spki"),
+          3,
+          [this](jsi::Runtime &rt, const jsi::Value &thisVal,
+                 const jsi::Value *args, size_t count) -> jsi::Value {
+            return this
+                ->cpp_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_verifying_key_to_spki(
+                    rt, thisVal, args, count);
+          });
+  props["ubrn_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_cose_sign1_create"] =
+      jsi::Function::createFromHostFunction(
+          rt,
+          jsi::PropNameID::forAscii(rt, "ubrn_uniffi_slh_dsa_uniffi_checksum_"
+                                        "func_slh_dsa_cose_sign1_create"),
+          0,
+          [this](jsi::Runtime &rt, const jsi::Value &thisVal,
+                 const jsi::Value *args, size_t count) -> jsi::Value {
+            return this
+                ->cpp_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_cose_sign1_create(
+                    rt, thisVal, args, count);
+          });
+  props["ubrn_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_cose_sign1_verify"] =
+      jsi::Function::createFromHostFunction(
+          rt,
+          jsi::PropNameID::forAscii(rt, "ubrn_uniffi_slh_dsa_uniffi_checksum_"
+                                        "func_slh_dsa_cose_sign1_verify"),
+          0,
+          [this](jsi::Runtime &rt, const jsi::Value &thisVal,
+                 const jsi::Value *args, size_t count) -> jsi::Value {
+            return this
+                ->cpp_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_cose_sign1_verify(
+                    rt, thisVal, args, count);
+          });
+  props["ubrn_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_csr_create"] =
+      jsi::Function::createFromHostFunction(
+          rt,
+          jsi::PropNameID::forAscii(
+              rt,
+              "ubrn_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_csr_create"),
+          0,
+          [this](jsi::Runtime &rt, const jsi::Value &thisVal,
+                 const jsi::Value *args, size_t count) -> jsi::Value {
+            return this
+                ->cpp_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_csr_create(
+                    rt, thisVal, args, count);
+          });
+  props["ubrn_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_csr_verify"] =
+      jsi::Function::createFromHostFunction(
+          rt,
+          jsi::PropNameID::forAscii(
+              rt,
+              "ubrn_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_csr_verify"),
+          0,
+          [this](jsi::Runtime &rt, const jsi::Value &thisVal,
+                 const jsi::Value *args, size_t count) -> jsi::Value {
+            return this
+                ->cpp_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_csr_verify(
+                    rt, thisVal, args, count);
+          });
+  props["ubrn_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_envelope_decode"] =
+      jsi::Function::createFromHostFunction(
+          rt,
+          jsi::PropNameID::forAscii(rt, "ubrn_uniffi_slh_dsa_uniffi_checksum_"
+                                        "func_slh_dsa_envelope_decode"),
+          0,
+          [this](jsi::Runtime &rt, const jsi::Value &thisVal,
+                 const jsi::Value *args, size_t count) -> jsi::Value {
+            return this
+                ->cpp_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_envelope_decode(
+                    rt, thisVal, args, count);
+          });
+  props["ubrn_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_envelope_sign"] =
+      jsi::Function::createFromHostFunction(
+          rt,
+          jsi::PropNameID::forAscii(
+              rt,
+              "ubrn_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_envelope_sign"),
+          0,
+          [this](jsi::Runtime &rt, const jsi::Value &thisVal,
+                 const jsi::Value *args, size_t count) -> jsi::Value {
+            return this
+                ->cpp_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_envelope_sign(
+                    rt, thisVal, args, count);
+          });
+  props["ubrn_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_envelope_verify"] =
+      jsi::Function::createFromHostFunction(
+          rt,
+          jsi::PropNameID::forAscii(rt, "ubrn_uniffi_slh_dsa_uniffi_checksum_"
+                                        "func_slh_dsa_envelope_verify"),
+          0,
+          [this](jsi::Runtime &rt, const jsi::Value &thisVal,
+                 const jsi::Value *args, size_t count) -> jsi::Value {
+            return this
+                ->cpp_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_envelope_verify(
+                    rt, thisVal, args, count);
+          });
+  props["ubrn_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_fingerprint"] =
+      jsi::Function::createFromHostFunction(
+          rt,
+          jsi::PropNameID::forAscii(
+              rt,
+              "ubrn_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_fingerprint"),
+          0,
+          [this](jsi::Runtime &rt, const jsi::Value &thisVal,
+                 const jsi::Value *args, size_t count) -> jsi::Value {
+            return this
+                ->cpp_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_fingerprint(
+                    rt, thisVal, args, count);
+          });
+  props["ubrn_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_key_file_len"] =
+      jsi::Function::createFromHostFunction(
+          rt,
+          jsi::PropNameID::forAscii(
+              rt,
+              "ubrn_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_key_file_len"),
+          0,
+          [this](jsi::Runtime &rt, const jsi::Value &thisVal,
+                 const jsi::Value *args, size_t count) -> jsi::Value {
+            return this
+                ->cpp_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_key_file_len(
+                    rt, thisVal, args, count);
+          });
+  props["ubrn_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_keypair_from_seed"] =
+      jsi::Function::createFromHostFunction(
+          rt,
+          jsi::PropNameID::forAscii(rt, "ubrn_uniffi_slh_dsa_uniffi_checksum_"
+                                        "func_slh_dsa_keypair_from_seed"),
+          0,
+          [this](jsi::Runtime &rt, const jsi::Value &thisVal,
+                 const jsi::Value *args, size_t count) -> jsi::Value {
+            return this
+                ->cpp_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_keypair_from_seed(
+                    rt, thisVal, args, count);
+          });
+  props["ubrn_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_keypair_generate"] =
+      jsi::Function::createFromHostFunction(
+          rt,
+          jsi::PropNameID::forAscii(rt, "ubrn_uniffi_slh_dsa_uniffi_checksum_"
+                                        "func_slh_dsa_keypair_generate"),
+          0,
+          [this](jsi::Runtime &rt, const jsi::Value &thisVal,
+                 const jsi::Value *args, size_t count) -> jsi::Value {
+            return this
+                ->cpp_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_keypair_generate(
+                    rt, thisVal, args, count);
+          });
+  props["ubrn_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_parameter_name"] =
+      jsi::Function::createFromHostFunction(
+          rt,
+          jsi::PropNameID::forAscii(rt, "ubrn_uniffi_slh_dsa_uniffi_checksum_"
+                                        "func_slh_dsa_parameter_name"),
+          0,
+          [this](jsi::Runtime &rt, const jsi::Value &thisVal,
+                 const jsi::Value *args, size_t count) -> jsi::Value {
+            return this
+                ->cpp_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_parameter_name(
+                    rt, thisVal, args, count);
+          });
+  props["ubrn_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_parameter_set_from_"
+        "name"] = jsi::Function::createFromHostFunction(
+      rt,
+      jsi::PropNameID::forAscii(rt, "ubrn_uniffi_slh_dsa_uniffi_checksum_func_"
+                                    "slh_dsa_parameter_set_from_name"),
+      0,
+      [this](jsi::Runtime &rt, const jsi::Value &thisVal,
+             const jsi::Value *args, size_t count) -> jsi::Value {
+        return this
+            ->cpp_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_parameter_set_from_name(
+                rt, thisVal, args, count);
+      });
+  props["ubrn_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_parameter_set_from_"
+        "oid"] = jsi::Function::createFromHostFunction(
+      rt,
+      jsi::PropNameID::forAscii(rt, "ubrn_uniffi_slh_dsa_uniffi_checksum_func_"
+                                    "slh_dsa_parameter_set_from_oid"),
+      0,
+      [this](jsi::Runtime &rt, const jsi::Value &thisVal,
+             const jsi::Value *args, size_t count) -> jsi::Value {
+        return this
+            ->cpp_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_parameter_set_from_oid(
+                rt, thisVal, args, count);
+      });
+  props["ubrn_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_parameter_sets"] =
+      jsi::Function::createFromHostFunction(
+          rt,
+          jsi::PropNameID::forAscii(rt, "ubrn_uniffi_slh_dsa_uniffi_checksum_"
+                                        "func_slh_dsa_parameter_sets"),
+          0,
+          [this](jsi::Runtime &rt, const jsi::Value &thisVal,
+                 const jsi::Value *args, size_t count) -> jsi::Value {
+            return this
+                ->cpp_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_parameter_sets(
+                    rt, thisVal, args, count);
+          });
+  props["ubrn_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_seed_len"] =
+      jsi::Function::createFromHostFunction(
+          rt,
+          jsi::PropNameID::forAscii(
+              rt, "ubrn_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_seed_len"),
+          0,
+          [this](jsi::Runtime &rt, const jsi::Value &thisVal,
+                 const jsi::Value *args, size_t count) -> jsi::Value {
+            return this
+                ->cpp_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_seed_len(
+                    rt, thisVal, args, count);
+          });
+  props["ubrn_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_set_verify_after_"
+        "sign"] = jsi::Function::createFromHostFunction(
+      rt,
+      jsi::PropNameID::forAscii(rt, "ubrn_uniffi_slh_dsa_uniffi_checksum_func_"
+                                    "slh_dsa_set_verify_after_sign"),
+      0,
+      [this](jsi::Runtime &rt, const jsi::Value &thisVal,
+             const jsi::Value *args, size_t count) -> jsi::Value {
+        return this
+            ->cpp_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_set_verify_after_sign(
+                rt, thisVal, args, count);
+      });
+  props["ubrn_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_sign"] =
+      jsi::Function::createFromHostFunction(
+          rt,
+          jsi::PropNameID::forAscii(
+              rt, "ubrn_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_sign"),
+          0,
+          [this](jsi::Runtime &rt, const jsi::Value &thisVal,
+                 const jsi::Value *args, size_t count) -> jsi::Value {
+            return this->cpp_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_sign(
+                rt, thisVal, args, count);
+          });
+  props["ubrn_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_sign_batch"] =
+      jsi::Function::createFromHostFunction(
+          rt,
+          jsi::PropNameID::forAscii(
+              rt,
+              "ubrn_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_sign_batch"),
+          0,
+          [this](jsi::Runtime &rt, const jsi::Value &thisVal,
+                 const jsi::Value *args, size_t count) -> jsi::Value {
+            return this
+                ->cpp_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_sign_batch(
+                    rt, thisVal, args, count);
+          });
+  props["ubrn_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_sign_checked"] =
+      jsi::Function::createFromHostFunction(
+          rt,
+          jsi::PropNameID::forAscii(
+              rt,
+              "ubrn_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_sign_checked"),
+          0,
+          [this](jsi::Runtime &rt, const jsi::Value &thisVal,
+                 const jsi::Value *args, size_t count) -> jsi::Value {
+            return this
+                ->cpp_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_sign_checked(
+                    rt, thisVal, args, count);
+          });
+  props["ubrn_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_sign_deterministic"] =
+      jsi::Function::createFromHostFunction(
+          rt,
+          jsi::PropNameID::forAscii(rt, "ubrn_uniffi_slh_dsa_uniffi_checksum_"
+                                        "func_slh_dsa_sign_deterministic"),
+          0,
+          [this](jsi::Runtime &rt, const jsi::Value &thisVal,
+                 const jsi::Value *args, size_t count) -> jsi::Value {
+            return this
+                ->cpp_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_sign_deterministic(
+                    rt, thisVal, args, count);
+          });
+  props["ubrn_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_signature_len"] =
+      jsi::Function::createFromHostFunction(
+          rt,
+          jsi::PropNameID::forAscii(
+              rt,
+              "ubrn_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_signature_len"),
+          0,
+          [this](jsi::Runtime &rt, const jsi::Value &thisVal,
+                 const jsi::Value *args, size_t count) -> jsi::Value {
+            return this
+                ->cpp_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_signature_len(
+                    rt, thisVal, args, count);
+          });
+  props["ubrn_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_signing_key_from_"
+        "cose_key"] = jsi::Function::createFromHostFunction(
+      rt,
+      jsi::PropNameID::forAscii(rt, "ubrn_uniffi_slh_dsa_uniffi_checksum_func_"
+                                    "slh_dsa_signing_key_from_cose_key"),
+      0,
+      [this](jsi::Runtime &rt, const jsi::Value &thisVal,
+             const jsi::Value *args, size_t count) -> jsi::Value {
+        return this
+            ->cpp_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_signing_key_from_cose_key(
+                rt, thisVal, args, count);
+      });
+  props["ubrn_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_signing_key_from_"
+        "encrypted_pkcs8"] = jsi::Function::createFromHostFunction(
+      rt,
+      jsi::PropNameID::forAscii(rt, "ubrn_uniffi_slh_dsa_uniffi_checksum_func_"
+                                    "slh_dsa_signing_key_from_encrypted_pkcs8"),
+      0,
+      [this](jsi::Runtime &rt, const jsi::Value &thisVal,
+             const jsi::Value *args, size_t count) -> jsi::Value {
+        return this
+            ->cpp_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_signing_key_from_encrypted_pkcs8(
+                rt, thisVal, args, count);
+      });
+  props["ubrn_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_signing_key_from_key_"
+        "file"] = jsi::Function::createFromHostFunction(
+      rt,
+      jsi::PropNameID::forAscii(rt, "ubrn_uniffi_slh_dsa_uniffi_checksum_func_"
+                                    "slh_dsa_signing_key_from_key_file"),
+      0,
+      [this](jsi::Runtime &rt, const jsi::Value &thisVal,
+             const jsi::Value *args, size_t count) -> jsi::Value {
+        return this
+            ->cpp_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_signing_key_from_key_file(
+                rt, thisVal, args, count);
+      });
+  props["ubrn_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_signing_key_from_"
+        "pkcs8"] = jsi::Function::createFromHostFunction(
+      rt,
+      jsi::PropNameID::forAscii(rt, "ubrn_uniffi_slh_dsa_uniffi_checksum_func_"
+                                    "slh_dsa_signing_key_from_pkcs8"),
+      0,
+      [this](jsi::Runtime &rt, const jsi::Value &thisVal,
+             const jsi::Value *args, size_t count) -> jsi::Value {
+        return this
+            ->cpp_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_signing_key_from_pkcs8(
+                rt, thisVal, args, count);
+      });
+  props["ubrn_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_signing_key_len"] =
+      jsi::Function::createFromHostFunction(
+          rt,
+          jsi::PropNameID::forAscii(rt, "ubrn_uniffi_slh_dsa_uniffi_checksum_"
+                                        "func_slh_dsa_signing_key_len"),
+          0,
+          [this](jsi::Runtime &rt, const jsi::Value &thisVal,
+                 const jsi::Value *args, size_t count) -> jsi::Value {
+            return this
+                ->cpp_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_signing_key_len(
+                    rt, thisVal, args, count);
+          });
+  props["ubrn_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_signing_key_to_cose_"
+        "key"] = jsi::Function::createFromHostFunction(
+      rt,
+      jsi::PropNameID::forAscii(rt, "ubrn_uniffi_slh_dsa_uniffi_checksum_func_"
+                                    "slh_dsa_signing_key_to_cose_key"),
+      0,
+      [this](jsi::Runtime &rt, const jsi::Value &thisVal,
+             const jsi::Value *args, size_t count) -> jsi::Value {
+        return this
+            ->cpp_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_signing_key_to_cose_key(
+                rt, thisVal, args, count);
+      });
+  props["ubrn_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_signing_key_to_"
+        "encrypted_pkcs8"] = jsi::Function::createFromHostFunction(
+      rt,
+      jsi::PropNameID::forAscii(rt, "ubrn_uniffi_slh_dsa_uniffi_checksum_func_"
+                                    "slh_dsa_signing_key_to_encrypted_pkcs8"),
+      0,
+      [this](jsi::Runtime &rt, const jsi::Value &thisVal,
+             const jsi::Value *args, size_t count) -> jsi::Value {
+        return this
+            ->cpp_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_signing_key_to_encrypted_pkcs8(
+                rt, thisVal, args, count);
+      });
+  props["ubrn_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_signing_key_to_key_"
+        "file"] = jsi::Function::createFromHostFunction(
+      rt,
+      jsi::PropNameID::forAscii(rt, "ubrn_uniffi_slh_dsa_uniffi_checksum_func_"
+                                    "slh_dsa_signing_key_to_key_file"),
+      0,
+      [this](jsi::Runtime &rt, const jsi::Value &thisVal,
+             const jsi::Value *args, size_t count) -> jsi::Value {
+        return this
+            ->cpp_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_signing_key_to_key_file(
+                rt, thisVal, args, count);
+      });
+  props["ubrn_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_signing_key_to_"
+        "pkcs8"] = jsi::Function::createFromHostFunction(
+      rt,
+      jsi::PropNameID::forAscii(rt, "ubrn_uniffi_slh_dsa_uniffi_checksum_func_"
+                                    "slh_dsa_signing_key_to_pkcs8"),
+      0,
+      [this](jsi::Runtime &rt, const jsi::Value &thisVal,
+             const jsi::Value *args, size_t count) -> jsi::Value {
+        return this
+            ->cpp_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_signing_key_to_pkcs8(
+                rt, thisVal, args, count);
+      });
+  props["ubrn_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_validate_signing_"
+        "key"] = jsi::Function::createFromHostFunction(
+      rt,
+      jsi::PropNameID::forAscii(rt, "ubrn_uniffi_slh_dsa_uniffi_checksum_func_"
+                                    "slh_dsa_validate_signing_key"),
+      0,
+      [this](jsi::Runtime &rt, const jsi::Value &thisVal,
+             const jsi::Value *args, size_t count) -> jsi::Value {
+        return this
+            ->cpp_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_validate_signing_key(
+                rt, thisVal, args, count);
+      });
+  props["ubrn_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_verify"] =
+      jsi::Function::createFromHostFunction(
+          rt,
+          jsi::PropNameID::forAscii(
+              rt, "ubrn_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_verify"),
+          0,
+          [this](jsi::Runtime &rt, const jsi::Value &thisVal,
+                 const jsi::Value *args, size_t count) -> jsi::Value {
+            return this->cpp_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_verify(
+                rt, thisVal, args, count);
+          });
+  props["ubrn_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_verify_after_sign_"
+        "enabled"] = jsi::Function::createFromHostFunction(
+      rt,
+      jsi::PropNameID::forAscii(rt, "ubrn_uniffi_slh_dsa_uniffi_checksum_func_"
+                                    "slh_dsa_verify_after_sign_enabled"),
+      0,
+      [this](jsi::Runtime &rt, const jsi::Value &thisVal,
+             const jsi::Value *args, size_t count) -> jsi::Value {
+        return this
+            ->cpp_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_verify_after_sign_enabled(
+                rt, thisVal, args, count);
+      });
+  props["ubrn_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_verifying_key_from_"
+        "cose_key"] = jsi::Function::createFromHostFunction(
+      rt,
+      jsi::PropNameID::forAscii(rt, "ubrn_uniffi_slh_dsa_uniffi_checksum_func_"
+                                    "slh_dsa_verifying_key_from_cose_key"),
+      0,
+      [this](jsi::Runtime &rt, const jsi::Value &thisVal,
+             const jsi::Value *args, size_t count) -> jsi::Value {
+        return this
+            ->cpp_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_verifying_key_from_cose_key(
+                rt, thisVal, args, count);
+      });
+  props["ubrn_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_verifying_key_from_"
+        "signing_key"] = jsi::Function::createFromHostFunction(
+      rt,
+      jsi::PropNameID::forAscii(rt, "ubrn_uniffi_slh_dsa_uniffi_checksum_func_"
+                                    "slh_dsa_verifying_key_from_signing_key"),
+      0,
+      [this](jsi::Runtime &rt, const jsi::Value &thisVal,
+             const jsi::Value *args, size_t count) -> jsi::Value {
+        return this
+            ->cpp_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_verifying_key_from_signing_key(
+                rt, thisVal, args, count);
+      });
+  props["ubrn_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_verifying_key_from_"
+        "signing_key_validated"] = jsi::Function::createFromHostFunction(
+      rt,
+      jsi::PropNameID::forAscii(rt, "ubrn_uniffi_slh_dsa_uniffi_checksum_func_"
+                                    "slh_dsa_verifying_key_from_signing_key_"
+                                    "validated"),
+      0,
+      [this](jsi::Runtime &rt, const jsi::Value &thisVal,
+             const jsi::Value *args, size_t count) -> jsi::Value {
+        return this
+            ->cpp_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_verifying_key_from_signing_key_validated(
+                rt, thisVal, args, count);
+      });
+  props["ubrn_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_verifying_key_from_"
+        "spki"] = jsi::Function::createFromHostFunction(
+      rt,
+      jsi::PropNameID::forAscii(rt, "ubrn_uniffi_slh_dsa_uniffi_checksum_func_"
+                                    "slh_dsa_verifying_key_from_spki"),
+      0,
+      [this](jsi::Runtime &rt, const jsi::Value &thisVal,
+             const jsi::Value *args, size_t count) -> jsi::Value {
+        return this
+            ->cpp_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_verifying_key_from_spki(
+                rt, thisVal, args, count);
+      });
+  props["ubrn_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_verifying_key_len"] =
+      jsi::Function::createFromHostFunction(
+          rt,
+          jsi::PropNameID::forAscii(rt, "ubrn_uniffi_slh_dsa_uniffi_checksum_"
+                                        "func_slh_dsa_verifying_key_len"),
+          0,
+          [this](jsi::Runtime &rt, const jsi::Value &thisVal,
+                 const jsi::Value *args, size_t count) -> jsi::Value {
+            return this
+                ->cpp_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_verifying_key_len(
+                    rt, thisVal, args, count);
+          });
+  props["ubrn_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_verifying_key_to_"
+        "cose_key"] = jsi::Function::createFromHostFunction(
+      rt,
+      jsi::PropNameID::forAscii(rt, "ubrn_uniffi_slh_dsa_uniffi_checksum_func_"
+                                    "slh_dsa_verifying_key_to_cose_key"),
+      0,
+      [this](jsi::Runtime &rt, const jsi::Value &thisVal,
+             const jsi::Value *args, size_t count) -> jsi::Value {
+        return this
+            ->cpp_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_verifying_key_to_cose_key(
+                rt, thisVal, args, count);
+      });
+  props["ubrn_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_verifying_key_to_"
+        "spki"] = jsi::Function::createFromHostFunction(
+      rt,
+      jsi::PropNameID::forAscii(rt, "ubrn_uniffi_slh_dsa_uniffi_checksum_func_"
+                                    "slh_dsa_verifying_key_to_spki"),
+      0,
+      [this](jsi::Runtime &rt, const jsi::Value &thisVal,
+             const jsi::Value *args, size_t count) -> jsi::Value {
+        return this
+            ->cpp_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_verifying_key_to_spki(
+                rt, thisVal, args, count);
+      });
+  props["ubrn_ffi_slh_dsa_uniffi_uniffi_contract_version"] =
+      jsi::Function::createFromHostFunction(
+          rt,
+          jsi::PropNameID::forAscii(
+              rt, "ubrn_ffi_slh_dsa_uniffi_uniffi_contract_version"),
+          0,
+          [this](jsi::Runtime &rt, const jsi::Value &thisVal,
+                 const jsi::Value *args, size_t count) -> jsi::Value {
+            return this->cpp_ffi_slh_dsa_uniffi_uniffi_contract_version(
+                rt, thisVal, args, count);
+          });
+}
+
+void NativeSlhDsaUniffi::registerModule(
+    jsi::Runtime &rt, std::shared_ptr<react::CallInvoker> callInvoker) {
+  auto invoker =
+      std::make_shared<uniffi_runtime::UniffiCallInvoker>(callInvoker);
+  auto tm = std::make_shared<NativeSlhDsaUniffi>(rt, invoker);
+  auto obj = rt.global().createFromHostObject(rt, tm);
+  rt.global().setProperty(rt, "NativeSlhDsaUniffi", obj);
+}
+
+void NativeSlhDsaUniffi::unregisterModule(jsi::Runtime &rt) {
+  uniffi::slh_dsa_uniffi::registry::clearRegistry();
+}
+
+jsi::Value NativeSlhDsaUniffi::get(jsi::Runtime &rt,
+                                   const jsi::PropNameID &name) {
+  try {
+    return jsi::Value(rt, props.at(name.utf8(rt)));
+  } catch (std::out_of_range &e) {
+    return jsi::Value::undefined();
+  }
+}
+
+std::vector<jsi::PropNameID>
+NativeSlhDsaUniffi::getPropertyNames(jsi::Runtime &rt) {
+  std::vector<jsi::PropNameID> rval;
+  for (auto &[key, value] : props) {
+    rval.push_back(jsi::PropNameID::forUtf8(rt, key));
+  }
+  return rval;
+}
+
+void NativeSlhDsaUniffi::set(jsi::Runtime &rt, const jsi::PropNameID &name,
+                             const jsi::Value &value) {
+  props.insert_or_assign(name.utf8(rt), &value);
+}
+
+NativeSlhDsaUniffi::~NativeSlhDsaUniffi() {
+  // Cleanup for callback function RustFutureContinuationCallback
+  uniffi::slh_dsa_uniffi::cb::rustfuturecontinuationcallback::cleanup();
+  // Cleanup for "free" callback function CallbackInterfaceFree
+  uniffi::slh_dsa_uniffi::st::foreignfuture::foreignfuture::free::cleanup();
+}
+
+// Utility functions for serialization/deserialization of strings.
+jsi::Value
+NativeSlhDsaUniffi::cpp_uniffi_internal_fn_func_ffi__string_to_byte_length(
+    jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args,
+    size_t count) {
+  return uniffi_jsi::Bridging<std::string>::string_to_bytelength(rt, args[0]);
+}
+
+jsi::Value
+NativeSlhDsaUniffi::cpp_uniffi_internal_fn_func_ffi__string_to_arraybuffer(
+    jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args,
+    size_t count) {
+  return uniffi_jsi::Bridging<std::string>::string_to_arraybuffer(rt, args[0]);
+}
+
+jsi::Value
+NativeSlhDsaUniffi::cpp_uniffi_internal_fn_func_ffi__arraybuffer_to_string(
+    jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args,
+    size_t count) {
+  return uniffi_jsi::Bridging<std::string>::arraybuffer_to_string(rt, args[0]);
+}
+
+// Methods calling directly into the uniffi generated C API of the Rust crate.
+jsi::Value
+NativeSlhDsaUniffi::cpp_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_cose_sign1_create(
+    jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args,
+    size_t count) {
+  RustCallStatus status =
+      uniffi::slh_dsa_uniffi::Bridging<RustCallStatus>::rustSuccess(rt);
+  auto value = uniffi_slh_dsa_uniffi_fn_func_slh_dsa_cose_sign1_create(
+      uniffi::slh_dsa_uniffi::Bridging<RustBuffer>::fromJs(rt, callInvoker,
+                                                           args[0]),
+      uniffi::slh_dsa_uniffi::Bridging<RustBuffer>::fromJs(rt, callInvoker,
+                                                           args[1]),
+      uniffi::slh_dsa_uniffi::Bridging<RustBuffer>::fromJs(rt, callInvoker,
+                                                           args[2]),
+      uniffi::slh_dsa_uniffi::Bridging<RustBuffer>::fromJs(rt, callInvoker,
+                                                           args[3]),
+      uniffi::slh_dsa_uniffi::Bridging<RustBuffer>::fromJs(rt, callInvoker,
+                                                           args[4]),
+      uniffi_jsi::Bridging<int8_t>::fromJs(rt, callInvoker, args[5]),
+      &status);
+  uniffi::slh_dsa_uniffi::Bridging<RustCallStatus>::copyIntoJs(
+      rt, callInvoker, status, args[count - 1]);
+
+  return uniffi::slh_dsa_uniffi::Bridging<RustBuffer>::toJs(rt, callInvoker,
+                                                            value);
+}
+jsi::Value
+NativeSlhDsaUniffi::cpp_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_cose_sign1_verify(
+    jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args,
+    size_t count) {
+  RustCallStatus status =
+      uniffi::slh_dsa_uniffi::Bridging<RustCallStatus>::rustSuccess(rt);
+  auto value = uniffi_slh_dsa_uniffi_fn_func_slh_dsa_cose_sign1_verify(
+      uniffi::slh_dsa_uniffi::Bridging<RustBuffer>::fromJs(rt, callInvoker,
+                                                           args[0]),
+      uniffi::slh_dsa_uniffi::Bridging<RustBuffer>::fromJs(rt, callInvoker,
+                                                           args[1]),
+      uniffi::slh_dsa_uniffi::Bridging<RustBuffer>::fromJs(rt, callInvoker,
+                                                           args[2]),
+      uniffi::slh_dsa_uniffi::Bridging<RustBuffer>::fromJs(rt, callInvoker,
+                                                           args[3]),
+      &status);
+  uniffi::slh_dsa_uniffi::Bridging<RustCallStatus>::copyIntoJs(
+      rt, callInvoker, status, args[count - 1]);
+
+  return uniffi::slh_dsa_uniffi::Bridging<RustBuffer>::toJs(rt, callInvoker,
+                                                            value);
+}
+jsi::Value
+NativeSlhDsaUniffi::cpp_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_csr_create(
+    jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args,
+    size_t count) {
+  RustCallStatus status =
+      uniffi::slh_dsa_uniffi::Bridging<RustCallStatus>::rustSuccess(rt);
+  auto value = uniffi_slh_dsa_uniffi_fn_func_slh_dsa_csr_create(
+      uniffi::slh_dsa_uniffi::Bridging<RustBuffer>::fromJs(rt, callInvoker,
+                                                           args[0]),
+      uniffi::slh_dsa_uniffi::Bridging<RustBuffer>::fromJs(rt, callInvoker,
+                                                           args[1]),
+      uniffi::slh_dsa_uniffi::Bridging<RustBuffer>::fromJs(rt, callInvoker,
+                                                           args[2]),
+      uniffi::slh_dsa_uniffi::Bridging<RustBuffer>::fromJs(rt, callInvoker,
+                                                           args[3]),
+      uniffi::slh_dsa_uniffi::Bridging<RustBuffer>::fromJs(rt, callInvoker,
+                                                           args[4]),
+      uniffi_jsi::Bridging<int8_t>::fromJs(rt, callInvoker, args[5]),
+      &status);
+  uniffi::slh_dsa_uniffi::Bridging<RustCallStatus>::copyIntoJs(
+      rt, callInvoker, status, args[count - 1]);
+
+  return uniffi::slh_dsa_uniffi::Bridging<RustBuffer>::toJs(rt, callInvoker,
+                                                            value);
+}
+jsi::Value
+NativeSlhDsaUniffi::cpp_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_csr_verify(
+    jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args,
+    size_t count) {
+  RustCallStatus status =
+      uniffi::slh_dsa_uniffi::Bridging<RustCallStatus>::rustSuccess(rt);
+  auto value = uniffi_slh_dsa_uniffi_fn_func_slh_dsa_csr_verify(
+      uniffi::slh_dsa_uniffi::Bridging<RustBuffer>::fromJs(rt, callInvoker,
+                                                           args[0]),
+      &status);
+  uniffi::slh_dsa_uniffi::Bridging<RustCallStatus>::copyIntoJs(
+      rt, callInvoker, status, args[count - 1]);
+
+  return uniffi::slh_dsa_uniffi::Bridging<RustBuffer>::toJs(rt, callInvoker,
+                                                            value);
+}
+jsi::Value
+NativeSlhDsaUniffi::cpp_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_envelope_decode(
+    jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args,
+    size_t count) {
+  RustCallStatus status =
+      uniffi::slh_dsa_uniffi::Bridging<RustCallStatus>::rustSuccess(rt);
+  auto value = uniffi_slh_dsa_uniffi_fn_func_slh_dsa_envelope_decode(
+      uniffi::slh_dsa_uniffi::Bridging<RustBuffer>::fromJs(rt, callInvoker,
+                                                           args[0]),
+      &status);
+  uniffi::slh_dsa_uniffi::Bridging<RustCallStatus>::copyIntoJs(
+      rt, callInvoker, status, args[count - 1]);
+
+  return uniffi::slh_dsa_uniffi::Bridging<RustBuffer>::toJs(rt, callInvoker,
+                                                            value);
 }
+jsi::Value
+NativeSlhDsaUniffi::cpp_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_envelope_sign(
+    jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args,
+    size_t count) {
+  RustCallStatus status =
+      uniffi::slh_dsa_uniffi::Bridging<RustCallStatus>::rustSuccess(rt);
+  auto value = uniffi_slh_dsa_uniffi_fn_func_slh_dsa_envelope_sign(
+      uniffi::slh_dsa_uniffi::Bridging<RustBuffer>::fromJs(rt, callInvoker,
+                                                           args[0]),
+      uniffi::slh_dsa_uniffi::Bridging<RustBuffer>::fromJs(rt, callInvoker,
+                                                           args[1]),
+      uniffi::slh_dsa_uniffi::Bridging<RustBuffer>::fromJs(rt, callInvoker,
+                                                           args[2]),
+      uniffi::slh_dsa_uniffi::Bridging<RustBuffer>::fromJs(rt, callInvoker,
+                                                           args[3]),
+      uniffi::slh_dsa_uniffi::Bridging<RustBuffer>::fromJs(rt, callInvoker,
+                                                           args[4]),
+      uniffi::slh_dsa_uniffi::Bridging<RustBuffer>::fromJs(rt, callInvoker,
+                                                           args[5]),
+      uniffi_jsi::Bridging<int8_t>::fromJs(rt, callInvoker, args[6]),
+      &status);
+  uniffi::slh_dsa_uniffi::Bridging<RustCallStatus>::copyIntoJs(
+      rt, callInvoker, status, args[count - 1]);
 
-void NativeSlhDsaUniffi::registerModule(
-    jsi::Runtime &rt, std::shared_ptr<react::CallInvoker> callInvoker) {
-  auto invoker =
-      std::make_shared<uniffi_runtime::UniffiCallInvoker>(callInvoker);
-  auto tm = std::make_shared<NativeSlhDsaUniffi>(rt, invoker);
-  auto obj = rt.global().createFromHostObject(rt, tm);
-  rt.global().setProperty(rt, "NativeSlhDsaUniffi", obj);
+  return uniffi::slh_dsa_uniffi::Bridging<RustBuffer>::toJs(rt, callInvoker,
+                                                            value);
 }
+jsi::Value
+NativeSlhDsaUniffi::cpp_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_envelope_verify(
+    jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args,
+    size_t count) {
+  RustCallStatus status =
+      uniffi::slh_dsa_uniffi::Bridging<RustCallStatus>::rustSuccess(rt);
+  auto value = uniffi_slh_dsa_uniffi_fn_func_slh_dsa_envelope_verify(
+      uniffi::slh_dsa_uniffi::Bridging<RustBuffer>::fromJs(rt, callInvoker,
+                                                           args[0]),
+      uniffi::slh_dsa_uniffi::Bridging<RustBuffer>::fromJs(rt, callInvoker,
+                                                           args[1]),
+      uniffi::slh_dsa_uniffi::Bridging<RustBuffer>::fromJs(rt, callInvoker,
+                                                           args[2]),
+      &status);
+  uniffi::slh_dsa_uniffi::Bridging<RustCallStatus>::copyIntoJs(
+      rt, callInvoker, status, args[count - 1]);
 
-void NativeSlhDsaUniffi::unregisterModule(jsi::Runtime &rt) {
-  uniffi::slh_dsa_uniffi::registry::clearRegistry();
+  return uniffi::slh_dsa_uniffi::Bridging<RustBuffer>::toJs(rt, callInvoker,
+                                                            value);
 }
+jsi::Value
+NativeSlhDsaUniffi::cpp_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_fingerprint(
+    jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args,
+    size_t count) {
+  RustCallStatus status =
+      uniffi::slh_dsa_uniffi::Bridging<RustCallStatus>::rustSuccess(rt);
+  auto value = uniffi_slh_dsa_uniffi_fn_func_slh_dsa_fingerprint(
+      uniffi::slh_dsa_uniffi::Bridging<RustBuffer>::fromJs(rt, callInvoker,
+                                                           args[0]),
+      uniffi::slh_dsa_uniffi::Bridging<RustBuffer>::fromJs(rt, callInvoker,
+                                                           args[1]),
+      uniffi::slh_dsa_uniffi::Bridging<RustBuffer>::fromJs(rt, callInvoker,
+                                                           args[2]),
+      &status);
+  uniffi::slh_dsa_uniffi::Bridging<RustCallStatus>::copyIntoJs(
+      rt, callInvoker, status, args[count - 1]);
 
-jsi::Value NativeSlhDsaUniffi::get(jsi::Runtime &rt,
-                                   const jsi::PropNameID &name) {
-  try {
-    return jsi::Value(rt, props.at(name.utf8(rt)));
-  } catch (std::out_of_range &e) {
-    return jsi::Value::undefined();
-  }
+  return uniffi::slh_dsa_uniffi::Bridging<RustBuffer>::toJs(rt, callInvoker,
+                                                            value);
 }
+jsi::Value
+NativeSlhDsaUniffi::cpp_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_key_file_len(
+    jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args,
+    size_t count) {
+  RustCallStatus status =
+      uniffi::slh_dsa_uniffi::Bridging<RustCallStatus>::rustSuccess(rt);
+  auto value = uniffi_slh_dsa_uniffi_fn_func_slh_dsa_key_file_len(
+      uniffi::slh_dsa_uniffi::Bridging<RustBuffer>::fromJs(rt, callInvoker,
+                                                           args[0]),
+      &status);
+  uniffi::slh_dsa_uniffi::Bridging<RustCallStatus>::copyIntoJs(
+      rt, callInvoker, status, args[count - 1]);
 
-std::vector<jsi::PropNameID>
-NativeSlhDsaUniffi::getPropertyNames(jsi::Runtime &rt) {
-  std::vector<jsi::PropNameID> rval;
-  for (auto &[key, value] : props) {
-    rval.push_back(jsi::PropNameID::forUtf8(rt, key));
-  }
-  return rval;
+  return uniffi_jsi::Bridging<uint64_t>::toJs(rt, callInvoker, value);
 }
+jsi::Value
+NativeSlhDsaUniffi::cpp_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_keypair_from_seed(
+    jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args,
+    size_t count) {
+  RustCallStatus status =
+      uniffi::slh_dsa_uniffi::Bridging<RustCallStatus>::rustSuccess(rt);
+  auto value = uniffi_slh_dsa_uniffi_fn_func_slh_dsa_keypair_from_seed(
+      uniffi::slh_dsa_uniffi::Bridging<RustBuffer>::fromJs(rt, callInvoker,
+                                                           args[0]),
+      uniffi::slh_dsa_uniffi::Bridging<RustBuffer>::fromJs(rt, callInvoker,
+                                                           args[1]),
+      uniffi::slh_dsa_uniffi::Bridging<RustBuffer>::fromJs(rt, callInvoker,
+                                                           args[2]),
+      uniffi::slh_dsa_uniffi::Bridging<RustBuffer>::fromJs(rt, callInvoker,
+                                                           args[3]),
+      &status);
+  uniffi::slh_dsa_uniffi::Bridging<RustCallStatus>::copyIntoJs(
+      rt, callInvoker, status, args[count - 1]);
 
-void NativeSlhDsaUniffi::set(jsi::Runtime &rt, const jsi::PropNameID &name,
-                             const jsi::Value &value) {
-  props.insert_or_assign(name.utf8(rt), &value);
+  return uniffi::slh_dsa_uniffi::Bridging<RustBuffer>::toJs(rt, callInvoker,
+                                                            value);
+}
+jsi::Value
+NativeSlhDsaUniffi::cpp_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_keypair_generate(
+    jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args,
+    size_t count) {
+  RustCallStatus status =
+      uniffi::slh_dsa_uniffi::Bridging<RustCallStatus>::rustSuccess(rt);
+  auto value = uniffi_slh_dsa_uniffi_fn_func_slh_dsa_keypair_generate(
+      uniffi::slh_dsa_uniffi::Bridging<RustBuffer>::fromJs(rt, callInvoker,
+                                                           args[0]),
+      &status);
+  uniffi::slh_dsa_uniffi::Bridging<RustCallStatus>::copyIntoJs(
+      rt, callInvoker, status, args[count - 1]);
+
+  return uniffi::slh_dsa_uniffi::Bridging<RustBuffer>::toJs(rt, callInvoker,
+                                                            value);
 }
+jsi::Value
+NativeSlhDsaUniffi::cpp_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_parameter_name(
+    jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args,
+    size_t count) {
+  RustCallStatus status =
+      uniffi::slh_dsa_uniffi::Bridging<RustCallStatus>::rustSuccess(rt);
+  auto value = uniffi_slh_dsa_uniffi_fn_func_slh_dsa_parameter_name(
+      uniffi::slh_dsa_uniffi::Bridging<RustBuffer>::fromJs(rt, callInvoker,
+                                                           args[0]),
+      &status);
+  uniffi::slh_dsa_uniffi::Bridging<RustCallStatus>::copyIntoJs(
+      rt, callInvoker, status, args[count - 1]);
 
-NativeSlhDsaUniffi::~NativeSlhDsaUniffi() {
-  // Cleanup for callback function RustFutureContinuationCallback
-  uniffi::slh_dsa_uniffi::cb::rustfuturecontinuationcallback::cleanup();
-  // Cleanup for "free" callback function CallbackInterfaceFree
-  uniffi::slh_dsa_uniffi::st::foreignfuture::foreignfuture::free::cleanup();
+  return uniffi::slh_dsa_uniffi::Bridging<RustBuffer>::toJs(rt, callInvoker,
+                                                            value);
+}
+jsi::Value NativeSlhDsaUniffi::
+    cpp_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_parameter_set_from_name(
+        jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args,
+        size_t count) {
+  RustCallStatus status =
+      uniffi::slh_dsa_uniffi::Bridging<RustCallStatus>::rustSuccess(rt);
+  auto value = uniffi_slh_dsa_uniffi_fn_func_slh_dsa_parameter_set_from_name(
+      uniffi::slh_dsa_uniffi::Bridging<RustBuffer>::fromJs(rt, callInvoker,
+                                                           args[0]),
+      &status);
+  uniffi::slh_dsa_uniffi::Bridging<RustCallStatus>::copyIntoJs(
+      rt, callInvoker, status, args[count - 1]);
+
+  return uniffi::slh_dsa_uniffi::Bridging<RustBuffer>::toJs(rt, callInvoker,
+                                                            value);
+}
+jsi::Value NativeSlhDsaUniffi::
+    cpp_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_parameter_set_from_oid(
+        jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args,
+        size_t count) {
+  RustCallStatus status =
+      uniffi::slh_dsa_uniffi::Bridging<RustCallStatus>::rustSuccess(rt);
+  auto value = uniffi_slh_dsa_uniffi_fn_func_slh_dsa_parameter_set_from_oid(
+      uniffi::slh_dsa_uniffi::Bridging<RustBuffer>::fromJs(rt, callInvoker,
+                                                           args[0]),
+      &status);
+  uniffi::slh_dsa_uniffi::Bridging<RustCallStatus>::copyIntoJs(
+      rt, callInvoker, status, args[count - 1]);
+
+  return uniffi::slh_dsa_uniffi::Bridging<RustBuffer>::toJs(rt, callInvoker,
+                                                            value);
+}
+jsi::Value
+NativeSlhDsaUniffi::cpp_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_parameter_sets(
+    jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args,
+    size_t count) {
+  RustCallStatus status =
+      uniffi::slh_dsa_uniffi::Bridging<RustCallStatus>::rustSuccess(rt);
+  auto value = uniffi_slh_dsa_uniffi_fn_func_slh_dsa_parameter_sets(
+      &status);
+  uniffi::slh_dsa_uniffi::Bridging<RustCallStatus>::copyIntoJs(
+      rt, callInvoker, status, args[count - 1]);
+
+  return uniffi::slh_dsa_uniffi::Bridging<RustBuffer>::toJs(rt, callInvoker,
+                                                            value);
+}
+jsi::Value
+NativeSlhDsaUniffi::cpp_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_seed_len(
+    jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args,
+    size_t count) {
+  RustCallStatus status =
+      uniffi::slh_dsa_uniffi::Bridging<RustCallStatus>::rustSuccess(rt);
+  auto value = uniffi_slh_dsa_uniffi_fn_func_slh_dsa_seed_len(
+      uniffi::slh_dsa_uniffi::Bridging<RustBuffer>::fromJs(rt, callInvoker,
+                                                           args[0]),
+      &status);
+  uniffi::slh_dsa_uniffi::Bridging<RustCallStatus>::copyIntoJs(
+      rt, callInvoker, status, args[count - 1]);
+
+  return uniffi_jsi::Bridging<uint64_t>::toJs(rt, callInvoker, value);
+}
+jsi::Value NativeSlhDsaUniffi::
+    cpp_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_set_verify_after_sign(
+        jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args,
+        size_t count) {
+  RustCallStatus status =
+      uniffi::slh_dsa_uniffi::Bridging<RustCallStatus>::rustSuccess(rt);
+  uniffi_slh_dsa_uniffi_fn_func_slh_dsa_set_verify_after_sign(
+      uniffi_jsi::Bridging<int8_t>::fromJs(rt, callInvoker, args[0]),
+      &status);
+  uniffi::slh_dsa_uniffi::Bridging<RustCallStatus>::copyIntoJs(
+      rt, callInvoker, status, args[count - 1]);
+
+  return jsi::Value::undefined();
+}
+jsi::Value NativeSlhDsaUniffi::cpp_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_sign(
+    jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args,
+    size_t count) {
+  RustCallStatus status =
+      uniffi::slh_dsa_uniffi::Bridging<RustCallStatus>::rustSuccess(rt);
+  auto value = uniffi_slh_dsa_uniffi_fn_func_slh_dsa_sign(
+      uniffi::slh_dsa_uniffi::Bridging<RustBuffer>::fromJs(rt, callInvoker,
+                                                           args[0]),
+      uniffi::slh_dsa_uniffi::Bridging<RustBuffer>::fromJs(rt, callInvoker,
+                                                           args[1]),
+      uniffi::slh_dsa_uniffi::Bridging<RustBuffer>::fromJs(rt, callInvoker,
+                                                           args[2]),
+      uniffi::slh_dsa_uniffi::Bridging<RustBuffer>::fromJs(rt, callInvoker,
+                                                           args[3]),
+      &status);
+  uniffi::slh_dsa_uniffi::Bridging<RustCallStatus>::copyIntoJs(
+      rt, callInvoker, status, args[count - 1]);
+
+  return uniffi::slh_dsa_uniffi::Bridging<RustBuffer>::toJs(rt, callInvoker,
+                                                            value);
+}
+jsi::Value
+NativeSlhDsaUniffi::cpp_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_sign_batch(
+    jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args,
+    size_t count) {
+  RustCallStatus status =
+      uniffi::slh_dsa_uniffi::Bridging<RustCallStatus>::rustSuccess(rt);
+  auto value = uniffi_slh_dsa_uniffi_fn_func_slh_dsa_sign_batch(
+      uniffi::slh_dsa_uniffi::Bridging<RustBuffer>::fromJs(rt, callInvoker,
+                                                           args[0]),
+      uniffi::slh_dsa_uniffi::Bridging<RustBuffer>::fromJs(rt, callInvoker,
+                                                           args[1]),
+      uniffi::slh_dsa_uniffi::Bridging<RustBuffer>::fromJs(rt, callInvoker,
+                                                           args[2]),
+      uniffi::slh_dsa_uniffi::Bridging<RustBuffer>::fromJs(rt, callInvoker,
+                                                           args[3]),
+      &status);
+  uniffi::slh_dsa_uniffi::Bridging<RustCallStatus>::copyIntoJs(
+      rt, callInvoker, status, args[count - 1]);
+
+  return uniffi::slh_dsa_uniffi::Bridging<RustBuffer>::toJs(rt, callInvoker,
+                                                            value);
 }
-
-// Utility functions for serialization/deserialization of strings.
 jsi::Value
-NativeSlhDsaUniffi::cpp_uniffi_internal_fn_func_ffi__string_to_byte_length(
+NativeSlhDsaUniffi::cpp_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_sign_checked(
     jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args,
     size_t count) {
-  return uniffi_jsi::Bridging<std::string>::string_to_bytelength(rt, args[0]);
+  RustCallStatus status =
+      uniffi::slh_dsa_uniffi::Bridging<RustCallStatus>::rustSuccess(rt);
+  auto value = uniffi_slh_dsa_uniffi_fn_func_slh_dsa_sign_checked(
+      uniffi::slh_dsa_uniffi::Bridging<RustBuffer>::fromJs(rt, callInvoker,
+                                                           args[0]),
+      uniffi::slh_dsa_uniffi::Bridging<RustBuffer>::fromJs(rt, callInvoker,
+                                                           args[1]),
+      uniffi::slh_dsa_uniffi::Bridging<RustBuffer>::fromJs(rt, callInvoker,
+                                                           args[2]),
+      uniffi::slh_dsa_uniffi::Bridging<RustBuffer>::fromJs(rt, callInvoker,
+                                                           args[3]),
+      &status);
+  uniffi::slh_dsa_uniffi::Bridging<RustCallStatus>::copyIntoJs(
+      rt, callInvoker, status, args[count - 1]);
+
+  return uniffi::slh_dsa_uniffi::Bridging<RustBuffer>::toJs(rt, callInvoker,
+                                                            value);
 }
+jsi::Value NativeSlhDsaUniffi::
+    cpp_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_sign_deterministic(
+        jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args,
+        size_t count) {
+  RustCallStatus status =
+      uniffi::slh_dsa_uniffi::Bridging<RustCallStatus>::rustSuccess(rt);
+  auto value = uniffi_slh_dsa_uniffi_fn_func_slh_dsa_sign_deterministic(
+      uniffi::slh_dsa_uniffi::Bridging<RustBuffer>::fromJs(rt, callInvoker,
+                                                           args[0]),
+      uniffi::slh_dsa_uniffi::Bridging<RustBuffer>::fromJs(rt, callInvoker,
+                                                           args[1]),
+      uniffi::slh_dsa_uniffi::Bridging<RustBuffer>::fromJs(rt, callInvoker,
+                                                           args[2]),
+      uniffi::slh_dsa_uniffi::Bridging<RustBuffer>::fromJs(rt, callInvoker,
+                                                           args[3]),
+      &status);
+  uniffi::slh_dsa_uniffi::Bridging<RustCallStatus>::copyIntoJs(
+      rt, callInvoker, status, args[count - 1]);
 
+  return uniffi::slh_dsa_uniffi::Bridging<RustBuffer>::toJs(rt, callInvoker,
+                                                            value);
+}
 jsi::Value
-NativeSlhDsaUniffi::cpp_uniffi_internal_fn_func_ffi__string_to_arraybuffer(
+NativeSlhDsaUniffi::cpp_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_signature_len(
     jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args,
     size_t count) {
-  return uniffi_jsi::Bridging<std::string>::string_to_arraybuffer(rt, args[0]);
+  RustCallStatus status =
+      uniffi::slh_dsa_uniffi::Bridging<RustCallStatus>::rustSuccess(rt);
+  auto value = uniffi_slh_dsa_uniffi_fn_func_slh_dsa_signature_len(
+      uniffi::slh_dsa_uniffi::Bridging<RustBuffer>::fromJs(rt, callInvoker,
+                                                           args[0]),
+      &status);
+  uniffi::slh_dsa_uniffi::Bridging<RustCallStatus>::copyIntoJs(
+      rt, callInvoker, status, args[count - 1]);
+
+  return uniffi_jsi::Bridging<uint64_t>::toJs(rt, callInvoker, value);
+}
+jsi::Value NativeSlhDsaUniffi::
+    cpp_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_signing_key_from_cose_key(
+        jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args,
+        size_t count) {
+  RustCallStatus status =
+      uniffi::slh_dsa_uniffi::Bridging<RustCallStatus>::rustSuccess(rt);
+  auto value = uniffi_slh_dsa_uniffi_fn_func_slh_dsa_signing_key_from_cose_key(
+      uniffi::slh_dsa_uniffi::Bridging<RustBuffer>::fromJs(rt, callInvoker,
+                                                           args[0]),
+      &status);
+  uniffi::slh_dsa_uniffi::Bridging<RustCallStatus>::copyIntoJs(
+      rt, callInvoker, status, args[count - 1]);
+
+  return uniffi::slh_dsa_uniffi::Bridging<RustBuffer>::toJs(rt, callInvoker,
+                                                            value);
 }
+jsi::Value NativeSlhDsaUniffi::
+    cpp_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_signing_key_from_encrypted_pkcs8(
+        jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args,
+        size_t count) {
+  RustCallStatus status =
+      uniffi::slh_dsa_uniffi::Bridging<RustCallStatus>::rustSuccess(rt);
+  auto value =
+      uniffi_slh_dsa_uniffi_fn_func_slh_dsa_signing_key_from_encrypted_pkcs8(
+          uniffi::slh_dsa_uniffi::Bridging<RustBuffer>::fromJs(rt, callInvoker,
+                                                               args[0]),
+          uniffi::slh_dsa_uniffi::Bridging<RustBuffer>::fromJs(rt, callInvoker,
+                                                               args[1]),
+          &status);
+  uniffi::slh_dsa_uniffi::Bridging<RustCallStatus>::copyIntoJs(
+      rt, callInvoker, status, args[count - 1]);
 
-jsi::Value
-NativeSlhDsaUniffi::cpp_uniffi_internal_fn_func_ffi__arraybuffer_to_string(
-    jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args,
-    size_t count) {
-  return uniffi_jsi::Bridging<std::string>::arraybuffer_to_string(rt, args[0]);
+  return uniffi::slh_dsa_uniffi::Bridging<RustBuffer>::toJs(rt, callInvoker,
+                                                            value);
 }
+jsi::Value NativeSlhDsaUniffi::
+    cpp_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_signing_key_from_key_file(
+        jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args,
+        size_t count) {
+  RustCallStatus status =
+      uniffi::slh_dsa_uniffi::Bridging<RustCallStatus>::rustSuccess(rt);
+  auto value = uniffi_slh_dsa_uniffi_fn_func_slh_dsa_signing_key_from_key_file(
+      uniffi::slh_dsa_uniffi::Bridging<RustBuffer>::fromJs(rt, callInvoker,
+                                                           args[0]),
+      uniffi::slh_dsa_uniffi::Bridging<RustBuffer>::fromJs(rt, callInvoker,
+                                                           args[1]),
+      &status);
+  uniffi::slh_dsa_uniffi::Bridging<RustCallStatus>::copyIntoJs(
+      rt, callInvoker, status, args[count - 1]);
 
-// Methods calling directly into the uniffi generated C API of the Rust crate.
-jsi::Value
-NativeSlhDsaUniffi::cpp_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_keypair_generate(
-    jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args,
-    size_t count) {
+  return uniffi::slh_dsa_uniffi::Bridging<RustBuffer>::toJs(rt, callInvoker,
+                                                            value);
+}
+jsi::Value NativeSlhDsaUniffi::
+    cpp_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_signing_key_from_pkcs8(
+        jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args,
+        size_t count) {
   RustCallStatus status =
       uniffi::slh_dsa_uniffi::Bridging<RustCallStatus>::rustSuccess(rt);
-  auto value = uniffi_slh_dsa_uniffi_fn_func_slh_dsa_keypair_generate(
+  auto value = uniffi_slh_dsa_uniffi_fn_func_slh_dsa_signing_key_from_pkcs8(
       uniffi::slh_dsa_uniffi::Bridging<RustBuffer>::fromJs(rt, callInvoker,
                                                            args[0]),
       &status);
@@ -1998,14 +3363,31 @@ NativeSlhDsaUniffi::cpp_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_keypair_generate(
                                                             value);
 }
 jsi::Value
-NativeSlhDsaUniffi::cpp_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_parameter_name(
+NativeSlhDsaUniffi::cpp_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_signing_key_len(
     jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args,
     size_t count) {
   RustCallStatus status =
       uniffi::slh_dsa_uniffi::Bridging<RustCallStatus>::rustSuccess(rt);
-  auto value = uniffi_slh_dsa_uniffi_fn_func_slh_dsa_parameter_name(
+  auto value = uniffi_slh_dsa_uniffi_fn_func_slh_dsa_signing_key_len(
+      uniffi::slh_dsa_uniffi::Bridging<RustBuffer>::fromJs(rt, callInvoker,
+                                                           args[0]),
+      &status);
+  uniffi::slh_dsa_uniffi::Bridging<RustCallStatus>::copyIntoJs(
+      rt, callInvoker, status, args[count - 1]);
+
+  return uniffi_jsi::Bridging<uint64_t>::toJs(rt, callInvoker, value);
+}
+jsi::Value NativeSlhDsaUniffi::
+    cpp_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_signing_key_to_cose_key(
+        jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args,
+        size_t count) {
+  RustCallStatus status =
+      uniffi::slh_dsa_uniffi::Bridging<RustCallStatus>::rustSuccess(rt);
+  auto value = uniffi_slh_dsa_uniffi_fn_func_slh_dsa_signing_key_to_cose_key(
       uniffi::slh_dsa_uniffi::Bridging<RustBuffer>::fromJs(rt, callInvoker,
                                                            args[0]),
+      uniffi::slh_dsa_uniffi::Bridging<RustBuffer>::fromJs(rt, callInvoker,
+                                                           args[1]),
       &status);
   uniffi::slh_dsa_uniffi::Bridging<RustCallStatus>::copyIntoJs(
       rt, callInvoker, status, args[count - 1]);
@@ -2013,12 +3395,35 @@ NativeSlhDsaUniffi::cpp_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_parameter_name(
   return uniffi::slh_dsa_uniffi::Bridging<RustBuffer>::toJs(rt, callInvoker,
                                                             value);
 }
-jsi::Value NativeSlhDsaUniffi::cpp_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_sign(
-    jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args,
-    size_t count) {
+jsi::Value NativeSlhDsaUniffi::
+    cpp_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_signing_key_to_encrypted_pkcs8(
+        jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args,
+        size_t count) {
   RustCallStatus status =
       uniffi::slh_dsa_uniffi::Bridging<RustCallStatus>::rustSuccess(rt);
-  auto value = uniffi_slh_dsa_uniffi_fn_func_slh_dsa_sign(
+  auto value =
+      uniffi_slh_dsa_uniffi_fn_func_slh_dsa_signing_key_to_encrypted_pkcs8(
+          uniffi::slh_dsa_uniffi::Bridging<RustBuffer>::fromJs(rt, callInvoker,
+                                                               args[0]),
+          uniffi::slh_dsa_uniffi::Bridging<RustBuffer>::fromJs(rt, callInvoker,
+                                                               args[1]),
+          uniffi::slh_dsa_uniffi::Bridging<RustBuffer>::fromJs(rt, callInvoker,
+                                                               args[2]),
+          uniffi_jsi::Bridging<int8_t>::fromJs(rt, callInvoker, args[3]),
+          &status);
+  uniffi::slh_dsa_uniffi::Bridging<RustCallStatus>::copyIntoJs(
+      rt, callInvoker, status, args[count - 1]);
+
+  return uniffi::slh_dsa_uniffi::Bridging<RustBuffer>::toJs(rt, callInvoker,
+                                                            value);
+}
+jsi::Value NativeSlhDsaUniffi::
+    cpp_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_signing_key_to_key_file(
+        jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args,
+        size_t count) {
+  RustCallStatus status =
+      uniffi::slh_dsa_uniffi::Bridging<RustCallStatus>::rustSuccess(rt);
+  auto value = uniffi_slh_dsa_uniffi_fn_func_slh_dsa_signing_key_to_key_file(
       uniffi::slh_dsa_uniffi::Bridging<RustBuffer>::fromJs(rt, callInvoker,
                                                            args[0]),
       uniffi::slh_dsa_uniffi::Bridging<RustBuffer>::fromJs(rt, callInvoker,
@@ -2027,6 +3432,8 @@ jsi::Value NativeSlhDsaUniffi::cpp_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_sign(
                                                            args[2]),
       uniffi::slh_dsa_uniffi::Bridging<RustBuffer>::fromJs(rt, callInvoker,
                                                            args[3]),
+      uniffi::slh_dsa_uniffi::Bridging<RustBuffer>::fromJs(rt, callInvoker,
+                                                           args[4]),
       &status);
   uniffi::slh_dsa_uniffi::Bridging<RustCallStatus>::copyIntoJs(
       rt, callInvoker, status, args[count - 1]);
@@ -2035,12 +3442,48 @@ jsi::Value NativeSlhDsaUniffi::cpp_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_sign(
                                                             value);
 }
 jsi::Value NativeSlhDsaUniffi::
-    cpp_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_sign_deterministic(
+    cpp_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_signing_key_to_pkcs8(
         jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args,
         size_t count) {
   RustCallStatus status =
       uniffi::slh_dsa_uniffi::Bridging<RustCallStatus>::rustSuccess(rt);
-  auto value = uniffi_slh_dsa_uniffi_fn_func_slh_dsa_sign_deterministic(
+  auto value = uniffi_slh_dsa_uniffi_fn_func_slh_dsa_signing_key_to_pkcs8(
+      uniffi::slh_dsa_uniffi::Bridging<RustBuffer>::fromJs(rt, callInvoker,
+                                                           args[0]),
+      uniffi::slh_dsa_uniffi::Bridging<RustBuffer>::fromJs(rt, callInvoker,
+                                                           args[1]),
+      uniffi_jsi::Bridging<int8_t>::fromJs(rt, callInvoker, args[2]),
+      &status);
+  uniffi::slh_dsa_uniffi::Bridging<RustCallStatus>::copyIntoJs(
+      rt, callInvoker, status, args[count - 1]);
+
+  return uniffi::slh_dsa_uniffi::Bridging<RustBuffer>::toJs(rt, callInvoker,
+                                                            value);
+}
+jsi::Value NativeSlhDsaUniffi::
+    cpp_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_validate_signing_key(
+        jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args,
+        size_t count) {
+  RustCallStatus status =
+      uniffi::slh_dsa_uniffi::Bridging<RustCallStatus>::rustSuccess(rt);
+  auto value = uniffi_slh_dsa_uniffi_fn_func_slh_dsa_validate_signing_key(
+      uniffi::slh_dsa_uniffi::Bridging<RustBuffer>::fromJs(rt, callInvoker,
+                                                           args[0]),
+      uniffi::slh_dsa_uniffi::Bridging<RustBuffer>::fromJs(rt, callInvoker,
+                                                           args[1]),
+      &status);
+  uniffi::slh_dsa_uniffi::Bridging<RustCallStatus>::copyIntoJs(
+      rt, callInvoker, status, args[count - 1]);
+
+  return uniffi::slh_dsa_uniffi::Bridging<RustBuffer>::toJs(rt, callInvoker,
+                                                            value);
+}
+jsi::Value NativeSlhDsaUniffi::cpp_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_verify(
+    jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args,
+    size_t count) {
+  RustCallStatus status =
+      uniffi::slh_dsa_uniffi::Bridging<RustCallStatus>::rustSuccess(rt);
+  auto value = uniffi_slh_dsa_uniffi_fn_func_slh_dsa_verify(
       uniffi::slh_dsa_uniffi::Bridging<RustBuffer>::fromJs(rt, callInvoker,
                                                            args[0]),
       uniffi::slh_dsa_uniffi::Bridging<RustBuffer>::fromJs(rt, callInvoker,
@@ -2049,35 +3492,106 @@ jsi::Value NativeSlhDsaUniffi::
                                                            args[2]),
       uniffi::slh_dsa_uniffi::Bridging<RustBuffer>::fromJs(rt, callInvoker,
                                                            args[3]),
+      uniffi::slh_dsa_uniffi::Bridging<RustBuffer>::fromJs(rt, callInvoker,
+                                                           args[4]),
+      &status);
+  uniffi::slh_dsa_uniffi::Bridging<RustCallStatus>::copyIntoJs(
+      rt, callInvoker, status, args[count - 1]);
+
+  return uniffi::slh_dsa_uniffi::Bridging<RustBuffer>::toJs(rt, callInvoker,
+                                                            value);
+}
+jsi::Value NativeSlhDsaUniffi::
+    cpp_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_verify_after_sign_enabled(
+        jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args,
+        size_t count) {
+  RustCallStatus status =
+      uniffi::slh_dsa_uniffi::Bridging<RustCallStatus>::rustSuccess(rt);
+  auto value = uniffi_slh_dsa_uniffi_fn_func_slh_dsa_verify_after_sign_enabled(
       &status);
   uniffi::slh_dsa_uniffi::Bridging<RustCallStatus>::copyIntoJs(
       rt, callInvoker, status, args[count - 1]);
 
+  return uniffi_jsi::Bridging<int8_t>::toJs(rt, callInvoker, value);
+}
+jsi::Value NativeSlhDsaUniffi::
+    cpp_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_verifying_key_from_cose_key(
+        jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args,
+        size_t count) {
+  RustCallStatus status =
+      uniffi::slh_dsa_uniffi::Bridging<RustCallStatus>::rustSuccess(rt);
+  auto value =
+      uniffi_slh_dsa_uniffi_fn_func_slh_dsa_verifying_key_from_cose_key(
+          uniffi::slh_dsa_uniffi::Bridging<RustBuffer>::fromJs(rt, callInvoker,
+                                                               args[0]),
+          &status);
+  uniffi::slh_dsa_uniffi::Bridging<RustCallStatus>::copyIntoJs(
+      rt, callInvoker, status, args[count - 1]);
+
+  return uniffi::slh_dsa_uniffi::Bridging<RustBuffer>::toJs(rt, callInvoker,
+                                                            value);
+}
+jsi::Value NativeSlhDsaUniffi::
+    cpp_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_verifying_key_from_signing_key(
+        jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args,
+        size_t count) {
+  RustCallStatus status =
+      uniffi::slh_dsa_uniffi::Bridging<RustCallStatus>::rustSuccess(rt);
+  auto value =
+      uniffi_slh_dsa_uniffi_fn_func_slh_dsa_verifying_key_from_signing_key(
+          uniffi::slh_dsa_uniffi::Bridging<RustBuffer>::fromJs(rt, callInvoker,
+                                                               args[0]),
+          uniffi::slh_dsa_uniffi::Bridging<RustBuffer>::fromJs(rt, callInvoker,
+                                                               args[1]),
+          &status);
+  uniffi::slh_dsa_uniffi::Bridging<RustCallStatus>::copyIntoJs(
+      rt, callInvoker, status, args[count - 1]);
+
+  return uniffi::slh_dsa_uniffi::Bridging<RustBuffer>::toJs(rt, callInvoker,
+                                                            value);
+}
+jsi::Value NativeSlhDsaUniffi::
+    cpp_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_verifying_key_from_signing_key_validated(
+        jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args,
+        size_t count) {
+  RustCallStatus status =
+      uniffi::slh_dsa_uniffi::Bridging<RustCallStatus>::rustSuccess(rt);
+  auto value =
+      uniffi_slh_dsa_uniffi_fn_func_slh_dsa_verifying_key_from_signing_key_validated(
+          uniffi::slh_dsa_uniffi::Bridging<RustBuffer>::fromJs(rt, callInvoker,
+                                                               args[0]),
+          uniffi::slh_dsa_uniffi::Bridging<RustBuffer>::fromJs(rt, callInvoker,
+                                                               args[1]),
+          &status);
+  uniffi::slh_dsa_uniffi::Bridging<RustCallStatus>::copyIntoJs(
+      rt, callInvoker, status, args[count - 1]);
+
   return uniffi::slh_dsa_uniffi::Bridging<RustBuffer>::toJs(rt, callInvoker,
                                                             value);
 }
-jsi::Value
-NativeSlhDsaUniffi::cpp_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_signature_len(
-    jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args,
-    size_t count) {
+jsi::Value NativeSlhDsaUniffi::
+    cpp_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_verifying_key_from_spki(
+        jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args,
+        size_t count) {
   RustCallStatus status =
       uniffi::slh_dsa_uniffi::Bridging<RustCallStatus>::rustSuccess(rt);
-  auto value = uniffi_slh_dsa_uniffi_fn_func_slh_dsa_signature_len(
+  auto value = uniffi_slh_dsa_uniffi_fn_func_slh_dsa_verifying_key_from_spki(
       uniffi::slh_dsa_uniffi::Bridging<RustBuffer>::fromJs(rt, callInvoker,
                                                            args[0]),
       &status);
   uniffi::slh_dsa_uniffi::Bridging<RustCallStatus>::copyIntoJs(
       rt, callInvoker, status, args[count - 1]);
 
-  return uniffi_jsi::Bridging<uint64_t>::toJs(rt, callInvoker, value);
+  return uniffi::slh_dsa_uniffi::Bridging<RustBuffer>::toJs(rt, callInvoker,
+                                                            value);
 }
 jsi::Value
-NativeSlhDsaUniffi::cpp_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_signing_key_len(
+NativeSlhDsaUniffi::cpp_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_verifying_key_len(
     jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args,
     size_t count) {
   RustCallStatus status =
       uniffi::slh_dsa_uniffi::Bridging<RustCallStatus>::rustSuccess(rt);
-  auto value = uniffi_slh_dsa_uniffi_fn_func_slh_dsa_signing_key_len(
+  auto value = uniffi_slh_dsa_uniffi_fn_func_slh_dsa_verifying_key_len(
       uniffi::slh_dsa_uniffi::Bridging<RustBuffer>::fromJs(rt, callInvoker,
                                                            args[0]),
       &status);
@@ -2086,22 +3600,17 @@ NativeSlhDsaUniffi::cpp_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_signing_key_len(
 
   return uniffi_jsi::Bridging<uint64_t>::toJs(rt, callInvoker, value);
 }
-jsi::Value NativeSlhDsaUniffi::cpp_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_verify(
-    jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args,
-    size_t count) {
+jsi::Value NativeSlhDsaUniffi::
+    cpp_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_verifying_key_to_cose_key(
+        jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args,
+        size_t count) {
   RustCallStatus status =
       uniffi::slh_dsa_uniffi::Bridging<RustCallStatus>::rustSuccess(rt);
-  auto value = uniffi_slh_dsa_uniffi_fn_func_slh_dsa_verify(
+  auto value = uniffi_slh_dsa_uniffi_fn_func_slh_dsa_verifying_key_to_cose_key(
       uniffi::slh_dsa_uniffi::Bridging<RustBuffer>::fromJs(rt, callInvoker,
                                                            args[0]),
       uniffi::slh_dsa_uniffi::Bridging<RustBuffer>::fromJs(rt, callInvoker,
                                                            args[1]),
-      uniffi::slh_dsa_uniffi::Bridging<RustBuffer>::fromJs(rt, callInvoker,
-                                                           args[2]),
-      uniffi::slh_dsa_uniffi::Bridging<RustBuffer>::fromJs(rt, callInvoker,
-                                                           args[3]),
-      uniffi::slh_dsa_uniffi::Bridging<RustBuffer>::fromJs(rt, callInvoker,
-                                                           args[4]),
       &status);
   uniffi::slh_dsa_uniffi::Bridging<RustCallStatus>::copyIntoJs(
       rt, callInvoker, status, args[count - 1]);
@@ -2110,38 +3619,103 @@ jsi::Value NativeSlhDsaUniffi::cpp_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_verify(
                                                             value);
 }
 jsi::Value NativeSlhDsaUniffi::
-    cpp_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_verifying_key_from_signing_key(
+    cpp_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_verifying_key_to_spki(
         jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args,
         size_t count) {
   RustCallStatus status =
       uniffi::slh_dsa_uniffi::Bridging<RustCallStatus>::rustSuccess(rt);
-  auto value =
-      uniffi_slh_dsa_uniffi_fn_func_slh_dsa_verifying_key_from_signing_key(
-          uniffi::slh_dsa_uniffi::Bridging<RustBuffer>::fromJs(rt, callInvoker,
-                                                               args[0]),
-          uniffi::slh_dsa_uniffi::Bridging<RustBuffer>::fromJs(rt, callInvoker,
-                                                               args[1]),
-          &status);
+  auto value = uniffi_slh_dsa_uniffi_fn_func_slh_dsa_verifying_key_to_spki(
+      uniffi::slh_dsa_uniffi::Bridging<RustBuffer>::fromJs(rt, callInvoker,
+                                                           args[0]),
+      uniffi::slh_dsa_uniffi::Bridging<RustBuffer>::fromJs(rt, callInvoker,
+                                                           args[1]),
+      uniffi_jsi::Bridging<int8_t>::fromJs(rt, callInvoker, args[2]),
+      &status);
   uniffi::slh_dsa_uniffi::Bridging<RustCallStatus>::copyIntoJs(
       rt, callInvoker, status, args[count - 1]);
 
   return uniffi::slh_dsa_uniffi::Bridging<RustBuffer>::toJs(rt, callInvoker,
                                                             value);
 }
+jsi::Value NativeSlhDsaUniffi::
+    cpp_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_cose_sign1_create(
+        jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args,
+        size_t count) {
+  auto value = uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_cose_sign1_create();
+
+  return uniffi_jsi::Bridging<uint16_t>::toJs(rt, callInvoker, value);
+}
+jsi::Value NativeSlhDsaUniffi::
+    cpp_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_cose_sign1_verify(
+        jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args,
+        size_t count) {
+  auto value = uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_cose_sign1_verify();
+
+  return uniffi_jsi::Bridging<uint16_t>::toJs(rt, callInvoker, value);
+}
 jsi::Value
-NativeSlhDsaUniffi::cpp_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_verifying_key_len(
+NativeSlhDsaUniffi::cpp_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_csr_create(
     jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args,
     size_t count) {
-  RustCallStatus status =
-      uniffi::slh_dsa_uniffi::Bridging<RustCallStatus>::rustSuccess(rt);
-  auto value = uniffi_slh_dsa_uniffi_fn_func_slh_dsa_verifying_key_len(
-      uniffi::slh_dsa_uniffi::Bridging<RustBuffer>::fromJs(rt, callInvoker,
-                                                           args[0]),
-      &status);
-  uniffi::slh_dsa_uniffi::Bridging<RustCallStatus>::copyIntoJs(
-      rt, callInvoker, status, args[count - 1]);
+  auto value = uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_csr_create();
 
-  return uniffi_jsi::Bridging<uint64_t>::toJs(rt, callInvoker, value);
+  return uniffi_jsi::Bridging<uint16_t>::toJs(rt, callInvoker, value);
+}
+jsi::Value
+NativeSlhDsaUniffi::cpp_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_csr_verify(
+    jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args,
+    size_t count) {
+  auto value = uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_csr_verify();
+
+  return uniffi_jsi::Bridging<uint16_t>::toJs(rt, callInvoker, value);
+}
+jsi::Value NativeSlhDsaUniffi::
+    cpp_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_envelope_decode(
+        jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args,
+        size_t count) {
+  auto value = uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_envelope_decode();
+
+  return uniffi_jsi::Bridging<uint16_t>::toJs(rt, callInvoker, value);
+}
+jsi::Value NativeSlhDsaUniffi::
+    cpp_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_envelope_sign(
+        jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args,
+        size_t count) {
+  auto value = uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_envelope_sign();
+
+  return uniffi_jsi::Bridging<uint16_t>::toJs(rt, callInvoker, value);
+}
+jsi::Value NativeSlhDsaUniffi::
+    cpp_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_envelope_verify(
+        jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args,
+        size_t count) {
+  auto value = uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_envelope_verify();
+
+  return uniffi_jsi::Bridging<uint16_t>::toJs(rt, callInvoker, value);
+}
+jsi::Value
+NativeSlhDsaUniffi::cpp_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_fingerprint(
+    jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args,
+    size_t count) {
+  auto value = uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_fingerprint();
+
+  return uniffi_jsi::Bridging<uint16_t>::toJs(rt, callInvoker, value);
+}
+jsi::Value NativeSlhDsaUniffi::
+    cpp_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_key_file_len(
+        jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args,
+        size_t count) {
+  auto value = uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_key_file_len();
+
+  return uniffi_jsi::Bridging<uint16_t>::toJs(rt, callInvoker, value);
+}
+jsi::Value NativeSlhDsaUniffi::
+    cpp_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_keypair_from_seed(
+        jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args,
+        size_t count) {
+  auto value = uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_keypair_from_seed();
+
+  return uniffi_jsi::Bridging<uint16_t>::toJs(rt, callInvoker, value);
 }
 jsi::Value NativeSlhDsaUniffi::
     cpp_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_keypair_generate(
@@ -2159,6 +3733,49 @@ jsi::Value NativeSlhDsaUniffi::
 
   return uniffi_jsi::Bridging<uint16_t>::toJs(rt, callInvoker, value);
 }
+jsi::Value NativeSlhDsaUniffi::
+    cpp_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_parameter_set_from_name(
+        jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args,
+        size_t count) {
+  auto value =
+      uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_parameter_set_from_name();
+
+  return uniffi_jsi::Bridging<uint16_t>::toJs(rt, callInvoker, value);
+}
+jsi::Value NativeSlhDsaUniffi::
+    cpp_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_parameter_set_from_oid(
+        jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args,
+        size_t count) {
+  auto value =
+      uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_parameter_set_from_oid();
+
+  return uniffi_jsi::Bridging<uint16_t>::toJs(rt, callInvoker, value);
+}
+jsi::Value NativeSlhDsaUniffi::
+    cpp_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_parameter_sets(
+        jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args,
+        size_t count) {
+  auto value = uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_parameter_sets();
+
+  return uniffi_jsi::Bridging<uint16_t>::toJs(rt, callInvoker, value);
+}
+jsi::Value
+NativeSlhDsaUniffi::cpp_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_seed_len(
+    jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args,
+    size_t count) {
+  auto value = uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_seed_len();
+
+  return uniffi_jsi::Bridging<uint16_t>::toJs(rt, callInvoker, value);
+}
+jsi::Value NativeSlhDsaUniffi::
+    cpp_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_set_verify_after_sign(
+        jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args,
+        size_t count) {
+  auto value =
+      uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_set_verify_after_sign();
+
+  return uniffi_jsi::Bridging<uint16_t>::toJs(rt, callInvoker, value);
+}
 jsi::Value
 NativeSlhDsaUniffi::cpp_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_sign(
     jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args,
@@ -2167,6 +3784,22 @@ NativeSlhDsaUniffi::cpp_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_sign(
 
   return uniffi_jsi::Bridging<uint16_t>::toJs(rt, callInvoker, value);
 }
+jsi::Value
+NativeSlhDsaUniffi::cpp_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_sign_batch(
+    jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args,
+    size_t count) {
+  auto value = uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_sign_batch();
+
+  return uniffi_jsi::Bridging<uint16_t>::toJs(rt, callInvoker, value);
+}
+jsi::Value NativeSlhDsaUniffi::
+    cpp_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_sign_checked(
+        jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args,
+        size_t count) {
+  auto value = uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_sign_checked();
+
+  return uniffi_jsi::Bridging<uint16_t>::toJs(rt, callInvoker, value);
+}
 jsi::Value NativeSlhDsaUniffi::
     cpp_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_sign_deterministic(
         jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args,
@@ -2183,6 +3816,42 @@ jsi::Value NativeSlhDsaUniffi::
 
   return uniffi_jsi::Bridging<uint16_t>::toJs(rt, callInvoker, value);
 }
+jsi::Value NativeSlhDsaUniffi::
+    cpp_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_signing_key_from_cose_key(
+        jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args,
+        size_t count) {
+  auto value =
+      uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_signing_key_from_cose_key();
+
+  return uniffi_jsi::Bridging<uint16_t>::toJs(rt, callInvoker, value);
+}
+jsi::Value NativeSlhDsaUniffi::
+    cpp_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_signing_key_from_encrypted_pkcs8(
+        jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args,
+        size_t count) {
+  auto value =
+      uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_signing_key_from_encrypted_pkcs8();
+
+  return uniffi_jsi::Bridging<uint16_t>::toJs(rt, callInvoker, value);
+}
+jsi::Value NativeSlhDsaUniffi::
+    cpp_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_signing_key_from_key_file(
+        jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args,
+        size_t count) {
+  auto value =
+      uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_signing_key_from_key_file();
+
+  return uniffi_jsi::Bridging<uint16_t>::toJs(rt, callInvoker, value);
+}
+jsi::Value NativeSlhDsaUniffi::
+    cpp_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_signing_key_from_pkcs8(
+        jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args,
+        size_t count) {
+  auto value =
+      uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_signing_key_from_pkcs8();
+
+  return uniffi_jsi::Bridging<uint16_t>::toJs(rt, callInvoker, value);
+}
 jsi::Value NativeSlhDsaUniffi::
     cpp_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_signing_key_len(
         jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args,
@@ -2191,6 +3860,51 @@ jsi::Value NativeSlhDsaUniffi::
 
   return uniffi_jsi::Bridging<uint16_t>::toJs(rt, callInvoker, value);
 }
+jsi::Value NativeSlhDsaUniffi::
+    cpp_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_signing_key_to_cose_key(
+        jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args,
+        size_t count) {
+  auto value =
+      uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_signing_key_to_cose_key();
+
+  return uniffi_jsi::Bridging<uint16_t>::toJs(rt, callInvoker, value);
+}
+jsi::Value NativeSlhDsaUniffi::
+    cpp_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_signing_key_to_encrypted_pkcs8(
+        jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args,
+        size_t count) {
+  auto value =
+      uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_signing_key_to_encrypted_pkcs8();
+
+  return uniffi_jsi::Bridging<uint16_t>::toJs(rt, callInvoker, value);
+}
+jsi::Value NativeSlhDsaUniffi::
+    cpp_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_signing_key_to_key_file(
+        jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args,
+        size_t count) {
+  auto value =
+      uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_signing_key_to_key_file();
+
+  return uniffi_jsi::Bridging<uint16_t>::toJs(rt, callInvoker, value);
+}
+jsi::Value NativeSlhDsaUniffi::
+    cpp_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_signing_key_to_pkcs8(
+        jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args,
+        size_t count) {
+  auto value =
+      uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_signing_key_to_pkcs8();
+
+  return uniffi_jsi::Bridging<uint16_t>::toJs(rt, callInvoker, value);
+}
+jsi::Value NativeSlhDsaUniffi::
+    cpp_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_validate_signing_key(
+        jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args,
+        size_t count) {
+  auto value =
+      uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_validate_signing_key();
+
+  return uniffi_jsi::Bridging<uint16_t>::toJs(rt, callInvoker, value);
+}
 jsi::Value
 NativeSlhDsaUniffi::cpp_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_verify(
     jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args,
@@ -2199,6 +3913,24 @@ NativeSlhDsaUniffi::cpp_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_verify(
 
   return uniffi_jsi::Bridging<uint16_t>::toJs(rt, callInvoker, value);
 }
+jsi::Value NativeSlhDsaUniffi::
+    cpp_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_verify_after_sign_enabled(
+        jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args,
+        size_t count) {
+  auto value =
+      uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_verify_after_sign_enabled();
+
+  return uniffi_jsi::Bridging<uint16_t>::toJs(rt, callInvoker, value);
+}
+jsi::Value NativeSlhDsaUniffi::
+    cpp_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_verifying_key_from_cose_key(
+        jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args,
+        size_t count) {
+  auto value =
+      uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_verifying_key_from_cose_key();
+
+  return uniffi_jsi::Bridging<uint16_t>::toJs(rt, callInvoker, value);
+}
 jsi::Value NativeSlhDsaUniffi::
     cpp_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_verifying_key_from_signing_key(
         jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args,
@@ -2208,6 +3940,24 @@ jsi::Value NativeSlhDsaUniffi::
 
   return uniffi_jsi::Bridging<uint16_t>::toJs(rt, callInvoker, value);
 }
+jsi::Value NativeSlhDsaUniffi::
+    cpp_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_verifying_key_from_signing_key_validated(
+        jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args,
+        size_t count) {
+  auto value =
+      uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_verifying_key_from_signing_key_validated();
+
+  return uniffi_jsi::Bridging<uint16_t>::toJs(rt, callInvoker, value);
+}
+jsi::Value NativeSlhDsaUniffi::
+    cpp_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_verifying_key_from_spki(
+        jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args,
+        size_t count) {
+  auto value =
+      uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_verifying_key_from_spki();
+
+  return uniffi_jsi::Bridging<uint16_t>::toJs(rt, callInvoker, value);
+}
 jsi::Value NativeSlhDsaUniffi::
     cpp_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_verifying_key_len(
         jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args,
@@ -2216,6 +3966,24 @@ jsi::Value NativeSlhDsaUniffi::
 
   return uniffi_jsi::Bridging<uint16_t>::toJs(rt, callInvoker, value);
 }
+jsi::Value NativeSlhDsaUniffi::
+    cpp_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_verifying_key_to_cose_key(
+        jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args,
+        size_t count) {
+  auto value =
+      uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_verifying_key_to_cose_key();
+
+  return uniffi_jsi::Bridging<uint16_t>::toJs(rt, callInvoker, value);
+}
+jsi::Value NativeSlhDsaUniffi::
+    cpp_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_verifying_key_to_spki(
+        jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args,
+        size_t count) {
+  auto value =
+      uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_verifying_key_to_spki();
+
+  return uniffi_jsi::Bridging<uint16_t>::toJs(rt, callInvoker, value);
+}
 jsi::Value NativeSlhDsaUniffi::cpp_ffi_slh_dsa_uniffi_uniffi_contract_version(
     jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args,
     size_t count) {
@@ -28,62 +28,280 @@ protected:
   jsi::Value cpp_uniffi_internal_fn_func_ffi__arraybuffer_to_string(
       jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args,
       size_t count);
+  jsi::Value cpp_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_cose_sign1_create(
+      jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args,
+      size_t count);
+  jsi::Value cpp_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_cose_sign1_verify(
+      jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args,
+      size_t count);
+  jsi::Value cpp_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_csr_create(
+      jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args,
+      size_t count);
+  jsi::Value cpp_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_csr_verify(
+      jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args,
+      size_t count);
+  jsi::Value cpp_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_envelope_decode(
+      jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args,
+      size_t count);
+  jsi::Value cpp_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_envelope_sign(
+      jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args,
+      size_t count);
+  jsi::Value cpp_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_envelope_verify(
+      jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args,
+      size_t count);
+  jsi::Value cpp_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_fingerprint(
+      jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args,
+      size_t count);
+  jsi::Value cpp_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_key_file_len(
+      jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args,
+      size_t count);
+  jsi::Value cpp_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_keypair_from_seed(
+      jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args,
+      size_t count);
   jsi::Value cpp_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_keypair_generate(
       jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args,
       size_t count);
   jsi::Value cpp_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_parameter_name(
       jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args,
       size_t count);
+  jsi::Value cpp_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_parameter_set_from_name(
+      jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args,
+      size_t count);
+  jsi::Value cpp_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_parameter_set_from_oid(
+      jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args,
+      size_t count);
+  jsi::Value cpp_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_parameter_sets(
+      jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args,
+      size_t count);
+  jsi::Value cpp_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_seed_len(
+      jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args,
+      size_t count);
+  jsi::Value cpp_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_set_verify_after_sign(
+      jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args,
+      size_t count);
   jsi::Value cpp_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_sign(
       jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args,
       size_t count);
+  jsi::Value cpp_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_sign_batch(
+      jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args,
+      size_t count);
+  jsi::Value cpp_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_sign_checked(
+      jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args,
+      size_t count);
   jsi::Value cpp_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_sign_deterministic(
       jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args,
       size_t count);
   jsi::Value cpp_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_signature_len(
       jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args,
       size_t count);
+  jsi::Value
+  cpp_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_signing_key_from_cose_key(
+      jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args,
+      size_t count);
+  jsi::Value
+  cpp_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_signing_key_from_encrypted_pkcs8(
+      jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args,
+      size_t count);
+  jsi::Value
+  cpp_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_signing_key_from_key_file(
+      jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args,
+      size_t count);
+  jsi::Value cpp_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_signing_key_from_pkcs8(
+      jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args,
+      size_t count);
   jsi::Value cpp_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_signing_key_len(
       jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args,
       size_t count);
+  jsi::Value cpp_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_signing_key_to_cose_key(
+      jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args,
+      size_t count);
+  jsi::Value
+  cpp_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_signing_key_to_encrypted_pkcs8(
+      jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args,
+      size_t count);
+  jsi::Value cpp_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_signing_key_to_key_file(
+      jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args,
+      size_t count);
+  jsi::Value cpp_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_signing_key_to_pkcs8(
+      jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args,
+      size_t count);
+  jsi::Value cpp_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_validate_signing_key(
+      jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args,
+      size_t count);
   jsi::Value cpp_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_verify(
       jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args,
       size_t count);
   jsi::Value
+  cpp_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_verify_after_sign_enabled(
+      jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args,
+      size_t count);
+  jsi::Value
+  cpp_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_verifying_key_from_cose_key(
+      jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args,
+      size_t count);
+  jsi::Value
   cpp_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_verifying_key_from_signing_key(
       jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args,
       size_t count);
+  jsi::Value
+  cpp_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_verifying_key_from_signing_key_validated(
+      jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args,
+      size_t count);
+  jsi::Value cpp_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_verifying_key_from_spki(
+      jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args,
+      size_t count);
   jsi::Value cpp_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_verifying_key_len(
       jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args,
       size_t count);
+  jsi::Value
+  cpp_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_verifying_key_to_cose_key(
+      jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args,
+      size_t count);
+  jsi::Value cpp_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_verifying_key_to_spki(
+      jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args,
+      size_t count);
+  jsi::Value cpp_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_cose_sign1_create(
+      jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args,
+      size_t count);
+  jsi::Value cpp_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_cose_sign1_verify(
+      jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args,
+      size_t count);
+  jsi::Value cpp_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_csr_create(
+      jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args,
+      size_t count);
+  jsi::Value cpp_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_csr_verify(
+      jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args,
+      size_t count);
+  jsi::Value cpp_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_envelope_decode(
+      jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args,
+      size_t count);
+  jsi::Value cpp_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_envelope_sign(
+      jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args,
+      size_t count);
+  jsi::Value cpp_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_envelope_verify(
+      jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args,
+      size_t count);
+  jsi::Value cpp_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_fingerprint(
+      jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args,
+      size_t count);
+  jsi::Value cpp_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_key_file_len(
+      jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args,
+      size_t count);
+  jsi::Value cpp_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_keypair_from_seed(
+      jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args,
+      size_t count);
   jsi::Value cpp_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_keypair_generate(
       jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args,
       size_t count);
   jsi::Value cpp_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_parameter_name(
       jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args,
       size_t count);
+  jsi::Value
+  cpp_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_parameter_set_from_name(
+      jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args,
+      size_t count);
+  jsi::Value
+  cpp_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_parameter_set_from_oid(
+      jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args,
+      size_t count);
+  jsi::Value cpp_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_parameter_sets(
+      jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args,
+      size_t count);
+  jsi::Value cpp_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_seed_len(
+      jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args,
+      size_t count);
+  jsi::Value
+  cpp_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_set_verify_after_sign(
+      jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args,
+      size_t count);
   jsi::Value cpp_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_sign(
       jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args,
       size_t count);
+  jsi::Value cpp_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_sign_batch(
+      jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args,
+      size_t count);
+  jsi::Value cpp_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_sign_checked(
+      jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args,
+      size_t count);
   jsi::Value cpp_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_sign_deterministic(
       jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args,
       size_t count);
   jsi::Value cpp_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_signature_len(
       jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args,
       size_t count);
+  jsi::Value
+  cpp_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_signing_key_from_cose_key(
+      jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args,
+      size_t count);
+  jsi::Value
+  cpp_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_signing_key_from_encrypted_pkcs8(
+      jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args,
+      size_t count);
+  jsi::Value
+  cpp_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_signing_key_from_key_file(
+      jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args,
+      size_t count);
+  jsi::Value
+  cpp_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_signing_key_from_pkcs8(
+      jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args,
+      size_t count);
   jsi::Value cpp_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_signing_key_len(
       jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args,
       size_t count);
+  jsi::Value
+  cpp_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_signing_key_to_cose_key(
+      jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args,
+      size_t count);
+  jsi::Value
+  cpp_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_signing_key_to_encrypted_pkcs8(
+      jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args,
+      size_t count);
+  jsi::Value
+  cpp_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_signing_key_to_key_file(
+      jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args,
+      size_t count);
+  jsi::Value
+  cpp_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_signing_key_to_pkcs8(
+      jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args,
+      size_t count);
+  jsi::Value
+  cpp_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_validate_signing_key(
+      jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args,
+      size_t count);
   jsi::Value cpp_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_verify(
       jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args,
       size_t count);
   jsi::Value
+  cpp_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_verify_after_sign_enabled(
+      jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args,
+      size_t count);
+  jsi::Value
+  cpp_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_verifying_key_from_cose_key(
+      jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args,
+      size_t count);
+  jsi::Value
   cpp_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_verifying_key_from_signing_key(
       jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args,
       size_t count);
+  jsi::Value
+  cpp_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_verifying_key_from_signing_key_validated(
+      jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args,
+      size_t count);
+  jsi::Value
+  cpp_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_verifying_key_from_spki(
+      jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args,
+      size_t count);
   jsi::Value cpp_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_verifying_key_len(
       jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args,
       size_t count);
+  jsi::Value
+  cpp_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_verifying_key_to_cose_key(
+      jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args,
+      size_t count);
+  jsi::Value
+  cpp_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_verifying_key_to_spki(
+      jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args,
+      size_t count);
   jsi::Value cpp_ffi_slh_dsa_uniffi_uniffi_contract_version(
       jsi::Runtime &rt, const jsi::Value &thisVal, const jsi::Value *args,
       size_t count);
@@ -17,19 +17,19 @@ package uniffi.slh_dsa_uniffi
 // compile the Rust component. The easiest way to ensure this is to bundle the Kotlin
 // helpers directly inline like we're doing here.
 
-import com.sun.jna.Callback
-import com.sun.jna.IntegerType
 import com.sun.jna.Library
+import com.sun.jna.IntegerType
 import com.sun.jna.Native
 import com.sun.jna.Pointer
 import com.sun.jna.Structure
+import com.sun.jna.Callback
 import com.sun.jna.ptr.*
 import java.nio.ByteBuffer
 import java.nio.ByteOrder
 import java.nio.CharBuffer
 import java.nio.charset.CodingErrorAction
-import java.util.concurrent.ConcurrentHashMap
 import java.util.concurrent.atomic.AtomicLong
+import java.util.concurrent.ConcurrentHashMap
 
 // This is a helper for safely working with byte buffers returned from the Rust code.
 // A rust-owned buffer is represented by its capacity, its current length, and a
@@ -43,41 +43,29 @@ open class RustBuffer : Structure() {
     // Note: `capacity` and `len` are actually `ULong` values, but JVM only supports signed values.
     // When dealing with these fields, make sure to call `toULong()`.
     @JvmField var capacity: Long = 0
-
     @JvmField var len: Long = 0
-
     @JvmField var data: Pointer? = null
 
-    class ByValue :
-        RustBuffer(),
-        Structure.ByValue
+    class ByValue: RustBuffer(), Structure.ByValue
+    class ByReference: RustBuffer(), Structure.ByReference
 
-    class ByReference :
-        RustBuffer(),
-        Structure.ByReference
-
-    internal fun setValue(other: RustBuffer) {
+   internal fun setValue(other: RustBuffer) {
         capacity = other.capacity
         len = other.len
         data = other.data
     }
 
     companion object {
-        internal fun alloc(size: ULong = 0UL) =
-            uniffiRustCall { status ->
-                // Note: need to convert the size to a `Long` value to make this work with JVM.
-                UniffiLib.ffi_slh_dsa_uniffi_rustbuffer_alloc(size.toLong(), status)
-            }.also {
-                if (it.data == null) {
-                    throw RuntimeException("RustBuffer.alloc() returned null data pointer (size=$size)")
-                }
-            }
+        internal fun alloc(size: ULong = 0UL) = uniffiRustCall() { status ->
+            // Note: need to convert the size to a `Long` value to make this work with JVM.
+            UniffiLib.INSTANCE.ffi_slh_dsa_uniffi_rustbuffer_alloc(size.toLong(), status)
+        }.also {
+            if(it.data == null) {
+               throw RuntimeException("RustBuffer.alloc() returned null data pointer (size=${size})")
+           }
+        }
 
-        internal fun create(
-            capacity: ULong,
-            len: ULong,
-            data: Pointer?,
-        ): RustBuffer.ByValue {
+        internal fun create(capacity: ULong, len: ULong, data: Pointer?): RustBuffer.ByValue {
             var buf = RustBuffer.ByValue()
             buf.capacity = capacity.toLong()
             buf.len = len.toLong()
@@ -85,10 +73,9 @@ open class RustBuffer : Structure() {
             return buf
         }
 
-        internal fun free(buf: RustBuffer.ByValue) =
-            uniffiRustCall { status ->
-                UniffiLib.ffi_slh_dsa_uniffi_rustbuffer_free(buf, status)
-            }
+        internal fun free(buf: RustBuffer.ByValue) = uniffiRustCall() { status ->
+            UniffiLib.INSTANCE.ffi_slh_dsa_uniffi_rustbuffer_free(buf, status)
+        }
     }
 
     @Suppress("TooGenericExceptionThrown")
@@ -98,6 +85,40 @@ open class RustBuffer : Structure() {
         }
 }
 
+/**
+ * The equivalent of the `*mut RustBuffer` type.
+ * Required for callbacks taking in an out pointer.
+ *
+ * Size is the sum of all values in the struct.
+ *
+ * @suppress
+ */
+class RustBufferByReference : ByReference(16) {
+    /**
+     * Set the pointed-to `RustBuffer` to the given value.
+     */
+    fun setValue(value: RustBuffer.ByValue) {
+        // NOTE: The offsets are as they are in the C-like struct.
+        val pointer = getPointer()
+        pointer.setLong(0, value.capacity)
+        pointer.setLong(8, value.len)
+        pointer.setPointer(16, value.data)
+    }
+
+    /**
+     * Get a `RustBuffer.ByValue` from this reference.
+     */
+    fun getValue(): RustBuffer.ByValue {
+        val pointer = getPointer()
+        val value = RustBuffer.ByValue()
+        value.writeField("capacity", pointer.getLong(0))
+        value.writeField("len", pointer.getLong(8))
+        value.writeField("data", pointer.getLong(16))
+
+        return value
+    }
+}
+
 // This is a helper for safely passing byte references into the rust code.
 // It's not actually used at the moment, because there aren't many things that you
 // can take a direct pointer to in the JVM, and if we're going to copy something
@@ -107,14 +128,10 @@ open class RustBuffer : Structure() {
 @Structure.FieldOrder("len", "data")
 internal open class ForeignBytes : Structure() {
     @JvmField var len: Int = 0
-
     @JvmField var data: Pointer? = null
 
-    class ByValue :
-        ForeignBytes(),
-        Structure.ByValue
+    class ByValue : ForeignBytes(), Structure.ByValue
 }
-
 /**
  * The FfiConverter interface handles converter types to and from the FFI
  *
@@ -144,10 +161,7 @@ public interface FfiConverter<KotlinType, FfiType> {
     fun allocationSize(value: KotlinType): ULong
 
     // Write a Kotlin type to a `ByteBuffer`
-    fun write(
-        value: KotlinType,
-        buf: ByteBuffer,
-    )
+    fun write(value: KotlinType, buf: ByteBuffer)
 
     // Lower a value into a `RustBuffer`
     //
@@ -158,10 +172,9 @@ public interface FfiConverter<KotlinType, FfiType> {
     fun lowerIntoRustBuffer(value: KotlinType): RustBuffer.ByValue {
         val rbuf = RustBuffer.alloc(allocationSize(value))
         try {
-            val bbuf =
-                rbuf.data!!.getByteBuffer(0, rbuf.capacity).also {
-                    it.order(ByteOrder.BIG_ENDIAN)
-                }
+            val bbuf = rbuf.data!!.getByteBuffer(0, rbuf.capacity).also {
+                it.order(ByteOrder.BIG_ENDIAN)
+            }
             write(value, bbuf)
             rbuf.writeField("len", bbuf.position().toLong())
             return rbuf
@@ -178,11 +191,11 @@ public interface FfiConverter<KotlinType, FfiType> {
     fun liftFromRustBuffer(rbuf: RustBuffer.ByValue): KotlinType {
         val byteBuf = rbuf.asByteBuffer()!!
         try {
-            val item = read(byteBuf)
-            if (byteBuf.hasRemaining()) {
-                throw RuntimeException("junk remaining in buffer after lifting, something is very wrong!!")
-            }
-            return item
+           val item = read(byteBuf)
+           if (byteBuf.hasRemaining()) {
+               throw RuntimeException("junk remaining in buffer after lifting, something is very wrong!!")
+           }
+           return item
         } finally {
             RustBuffer.free(rbuf)
         }
@@ -194,9 +207,8 @@ public interface FfiConverter<KotlinType, FfiType> {
  *
  * @suppress
  */
-public interface FfiConverterRustBuffer<KotlinType> : FfiConverter<KotlinType, RustBuffer.ByValue> {
+public interface FfiConverterRustBuffer<KotlinType>: FfiConverter<KotlinType, RustBuffer.ByValue> {
     override fun lift(value: RustBuffer.ByValue) = liftFromRustBuffer(value)
-
     override fun lower(value: KotlinType) = lowerIntoRustBuffer(value)
 }
 // A handful of classes and functions to support the generated data structures.
@@ -209,24 +221,24 @@ internal const val UNIFFI_CALL_UNEXPECTED_ERROR = 2.toByte()
 @Structure.FieldOrder("code", "error_buf")
 internal open class UniffiRustCallStatus : Structure() {
     @JvmField var code: Byte = 0
-
     @JvmField var error_buf: RustBuffer.ByValue = RustBuffer.ByValue()
 
-    class ByValue :
-        UniffiRustCallStatus(),
-        Structure.ByValue
+    class ByValue: UniffiRustCallStatus(), Structure.ByValue
 
-    fun isSuccess(): Boolean = code == UNIFFI_CALL_SUCCESS
+    fun isSuccess(): Boolean {
+        return code == UNIFFI_CALL_SUCCESS
+    }
 
-    fun isError(): Boolean = code == UNIFFI_CALL_ERROR
+    fun isError(): Boolean {
+        return code == UNIFFI_CALL_ERROR
+    }
 
-    fun isPanic(): Boolean = code == UNIFFI_CALL_UNEXPECTED_ERROR
+    fun isPanic(): Boolean {
+        return code == UNIFFI_CALL_UNEXPECTED_ERROR
+    }
 
     companion object {
-        fun create(
-            code: Byte,
-            errorBuf: RustBuffer.ByValue,
-        ): UniffiRustCallStatus.ByValue {
+        fun create(code: Byte, errorBuf: RustBuffer.ByValue): UniffiRustCallStatus.ByValue {
             val callStatus = UniffiRustCallStatus.ByValue()
             callStatus.code = code
             callStatus.error_buf = errorBuf
@@ -235,9 +247,7 @@ internal open class UniffiRustCallStatus : Structure() {
     }
 }
 
-class InternalException(
-    message: String,
-) : kotlin.Exception(message)
+class InternalException(message: String) : kotlin.Exception(message)
 
 /**
  * Each top-level error class has a companion object that can lift the error from the call status's rust buffer
@@ -245,7 +255,7 @@ class InternalException(
  * @suppress
  */
 interface UniffiRustCallStatusErrorHandler<E> {
-    fun lift(error_buf: RustBuffer.ByValue): E
+    fun lift(error_buf: RustBuffer.ByValue): E;
 }
 
 // Helpers for calling Rust
@@ -253,10 +263,7 @@ interface UniffiRustCallStatusErrorHandler<E> {
 // synchronize itself
 
 // Call a rust function that returns a Result<>.  Pass in the Error class companion that corresponds to the Err
-private inline fun <U, E : kotlin.Exception> uniffiRustCallWithError(
-    errorHandler: UniffiRustCallStatusErrorHandler<E>,
-    callback: (UniffiRustCallStatus) -> U,
-): U {
+private inline fun <U, E: kotlin.Exception> uniffiRustCallWithError(errorHandler: UniffiRustCallStatusErrorHandler<E>, callback: (UniffiRustCallStatus) -> U): U {
     var status = UniffiRustCallStatus()
     val return_value = callback(status)
     uniffiCheckCallStatus(errorHandler, status)
@@ -264,10 +271,7 @@ private inline fun <U, E : kotlin.Exception> uniffiRustCallWithError(
 }
 
 // Check UniffiRustCallStatus and throw an error if the call wasn't successful
-private fun <E : kotlin.Exception> uniffiCheckCallStatus(
-    errorHandler: UniffiRustCallStatusErrorHandler<E>,
-    status: UniffiRustCallStatus,
-) {
+private fun<E: kotlin.Exception> uniffiCheckCallStatus(errorHandler: UniffiRustCallStatusErrorHandler<E>, status: UniffiRustCallStatus) {
     if (status.isSuccess()) {
         return
     } else if (status.isError()) {
@@ -291,7 +295,7 @@ private fun <E : kotlin.Exception> uniffiCheckCallStatus(
  *
  * @suppress
  */
-object UniffiNullRustCallStatusErrorHandler : UniffiRustCallStatusErrorHandler<InternalException> {
+object UniffiNullRustCallStatusErrorHandler: UniffiRustCallStatusErrorHandler<InternalException> {
     override fun lift(error_buf: RustBuffer.ByValue): InternalException {
         RustBuffer.free(error_buf)
         return InternalException("Unexpected CALL_ERROR")
@@ -299,31 +303,32 @@ object UniffiNullRustCallStatusErrorHandler : UniffiRustCallStatusErrorHandler<I
 }
 
 // Call a rust function that returns a plain value
-private inline fun <U> uniffiRustCall(callback: (UniffiRustCallStatus) -> U): U =
-    uniffiRustCallWithError(UniffiNullRustCallStatusErrorHandler, callback)
+private inline fun <U> uniffiRustCall(callback: (UniffiRustCallStatus) -> U): U {
+    return uniffiRustCallWithError(UniffiNullRustCallStatusErrorHandler, callback)
+}
 
-internal inline fun <T> uniffiTraitInterfaceCall(
+internal inline fun<T> uniffiTraitInterfaceCall(
     callStatus: UniffiRustCallStatus,
     makeCall: () -> T,
     writeReturn: (T) -> Unit,
 ) {
     try {
         writeReturn(makeCall())
-    } catch (e: kotlin.Exception) {
+    } catch(e: kotlin.Exception) {
         callStatus.code = UNIFFI_CALL_UNEXPECTED_ERROR
         callStatus.error_buf = FfiConverterString.lower(e.toString())
     }
 }
 
-internal inline fun <T, reified E : Throwable> uniffiTraitInterfaceCallWithError(
+internal inline fun<T, reified E: Throwable> uniffiTraitInterfaceCallWithError(
     callStatus: UniffiRustCallStatus,
     makeCall: () -> T,
     writeReturn: (T) -> Unit,
-    lowerError: (E) -> RustBuffer.ByValue,
+    lowerError: (E) -> RustBuffer.ByValue
 ) {
     try {
         writeReturn(makeCall())
-    } catch (e: kotlin.Exception) {
+    } catch(e: kotlin.Exception) {
         if (e is E) {
             callStatus.code = UNIFFI_CALL_ERROR
             callStatus.error_buf = lowerError(e)
@@ -333,44 +338,32 @@ internal inline fun <T, reified E : Throwable> uniffiTraitInterfaceCallWithError
         }
     }
 }
-
-// Initial value and increment amount for handles.
-// These ensure that Kotlin-generated handles always have the lowest bit set
-private const val UNIFFI_HANDLEMAP_INITIAL = 1.toLong()
-private const val UNIFFI_HANDLEMAP_DELTA = 2.toLong()
-
 // Map handles to objects
 //
 // This is used pass an opaque 64-bit handle representing a foreign object to the Rust code.
-internal class UniffiHandleMap<T : Any> {
+internal class UniffiHandleMap<T: Any> {
     private val map = ConcurrentHashMap<Long, T>()
-
-    // Start
-    private val counter =
-        java.util.concurrent.atomic
-            .AtomicLong(UNIFFI_HANDLEMAP_INITIAL)
+    private val counter = java.util.concurrent.atomic.AtomicLong(0)
 
     val size: Int
         get() = map.size
 
     // Insert a new object into the handle map and get a handle for it
     fun insert(obj: T): Long {
-        val handle = counter.getAndAdd(UNIFFI_HANDLEMAP_DELTA)
+        val handle = counter.getAndAdd(1)
         map.put(handle, obj)
         return handle
     }
 
-    // Clone a handle, creating a new one
-    fun clone(handle: Long): Long {
-        val obj = map.get(handle) ?: throw InternalException("UniffiHandleMap.clone: Invalid handle")
-        return insert(obj)
-    }
-
     // Get an object from the handle map
-    fun get(handle: Long): T = map.get(handle) ?: throw InternalException("UniffiHandleMap.get: Invalid handle")
+    fun get(handle: Long): T {
+        return map.get(handle) ?: throw InternalException("UniffiHandleMap.get: Invalid handle")
+    }
 
     // Remove an entry from the handlemap and get the Kotlin object back
-    fun remove(handle: Long): T = map.remove(handle) ?: throw InternalException("UniffiHandleMap: Invalid handle")
+    fun remove(handle: Long): T {
+        return map.remove(handle) ?: throw InternalException("UniffiHandleMap: Invalid handle")
+    }
 }
 
 // Contains loading, initialization code,
@@ -384,690 +377,909 @@ private fun findLibraryName(componentName: String): String {
     return "slh_dsa_uniffi"
 }
 
+private inline fun <reified Lib : Library> loadIndirect(
+    componentName: String
+): Lib {
+    return Native.load<Lib>(findLibraryName(componentName), Lib::class.java)
+}
+
 // Define FFI callback types
 internal interface UniffiRustFutureContinuationCallback : com.sun.jna.Callback {
-    fun callback(
-        `data`: Long,
-        `pollResult`: Byte,
-    )
+    fun callback(`data`: Long,`pollResult`: Byte,)
 }
-
-internal interface UniffiForeignFutureDroppedCallback : com.sun.jna.Callback {
-    fun callback(`handle`: Long)
+internal interface UniffiForeignFutureFree : com.sun.jna.Callback {
+    fun callback(`handle`: Long,)
 }
-
 internal interface UniffiCallbackInterfaceFree : com.sun.jna.Callback {
-    fun callback(`handle`: Long)
-}
-
-internal interface UniffiCallbackInterfaceClone : com.sun.jna.Callback {
-    fun callback(`handle`: Long): Long
+    fun callback(`handle`: Long,)
 }
-
 @Structure.FieldOrder("handle", "free")
-internal open class UniffiForeignFutureDroppedCallbackStruct(
+internal open class UniffiForeignFuture(
     @JvmField internal var `handle`: Long = 0.toLong(),
-    @JvmField internal var `free`: UniffiForeignFutureDroppedCallback? = null,
+    @JvmField internal var `free`: UniffiForeignFutureFree? = null,
 ) : Structure() {
     class UniffiByValue(
         `handle`: Long = 0.toLong(),
-        `free`: UniffiForeignFutureDroppedCallback? = null,
-    ) : UniffiForeignFutureDroppedCallbackStruct(`handle`, `free`),
-        Structure.ByValue
+        `free`: UniffiForeignFutureFree? = null,
+    ): UniffiForeignFuture(`handle`,`free`,), Structure.ByValue
 
-    internal fun uniffiSetValue(other: UniffiForeignFutureDroppedCallbackStruct) {
+   internal fun uniffiSetValue(other: UniffiForeignFuture) {
         `handle` = other.`handle`
         `free` = other.`free`
     }
-}
 
+}
 @Structure.FieldOrder("returnValue", "callStatus")
-internal open class UniffiForeignFutureResultU8(
+internal open class UniffiForeignFutureStructU8(
     @JvmField internal var `returnValue`: Byte = 0.toByte(),
     @JvmField internal var `callStatus`: UniffiRustCallStatus.ByValue = UniffiRustCallStatus.ByValue(),
 ) : Structure() {
     class UniffiByValue(
         `returnValue`: Byte = 0.toByte(),
         `callStatus`: UniffiRustCallStatus.ByValue = UniffiRustCallStatus.ByValue(),
-    ) : UniffiForeignFutureResultU8(`returnValue`, `callStatus`),
-        Structure.ByValue
+    ): UniffiForeignFutureStructU8(`returnValue`,`callStatus`,), Structure.ByValue
 
-    internal fun uniffiSetValue(other: UniffiForeignFutureResultU8) {
+   internal fun uniffiSetValue(other: UniffiForeignFutureStructU8) {
         `returnValue` = other.`returnValue`
         `callStatus` = other.`callStatus`
     }
-}
 
+}
 internal interface UniffiForeignFutureCompleteU8 : com.sun.jna.Callback {
-    fun callback(
-        `callbackData`: Long,
-        `result`: UniffiForeignFutureResultU8.UniffiByValue,
-    )
+    fun callback(`callbackData`: Long,`result`: UniffiForeignFutureStructU8.UniffiByValue,)
 }
-
 @Structure.FieldOrder("returnValue", "callStatus")
-internal open class UniffiForeignFutureResultI8(
+internal open class UniffiForeignFutureStructI8(
     @JvmField internal var `returnValue`: Byte = 0.toByte(),
     @JvmField internal var `callStatus`: UniffiRustCallStatus.ByValue = UniffiRustCallStatus.ByValue(),
 ) : Structure() {
     class UniffiByValue(
         `returnValue`: Byte = 0.toByte(),
         `callStatus`: UniffiRustCallStatus.ByValue = UniffiRustCallStatus.ByValue(),
-    ) : UniffiForeignFutureResultI8(`returnValue`, `callStatus`),
-        Structure.ByValue
+    ): UniffiForeignFutureStructI8(`returnValue`,`callStatus`,), Structure.ByValue
 
-    internal fun uniffiSetValue(other: UniffiForeignFutureResultI8) {
+   internal fun uniffiSetValue(other: UniffiForeignFutureStructI8) {
         `returnValue` = other.`returnValue`
         `callStatus` = other.`callStatus`
     }
-}
 
+}
 internal interface UniffiForeignFutureCompleteI8 : com.sun.jna.Callback {
-    fun callback(
-        `callbackData`: Long,
-        `result`: UniffiForeignFutureResultI8.UniffiByValue,
-    )
+    fun callback(`callbackData`: Long,`result`: UniffiForeignFutureStructI8.UniffiByValue,)
 }
-
 @Structure.FieldOrder("returnValue", "callStatus")
-internal open class UniffiForeignFutureResultU16(
+internal open class UniffiForeignFutureStructU16(
     @JvmField internal var `returnValue`: Short = 0.toShort(),
     @JvmField internal var `callStatus`: UniffiRustCallStatus.ByValue = UniffiRustCallStatus.ByValue(),
 ) : Structure() {
     class UniffiByValue(
         `returnValue`: Short = 0.toShort(),
         `callStatus`: UniffiRustCallStatus.ByValue = UniffiRustCallStatus.ByValue(),
-    ) : UniffiForeignFutureResultU16(`returnValue`, `callStatus`),
-        Structure.ByValue
+    ): UniffiForeignFutureStructU16(`returnValue`,`callStatus`,), Structure.ByValue
 
-    internal fun uniffiSetValue(other: UniffiForeignFutureResultU16) {
+   internal fun uniffiSetValue(other: UniffiForeignFutureStructU16) {
         `returnValue` = other.`returnValue`
         `callStatus` = other.`callStatus`
     }
-}
 
+}
 internal interface UniffiForeignFutureCompleteU16 : com.sun.jna.Callback {
-    fun callback(
-        `callbackData`: Long,
-        `result`: UniffiForeignFutureResultU16.UniffiByValue,
-    )
+    fun callback(`callbackData`: Long,`result`: UniffiForeignFutureStructU16.UniffiByValue,)
 }
-
 @Structure.FieldOrder("returnValue", "callStatus")
-internal open class UniffiForeignFutureResultI16(
+internal open class UniffiForeignFutureStructI16(
     @JvmField internal var `returnValue`: Short = 0.toShort(),
     @JvmField internal var `callStatus`: UniffiRustCallStatus.ByValue = UniffiRustCallStatus.ByValue(),
 ) : Structure() {
     class UniffiByValue(
         `returnValue`: Short = 0.toShort(),
         `callStatus`: UniffiRustCallStatus.ByValue = UniffiRustCallStatus.ByValue(),
-    ) : UniffiForeignFutureResultI16(`returnValue`, `callStatus`),
-        Structure.ByValue
+    ): UniffiForeignFutureStructI16(`returnValue`,`callStatus`,), Structure.ByValue
 
-    internal fun uniffiSetValue(other: UniffiForeignFutureResultI16) {
+   internal fun uniffiSetValue(other: UniffiForeignFutureStructI16) {
         `returnValue` = other.`returnValue`
         `callStatus` = other.`callStatus`
     }
-}
 
+}
 internal interface UniffiForeignFutureCompleteI16 : com.sun.jna.Callback {
-    fun callback(
-        `callbackData`: Long,
-        `result`: UniffiForeignFutureResultI16.UniffiByValue,
-    )
+    fun callback(`callbackData`: Long,`result`: UniffiForeignFutureStructI16.UniffiByValue,)
 }
-
 @Structure.FieldOrder("returnValue", "callStatus")
-internal open class UniffiForeignFutureResultU32(
+internal open class UniffiForeignFutureStructU32(
     @JvmField internal var `returnValue`: Int = 0,
     @JvmField internal var `callStatus`: UniffiRustCallStatus.ByValue = UniffiRustCallStatus.ByValue(),
 ) : Structure() {
     class UniffiByValue(
         `returnValue`: Int = 0,
         `callStatus`: UniffiRustCallStatus.ByValue = UniffiRustCallStatus.ByValue(),
-    ) : UniffiForeignFutureResultU32(`returnValue`, `callStatus`),
-        Structure.ByValue
+    ): UniffiForeignFutureStructU32(`returnValue`,`callStatus`,), Structure.ByValue
 
-    internal fun uniffiSetValue(other: UniffiForeignFutureResultU32) {
+   internal fun uniffiSetValue(other: UniffiForeignFutureStructU32) {
         `returnValue` = other.`returnValue`
         `callStatus` = other.`callStatus`
     }
-}
 
+}
 internal interface UniffiForeignFutureCompleteU32 : com.sun.jna.Callback {
-    fun callback(
-        `callbackData`: Long,
-        `result`: UniffiForeignFutureResultU32.UniffiByValue,
-    )
+    fun callback(`callbackData`: Long,`result`: UniffiForeignFutureStructU32.UniffiByValue,)
 }
-
 @Structure.FieldOrder("returnValue", "callStatus")
-internal open class UniffiForeignFutureResultI32(
+internal open class UniffiForeignFutureStructI32(
     @JvmField internal var `returnValue`: Int = 0,
     @JvmField internal var `callStatus`: UniffiRustCallStatus.ByValue = UniffiRustCallStatus.ByValue(),
 ) : Structure() {
     class UniffiByValue(
         `returnValue`: Int = 0,
         `callStatus`: UniffiRustCallStatus.ByValue = UniffiRustCallStatus.ByValue(),
-    ) : UniffiForeignFutureResultI32(`returnValue`, `callStatus`),
-        Structure.ByValue
+    ): UniffiForeignFutureStructI32(`returnValue`,`callStatus`,), Structure.ByValue
 
-    internal fun uniffiSetValue(other: UniffiForeignFutureResultI32) {
+   internal fun uniffiSetValue(other: UniffiForeignFutureStructI32) {
         `returnValue` = other.`returnValue`
         `callStatus` = other.`callStatus`
     }
-}
 
+}
 internal interface UniffiForeignFutureCompleteI32 : com.sun.jna.Callback {
-    fun callback(
-        `callbackData`: Long,
-        `result`: UniffiForeignFutureResultI32.UniffiByValue,
-    )
+    fun callback(`callbackData`: Long,`result`: UniffiForeignFutureStructI32.UniffiByValue,)
 }
-
 @Structure.FieldOrder("returnValue", "callStatus")
-internal open class UniffiForeignFutureResultU64(
+internal open class UniffiForeignFutureStructU64(
     @JvmField internal var `returnValue`: Long = 0.toLong(),
     @JvmField internal var `callStatus`: UniffiRustCallStatus.ByValue = UniffiRustCallStatus.ByValue(),
 ) : Structure() {
     class UniffiByValue(
         `returnValue`: Long = 0.toLong(),
         `callStatus`: UniffiRustCallStatus.ByValue = UniffiRustCallStatus.ByValue(),
-    ) : UniffiForeignFutureResultU64(`returnValue`, `callStatus`),
-        Structure.ByValue
+    ): UniffiForeignFutureStructU64(`returnValue`,`callStatus`,), Structure.ByValue
 
-    internal fun uniffiSetValue(other: UniffiForeignFutureResultU64) {
+   internal fun uniffiSetValue(other: UniffiForeignFutureStructU64) {
         `returnValue` = other.`returnValue`
         `callStatus` = other.`callStatus`
     }
-}
 
+}
 internal interface UniffiForeignFutureCompleteU64 : com.sun.jna.Callback {
-    fun callback(
-        `callbackData`: Long,
-        `result`: UniffiForeignFutureResultU64.UniffiByValue,
-    )
+    fun callback(`callbackData`: Long,`result`: UniffiForeignFutureStructU64.UniffiByValue,)
 }
-
 @Structure.FieldOrder("returnValue", "callStatus")
-internal open class UniffiForeignFutureResultI64(
+internal open class UniffiForeignFutureStructI64(
     @JvmField internal var `returnValue`: Long = 0.toLong(),
     @JvmField internal var `callStatus`: UniffiRustCallStatus.ByValue = UniffiRustCallStatus.ByValue(),
 ) : Structure() {
     class UniffiByValue(
         `returnValue`: Long = 0.toLong(),
         `callStatus`: UniffiRustCallStatus.ByValue = UniffiRustCallStatus.ByValue(),
-    ) : UniffiForeignFutureResultI64(`returnValue`, `callStatus`),
-        Structure.ByValue
+    ): UniffiForeignFutureStructI64(`returnValue`,`callStatus`,), Structure.ByValue
 
-    internal fun uniffiSetValue(other: UniffiForeignFutureResultI64) {
+   internal fun uniffiSetValue(other: UniffiForeignFutureStructI64) {
         `returnValue` = other.`returnValue`
         `callStatus` = other.`callStatus`
     }
-}
 
+}
 internal interface UniffiForeignFutureCompleteI64 : com.sun.jna.Callback {
-    fun callback(
-        `callbackData`: Long,
-        `result`: UniffiForeignFutureResultI64.UniffiByValue,
-    )
+    fun callback(`callbackData`: Long,`result`: UniffiForeignFutureStructI64.UniffiByValue,)
 }
-
 @Structure.FieldOrder("returnValue", "callStatus")
-internal open class UniffiForeignFutureResultF32(
+internal open class UniffiForeignFutureStructF32(
     @JvmField internal var `returnValue`: Float = 0.0f,
     @JvmField internal var `callStatus`: UniffiRustCallStatus.ByValue = UniffiRustCallStatus.ByValue(),
 ) : Structure() {
     class UniffiByValue(
         `returnValue`: Float = 0.0f,
         `callStatus`: UniffiRustCallStatus.ByValue = UniffiRustCallStatus.ByValue(),
-    ) : UniffiForeignFutureResultF32(`returnValue`, `callStatus`),
-        Structure.ByValue
+    ): UniffiForeignFutureStructF32(`returnValue`,`callStatus`,), Structure.ByValue
 
-    internal fun uniffiSetValue(other: UniffiForeignFutureResultF32) {
+   internal fun uniffiSetValue(other: UniffiForeignFutureStructF32) {
         `returnValue` = other.`returnValue`
         `callStatus` = other.`callStatus`
     }
-}
 
+}
 internal interface UniffiForeignFutureCompleteF32 : com.sun.jna.Callback {
-    fun callback(
-        `callbackData`: Long,
-        `result`: UniffiForeignFutureResultF32.UniffiByValue,
-    )
+    fun callback(`callbackData`: Long,`result`: UniffiForeignFutureStructF32.UniffiByValue,)
 }
-
 @Structure.FieldOrder("returnValue", "callStatus")
-internal open class UniffiForeignFutureResultF64(
+internal open class UniffiForeignFutureStructF64(
     @JvmField internal var `returnValue`: Double = 0.0,
     @JvmField internal var `callStatus`: UniffiRustCallStatus.ByValue = UniffiRustCallStatus.ByValue(),
 ) : Structure() {
     class UniffiByValue(
         `returnValue`: Double = 0.0,
         `callStatus`: UniffiRustCallStatus.ByValue = UniffiRustCallStatus.ByValue(),
-    ) : UniffiForeignFutureResultF64(`returnValue`, `callStatus`),
-        Structure.ByValue
+    ): UniffiForeignFutureStructF64(`returnValue`,`callStatus`,), Structure.ByValue
 
-    internal fun uniffiSetValue(other: UniffiForeignFutureResultF64) {
+   internal fun uniffiSetValue(other: UniffiForeignFutureStructF64) {
         `returnValue` = other.`returnValue`
         `callStatus` = other.`callStatus`
     }
-}
 
+}
 internal interface UniffiForeignFutureCompleteF64 : com.sun.jna.Callback {
-    fun callback(
-        `callbackData`: Long,
-        `result`: UniffiForeignFutureResultF64.UniffiByValue,
-    )
+    fun callback(`callbackData`: Long,`result`: UniffiForeignFutureStructF64.UniffiByValue,)
 }
+@Structure.FieldOrder("returnValue", "callStatus")
+internal open class UniffiForeignFutureStructPointer(
+    @JvmField internal var `returnValue`: Pointer = Pointer.NULL,
+    @JvmField internal var `callStatus`: UniffiRustCallStatus.ByValue = UniffiRustCallStatus.ByValue(),
+) : Structure() {
+    class UniffiByValue(
+        `returnValue`: Pointer = Pointer.NULL,
+        `callStatus`: UniffiRustCallStatus.ByValue = UniffiRustCallStatus.ByValue(),
+    ): UniffiForeignFutureStructPointer(`returnValue`,`callStatus`,), Structure.ByValue
+
+   internal fun uniffiSetValue(other: UniffiForeignFutureStructPointer) {
+        `returnValue` = other.`returnValue`
+        `callStatus` = other.`callStatus`
+    }
 
+}
+internal interface UniffiForeignFutureCompletePointer : com.sun.jna.Callback {
+    fun callback(`callbackData`: Long,`result`: UniffiForeignFutureStructPointer.UniffiByValue,)
+}
 @Structure.FieldOrder("returnValue", "callStatus")
-internal open class UniffiForeignFutureResultRustBuffer(
+internal open class UniffiForeignFutureStructRustBuffer(
     @JvmField internal var `returnValue`: RustBuffer.ByValue = RustBuffer.ByValue(),
     @JvmField internal var `callStatus`: UniffiRustCallStatus.ByValue = UniffiRustCallStatus.ByValue(),
 ) : Structure() {
     class UniffiByValue(
         `returnValue`: RustBuffer.ByValue = RustBuffer.ByValue(),
         `callStatus`: UniffiRustCallStatus.ByValue = UniffiRustCallStatus.ByValue(),
-    ) : UniffiForeignFutureResultRustBuffer(`returnValue`, `callStatus`),
-        Structure.ByValue
+    ): UniffiForeignFutureStructRustBuffer(`returnValue`,`callStatus`,), Structure.ByValue
 
-    internal fun uniffiSetValue(other: UniffiForeignFutureResultRustBuffer) {
+   internal fun uniffiSetValue(other: UniffiForeignFutureStructRustBuffer) {
         `returnValue` = other.`returnValue`
         `callStatus` = other.`callStatus`
     }
-}
 
+}
 internal interface UniffiForeignFutureCompleteRustBuffer : com.sun.jna.Callback {
-    fun callback(
-        `callbackData`: Long,
-        `result`: UniffiForeignFutureResultRustBuffer.UniffiByValue,
-    )
+    fun callback(`callbackData`: Long,`result`: UniffiForeignFutureStructRustBuffer.UniffiByValue,)
 }
-
 @Structure.FieldOrder("callStatus")
-internal open class UniffiForeignFutureResultVoid(
+internal open class UniffiForeignFutureStructVoid(
     @JvmField internal var `callStatus`: UniffiRustCallStatus.ByValue = UniffiRustCallStatus.ByValue(),
 ) : Structure() {
     class UniffiByValue(
         `callStatus`: UniffiRustCallStatus.ByValue = UniffiRustCallStatus.ByValue(),
-    ) : UniffiForeignFutureResultVoid(`callStatus`),
-        Structure.ByValue
+    ): UniffiForeignFutureStructVoid(`callStatus`,), Structure.ByValue
 
-    internal fun uniffiSetValue(other: UniffiForeignFutureResultVoid) {
+   internal fun uniffiSetValue(other: UniffiForeignFutureStructVoid) {
         `callStatus` = other.`callStatus`
     }
-}
 
+}
 internal interface UniffiForeignFutureCompleteVoid : com.sun.jna.Callback {
-    fun callback(
-        `callbackData`: Long,
-        `result`: UniffiForeignFutureResultVoid.UniffiByValue,
-    )
+    fun callback(`callbackData`: Long,`result`: UniffiForeignFutureStructVoid.UniffiByValue,)
 }
 
-// A JNA Library to expose the extern-C FFI definitions.
-// This is an implementation detail which will be called internally by the public API.
 
-// For large crates we prevent `MethodTooLargeException` (see #2340)
-// N.B. the name of the extension is very misleading, since it is
-// rather `InterfaceTooLargeException`, caused by too many methods
-// in the interface for large crates.
-//
-// By splitting the otherwise huge interface into two parts
-// * UniffiLib (this)
-// * IntegrityCheckingUniffiLib
-// And all checksum methods are put into `IntegrityCheckingUniffiLib`
-// we allow for ~2x as many methods in the UniffiLib interface.
-//
-// Note: above all written when we used JNA's `loadIndirect` etc.
-// We now use JNA's "direct mapping" - unclear if same considerations apply exactly.
-internal object IntegrityCheckingUniffiLib {
-    init {
-        Native.register(IntegrityCheckingUniffiLib::class.java, findLibraryName(componentName = "slh_dsa_uniffi"))
-        uniffiCheckContractApiVersion(this)
-        uniffiCheckApiChecksums(this)
-    }
 
-    external fun uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_keypair_generate(): Short
 
-    external fun uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_parameter_name(): Short
 
-    external fun uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_sign(): Short
 
-    external fun uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_sign_deterministic(): Short
-
-    external fun uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_signature_len(): Short
 
-    external fun uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_signing_key_len(): Short
 
-    external fun uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_verify(): Short
 
-    external fun uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_verifying_key_from_signing_key(): Short
 
-    external fun uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_verifying_key_len(): Short
 
-    external fun ffi_slh_dsa_uniffi_uniffi_contract_version(): Int
-}
 
-internal object UniffiLib {
-    init {
-        Native.register(UniffiLib::class.java, findLibraryName(componentName = "slh_dsa_uniffi"))
-    }
 
-    external fun uniffi_slh_dsa_uniffi_fn_func_slh_dsa_keypair_generate(
-        `param`: RustBuffer.ByValue,
-        uniffi_out_err: UniffiRustCallStatus,
-    ): RustBuffer.ByValue
 
-    external fun uniffi_slh_dsa_uniffi_fn_func_slh_dsa_parameter_name(
-        `param`: RustBuffer.ByValue,
-        uniffi_out_err: UniffiRustCallStatus,
-    ): RustBuffer.ByValue
 
-    external fun uniffi_slh_dsa_uniffi_fn_func_slh_dsa_sign(
-        `param`: RustBuffer.ByValue,
-        `signingKey`: RustBuffer.ByValue,
-        `msg`: RustBuffer.ByValue,
-        `ctx`: RustBuffer.ByValue,
-        uniffi_out_err: UniffiRustCallStatus,
-    ): RustBuffer.ByValue
 
-    external fun uniffi_slh_dsa_uniffi_fn_func_slh_dsa_sign_deterministic(
-        `param`: RustBuffer.ByValue,
-        `signingKey`: RustBuffer.ByValue,
-        `msg`: RustBuffer.ByValue,
-        `ctx`: RustBuffer.ByValue,
-        uniffi_out_err: UniffiRustCallStatus,
-    ): RustBuffer.ByValue
 
-    external fun uniffi_slh_dsa_uniffi_fn_func_slh_dsa_signature_len(
-        `param`: RustBuffer.ByValue,
-        uniffi_out_err: UniffiRustCallStatus,
-    ): Long
 
-    external fun uniffi_slh_dsa_uniffi_fn_func_slh_dsa_signing_key_len(
-        `param`: RustBuffer.ByValue,
-        uniffi_out_err: UniffiRustCallStatus,
-    ): Long
 
-    external fun uniffi_slh_dsa_uniffi_fn_func_slh_dsa_verify(
-        `param`: RustBuffer.ByValue,
-        `verifyingKey`: RustBuffer.ByValue,
-        `msg`: RustBuffer.ByValue,
-        `ctx`: RustBuffer.ByValue,
-        `signature`: RustBuffer.ByValue,
-        uniffi_out_err: UniffiRustCallStatus,
-    ): RustBuffer.ByValue
 
-    external fun uniffi_slh_dsa_uniffi_fn_func_slh_dsa_verifying_key_from_signing_key(
-        `param`: RustBuffer.ByValue,
-        `signingKey`: RustBuffer.ByValue,
-        uniffi_out_err: UniffiRustCallStatus,
-    ): RustBuffer.ByValue
 
-    external fun uniffi_slh_dsa_uniffi_fn_func_slh_dsa_verifying_key_len(
-        `param`: RustBuffer.ByValue,
-        uniffi_out_err: UniffiRustCallStatus,
-    ): Long
 
-    external fun ffi_slh_dsa_uniffi_rustbuffer_alloc(
-        `size`: Long,
-        uniffi_out_err: UniffiRustCallStatus,
-    ): RustBuffer.ByValue
 
-    external fun ffi_slh_dsa_uniffi_rustbuffer_from_bytes(
-        `bytes`: ForeignBytes.ByValue,
-        uniffi_out_err: UniffiRustCallStatus,
-    ): RustBuffer.ByValue
 
-    external fun ffi_slh_dsa_uniffi_rustbuffer_free(
-        `buf`: RustBuffer.ByValue,
-        uniffi_out_err: UniffiRustCallStatus,
-    ): Unit
 
-    external fun ffi_slh_dsa_uniffi_rustbuffer_reserve(
-        `buf`: RustBuffer.ByValue,
-        `additional`: Long,
-        uniffi_out_err: UniffiRustCallStatus,
-    ): RustBuffer.ByValue
 
-    external fun ffi_slh_dsa_uniffi_rust_future_poll_u8(
-        `handle`: Long,
-        `callback`: UniffiRustFutureContinuationCallback,
-        `callbackData`: Long,
-    ): Unit
 
-    external fun ffi_slh_dsa_uniffi_rust_future_cancel_u8(`handle`: Long): Unit
-
-    external fun ffi_slh_dsa_uniffi_rust_future_free_u8(`handle`: Long): Unit
-
-    external fun ffi_slh_dsa_uniffi_rust_future_complete_u8(
-        `handle`: Long,
-        uniffi_out_err: UniffiRustCallStatus,
-    ): Byte
-
-    external fun ffi_slh_dsa_uniffi_rust_future_poll_i8(
-        `handle`: Long,
-        `callback`: UniffiRustFutureContinuationCallback,
-        `callbackData`: Long,
-    ): Unit
-
-    external fun ffi_slh_dsa_uniffi_rust_future_cancel_i8(`handle`: Long): Unit
-
-    external fun ffi_slh_dsa_uniffi_rust_future_free_i8(`handle`: Long): Unit
-
-    external fun ffi_slh_dsa_uniffi_rust_future_complete_i8(
-        `handle`: Long,
-        uniffi_out_err: UniffiRustCallStatus,
-    ): Byte
-
-    external fun ffi_slh_dsa_uniffi_rust_future_poll_u16(
-        `handle`: Long,
-        `callback`: UniffiRustFutureContinuationCallback,
-        `callbackData`: Long,
-    ): Unit
-
-    external fun ffi_slh_dsa_uniffi_rust_future_cancel_u16(`handle`: Long): Unit
-
-    external fun ffi_slh_dsa_uniffi_rust_future_free_u16(`handle`: Long): Unit
-
-    external fun ffi_slh_dsa_uniffi_rust_future_complete_u16(
-        `handle`: Long,
-        uniffi_out_err: UniffiRustCallStatus,
-    ): Short
-
-    external fun ffi_slh_dsa_uniffi_rust_future_poll_i16(
-        `handle`: Long,
-        `callback`: UniffiRustFutureContinuationCallback,
-        `callbackData`: Long,
-    ): Unit
-
-    external fun ffi_slh_dsa_uniffi_rust_future_cancel_i16(`handle`: Long): Unit
-
-    external fun ffi_slh_dsa_uniffi_rust_future_free_i16(`handle`: Long): Unit
-
-    external fun ffi_slh_dsa_uniffi_rust_future_complete_i16(
-        `handle`: Long,
-        uniffi_out_err: UniffiRustCallStatus,
-    ): Short
 
-    external fun ffi_slh_dsa_uniffi_rust_future_poll_u32(
-        `handle`: Long,
-        `callback`: UniffiRustFutureContinuationCallback,
-        `callbackData`: Long,
-    ): Unit
 
-    external fun ffi_slh_dsa_uniffi_rust_future_cancel_u32(`handle`: Long): Unit
 
-    external fun ffi_slh_dsa_uniffi_rust_future_free_u32(`handle`: Long): Unit
 
-    external fun ffi_slh_dsa_uniffi_rust_future_complete_u32(
-        `handle`: Long,
-        uniffi_out_err: UniffiRustCallStatus,
-    ): Int
 
-    external fun ffi_slh_dsa_uniffi_rust_future_poll_i32(
-        `handle`: Long,
-        `callback`: UniffiRustFutureContinuationCallback,
-        `callbackData`: Long,
-    ): Unit
 
-    external fun ffi_slh_dsa_uniffi_rust_future_cancel_i32(`handle`: Long): Unit
 
-    external fun ffi_slh_dsa_uniffi_rust_future_free_i32(`handle`: Long): Unit
 
-    external fun ffi_slh_dsa_uniffi_rust_future_complete_i32(
-        `handle`: Long,
-        uniffi_out_err: UniffiRustCallStatus,
-    ): Int
 
-    external fun ffi_slh_dsa_uniffi_rust_future_poll_u64(
-        `handle`: Long,
-        `callback`: UniffiRustFutureContinuationCallback,
-        `callbackData`: Long,
-    ): Unit
 
-    external fun ffi_slh_dsa_uniffi_rust_future_cancel_u64(`handle`: Long): Unit
 
-    external fun ffi_slh_dsa_uniffi_rust_future_free_u64(`handle`: Long): Unit
 
-    external fun ffi_slh_dsa_uniffi_rust_future_complete_u64(
-        `handle`: Long,
-        uniffi_out_err: UniffiRustCallStatus,
-    ): Long
 
-    external fun ffi_slh_dsa_uniffi_rust_future_poll_i64(
-        `handle`: Long,
-        `callback`: UniffiRustFutureContinuationCallback,
-        `callbackData`: Long,
-    ): Unit
 
-    external fun ffi_slh_dsa_uniffi_rust_future_cancel_i64(`handle`: Long): Unit
 
-    external fun ffi_slh_dsa_uniffi_rust_future_free_i64(`handle`: Long): Unit
 
-    external fun ffi_slh_dsa_uniffi_rust_future_complete_i64(
-        `handle`: Long,
-        uniffi_out_err: UniffiRustCallStatus,
-    ): Long
 
-    external fun ffi_slh_dsa_uniffi_rust_future_poll_f32(
-        `handle`: Long,
-        `callback`: UniffiRustFutureContinuationCallback,
-        `callbackData`: Long,
-    ): Unit
 
-    external fun ffi_slh_dsa_uniffi_rust_future_cancel_f32(`handle`: Long): Unit
 
-    external fun ffi_slh_dsa_uniffi_rust_future_free_f32(`handle`: Long): Unit
 
-    external fun ffi_slh_dsa_uniffi_rust_future_complete_f32(
-        `handle`: Long,
-        uniffi_out_err: UniffiRustCallStatus,
-    ): Float
 
-    external fun ffi_slh_dsa_uniffi_rust_future_poll_f64(
-        `handle`: Long,
-        `callback`: UniffiRustFutureContinuationCallback,
-        `callbackData`: Long,
-    ): Unit
 
-    external fun ffi_slh_dsa_uniffi_rust_future_cancel_f64(`handle`: Long): Unit
 
-    external fun ffi_slh_dsa_uniffi_rust_future_free_f64(`handle`: Long): Unit
 
-    external fun ffi_slh_dsa_uniffi_rust_future_complete_f64(
-        `handle`: Long,
-        uniffi_out_err: UniffiRustCallStatus,
-    ): Double
 
-    external fun ffi_slh_dsa_uniffi_rust_future_poll_rust_buffer(
-        `handle`: Long,
-        `callback`: UniffiRustFutureContinuationCallback,
-        `callbackData`: Long,
-    ): Unit
 
-    external fun ffi_slh_dsa_uniffi_rust_future_cancel_rust_buffer(`handle`: Long): Unit
 
-    external fun ffi_slh_dsa_uniffi_rust_future_free_rust_buffer(`handle`: Long): Unit
 
-    external fun ffi_slh_dsa_uniffi_rust_future_complete_rust_buffer(
-        `handle`: Long,
-        uniffi_out_err: UniffiRustCallStatus,
-    ): RustBuffer.ByValue
 
-    external fun ffi_slh_dsa_uniffi_rust_future_poll_void(
-        `handle`: Long,
-        `callback`: UniffiRustFutureContinuationCallback,
-        `callbackData`: Long,
-    ): Unit
 
-    external fun ffi_slh_dsa_uniffi_rust_future_cancel_void(`handle`: Long): Unit
 
-    external fun ffi_slh_dsa_uniffi_rust_future_free_void(`handle`: Long): Unit
 
-    external fun ffi_slh_dsa_uniffi_rust_future_complete_void(
-        `handle`: Long,
-        uniffi_out_err: UniffiRustCallStatus,
-    ): Unit
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+// For large crates we prevent `MethodTooLargeException` (see #2340)
+// N.B. the name of the extension is very misleading, since it is 
+// rather `InterfaceTooLargeException`, caused by too many methods 
+// in the interface for large crates.
+//
+// By splitting the otherwise huge interface into two parts
+// * UniffiLib 
+// * IntegrityCheckingUniffiLib (this)
+// we allow for ~2x as many methods in the UniffiLib interface.
+// 
+// The `ffi_uniffi_contract_version` method and all checksum methods are put 
+// into `IntegrityCheckingUniffiLib` and these methods are called only once,
+// when the library is loaded.
+internal interface IntegrityCheckingUniffiLib : Library {
+    // Integrity check functions only
+    fun uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_cose_sign1_create(
+): Short
+fun uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_cose_sign1_verify(
+): Short
+fun uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_csr_create(
+): Short
+fun uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_csr_verify(
+): Short
+fun uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_envelope_decode(
+): Short
+fun uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_envelope_sign(
+): Short
+fun uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_envelope_verify(
+): Short
+fun uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_fingerprint(
+): Short
+fun uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_key_file_len(
+): Short
+fun uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_keypair_from_seed(
+): Short
+fun uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_keypair_generate(
+): Short
+fun uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_parameter_name(
+): Short
+fun uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_parameter_set_from_name(
+): Short
+fun uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_parameter_set_from_oid(
+): Short
+fun uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_parameter_sets(
+): Short
+fun uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_seed_len(
+): Short
+fun uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_set_verify_after_sign(
+): Short
+fun uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_sign(
+): Short
+fun uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_sign_batch(
+): Short
+fun uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_sign_checked(
+): Short
+fun uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_sign_deterministic(
+): Short
+fun uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_signature_len(
+): Short
+fun uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_signing_key_from_cose_key(
+): Short
+fun uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_signing_key_from_encrypted_pkcs8(
+): Short
+fun uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_signing_key_from_key_file(
+): Short
+fun uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_signing_key_from_pkcs8(
+): Short
+fun uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_signing_key_len(
+): Short
+fun uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_signing_key_to_cose_key(
+): Short
+fun uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_signing_key_to_encrypted_pkcs8(
+): Short
+fun uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_signing_key_to_key_file(
+): Short
+fun uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_signing_key_to_pkcs8(
+): Short
+fun uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_validate_signing_key(
+): Short
+fun uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_verify(
+): Short
+fun uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_verify_after_sign_enabled(
+): Short
+fun uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_verifying_key_from_cose_key(
+): Short
+fun uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_verifying_key_from_signing_key(
+): Short
+fun uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_verifying_key_from_signing_key_validated(
+): Short
+fun uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_verifying_key_from_spki(
+): Short
+fun uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_verifying_key_len(
+): Short
+fun uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_verifying_key_to_cose_key(
+): Short
+fun uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_verifying_key_to_spki(
+): Short
+fun ffi_slh_dsa_uniffi_uniffi_contract_version(
+): Int
+
+}
+
+// A JNA Library to expose the extern-C FFI definitions.
+// This is an implementation detail which will be called internally by the public API.
+internal interface UniffiLib : Library {
+    companion object {
+        internal val INSTANCE: UniffiLib by lazy {
+            val componentName = "slh_dsa_uniffi"
+            // For large crates we prevent `MethodTooLargeException` (see #2340)
+            // N.B. the name of the extension is very misleading, since it is 
+            // rather `InterfaceTooLargeException`, caused by too many methods 
+            // in the interface for large crates.
+            //
+            // By splitting the otherwise huge interface into two parts
+            // * UniffiLib (this)
+            // * IntegrityCheckingUniffiLib
+            // And all checksum methods are put into `IntegrityCheckingUniffiLib`
+            // we allow for ~2x as many methods in the UniffiLib interface.
+            // 
+            // Thus we first load the library with `loadIndirect` as `IntegrityCheckingUniffiLib`
+            // so that we can (optionally!) call `uniffiCheckApiChecksums`...
+            loadIndirect<IntegrityCheckingUniffiLib>(componentName)
+                .also { lib: IntegrityCheckingUniffiLib ->
+                    uniffiCheckContractApiVersion(lib)
+                    uniffiCheckApiChecksums(lib)
+                }
+            // ... and then we load the library as `UniffiLib`
+            // N.B. we cannot use `loadIndirect` once and then try to cast it to `UniffiLib`
+            // => results in `java.lang.ClassCastException: com.sun.proxy.$Proxy cannot be cast to ...`
+            // error. So we must call `loadIndirect` twice. For crates large enough
+            // to trigger this issue, the performance impact is negligible, running on
+            // a macOS M1 machine the `loadIndirect` call takes ~50ms.
+            val lib = loadIndirect<UniffiLib>(componentName)
+            // No need to check the contract version and checksums, since 
+            // we already did that with `IntegrityCheckingUniffiLib` above.
+            // Loading of library with integrity check done.
+            lib
+        }
+        
+    }
+
+    // FFI functions
+    fun uniffi_slh_dsa_uniffi_fn_func_slh_dsa_cose_sign1_create(`param`: RustBuffer.ByValue,`signingKey`: RustBuffer.ByValue,`payload`: RustBuffer.ByValue,`keyId`: RustBuffer.ByValue,`externalAad`: RustBuffer.ByValue,`detached`: Byte,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+fun uniffi_slh_dsa_uniffi_fn_func_slh_dsa_cose_sign1_verify(`verifyingKey`: RustBuffer.ByValue,`encoded`: RustBuffer.ByValue,`detachedPayload`: RustBuffer.ByValue,`externalAad`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+fun uniffi_slh_dsa_uniffi_fn_func_slh_dsa_csr_create(`param`: RustBuffer.ByValue,`signingKey`: RustBuffer.ByValue,`subject`: RustBuffer.ByValue,`subjectAltNames`: RustBuffer.ByValue,`extensions`: RustBuffer.ByValue,`pem`: Byte,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+fun uniffi_slh_dsa_uniffi_fn_func_slh_dsa_csr_verify(`encoded`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+fun uniffi_slh_dsa_uniffi_fn_func_slh_dsa_envelope_decode(`encoded`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+fun uniffi_slh_dsa_uniffi_fn_func_slh_dsa_envelope_sign(`param`: RustBuffer.ByValue,`signingKey`: RustBuffer.ByValue,`payload`: RustBuffer.ByValue,`ctx`: RustBuffer.ByValue,`keyId`: RustBuffer.ByValue,`mode`: RustBuffer.ByValue,`detached`: Byte,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+fun uniffi_slh_dsa_uniffi_fn_func_slh_dsa_envelope_verify(`verifyingKey`: RustBuffer.ByValue,`encoded`: RustBuffer.ByValue,`detachedPayload`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+fun uniffi_slh_dsa_uniffi_fn_func_slh_dsa_fingerprint(`param`: RustBuffer.ByValue,`verifyingKey`: RustBuffer.ByValue,`source`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+fun uniffi_slh_dsa_uniffi_fn_func_slh_dsa_key_file_len(`param`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): Long
+fun uniffi_slh_dsa_uniffi_fn_func_slh_dsa_keypair_from_seed(`param`: RustBuffer.ByValue,`skSeed`: RustBuffer.ByValue,`skPrf`: RustBuffer.ByValue,`pkSeed`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+fun uniffi_slh_dsa_uniffi_fn_func_slh_dsa_keypair_generate(`param`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+fun uniffi_slh_dsa_uniffi_fn_func_slh_dsa_parameter_name(`param`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+fun uniffi_slh_dsa_uniffi_fn_func_slh_dsa_parameter_set_from_name(`name`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+fun uniffi_slh_dsa_uniffi_fn_func_slh_dsa_parameter_set_from_oid(`oid`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+fun uniffi_slh_dsa_uniffi_fn_func_slh_dsa_parameter_sets(uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+fun uniffi_slh_dsa_uniffi_fn_func_slh_dsa_seed_len(`param`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): Long
+fun uniffi_slh_dsa_uniffi_fn_func_slh_dsa_set_verify_after_sign(`enabled`: Byte,uniffi_out_err: UniffiRustCallStatus, 
+): Unit
+fun uniffi_slh_dsa_uniffi_fn_func_slh_dsa_sign(`param`: RustBuffer.ByValue,`signingKey`: RustBuffer.ByValue,`msg`: RustBuffer.ByValue,`ctx`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+fun uniffi_slh_dsa_uniffi_fn_func_slh_dsa_sign_batch(`param`: RustBuffer.ByValue,`signingKey`: RustBuffer.ByValue,`msgs`: RustBuffer.ByValue,`ctx`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+fun uniffi_slh_dsa_uniffi_fn_func_slh_dsa_sign_checked(`param`: RustBuffer.ByValue,`signingKey`: RustBuffer.ByValue,`msg`: RustBuffer.ByValue,`ctx`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+fun uniffi_slh_dsa_uniffi_fn_func_slh_dsa_sign_deterministic(`param`: RustBuffer.ByValue,`signingKey`: RustBuffer.ByValue,`msg`: RustBuffer.ByValue,`ctx`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+fun uniffi_slh_dsa_uniffi_fn_func_slh_dsa_signature_len(`param`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): Long
+fun uniffi_slh_dsa_uniffi_fn_func_slh_dsa_signing_key_from_cose_key(`encoded`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+fun uniffi_slh_dsa_uniffi_fn_func_slh_dsa_signing_key_from_encrypted_pkcs8(`encoded`: RustBuffer.ByValue,`passphrase`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+fun uniffi_slh_dsa_uniffi_fn_func_slh_dsa_signing_key_from_key_file(`encoded`: RustBuffer.ByValue,`passphrase`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+fun uniffi_slh_dsa_uniffi_fn_func_slh_dsa_signing_key_from_pkcs8(`encoded`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+fun uniffi_slh_dsa_uniffi_fn_func_slh_dsa_signing_key_len(`param`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): Long
+fun uniffi_slh_dsa_uniffi_fn_func_slh_dsa_signing_key_to_cose_key(`param`: RustBuffer.ByValue,`signingKey`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+fun uniffi_slh_dsa_uniffi_fn_func_slh_dsa_signing_key_to_encrypted_pkcs8(`param`: RustBuffer.ByValue,`signingKey`: RustBuffer.ByValue,`passphrase`: RustBuffer.ByValue,`pem`: Byte,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+fun uniffi_slh_dsa_uniffi_fn_func_slh_dsa_signing_key_to_key_file(`param`: RustBuffer.ByValue,`signingKey`: RustBuffer.ByValue,`passphrase`: RustBuffer.ByValue,`kdf`: RustBuffer.ByValue,`aead`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+fun uniffi_slh_dsa_uniffi_fn_func_slh_dsa_signing_key_to_pkcs8(`param`: RustBuffer.ByValue,`signingKey`: RustBuffer.ByValue,`pem`: Byte,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+fun uniffi_slh_dsa_uniffi_fn_func_slh_dsa_validate_signing_key(`param`: RustBuffer.ByValue,`signingKey`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+fun uniffi_slh_dsa_uniffi_fn_func_slh_dsa_verify(`param`: RustBuffer.ByValue,`verifyingKey`: RustBuffer.ByValue,`msg`: RustBuffer.ByValue,`ctx`: RustBuffer.ByValue,`signature`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+fun uniffi_slh_dsa_uniffi_fn_func_slh_dsa_verify_after_sign_enabled(uniffi_out_err: UniffiRustCallStatus, 
+): Byte
+fun uniffi_slh_dsa_uniffi_fn_func_slh_dsa_verifying_key_from_cose_key(`encoded`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+fun uniffi_slh_dsa_uniffi_fn_func_slh_dsa_verifying_key_from_signing_key(`param`: RustBuffer.ByValue,`signingKey`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+fun uniffi_slh_dsa_uniffi_fn_func_slh_dsa_verifying_key_from_signing_key_validated(`param`: RustBuffer.ByValue,`signingKey`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+fun uniffi_slh_dsa_uniffi_fn_func_slh_dsa_verifying_key_from_spki(`encoded`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+fun uniffi_slh_dsa_uniffi_fn_func_slh_dsa_verifying_key_len(`param`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): Long
+fun uniffi_slh_dsa_uniffi_fn_func_slh_dsa_verifying_key_to_cose_key(`param`: RustBuffer.ByValue,`verifyingKey`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+fun uniffi_slh_dsa_uniffi_fn_func_slh_dsa_verifying_key_to_spki(`param`: RustBuffer.ByValue,`verifyingKey`: RustBuffer.ByValue,`pem`: Byte,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+fun ffi_slh_dsa_uniffi_rustbuffer_alloc(`size`: Long,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+fun ffi_slh_dsa_uniffi_rustbuffer_from_bytes(`bytes`: ForeignBytes.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+fun ffi_slh_dsa_uniffi_rustbuffer_free(`buf`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+): Unit
+fun ffi_slh_dsa_uniffi_rustbuffer_reserve(`buf`: RustBuffer.ByValue,`additional`: Long,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+fun ffi_slh_dsa_uniffi_rust_future_poll_u8(`handle`: Long,`callback`: UniffiRustFutureContinuationCallback,`callbackData`: Long,
+): Unit
+fun ffi_slh_dsa_uniffi_rust_future_cancel_u8(`handle`: Long,
+): Unit
+fun ffi_slh_dsa_uniffi_rust_future_free_u8(`handle`: Long,
+): Unit
+fun ffi_slh_dsa_uniffi_rust_future_complete_u8(`handle`: Long,uniffi_out_err: UniffiRustCallStatus, 
+): Byte
+fun ffi_slh_dsa_uniffi_rust_future_poll_i8(`handle`: Long,`callback`: UniffiRustFutureContinuationCallback,`callbackData`: Long,
+): Unit
+fun ffi_slh_dsa_uniffi_rust_future_cancel_i8(`handle`: Long,
+): Unit
+fun ffi_slh_dsa_uniffi_rust_future_free_i8(`handle`: Long,
+): Unit
+fun ffi_slh_dsa_uniffi_rust_future_complete_i8(`handle`: Long,uniffi_out_err: UniffiRustCallStatus, 
+): Byte
+fun ffi_slh_dsa_uniffi_rust_future_poll_u16(`handle`: Long,`callback`: UniffiRustFutureContinuationCallback,`callbackData`: Long,
+): Unit
+fun ffi_slh_dsa_uniffi_rust_future_cancel_u16(`handle`: Long,
+): Unit
+fun ffi_slh_dsa_uniffi_rust_future_free_u16(`handle`: Long,
+): Unit
+fun ffi_slh_dsa_uniffi_rust_future_complete_u16(`handle`: Long,uniffi_out_err: UniffiRustCallStatus, 
+): Short
+fun ffi_slh_dsa_uniffi_rust_future_poll_i16(`handle`: Long,`callback`: UniffiRustFutureContinuationCallback,`callbackData`: Long,
+): Unit
+fun ffi_slh_dsa_uniffi_rust_future_cancel_i16(`handle`: Long,
+): Unit
+fun ffi_slh_dsa_uniffi_rust_future_free_i16(`handle`: Long,
+): Unit
+fun ffi_slh_dsa_uniffi_rust_future_complete_i16(`handle`: Long,uniffi_out_err: UniffiRustCallStatus, 
+): Short
+fun ffi_slh_dsa_uniffi_rust_future_poll_u32(`handle`: Long,`callback`: UniffiRustFutureContinuationCallback,`callbackData`: Long,
+): Unit
+fun ffi_slh_dsa_uniffi_rust_future_cancel_u32(`handle`: Long,
+): Unit
+fun ffi_slh_dsa_uniffi_rust_future_free_u32(`handle`: Long,
+): Unit
+fun ffi_slh_dsa_uniffi_rust_future_complete_u32(`handle`: Long,uniffi_out_err: UniffiRustCallStatus, 
+): Int
+fun ffi_slh_dsa_uniffi_rust_future_poll_i32(`handle`: Long,`callback`: UniffiRustFutureContinuationCallback,`callbackData`: Long,
+): Unit
+fun ffi_slh_dsa_uniffi_rust_future_cancel_i32(`handle`: Long,
+): Unit
+fun ffi_slh_dsa_uniffi_rust_future_free_i32(`handle`: Long,
+): Unit
+fun ffi_slh_dsa_uniffi_rust_future_complete_i32(`handle`: Long,uniffi_out_err: UniffiRustCallStatus, 
+): Int
+fun ffi_slh_dsa_uniffi_rust_future_poll_u64(`handle`: Long,`callback`: UniffiRustFutureContinuationCallback,`callbackData`: Long,
+): Unit
+fun ffi_slh_dsa_uniffi_rust_future_cancel_u64(`handle`: Long,
+): Unit
+fun ffi_slh_dsa_uniffi_rust_future_free_u64(`handle`: Long,
+): Unit
+fun ffi_slh_dsa_uniffi_rust_future_complete_u64(`handle`: Long,uniffi_out_err: UniffiRustCallStatus, 
+): Long
+fun ffi_slh_dsa_uniffi_rust_future_poll_i64(`handle`: Long,`callback`: UniffiRustFutureContinuationCallback,`callbackData`: Long,
+): Unit
+fun ffi_slh_dsa_uniffi_rust_future_cancel_i64(`handle`: Long,
+): Unit
+fun ffi_slh_dsa_uniffi_rust_future_free_i64(`handle`: Long,
+): Unit
+fun ffi_slh_dsa_uniffi_rust_future_complete_i64(`handle`: Long,uniffi_out_err: UniffiRustCallStatus, 
+): Long
+fun ffi_slh_dsa_uniffi_rust_future_poll_f32(`handle`: Long,`callback`: UniffiRustFutureContinuationCallback,`callbackData`: Long,
+): Unit
+fun ffi_slh_dsa_uniffi_rust_future_cancel_f32(`handle`: Long,
+): Unit
+fun ffi_slh_dsa_uniffi_rust_future_free_f32(`handle`: Long,
+): Unit
+fun ffi_slh_dsa_uniffi_rust_future_complete_f32(`handle`: Long,uniffi_out_err: UniffiRustCallStatus, 
+): Float
+fun ffi_slh_dsa_uniffi_rust_future_poll_f64(`handle`: Long,`callback`: UniffiRustFutureContinuationCallback,`callbackData`: Long,
+): Unit
+fun ffi_slh_dsa_uniffi_rust_future_cancel_f64(`handle`: Long,
+): Unit
+fun ffi_slh_dsa_uniffi_rust_future_free_f64(`handle`: Long,
+): Unit
+fun ffi_slh_dsa_uniffi_rust_future_complete_f64(`handle`: Long,uniffi_out_err: UniffiRustCallStatus, 
+): Double
+fun ffi_slh_dsa_uniffi_rust_future_poll_pointer(`handle`: Long,`callback`: UniffiRustFutureContinuationCallback,`callbackData`: Long,
+): Unit
+fun ffi_slh_dsa_uniffi_rust_future_cancel_pointer(`handle`: Long,
+): Unit
+fun ffi_slh_dsa_uniffi_rust_future_free_pointer(`handle`: Long,
+): Unit
+fun ffi_slh_dsa_uniffi_rust_future_complete_pointer(`handle`: Long,uniffi_out_err: UniffiRustCallStatus, 
+): Pointer
+fun ffi_slh_dsa_uniffi_rust_future_poll_rust_buffer(`handle`: Long,`callback`: UniffiRustFutureContinuationCallback,`callbackData`: Long,
+): Unit
+fun ffi_slh_dsa_uniffi_rust_future_cancel_rust_buffer(`handle`: Long,
+): Unit
+fun ffi_slh_dsa_uniffi_rust_future_free_rust_buffer(`handle`: Long,
+): Unit
+fun ffi_slh_dsa_uniffi_rust_future_complete_rust_buffer(`handle`: Long,uniffi_out_err: UniffiRustCallStatus, 
+): RustBuffer.ByValue
+fun ffi_slh_dsa_uniffi_rust_future_poll_void(`handle`: Long,`callback`: UniffiRustFutureContinuationCallback,`callbackData`: Long,
+): Unit
+fun ffi_slh_dsa_uniffi_rust_future_cancel_void(`handle`: Long,
+): Unit
+fun ffi_slh_dsa_uniffi_rust_future_free_void(`handle`: Long,
+): Unit
+fun ffi_slh_dsa_uniffi_rust_future_complete_void(`handle`: Long,uniffi_out_err: UniffiRustCallStatus, 
+): Unit
+
 }
 
 private fun uniffiCheckContractApiVersion(lib: IntegrityCheckingUniffiLib) {
     // Get the bindings contract version from our ComponentInterface
-    val bindings_contract_version = 30
+    val bindings_contract_version = 29
     // Get the scaffolding contract version by calling the into the dylib
     val scaffolding_contract_version = lib.ffi_slh_dsa_uniffi_uniffi_contract_version()
     if (bindings_contract_version != scaffolding_contract_version) {
         throw RuntimeException("UniFFI contract version mismatch: try cleaning and rebuilding your project")
     }
 }
-
 @Suppress("UNUSED_PARAMETER")
 private fun uniffiCheckApiChecksums(lib: IntegrityCheckingUniffiLib) {
+    if (lib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_cose_sign1_create() != 11547.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_cose_sign1_verify() != 34918.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_csr_create() != 38104.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_csr_verify() != 54660.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_envelope_decode() != 43712.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_envelope_sign() != 62941.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_envelope_verify() != 55803.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_fingerprint() != 23530.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_key_file_len() != 21678.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_keypair_from_seed() != 31227.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
     if (lib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_keypair_generate() != 7757.toShort()) {
         throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
     }
     if (lib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_parameter_name() != 63153.toShort()) {
         throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
     }
-    if (lib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_sign() != 51560.toShort()) {
+    if (lib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_parameter_set_from_name() != 56401.toShort()) {
         throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
     }
-    if (lib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_sign_deterministic() != 23979.toShort()) {
+    if (lib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_parameter_set_from_oid() != 48394.toShort()) {
         throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
     }
-    if (lib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_signature_len() != 62991.toShort()) {
+    if (lib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_parameter_sets() != 26697.toShort()) {
         throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
     }
-    if (lib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_signing_key_len() != 40535.toShort()) {
+    if (lib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_seed_len() != 18769.toShort()) {
         throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
     }
-    if (lib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_verify() != 22241.toShort()) {
+    if (lib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_set_verify_after_sign() != 6374.toShort()) {
         throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
     }
-    if (lib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_verifying_key_from_signing_key() != 64766.toShort()) {
+    if (lib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_sign() != 51560.toShort()) {
         throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
     }
-    if (lib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_verifying_key_len() != 16179.toShort()) {
+    if (lib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_sign_batch() != 55765.toShort()) {
         throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
     }
-}
-
-/**
- * @suppress
- */
-public fun uniffiEnsureInitialized() {
-    IntegrityCheckingUniffiLib
-    // UniffiLib() initialized as objects are used, but we still need to explicitly
-    // reference it so initialization across crates works as expected.
-    UniffiLib
-}
-
+    if (lib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_sign_checked() != 30107.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_sign_deterministic() != 23979.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_signature_len() != 62991.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_signing_key_from_cose_key() != 34229.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_signing_key_from_encrypted_pkcs8() != 19253.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_signing_key_from_key_file() != 53092.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_signing_key_from_pkcs8() != 17809.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_signing_key_len() != 40535.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_signing_key_to_cose_key() != 51163.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_signing_key_to_encrypted_pkcs8() != 36675.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_signing_key_to_key_file() != 43469.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_signing_key_to_pkcs8() != 52043.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_validate_signing_key() != 6415.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_verify() != 22241.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_verify_after_sign_enabled() != 43959.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_verifying_key_from_cose_key() != 62128.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_verifying_key_from_signing_key() != 64766.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_verifying_key_from_signing_key_validated() != 37694.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_verifying_key_from_spki() != 1557.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_verifying_key_len() != 16179.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_verifying_key_to_cose_key() != 45780.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_verifying_key_to_spki() != 37838.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+}
+
+/**
+ * @suppress
+ */
+public fun uniffiEnsureInitialized() {
+    UniffiLib.INSTANCE
+}
+
 // Async support
 
 // Public interface members begin here.
 
+
 // Interface implemented by anything that can contain an object reference.
 //
 // Such types expose a `destroy()` method that must be called to cleanly
@@ -1078,15 +1290,11 @@ public fun uniffiEnsureInitialized() {
 // helper method to execute a block and destroy the object at the end.
 interface Disposable {
     fun destroy()
-
     companion object {
         fun destroy(vararg args: Any?) {
             for (arg in args) {
                 when (arg) {
-                    is Disposable -> {
-                        arg.destroy()
-                    }
-
+                    is Disposable -> arg.destroy()
                     is ArrayList<*> -> {
                         for (idx in arg.indices) {
                             val element = arg[idx]
@@ -1095,7 +1303,6 @@ interface Disposable {
                             }
                         }
                     }
-
                     is Map<*, *> -> {
                         for (element in arg.values) {
                             if (element is Disposable) {
@@ -1103,7 +1310,6 @@ interface Disposable {
                             }
                         }
                     }
-
                     is Iterable<*> -> {
                         for (element in arg) {
                             if (element is Disposable) {
@@ -1132,40 +1338,78 @@ inline fun <T : Disposable?, R> T.use(block: (T) -> R) =
         }
     }
 
-/**
- * Placeholder object used to signal that we're constructing an interface with a FFI handle.
- *
- * This is the first argument for interface constructors that input a raw handle. It exists is that
- * so we can avoid signature conflicts when an interface has a regular constructor than inputs a
- * Long.
+/** 
+ * Used to instantiate an interface without an actual pointer, for fakes in tests, mostly.
  *
  * @suppress
  * */
-object UniffiWithHandle
+object NoPointer
 
 /**
- * Used to instantiate an interface without an actual pointer, for fakes in tests, mostly.
- *
  * @suppress
- * */
-object NoHandle
+ */
+public object FfiConverterUByte: FfiConverter<UByte, Byte> {
+    override fun lift(value: Byte): UByte {
+        return value.toUByte()
+    }
+
+    override fun read(buf: ByteBuffer): UByte {
+        return lift(buf.get())
+    }
+
+    override fun lower(value: UByte): Byte {
+        return value.toByte()
+    }
+
+    override fun allocationSize(value: UByte) = 1UL
+
+    override fun write(value: UByte, buf: ByteBuffer) {
+        buf.put(value.toByte())
+    }
+}
 
 /**
  * @suppress
  */
-public object FfiConverterULong : FfiConverter<ULong, Long> {
-    override fun lift(value: Long): ULong = value.toULong()
+public object FfiConverterUInt: FfiConverter<UInt, Int> {
+    override fun lift(value: Int): UInt {
+        return value.toUInt()
+    }
+
+    override fun read(buf: ByteBuffer): UInt {
+        return lift(buf.getInt())
+    }
+
+    override fun lower(value: UInt): Int {
+        return value.toInt()
+    }
+
+    override fun allocationSize(value: UInt) = 4UL
+
+    override fun write(value: UInt, buf: ByteBuffer) {
+        buf.putInt(value.toInt())
+    }
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterULong: FfiConverter<ULong, Long> {
+    override fun lift(value: Long): ULong {
+        return value.toULong()
+    }
 
-    override fun read(buf: ByteBuffer): ULong = lift(buf.getLong())
+    override fun read(buf: ByteBuffer): ULong {
+        return lift(buf.getLong())
+    }
 
-    override fun lower(value: ULong): Long = value.toLong()
+    override fun lower(value: ULong): Long {
+        return value.toLong()
+    }
 
     override fun allocationSize(value: ULong) = 8UL
 
-    override fun write(
-        value: ULong,
-        buf: ByteBuffer,
-    ) {
+    override fun write(value: ULong, buf: ByteBuffer) {
         buf.putLong(value.toLong())
     }
 }
@@ -1173,19 +1417,22 @@ public object FfiConverterULong : FfiConverter<ULong, Long> {
 /**
  * @suppress
  */
-public object FfiConverterBoolean : FfiConverter<Boolean, Byte> {
-    override fun lift(value: Byte): Boolean = value.toInt() != 0
+public object FfiConverterBoolean: FfiConverter<Boolean, Byte> {
+    override fun lift(value: Byte): Boolean {
+        return value.toInt() != 0
+    }
 
-    override fun read(buf: ByteBuffer): Boolean = lift(buf.get())
+    override fun read(buf: ByteBuffer): Boolean {
+        return lift(buf.get())
+    }
 
-    override fun lower(value: Boolean): Byte = if (value) 1.toByte() else 0.toByte()
+    override fun lower(value: Boolean): Byte {
+        return if (value) 1.toByte() else 0.toByte()
+    }
 
     override fun allocationSize(value: Boolean) = 1UL
 
-    override fun write(
-        value: Boolean,
-        buf: ByteBuffer,
-    ) {
+    override fun write(value: Boolean, buf: ByteBuffer) {
         buf.put(lower(value))
     }
 }
@@ -1193,7 +1440,7 @@ public object FfiConverterBoolean : FfiConverter<Boolean, Byte> {
 /**
  * @suppress
  */
-public object FfiConverterString : FfiConverter<String, RustBuffer.ByValue> {
+public object FfiConverterString: FfiConverter<String, RustBuffer.ByValue> {
     // Note: we don't inherit from FfiConverterRustBuffer, because we use a
     // special encoding when lowering/lifting.  We can use `RustBuffer.len` to
     // store our length and avoid writing it out to the buffer.
@@ -1240,10 +1487,7 @@ public object FfiConverterString : FfiConverter<String, RustBuffer.ByValue> {
         return sizeForLength + sizeForString
     }
 
-    override fun write(
-        value: String,
-        buf: ByteBuffer,
-    ) {
+    override fun write(value: String, buf: ByteBuffer) {
         val byteBuf = toUtf8(value)
         buf.putInt(byteBuf.limit())
         buf.put(byteBuf)
@@ -1253,325 +1497,1563 @@ public object FfiConverterString : FfiConverter<String, RustBuffer.ByValue> {
 /**
  * @suppress
  */
-public object FfiConverterByteArray : FfiConverterRustBuffer<ByteArray> {
+public object FfiConverterByteArray: FfiConverterRustBuffer<ByteArray> {
     override fun read(buf: ByteBuffer): ByteArray {
         val len = buf.getInt()
         val byteArr = ByteArray(len)
         buf.get(byteArr)
         return byteArr
     }
-
-    override fun allocationSize(value: ByteArray): ULong = 4UL + value.size.toULong()
-
-    override fun write(
-        value: ByteArray,
-        buf: ByteBuffer,
-    ) {
+    override fun allocationSize(value: ByteArray): ULong {
+        return 4UL + value.size.toULong()
+    }
+    override fun write(value: ByteArray, buf: ByteBuffer) {
         buf.putInt(value.size)
         buf.put(value)
     }
 }
 
-data class KeyResult(
-    var `status`: SlhDsaStatus,
-    var `verifyingKey`: kotlin.ByteArray,
+
+
+data class CoseSign1Info (
+    var `status`: SlhDsaStatus, 
+    var `valid`: kotlin.Boolean, 
+    var `param`: ParameterSetId?, 
+    var `keyId`: kotlin.ByteArray?, 
+    var `payload`: kotlin.ByteArray?
 ) {
+    
     companion object
 }
 
 /**
  * @suppress
  */
-public object FfiConverterTypeKeyResult : FfiConverterRustBuffer<KeyResult> {
-    override fun read(buf: ByteBuffer): KeyResult =
-        KeyResult(
+public object FfiConverterTypeCoseSign1Info: FfiConverterRustBuffer<CoseSign1Info> {
+    override fun read(buf: ByteBuffer): CoseSign1Info {
+        return CoseSign1Info(
             FfiConverterTypeSlhDsaStatus.read(buf),
-            FfiConverterByteArray.read(buf),
+            FfiConverterBoolean.read(buf),
+            FfiConverterOptionalTypeParameterSetId.read(buf),
+            FfiConverterOptionalByteArray.read(buf),
+            FfiConverterOptionalByteArray.read(buf),
         )
+    }
 
-    override fun allocationSize(value: KeyResult) =
-        (
+    override fun allocationSize(value: CoseSign1Info) = (
             FfiConverterTypeSlhDsaStatus.allocationSize(value.`status`) +
-                FfiConverterByteArray.allocationSize(value.`verifyingKey`)
-        )
+            FfiConverterBoolean.allocationSize(value.`valid`) +
+            FfiConverterOptionalTypeParameterSetId.allocationSize(value.`param`) +
+            FfiConverterOptionalByteArray.allocationSize(value.`keyId`) +
+            FfiConverterOptionalByteArray.allocationSize(value.`payload`)
+    )
 
-    override fun write(
-        value: KeyResult,
-        buf: ByteBuffer,
-    ) {
-        FfiConverterTypeSlhDsaStatus.write(value.`status`, buf)
-        FfiConverterByteArray.write(value.`verifyingKey`, buf)
+    override fun write(value: CoseSign1Info, buf: ByteBuffer) {
+            FfiConverterTypeSlhDsaStatus.write(value.`status`, buf)
+            FfiConverterBoolean.write(value.`valid`, buf)
+            FfiConverterOptionalTypeParameterSetId.write(value.`param`, buf)
+            FfiConverterOptionalByteArray.write(value.`keyId`, buf)
+            FfiConverterOptionalByteArray.write(value.`payload`, buf)
     }
 }
 
-data class Keypair(
-    var `signingKey`: kotlin.ByteArray,
-    var `verifyingKey`: kotlin.ByteArray,
+
+
+data class CsrExtension (
+    var `oid`: kotlin.String, 
+    var `critical`: kotlin.Boolean, 
+    var `value`: kotlin.ByteArray
 ) {
+    
     companion object
 }
 
 /**
  * @suppress
  */
-public object FfiConverterTypeKeypair : FfiConverterRustBuffer<Keypair> {
-    override fun read(buf: ByteBuffer): Keypair =
-        Keypair(
+public object FfiConverterTypeCsrExtension: FfiConverterRustBuffer<CsrExtension> {
+    override fun read(buf: ByteBuffer): CsrExtension {
+        return CsrExtension(
+            FfiConverterString.read(buf),
+            FfiConverterBoolean.read(buf),
             FfiConverterByteArray.read(buf),
+        )
+    }
+
+    override fun allocationSize(value: CsrExtension) = (
+            FfiConverterString.allocationSize(value.`oid`) +
+            FfiConverterBoolean.allocationSize(value.`critical`) +
+            FfiConverterByteArray.allocationSize(value.`value`)
+    )
+
+    override fun write(value: CsrExtension, buf: ByteBuffer) {
+            FfiConverterString.write(value.`oid`, buf)
+            FfiConverterBoolean.write(value.`critical`, buf)
+            FfiConverterByteArray.write(value.`value`, buf)
+    }
+}
+
+
+
+data class CsrInfo (
+    var `status`: SlhDsaStatus, 
+    var `valid`: kotlin.Boolean, 
+    var `param`: ParameterSetId?, 
+    var `verifyingKey`: kotlin.ByteArray, 
+    var `subject`: CsrSubject, 
+    var `subjectAltNames`: List<SubjectAltName>, 
+    var `extensions`: List<CsrExtension>
+) {
+    
+    companion object
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeCsrInfo: FfiConverterRustBuffer<CsrInfo> {
+    override fun read(buf: ByteBuffer): CsrInfo {
+        return CsrInfo(
+            FfiConverterTypeSlhDsaStatus.read(buf),
+            FfiConverterBoolean.read(buf),
+            FfiConverterOptionalTypeParameterSetId.read(buf),
             FfiConverterByteArray.read(buf),
+            FfiConverterTypeCsrSubject.read(buf),
+            FfiConverterSequenceTypeSubjectAltName.read(buf),
+            FfiConverterSequenceTypeCsrExtension.read(buf),
         )
+    }
 
-    override fun allocationSize(value: Keypair) =
-        (
-            FfiConverterByteArray.allocationSize(value.`signingKey`) +
-                FfiConverterByteArray.allocationSize(value.`verifyingKey`)
+    override fun allocationSize(value: CsrInfo) = (
+            FfiConverterTypeSlhDsaStatus.allocationSize(value.`status`) +
+            FfiConverterBoolean.allocationSize(value.`valid`) +
+            FfiConverterOptionalTypeParameterSetId.allocationSize(value.`param`) +
+            FfiConverterByteArray.allocationSize(value.`verifyingKey`) +
+            FfiConverterTypeCsrSubject.allocationSize(value.`subject`) +
+            FfiConverterSequenceTypeSubjectAltName.allocationSize(value.`subjectAltNames`) +
+            FfiConverterSequenceTypeCsrExtension.allocationSize(value.`extensions`)
+    )
+
+    override fun write(value: CsrInfo, buf: ByteBuffer) {
+            FfiConverterTypeSlhDsaStatus.write(value.`status`, buf)
+            FfiConverterBoolean.write(value.`valid`, buf)
+            FfiConverterOptionalTypeParameterSetId.write(value.`param`, buf)
+            FfiConverterByteArray.write(value.`verifyingKey`, buf)
+            FfiConverterTypeCsrSubject.write(value.`subject`, buf)
+            FfiConverterSequenceTypeSubjectAltName.write(value.`subjectAltNames`, buf)
+            FfiConverterSequenceTypeCsrExtension.write(value.`extensions`, buf)
+    }
+}
+
+
+
+data class CsrSubject (
+    var `commonName`: kotlin.String?, 
+    var `organization`: kotlin.String?, 
+    var `organizationalUnit`: kotlin.String?, 
+    var `country`: kotlin.String?
+) {
+    
+    companion object
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeCsrSubject: FfiConverterRustBuffer<CsrSubject> {
+    override fun read(buf: ByteBuffer): CsrSubject {
+        return CsrSubject(
+            FfiConverterOptionalString.read(buf),
+            FfiConverterOptionalString.read(buf),
+            FfiConverterOptionalString.read(buf),
+            FfiConverterOptionalString.read(buf),
         )
+    }
+
+    override fun allocationSize(value: CsrSubject) = (
+            FfiConverterOptionalString.allocationSize(value.`commonName`) +
+            FfiConverterOptionalString.allocationSize(value.`organization`) +
+            FfiConverterOptionalString.allocationSize(value.`organizationalUnit`) +
+            FfiConverterOptionalString.allocationSize(value.`country`)
+    )
 
-    override fun write(
-        value: Keypair,
-        buf: ByteBuffer,
-    ) {
-        FfiConverterByteArray.write(value.`signingKey`, buf)
-        FfiConverterByteArray.write(value.`verifyingKey`, buf)
+    override fun write(value: CsrSubject, buf: ByteBuffer) {
+            FfiConverterOptionalString.write(value.`commonName`, buf)
+            FfiConverterOptionalString.write(value.`organization`, buf)
+            FfiConverterOptionalString.write(value.`organizationalUnit`, buf)
+            FfiConverterOptionalString.write(value.`country`, buf)
     }
 }
 
-data class SignResult(
-    var `status`: SlhDsaStatus,
-    var `signature`: kotlin.ByteArray,
+
+
+data class DecodeKeyResult (
+    var `status`: SlhDsaStatus, 
+    var `param`: ParameterSetId?, 
+    var `key`: kotlin.ByteArray
 ) {
+    
     companion object
 }
 
 /**
  * @suppress
  */
-public object FfiConverterTypeSignResult : FfiConverterRustBuffer<SignResult> {
-    override fun read(buf: ByteBuffer): SignResult =
-        SignResult(
+public object FfiConverterTypeDecodeKeyResult: FfiConverterRustBuffer<DecodeKeyResult> {
+    override fun read(buf: ByteBuffer): DecodeKeyResult {
+        return DecodeKeyResult(
             FfiConverterTypeSlhDsaStatus.read(buf),
+            FfiConverterOptionalTypeParameterSetId.read(buf),
             FfiConverterByteArray.read(buf),
         )
+    }
 
-    override fun allocationSize(value: SignResult) =
-        (
+    override fun allocationSize(value: DecodeKeyResult) = (
             FfiConverterTypeSlhDsaStatus.allocationSize(value.`status`) +
-                FfiConverterByteArray.allocationSize(value.`signature`)
+            FfiConverterOptionalTypeParameterSetId.allocationSize(value.`param`) +
+            FfiConverterByteArray.allocationSize(value.`key`)
+    )
+
+    override fun write(value: DecodeKeyResult, buf: ByteBuffer) {
+            FfiConverterTypeSlhDsaStatus.write(value.`status`, buf)
+            FfiConverterOptionalTypeParameterSetId.write(value.`param`, buf)
+            FfiConverterByteArray.write(value.`key`, buf)
+    }
+}
+
+
+
+data class EncodeResult (
+    var `status`: SlhDsaStatus, 
+    var `encoded`: kotlin.ByteArray
+) {
+    
+    companion object
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeEncodeResult: FfiConverterRustBuffer<EncodeResult> {
+    override fun read(buf: ByteBuffer): EncodeResult {
+        return EncodeResult(
+            FfiConverterTypeSlhDsaStatus.read(buf),
+            FfiConverterByteArray.read(buf),
         )
+    }
+
+    override fun allocationSize(value: EncodeResult) = (
+            FfiConverterTypeSlhDsaStatus.allocationSize(value.`status`) +
+            FfiConverterByteArray.allocationSize(value.`encoded`)
+    )
 
-    override fun write(
-        value: SignResult,
-        buf: ByteBuffer,
-    ) {
-        FfiConverterTypeSlhDsaStatus.write(value.`status`, buf)
-        FfiConverterByteArray.write(value.`signature`, buf)
+    override fun write(value: EncodeResult, buf: ByteBuffer) {
+            FfiConverterTypeSlhDsaStatus.write(value.`status`, buf)
+            FfiConverterByteArray.write(value.`encoded`, buf)
     }
 }
 
-data class VerifyResult(
-    var `status`: SlhDsaStatus,
-    var `valid`: kotlin.Boolean,
+
+
+data class EnvelopeInfo (
+    var `status`: SlhDsaStatus, 
+    var `valid`: kotlin.Boolean, 
+    var `param`: ParameterSetId?, 
+    var `mode`: SignatureMode?, 
+    var `keyId`: kotlin.ByteArray?, 
+    var `context`: kotlin.ByteArray, 
+    var `payload`: kotlin.ByteArray?
 ) {
+    
     companion object
 }
 
 /**
  * @suppress
  */
-public object FfiConverterTypeVerifyResult : FfiConverterRustBuffer<VerifyResult> {
-    override fun read(buf: ByteBuffer): VerifyResult =
-        VerifyResult(
+public object FfiConverterTypeEnvelopeInfo: FfiConverterRustBuffer<EnvelopeInfo> {
+    override fun read(buf: ByteBuffer): EnvelopeInfo {
+        return EnvelopeInfo(
             FfiConverterTypeSlhDsaStatus.read(buf),
             FfiConverterBoolean.read(buf),
+            FfiConverterOptionalTypeParameterSetId.read(buf),
+            FfiConverterOptionalTypeSignatureMode.read(buf),
+            FfiConverterOptionalByteArray.read(buf),
+            FfiConverterByteArray.read(buf),
+            FfiConverterOptionalByteArray.read(buf),
         )
+    }
 
-    override fun allocationSize(value: VerifyResult) =
-        (
+    override fun allocationSize(value: EnvelopeInfo) = (
             FfiConverterTypeSlhDsaStatus.allocationSize(value.`status`) +
-                FfiConverterBoolean.allocationSize(value.`valid`)
-        )
+            FfiConverterBoolean.allocationSize(value.`valid`) +
+            FfiConverterOptionalTypeParameterSetId.allocationSize(value.`param`) +
+            FfiConverterOptionalTypeSignatureMode.allocationSize(value.`mode`) +
+            FfiConverterOptionalByteArray.allocationSize(value.`keyId`) +
+            FfiConverterByteArray.allocationSize(value.`context`) +
+            FfiConverterOptionalByteArray.allocationSize(value.`payload`)
+    )
 
-    override fun write(
-        value: VerifyResult,
-        buf: ByteBuffer,
-    ) {
-        FfiConverterTypeSlhDsaStatus.write(value.`status`, buf)
-        FfiConverterBoolean.write(value.`valid`, buf)
+    override fun write(value: EnvelopeInfo, buf: ByteBuffer) {
+            FfiConverterTypeSlhDsaStatus.write(value.`status`, buf)
+            FfiConverterBoolean.write(value.`valid`, buf)
+            FfiConverterOptionalTypeParameterSetId.write(value.`param`, buf)
+            FfiConverterOptionalTypeSignatureMode.write(value.`mode`, buf)
+            FfiConverterOptionalByteArray.write(value.`keyId`, buf)
+            FfiConverterByteArray.write(value.`context`, buf)
+            FfiConverterOptionalByteArray.write(value.`payload`, buf)
     }
 }
 
-enum class ParameterSetId {
-    SHAKE128S,
-    SHAKE128F,
-    SHAKE192S,
-    SHAKE192F,
-    SHAKE256S,
-    SHAKE256F,
-    SHA2_128S,
-    SHA2_128F,
-    SHA2_192S,
-    SHA2_192F,
-    SHA2_256S,
-    SHA2_256F,
-    ;
 
+
+data class FingerprintResult (
+    var `status`: SlhDsaStatus, 
+    var `fingerprint`: kotlin.ByteArray, 
+    var `hex`: kotlin.String, 
+    var `base32`: kotlin.String, 
+    var `words`: kotlin.String
+) {
+    
     companion object
 }
 
 /**
  * @suppress
  */
-public object FfiConverterTypeParameterSetId : FfiConverterRustBuffer<ParameterSetId> {
-    override fun read(buf: ByteBuffer) =
-        try {
-            ParameterSetId.values()[buf.getInt() - 1]
-        } catch (e: IndexOutOfBoundsException) {
-            throw RuntimeException("invalid enum value, something is very wrong!!", e)
-        }
+public object FfiConverterTypeFingerprintResult: FfiConverterRustBuffer<FingerprintResult> {
+    override fun read(buf: ByteBuffer): FingerprintResult {
+        return FingerprintResult(
+            FfiConverterTypeSlhDsaStatus.read(buf),
+            FfiConverterByteArray.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterString.read(buf),
+        )
+    }
 
-    override fun allocationSize(value: ParameterSetId) = 4UL
+    override fun allocationSize(value: FingerprintResult) = (
+            FfiConverterTypeSlhDsaStatus.allocationSize(value.`status`) +
+            FfiConverterByteArray.allocationSize(value.`fingerprint`) +
+            FfiConverterString.allocationSize(value.`hex`) +
+            FfiConverterString.allocationSize(value.`base32`) +
+            FfiConverterString.allocationSize(value.`words`)
+    )
 
-    override fun write(
-        value: ParameterSetId,
-        buf: ByteBuffer,
-    ) {
-        buf.putInt(value.ordinal + 1)
+    override fun write(value: FingerprintResult, buf: ByteBuffer) {
+            FfiConverterTypeSlhDsaStatus.write(value.`status`, buf)
+            FfiConverterByteArray.write(value.`fingerprint`, buf)
+            FfiConverterString.write(value.`hex`, buf)
+            FfiConverterString.write(value.`base32`, buf)
+            FfiConverterString.write(value.`words`, buf)
     }
 }
 
-enum class SlhDsaStatus {
-    OK,
-    INVALID_PARAM,
-    INVALID_LENGTH,
-    DECODE_ERROR,
-    VERIFY_FAILED,
-    ;
 
+
+data class KeyResult (
+    var `status`: SlhDsaStatus, 
+    var `verifyingKey`: kotlin.ByteArray
+) {
+    
     companion object
 }
 
 /**
  * @suppress
  */
-public object FfiConverterTypeSlhDsaStatus : FfiConverterRustBuffer<SlhDsaStatus> {
-    override fun read(buf: ByteBuffer) =
-        try {
-            SlhDsaStatus.values()[buf.getInt() - 1]
-        } catch (e: IndexOutOfBoundsException) {
-            throw RuntimeException("invalid enum value, something is very wrong!!", e)
-        }
+public object FfiConverterTypeKeyResult: FfiConverterRustBuffer<KeyResult> {
+    override fun read(buf: ByteBuffer): KeyResult {
+        return KeyResult(
+            FfiConverterTypeSlhDsaStatus.read(buf),
+            FfiConverterByteArray.read(buf),
+        )
+    }
 
-    override fun allocationSize(value: SlhDsaStatus) = 4UL
+    override fun allocationSize(value: KeyResult) = (
+            FfiConverterTypeSlhDsaStatus.allocationSize(value.`status`) +
+            FfiConverterByteArray.allocationSize(value.`verifyingKey`)
+    )
 
-    override fun write(
-        value: SlhDsaStatus,
-        buf: ByteBuffer,
-    ) {
-        buf.putInt(value.ordinal + 1)
+    override fun write(value: KeyResult, buf: ByteBuffer) {
+            FfiConverterTypeSlhDsaStatus.write(value.`status`, buf)
+            FfiConverterByteArray.write(value.`verifyingKey`, buf)
     }
 }
 
-fun `slhDsaKeypairGenerate`(`param`: ParameterSetId): Keypair =
-    FfiConverterTypeKeypair.lift(
-        uniffiRustCall { _status ->
-            UniffiLib.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_keypair_generate(FfiConverterTypeParameterSetId.lower(`param`), _status)
-        },
-    )
 
-fun `slhDsaParameterName`(`param`: ParameterSetId): kotlin.String =
-    FfiConverterString.lift(
-        uniffiRustCall { _status ->
-            UniffiLib.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_parameter_name(FfiConverterTypeParameterSetId.lower(`param`), _status)
-        },
-    )
 
-fun `slhDsaSign`(
-    `param`: ParameterSetId,
-    `signingKey`: kotlin.ByteArray,
-    `msg`: kotlin.ByteArray,
-    `ctx`: kotlin.ByteArray,
-): SignResult =
-    FfiConverterTypeSignResult.lift(
-        uniffiRustCall { _status ->
-            UniffiLib.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_sign(
-                FfiConverterTypeParameterSetId.lower(`param`),
-                FfiConverterByteArray.lower(`signingKey`),
-                FfiConverterByteArray.lower(`msg`),
-                FfiConverterByteArray.lower(`ctx`),
-                _status,
-            )
-        },
-    )
+data class Keypair (
+    var `status`: SlhDsaStatus, 
+    var `signingKey`: kotlin.ByteArray, 
+    var `verifyingKey`: kotlin.ByteArray
+) {
+    
+    companion object
+}
 
-fun `slhDsaSignDeterministic`(
-    `param`: ParameterSetId,
-    `signingKey`: kotlin.ByteArray,
-    `msg`: kotlin.ByteArray,
-    `ctx`: kotlin.ByteArray,
-): SignResult =
-    FfiConverterTypeSignResult.lift(
-        uniffiRustCall { _status ->
-            UniffiLib.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_sign_deterministic(
-                FfiConverterTypeParameterSetId.lower(`param`),
-                FfiConverterByteArray.lower(`signingKey`),
-                FfiConverterByteArray.lower(`msg`),
-                FfiConverterByteArray.lower(`ctx`),
-                _status,
-            )
-        },
-    )
+/**
+ * @suppress
+ */
+public object FfiConverterTypeKeypair: FfiConverterRustBuffer<Keypair> {
+    override fun read(buf: ByteBuffer): Keypair {
+        return Keypair(
+            FfiConverterTypeSlhDsaStatus.read(buf),
+            FfiConverterByteArray.read(buf),
+            FfiConverterByteArray.read(buf),
+        )
+    }
 
-fun `slhDsaSignatureLen`(`param`: ParameterSetId): kotlin.ULong =
-    FfiConverterULong.lift(
-        uniffiRustCall { _status ->
-            UniffiLib.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_signature_len(FfiConverterTypeParameterSetId.lower(`param`), _status)
-        },
+    override fun allocationSize(value: Keypair) = (
+            FfiConverterTypeSlhDsaStatus.allocationSize(value.`status`) +
+            FfiConverterByteArray.allocationSize(value.`signingKey`) +
+            FfiConverterByteArray.allocationSize(value.`verifyingKey`)
     )
 
-fun `slhDsaSigningKeyLen`(`param`: ParameterSetId): kotlin.ULong =
-    FfiConverterULong.lift(
-        uniffiRustCall { _status ->
-            UniffiLib.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_signing_key_len(FfiConverterTypeParameterSetId.lower(`param`), _status)
-        },
-    )
+    override fun write(value: Keypair, buf: ByteBuffer) {
+            FfiConverterTypeSlhDsaStatus.write(value.`status`, buf)
+            FfiConverterByteArray.write(value.`signingKey`, buf)
+            FfiConverterByteArray.write(value.`verifyingKey`, buf)
+    }
+}
 
-fun `slhDsaVerify`(
-    `param`: ParameterSetId,
-    `verifyingKey`: kotlin.ByteArray,
-    `msg`: kotlin.ByteArray,
-    `ctx`: kotlin.ByteArray,
-    `signature`: kotlin.ByteArray,
-): VerifyResult =
-    FfiConverterTypeVerifyResult.lift(
-        uniffiRustCall { _status ->
-            UniffiLib.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_verify(
-                FfiConverterTypeParameterSetId.lower(`param`),
-                FfiConverterByteArray.lower(`verifyingKey`),
-                FfiConverterByteArray.lower(`msg`),
-                FfiConverterByteArray.lower(`ctx`),
-                FfiConverterByteArray.lower(`signature`),
-                _status,
-            )
-        },
-    )
 
-fun `slhDsaVerifyingKeyFromSigningKey`(
-    `param`: ParameterSetId,
-    `signingKey`: kotlin.ByteArray,
-): KeyResult =
-    FfiConverterTypeKeyResult.lift(
-        uniffiRustCall { _status ->
-            UniffiLib.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_verifying_key_from_signing_key(
-                FfiConverterTypeParameterSetId.lower(`param`),
-                FfiConverterByteArray.lower(`signingKey`),
-                _status,
-            )
-        },
-    )
 
-fun `slhDsaVerifyingKeyLen`(`param`: ParameterSetId): kotlin.ULong =
-    FfiConverterULong.lift(
-        uniffiRustCall { _status ->
-            UniffiLib.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_verifying_key_len(FfiConverterTypeParameterSetId.lower(`param`), _status)
-        },
-    )
+data class ParameterSetInfo (
+    var `param`: ParameterSetId, 
+    var `name`: kotlin.String, 
+    var `hashFamily`: HashFamily, 
+    var `fast`: kotlin.Boolean, 
+    var `n`: kotlin.UInt, 
+    var `h`: kotlin.UInt, 
+    var `d`: kotlin.UInt, 
+    var `a`: kotlin.UInt, 
+    var `k`: kotlin.UInt, 
+    var `w`: kotlin.UInt, 
+    var `securityCategory`: kotlin.UByte, 
+    var `oid`: kotlin.String, 
+    var `signingKeyLen`: kotlin.ULong, 
+    var `verifyingKeyLen`: kotlin.ULong, 
+    var `signatureLen`: kotlin.ULong
+) {
+    
+    companion object
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeParameterSetInfo: FfiConverterRustBuffer<ParameterSetInfo> {
+    override fun read(buf: ByteBuffer): ParameterSetInfo {
+        return ParameterSetInfo(
+            FfiConverterTypeParameterSetId.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterTypeHashFamily.read(buf),
+            FfiConverterBoolean.read(buf),
+            FfiConverterUInt.read(buf),
+            FfiConverterUInt.read(buf),
+            FfiConverterUInt.read(buf),
+            FfiConverterUInt.read(buf),
+            FfiConverterUInt.read(buf),
+            FfiConverterUInt.read(buf),
+            FfiConverterUByte.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterULong.read(buf),
+            FfiConverterULong.read(buf),
+            FfiConverterULong.read(buf),
+        )
+    }
+
+    override fun allocationSize(value: ParameterSetInfo) = (
+            FfiConverterTypeParameterSetId.allocationSize(value.`param`) +
+            FfiConverterString.allocationSize(value.`name`) +
+            FfiConverterTypeHashFamily.allocationSize(value.`hashFamily`) +
+            FfiConverterBoolean.allocationSize(value.`fast`) +
+            FfiConverterUInt.allocationSize(value.`n`) +
+            FfiConverterUInt.allocationSize(value.`h`) +
+            FfiConverterUInt.allocationSize(value.`d`) +
+            FfiConverterUInt.allocationSize(value.`a`) +
+            FfiConverterUInt.allocationSize(value.`k`) +
+            FfiConverterUInt.allocationSize(value.`w`) +
+            FfiConverterUByte.allocationSize(value.`securityCategory`) +
+            FfiConverterString.allocationSize(value.`oid`) +
+            FfiConverterULong.allocationSize(value.`signingKeyLen`) +
+            FfiConverterULong.allocationSize(value.`verifyingKeyLen`) +
+            FfiConverterULong.allocationSize(value.`signatureLen`)
+    )
+
+    override fun write(value: ParameterSetInfo, buf: ByteBuffer) {
+            FfiConverterTypeParameterSetId.write(value.`param`, buf)
+            FfiConverterString.write(value.`name`, buf)
+            FfiConverterTypeHashFamily.write(value.`hashFamily`, buf)
+            FfiConverterBoolean.write(value.`fast`, buf)
+            FfiConverterUInt.write(value.`n`, buf)
+            FfiConverterUInt.write(value.`h`, buf)
+            FfiConverterUInt.write(value.`d`, buf)
+            FfiConverterUInt.write(value.`a`, buf)
+            FfiConverterUInt.write(value.`k`, buf)
+            FfiConverterUInt.write(value.`w`, buf)
+            FfiConverterUByte.write(value.`securityCategory`, buf)
+            FfiConverterString.write(value.`oid`, buf)
+            FfiConverterULong.write(value.`signingKeyLen`, buf)
+            FfiConverterULong.write(value.`verifyingKeyLen`, buf)
+            FfiConverterULong.write(value.`signatureLen`, buf)
+    }
+}
+
+
+
+data class SignBatchResult (
+    var `status`: SlhDsaStatus, 
+    var `signatures`: List<kotlin.ByteArray>
+) {
+    
+    companion object
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeSignBatchResult: FfiConverterRustBuffer<SignBatchResult> {
+    override fun read(buf: ByteBuffer): SignBatchResult {
+        return SignBatchResult(
+            FfiConverterTypeSlhDsaStatus.read(buf),
+            FfiConverterSequenceByteArray.read(buf),
+        )
+    }
+
+    override fun allocationSize(value: SignBatchResult) = (
+            FfiConverterTypeSlhDsaStatus.allocationSize(value.`status`) +
+            FfiConverterSequenceByteArray.allocationSize(value.`signatures`)
+    )
+
+    override fun write(value: SignBatchResult, buf: ByteBuffer) {
+            FfiConverterTypeSlhDsaStatus.write(value.`status`, buf)
+            FfiConverterSequenceByteArray.write(value.`signatures`, buf)
+    }
+}
+
+
+
+data class SignResult (
+    var `status`: SlhDsaStatus, 
+    var `signature`: kotlin.ByteArray
+) {
+    
+    companion object
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeSignResult: FfiConverterRustBuffer<SignResult> {
+    override fun read(buf: ByteBuffer): SignResult {
+        return SignResult(
+            FfiConverterTypeSlhDsaStatus.read(buf),
+            FfiConverterByteArray.read(buf),
+        )
+    }
+
+    override fun allocationSize(value: SignResult) = (
+            FfiConverterTypeSlhDsaStatus.allocationSize(value.`status`) +
+            FfiConverterByteArray.allocationSize(value.`signature`)
+    )
+
+    override fun write(value: SignResult, buf: ByteBuffer) {
+            FfiConverterTypeSlhDsaStatus.write(value.`status`, buf)
+            FfiConverterByteArray.write(value.`signature`, buf)
+    }
+}
+
+
+
+data class VerifyResult (
+    var `status`: SlhDsaStatus, 
+    var `valid`: kotlin.Boolean
+) {
+    
+    companion object
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeVerifyResult: FfiConverterRustBuffer<VerifyResult> {
+    override fun read(buf: ByteBuffer): VerifyResult {
+        return VerifyResult(
+            FfiConverterTypeSlhDsaStatus.read(buf),
+            FfiConverterBoolean.read(buf),
+        )
+    }
+
+    override fun allocationSize(value: VerifyResult) = (
+            FfiConverterTypeSlhDsaStatus.allocationSize(value.`status`) +
+            FfiConverterBoolean.allocationSize(value.`valid`)
+    )
+
+    override fun write(value: VerifyResult, buf: ByteBuffer) {
+            FfiConverterTypeSlhDsaStatus.write(value.`status`, buf)
+            FfiConverterBoolean.write(value.`valid`, buf)
+    }
+}
+
+
+
+
+enum class Aead {
+    
+    CHA_CHA20_POLY1305,
+    AES256_GCM;
+    companion object
+}
+
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeAead: FfiConverterRustBuffer<Aead> {
+    override fun read(buf: ByteBuffer) = try {
+        Aead.values()[buf.getInt() - 1]
+    } catch (e: IndexOutOfBoundsException) {
+        throw RuntimeException("invalid enum value, something is very wrong!!", e)
+    }
+
+    override fun allocationSize(value: Aead) = 4UL
+
+    override fun write(value: Aead, buf: ByteBuffer) {
+        buf.putInt(value.ordinal + 1)
+    }
+}
+
+
+
+
+
+
+enum class FingerprintSource {
+    
+    RAW,
+    SPKI;
+    companion object
+}
+
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeFingerprintSource: FfiConverterRustBuffer<FingerprintSource> {
+    override fun read(buf: ByteBuffer) = try {
+        FingerprintSource.values()[buf.getInt() - 1]
+    } catch (e: IndexOutOfBoundsException) {
+        throw RuntimeException("invalid enum value, something is very wrong!!", e)
+    }
+
+    override fun allocationSize(value: FingerprintSource) = 4UL
+
+    override fun write(value: FingerprintSource, buf: ByteBuffer) {
+        buf.putInt(value.ordinal + 1)
+    }
+}
+
+
+
+
+
+
+enum class HashFamily {
+    
+    SHA2,
+    SHAKE;
+    companion object
+}
+
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeHashFamily: FfiConverterRustBuffer<HashFamily> {
+    override fun read(buf: ByteBuffer) = try {
+        HashFamily.values()[buf.getInt() - 1]
+    } catch (e: IndexOutOfBoundsException) {
+        throw RuntimeException("invalid enum value, something is very wrong!!", e)
+    }
+
+    override fun allocationSize(value: HashFamily) = 4UL
+
+    override fun write(value: HashFamily, buf: ByteBuffer) {
+        buf.putInt(value.ordinal + 1)
+    }
+}
+
+
+
+
+
+
+enum class Kdf {
+    
+    ARGON2ID,
+    SCRYPT;
+    companion object
+}
+
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeKdf: FfiConverterRustBuffer<Kdf> {
+    override fun read(buf: ByteBuffer) = try {
+        Kdf.values()[buf.getInt() - 1]
+    } catch (e: IndexOutOfBoundsException) {
+        throw RuntimeException("invalid enum value, something is very wrong!!", e)
+    }
+
+    override fun allocationSize(value: Kdf) = 4UL
+
+    override fun write(value: Kdf, buf: ByteBuffer) {
+        buf.putInt(value.ordinal + 1)
+    }
+}
+
+
+
+
+
+
+enum class ParameterSetId {
+    
+    SHAKE128S,
+    SHAKE128F,
+    SHAKE192S,
+    SHAKE192F,
+    SHAKE256S,
+    SHAKE256F,
+    SHA2_128S,
+    SHA2_128F,
+    SHA2_192S,
+    SHA2_192F,
+    SHA2_256S,
+    SHA2_256F;
+    companion object
+}
+
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeParameterSetId: FfiConverterRustBuffer<ParameterSetId> {
+    override fun read(buf: ByteBuffer) = try {
+        ParameterSetId.values()[buf.getInt() - 1]
+    } catch (e: IndexOutOfBoundsException) {
+        throw RuntimeException("invalid enum value, something is very wrong!!", e)
+    }
+
+    override fun allocationSize(value: ParameterSetId) = 4UL
+
+    override fun write(value: ParameterSetId, buf: ByteBuffer) {
+        buf.putInt(value.ordinal + 1)
+    }
+}
+
+
+
+
+
+
+enum class SignatureMode {
+    
+    PURE,
+    PRE_HASH_SHA256,
+    PRE_HASH_SHA512,
+    PRE_HASH_SHAKE128,
+    PRE_HASH_SHAKE256;
+    companion object
+}
+
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeSignatureMode: FfiConverterRustBuffer<SignatureMode> {
+    override fun read(buf: ByteBuffer) = try {
+        SignatureMode.values()[buf.getInt() - 1]
+    } catch (e: IndexOutOfBoundsException) {
+        throw RuntimeException("invalid enum value, something is very wrong!!", e)
+    }
+
+    override fun allocationSize(value: SignatureMode) = 4UL
+
+    override fun write(value: SignatureMode, buf: ByteBuffer) {
+        buf.putInt(value.ordinal + 1)
+    }
+}
+
+
+
+
+
+
+enum class SlhDsaStatus {
+    
+    OK,
+    INVALID_PARAM,
+    INVALID_LENGTH,
+    DECODE_ERROR,
+    VERIFY_FAILED,
+    CONTEXT_TOO_LONG,
+    MALFORMED_SIGNING_KEY,
+    MALFORMED_VERIFYING_KEY,
+    MALFORMED_SIGNATURE,
+    PARAMETER_SET_MISMATCH,
+    RNG_FAILURE,
+    UNSUPPORTED_ALGORITHM,
+    MALFORMED_ENCODING,
+    INVALID_CERTIFICATE,
+    CERTIFICATE_EXPIRED,
+    UNTRUSTED_CHAIN,
+    TOKEN_EXPIRED,
+    AUDIENCE_MISMATCH,
+    SELF_TEST_FAILED,
+    FAULT_DETECTED,
+    DECRYPTION_FAILED;
+    companion object
+}
+
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeSlhDsaStatus: FfiConverterRustBuffer<SlhDsaStatus> {
+    override fun read(buf: ByteBuffer) = try {
+        SlhDsaStatus.values()[buf.getInt() - 1]
+    } catch (e: IndexOutOfBoundsException) {
+        throw RuntimeException("invalid enum value, something is very wrong!!", e)
+    }
+
+    override fun allocationSize(value: SlhDsaStatus) = 4UL
+
+    override fun write(value: SlhDsaStatus, buf: ByteBuffer) {
+        buf.putInt(value.ordinal + 1)
+    }
+}
+
+
+
+
+
+sealed class SubjectAltName {
+    
+    data class Dns(
+        val `name`: kotlin.String) : SubjectAltName() {
+        companion object
+    }
+    
+    data class Email(
+        val `address`: kotlin.String) : SubjectAltName() {
+        companion object
+    }
+    
+    data class Uri(
+        val `uri`: kotlin.String) : SubjectAltName() {
+        companion object
+    }
+    
+    data class Ip(
+        val `address`: kotlin.ByteArray) : SubjectAltName() {
+        companion object
+    }
+    
+
+    
+    companion object
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeSubjectAltName : FfiConverterRustBuffer<SubjectAltName>{
+    override fun read(buf: ByteBuffer): SubjectAltName {
+        return when(buf.getInt()) {
+            1 -> SubjectAltName.Dns(
+                FfiConverterString.read(buf),
+                )
+            2 -> SubjectAltName.Email(
+                FfiConverterString.read(buf),
+                )
+            3 -> SubjectAltName.Uri(
+                FfiConverterString.read(buf),
+                )
+            4 -> SubjectAltName.Ip(
+                FfiConverterByteArray.read(buf),
+                )
+            else -> throw RuntimeException("invalid enum value, something is very wrong!!")
+        }
+    }
+
+    override fun allocationSize(value: SubjectAltName) = when(value) {
+        is SubjectAltName.Dns -> {
+            // Add the size for the Int that specifies the variant plus the size needed for all fields
+            (
+                4UL
+                + FfiConverterString.allocationSize(value.`name`)
+            )
+        }
+        is SubjectAltName.Email -> {
+            // Add the size for the Int that specifies the variant plus the size needed for all fields
+            (
+                4UL
+                + FfiConverterString.allocationSize(value.`address`)
+            )
+        }
+        is SubjectAltName.Uri -> {
+            // Add the size for the Int that specifies the variant plus the size needed for all fields
+            (
+                4UL
+                + FfiConverterString.allocationSize(value.`uri`)
+            )
+        }
+        is SubjectAltName.Ip -> {
+            // Add the size for the Int that specifies the variant plus the size needed for all fields
+            (
+                4UL
+                + FfiConverterByteArray.allocationSize(value.`address`)
+            )
+        }
+    }
+
+    override fun write(value: SubjectAltName, buf: ByteBuffer) {
+        when(value) {
+            is SubjectAltName.Dns -> {
+                buf.putInt(1)
+                FfiConverterString.write(value.`name`, buf)
+                Unit
+            }
+            is SubjectAltName.Email -> {
+                buf.putInt(2)
+                FfiConverterString.write(value.`address`, buf)
+                Unit
+            }
+            is SubjectAltName.Uri -> {
+                buf.putInt(3)
+                FfiConverterString.write(value.`uri`, buf)
+                Unit
+            }
+            is SubjectAltName.Ip -> {
+                buf.putInt(4)
+                FfiConverterByteArray.write(value.`address`, buf)
+                Unit
+            }
+        }.let { /* this makes the `when` an expression, which ensures it is exhaustive */ }
+    }
+}
+
+
+
+
+
+
+/**
+ * @suppress
+ */
+public object FfiConverterOptionalString: FfiConverterRustBuffer<kotlin.String?> {
+    override fun read(buf: ByteBuffer): kotlin.String? {
+        if (buf.get().toInt() == 0) {
+            return null
+        }
+        return FfiConverterString.read(buf)
+    }
+
+    override fun allocationSize(value: kotlin.String?): ULong {
+        if (value == null) {
+            return 1UL
+        } else {
+            return 1UL + FfiConverterString.allocationSize(value)
+        }
+    }
+
+    override fun write(value: kotlin.String?, buf: ByteBuffer) {
+        if (value == null) {
+            buf.put(0)
+        } else {
+            buf.put(1)
+            FfiConverterString.write(value, buf)
+        }
+    }
+}
+
+
+
+
+/**
+ * @suppress
+ */
+public object FfiConverterOptionalByteArray: FfiConverterRustBuffer<kotlin.ByteArray?> {
+    override fun read(buf: ByteBuffer): kotlin.ByteArray? {
+        if (buf.get().toInt() == 0) {
+            return null
+        }
+        return FfiConverterByteArray.read(buf)
+    }
+
+    override fun allocationSize(value: kotlin.ByteArray?): ULong {
+        if (value == null) {
+            return 1UL
+        } else {
+            return 1UL + FfiConverterByteArray.allocationSize(value)
+        }
+    }
+
+    override fun write(value: kotlin.ByteArray?, buf: ByteBuffer) {
+        if (value == null) {
+            buf.put(0)
+        } else {
+            buf.put(1)
+            FfiConverterByteArray.write(value, buf)
+        }
+    }
+}
+
+
+
+
+/**
+ * @suppress
+ */
+public object FfiConverterOptionalTypeParameterSetId: FfiConverterRustBuffer<ParameterSetId?> {
+    override fun read(buf: ByteBuffer): ParameterSetId? {
+        if (buf.get().toInt() == 0) {
+            return null
+        }
+        return FfiConverterTypeParameterSetId.read(buf)
+    }
+
+    override fun allocationSize(value: ParameterSetId?): ULong {
+        if (value == null) {
+            return 1UL
+        } else {
+            return 1UL + FfiConverterTypeParameterSetId.allocationSize(value)
+        }
+    }
+
+    override fun write(value: ParameterSetId?, buf: ByteBuffer) {
+        if (value == null) {
+            buf.put(0)
+        } else {
+            buf.put(1)
+            FfiConverterTypeParameterSetId.write(value, buf)
+        }
+    }
+}
+
+
+
+
+/**
+ * @suppress
+ */
+public object FfiConverterOptionalTypeSignatureMode: FfiConverterRustBuffer<SignatureMode?> {
+    override fun read(buf: ByteBuffer): SignatureMode? {
+        if (buf.get().toInt() == 0) {
+            return null
+        }
+        return FfiConverterTypeSignatureMode.read(buf)
+    }
+
+    override fun allocationSize(value: SignatureMode?): ULong {
+        if (value == null) {
+            return 1UL
+        } else {
+            return 1UL + FfiConverterTypeSignatureMode.allocationSize(value)
+        }
+    }
+
+    override fun write(value: SignatureMode?, buf: ByteBuffer) {
+        if (value == null) {
+            buf.put(0)
+        } else {
+            buf.put(1)
+            FfiConverterTypeSignatureMode.write(value, buf)
+        }
+    }
+}
+
+
+
+
+/**
+ * @suppress
+ */
+public object FfiConverterSequenceByteArray: FfiConverterRustBuffer<List<kotlin.ByteArray>> {
+    override fun read(buf: ByteBuffer): List<kotlin.ByteArray> {
+        val len = buf.getInt()
+        return List<kotlin.ByteArray>(len) {
+            FfiConverterByteArray.read(buf)
+        }
+    }
+
+    override fun allocationSize(value: List<kotlin.ByteArray>): ULong {
+        val sizeForLength = 4UL
+        val sizeForItems = value.map { FfiConverterByteArray.allocationSize(it) }.sum()
+        return sizeForLength + sizeForItems
+    }
+
+    override fun write(value: List<kotlin.ByteArray>, buf: ByteBuffer) {
+        buf.putInt(value.size)
+        value.iterator().forEach {
+            FfiConverterByteArray.write(it, buf)
+        }
+    }
+}
+
+
+
+
+/**
+ * @suppress
+ */
+public object FfiConverterSequenceTypeCsrExtension: FfiConverterRustBuffer<List<CsrExtension>> {
+    override fun read(buf: ByteBuffer): List<CsrExtension> {
+        val len = buf.getInt()
+        return List<CsrExtension>(len) {
+            FfiConverterTypeCsrExtension.read(buf)
+        }
+    }
+
+    override fun allocationSize(value: List<CsrExtension>): ULong {
+        val sizeForLength = 4UL
+        val sizeForItems = value.map { FfiConverterTypeCsrExtension.allocationSize(it) }.sum()
+        return sizeForLength + sizeForItems
+    }
+
+    override fun write(value: List<CsrExtension>, buf: ByteBuffer) {
+        buf.putInt(value.size)
+        value.iterator().forEach {
+            FfiConverterTypeCsrExtension.write(it, buf)
+        }
+    }
+}
+
+
+
+
+/**
+ * @suppress
+ */
+public object FfiConverterSequenceTypeParameterSetInfo: FfiConverterRustBuffer<List<ParameterSetInfo>> {
+    override fun read(buf: ByteBuffer): List<ParameterSetInfo> {
+        val len = buf.getInt()
+        return List<ParameterSetInfo>(len) {
+            FfiConverterTypeParameterSetInfo.read(buf)
+        }
+    }
+
+    override fun allocationSize(value: List<ParameterSetInfo>): ULong {
+        val sizeForLength = 4UL
+        val sizeForItems = value.map { FfiConverterTypeParameterSetInfo.allocationSize(it) }.sum()
+        return sizeForLength + sizeForItems
+    }
+
+    override fun write(value: List<ParameterSetInfo>, buf: ByteBuffer) {
+        buf.putInt(value.size)
+        value.iterator().forEach {
+            FfiConverterTypeParameterSetInfo.write(it, buf)
+        }
+    }
+}
+
+
+
+
+/**
+ * @suppress
+ */
+public object FfiConverterSequenceTypeSubjectAltName: FfiConverterRustBuffer<List<SubjectAltName>> {
+    override fun read(buf: ByteBuffer): List<SubjectAltName> {
+        val len = buf.getInt()
+        return List<SubjectAltName>(len) {
+            FfiConverterTypeSubjectAltName.read(buf)
+        }
+    }
+
+    override fun allocationSize(value: List<SubjectAltName>): ULong {
+        val sizeForLength = 4UL
+        val sizeForItems = value.map { FfiConverterTypeSubjectAltName.allocationSize(it) }.sum()
+        return sizeForLength + sizeForItems
+    }
+
+    override fun write(value: List<SubjectAltName>, buf: ByteBuffer) {
+        buf.putInt(value.size)
+        value.iterator().forEach {
+            FfiConverterTypeSubjectAltName.write(it, buf)
+        }
+    }
+} fun `slhDsaCoseSign1Create`(`param`: ParameterSetId, `signingKey`: kotlin.ByteArray, `payload`: kotlin.ByteArray, `keyId`: kotlin.ByteArray?, `externalAad`: kotlin.ByteArray, `detached`: kotlin.Boolean): EncodeResult {
+            return FfiConverterTypeEncodeResult.lift(
+    uniffiRustCall() { _status ->
+    UniffiLib.INSTANCE.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_cose_sign1_create(
+        FfiConverterTypeParameterSetId.lower(`param`),FfiConverterByteArray.lower(`signingKey`),FfiConverterByteArray.lower(`payload`),FfiConverterOptionalByteArray.lower(`keyId`),FfiConverterByteArray.lower(`externalAad`),FfiConverterBoolean.lower(`detached`),_status)
+}
+    )
+    }
+    
+ fun `slhDsaCoseSign1Verify`(`verifyingKey`: kotlin.ByteArray, `encoded`: kotlin.ByteArray, `detachedPayload`: kotlin.ByteArray?, `externalAad`: kotlin.ByteArray): CoseSign1Info {
+            return FfiConverterTypeCoseSign1Info.lift(
+    uniffiRustCall() { _status ->
+    UniffiLib.INSTANCE.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_cose_sign1_verify(
+        FfiConverterByteArray.lower(`verifyingKey`),FfiConverterByteArray.lower(`encoded`),FfiConverterOptionalByteArray.lower(`detachedPayload`),FfiConverterByteArray.lower(`externalAad`),_status)
+}
+    )
+    }
+    
+ fun `slhDsaCsrCreate`(`param`: ParameterSetId, `signingKey`: kotlin.ByteArray, `subject`: CsrSubject, `subjectAltNames`: List<SubjectAltName>, `extensions`: List<CsrExtension>, `pem`: kotlin.Boolean): EncodeResult {
+            return FfiConverterTypeEncodeResult.lift(
+    uniffiRustCall() { _status ->
+    UniffiLib.INSTANCE.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_csr_create(
+        FfiConverterTypeParameterSetId.lower(`param`),FfiConverterByteArray.lower(`signingKey`),FfiConverterTypeCsrSubject.lower(`subject`),FfiConverterSequenceTypeSubjectAltName.lower(`subjectAltNames`),FfiConverterSequenceTypeCsrExtension.lower(`extensions`),FfiConverterBoolean.lower(`pem`),_status)
+}
+    )
+    }
+    
+ fun `slhDsaCsrVerify`(`encoded`: kotlin.ByteArray): CsrInfo {
+            return FfiConverterTypeCsrInfo.lift(
+    uniffiRustCall() { _status ->
+    UniffiLib.INSTANCE.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_csr_verify(
+        FfiConverterByteArray.lower(`encoded`),_status)
+}
+    )
+    }
+    
+ fun `slhDsaEnvelopeDecode`(`encoded`: kotlin.ByteArray): EnvelopeInfo {
+            return FfiConverterTypeEnvelopeInfo.lift(
+    uniffiRustCall() { _status ->
+    UniffiLib.INSTANCE.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_envelope_decode(
+        FfiConverterByteArray.lower(`encoded`),_status)
+}
+    )
+    }
+    
+ fun `slhDsaEnvelopeSign`(`param`: ParameterSetId, `signingKey`: kotlin.ByteArray, `payload`: kotlin.ByteArray, `ctx`: kotlin.ByteArray, `keyId`: kotlin.ByteArray?, `mode`: SignatureMode, `detached`: kotlin.Boolean): EncodeResult {
+            return FfiConverterTypeEncodeResult.lift(
+    uniffiRustCall() { _status ->
+    UniffiLib.INSTANCE.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_envelope_sign(
+        FfiConverterTypeParameterSetId.lower(`param`),FfiConverterByteArray.lower(`signingKey`),FfiConverterByteArray.lower(`payload`),FfiConverterByteArray.lower(`ctx`),FfiConverterOptionalByteArray.lower(`keyId`),FfiConverterTypeSignatureMode.lower(`mode`),FfiConverterBoolean.lower(`detached`),_status)
+}
+    )
+    }
+    
+ fun `slhDsaEnvelopeVerify`(`verifyingKey`: kotlin.ByteArray, `encoded`: kotlin.ByteArray, `detachedPayload`: kotlin.ByteArray?): EnvelopeInfo {
+            return FfiConverterTypeEnvelopeInfo.lift(
+    uniffiRustCall() { _status ->
+    UniffiLib.INSTANCE.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_envelope_verify(
+        FfiConverterByteArray.lower(`verifyingKey`),FfiConverterByteArray.lower(`encoded`),FfiConverterOptionalByteArray.lower(`detachedPayload`),_status)
+}
+    )
+    }
+    
+ fun `slhDsaFingerprint`(`param`: ParameterSetId, `verifyingKey`: kotlin.ByteArray, `source`: FingerprintSource): FingerprintResult {
+            return FfiConverterTypeFingerprintResult.lift(
+    uniffiRustCall() { _status ->
+    UniffiLib.INSTANCE.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_fingerprint(
+        FfiConverterTypeParameterSetId.lower(`param`),FfiConverterByteArray.lower(`verifyingKey`),FfiConverterTypeFingerprintSource.lower(`source`),_status)
+}
+    )
+    }
+    
+ fun `slhDsaKeyFileLen`(`param`: ParameterSetId): kotlin.ULong {
+            return FfiConverterULong.lift(
+    uniffiRustCall() { _status ->
+    UniffiLib.INSTANCE.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_key_file_len(
+        FfiConverterTypeParameterSetId.lower(`param`),_status)
+}
+    )
+    }
+    
+ fun `slhDsaKeypairFromSeed`(`param`: ParameterSetId, `skSeed`: kotlin.ByteArray, `skPrf`: kotlin.ByteArray, `pkSeed`: kotlin.ByteArray): Keypair {
+            return FfiConverterTypeKeypair.lift(
+    uniffiRustCall() { _status ->
+    UniffiLib.INSTANCE.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_keypair_from_seed(
+        FfiConverterTypeParameterSetId.lower(`param`),FfiConverterByteArray.lower(`skSeed`),FfiConverterByteArray.lower(`skPrf`),FfiConverterByteArray.lower(`pkSeed`),_status)
+}
+    )
+    }
+    
+ fun `slhDsaKeypairGenerate`(`param`: ParameterSetId): Keypair {
+            return FfiConverterTypeKeypair.lift(
+    uniffiRustCall() { _status ->
+    UniffiLib.INSTANCE.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_keypair_generate(
+        FfiConverterTypeParameterSetId.lower(`param`),_status)
+}
+    )
+    }
+    
+ fun `slhDsaParameterName`(`param`: ParameterSetId): kotlin.String {
+            return FfiConverterString.lift(
+    uniffiRustCall() { _status ->
+    UniffiLib.INSTANCE.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_parameter_name(
+        FfiConverterTypeParameterSetId.lower(`param`),_status)
+}
+    )
+    }
+    
+ fun `slhDsaParameterSetFromName`(`name`: kotlin.String): ParameterSetId? {
+            return FfiConverterOptionalTypeParameterSetId.lift(
+    uniffiRustCall() { _status ->
+    UniffiLib.INSTANCE.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_parameter_set_from_name(
+        FfiConverterString.lower(`name`),_status)
+}
+    )
+    }
+    
+ fun `slhDsaParameterSetFromOid`(`oid`: kotlin.String): ParameterSetId? {
+            return FfiConverterOptionalTypeParameterSetId.lift(
+    uniffiRustCall() { _status ->
+    UniffiLib.INSTANCE.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_parameter_set_from_oid(
+        FfiConverterString.lower(`oid`),_status)
+}
+    )
+    }
+    
+ fun `slhDsaParameterSets`(): List<ParameterSetInfo> {
+            return FfiConverterSequenceTypeParameterSetInfo.lift(
+    uniffiRustCall() { _status ->
+    UniffiLib.INSTANCE.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_parameter_sets(
+        _status)
+}
+    )
+    }
+    
+ fun `slhDsaSeedLen`(`param`: ParameterSetId): kotlin.ULong {
+            return FfiConverterULong.lift(
+    uniffiRustCall() { _status ->
+    UniffiLib.INSTANCE.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_seed_len(
+        FfiConverterTypeParameterSetId.lower(`param`),_status)
+}
+    )
+    }
+    
+ fun `slhDsaSetVerifyAfterSign`(`enabled`: kotlin.Boolean)
+        = 
+    uniffiRustCall() { _status ->
+    UniffiLib.INSTANCE.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_set_verify_after_sign(
+        FfiConverterBoolean.lower(`enabled`),_status)
+}
+    
+    
+ fun `slhDsaSign`(`param`: ParameterSetId, `signingKey`: kotlin.ByteArray, `msg`: kotlin.ByteArray, `ctx`: kotlin.ByteArray): SignResult {
+            return FfiConverterTypeSignResult.lift(
+    uniffiRustCall() { _status ->
+    UniffiLib.INSTANCE.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_sign(
+        FfiConverterTypeParameterSetId.lower(`param`),FfiConverterByteArray.lower(`signingKey`),FfiConverterByteArray.lower(`msg`),FfiConverterByteArray.lower(`ctx`),_status)
+}
+    )
+    }
+    
+ fun `slhDsaSignBatch`(`param`: ParameterSetId, `signingKey`: kotlin.ByteArray, `msgs`: List<kotlin.ByteArray>, `ctx`: kotlin.ByteArray): SignBatchResult {
+            return FfiConverterTypeSignBatchResult.lift(
+    uniffiRustCall() { _status ->
+    UniffiLib.INSTANCE.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_sign_batch(
+        FfiConverterTypeParameterSetId.lower(`param`),FfiConverterByteArray.lower(`signingKey`),FfiConverterSequenceByteArray.lower(`msgs`),FfiConverterByteArray.lower(`ctx`),_status)
+}
+    )
+    }
+    
+ fun `slhDsaSignChecked`(`param`: ParameterSetId, `signingKey`: kotlin.ByteArray, `msg`: kotlin.ByteArray, `ctx`: kotlin.ByteArray): SignResult {
+            return FfiConverterTypeSignResult.lift(
+    uniffiRustCall() { _status ->
+    UniffiLib.INSTANCE.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_sign_checked(
+        FfiConverterTypeParameterSetId.lower(`param`),FfiConverterByteArray.lower(`signingKey`),FfiConverterByteArray.lower(`msg`),FfiConverterByteArray.lower(`ctx`),_status)
+}
+    )
+    }
+    
+ fun `slhDsaSignDeterministic`(`param`: ParameterSetId, `signingKey`: kotlin.ByteArray, `msg`: kotlin.ByteArray, `ctx`: kotlin.ByteArray): SignResult {
+            return FfiConverterTypeSignResult.lift(
+    uniffiRustCall() { _status ->
+    UniffiLib.INSTANCE.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_sign_deterministic(
+        FfiConverterTypeParameterSetId.lower(`param`),FfiConverterByteArray.lower(`signingKey`),FfiConverterByteArray.lower(`msg`),FfiConverterByteArray.lower(`ctx`),_status)
+}
+    )
+    }
+    
+ fun `slhDsaSignatureLen`(`param`: ParameterSetId): kotlin.ULong {
+            return FfiConverterULong.lift(
+    uniffiRustCall() { _status ->
+    UniffiLib.INSTANCE.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_signature_len(
+        FfiConverterTypeParameterSetId.lower(`param`),_status)
+}
+    )
+    }
+    
+ fun `slhDsaSigningKeyFromCoseKey`(`encoded`: kotlin.ByteArray): DecodeKeyResult {
+            return FfiConverterTypeDecodeKeyResult.lift(
+    uniffiRustCall() { _status ->
+    UniffiLib.INSTANCE.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_signing_key_from_cose_key(
+        FfiConverterByteArray.lower(`encoded`),_status)
+}
+    )
+    }
+    
+ fun `slhDsaSigningKeyFromEncryptedPkcs8`(`encoded`: kotlin.ByteArray, `passphrase`: kotlin.ByteArray): DecodeKeyResult {
+            return FfiConverterTypeDecodeKeyResult.lift(
+    uniffiRustCall() { _status ->
+    UniffiLib.INSTANCE.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_signing_key_from_encrypted_pkcs8(
+        FfiConverterByteArray.lower(`encoded`),FfiConverterByteArray.lower(`passphrase`),_status)
+}
+    )
+    }
+    
+ fun `slhDsaSigningKeyFromKeyFile`(`encoded`: kotlin.ByteArray, `passphrase`: kotlin.ByteArray): DecodeKeyResult {
+            return FfiConverterTypeDecodeKeyResult.lift(
+    uniffiRustCall() { _status ->
+    UniffiLib.INSTANCE.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_signing_key_from_key_file(
+        FfiConverterByteArray.lower(`encoded`),FfiConverterByteArray.lower(`passphrase`),_status)
+}
+    )
+    }
+    
+ fun `slhDsaSigningKeyFromPkcs8`(`encoded`: kotlin.ByteArray): DecodeKeyResult {
+            return FfiConverterTypeDecodeKeyResult.lift(
+    uniffiRustCall() { _status ->
+    UniffiLib.INSTANCE.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_signing_key_from_pkcs8(
+        FfiConverterByteArray.lower(`encoded`),_status)
+}
+    )
+    }
+    
+ fun `slhDsaSigningKeyLen`(`param`: ParameterSetId): kotlin.ULong {
+            return FfiConverterULong.lift(
+    uniffiRustCall() { _status ->
+    UniffiLib.INSTANCE.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_signing_key_len(
+        FfiConverterTypeParameterSetId.lower(`param`),_status)
+}
+    )
+    }
+    
+ fun `slhDsaSigningKeyToCoseKey`(`param`: ParameterSetId, `signingKey`: kotlin.ByteArray): EncodeResult {
+            return FfiConverterTypeEncodeResult.lift(
+    uniffiRustCall() { _status ->
+    UniffiLib.INSTANCE.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_signing_key_to_cose_key(
+        FfiConverterTypeParameterSetId.lower(`param`),FfiConverterByteArray.lower(`signingKey`),_status)
+}
+    )
+    }
+    
+ fun `slhDsaSigningKeyToEncryptedPkcs8`(`param`: ParameterSetId, `signingKey`: kotlin.ByteArray, `passphrase`: kotlin.ByteArray, `pem`: kotlin.Boolean): EncodeResult {
+            return FfiConverterTypeEncodeResult.lift(
+    uniffiRustCall() { _status ->
+    UniffiLib.INSTANCE.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_signing_key_to_encrypted_pkcs8(
+        FfiConverterTypeParameterSetId.lower(`param`),FfiConverterByteArray.lower(`signingKey`),FfiConverterByteArray.lower(`passphrase`),FfiConverterBoolean.lower(`pem`),_status)
+}
+    )
+    }
+    
+ fun `slhDsaSigningKeyToKeyFile`(`param`: ParameterSetId, `signingKey`: kotlin.ByteArray, `passphrase`: kotlin.ByteArray, `kdf`: Kdf, `aead`: Aead): EncodeResult {
+            return FfiConverterTypeEncodeResult.lift(
+    uniffiRustCall() { _status ->
+    UniffiLib.INSTANCE.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_signing_key_to_key_file(
+        FfiConverterTypeParameterSetId.lower(`param`),FfiConverterByteArray.lower(`signingKey`),FfiConverterByteArray.lower(`passphrase`),FfiConverterTypeKdf.lower(`kdf`),FfiConverterTypeAead.lower(`aead`),_status)
+}
+    )
+    }
+    
+ fun `slhDsaSigningKeyToPkcs8`(`param`: ParameterSetId, `signingKey`: kotlin.ByteArray, `pem`: kotlin.Boolean): EncodeResult {
+            return FfiConverterTypeEncodeResult.lift(
+    uniffiRustCall() { _status ->
+    UniffiLib.INSTANCE.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_signing_key_to_pkcs8(
+        FfiConverterTypeParameterSetId.lower(`param`),FfiConverterByteArray.lower(`signingKey`),FfiConverterBoolean.lower(`pem`),_status)
+}
+    )
+    }
+    
+ fun `slhDsaValidateSigningKey`(`param`: ParameterSetId, `signingKey`: kotlin.ByteArray): SlhDsaStatus {
+            return FfiConverterTypeSlhDsaStatus.lift(
+    uniffiRustCall() { _status ->
+    UniffiLib.INSTANCE.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_validate_signing_key(
+        FfiConverterTypeParameterSetId.lower(`param`),FfiConverterByteArray.lower(`signingKey`),_status)
+}
+    )
+    }
+    
+ fun `slhDsaVerify`(`param`: ParameterSetId, `verifyingKey`: kotlin.ByteArray, `msg`: kotlin.ByteArray, `ctx`: kotlin.ByteArray, `signature`: kotlin.ByteArray): VerifyResult {
+            return FfiConverterTypeVerifyResult.lift(
+    uniffiRustCall() { _status ->
+    UniffiLib.INSTANCE.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_verify(
+        FfiConverterTypeParameterSetId.lower(`param`),FfiConverterByteArray.lower(`verifyingKey`),FfiConverterByteArray.lower(`msg`),FfiConverterByteArray.lower(`ctx`),FfiConverterByteArray.lower(`signature`),_status)
+}
+    )
+    }
+    
+ fun `slhDsaVerifyAfterSignEnabled`(): kotlin.Boolean {
+            return FfiConverterBoolean.lift(
+    uniffiRustCall() { _status ->
+    UniffiLib.INSTANCE.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_verify_after_sign_enabled(
+        _status)
+}
+    )
+    }
+    
+ fun `slhDsaVerifyingKeyFromCoseKey`(`encoded`: kotlin.ByteArray): DecodeKeyResult {
+            return FfiConverterTypeDecodeKeyResult.lift(
+    uniffiRustCall() { _status ->
+    UniffiLib.INSTANCE.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_verifying_key_from_cose_key(
+        FfiConverterByteArray.lower(`encoded`),_status)
+}
+    )
+    }
+    
+ fun `slhDsaVerifyingKeyFromSigningKey`(`param`: ParameterSetId, `signingKey`: kotlin.ByteArray): KeyResult {
+            return FfiConverterTypeKeyResult.lift(
+    uniffiRustCall() { _status ->
+    UniffiLib.INSTANCE.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_verifying_key_from_signing_key(
+        FfiConverterTypeParameterSetId.lower(`param`),FfiConverterByteArray.lower(`signingKey`),_status)
+}
+    )
+    }
+    
+ fun `slhDsaVerifyingKeyFromSigningKeyValidated`(`param`: ParameterSetId, `signingKey`: kotlin.ByteArray): KeyResult {
+            return FfiConverterTypeKeyResult.lift(
+    uniffiRustCall() { _status ->
+    UniffiLib.INSTANCE.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_verifying_key_from_signing_key_validated(
+        FfiConverterTypeParameterSetId.lower(`param`),FfiConverterByteArray.lower(`signingKey`),_status)
+}
+    )
+    }
+    
+ fun `slhDsaVerifyingKeyFromSpki`(`encoded`: kotlin.ByteArray): DecodeKeyResult {
+            return FfiConverterTypeDecodeKeyResult.lift(
+    uniffiRustCall() { _status ->
+    UniffiLib.INSTANCE.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_verifying_key_from_spki(
+        FfiConverterByteArray.lower(`encoded`),_status)
+}
+    )
+    }
+    
+ fun `slhDsaVerifyingKeyLen`(`param`: ParameterSetId): kotlin.ULong {
+            return FfiConverterULong.lift(
+    uniffiRustCall() { _status ->
+    UniffiLib.INSTANCE.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_verifying_key_len(
+        FfiConverterTypeParameterSetId.lower(`param`),_status)
+}
+    )
+    }
+    
+ fun `slhDsaVerifyingKeyToCoseKey`(`param`: ParameterSetId, `verifyingKey`: kotlin.ByteArray): EncodeResult {
+            return FfiConverterTypeEncodeResult.lift(
+    uniffiRustCall() { _status ->
+    UniffiLib.INSTANCE.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_verifying_key_to_cose_key(
+        FfiConverterTypeParameterSetId.lower(`param`),FfiConverterByteArray.lower(`verifyingKey`),_status)
+}
+    )
+    }
+    
+ fun `slhDsaVerifyingKeyToSpki`(`param`: ParameterSetId, `verifyingKey`: kotlin.ByteArray, `pem`: kotlin.Boolean): EncodeResult {
+            return FfiConverterTypeEncodeResult.lift(
+    uniffiRustCall() { _status ->
+    UniffiLib.INSTANCE.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_verifying_key_to_spki(
+        FfiConverterTypeParameterSetId.lower(`param`),FfiConverterByteArray.lower(`verifyingKey`),FfiConverterBoolean.lower(`pem`),_status)
+}
+    )
+    }
+    
+
+
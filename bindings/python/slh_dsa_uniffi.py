@@ -1,3 +1,5 @@
+
+
 # This file was autogenerated by some hot garbage in the `uniffi` crate.
 # Trust me, you don't want to mess with it!
 
@@ -17,7 +19,6 @@ from __future__ import annotations
 import os
 import sys
 import ctypes
-from dataclasses import dataclass
 import enum
 import struct
 import contextlib
@@ -28,7 +29,6 @@ import traceback
 import typing
 import platform
 
-
 # Used for default argument values
 _DEFAULT = object() # type: typing.Any
 
@@ -87,7 +87,7 @@ class _UniffiRustBuffer(ctypes.Structure):
             s = _UniffiRustBufferStream.from_rust_buffer(self)
             yield s
             if s.remaining() != 0:
-                raise RuntimeError(f"junk data left in buffer at end of consume_with_stream {s.remaining()}")
+                raise RuntimeError("junk data left in buffer at end of consume_with_stream")
         finally:
             self.free()
 
@@ -101,7 +101,7 @@ class _UniffiRustBuffer(ctypes.Structure):
         s = _UniffiRustBufferStream.from_rust_buffer(self)
         yield s
         if s.remaining() != 0:
-            raise RuntimeError(f"junk data left in buffer at end of read_with_stream {s.remaining()}")
+            raise RuntimeError("junk data left in buffer at end of read_with_stream")
 
 class _UniffiForeignBytes(ctypes.Structure):
     _fields_ = [
@@ -308,7 +308,7 @@ def _uniffi_check_call_status(error_ffi_converter, call_status):
         # with the message.  But if that code panics, then it just sends back
         # an empty buffer.
         if call_status.error_buf.len > 0:
-            msg = _UniffiFfiConverterString.lift(call_status.error_buf)
+            msg = _UniffiConverterString.lift(call_status.error_buf)
         else:
             msg = "Unknown rust panic"
         raise InternalError(msg)
@@ -321,7 +321,7 @@ def _uniffi_trait_interface_call(call_status, make_call, write_return_value):
         return write_return_value(make_call())
     except Exception as e:
         call_status.code = _UniffiRustCallStatus.CALL_UNEXPECTED_ERROR
-        call_status.error_buf = _UniffiFfiConverterString.lower(repr(e))
+        call_status.error_buf = _UniffiConverterString.lower(repr(e))
 
 def _uniffi_trait_interface_call_with_error(call_status, make_call, write_return_value, error_type, lower_error):
     try:
@@ -332,12 +332,7 @@ def _uniffi_trait_interface_call_with_error(call_status, make_call, write_return
             call_status.error_buf = lower_error(e)
     except Exception as e:
         call_status.code = _UniffiRustCallStatus.CALL_UNEXPECTED_ERROR
-        call_status.error_buf = _UniffiFfiConverterString.lower(repr(e))
-# Initial value and increment amount for handles. 
-# These ensure that Python-generated handles always have the lowest bit set
-_UNIFFI_HANDLEMAP_INITIAL = 1
-_UNIFFI_HANDLEMAP_DELTA = 2
-
+        call_status.error_buf = _UniffiConverterString.lower(repr(e))
 class _UniffiHandleMap:
     """
     A map where inserting, getting and removing data is synchronized with a lock.
@@ -347,40 +342,27 @@ class _UniffiHandleMap:
         # type Handle = int
         self._map = {}  # type: Dict[Handle, Any]
         self._lock = threading.Lock()
-        self._counter = _UNIFFI_HANDLEMAP_INITIAL
+        self._counter = itertools.count()
 
     def insert(self, obj):
         with self._lock:
-            return self._insert(obj)
-
-    """Low-level insert, this assumes `self._lock` is held."""
-    def _insert(self, obj):
-        handle = self._counter
-        self._counter += _UNIFFI_HANDLEMAP_DELTA
-        self._map[handle] = obj
-        return handle
+            handle = next(self._counter)
+            self._map[handle] = obj
+            return handle
 
     def get(self, handle):
         try:
             with self._lock:
                 return self._map[handle]
         except KeyError:
-            raise InternalError(f"_UniffiHandleMap.get: Invalid handle {handle}")
-
-    def clone(self, handle):
-        try:
-            with self._lock:
-                obj = self._map[handle]
-                return self._insert(obj)
-        except KeyError:
-            raise InternalError(f"_UniffiHandleMap.clone: Invalid handle {handle}")
+            raise InternalError("_UniffiHandleMap.get: Invalid handle")
 
     def remove(self, handle):
         try:
             with self._lock:
                 return self._map.pop(handle)
         except KeyError:
-            raise InternalError(f"_UniffiHandleMap.remove: Invalid handle: {handle}")
+            raise InternalError("_UniffiHandleMap.remove: Invalid handle")
 
     def __len__(self):
         return len(self._map)
@@ -471,330 +453,411 @@ def _uniffi_load_indirect():
 
 def _uniffi_check_contract_api_version(lib):
     # Get the bindings contract version from our ComponentInterface
-    bindings_contract_version = 30
+    bindings_contract_version = 29
     # Get the scaffolding contract version by calling the into the dylib
     scaffolding_contract_version = lib.ffi_slh_dsa_uniffi_uniffi_contract_version()
     if bindings_contract_version != scaffolding_contract_version:
         raise InternalError("UniFFI contract version mismatch: try cleaning and rebuilding your project")
 
 def _uniffi_check_api_checksums(lib):
+    if lib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_cose_sign1_create() != 11547:
+        raise InternalError("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    if lib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_cose_sign1_verify() != 34918:
+        raise InternalError("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    if lib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_csr_create() != 38104:
+        raise InternalError("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    if lib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_csr_verify() != 54660:
+        raise InternalError("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    if lib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_envelope_decode() != 43712:
+        raise InternalError("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    if lib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_envelope_sign() != 62941:
+        raise InternalError("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    if lib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_envelope_verify() != 55803:
+        raise InternalError("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    if lib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_fingerprint() != 23530:
+        raise InternalError("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    if lib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_key_file_len() != 21678:
+        raise InternalError("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    if lib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_keypair_from_seed() != 31227:
+        raise InternalError("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
     if lib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_keypair_generate() != 7757:
         raise InternalError("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
     if lib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_parameter_name() != 63153:
         raise InternalError("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    if lib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_parameter_set_from_name() != 56401:
+        raise InternalError("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    if lib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_parameter_set_from_oid() != 48394:
+        raise InternalError("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    if lib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_parameter_sets() != 26697:
+        raise InternalError("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    if lib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_seed_len() != 18769:
+        raise InternalError("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    if lib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_set_verify_after_sign() != 6374:
+        raise InternalError("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
     if lib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_sign() != 51560:
         raise InternalError("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    if lib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_sign_batch() != 55765:
+        raise InternalError("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    if lib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_sign_checked() != 30107:
+        raise InternalError("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
     if lib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_sign_deterministic() != 23979:
         raise InternalError("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
     if lib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_signature_len() != 62991:
         raise InternalError("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    if lib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_signing_key_from_cose_key() != 34229:
+        raise InternalError("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    if lib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_signing_key_from_encrypted_pkcs8() != 19253:
+        raise InternalError("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    if lib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_signing_key_from_key_file() != 53092:
+        raise InternalError("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    if lib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_signing_key_from_pkcs8() != 17809:
+        raise InternalError("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
     if lib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_signing_key_len() != 40535:
         raise InternalError("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    if lib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_signing_key_to_cose_key() != 51163:
+        raise InternalError("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    if lib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_signing_key_to_encrypted_pkcs8() != 36675:
+        raise InternalError("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    if lib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_signing_key_to_key_file() != 43469:
+        raise InternalError("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    if lib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_signing_key_to_pkcs8() != 52043:
+        raise InternalError("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    if lib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_validate_signing_key() != 6415:
+        raise InternalError("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
     if lib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_verify() != 22241:
         raise InternalError("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    if lib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_verify_after_sign_enabled() != 43959:
+        raise InternalError("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    if lib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_verifying_key_from_cose_key() != 62128:
+        raise InternalError("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
     if lib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_verifying_key_from_signing_key() != 64766:
         raise InternalError("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    if lib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_verifying_key_from_signing_key_validated() != 37694:
+        raise InternalError("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    if lib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_verifying_key_from_spki() != 1557:
+        raise InternalError("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
     if lib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_verifying_key_len() != 16179:
         raise InternalError("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    if lib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_verifying_key_to_cose_key() != 45780:
+        raise InternalError("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    if lib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_verifying_key_to_spki() != 37838:
+        raise InternalError("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
 
 # A ctypes library to expose the extern-C FFI definitions.
 # This is an implementation detail which will be called internally by the public API.
 
 _UniffiLib = _uniffi_load_indirect()
-_UniffiLib.ffi_slh_dsa_uniffi_rustbuffer_alloc.argtypes = (
-    ctypes.c_uint64,
-    ctypes.POINTER(_UniffiRustCallStatus),
-)
-_UniffiLib.ffi_slh_dsa_uniffi_rustbuffer_alloc.restype = _UniffiRustBuffer
-_UniffiLib.ffi_slh_dsa_uniffi_rustbuffer_from_bytes.argtypes = (
-    _UniffiForeignBytes,
-    ctypes.POINTER(_UniffiRustCallStatus),
-)
-_UniffiLib.ffi_slh_dsa_uniffi_rustbuffer_from_bytes.restype = _UniffiRustBuffer
-_UniffiLib.ffi_slh_dsa_uniffi_rustbuffer_free.argtypes = (
-    _UniffiRustBuffer,
-    ctypes.POINTER(_UniffiRustCallStatus),
-)
-_UniffiLib.ffi_slh_dsa_uniffi_rustbuffer_free.restype = None
-_UniffiLib.ffi_slh_dsa_uniffi_rustbuffer_reserve.argtypes = (
-    _UniffiRustBuffer,
-    ctypes.c_uint64,
-    ctypes.POINTER(_UniffiRustCallStatus),
-)
-_UniffiLib.ffi_slh_dsa_uniffi_rustbuffer_reserve.restype = _UniffiRustBuffer
 _UNIFFI_RUST_FUTURE_CONTINUATION_CALLBACK = ctypes.CFUNCTYPE(None,ctypes.c_uint64,ctypes.c_int8,
 )
-_UNIFFI_FOREIGN_FUTURE_DROPPED_CALLBACK = ctypes.CFUNCTYPE(None,ctypes.c_uint64,
+_UNIFFI_FOREIGN_FUTURE_FREE = ctypes.CFUNCTYPE(None,ctypes.c_uint64,
 )
-class _UniffiForeignFutureDroppedCallbackStruct(ctypes.Structure):
+_UNIFFI_CALLBACK_INTERFACE_FREE = ctypes.CFUNCTYPE(None,ctypes.c_uint64,
+)
+class _UniffiForeignFuture(ctypes.Structure):
     _fields_ = [
         ("handle", ctypes.c_uint64),
-        ("free", _UNIFFI_FOREIGN_FUTURE_DROPPED_CALLBACK),
+        ("free", _UNIFFI_FOREIGN_FUTURE_FREE),
     ]
-_UniffiLib.ffi_slh_dsa_uniffi_rust_future_poll_u8.argtypes = (
-    ctypes.c_uint64,
-    _UNIFFI_RUST_FUTURE_CONTINUATION_CALLBACK,
-    ctypes.c_uint64,
-)
-_UniffiLib.ffi_slh_dsa_uniffi_rust_future_poll_u8.restype = None
-_UniffiLib.ffi_slh_dsa_uniffi_rust_future_cancel_u8.argtypes = (
-    ctypes.c_uint64,
+class _UniffiForeignFutureStructU8(ctypes.Structure):
+    _fields_ = [
+        ("return_value", ctypes.c_uint8),
+        ("call_status", _UniffiRustCallStatus),
+    ]
+_UNIFFI_FOREIGN_FUTURE_COMPLETE_U8 = ctypes.CFUNCTYPE(None,ctypes.c_uint64,_UniffiForeignFutureStructU8,
 )
-_UniffiLib.ffi_slh_dsa_uniffi_rust_future_cancel_u8.restype = None
-_UniffiLib.ffi_slh_dsa_uniffi_rust_future_complete_u8.argtypes = (
-    ctypes.c_uint64,
-    ctypes.POINTER(_UniffiRustCallStatus),
+class _UniffiForeignFutureStructI8(ctypes.Structure):
+    _fields_ = [
+        ("return_value", ctypes.c_int8),
+        ("call_status", _UniffiRustCallStatus),
+    ]
+_UNIFFI_FOREIGN_FUTURE_COMPLETE_I8 = ctypes.CFUNCTYPE(None,ctypes.c_uint64,_UniffiForeignFutureStructI8,
 )
-_UniffiLib.ffi_slh_dsa_uniffi_rust_future_complete_u8.restype = ctypes.c_uint8
-_UniffiLib.ffi_slh_dsa_uniffi_rust_future_free_u8.argtypes = (
-    ctypes.c_uint64,
+class _UniffiForeignFutureStructU16(ctypes.Structure):
+    _fields_ = [
+        ("return_value", ctypes.c_uint16),
+        ("call_status", _UniffiRustCallStatus),
+    ]
+_UNIFFI_FOREIGN_FUTURE_COMPLETE_U16 = ctypes.CFUNCTYPE(None,ctypes.c_uint64,_UniffiForeignFutureStructU16,
 )
-_UniffiLib.ffi_slh_dsa_uniffi_rust_future_free_u8.restype = None
-_UniffiLib.ffi_slh_dsa_uniffi_rust_future_poll_i8.argtypes = (
-    ctypes.c_uint64,
-    _UNIFFI_RUST_FUTURE_CONTINUATION_CALLBACK,
-    ctypes.c_uint64,
+class _UniffiForeignFutureStructI16(ctypes.Structure):
+    _fields_ = [
+        ("return_value", ctypes.c_int16),
+        ("call_status", _UniffiRustCallStatus),
+    ]
+_UNIFFI_FOREIGN_FUTURE_COMPLETE_I16 = ctypes.CFUNCTYPE(None,ctypes.c_uint64,_UniffiForeignFutureStructI16,
 )
-_UniffiLib.ffi_slh_dsa_uniffi_rust_future_poll_i8.restype = None
-_UniffiLib.ffi_slh_dsa_uniffi_rust_future_cancel_i8.argtypes = (
-    ctypes.c_uint64,
+class _UniffiForeignFutureStructU32(ctypes.Structure):
+    _fields_ = [
+        ("return_value", ctypes.c_uint32),
+        ("call_status", _UniffiRustCallStatus),
+    ]
+_UNIFFI_FOREIGN_FUTURE_COMPLETE_U32 = ctypes.CFUNCTYPE(None,ctypes.c_uint64,_UniffiForeignFutureStructU32,
 )
-_UniffiLib.ffi_slh_dsa_uniffi_rust_future_cancel_i8.restype = None
-_UniffiLib.ffi_slh_dsa_uniffi_rust_future_complete_i8.argtypes = (
-    ctypes.c_uint64,
-    ctypes.POINTER(_UniffiRustCallStatus),
+class _UniffiForeignFutureStructI32(ctypes.Structure):
+    _fields_ = [
+        ("return_value", ctypes.c_int32),
+        ("call_status", _UniffiRustCallStatus),
+    ]
+_UNIFFI_FOREIGN_FUTURE_COMPLETE_I32 = ctypes.CFUNCTYPE(None,ctypes.c_uint64,_UniffiForeignFutureStructI32,
 )
-_UniffiLib.ffi_slh_dsa_uniffi_rust_future_complete_i8.restype = ctypes.c_int8
-_UniffiLib.ffi_slh_dsa_uniffi_rust_future_free_i8.argtypes = (
-    ctypes.c_uint64,
+class _UniffiForeignFutureStructU64(ctypes.Structure):
+    _fields_ = [
+        ("return_value", ctypes.c_uint64),
+        ("call_status", _UniffiRustCallStatus),
+    ]
+_UNIFFI_FOREIGN_FUTURE_COMPLETE_U64 = ctypes.CFUNCTYPE(None,ctypes.c_uint64,_UniffiForeignFutureStructU64,
 )
-_UniffiLib.ffi_slh_dsa_uniffi_rust_future_free_i8.restype = None
-_UniffiLib.ffi_slh_dsa_uniffi_rust_future_poll_u16.argtypes = (
-    ctypes.c_uint64,
-    _UNIFFI_RUST_FUTURE_CONTINUATION_CALLBACK,
-    ctypes.c_uint64,
+class _UniffiForeignFutureStructI64(ctypes.Structure):
+    _fields_ = [
+        ("return_value", ctypes.c_int64),
+        ("call_status", _UniffiRustCallStatus),
+    ]
+_UNIFFI_FOREIGN_FUTURE_COMPLETE_I64 = ctypes.CFUNCTYPE(None,ctypes.c_uint64,_UniffiForeignFutureStructI64,
 )
-_UniffiLib.ffi_slh_dsa_uniffi_rust_future_poll_u16.restype = None
-_UniffiLib.ffi_slh_dsa_uniffi_rust_future_cancel_u16.argtypes = (
-    ctypes.c_uint64,
+class _UniffiForeignFutureStructF32(ctypes.Structure):
+    _fields_ = [
+        ("return_value", ctypes.c_float),
+        ("call_status", _UniffiRustCallStatus),
+    ]
+_UNIFFI_FOREIGN_FUTURE_COMPLETE_F32 = ctypes.CFUNCTYPE(None,ctypes.c_uint64,_UniffiForeignFutureStructF32,
 )
-_UniffiLib.ffi_slh_dsa_uniffi_rust_future_cancel_u16.restype = None
-_UniffiLib.ffi_slh_dsa_uniffi_rust_future_complete_u16.argtypes = (
-    ctypes.c_uint64,
-    ctypes.POINTER(_UniffiRustCallStatus),
+class _UniffiForeignFutureStructF64(ctypes.Structure):
+    _fields_ = [
+        ("return_value", ctypes.c_double),
+        ("call_status", _UniffiRustCallStatus),
+    ]
+_UNIFFI_FOREIGN_FUTURE_COMPLETE_F64 = ctypes.CFUNCTYPE(None,ctypes.c_uint64,_UniffiForeignFutureStructF64,
 )
-_UniffiLib.ffi_slh_dsa_uniffi_rust_future_complete_u16.restype = ctypes.c_uint16
-_UniffiLib.ffi_slh_dsa_uniffi_rust_future_free_u16.argtypes = (
-    ctypes.c_uint64,
+class _UniffiForeignFutureStructPointer(ctypes.Structure):
+    _fields_ = [
+        ("return_value", ctypes.c_void_p),
+        ("call_status", _UniffiRustCallStatus),
+    ]
+_UNIFFI_FOREIGN_FUTURE_COMPLETE_POINTER = ctypes.CFUNCTYPE(None,ctypes.c_uint64,_UniffiForeignFutureStructPointer,
 )
-_UniffiLib.ffi_slh_dsa_uniffi_rust_future_free_u16.restype = None
-_UniffiLib.ffi_slh_dsa_uniffi_rust_future_poll_i16.argtypes = (
-    ctypes.c_uint64,
-    _UNIFFI_RUST_FUTURE_CONTINUATION_CALLBACK,
-    ctypes.c_uint64,
+class _UniffiForeignFutureStructRustBuffer(ctypes.Structure):
+    _fields_ = [
+        ("return_value", _UniffiRustBuffer),
+        ("call_status", _UniffiRustCallStatus),
+    ]
+_UNIFFI_FOREIGN_FUTURE_COMPLETE_RUST_BUFFER = ctypes.CFUNCTYPE(None,ctypes.c_uint64,_UniffiForeignFutureStructRustBuffer,
 )
-_UniffiLib.ffi_slh_dsa_uniffi_rust_future_poll_i16.restype = None
-_UniffiLib.ffi_slh_dsa_uniffi_rust_future_cancel_i16.argtypes = (
-    ctypes.c_uint64,
+class _UniffiForeignFutureStructVoid(ctypes.Structure):
+    _fields_ = [
+        ("call_status", _UniffiRustCallStatus),
+    ]
+_UNIFFI_FOREIGN_FUTURE_COMPLETE_VOID = ctypes.CFUNCTYPE(None,ctypes.c_uint64,_UniffiForeignFutureStructVoid,
 )
-_UniffiLib.ffi_slh_dsa_uniffi_rust_future_cancel_i16.restype = None
-_UniffiLib.ffi_slh_dsa_uniffi_rust_future_complete_i16.argtypes = (
-    ctypes.c_uint64,
+_UniffiLib.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_cose_sign1_create.argtypes = (
+    _UniffiRustBuffer,
+    _UniffiRustBuffer,
+    _UniffiRustBuffer,
+    _UniffiRustBuffer,
+    _UniffiRustBuffer,
+    ctypes.c_int8,
     ctypes.POINTER(_UniffiRustCallStatus),
 )
-_UniffiLib.ffi_slh_dsa_uniffi_rust_future_complete_i16.restype = ctypes.c_int16
-_UniffiLib.ffi_slh_dsa_uniffi_rust_future_free_i16.argtypes = (
-    ctypes.c_uint64,
+_UniffiLib.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_cose_sign1_create.restype = _UniffiRustBuffer
+_UniffiLib.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_cose_sign1_verify.argtypes = (
+    _UniffiRustBuffer,
+    _UniffiRustBuffer,
+    _UniffiRustBuffer,
+    _UniffiRustBuffer,
+    ctypes.POINTER(_UniffiRustCallStatus),
 )
-_UniffiLib.ffi_slh_dsa_uniffi_rust_future_free_i16.restype = None
-_UniffiLib.ffi_slh_dsa_uniffi_rust_future_poll_u32.argtypes = (
-    ctypes.c_uint64,
-    _UNIFFI_RUST_FUTURE_CONTINUATION_CALLBACK,
-    ctypes.c_uint64,
+_UniffiLib.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_cose_sign1_verify.restype = _UniffiRustBuffer
+_UniffiLib.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_csr_create.argtypes = (
+    _UniffiRustBuffer,
+    _UniffiRustBuffer,
+    _UniffiRustBuffer,
+    _UniffiRustBuffer,
+    _UniffiRustBuffer,
+    ctypes.c_int8,
+    ctypes.POINTER(_UniffiRustCallStatus),
 )
-_UniffiLib.ffi_slh_dsa_uniffi_rust_future_poll_u32.restype = None
-_UniffiLib.ffi_slh_dsa_uniffi_rust_future_cancel_u32.argtypes = (
-    ctypes.c_uint64,
+_UniffiLib.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_csr_create.restype = _UniffiRustBuffer
+_UniffiLib.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_csr_verify.argtypes = (
+    _UniffiRustBuffer,
+    ctypes.POINTER(_UniffiRustCallStatus),
 )
-_UniffiLib.ffi_slh_dsa_uniffi_rust_future_cancel_u32.restype = None
-_UniffiLib.ffi_slh_dsa_uniffi_rust_future_complete_u32.argtypes = (
-    ctypes.c_uint64,
+_UniffiLib.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_csr_verify.restype = _UniffiRustBuffer
+_UniffiLib.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_envelope_decode.argtypes = (
+    _UniffiRustBuffer,
     ctypes.POINTER(_UniffiRustCallStatus),
 )
-_UniffiLib.ffi_slh_dsa_uniffi_rust_future_complete_u32.restype = ctypes.c_uint32
-_UniffiLib.ffi_slh_dsa_uniffi_rust_future_free_u32.argtypes = (
-    ctypes.c_uint64,
+_UniffiLib.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_envelope_decode.restype = _UniffiRustBuffer
+_UniffiLib.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_envelope_sign.argtypes = (
+    _UniffiRustBuffer,
+    _UniffiRustBuffer,
+    _UniffiRustBuffer,
+    _UniffiRustBuffer,
+    _UniffiRustBuffer,
+    _UniffiRustBuffer,
+    ctypes.c_int8,
+    ctypes.POINTER(_UniffiRustCallStatus),
 )
-_UniffiLib.ffi_slh_dsa_uniffi_rust_future_free_u32.restype = None
-_UniffiLib.ffi_slh_dsa_uniffi_rust_future_poll_i32.argtypes = (
-    ctypes.c_uint64,
-    _UNIFFI_RUST_FUTURE_CONTINUATION_CALLBACK,
-    ctypes.c_uint64,
+_UniffiLib.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_envelope_sign.restype = _UniffiRustBuffer
+_UniffiLib.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_envelope_verify.argtypes = (
+    _UniffiRustBuffer,
+    _UniffiRustBuffer,
+    _UniffiRustBuffer,
+    ctypes.POINTER(_UniffiRustCallStatus),
 )
-_UniffiLib.ffi_slh_dsa_uniffi_rust_future_poll_i32.restype = None
-_UniffiLib.ffi_slh_dsa_uniffi_rust_future_cancel_i32.argtypes = (
-    ctypes.c_uint64,
+_UniffiLib.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_envelope_verify.restype = _UniffiRustBuffer
+_UniffiLib.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_fingerprint.argtypes = (
+    _UniffiRustBuffer,
+    _UniffiRustBuffer,
+    _UniffiRustBuffer,
+    ctypes.POINTER(_UniffiRustCallStatus),
 )
-_UniffiLib.ffi_slh_dsa_uniffi_rust_future_cancel_i32.restype = None
-_UniffiLib.ffi_slh_dsa_uniffi_rust_future_complete_i32.argtypes = (
-    ctypes.c_uint64,
+_UniffiLib.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_fingerprint.restype = _UniffiRustBuffer
+_UniffiLib.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_key_file_len.argtypes = (
+    _UniffiRustBuffer,
     ctypes.POINTER(_UniffiRustCallStatus),
 )
-_UniffiLib.ffi_slh_dsa_uniffi_rust_future_complete_i32.restype = ctypes.c_int32
-_UniffiLib.ffi_slh_dsa_uniffi_rust_future_free_i32.argtypes = (
-    ctypes.c_uint64,
+_UniffiLib.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_key_file_len.restype = ctypes.c_uint64
+_UniffiLib.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_keypair_from_seed.argtypes = (
+    _UniffiRustBuffer,
+    _UniffiRustBuffer,
+    _UniffiRustBuffer,
+    _UniffiRustBuffer,
+    ctypes.POINTER(_UniffiRustCallStatus),
 )
-_UniffiLib.ffi_slh_dsa_uniffi_rust_future_free_i32.restype = None
-_UniffiLib.ffi_slh_dsa_uniffi_rust_future_poll_u64.argtypes = (
-    ctypes.c_uint64,
-    _UNIFFI_RUST_FUTURE_CONTINUATION_CALLBACK,
-    ctypes.c_uint64,
+_UniffiLib.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_keypair_from_seed.restype = _UniffiRustBuffer
+_UniffiLib.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_keypair_generate.argtypes = (
+    _UniffiRustBuffer,
+    ctypes.POINTER(_UniffiRustCallStatus),
 )
-_UniffiLib.ffi_slh_dsa_uniffi_rust_future_poll_u64.restype = None
-_UniffiLib.ffi_slh_dsa_uniffi_rust_future_cancel_u64.argtypes = (
-    ctypes.c_uint64,
+_UniffiLib.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_keypair_generate.restype = _UniffiRustBuffer
+_UniffiLib.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_parameter_name.argtypes = (
+    _UniffiRustBuffer,
+    ctypes.POINTER(_UniffiRustCallStatus),
 )
-_UniffiLib.ffi_slh_dsa_uniffi_rust_future_cancel_u64.restype = None
-_UniffiLib.ffi_slh_dsa_uniffi_rust_future_complete_u64.argtypes = (
-    ctypes.c_uint64,
+_UniffiLib.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_parameter_name.restype = _UniffiRustBuffer
+_UniffiLib.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_parameter_set_from_name.argtypes = (
+    _UniffiRustBuffer,
     ctypes.POINTER(_UniffiRustCallStatus),
 )
-_UniffiLib.ffi_slh_dsa_uniffi_rust_future_complete_u64.restype = ctypes.c_uint64
-_UniffiLib.ffi_slh_dsa_uniffi_rust_future_free_u64.argtypes = (
-    ctypes.c_uint64,
+_UniffiLib.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_parameter_set_from_name.restype = _UniffiRustBuffer
+_UniffiLib.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_parameter_set_from_oid.argtypes = (
+    _UniffiRustBuffer,
+    ctypes.POINTER(_UniffiRustCallStatus),
 )
-_UniffiLib.ffi_slh_dsa_uniffi_rust_future_free_u64.restype = None
-_UniffiLib.ffi_slh_dsa_uniffi_rust_future_poll_i64.argtypes = (
-    ctypes.c_uint64,
-    _UNIFFI_RUST_FUTURE_CONTINUATION_CALLBACK,
-    ctypes.c_uint64,
+_UniffiLib.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_parameter_set_from_oid.restype = _UniffiRustBuffer
+_UniffiLib.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_parameter_sets.argtypes = (
+    ctypes.POINTER(_UniffiRustCallStatus),
 )
-_UniffiLib.ffi_slh_dsa_uniffi_rust_future_poll_i64.restype = None
-_UniffiLib.ffi_slh_dsa_uniffi_rust_future_cancel_i64.argtypes = (
-    ctypes.c_uint64,
+_UniffiLib.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_parameter_sets.restype = _UniffiRustBuffer
+_UniffiLib.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_seed_len.argtypes = (
+    _UniffiRustBuffer,
+    ctypes.POINTER(_UniffiRustCallStatus),
 )
-_UniffiLib.ffi_slh_dsa_uniffi_rust_future_cancel_i64.restype = None
-_UniffiLib.ffi_slh_dsa_uniffi_rust_future_complete_i64.argtypes = (
-    ctypes.c_uint64,
+_UniffiLib.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_seed_len.restype = ctypes.c_uint64
+_UniffiLib.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_set_verify_after_sign.argtypes = (
+    ctypes.c_int8,
     ctypes.POINTER(_UniffiRustCallStatus),
 )
-_UniffiLib.ffi_slh_dsa_uniffi_rust_future_complete_i64.restype = ctypes.c_int64
-_UniffiLib.ffi_slh_dsa_uniffi_rust_future_free_i64.argtypes = (
-    ctypes.c_uint64,
-)
-_UniffiLib.ffi_slh_dsa_uniffi_rust_future_free_i64.restype = None
-_UniffiLib.ffi_slh_dsa_uniffi_rust_future_poll_f32.argtypes = (
-    ctypes.c_uint64,
-    _UNIFFI_RUST_FUTURE_CONTINUATION_CALLBACK,
-    ctypes.c_uint64,
-)
-_UniffiLib.ffi_slh_dsa_uniffi_rust_future_poll_f32.restype = None
-_UniffiLib.ffi_slh_dsa_uniffi_rust_future_cancel_f32.argtypes = (
-    ctypes.c_uint64,
-)
-_UniffiLib.ffi_slh_dsa_uniffi_rust_future_cancel_f32.restype = None
-_UniffiLib.ffi_slh_dsa_uniffi_rust_future_complete_f32.argtypes = (
-    ctypes.c_uint64,
+_UniffiLib.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_set_verify_after_sign.restype = None
+_UniffiLib.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_sign.argtypes = (
+    _UniffiRustBuffer,
+    _UniffiRustBuffer,
+    _UniffiRustBuffer,
+    _UniffiRustBuffer,
     ctypes.POINTER(_UniffiRustCallStatus),
 )
-_UniffiLib.ffi_slh_dsa_uniffi_rust_future_complete_f32.restype = ctypes.c_float
-_UniffiLib.ffi_slh_dsa_uniffi_rust_future_free_f32.argtypes = (
-    ctypes.c_uint64,
-)
-_UniffiLib.ffi_slh_dsa_uniffi_rust_future_free_f32.restype = None
-_UniffiLib.ffi_slh_dsa_uniffi_rust_future_poll_f64.argtypes = (
-    ctypes.c_uint64,
-    _UNIFFI_RUST_FUTURE_CONTINUATION_CALLBACK,
-    ctypes.c_uint64,
-)
-_UniffiLib.ffi_slh_dsa_uniffi_rust_future_poll_f64.restype = None
-_UniffiLib.ffi_slh_dsa_uniffi_rust_future_cancel_f64.argtypes = (
-    ctypes.c_uint64,
-)
-_UniffiLib.ffi_slh_dsa_uniffi_rust_future_cancel_f64.restype = None
-_UniffiLib.ffi_slh_dsa_uniffi_rust_future_complete_f64.argtypes = (
-    ctypes.c_uint64,
+_UniffiLib.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_sign.restype = _UniffiRustBuffer
+_UniffiLib.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_sign_batch.argtypes = (
+    _UniffiRustBuffer,
+    _UniffiRustBuffer,
+    _UniffiRustBuffer,
+    _UniffiRustBuffer,
     ctypes.POINTER(_UniffiRustCallStatus),
 )
-_UniffiLib.ffi_slh_dsa_uniffi_rust_future_complete_f64.restype = ctypes.c_double
-_UniffiLib.ffi_slh_dsa_uniffi_rust_future_free_f64.argtypes = (
-    ctypes.c_uint64,
-)
-_UniffiLib.ffi_slh_dsa_uniffi_rust_future_free_f64.restype = None
-_UniffiLib.ffi_slh_dsa_uniffi_rust_future_poll_rust_buffer.argtypes = (
-    ctypes.c_uint64,
-    _UNIFFI_RUST_FUTURE_CONTINUATION_CALLBACK,
-    ctypes.c_uint64,
-)
-_UniffiLib.ffi_slh_dsa_uniffi_rust_future_poll_rust_buffer.restype = None
-_UniffiLib.ffi_slh_dsa_uniffi_rust_future_cancel_rust_buffer.argtypes = (
-    ctypes.c_uint64,
-)
-_UniffiLib.ffi_slh_dsa_uniffi_rust_future_cancel_rust_buffer.restype = None
-_UniffiLib.ffi_slh_dsa_uniffi_rust_future_complete_rust_buffer.argtypes = (
-    ctypes.c_uint64,
+_UniffiLib.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_sign_batch.restype = _UniffiRustBuffer
+_UniffiLib.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_sign_checked.argtypes = (
+    _UniffiRustBuffer,
+    _UniffiRustBuffer,
+    _UniffiRustBuffer,
+    _UniffiRustBuffer,
     ctypes.POINTER(_UniffiRustCallStatus),
 )
-_UniffiLib.ffi_slh_dsa_uniffi_rust_future_complete_rust_buffer.restype = _UniffiRustBuffer
-_UniffiLib.ffi_slh_dsa_uniffi_rust_future_free_rust_buffer.argtypes = (
-    ctypes.c_uint64,
+_UniffiLib.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_sign_checked.restype = _UniffiRustBuffer
+_UniffiLib.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_sign_deterministic.argtypes = (
+    _UniffiRustBuffer,
+    _UniffiRustBuffer,
+    _UniffiRustBuffer,
+    _UniffiRustBuffer,
+    ctypes.POINTER(_UniffiRustCallStatus),
 )
-_UniffiLib.ffi_slh_dsa_uniffi_rust_future_free_rust_buffer.restype = None
-_UniffiLib.ffi_slh_dsa_uniffi_rust_future_poll_void.argtypes = (
-    ctypes.c_uint64,
-    _UNIFFI_RUST_FUTURE_CONTINUATION_CALLBACK,
-    ctypes.c_uint64,
+_UniffiLib.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_sign_deterministic.restype = _UniffiRustBuffer
+_UniffiLib.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_signature_len.argtypes = (
+    _UniffiRustBuffer,
+    ctypes.POINTER(_UniffiRustCallStatus),
 )
-_UniffiLib.ffi_slh_dsa_uniffi_rust_future_poll_void.restype = None
-_UniffiLib.ffi_slh_dsa_uniffi_rust_future_cancel_void.argtypes = (
-    ctypes.c_uint64,
+_UniffiLib.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_signature_len.restype = ctypes.c_uint64
+_UniffiLib.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_signing_key_from_cose_key.argtypes = (
+    _UniffiRustBuffer,
+    ctypes.POINTER(_UniffiRustCallStatus),
 )
-_UniffiLib.ffi_slh_dsa_uniffi_rust_future_cancel_void.restype = None
-_UniffiLib.ffi_slh_dsa_uniffi_rust_future_complete_void.argtypes = (
-    ctypes.c_uint64,
+_UniffiLib.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_signing_key_from_cose_key.restype = _UniffiRustBuffer
+_UniffiLib.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_signing_key_from_encrypted_pkcs8.argtypes = (
+    _UniffiRustBuffer,
+    _UniffiRustBuffer,
     ctypes.POINTER(_UniffiRustCallStatus),
 )
-_UniffiLib.ffi_slh_dsa_uniffi_rust_future_complete_void.restype = None
-_UniffiLib.ffi_slh_dsa_uniffi_rust_future_free_void.argtypes = (
-    ctypes.c_uint64,
+_UniffiLib.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_signing_key_from_encrypted_pkcs8.restype = _UniffiRustBuffer
+_UniffiLib.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_signing_key_from_key_file.argtypes = (
+    _UniffiRustBuffer,
+    _UniffiRustBuffer,
+    ctypes.POINTER(_UniffiRustCallStatus),
 )
-_UniffiLib.ffi_slh_dsa_uniffi_rust_future_free_void.restype = None
-_UniffiLib.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_keypair_generate.argtypes = (
+_UniffiLib.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_signing_key_from_key_file.restype = _UniffiRustBuffer
+_UniffiLib.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_signing_key_from_pkcs8.argtypes = (
     _UniffiRustBuffer,
     ctypes.POINTER(_UniffiRustCallStatus),
 )
-_UniffiLib.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_keypair_generate.restype = _UniffiRustBuffer
-_UniffiLib.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_parameter_name.argtypes = (
+_UniffiLib.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_signing_key_from_pkcs8.restype = _UniffiRustBuffer
+_UniffiLib.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_signing_key_len.argtypes = (
     _UniffiRustBuffer,
     ctypes.POINTER(_UniffiRustCallStatus),
 )
-_UniffiLib.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_parameter_name.restype = _UniffiRustBuffer
-_UniffiLib.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_sign.argtypes = (
+_UniffiLib.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_signing_key_len.restype = ctypes.c_uint64
+_UniffiLib.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_signing_key_to_cose_key.argtypes = (
+    _UniffiRustBuffer,
     _UniffiRustBuffer,
+    ctypes.POINTER(_UniffiRustCallStatus),
+)
+_UniffiLib.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_signing_key_to_cose_key.restype = _UniffiRustBuffer
+_UniffiLib.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_signing_key_to_encrypted_pkcs8.argtypes = (
     _UniffiRustBuffer,
     _UniffiRustBuffer,
     _UniffiRustBuffer,
+    ctypes.c_int8,
     ctypes.POINTER(_UniffiRustCallStatus),
 )
-_UniffiLib.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_sign.restype = _UniffiRustBuffer
-_UniffiLib.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_sign_deterministic.argtypes = (
+_UniffiLib.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_signing_key_to_encrypted_pkcs8.restype = _UniffiRustBuffer
+_UniffiLib.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_signing_key_to_key_file.argtypes = (
+    _UniffiRustBuffer,
     _UniffiRustBuffer,
     _UniffiRustBuffer,
     _UniffiRustBuffer,
     _UniffiRustBuffer,
     ctypes.POINTER(_UniffiRustCallStatus),
 )
-_UniffiLib.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_sign_deterministic.restype = _UniffiRustBuffer
-_UniffiLib.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_signature_len.argtypes = (
+_UniffiLib.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_signing_key_to_key_file.restype = _UniffiRustBuffer
+_UniffiLib.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_signing_key_to_pkcs8.argtypes = (
+    _UniffiRustBuffer,
     _UniffiRustBuffer,
+    ctypes.c_int8,
     ctypes.POINTER(_UniffiRustCallStatus),
 )
-_UniffiLib.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_signature_len.restype = ctypes.c_uint64
-_UniffiLib.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_signing_key_len.argtypes = (
+_UniffiLib.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_signing_key_to_pkcs8.restype = _UniffiRustBuffer
+_UniffiLib.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_validate_signing_key.argtypes = (
+    _UniffiRustBuffer,
     _UniffiRustBuffer,
     ctypes.POINTER(_UniffiRustCallStatus),
 )
-_UniffiLib.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_signing_key_len.restype = ctypes.c_uint64
+_UniffiLib.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_validate_signing_key.restype = _UniffiRustBuffer
 _UniffiLib.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_verify.argtypes = (
     _UniffiRustBuffer,
     _UniffiRustBuffer,
@@ -804,657 +867,2725 @@ _UniffiLib.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_verify.argtypes = (
     ctypes.POINTER(_UniffiRustCallStatus),
 )
 _UniffiLib.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_verify.restype = _UniffiRustBuffer
+_UniffiLib.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_verify_after_sign_enabled.argtypes = (
+    ctypes.POINTER(_UniffiRustCallStatus),
+)
+_UniffiLib.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_verify_after_sign_enabled.restype = ctypes.c_int8
+_UniffiLib.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_verifying_key_from_cose_key.argtypes = (
+    _UniffiRustBuffer,
+    ctypes.POINTER(_UniffiRustCallStatus),
+)
+_UniffiLib.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_verifying_key_from_cose_key.restype = _UniffiRustBuffer
 _UniffiLib.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_verifying_key_from_signing_key.argtypes = (
     _UniffiRustBuffer,
     _UniffiRustBuffer,
     ctypes.POINTER(_UniffiRustCallStatus),
 )
 _UniffiLib.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_verifying_key_from_signing_key.restype = _UniffiRustBuffer
+_UniffiLib.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_verifying_key_from_signing_key_validated.argtypes = (
+    _UniffiRustBuffer,
+    _UniffiRustBuffer,
+    ctypes.POINTER(_UniffiRustCallStatus),
+)
+_UniffiLib.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_verifying_key_from_signing_key_validated.restype = _UniffiRustBuffer
+_UniffiLib.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_verifying_key_from_spki.argtypes = (
+    _UniffiRustBuffer,
+    ctypes.POINTER(_UniffiRustCallStatus),
+)
+_UniffiLib.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_verifying_key_from_spki.restype = _UniffiRustBuffer
 _UniffiLib.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_verifying_key_len.argtypes = (
     _UniffiRustBuffer,
     ctypes.POINTER(_UniffiRustCallStatus),
 )
 _UniffiLib.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_verifying_key_len.restype = ctypes.c_uint64
-_UniffiLib.ffi_slh_dsa_uniffi_uniffi_contract_version.argtypes = (
+_UniffiLib.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_verifying_key_to_cose_key.argtypes = (
+    _UniffiRustBuffer,
+    _UniffiRustBuffer,
+    ctypes.POINTER(_UniffiRustCallStatus),
 )
-_UniffiLib.ffi_slh_dsa_uniffi_uniffi_contract_version.restype = ctypes.c_uint32
-_UniffiLib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_keypair_generate.argtypes = (
+_UniffiLib.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_verifying_key_to_cose_key.restype = _UniffiRustBuffer
+_UniffiLib.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_verifying_key_to_spki.argtypes = (
+    _UniffiRustBuffer,
+    _UniffiRustBuffer,
+    ctypes.c_int8,
+    ctypes.POINTER(_UniffiRustCallStatus),
 )
-_UniffiLib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_keypair_generate.restype = ctypes.c_uint16
-_UniffiLib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_parameter_name.argtypes = (
+_UniffiLib.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_verifying_key_to_spki.restype = _UniffiRustBuffer
+_UniffiLib.ffi_slh_dsa_uniffi_rustbuffer_alloc.argtypes = (
+    ctypes.c_uint64,
+    ctypes.POINTER(_UniffiRustCallStatus),
 )
-_UniffiLib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_parameter_name.restype = ctypes.c_uint16
-_UniffiLib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_sign.argtypes = (
+_UniffiLib.ffi_slh_dsa_uniffi_rustbuffer_alloc.restype = _UniffiRustBuffer
+_UniffiLib.ffi_slh_dsa_uniffi_rustbuffer_from_bytes.argtypes = (
+    _UniffiForeignBytes,
+    ctypes.POINTER(_UniffiRustCallStatus),
 )
-_UniffiLib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_sign.restype = ctypes.c_uint16
-_UniffiLib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_sign_deterministic.argtypes = (
+_UniffiLib.ffi_slh_dsa_uniffi_rustbuffer_from_bytes.restype = _UniffiRustBuffer
+_UniffiLib.ffi_slh_dsa_uniffi_rustbuffer_free.argtypes = (
+    _UniffiRustBuffer,
+    ctypes.POINTER(_UniffiRustCallStatus),
 )
-_UniffiLib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_sign_deterministic.restype = ctypes.c_uint16
-_UniffiLib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_signature_len.argtypes = (
+_UniffiLib.ffi_slh_dsa_uniffi_rustbuffer_free.restype = None
+_UniffiLib.ffi_slh_dsa_uniffi_rustbuffer_reserve.argtypes = (
+    _UniffiRustBuffer,
+    ctypes.c_uint64,
+    ctypes.POINTER(_UniffiRustCallStatus),
 )
-_UniffiLib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_signature_len.restype = ctypes.c_uint16
-_UniffiLib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_signing_key_len.argtypes = (
+_UniffiLib.ffi_slh_dsa_uniffi_rustbuffer_reserve.restype = _UniffiRustBuffer
+_UniffiLib.ffi_slh_dsa_uniffi_rust_future_poll_u8.argtypes = (
+    ctypes.c_uint64,
+    _UNIFFI_RUST_FUTURE_CONTINUATION_CALLBACK,
+    ctypes.c_uint64,
 )
-_UniffiLib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_signing_key_len.restype = ctypes.c_uint16
-_UniffiLib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_verify.argtypes = (
+_UniffiLib.ffi_slh_dsa_uniffi_rust_future_poll_u8.restype = None
+_UniffiLib.ffi_slh_dsa_uniffi_rust_future_cancel_u8.argtypes = (
+    ctypes.c_uint64,
 )
-_UniffiLib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_verify.restype = ctypes.c_uint16
-_UniffiLib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_verifying_key_from_signing_key.argtypes = (
+_UniffiLib.ffi_slh_dsa_uniffi_rust_future_cancel_u8.restype = None
+_UniffiLib.ffi_slh_dsa_uniffi_rust_future_free_u8.argtypes = (
+    ctypes.c_uint64,
 )
-_UniffiLib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_verifying_key_from_signing_key.restype = ctypes.c_uint16
-_UniffiLib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_verifying_key_len.argtypes = (
+_UniffiLib.ffi_slh_dsa_uniffi_rust_future_free_u8.restype = None
+_UniffiLib.ffi_slh_dsa_uniffi_rust_future_complete_u8.argtypes = (
+    ctypes.c_uint64,
+    ctypes.POINTER(_UniffiRustCallStatus),
 )
-_UniffiLib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_verifying_key_len.restype = ctypes.c_uint16
-
-_uniffi_check_contract_api_version(_UniffiLib)
-# _uniffi_check_api_checksums(_UniffiLib)
-
-
-
-# Public interface members begin here.
-
-
-
-
-
-
-
-class SlhDsaStatus(enum.Enum):
-    
-    OK = 0
-    
-    INVALID_PARAM = 1
-    
-    INVALID_LENGTH = 2
-    
-    DECODE_ERROR = 3
-    
-    VERIFY_FAILED = 4
-    
-
-
-class _UniffiFfiConverterTypeSlhDsaStatus(_UniffiConverterRustBuffer):
-    @staticmethod
-    def read(buf):
-        variant = buf.read_i32()
-        if variant == 1:
-            return SlhDsaStatus.OK
-        if variant == 2:
-            return SlhDsaStatus.INVALID_PARAM
-        if variant == 3:
-            return SlhDsaStatus.INVALID_LENGTH
-        if variant == 4:
-            return SlhDsaStatus.DECODE_ERROR
-        if variant == 5:
-            return SlhDsaStatus.VERIFY_FAILED
-        raise InternalError("Raw enum value doesn't match any cases")
-
-    @staticmethod
-    def check_lower(value):
-        if value == SlhDsaStatus.OK:
-            return
-        if value == SlhDsaStatus.INVALID_PARAM:
-            return
-        if value == SlhDsaStatus.INVALID_LENGTH:
-            return
-        if value == SlhDsaStatus.DECODE_ERROR:
-            return
-        if value == SlhDsaStatus.VERIFY_FAILED:
-            return
-        raise ValueError(value)
-
-    @staticmethod
-    def write(value, buf):
-        if value == SlhDsaStatus.OK:
-            buf.write_i32(1)
-        if value == SlhDsaStatus.INVALID_PARAM:
-            buf.write_i32(2)
-        if value == SlhDsaStatus.INVALID_LENGTH:
-            buf.write_i32(3)
-        if value == SlhDsaStatus.DECODE_ERROR:
-            buf.write_i32(4)
+_UniffiLib.ffi_slh_dsa_uniffi_rust_future_complete_u8.restype = ctypes.c_uint8
+_UniffiLib.ffi_slh_dsa_uniffi_rust_future_poll_i8.argtypes = (
+    ctypes.c_uint64,
+    _UNIFFI_RUST_FUTURE_CONTINUATION_CALLBACK,
+    ctypes.c_uint64,
+)
+_UniffiLib.ffi_slh_dsa_uniffi_rust_future_poll_i8.restype = None
+_UniffiLib.ffi_slh_dsa_uniffi_rust_future_cancel_i8.argtypes = (
+    ctypes.c_uint64,
+)
+_UniffiLib.ffi_slh_dsa_uniffi_rust_future_cancel_i8.restype = None
+_UniffiLib.ffi_slh_dsa_uniffi_rust_future_free_i8.argtypes = (
+    ctypes.c_uint64,
+)
+_UniffiLib.ffi_slh_dsa_uniffi_rust_future_free_i8.restype = None
+_UniffiLib.ffi_slh_dsa_uniffi_rust_future_complete_i8.argtypes = (
+    ctypes.c_uint64,
+    ctypes.POINTER(_UniffiRustCallStatus),
+)
+_UniffiLib.ffi_slh_dsa_uniffi_rust_future_complete_i8.restype = ctypes.c_int8
+_UniffiLib.ffi_slh_dsa_uniffi_rust_future_poll_u16.argtypes = (
+    ctypes.c_uint64,
+    _UNIFFI_RUST_FUTURE_CONTINUATION_CALLBACK,
+    ctypes.c_uint64,
+)
+_UniffiLib.ffi_slh_dsa_uniffi_rust_future_poll_u16.restype = None
+_UniffiLib.ffi_slh_dsa_uniffi_rust_future_cancel_u16.argtypes = (
+    ctypes.c_uint64,
+)
+_UniffiLib.ffi_slh_dsa_uniffi_rust_future_cancel_u16.restype = None
+_UniffiLib.ffi_slh_dsa_uniffi_rust_future_free_u16.argtypes = (
+    ctypes.c_uint64,
+)
+_UniffiLib.ffi_slh_dsa_uniffi_rust_future_free_u16.restype = None
+_UniffiLib.ffi_slh_dsa_uniffi_rust_future_complete_u16.argtypes = (
+    ctypes.c_uint64,
+    ctypes.POINTER(_UniffiRustCallStatus),
+)
+_UniffiLib.ffi_slh_dsa_uniffi_rust_future_complete_u16.restype = ctypes.c_uint16
+_UniffiLib.ffi_slh_dsa_uniffi_rust_future_poll_i16.argtypes = (
+    ctypes.c_uint64,
+    _UNIFFI_RUST_FUTURE_CONTINUATION_CALLBACK,
+    ctypes.c_uint64,
+)
+_UniffiLib.ffi_slh_dsa_uniffi_rust_future_poll_i16.restype = None
+_UniffiLib.ffi_slh_dsa_uniffi_rust_future_cancel_i16.argtypes = (
+    ctypes.c_uint64,
+)
+_UniffiLib.ffi_slh_dsa_uniffi_rust_future_cancel_i16.restype = None
+_UniffiLib.ffi_slh_dsa_uniffi_rust_future_free_i16.argtypes = (
+    ctypes.c_uint64,
+)
+_UniffiLib.ffi_slh_dsa_uniffi_rust_future_free_i16.restype = None
+_UniffiLib.ffi_slh_dsa_uniffi_rust_future_complete_i16.argtypes = (
+    ctypes.c_uint64,
+    ctypes.POINTER(_UniffiRustCallStatus),
+)
+_UniffiLib.ffi_slh_dsa_uniffi_rust_future_complete_i16.restype = ctypes.c_int16
+_UniffiLib.ffi_slh_dsa_uniffi_rust_future_poll_u32.argtypes = (
+    ctypes.c_uint64,
+    _UNIFFI_RUST_FUTURE_CONTINUATION_CALLBACK,
+    ctypes.c_uint64,
+)
+_UniffiLib.ffi_slh_dsa_uniffi_rust_future_poll_u32.restype = None
+_UniffiLib.ffi_slh_dsa_uniffi_rust_future_cancel_u32.argtypes = (
+    ctypes.c_uint64,
+)
+_UniffiLib.ffi_slh_dsa_uniffi_rust_future_cancel_u32.restype = None
+_UniffiLib.ffi_slh_dsa_uniffi_rust_future_free_u32.argtypes = (
+    ctypes.c_uint64,
+)
+_UniffiLib.ffi_slh_dsa_uniffi_rust_future_free_u32.restype = None
+_UniffiLib.ffi_slh_dsa_uniffi_rust_future_complete_u32.argtypes = (
+    ctypes.c_uint64,
+    ctypes.POINTER(_UniffiRustCallStatus),
+)
+_UniffiLib.ffi_slh_dsa_uniffi_rust_future_complete_u32.restype = ctypes.c_uint32
+_UniffiLib.ffi_slh_dsa_uniffi_rust_future_poll_i32.argtypes = (
+    ctypes.c_uint64,
+    _UNIFFI_RUST_FUTURE_CONTINUATION_CALLBACK,
+    ctypes.c_uint64,
+)
+_UniffiLib.ffi_slh_dsa_uniffi_rust_future_poll_i32.restype = None
+_UniffiLib.ffi_slh_dsa_uniffi_rust_future_cancel_i32.argtypes = (
+    ctypes.c_uint64,
+)
+_UniffiLib.ffi_slh_dsa_uniffi_rust_future_cancel_i32.restype = None
+_UniffiLib.ffi_slh_dsa_uniffi_rust_future_free_i32.argtypes = (
+    ctypes.c_uint64,
+)
+_UniffiLib.ffi_slh_dsa_uniffi_rust_future_free_i32.restype = None
+_UniffiLib.ffi_slh_dsa_uniffi_rust_future_complete_i32.argtypes = (
+    ctypes.c_uint64,
+    ctypes.POINTER(_UniffiRustCallStatus),
+)
+_UniffiLib.ffi_slh_dsa_uniffi_rust_future_complete_i32.restype = ctypes.c_int32
+_UniffiLib.ffi_slh_dsa_uniffi_rust_future_poll_u64.argtypes = (
+    ctypes.c_uint64,
+    _UNIFFI_RUST_FUTURE_CONTINUATION_CALLBACK,
+    ctypes.c_uint64,
+)
+_UniffiLib.ffi_slh_dsa_uniffi_rust_future_poll_u64.restype = None
+_UniffiLib.ffi_slh_dsa_uniffi_rust_future_cancel_u64.argtypes = (
+    ctypes.c_uint64,
+)
+_UniffiLib.ffi_slh_dsa_uniffi_rust_future_cancel_u64.restype = None
+_UniffiLib.ffi_slh_dsa_uniffi_rust_future_free_u64.argtypes = (
+    ctypes.c_uint64,
+)
+_UniffiLib.ffi_slh_dsa_uniffi_rust_future_free_u64.restype = None
+_UniffiLib.ffi_slh_dsa_uniffi_rust_future_complete_u64.argtypes = (
+    ctypes.c_uint64,
+    ctypes.POINTER(_UniffiRustCallStatus),
+)
+_UniffiLib.ffi_slh_dsa_uniffi_rust_future_complete_u64.restype = ctypes.c_uint64
+_UniffiLib.ffi_slh_dsa_uniffi_rust_future_poll_i64.argtypes = (
+    ctypes.c_uint64,
+    _UNIFFI_RUST_FUTURE_CONTINUATION_CALLBACK,
+    ctypes.c_uint64,
+)
+_UniffiLib.ffi_slh_dsa_uniffi_rust_future_poll_i64.restype = None
+_UniffiLib.ffi_slh_dsa_uniffi_rust_future_cancel_i64.argtypes = (
+    ctypes.c_uint64,
+)
+_UniffiLib.ffi_slh_dsa_uniffi_rust_future_cancel_i64.restype = None
+_UniffiLib.ffi_slh_dsa_uniffi_rust_future_free_i64.argtypes = (
+    ctypes.c_uint64,
+)
+_UniffiLib.ffi_slh_dsa_uniffi_rust_future_free_i64.restype = None
+_UniffiLib.ffi_slh_dsa_uniffi_rust_future_complete_i64.argtypes = (
+    ctypes.c_uint64,
+    ctypes.POINTER(_UniffiRustCallStatus),
+)
+_UniffiLib.ffi_slh_dsa_uniffi_rust_future_complete_i64.restype = ctypes.c_int64
+_UniffiLib.ffi_slh_dsa_uniffi_rust_future_poll_f32.argtypes = (
+    ctypes.c_uint64,
+    _UNIFFI_RUST_FUTURE_CONTINUATION_CALLBACK,
+    ctypes.c_uint64,
+)
+_UniffiLib.ffi_slh_dsa_uniffi_rust_future_poll_f32.restype = None
+_UniffiLib.ffi_slh_dsa_uniffi_rust_future_cancel_f32.argtypes = (
+    ctypes.c_uint64,
+)
+_UniffiLib.ffi_slh_dsa_uniffi_rust_future_cancel_f32.restype = None
+_UniffiLib.ffi_slh_dsa_uniffi_rust_future_free_f32.argtypes = (
+    ctypes.c_uint64,
+)
+_UniffiLib.ffi_slh_dsa_uniffi_rust_future_free_f32.restype = None
+_UniffiLib.ffi_slh_dsa_uniffi_rust_future_complete_f32.argtypes = (
+    ctypes.c_uint64,
+    ctypes.POINTER(_UniffiRustCallStatus),
+)
+_UniffiLib.ffi_slh_dsa_uniffi_rust_future_complete_f32.restype = ctypes.c_float
+_UniffiLib.ffi_slh_dsa_uniffi_rust_future_poll_f64.argtypes = (
+    ctypes.c_uint64,
+    _UNIFFI_RUST_FUTURE_CONTINUATION_CALLBACK,
+    ctypes.c_uint64,
+)
+_UniffiLib.ffi_slh_dsa_uniffi_rust_future_poll_f64.restype = None
+_UniffiLib.ffi_slh_dsa_uniffi_rust_future_cancel_f64.argtypes = (
+    ctypes.c_uint64,
+)
+_UniffiLib.ffi_slh_dsa_uniffi_rust_future_cancel_f64.restype = None
+_UniffiLib.ffi_slh_dsa_uniffi_rust_future_free_f64.argtypes = (
+    ctypes.c_uint64,
+)
+_UniffiLib.ffi_slh_dsa_uniffi_rust_future_free_f64.restype = None
+_UniffiLib.ffi_slh_dsa_uniffi_rust_future_complete_f64.argtypes = (
+    ctypes.c_uint64,
+    ctypes.POINTER(_UniffiRustCallStatus),
+)
+_UniffiLib.ffi_slh_dsa_uniffi_rust_future_complete_f64.restype = ctypes.c_double
+_UniffiLib.ffi_slh_dsa_uniffi_rust_future_poll_pointer.argtypes = (
+    ctypes.c_uint64,
+    _UNIFFI_RUST_FUTURE_CONTINUATION_CALLBACK,
+    ctypes.c_uint64,
+)
+_UniffiLib.ffi_slh_dsa_uniffi_rust_future_poll_pointer.restype = None
+_UniffiLib.ffi_slh_dsa_uniffi_rust_future_cancel_pointer.argtypes = (
+    ctypes.c_uint64,
+)
+_UniffiLib.ffi_slh_dsa_uniffi_rust_future_cancel_pointer.restype = None
+_UniffiLib.ffi_slh_dsa_uniffi_rust_future_free_pointer.argtypes = (
+    ctypes.c_uint64,
+)
+_UniffiLib.ffi_slh_dsa_uniffi_rust_future_free_pointer.restype = None
+_UniffiLib.ffi_slh_dsa_uniffi_rust_future_complete_pointer.argtypes = (
+    ctypes.c_uint64,
+    ctypes.POINTER(_UniffiRustCallStatus),
+)
+_UniffiLib.ffi_slh_dsa_uniffi_rust_future_complete_pointer.restype = ctypes.c_void_p
+_UniffiLib.ffi_slh_dsa_uniffi_rust_future_poll_rust_buffer.argtypes = (
+    ctypes.c_uint64,
+    _UNIFFI_RUST_FUTURE_CONTINUATION_CALLBACK,
+    ctypes.c_uint64,
+)
+_UniffiLib.ffi_slh_dsa_uniffi_rust_future_poll_rust_buffer.restype = None
+_UniffiLib.ffi_slh_dsa_uniffi_rust_future_cancel_rust_buffer.argtypes = (
+    ctypes.c_uint64,
+)
+_UniffiLib.ffi_slh_dsa_uniffi_rust_future_cancel_rust_buffer.restype = None
+_UniffiLib.ffi_slh_dsa_uniffi_rust_future_free_rust_buffer.argtypes = (
+    ctypes.c_uint64,
+)
+_UniffiLib.ffi_slh_dsa_uniffi_rust_future_free_rust_buffer.restype = None
+_UniffiLib.ffi_slh_dsa_uniffi_rust_future_complete_rust_buffer.argtypes = (
+    ctypes.c_uint64,
+    ctypes.POINTER(_UniffiRustCallStatus),
+)
+_UniffiLib.ffi_slh_dsa_uniffi_rust_future_complete_rust_buffer.restype = _UniffiRustBuffer
+_UniffiLib.ffi_slh_dsa_uniffi_rust_future_poll_void.argtypes = (
+    ctypes.c_uint64,
+    _UNIFFI_RUST_FUTURE_CONTINUATION_CALLBACK,
+    ctypes.c_uint64,
+)
+_UniffiLib.ffi_slh_dsa_uniffi_rust_future_poll_void.restype = None
+_UniffiLib.ffi_slh_dsa_uniffi_rust_future_cancel_void.argtypes = (
+    ctypes.c_uint64,
+)
+_UniffiLib.ffi_slh_dsa_uniffi_rust_future_cancel_void.restype = None
+_UniffiLib.ffi_slh_dsa_uniffi_rust_future_free_void.argtypes = (
+    ctypes.c_uint64,
+)
+_UniffiLib.ffi_slh_dsa_uniffi_rust_future_free_void.restype = None
+_UniffiLib.ffi_slh_dsa_uniffi_rust_future_complete_void.argtypes = (
+    ctypes.c_uint64,
+    ctypes.POINTER(_UniffiRustCallStatus),
+)
+_UniffiLib.ffi_slh_dsa_uniffi_rust_future_complete_void.restype = None
+_UniffiLib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_cose_sign1_create.argtypes = (
+)
+_UniffiLib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_cose_sign1_create.restype = ctypes.c_uint16
+_UniffiLib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_cose_sign1_verify.argtypes = (
+)
+_UniffiLib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_cose_sign1_verify.restype = ctypes.c_uint16
+_UniffiLib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_csr_create.argtypes = (
+)
+_UniffiLib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_csr_create.restype = ctypes.c_uint16
+_UniffiLib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_csr_verify.argtypes = (
+)
+_UniffiLib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_csr_verify.restype = ctypes.c_uint16
+_UniffiLib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_envelope_decode.argtypes = (
+)
+_UniffiLib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_envelope_decode.restype = ctypes.c_uint16
+_UniffiLib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_envelope_sign.argtypes = (
+)
+_UniffiLib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_envelope_sign.restype = ctypes.c_uint16
+_UniffiLib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_envelope_verify.argtypes = (
+)
+_UniffiLib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_envelope_verify.restype = ctypes.c_uint16
+_UniffiLib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_fingerprint.argtypes = (
+)
+_UniffiLib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_fingerprint.restype = ctypes.c_uint16
+_UniffiLib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_key_file_len.argtypes = (
+)
+_UniffiLib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_key_file_len.restype = ctypes.c_uint16
+_UniffiLib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_keypair_from_seed.argtypes = (
+)
+_UniffiLib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_keypair_from_seed.restype = ctypes.c_uint16
+_UniffiLib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_keypair_generate.argtypes = (
+)
+_UniffiLib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_keypair_generate.restype = ctypes.c_uint16
+_UniffiLib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_parameter_name.argtypes = (
+)
+_UniffiLib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_parameter_name.restype = ctypes.c_uint16
+_UniffiLib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_parameter_set_from_name.argtypes = (
+)
+_UniffiLib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_parameter_set_from_name.restype = ctypes.c_uint16
+_UniffiLib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_parameter_set_from_oid.argtypes = (
+)
+_UniffiLib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_parameter_set_from_oid.restype = ctypes.c_uint16
+_UniffiLib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_parameter_sets.argtypes = (
+)
+_UniffiLib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_parameter_sets.restype = ctypes.c_uint16
+_UniffiLib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_seed_len.argtypes = (
+)
+_UniffiLib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_seed_len.restype = ctypes.c_uint16
+_UniffiLib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_set_verify_after_sign.argtypes = (
+)
+_UniffiLib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_set_verify_after_sign.restype = ctypes.c_uint16
+_UniffiLib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_sign.argtypes = (
+)
+_UniffiLib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_sign.restype = ctypes.c_uint16
+_UniffiLib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_sign_batch.argtypes = (
+)
+_UniffiLib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_sign_batch.restype = ctypes.c_uint16
+_UniffiLib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_sign_checked.argtypes = (
+)
+_UniffiLib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_sign_checked.restype = ctypes.c_uint16
+_UniffiLib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_sign_deterministic.argtypes = (
+)
+_UniffiLib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_sign_deterministic.restype = ctypes.c_uint16
+_UniffiLib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_signature_len.argtypes = (
+)
+_UniffiLib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_signature_len.restype = ctypes.c_uint16
+_UniffiLib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_signing_key_from_cose_key.argtypes = (
+)
+_UniffiLib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_signing_key_from_cose_key.restype = ctypes.c_uint16
+_UniffiLib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_signing_key_from_encrypted_pkcs8.argtypes = (
+)
+_UniffiLib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_signing_key_from_encrypted_pkcs8.restype = ctypes.c_uint16
+_UniffiLib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_signing_key_from_key_file.argtypes = (
+)
+_UniffiLib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_signing_key_from_key_file.restype = ctypes.c_uint16
+_UniffiLib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_signing_key_from_pkcs8.argtypes = (
+)
+_UniffiLib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_signing_key_from_pkcs8.restype = ctypes.c_uint16
+_UniffiLib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_signing_key_len.argtypes = (
+)
+_UniffiLib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_signing_key_len.restype = ctypes.c_uint16
+_UniffiLib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_signing_key_to_cose_key.argtypes = (
+)
+_UniffiLib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_signing_key_to_cose_key.restype = ctypes.c_uint16
+_UniffiLib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_signing_key_to_encrypted_pkcs8.argtypes = (
+)
+_UniffiLib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_signing_key_to_encrypted_pkcs8.restype = ctypes.c_uint16
+_UniffiLib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_signing_key_to_key_file.argtypes = (
+)
+_UniffiLib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_signing_key_to_key_file.restype = ctypes.c_uint16
+_UniffiLib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_signing_key_to_pkcs8.argtypes = (
+)
+_UniffiLib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_signing_key_to_pkcs8.restype = ctypes.c_uint16
+_UniffiLib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_validate_signing_key.argtypes = (
+)
+_UniffiLib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_validate_signing_key.restype = ctypes.c_uint16
+_UniffiLib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_verify.argtypes = (
+)
+_UniffiLib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_verify.restype = ctypes.c_uint16
+_UniffiLib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_verify_after_sign_enabled.argtypes = (
+)
+_UniffiLib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_verify_after_sign_enabled.restype = ctypes.c_uint16
+_UniffiLib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_verifying_key_from_cose_key.argtypes = (
+)
+_UniffiLib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_verifying_key_from_cose_key.restype = ctypes.c_uint16
+_UniffiLib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_verifying_key_from_signing_key.argtypes = (
+)
+_UniffiLib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_verifying_key_from_signing_key.restype = ctypes.c_uint16
+_UniffiLib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_verifying_key_from_signing_key_validated.argtypes = (
+)
+_UniffiLib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_verifying_key_from_signing_key_validated.restype = ctypes.c_uint16
+_UniffiLib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_verifying_key_from_spki.argtypes = (
+)
+_UniffiLib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_verifying_key_from_spki.restype = ctypes.c_uint16
+_UniffiLib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_verifying_key_len.argtypes = (
+)
+_UniffiLib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_verifying_key_len.restype = ctypes.c_uint16
+_UniffiLib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_verifying_key_to_cose_key.argtypes = (
+)
+_UniffiLib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_verifying_key_to_cose_key.restype = ctypes.c_uint16
+_UniffiLib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_verifying_key_to_spki.argtypes = (
+)
+_UniffiLib.uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_verifying_key_to_spki.restype = ctypes.c_uint16
+_UniffiLib.ffi_slh_dsa_uniffi_uniffi_contract_version.argtypes = (
+)
+_UniffiLib.ffi_slh_dsa_uniffi_uniffi_contract_version.restype = ctypes.c_uint32
+
+_uniffi_check_contract_api_version(_UniffiLib)
+# _uniffi_check_api_checksums(_UniffiLib)
+
+# Public interface members begin here.
+
+
+class _UniffiConverterUInt8(_UniffiConverterPrimitiveInt):
+    CLASS_NAME = "u8"
+    VALUE_MIN = 0
+    VALUE_MAX = 2**8
+
+    @staticmethod
+    def read(buf):
+        return buf.read_u8()
+
+    @staticmethod
+    def write(value, buf):
+        buf.write_u8(value)
+
+class _UniffiConverterUInt32(_UniffiConverterPrimitiveInt):
+    CLASS_NAME = "u32"
+    VALUE_MIN = 0
+    VALUE_MAX = 2**32
+
+    @staticmethod
+    def read(buf):
+        return buf.read_u32()
+
+    @staticmethod
+    def write(value, buf):
+        buf.write_u32(value)
+
+class _UniffiConverterUInt64(_UniffiConverterPrimitiveInt):
+    CLASS_NAME = "u64"
+    VALUE_MIN = 0
+    VALUE_MAX = 2**64
+
+    @staticmethod
+    def read(buf):
+        return buf.read_u64()
+
+    @staticmethod
+    def write(value, buf):
+        buf.write_u64(value)
+
+class _UniffiConverterBool:
+    @classmethod
+    def check_lower(cls, value):
+        return not not value
+
+    @classmethod
+    def lower(cls, value):
+        return 1 if value else 0
+
+    @staticmethod
+    def lift(value):
+        return value != 0
+
+    @classmethod
+    def read(cls, buf):
+        return cls.lift(buf.read_u8())
+
+    @classmethod
+    def write(cls, value, buf):
+        buf.write_u8(value)
+
+class _UniffiConverterString:
+    @staticmethod
+    def check_lower(value):
+        if not isinstance(value, str):
+            raise TypeError("argument must be str, not {}".format(type(value).__name__))
+        return value
+
+    @staticmethod
+    def read(buf):
+        size = buf.read_i32()
+        if size < 0:
+            raise InternalError("Unexpected negative string length")
+        utf8_bytes = buf.read(size)
+        return utf8_bytes.decode("utf-8")
+
+    @staticmethod
+    def write(value, buf):
+        utf8_bytes = value.encode("utf-8")
+        buf.write_i32(len(utf8_bytes))
+        buf.write(utf8_bytes)
+
+    @staticmethod
+    def lift(buf):
+        with buf.consume_with_stream() as stream:
+            return stream.read(stream.remaining()).decode("utf-8")
+
+    @staticmethod
+    def lower(value):
+        with _UniffiRustBuffer.alloc_with_builder() as builder:
+            builder.write(value.encode("utf-8"))
+            return builder.finalize()
+
+class _UniffiConverterBytes(_UniffiConverterRustBuffer):
+    @staticmethod
+    def read(buf):
+        size = buf.read_i32()
+        if size < 0:
+            raise InternalError("Unexpected negative byte string length")
+        return buf.read(size)
+
+    @staticmethod
+    def check_lower(value):
+        try:
+            memoryview(value)
+        except TypeError:
+            raise TypeError("a bytes-like object is required, not {!r}".format(type(value).__name__))
+
+    @staticmethod
+    def write(value, buf):
+        buf.write_i32(len(value))
+        buf.write(value)
+
+
+class CoseSign1Info:
+    status: "SlhDsaStatus"
+    valid: "bool"
+    param: "typing.Optional[ParameterSetId]"
+    key_id: "typing.Optional[bytes]"
+    payload: "typing.Optional[bytes]"
+    def __init__(self, *, status: "SlhDsaStatus", valid: "bool", param: "typing.Optional[ParameterSetId]", key_id: "typing.Optional[bytes]", payload: "typing.Optional[bytes]"):
+        self.status = status
+        self.valid = valid
+        self.param = param
+        self.key_id = key_id
+        self.payload = payload
+
+    def __str__(self):
+        return "CoseSign1Info(status={}, valid={}, param={}, key_id={}, payload={})".format(self.status, self.valid, self.param, self.key_id, self.payload)
+
+    def __eq__(self, other):
+        if self.status != other.status:
+            return False
+        if self.valid != other.valid:
+            return False
+        if self.param != other.param:
+            return False
+        if self.key_id != other.key_id:
+            return False
+        if self.payload != other.payload:
+            return False
+        return True
+
+class _UniffiConverterTypeCoseSign1Info(_UniffiConverterRustBuffer):
+    @staticmethod
+    def read(buf):
+        return CoseSign1Info(
+            status=_UniffiConverterTypeSlhDsaStatus.read(buf),
+            valid=_UniffiConverterBool.read(buf),
+            param=_UniffiConverterOptionalTypeParameterSetId.read(buf),
+            key_id=_UniffiConverterOptionalBytes.read(buf),
+            payload=_UniffiConverterOptionalBytes.read(buf),
+        )
+
+    @staticmethod
+    def check_lower(value):
+        _UniffiConverterTypeSlhDsaStatus.check_lower(value.status)
+        _UniffiConverterBool.check_lower(value.valid)
+        _UniffiConverterOptionalTypeParameterSetId.check_lower(value.param)
+        _UniffiConverterOptionalBytes.check_lower(value.key_id)
+        _UniffiConverterOptionalBytes.check_lower(value.payload)
+
+    @staticmethod
+    def write(value, buf):
+        _UniffiConverterTypeSlhDsaStatus.write(value.status, buf)
+        _UniffiConverterBool.write(value.valid, buf)
+        _UniffiConverterOptionalTypeParameterSetId.write(value.param, buf)
+        _UniffiConverterOptionalBytes.write(value.key_id, buf)
+        _UniffiConverterOptionalBytes.write(value.payload, buf)
+
+
+class CsrExtension:
+    oid: "str"
+    critical: "bool"
+    value: "bytes"
+    def __init__(self, *, oid: "str", critical: "bool", value: "bytes"):
+        self.oid = oid
+        self.critical = critical
+        self.value = value
+
+    def __str__(self):
+        return "CsrExtension(oid={}, critical={}, value={})".format(self.oid, self.critical, self.value)
+
+    def __eq__(self, other):
+        if self.oid != other.oid:
+            return False
+        if self.critical != other.critical:
+            return False
+        if self.value != other.value:
+            return False
+        return True
+
+class _UniffiConverterTypeCsrExtension(_UniffiConverterRustBuffer):
+    @staticmethod
+    def read(buf):
+        return CsrExtension(
+            oid=_UniffiConverterString.read(buf),
+            critical=_UniffiConverterBool.read(buf),
+            value=_UniffiConverterBytes.read(buf),
+        )
+
+    @staticmethod
+    def check_lower(value):
+        _UniffiConverterString.check_lower(value.oid)
+        _UniffiConverterBool.check_lower(value.critical)
+        _UniffiConverterBytes.check_lower(value.value)
+
+    @staticmethod
+    def write(value, buf):
+        _UniffiConverterString.write(value.oid, buf)
+        _UniffiConverterBool.write(value.critical, buf)
+        _UniffiConverterBytes.write(value.value, buf)
+
+
+class CsrInfo:
+    status: "SlhDsaStatus"
+    valid: "bool"
+    param: "typing.Optional[ParameterSetId]"
+    verifying_key: "bytes"
+    subject: "CsrSubject"
+    subject_alt_names: "typing.List[SubjectAltName]"
+    extensions: "typing.List[CsrExtension]"
+    def __init__(self, *, status: "SlhDsaStatus", valid: "bool", param: "typing.Optional[ParameterSetId]", verifying_key: "bytes", subject: "CsrSubject", subject_alt_names: "typing.List[SubjectAltName]", extensions: "typing.List[CsrExtension]"):
+        self.status = status
+        self.valid = valid
+        self.param = param
+        self.verifying_key = verifying_key
+        self.subject = subject
+        self.subject_alt_names = subject_alt_names
+        self.extensions = extensions
+
+    def __str__(self):
+        return "CsrInfo(status={}, valid={}, param={}, verifying_key={}, subject={}, subject_alt_names={}, extensions={})".format(self.status, self.valid, self.param, self.verifying_key, self.subject, self.subject_alt_names, self.extensions)
+
+    def __eq__(self, other):
+        if self.status != other.status:
+            return False
+        if self.valid != other.valid:
+            return False
+        if self.param != other.param:
+            return False
+        if self.verifying_key != other.verifying_key:
+            return False
+        if self.subject != other.subject:
+            return False
+        if self.subject_alt_names != other.subject_alt_names:
+            return False
+        if self.extensions != other.extensions:
+            return False
+        return True
+
+class _UniffiConverterTypeCsrInfo(_UniffiConverterRustBuffer):
+    @staticmethod
+    def read(buf):
+        return CsrInfo(
+            status=_UniffiConverterTypeSlhDsaStatus.read(buf),
+            valid=_UniffiConverterBool.read(buf),
+            param=_UniffiConverterOptionalTypeParameterSetId.read(buf),
+            verifying_key=_UniffiConverterBytes.read(buf),
+            subject=_UniffiConverterTypeCsrSubject.read(buf),
+            subject_alt_names=_UniffiConverterSequenceTypeSubjectAltName.read(buf),
+            extensions=_UniffiConverterSequenceTypeCsrExtension.read(buf),
+        )
+
+    @staticmethod
+    def check_lower(value):
+        _UniffiConverterTypeSlhDsaStatus.check_lower(value.status)
+        _UniffiConverterBool.check_lower(value.valid)
+        _UniffiConverterOptionalTypeParameterSetId.check_lower(value.param)
+        _UniffiConverterBytes.check_lower(value.verifying_key)
+        _UniffiConverterTypeCsrSubject.check_lower(value.subject)
+        _UniffiConverterSequenceTypeSubjectAltName.check_lower(value.subject_alt_names)
+        _UniffiConverterSequenceTypeCsrExtension.check_lower(value.extensions)
+
+    @staticmethod
+    def write(value, buf):
+        _UniffiConverterTypeSlhDsaStatus.write(value.status, buf)
+        _UniffiConverterBool.write(value.valid, buf)
+        _UniffiConverterOptionalTypeParameterSetId.write(value.param, buf)
+        _UniffiConverterBytes.write(value.verifying_key, buf)
+        _UniffiConverterTypeCsrSubject.write(value.subject, buf)
+        _UniffiConverterSequenceTypeSubjectAltName.write(value.subject_alt_names, buf)
+        _UniffiConverterSequenceTypeCsrExtension.write(value.extensions, buf)
+
+
+class CsrSubject:
+    common_name: "typing.Optional[str]"
+    organization: "typing.Optional[str]"
+    organizational_unit: "typing.Optional[str]"
+    country: "typing.Optional[str]"
+    def __init__(self, *, common_name: "typing.Optional[str]", organization: "typing.Optional[str]", organizational_unit: "typing.Optional[str]", country: "typing.Optional[str]"):
+        self.common_name = common_name
+        self.organization = organization
+        self.organizational_unit = organizational_unit
+        self.country = country
+
+    def __str__(self):
+        return "CsrSubject(common_name={}, organization={}, organizational_unit={}, country={})".format(self.common_name, self.organization, self.organizational_unit, self.country)
+
+    def __eq__(self, other):
+        if self.common_name != other.common_name:
+            return False
+        if self.organization != other.organization:
+            return False
+        if self.organizational_unit != other.organizational_unit:
+            return False
+        if self.country != other.country:
+            return False
+        return True
+
+class _UniffiConverterTypeCsrSubject(_UniffiConverterRustBuffer):
+    @staticmethod
+    def read(buf):
+        return CsrSubject(
+            common_name=_UniffiConverterOptionalString.read(buf),
+            organization=_UniffiConverterOptionalString.read(buf),
+            organizational_unit=_UniffiConverterOptionalString.read(buf),
+            country=_UniffiConverterOptionalString.read(buf),
+        )
+
+    @staticmethod
+    def check_lower(value):
+        _UniffiConverterOptionalString.check_lower(value.common_name)
+        _UniffiConverterOptionalString.check_lower(value.organization)
+        _UniffiConverterOptionalString.check_lower(value.organizational_unit)
+        _UniffiConverterOptionalString.check_lower(value.country)
+
+    @staticmethod
+    def write(value, buf):
+        _UniffiConverterOptionalString.write(value.common_name, buf)
+        _UniffiConverterOptionalString.write(value.organization, buf)
+        _UniffiConverterOptionalString.write(value.organizational_unit, buf)
+        _UniffiConverterOptionalString.write(value.country, buf)
+
+
+class DecodeKeyResult:
+    status: "SlhDsaStatus"
+    param: "typing.Optional[ParameterSetId]"
+    key: "bytes"
+    def __init__(self, *, status: "SlhDsaStatus", param: "typing.Optional[ParameterSetId]", key: "bytes"):
+        self.status = status
+        self.param = param
+        self.key = key
+
+    def __str__(self):
+        return "DecodeKeyResult(status={}, param={}, key={})".format(self.status, self.param, self.key)
+
+    def __eq__(self, other):
+        if self.status != other.status:
+            return False
+        if self.param != other.param:
+            return False
+        if self.key != other.key:
+            return False
+        return True
+
+class _UniffiConverterTypeDecodeKeyResult(_UniffiConverterRustBuffer):
+    @staticmethod
+    def read(buf):
+        return DecodeKeyResult(
+            status=_UniffiConverterTypeSlhDsaStatus.read(buf),
+            param=_UniffiConverterOptionalTypeParameterSetId.read(buf),
+            key=_UniffiConverterBytes.read(buf),
+        )
+
+    @staticmethod
+    def check_lower(value):
+        _UniffiConverterTypeSlhDsaStatus.check_lower(value.status)
+        _UniffiConverterOptionalTypeParameterSetId.check_lower(value.param)
+        _UniffiConverterBytes.check_lower(value.key)
+
+    @staticmethod
+    def write(value, buf):
+        _UniffiConverterTypeSlhDsaStatus.write(value.status, buf)
+        _UniffiConverterOptionalTypeParameterSetId.write(value.param, buf)
+        _UniffiConverterBytes.write(value.key, buf)
+
+
+class EncodeResult:
+    status: "SlhDsaStatus"
+    encoded: "bytes"
+    def __init__(self, *, status: "SlhDsaStatus", encoded: "bytes"):
+        self.status = status
+        self.encoded = encoded
+
+    def __str__(self):
+        return "EncodeResult(status={}, encoded={})".format(self.status, self.encoded)
+
+    def __eq__(self, other):
+        if self.status != other.status:
+            return False
+        if self.encoded != other.encoded:
+            return False
+        return True
+
+class _UniffiConverterTypeEncodeResult(_UniffiConverterRustBuffer):
+    @staticmethod
+    def read(buf):
+        return EncodeResult(
+            status=_UniffiConverterTypeSlhDsaStatus.read(buf),
+            encoded=_UniffiConverterBytes.read(buf),
+        )
+
+    @staticmethod
+    def check_lower(value):
+        _UniffiConverterTypeSlhDsaStatus.check_lower(value.status)
+        _UniffiConverterBytes.check_lower(value.encoded)
+
+    @staticmethod
+    def write(value, buf):
+        _UniffiConverterTypeSlhDsaStatus.write(value.status, buf)
+        _UniffiConverterBytes.write(value.encoded, buf)
+
+
+class EnvelopeInfo:
+    status: "SlhDsaStatus"
+    valid: "bool"
+    param: "typing.Optional[ParameterSetId]"
+    mode: "typing.Optional[SignatureMode]"
+    key_id: "typing.Optional[bytes]"
+    context: "bytes"
+    payload: "typing.Optional[bytes]"
+    def __init__(self, *, status: "SlhDsaStatus", valid: "bool", param: "typing.Optional[ParameterSetId]", mode: "typing.Optional[SignatureMode]", key_id: "typing.Optional[bytes]", context: "bytes", payload: "typing.Optional[bytes]"):
+        self.status = status
+        self.valid = valid
+        self.param = param
+        self.mode = mode
+        self.key_id = key_id
+        self.context = context
+        self.payload = payload
+
+    def __str__(self):
+        return "EnvelopeInfo(status={}, valid={}, param={}, mode={}, key_id={}, context={}, payload={})".format(self.status, self.valid, self.param, self.mode, self.key_id, self.context, self.payload)
+
+    def __eq__(self, other):
+        if self.status != other.status:
+            return False
+        if self.valid != other.valid:
+            return False
+        if self.param != other.param:
+            return False
+        if self.mode != other.mode:
+            return False
+        if self.key_id != other.key_id:
+            return False
+        if self.context != other.context:
+            return False
+        if self.payload != other.payload:
+            return False
+        return True
+
+class _UniffiConverterTypeEnvelopeInfo(_UniffiConverterRustBuffer):
+    @staticmethod
+    def read(buf):
+        return EnvelopeInfo(
+            status=_UniffiConverterTypeSlhDsaStatus.read(buf),
+            valid=_UniffiConverterBool.read(buf),
+            param=_UniffiConverterOptionalTypeParameterSetId.read(buf),
+            mode=_UniffiConverterOptionalTypeSignatureMode.read(buf),
+            key_id=_UniffiConverterOptionalBytes.read(buf),
+            context=_UniffiConverterBytes.read(buf),
+            payload=_UniffiConverterOptionalBytes.read(buf),
+        )
+
+    @staticmethod
+    def check_lower(value):
+        _UniffiConverterTypeSlhDsaStatus.check_lower(value.status)
+        _UniffiConverterBool.check_lower(value.valid)
+        _UniffiConverterOptionalTypeParameterSetId.check_lower(value.param)
+        _UniffiConverterOptionalTypeSignatureMode.check_lower(value.mode)
+        _UniffiConverterOptionalBytes.check_lower(value.key_id)
+        _UniffiConverterBytes.check_lower(value.context)
+        _UniffiConverterOptionalBytes.check_lower(value.payload)
+
+    @staticmethod
+    def write(value, buf):
+        _UniffiConverterTypeSlhDsaStatus.write(value.status, buf)
+        _UniffiConverterBool.write(value.valid, buf)
+        _UniffiConverterOptionalTypeParameterSetId.write(value.param, buf)
+        _UniffiConverterOptionalTypeSignatureMode.write(value.mode, buf)
+        _UniffiConverterOptionalBytes.write(value.key_id, buf)
+        _UniffiConverterBytes.write(value.context, buf)
+        _UniffiConverterOptionalBytes.write(value.payload, buf)
+
+
+class FingerprintResult:
+    status: "SlhDsaStatus"
+    fingerprint: "bytes"
+    hex: "str"
+    base32: "str"
+    words: "str"
+    def __init__(self, *, status: "SlhDsaStatus", fingerprint: "bytes", hex: "str", base32: "str", words: "str"):
+        self.status = status
+        self.fingerprint = fingerprint
+        self.hex = hex
+        self.base32 = base32
+        self.words = words
+
+    def __str__(self):
+        return "FingerprintResult(status={}, fingerprint={}, hex={}, base32={}, words={})".format(self.status, self.fingerprint, self.hex, self.base32, self.words)
+
+    def __eq__(self, other):
+        if self.status != other.status:
+            return False
+        if self.fingerprint != other.fingerprint:
+            return False
+        if self.hex != other.hex:
+            return False
+        if self.base32 != other.base32:
+            return False
+        if self.words != other.words:
+            return False
+        return True
+
+class _UniffiConverterTypeFingerprintResult(_UniffiConverterRustBuffer):
+    @staticmethod
+    def read(buf):
+        return FingerprintResult(
+            status=_UniffiConverterTypeSlhDsaStatus.read(buf),
+            fingerprint=_UniffiConverterBytes.read(buf),
+            hex=_UniffiConverterString.read(buf),
+            base32=_UniffiConverterString.read(buf),
+            words=_UniffiConverterString.read(buf),
+        )
+
+    @staticmethod
+    def check_lower(value):
+        _UniffiConverterTypeSlhDsaStatus.check_lower(value.status)
+        _UniffiConverterBytes.check_lower(value.fingerprint)
+        _UniffiConverterString.check_lower(value.hex)
+        _UniffiConverterString.check_lower(value.base32)
+        _UniffiConverterString.check_lower(value.words)
+
+    @staticmethod
+    def write(value, buf):
+        _UniffiConverterTypeSlhDsaStatus.write(value.status, buf)
+        _UniffiConverterBytes.write(value.fingerprint, buf)
+        _UniffiConverterString.write(value.hex, buf)
+        _UniffiConverterString.write(value.base32, buf)
+        _UniffiConverterString.write(value.words, buf)
+
+
+class KeyResult:
+    status: "SlhDsaStatus"
+    verifying_key: "bytes"
+    def __init__(self, *, status: "SlhDsaStatus", verifying_key: "bytes"):
+        self.status = status
+        self.verifying_key = verifying_key
+
+    def __str__(self):
+        return "KeyResult(status={}, verifying_key={})".format(self.status, self.verifying_key)
+
+    def __eq__(self, other):
+        if self.status != other.status:
+            return False
+        if self.verifying_key != other.verifying_key:
+            return False
+        return True
+
+class _UniffiConverterTypeKeyResult(_UniffiConverterRustBuffer):
+    @staticmethod
+    def read(buf):
+        return KeyResult(
+            status=_UniffiConverterTypeSlhDsaStatus.read(buf),
+            verifying_key=_UniffiConverterBytes.read(buf),
+        )
+
+    @staticmethod
+    def check_lower(value):
+        _UniffiConverterTypeSlhDsaStatus.check_lower(value.status)
+        _UniffiConverterBytes.check_lower(value.verifying_key)
+
+    @staticmethod
+    def write(value, buf):
+        _UniffiConverterTypeSlhDsaStatus.write(value.status, buf)
+        _UniffiConverterBytes.write(value.verifying_key, buf)
+
+
+class Keypair:
+    status: "SlhDsaStatus"
+    signing_key: "bytes"
+    verifying_key: "bytes"
+    def __init__(self, *, status: "SlhDsaStatus", signing_key: "bytes", verifying_key: "bytes"):
+        self.status = status
+        self.signing_key = signing_key
+        self.verifying_key = verifying_key
+
+    def __str__(self):
+        return "Keypair(status={}, signing_key={}, verifying_key={})".format(self.status, self.signing_key, self.verifying_key)
+
+    def __eq__(self, other):
+        if self.status != other.status:
+            return False
+        if self.signing_key != other.signing_key:
+            return False
+        if self.verifying_key != other.verifying_key:
+            return False
+        return True
+
+class _UniffiConverterTypeKeypair(_UniffiConverterRustBuffer):
+    @staticmethod
+    def read(buf):
+        return Keypair(
+            status=_UniffiConverterTypeSlhDsaStatus.read(buf),
+            signing_key=_UniffiConverterBytes.read(buf),
+            verifying_key=_UniffiConverterBytes.read(buf),
+        )
+
+    @staticmethod
+    def check_lower(value):
+        _UniffiConverterTypeSlhDsaStatus.check_lower(value.status)
+        _UniffiConverterBytes.check_lower(value.signing_key)
+        _UniffiConverterBytes.check_lower(value.verifying_key)
+
+    @staticmethod
+    def write(value, buf):
+        _UniffiConverterTypeSlhDsaStatus.write(value.status, buf)
+        _UniffiConverterBytes.write(value.signing_key, buf)
+        _UniffiConverterBytes.write(value.verifying_key, buf)
+
+
+class ParameterSetInfo:
+    param: "ParameterSetId"
+    name: "str"
+    hash_family: "HashFamily"
+    fast: "bool"
+    n: "int"
+    h: "int"
+    d: "int"
+    a: "int"
+    k: "int"
+    w: "int"
+    security_category: "int"
+    oid: "str"
+    signing_key_len: "int"
+    verifying_key_len: "int"
+    signature_len: "int"
+    def __init__(self, *, param: "ParameterSetId", name: "str", hash_family: "HashFamily", fast: "bool", n: "int", h: "int", d: "int", a: "int", k: "int", w: "int", security_category: "int", oid: "str", signing_key_len: "int", verifying_key_len: "int", signature_len: "int"):
+        self.param = param
+        self.name = name
+        self.hash_family = hash_family
+        self.fast = fast
+        self.n = n
+        self.h = h
+        self.d = d
+        self.a = a
+        self.k = k
+        self.w = w
+        self.security_category = security_category
+        self.oid = oid
+        self.signing_key_len = signing_key_len
+        self.verifying_key_len = verifying_key_len
+        self.signature_len = signature_len
+
+    def __str__(self):
+        return "ParameterSetInfo(param={}, name={}, hash_family={}, fast={}, n={}, h={}, d={}, a={}, k={}, w={}, security_category={}, oid={}, signing_key_len={}, verifying_key_len={}, signature_len={})".format(self.param, self.name, self.hash_family, self.fast, self.n, self.h, self.d, self.a, self.k, self.w, self.security_category, self.oid, self.signing_key_len, self.verifying_key_len, self.signature_len)
+
+    def __eq__(self, other):
+        if self.param != other.param:
+            return False
+        if self.name != other.name:
+            return False
+        if self.hash_family != other.hash_family:
+            return False
+        if self.fast != other.fast:
+            return False
+        if self.n != other.n:
+            return False
+        if self.h != other.h:
+            return False
+        if self.d != other.d:
+            return False
+        if self.a != other.a:
+            return False
+        if self.k != other.k:
+            return False
+        if self.w != other.w:
+            return False
+        if self.security_category != other.security_category:
+            return False
+        if self.oid != other.oid:
+            return False
+        if self.signing_key_len != other.signing_key_len:
+            return False
+        if self.verifying_key_len != other.verifying_key_len:
+            return False
+        if self.signature_len != other.signature_len:
+            return False
+        return True
+
+class _UniffiConverterTypeParameterSetInfo(_UniffiConverterRustBuffer):
+    @staticmethod
+    def read(buf):
+        return ParameterSetInfo(
+            param=_UniffiConverterTypeParameterSetId.read(buf),
+            name=_UniffiConverterString.read(buf),
+            hash_family=_UniffiConverterTypeHashFamily.read(buf),
+            fast=_UniffiConverterBool.read(buf),
+            n=_UniffiConverterUInt32.read(buf),
+            h=_UniffiConverterUInt32.read(buf),
+            d=_UniffiConverterUInt32.read(buf),
+            a=_UniffiConverterUInt32.read(buf),
+            k=_UniffiConverterUInt32.read(buf),
+            w=_UniffiConverterUInt32.read(buf),
+            security_category=_UniffiConverterUInt8.read(buf),
+            oid=_UniffiConverterString.read(buf),
+            signing_key_len=_UniffiConverterUInt64.read(buf),
+            verifying_key_len=_UniffiConverterUInt64.read(buf),
+            signature_len=_UniffiConverterUInt64.read(buf),
+        )
+
+    @staticmethod
+    def check_lower(value):
+        _UniffiConverterTypeParameterSetId.check_lower(value.param)
+        _UniffiConverterString.check_lower(value.name)
+        _UniffiConverterTypeHashFamily.check_lower(value.hash_family)
+        _UniffiConverterBool.check_lower(value.fast)
+        _UniffiConverterUInt32.check_lower(value.n)
+        _UniffiConverterUInt32.check_lower(value.h)
+        _UniffiConverterUInt32.check_lower(value.d)
+        _UniffiConverterUInt32.check_lower(value.a)
+        _UniffiConverterUInt32.check_lower(value.k)
+        _UniffiConverterUInt32.check_lower(value.w)
+        _UniffiConverterUInt8.check_lower(value.security_category)
+        _UniffiConverterString.check_lower(value.oid)
+        _UniffiConverterUInt64.check_lower(value.signing_key_len)
+        _UniffiConverterUInt64.check_lower(value.verifying_key_len)
+        _UniffiConverterUInt64.check_lower(value.signature_len)
+
+    @staticmethod
+    def write(value, buf):
+        _UniffiConverterTypeParameterSetId.write(value.param, buf)
+        _UniffiConverterString.write(value.name, buf)
+        _UniffiConverterTypeHashFamily.write(value.hash_family, buf)
+        _UniffiConverterBool.write(value.fast, buf)
+        _UniffiConverterUInt32.write(value.n, buf)
+        _UniffiConverterUInt32.write(value.h, buf)
+        _UniffiConverterUInt32.write(value.d, buf)
+        _UniffiConverterUInt32.write(value.a, buf)
+        _UniffiConverterUInt32.write(value.k, buf)
+        _UniffiConverterUInt32.write(value.w, buf)
+        _UniffiConverterUInt8.write(value.security_category, buf)
+        _UniffiConverterString.write(value.oid, buf)
+        _UniffiConverterUInt64.write(value.signing_key_len, buf)
+        _UniffiConverterUInt64.write(value.verifying_key_len, buf)
+        _UniffiConverterUInt64.write(value.signature_len, buf)
+
+
+class SignBatchResult:
+    status: "SlhDsaStatus"
+    signatures: "typing.List[bytes]"
+    def __init__(self, *, status: "SlhDsaStatus", signatures: "typing.List[bytes]"):
+        self.status = status
+        self.signatures = signatures
+
+    def __str__(self):
+        return "SignBatchResult(status={}, signatures={})".format(self.status, self.signatures)
+
+    def __eq__(self, other):
+        if self.status != other.status:
+            return False
+        if self.signatures != other.signatures:
+            return False
+        return True
+
+class _UniffiConverterTypeSignBatchResult(_UniffiConverterRustBuffer):
+    @staticmethod
+    def read(buf):
+        return SignBatchResult(
+            status=_UniffiConverterTypeSlhDsaStatus.read(buf),
+            signatures=_UniffiConverterSequenceBytes.read(buf),
+        )
+
+    @staticmethod
+    def check_lower(value):
+        _UniffiConverterTypeSlhDsaStatus.check_lower(value.status)
+        _UniffiConverterSequenceBytes.check_lower(value.signatures)
+
+    @staticmethod
+    def write(value, buf):
+        _UniffiConverterTypeSlhDsaStatus.write(value.status, buf)
+        _UniffiConverterSequenceBytes.write(value.signatures, buf)
+
+
+class SignResult:
+    status: "SlhDsaStatus"
+    signature: "bytes"
+    def __init__(self, *, status: "SlhDsaStatus", signature: "bytes"):
+        self.status = status
+        self.signature = signature
+
+    def __str__(self):
+        return "SignResult(status={}, signature={})".format(self.status, self.signature)
+
+    def __eq__(self, other):
+        if self.status != other.status:
+            return False
+        if self.signature != other.signature:
+            return False
+        return True
+
+class _UniffiConverterTypeSignResult(_UniffiConverterRustBuffer):
+    @staticmethod
+    def read(buf):
+        return SignResult(
+            status=_UniffiConverterTypeSlhDsaStatus.read(buf),
+            signature=_UniffiConverterBytes.read(buf),
+        )
+
+    @staticmethod
+    def check_lower(value):
+        _UniffiConverterTypeSlhDsaStatus.check_lower(value.status)
+        _UniffiConverterBytes.check_lower(value.signature)
+
+    @staticmethod
+    def write(value, buf):
+        _UniffiConverterTypeSlhDsaStatus.write(value.status, buf)
+        _UniffiConverterBytes.write(value.signature, buf)
+
+
+class VerifyResult:
+    status: "SlhDsaStatus"
+    valid: "bool"
+    def __init__(self, *, status: "SlhDsaStatus", valid: "bool"):
+        self.status = status
+        self.valid = valid
+
+    def __str__(self):
+        return "VerifyResult(status={}, valid={})".format(self.status, self.valid)
+
+    def __eq__(self, other):
+        if self.status != other.status:
+            return False
+        if self.valid != other.valid:
+            return False
+        return True
+
+class _UniffiConverterTypeVerifyResult(_UniffiConverterRustBuffer):
+    @staticmethod
+    def read(buf):
+        return VerifyResult(
+            status=_UniffiConverterTypeSlhDsaStatus.read(buf),
+            valid=_UniffiConverterBool.read(buf),
+        )
+
+    @staticmethod
+    def check_lower(value):
+        _UniffiConverterTypeSlhDsaStatus.check_lower(value.status)
+        _UniffiConverterBool.check_lower(value.valid)
+
+    @staticmethod
+    def write(value, buf):
+        _UniffiConverterTypeSlhDsaStatus.write(value.status, buf)
+        _UniffiConverterBool.write(value.valid, buf)
+
+
+
+
+
+class Aead(enum.Enum):
+    CHA_CHA20_POLY1305 = 0
+    
+    AES256_GCM = 1
+    
+
+
+class _UniffiConverterTypeAead(_UniffiConverterRustBuffer):
+    @staticmethod
+    def read(buf):
+        variant = buf.read_i32()
+        if variant == 1:
+            return Aead.CHA_CHA20_POLY1305
+        if variant == 2:
+            return Aead.AES256_GCM
+        raise InternalError("Raw enum value doesn't match any cases")
+
+    @staticmethod
+    def check_lower(value):
+        if value == Aead.CHA_CHA20_POLY1305:
+            return
+        if value == Aead.AES256_GCM:
+            return
+        raise ValueError(value)
+
+    @staticmethod
+    def write(value, buf):
+        if value == Aead.CHA_CHA20_POLY1305:
+            buf.write_i32(1)
+        if value == Aead.AES256_GCM:
+            buf.write_i32(2)
+
+
+
+
+
+
+
+class FingerprintSource(enum.Enum):
+    RAW = 0
+    
+    SPKI = 1
+    
+
+
+class _UniffiConverterTypeFingerprintSource(_UniffiConverterRustBuffer):
+    @staticmethod
+    def read(buf):
+        variant = buf.read_i32()
+        if variant == 1:
+            return FingerprintSource.RAW
+        if variant == 2:
+            return FingerprintSource.SPKI
+        raise InternalError("Raw enum value doesn't match any cases")
+
+    @staticmethod
+    def check_lower(value):
+        if value == FingerprintSource.RAW:
+            return
+        if value == FingerprintSource.SPKI:
+            return
+        raise ValueError(value)
+
+    @staticmethod
+    def write(value, buf):
+        if value == FingerprintSource.RAW:
+            buf.write_i32(1)
+        if value == FingerprintSource.SPKI:
+            buf.write_i32(2)
+
+
+
+
+
+
+
+class HashFamily(enum.Enum):
+    SHA2 = 0
+    
+    SHAKE = 1
+    
+
+
+class _UniffiConverterTypeHashFamily(_UniffiConverterRustBuffer):
+    @staticmethod
+    def read(buf):
+        variant = buf.read_i32()
+        if variant == 1:
+            return HashFamily.SHA2
+        if variant == 2:
+            return HashFamily.SHAKE
+        raise InternalError("Raw enum value doesn't match any cases")
+
+    @staticmethod
+    def check_lower(value):
+        if value == HashFamily.SHA2:
+            return
+        if value == HashFamily.SHAKE:
+            return
+        raise ValueError(value)
+
+    @staticmethod
+    def write(value, buf):
+        if value == HashFamily.SHA2:
+            buf.write_i32(1)
+        if value == HashFamily.SHAKE:
+            buf.write_i32(2)
+
+
+
+
+
+
+
+class Kdf(enum.Enum):
+    ARGON2ID = 0
+    
+    SCRYPT = 1
+    
+
+
+class _UniffiConverterTypeKdf(_UniffiConverterRustBuffer):
+    @staticmethod
+    def read(buf):
+        variant = buf.read_i32()
+        if variant == 1:
+            return Kdf.ARGON2ID
+        if variant == 2:
+            return Kdf.SCRYPT
+        raise InternalError("Raw enum value doesn't match any cases")
+
+    @staticmethod
+    def check_lower(value):
+        if value == Kdf.ARGON2ID:
+            return
+        if value == Kdf.SCRYPT:
+            return
+        raise ValueError(value)
+
+    @staticmethod
+    def write(value, buf):
+        if value == Kdf.ARGON2ID:
+            buf.write_i32(1)
+        if value == Kdf.SCRYPT:
+            buf.write_i32(2)
+
+
+
+
+
+
+
+class ParameterSetId(enum.Enum):
+    SHAKE128S = 0
+    
+    SHAKE128F = 1
+    
+    SHAKE192S = 2
+    
+    SHAKE192F = 3
+    
+    SHAKE256S = 4
+    
+    SHAKE256F = 5
+    
+    SHA2_128S = 6
+    
+    SHA2_128F = 7
+    
+    SHA2_192S = 8
+    
+    SHA2_192F = 9
+    
+    SHA2_256S = 10
+    
+    SHA2_256F = 11
+    
+
+
+class _UniffiConverterTypeParameterSetId(_UniffiConverterRustBuffer):
+    @staticmethod
+    def read(buf):
+        variant = buf.read_i32()
+        if variant == 1:
+            return ParameterSetId.SHAKE128S
+        if variant == 2:
+            return ParameterSetId.SHAKE128F
+        if variant == 3:
+            return ParameterSetId.SHAKE192S
+        if variant == 4:
+            return ParameterSetId.SHAKE192F
+        if variant == 5:
+            return ParameterSetId.SHAKE256S
+        if variant == 6:
+            return ParameterSetId.SHAKE256F
+        if variant == 7:
+            return ParameterSetId.SHA2_128S
+        if variant == 8:
+            return ParameterSetId.SHA2_128F
+        if variant == 9:
+            return ParameterSetId.SHA2_192S
+        if variant == 10:
+            return ParameterSetId.SHA2_192F
+        if variant == 11:
+            return ParameterSetId.SHA2_256S
+        if variant == 12:
+            return ParameterSetId.SHA2_256F
+        raise InternalError("Raw enum value doesn't match any cases")
+
+    @staticmethod
+    def check_lower(value):
+        if value == ParameterSetId.SHAKE128S:
+            return
+        if value == ParameterSetId.SHAKE128F:
+            return
+        if value == ParameterSetId.SHAKE192S:
+            return
+        if value == ParameterSetId.SHAKE192F:
+            return
+        if value == ParameterSetId.SHAKE256S:
+            return
+        if value == ParameterSetId.SHAKE256F:
+            return
+        if value == ParameterSetId.SHA2_128S:
+            return
+        if value == ParameterSetId.SHA2_128F:
+            return
+        if value == ParameterSetId.SHA2_192S:
+            return
+        if value == ParameterSetId.SHA2_192F:
+            return
+        if value == ParameterSetId.SHA2_256S:
+            return
+        if value == ParameterSetId.SHA2_256F:
+            return
+        raise ValueError(value)
+
+    @staticmethod
+    def write(value, buf):
+        if value == ParameterSetId.SHAKE128S:
+            buf.write_i32(1)
+        if value == ParameterSetId.SHAKE128F:
+            buf.write_i32(2)
+        if value == ParameterSetId.SHAKE192S:
+            buf.write_i32(3)
+        if value == ParameterSetId.SHAKE192F:
+            buf.write_i32(4)
+        if value == ParameterSetId.SHAKE256S:
+            buf.write_i32(5)
+        if value == ParameterSetId.SHAKE256F:
+            buf.write_i32(6)
+        if value == ParameterSetId.SHA2_128S:
+            buf.write_i32(7)
+        if value == ParameterSetId.SHA2_128F:
+            buf.write_i32(8)
+        if value == ParameterSetId.SHA2_192S:
+            buf.write_i32(9)
+        if value == ParameterSetId.SHA2_192F:
+            buf.write_i32(10)
+        if value == ParameterSetId.SHA2_256S:
+            buf.write_i32(11)
+        if value == ParameterSetId.SHA2_256F:
+            buf.write_i32(12)
+
+
+
+
+
+
+
+class SignatureMode(enum.Enum):
+    PURE = 0
+    
+    PRE_HASH_SHA256 = 1
+    
+    PRE_HASH_SHA512 = 2
+    
+    PRE_HASH_SHAKE128 = 3
+    
+    PRE_HASH_SHAKE256 = 4
+    
+
+
+class _UniffiConverterTypeSignatureMode(_UniffiConverterRustBuffer):
+    @staticmethod
+    def read(buf):
+        variant = buf.read_i32()
+        if variant == 1:
+            return SignatureMode.PURE
+        if variant == 2:
+            return SignatureMode.PRE_HASH_SHA256
+        if variant == 3:
+            return SignatureMode.PRE_HASH_SHA512
+        if variant == 4:
+            return SignatureMode.PRE_HASH_SHAKE128
+        if variant == 5:
+            return SignatureMode.PRE_HASH_SHAKE256
+        raise InternalError("Raw enum value doesn't match any cases")
+
+    @staticmethod
+    def check_lower(value):
+        if value == SignatureMode.PURE:
+            return
+        if value == SignatureMode.PRE_HASH_SHA256:
+            return
+        if value == SignatureMode.PRE_HASH_SHA512:
+            return
+        if value == SignatureMode.PRE_HASH_SHAKE128:
+            return
+        if value == SignatureMode.PRE_HASH_SHAKE256:
+            return
+        raise ValueError(value)
+
+    @staticmethod
+    def write(value, buf):
+        if value == SignatureMode.PURE:
+            buf.write_i32(1)
+        if value == SignatureMode.PRE_HASH_SHA256:
+            buf.write_i32(2)
+        if value == SignatureMode.PRE_HASH_SHA512:
+            buf.write_i32(3)
+        if value == SignatureMode.PRE_HASH_SHAKE128:
+            buf.write_i32(4)
+        if value == SignatureMode.PRE_HASH_SHAKE256:
+            buf.write_i32(5)
+
+
+
+
+
+
+
+class SlhDsaStatus(enum.Enum):
+    OK = 0
+    
+    INVALID_PARAM = 1
+    
+    INVALID_LENGTH = 2
+    
+    DECODE_ERROR = 3
+    
+    VERIFY_FAILED = 4
+    
+    CONTEXT_TOO_LONG = 5
+    
+    MALFORMED_SIGNING_KEY = 6
+    
+    MALFORMED_VERIFYING_KEY = 7
+    
+    MALFORMED_SIGNATURE = 8
+    
+    PARAMETER_SET_MISMATCH = 9
+    
+    RNG_FAILURE = 10
+    
+    UNSUPPORTED_ALGORITHM = 11
+    
+    MALFORMED_ENCODING = 12
+    
+    INVALID_CERTIFICATE = 13
+    
+    CERTIFICATE_EXPIRED = 14
+    
+    UNTRUSTED_CHAIN = 15
+    
+    TOKEN_EXPIRED = 16
+    
+    AUDIENCE_MISMATCH = 17
+    
+    SELF_TEST_FAILED = 18
+    
+    FAULT_DETECTED = 19
+    
+    DECRYPTION_FAILED = 20
+    
+
+
+class _UniffiConverterTypeSlhDsaStatus(_UniffiConverterRustBuffer):
+    @staticmethod
+    def read(buf):
+        variant = buf.read_i32()
+        if variant == 1:
+            return SlhDsaStatus.OK
+        if variant == 2:
+            return SlhDsaStatus.INVALID_PARAM
+        if variant == 3:
+            return SlhDsaStatus.INVALID_LENGTH
+        if variant == 4:
+            return SlhDsaStatus.DECODE_ERROR
+        if variant == 5:
+            return SlhDsaStatus.VERIFY_FAILED
+        if variant == 6:
+            return SlhDsaStatus.CONTEXT_TOO_LONG
+        if variant == 7:
+            return SlhDsaStatus.MALFORMED_SIGNING_KEY
+        if variant == 8:
+            return SlhDsaStatus.MALFORMED_VERIFYING_KEY
+        if variant == 9:
+            return SlhDsaStatus.MALFORMED_SIGNATURE
+        if variant == 10:
+            return SlhDsaStatus.PARAMETER_SET_MISMATCH
+        if variant == 11:
+            return SlhDsaStatus.RNG_FAILURE
+        if variant == 12:
+            return SlhDsaStatus.UNSUPPORTED_ALGORITHM
+        if variant == 13:
+            return SlhDsaStatus.MALFORMED_ENCODING
+        if variant == 14:
+            return SlhDsaStatus.INVALID_CERTIFICATE
+        if variant == 15:
+            return SlhDsaStatus.CERTIFICATE_EXPIRED
+        if variant == 16:
+            return SlhDsaStatus.UNTRUSTED_CHAIN
+        if variant == 17:
+            return SlhDsaStatus.TOKEN_EXPIRED
+        if variant == 18:
+            return SlhDsaStatus.AUDIENCE_MISMATCH
+        if variant == 19:
+            return SlhDsaStatus.SELF_TEST_FAILED
+        if variant == 20:
+            return SlhDsaStatus.FAULT_DETECTED
+        if variant == 21:
+            return SlhDsaStatus.DECRYPTION_FAILED
+        raise InternalError("Raw enum value doesn't match any cases")
+
+    @staticmethod
+    def check_lower(value):
+        if value == SlhDsaStatus.OK:
+            return
+        if value == SlhDsaStatus.INVALID_PARAM:
+            return
+        if value == SlhDsaStatus.INVALID_LENGTH:
+            return
+        if value == SlhDsaStatus.DECODE_ERROR:
+            return
+        if value == SlhDsaStatus.VERIFY_FAILED:
+            return
+        if value == SlhDsaStatus.CONTEXT_TOO_LONG:
+            return
+        if value == SlhDsaStatus.MALFORMED_SIGNING_KEY:
+            return
+        if value == SlhDsaStatus.MALFORMED_VERIFYING_KEY:
+            return
+        if value == SlhDsaStatus.MALFORMED_SIGNATURE:
+            return
+        if value == SlhDsaStatus.PARAMETER_SET_MISMATCH:
+            return
+        if value == SlhDsaStatus.RNG_FAILURE:
+            return
+        if value == SlhDsaStatus.UNSUPPORTED_ALGORITHM:
+            return
+        if value == SlhDsaStatus.MALFORMED_ENCODING:
+            return
+        if value == SlhDsaStatus.INVALID_CERTIFICATE:
+            return
+        if value == SlhDsaStatus.CERTIFICATE_EXPIRED:
+            return
+        if value == SlhDsaStatus.UNTRUSTED_CHAIN:
+            return
+        if value == SlhDsaStatus.TOKEN_EXPIRED:
+            return
+        if value == SlhDsaStatus.AUDIENCE_MISMATCH:
+            return
+        if value == SlhDsaStatus.SELF_TEST_FAILED:
+            return
+        if value == SlhDsaStatus.FAULT_DETECTED:
+            return
+        if value == SlhDsaStatus.DECRYPTION_FAILED:
+            return
+        raise ValueError(value)
+
+    @staticmethod
+    def write(value, buf):
+        if value == SlhDsaStatus.OK:
+            buf.write_i32(1)
+        if value == SlhDsaStatus.INVALID_PARAM:
+            buf.write_i32(2)
+        if value == SlhDsaStatus.INVALID_LENGTH:
+            buf.write_i32(3)
+        if value == SlhDsaStatus.DECODE_ERROR:
+            buf.write_i32(4)
         if value == SlhDsaStatus.VERIFY_FAILED:
             buf.write_i32(5)
+        if value == SlhDsaStatus.CONTEXT_TOO_LONG:
+            buf.write_i32(6)
+        if value == SlhDsaStatus.MALFORMED_SIGNING_KEY:
+            buf.write_i32(7)
+        if value == SlhDsaStatus.MALFORMED_VERIFYING_KEY:
+            buf.write_i32(8)
+        if value == SlhDsaStatus.MALFORMED_SIGNATURE:
+            buf.write_i32(9)
+        if value == SlhDsaStatus.PARAMETER_SET_MISMATCH:
+            buf.write_i32(10)
+        if value == SlhDsaStatus.RNG_FAILURE:
+            buf.write_i32(11)
+        if value == SlhDsaStatus.UNSUPPORTED_ALGORITHM:
+            buf.write_i32(12)
+        if value == SlhDsaStatus.MALFORMED_ENCODING:
+            buf.write_i32(13)
+        if value == SlhDsaStatus.INVALID_CERTIFICATE:
+            buf.write_i32(14)
+        if value == SlhDsaStatus.CERTIFICATE_EXPIRED:
+            buf.write_i32(15)
+        if value == SlhDsaStatus.UNTRUSTED_CHAIN:
+            buf.write_i32(16)
+        if value == SlhDsaStatus.TOKEN_EXPIRED:
+            buf.write_i32(17)
+        if value == SlhDsaStatus.AUDIENCE_MISMATCH:
+            buf.write_i32(18)
+        if value == SlhDsaStatus.SELF_TEST_FAILED:
+            buf.write_i32(19)
+        if value == SlhDsaStatus.FAULT_DETECTED:
+            buf.write_i32(20)
+        if value == SlhDsaStatus.DECRYPTION_FAILED:
+            buf.write_i32(21)
 
 
 
-class _UniffiFfiConverterBytes(_UniffiConverterRustBuffer):
-    @staticmethod
-    def read(buf):
-        size = buf.read_i32()
-        if size < 0:
-            raise InternalError("Unexpected negative byte string length")
-        return buf.read(size)
 
-    @staticmethod
-    def check_lower(value):
-        try:
-            memoryview(value)
-        except TypeError:
-            raise TypeError("a bytes-like object is required, not {!r}".format(type(value).__name__))
 
-    @staticmethod
-    def write(value, buf):
-        buf.write_i32(len(value))
-        buf.write(value)
 
-@dataclass
-class KeyResult:
-    def __init__(self, *, status:SlhDsaStatus, verifying_key:bytes):
-        self.status = status
-        self.verifying_key = verifying_key
-        
-        
 
+class SubjectAltName:
+    def __init__(self):
+        raise RuntimeError("SubjectAltName cannot be instantiated directly")
+
+    # Each enum variant is a nested class of the enum itself.
+    class DNS:
+        name: "str"
+
+        def __init__(self,name: "str"):
+            self.name = name
+
+        def __str__(self):
+            return "SubjectAltName.DNS(name={})".format(self.name)
+
+        def __eq__(self, other):
+            if not other.is_DNS():
+                return False
+            if self.name != other.name:
+                return False
+            return True
     
-    def __str__(self):
-        return "KeyResult(status={}, verifying_key={})".format(self.status, self.verifying_key)
-    def __eq__(self, other):
-        if self.status != other.status:
-            return False
-        if self.verifying_key != other.verifying_key:
-            return False
-        return True
+    class EMAIL:
+        address: "str"
+
+        def __init__(self,address: "str"):
+            self.address = address
+
+        def __str__(self):
+            return "SubjectAltName.EMAIL(address={})".format(self.address)
+
+        def __eq__(self, other):
+            if not other.is_EMAIL():
+                return False
+            if self.address != other.address:
+                return False
+            return True
+    
+    class URI:
+        uri: "str"
+
+        def __init__(self,uri: "str"):
+            self.uri = uri
+
+        def __str__(self):
+            return "SubjectAltName.URI(uri={})".format(self.uri)
+
+        def __eq__(self, other):
+            if not other.is_URI():
+                return False
+            if self.uri != other.uri:
+                return False
+            return True
+    
+    class IP:
+        address: "bytes"
+
+        def __init__(self,address: "bytes"):
+            self.address = address
+
+        def __str__(self):
+            return "SubjectAltName.IP(address={})".format(self.address)
+
+        def __eq__(self, other):
+            if not other.is_IP():
+                return False
+            if self.address != other.address:
+                return False
+            return True
+    
+    
+
+    # For each variant, we have `is_NAME` and `is_name` methods for easily checking
+    # whether an instance is that variant.
+    def is_DNS(self) -> bool:
+        return isinstance(self, SubjectAltName.DNS)
+    def is_dns(self) -> bool:
+        return isinstance(self, SubjectAltName.DNS)
+    def is_EMAIL(self) -> bool:
+        return isinstance(self, SubjectAltName.EMAIL)
+    def is_email(self) -> bool:
+        return isinstance(self, SubjectAltName.EMAIL)
+    def is_URI(self) -> bool:
+        return isinstance(self, SubjectAltName.URI)
+    def is_uri(self) -> bool:
+        return isinstance(self, SubjectAltName.URI)
+    def is_IP(self) -> bool:
+        return isinstance(self, SubjectAltName.IP)
+    def is_ip(self) -> bool:
+        return isinstance(self, SubjectAltName.IP)
+    
+
+# Now, a little trick - we make each nested variant class be a subclass of the main
+# enum class, so that method calls and instance checks etc will work intuitively.
+# We might be able to do this a little more neatly with a metaclass, but this'll do.
+SubjectAltName.DNS = type("SubjectAltName.DNS", (SubjectAltName.DNS, SubjectAltName,), {})  # type: ignore
+SubjectAltName.EMAIL = type("SubjectAltName.EMAIL", (SubjectAltName.EMAIL, SubjectAltName,), {})  # type: ignore
+SubjectAltName.URI = type("SubjectAltName.URI", (SubjectAltName.URI, SubjectAltName,), {})  # type: ignore
+SubjectAltName.IP = type("SubjectAltName.IP", (SubjectAltName.IP, SubjectAltName,), {})  # type: ignore
 
-class _UniffiFfiConverterTypeKeyResult(_UniffiConverterRustBuffer):
+
+
+
+class _UniffiConverterTypeSubjectAltName(_UniffiConverterRustBuffer):
     @staticmethod
     def read(buf):
-        return KeyResult(
-            status=_UniffiFfiConverterTypeSlhDsaStatus.read(buf),
-            verifying_key=_UniffiFfiConverterBytes.read(buf),
-        )
+        variant = buf.read_i32()
+        if variant == 1:
+            return SubjectAltName.DNS(
+                _UniffiConverterString.read(buf),
+            )
+        if variant == 2:
+            return SubjectAltName.EMAIL(
+                _UniffiConverterString.read(buf),
+            )
+        if variant == 3:
+            return SubjectAltName.URI(
+                _UniffiConverterString.read(buf),
+            )
+        if variant == 4:
+            return SubjectAltName.IP(
+                _UniffiConverterBytes.read(buf),
+            )
+        raise InternalError("Raw enum value doesn't match any cases")
 
     @staticmethod
     def check_lower(value):
-        _UniffiFfiConverterTypeSlhDsaStatus.check_lower(value.status)
-        _UniffiFfiConverterBytes.check_lower(value.verifying_key)
+        if value.is_DNS():
+            _UniffiConverterString.check_lower(value.name)
+            return
+        if value.is_EMAIL():
+            _UniffiConverterString.check_lower(value.address)
+            return
+        if value.is_URI():
+            _UniffiConverterString.check_lower(value.uri)
+            return
+        if value.is_IP():
+            _UniffiConverterBytes.check_lower(value.address)
+            return
+        raise ValueError(value)
 
     @staticmethod
     def write(value, buf):
-        _UniffiFfiConverterTypeSlhDsaStatus.write(value.status, buf)
-        _UniffiFfiConverterBytes.write(value.verifying_key, buf)
+        if value.is_DNS():
+            buf.write_i32(1)
+            _UniffiConverterString.write(value.name, buf)
+        if value.is_EMAIL():
+            buf.write_i32(2)
+            _UniffiConverterString.write(value.address, buf)
+        if value.is_URI():
+            buf.write_i32(3)
+            _UniffiConverterString.write(value.uri, buf)
+        if value.is_IP():
+            buf.write_i32(4)
+            _UniffiConverterBytes.write(value.address, buf)
 
-@dataclass
-class Keypair:
-    def __init__(self, *, signing_key:bytes, verifying_key:bytes):
-        self.signing_key = signing_key
-        self.verifying_key = verifying_key
-        
-        
 
+
+
+
+class _UniffiConverterOptionalString(_UniffiConverterRustBuffer):
+    @classmethod
+    def check_lower(cls, value):
+        if value is not None:
+            _UniffiConverterString.check_lower(value)
+
+    @classmethod
+    def write(cls, value, buf):
+        if value is None:
+            buf.write_u8(0)
+            return
+
+        buf.write_u8(1)
+        _UniffiConverterString.write(value, buf)
+
+    @classmethod
+    def read(cls, buf):
+        flag = buf.read_u8()
+        if flag == 0:
+            return None
+        elif flag == 1:
+            return _UniffiConverterString.read(buf)
+        else:
+            raise InternalError("Unexpected flag byte for optional type")
+
+
+
+class _UniffiConverterOptionalBytes(_UniffiConverterRustBuffer):
+    @classmethod
+    def check_lower(cls, value):
+        if value is not None:
+            _UniffiConverterBytes.check_lower(value)
+
+    @classmethod
+    def write(cls, value, buf):
+        if value is None:
+            buf.write_u8(0)
+            return
+
+        buf.write_u8(1)
+        _UniffiConverterBytes.write(value, buf)
+
+    @classmethod
+    def read(cls, buf):
+        flag = buf.read_u8()
+        if flag == 0:
+            return None
+        elif flag == 1:
+            return _UniffiConverterBytes.read(buf)
+        else:
+            raise InternalError("Unexpected flag byte for optional type")
+
+
+
+class _UniffiConverterOptionalTypeParameterSetId(_UniffiConverterRustBuffer):
+    @classmethod
+    def check_lower(cls, value):
+        if value is not None:
+            _UniffiConverterTypeParameterSetId.check_lower(value)
+
+    @classmethod
+    def write(cls, value, buf):
+        if value is None:
+            buf.write_u8(0)
+            return
+
+        buf.write_u8(1)
+        _UniffiConverterTypeParameterSetId.write(value, buf)
+
+    @classmethod
+    def read(cls, buf):
+        flag = buf.read_u8()
+        if flag == 0:
+            return None
+        elif flag == 1:
+            return _UniffiConverterTypeParameterSetId.read(buf)
+        else:
+            raise InternalError("Unexpected flag byte for optional type")
+
+
+
+class _UniffiConverterOptionalTypeSignatureMode(_UniffiConverterRustBuffer):
+    @classmethod
+    def check_lower(cls, value):
+        if value is not None:
+            _UniffiConverterTypeSignatureMode.check_lower(value)
+
+    @classmethod
+    def write(cls, value, buf):
+        if value is None:
+            buf.write_u8(0)
+            return
+
+        buf.write_u8(1)
+        _UniffiConverterTypeSignatureMode.write(value, buf)
+
+    @classmethod
+    def read(cls, buf):
+        flag = buf.read_u8()
+        if flag == 0:
+            return None
+        elif flag == 1:
+            return _UniffiConverterTypeSignatureMode.read(buf)
+        else:
+            raise InternalError("Unexpected flag byte for optional type")
+
+
+
+class _UniffiConverterSequenceBytes(_UniffiConverterRustBuffer):
+    @classmethod
+    def check_lower(cls, value):
+        for item in value:
+            _UniffiConverterBytes.check_lower(item)
+
+    @classmethod
+    def write(cls, value, buf):
+        items = len(value)
+        buf.write_i32(items)
+        for item in value:
+            _UniffiConverterBytes.write(item, buf)
+
+    @classmethod
+    def read(cls, buf):
+        count = buf.read_i32()
+        if count < 0:
+            raise InternalError("Unexpected negative sequence length")
+
+        return [
+            _UniffiConverterBytes.read(buf) for i in range(count)
+        ]
+
+
+
+class _UniffiConverterSequenceTypeCsrExtension(_UniffiConverterRustBuffer):
+    @classmethod
+    def check_lower(cls, value):
+        for item in value:
+            _UniffiConverterTypeCsrExtension.check_lower(item)
+
+    @classmethod
+    def write(cls, value, buf):
+        items = len(value)
+        buf.write_i32(items)
+        for item in value:
+            _UniffiConverterTypeCsrExtension.write(item, buf)
+
+    @classmethod
+    def read(cls, buf):
+        count = buf.read_i32()
+        if count < 0:
+            raise InternalError("Unexpected negative sequence length")
+
+        return [
+            _UniffiConverterTypeCsrExtension.read(buf) for i in range(count)
+        ]
+
+
+
+class _UniffiConverterSequenceTypeParameterSetInfo(_UniffiConverterRustBuffer):
+    @classmethod
+    def check_lower(cls, value):
+        for item in value:
+            _UniffiConverterTypeParameterSetInfo.check_lower(item)
+
+    @classmethod
+    def write(cls, value, buf):
+        items = len(value)
+        buf.write_i32(items)
+        for item in value:
+            _UniffiConverterTypeParameterSetInfo.write(item, buf)
+
+    @classmethod
+    def read(cls, buf):
+        count = buf.read_i32()
+        if count < 0:
+            raise InternalError("Unexpected negative sequence length")
+
+        return [
+            _UniffiConverterTypeParameterSetInfo.read(buf) for i in range(count)
+        ]
+
+
+
+class _UniffiConverterSequenceTypeSubjectAltName(_UniffiConverterRustBuffer):
+    @classmethod
+    def check_lower(cls, value):
+        for item in value:
+            _UniffiConverterTypeSubjectAltName.check_lower(item)
+
+    @classmethod
+    def write(cls, value, buf):
+        items = len(value)
+        buf.write_i32(items)
+        for item in value:
+            _UniffiConverterTypeSubjectAltName.write(item, buf)
+
+    @classmethod
+    def read(cls, buf):
+        count = buf.read_i32()
+        if count < 0:
+            raise InternalError("Unexpected negative sequence length")
+
+        return [
+            _UniffiConverterTypeSubjectAltName.read(buf) for i in range(count)
+        ]
+
+# objects.
+
+# Async support
+
+def slh_dsa_cose_sign1_create(param: "ParameterSetId",signing_key: "bytes",payload: "bytes",key_id: "typing.Optional[bytes]",external_aad: "bytes",detached: "bool") -> "EncodeResult":
+    _UniffiConverterTypeParameterSetId.check_lower(param)
     
-    def __str__(self):
-        return "Keypair(signing_key={}, verifying_key={})".format(self.signing_key, self.verifying_key)
-    def __eq__(self, other):
-        if self.signing_key != other.signing_key:
-            return False
-        if self.verifying_key != other.verifying_key:
-            return False
-        return True
+    _UniffiConverterBytes.check_lower(signing_key)
+    
+    _UniffiConverterBytes.check_lower(payload)
+    
+    _UniffiConverterOptionalBytes.check_lower(key_id)
+    
+    _UniffiConverterBytes.check_lower(external_aad)
+    
+    _UniffiConverterBool.check_lower(detached)
+    
+    return _UniffiConverterTypeEncodeResult.lift(_uniffi_rust_call(_UniffiLib.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_cose_sign1_create,
+        _UniffiConverterTypeParameterSetId.lower(param),
+        _UniffiConverterBytes.lower(signing_key),
+        _UniffiConverterBytes.lower(payload),
+        _UniffiConverterOptionalBytes.lower(key_id),
+        _UniffiConverterBytes.lower(external_aad),
+        _UniffiConverterBool.lower(detached)))
+
+
+def slh_dsa_cose_sign1_verify(verifying_key: "bytes",encoded: "bytes",detached_payload: "typing.Optional[bytes]",external_aad: "bytes") -> "CoseSign1Info":
+    _UniffiConverterBytes.check_lower(verifying_key)
+    
+    _UniffiConverterBytes.check_lower(encoded)
+    
+    _UniffiConverterOptionalBytes.check_lower(detached_payload)
+    
+    _UniffiConverterBytes.check_lower(external_aad)
+    
+    return _UniffiConverterTypeCoseSign1Info.lift(_uniffi_rust_call(_UniffiLib.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_cose_sign1_verify,
+        _UniffiConverterBytes.lower(verifying_key),
+        _UniffiConverterBytes.lower(encoded),
+        _UniffiConverterOptionalBytes.lower(detached_payload),
+        _UniffiConverterBytes.lower(external_aad)))
+
+
+def slh_dsa_csr_create(param: "ParameterSetId",signing_key: "bytes",subject: "CsrSubject",subject_alt_names: "typing.List[SubjectAltName]",extensions: "typing.List[CsrExtension]",pem: "bool") -> "EncodeResult":
+    _UniffiConverterTypeParameterSetId.check_lower(param)
+    
+    _UniffiConverterBytes.check_lower(signing_key)
+    
+    _UniffiConverterTypeCsrSubject.check_lower(subject)
+    
+    _UniffiConverterSequenceTypeSubjectAltName.check_lower(subject_alt_names)
+    
+    _UniffiConverterSequenceTypeCsrExtension.check_lower(extensions)
+    
+    _UniffiConverterBool.check_lower(pem)
+    
+    return _UniffiConverterTypeEncodeResult.lift(_uniffi_rust_call(_UniffiLib.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_csr_create,
+        _UniffiConverterTypeParameterSetId.lower(param),
+        _UniffiConverterBytes.lower(signing_key),
+        _UniffiConverterTypeCsrSubject.lower(subject),
+        _UniffiConverterSequenceTypeSubjectAltName.lower(subject_alt_names),
+        _UniffiConverterSequenceTypeCsrExtension.lower(extensions),
+        _UniffiConverterBool.lower(pem)))
+
+
+def slh_dsa_csr_verify(encoded: "bytes") -> "CsrInfo":
+    _UniffiConverterBytes.check_lower(encoded)
+    
+    return _UniffiConverterTypeCsrInfo.lift(_uniffi_rust_call(_UniffiLib.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_csr_verify,
+        _UniffiConverterBytes.lower(encoded)))
+
+
+def slh_dsa_envelope_decode(encoded: "bytes") -> "EnvelopeInfo":
+    _UniffiConverterBytes.check_lower(encoded)
+    
+    return _UniffiConverterTypeEnvelopeInfo.lift(_uniffi_rust_call(_UniffiLib.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_envelope_decode,
+        _UniffiConverterBytes.lower(encoded)))
+
+
+def slh_dsa_envelope_sign(param: "ParameterSetId",signing_key: "bytes",payload: "bytes",ctx: "bytes",key_id: "typing.Optional[bytes]",mode: "SignatureMode",detached: "bool") -> "EncodeResult":
+    _UniffiConverterTypeParameterSetId.check_lower(param)
+    
+    _UniffiConverterBytes.check_lower(signing_key)
+    
+    _UniffiConverterBytes.check_lower(payload)
+    
+    _UniffiConverterBytes.check_lower(ctx)
+    
+    _UniffiConverterOptionalBytes.check_lower(key_id)
+    
+    _UniffiConverterTypeSignatureMode.check_lower(mode)
+    
+    _UniffiConverterBool.check_lower(detached)
+    
+    return _UniffiConverterTypeEncodeResult.lift(_uniffi_rust_call(_UniffiLib.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_envelope_sign,
+        _UniffiConverterTypeParameterSetId.lower(param),
+        _UniffiConverterBytes.lower(signing_key),
+        _UniffiConverterBytes.lower(payload),
+        _UniffiConverterBytes.lower(ctx),
+        _UniffiConverterOptionalBytes.lower(key_id),
+        _UniffiConverterTypeSignatureMode.lower(mode),
+        _UniffiConverterBool.lower(detached)))
+
+
+def slh_dsa_envelope_verify(verifying_key: "bytes",encoded: "bytes",detached_payload: "typing.Optional[bytes]") -> "EnvelopeInfo":
+    _UniffiConverterBytes.check_lower(verifying_key)
+    
+    _UniffiConverterBytes.check_lower(encoded)
+    
+    _UniffiConverterOptionalBytes.check_lower(detached_payload)
+    
+    return _UniffiConverterTypeEnvelopeInfo.lift(_uniffi_rust_call(_UniffiLib.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_envelope_verify,
+        _UniffiConverterBytes.lower(verifying_key),
+        _UniffiConverterBytes.lower(encoded),
+        _UniffiConverterOptionalBytes.lower(detached_payload)))
+
+
+def slh_dsa_fingerprint(param: "ParameterSetId",verifying_key: "bytes",source: "FingerprintSource") -> "FingerprintResult":
+    _UniffiConverterTypeParameterSetId.check_lower(param)
+    
+    _UniffiConverterBytes.check_lower(verifying_key)
+    
+    _UniffiConverterTypeFingerprintSource.check_lower(source)
+    
+    return _UniffiConverterTypeFingerprintResult.lift(_uniffi_rust_call(_UniffiLib.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_fingerprint,
+        _UniffiConverterTypeParameterSetId.lower(param),
+        _UniffiConverterBytes.lower(verifying_key),
+        _UniffiConverterTypeFingerprintSource.lower(source)))
+
+
+def slh_dsa_key_file_len(param: "ParameterSetId") -> "int":
+    _UniffiConverterTypeParameterSetId.check_lower(param)
+    
+    return _UniffiConverterUInt64.lift(_uniffi_rust_call(_UniffiLib.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_key_file_len,
+        _UniffiConverterTypeParameterSetId.lower(param)))
+
+
+def slh_dsa_keypair_from_seed(param: "ParameterSetId",sk_seed: "bytes",sk_prf: "bytes",pk_seed: "bytes") -> "Keypair":
+    _UniffiConverterTypeParameterSetId.check_lower(param)
+    
+    _UniffiConverterBytes.check_lower(sk_seed)
+    
+    _UniffiConverterBytes.check_lower(sk_prf)
+    
+    _UniffiConverterBytes.check_lower(pk_seed)
+    
+    return _UniffiConverterTypeKeypair.lift(_uniffi_rust_call(_UniffiLib.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_keypair_from_seed,
+        _UniffiConverterTypeParameterSetId.lower(param),
+        _UniffiConverterBytes.lower(sk_seed),
+        _UniffiConverterBytes.lower(sk_prf),
+        _UniffiConverterBytes.lower(pk_seed)))
+
+
+def slh_dsa_keypair_generate(param: "ParameterSetId") -> "Keypair":
+    _UniffiConverterTypeParameterSetId.check_lower(param)
+    
+    return _UniffiConverterTypeKeypair.lift(_uniffi_rust_call(_UniffiLib.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_keypair_generate,
+        _UniffiConverterTypeParameterSetId.lower(param)))
+
+
+def slh_dsa_parameter_name(param: "ParameterSetId") -> "str":
+    _UniffiConverterTypeParameterSetId.check_lower(param)
+    
+    return _UniffiConverterString.lift(_uniffi_rust_call(_UniffiLib.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_parameter_name,
+        _UniffiConverterTypeParameterSetId.lower(param)))
+
+
+def slh_dsa_parameter_set_from_name(name: "str") -> "typing.Optional[ParameterSetId]":
+    _UniffiConverterString.check_lower(name)
+    
+    return _UniffiConverterOptionalTypeParameterSetId.lift(_uniffi_rust_call(_UniffiLib.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_parameter_set_from_name,
+        _UniffiConverterString.lower(name)))
+
+
+def slh_dsa_parameter_set_from_oid(oid: "str") -> "typing.Optional[ParameterSetId]":
+    _UniffiConverterString.check_lower(oid)
+    
+    return _UniffiConverterOptionalTypeParameterSetId.lift(_uniffi_rust_call(_UniffiLib.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_parameter_set_from_oid,
+        _UniffiConverterString.lower(oid)))
+
+
+def slh_dsa_parameter_sets() -> "typing.List[ParameterSetInfo]":
+    return _UniffiConverterSequenceTypeParameterSetInfo.lift(_uniffi_rust_call(_UniffiLib.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_parameter_sets,))
+
+
+def slh_dsa_seed_len(param: "ParameterSetId") -> "int":
+    _UniffiConverterTypeParameterSetId.check_lower(param)
+    
+    return _UniffiConverterUInt64.lift(_uniffi_rust_call(_UniffiLib.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_seed_len,
+        _UniffiConverterTypeParameterSetId.lower(param)))
 
-class _UniffiFfiConverterTypeKeypair(_UniffiConverterRustBuffer):
-    @staticmethod
-    def read(buf):
-        return Keypair(
-            signing_key=_UniffiFfiConverterBytes.read(buf),
-            verifying_key=_UniffiFfiConverterBytes.read(buf),
-        )
 
-    @staticmethod
-    def check_lower(value):
-        _UniffiFfiConverterBytes.check_lower(value.signing_key)
-        _UniffiFfiConverterBytes.check_lower(value.verifying_key)
+def slh_dsa_set_verify_after_sign(enabled: "bool") -> None:
+    _UniffiConverterBool.check_lower(enabled)
+    
+    _uniffi_rust_call(_UniffiLib.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_set_verify_after_sign,
+        _UniffiConverterBool.lower(enabled))
 
-    @staticmethod
-    def write(value, buf):
-        _UniffiFfiConverterBytes.write(value.signing_key, buf)
-        _UniffiFfiConverterBytes.write(value.verifying_key, buf)
 
-@dataclass
-class SignResult:
-    def __init__(self, *, status:SlhDsaStatus, signature:bytes):
-        self.status = status
-        self.signature = signature
-        
-        
+def slh_dsa_sign(param: "ParameterSetId",signing_key: "bytes",msg: "bytes",ctx: "bytes") -> "SignResult":
+    _UniffiConverterTypeParameterSetId.check_lower(param)
+    
+    _UniffiConverterBytes.check_lower(signing_key)
+    
+    _UniffiConverterBytes.check_lower(msg)
+    
+    _UniffiConverterBytes.check_lower(ctx)
+    
+    return _UniffiConverterTypeSignResult.lift(_uniffi_rust_call(_UniffiLib.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_sign,
+        _UniffiConverterTypeParameterSetId.lower(param),
+        _UniffiConverterBytes.lower(signing_key),
+        _UniffiConverterBytes.lower(msg),
+        _UniffiConverterBytes.lower(ctx)))
+
 
+def slh_dsa_sign_batch(param: "ParameterSetId",signing_key: "bytes",msgs: "typing.List[bytes]",ctx: "bytes") -> "SignBatchResult":
+    _UniffiConverterTypeParameterSetId.check_lower(param)
     
-    def __str__(self):
-        return "SignResult(status={}, signature={})".format(self.status, self.signature)
-    def __eq__(self, other):
-        if self.status != other.status:
-            return False
-        if self.signature != other.signature:
-            return False
-        return True
+    _UniffiConverterBytes.check_lower(signing_key)
+    
+    _UniffiConverterSequenceBytes.check_lower(msgs)
+    
+    _UniffiConverterBytes.check_lower(ctx)
+    
+    return _UniffiConverterTypeSignBatchResult.lift(_uniffi_rust_call(_UniffiLib.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_sign_batch,
+        _UniffiConverterTypeParameterSetId.lower(param),
+        _UniffiConverterBytes.lower(signing_key),
+        _UniffiConverterSequenceBytes.lower(msgs),
+        _UniffiConverterBytes.lower(ctx)))
 
-class _UniffiFfiConverterTypeSignResult(_UniffiConverterRustBuffer):
-    @staticmethod
-    def read(buf):
-        return SignResult(
-            status=_UniffiFfiConverterTypeSlhDsaStatus.read(buf),
-            signature=_UniffiFfiConverterBytes.read(buf),
-        )
 
-    @staticmethod
-    def check_lower(value):
-        _UniffiFfiConverterTypeSlhDsaStatus.check_lower(value.status)
-        _UniffiFfiConverterBytes.check_lower(value.signature)
+def slh_dsa_sign_checked(param: "ParameterSetId",signing_key: "bytes",msg: "bytes",ctx: "bytes") -> "SignResult":
+    _UniffiConverterTypeParameterSetId.check_lower(param)
+    
+    _UniffiConverterBytes.check_lower(signing_key)
+    
+    _UniffiConverterBytes.check_lower(msg)
+    
+    _UniffiConverterBytes.check_lower(ctx)
+    
+    return _UniffiConverterTypeSignResult.lift(_uniffi_rust_call(_UniffiLib.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_sign_checked,
+        _UniffiConverterTypeParameterSetId.lower(param),
+        _UniffiConverterBytes.lower(signing_key),
+        _UniffiConverterBytes.lower(msg),
+        _UniffiConverterBytes.lower(ctx)))
 
-    @staticmethod
-    def write(value, buf):
-        _UniffiFfiConverterTypeSlhDsaStatus.write(value.status, buf)
-        _UniffiFfiConverterBytes.write(value.signature, buf)
 
-class _UniffiFfiConverterBoolean:
-    @classmethod
-    def check_lower(cls, value):
-        return not not value
+def slh_dsa_sign_deterministic(param: "ParameterSetId",signing_key: "bytes",msg: "bytes",ctx: "bytes") -> "SignResult":
+    _UniffiConverterTypeParameterSetId.check_lower(param)
+    
+    _UniffiConverterBytes.check_lower(signing_key)
+    
+    _UniffiConverterBytes.check_lower(msg)
+    
+    _UniffiConverterBytes.check_lower(ctx)
+    
+    return _UniffiConverterTypeSignResult.lift(_uniffi_rust_call(_UniffiLib.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_sign_deterministic,
+        _UniffiConverterTypeParameterSetId.lower(param),
+        _UniffiConverterBytes.lower(signing_key),
+        _UniffiConverterBytes.lower(msg),
+        _UniffiConverterBytes.lower(ctx)))
 
-    @classmethod
-    def lower(cls, value):
-        return 1 if value else 0
 
-    @staticmethod
-    def lift(value):
-        return value != 0
+def slh_dsa_signature_len(param: "ParameterSetId") -> "int":
+    _UniffiConverterTypeParameterSetId.check_lower(param)
+    
+    return _UniffiConverterUInt64.lift(_uniffi_rust_call(_UniffiLib.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_signature_len,
+        _UniffiConverterTypeParameterSetId.lower(param)))
 
-    @classmethod
-    def read(cls, buf):
-        return cls.lift(buf.read_u8())
 
-    @classmethod
-    def write(cls, value, buf):
-        buf.write_u8(value)
+def slh_dsa_signing_key_from_cose_key(encoded: "bytes") -> "DecodeKeyResult":
+    _UniffiConverterBytes.check_lower(encoded)
+    
+    return _UniffiConverterTypeDecodeKeyResult.lift(_uniffi_rust_call(_UniffiLib.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_signing_key_from_cose_key,
+        _UniffiConverterBytes.lower(encoded)))
 
-@dataclass
-class VerifyResult:
-    def __init__(self, *, status:SlhDsaStatus, valid:bool):
-        self.status = status
-        self.valid = valid
-        
-        
 
+def slh_dsa_signing_key_from_encrypted_pkcs8(encoded: "bytes",passphrase: "bytes") -> "DecodeKeyResult":
+    _UniffiConverterBytes.check_lower(encoded)
     
-    def __str__(self):
-        return "VerifyResult(status={}, valid={})".format(self.status, self.valid)
-    def __eq__(self, other):
-        if self.status != other.status:
-            return False
-        if self.valid != other.valid:
-            return False
-        return True
+    _UniffiConverterBytes.check_lower(passphrase)
+    
+    return _UniffiConverterTypeDecodeKeyResult.lift(_uniffi_rust_call(_UniffiLib.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_signing_key_from_encrypted_pkcs8,
+        _UniffiConverterBytes.lower(encoded),
+        _UniffiConverterBytes.lower(passphrase)))
 
-class _UniffiFfiConverterTypeVerifyResult(_UniffiConverterRustBuffer):
-    @staticmethod
-    def read(buf):
-        return VerifyResult(
-            status=_UniffiFfiConverterTypeSlhDsaStatus.read(buf),
-            valid=_UniffiFfiConverterBoolean.read(buf),
-        )
 
-    @staticmethod
-    def check_lower(value):
-        _UniffiFfiConverterTypeSlhDsaStatus.check_lower(value.status)
-        _UniffiFfiConverterBoolean.check_lower(value.valid)
+def slh_dsa_signing_key_from_key_file(encoded: "bytes",passphrase: "bytes") -> "DecodeKeyResult":
+    _UniffiConverterBytes.check_lower(encoded)
+    
+    _UniffiConverterBytes.check_lower(passphrase)
+    
+    return _UniffiConverterTypeDecodeKeyResult.lift(_uniffi_rust_call(_UniffiLib.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_signing_key_from_key_file,
+        _UniffiConverterBytes.lower(encoded),
+        _UniffiConverterBytes.lower(passphrase)))
 
-    @staticmethod
-    def write(value, buf):
-        _UniffiFfiConverterTypeSlhDsaStatus.write(value.status, buf)
-        _UniffiFfiConverterBoolean.write(value.valid, buf)
+
+def slh_dsa_signing_key_from_pkcs8(encoded: "bytes") -> "DecodeKeyResult":
+    _UniffiConverterBytes.check_lower(encoded)
+    
+    return _UniffiConverterTypeDecodeKeyResult.lift(_uniffi_rust_call(_UniffiLib.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_signing_key_from_pkcs8,
+        _UniffiConverterBytes.lower(encoded)))
 
 
+def slh_dsa_signing_key_len(param: "ParameterSetId") -> "int":
+    _UniffiConverterTypeParameterSetId.check_lower(param)
+    
+    return _UniffiConverterUInt64.lift(_uniffi_rust_call(_UniffiLib.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_signing_key_len,
+        _UniffiConverterTypeParameterSetId.lower(param)))
 
 
+def slh_dsa_signing_key_to_cose_key(param: "ParameterSetId",signing_key: "bytes") -> "EncodeResult":
+    _UniffiConverterTypeParameterSetId.check_lower(param)
+    
+    _UniffiConverterBytes.check_lower(signing_key)
+    
+    return _UniffiConverterTypeEncodeResult.lift(_uniffi_rust_call(_UniffiLib.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_signing_key_to_cose_key,
+        _UniffiConverterTypeParameterSetId.lower(param),
+        _UniffiConverterBytes.lower(signing_key)))
 
 
-class ParameterSetId(enum.Enum):
+def slh_dsa_signing_key_to_encrypted_pkcs8(param: "ParameterSetId",signing_key: "bytes",passphrase: "bytes",pem: "bool") -> "EncodeResult":
+    _UniffiConverterTypeParameterSetId.check_lower(param)
     
-    SHAKE128S = 0
+    _UniffiConverterBytes.check_lower(signing_key)
     
-    SHAKE128F = 1
+    _UniffiConverterBytes.check_lower(passphrase)
     
-    SHAKE192S = 2
+    _UniffiConverterBool.check_lower(pem)
     
-    SHAKE192F = 3
+    return _UniffiConverterTypeEncodeResult.lift(_uniffi_rust_call(_UniffiLib.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_signing_key_to_encrypted_pkcs8,
+        _UniffiConverterTypeParameterSetId.lower(param),
+        _UniffiConverterBytes.lower(signing_key),
+        _UniffiConverterBytes.lower(passphrase),
+        _UniffiConverterBool.lower(pem)))
+
+
+def slh_dsa_signing_key_to_key_file(param: "ParameterSetId",signing_key: "bytes",passphrase: "bytes",kdf: "Kdf",aead: "Aead") -> "EncodeResult":
+    _UniffiConverterTypeParameterSetId.check_lower(param)
     
-    SHAKE256S = 4
+    _UniffiConverterBytes.check_lower(signing_key)
     
-    SHAKE256F = 5
+    _UniffiConverterBytes.check_lower(passphrase)
     
-    SHA2_128S = 6
+    _UniffiConverterTypeKdf.check_lower(kdf)
     
-    SHA2_128F = 7
+    _UniffiConverterTypeAead.check_lower(aead)
     
-    SHA2_192S = 8
+    return _UniffiConverterTypeEncodeResult.lift(_uniffi_rust_call(_UniffiLib.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_signing_key_to_key_file,
+        _UniffiConverterTypeParameterSetId.lower(param),
+        _UniffiConverterBytes.lower(signing_key),
+        _UniffiConverterBytes.lower(passphrase),
+        _UniffiConverterTypeKdf.lower(kdf),
+        _UniffiConverterTypeAead.lower(aead)))
+
+
+def slh_dsa_signing_key_to_pkcs8(param: "ParameterSetId",signing_key: "bytes",pem: "bool") -> "EncodeResult":
+    _UniffiConverterTypeParameterSetId.check_lower(param)
     
-    SHA2_192F = 9
+    _UniffiConverterBytes.check_lower(signing_key)
     
-    SHA2_256S = 10
+    _UniffiConverterBool.check_lower(pem)
     
-    SHA2_256F = 11
+    return _UniffiConverterTypeEncodeResult.lift(_uniffi_rust_call(_UniffiLib.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_signing_key_to_pkcs8,
+        _UniffiConverterTypeParameterSetId.lower(param),
+        _UniffiConverterBytes.lower(signing_key),
+        _UniffiConverterBool.lower(pem)))
+
+
+def slh_dsa_validate_signing_key(param: "ParameterSetId",signing_key: "bytes") -> "SlhDsaStatus":
+    _UniffiConverterTypeParameterSetId.check_lower(param)
     
+    _UniffiConverterBytes.check_lower(signing_key)
+    
+    return _UniffiConverterTypeSlhDsaStatus.lift(_uniffi_rust_call(_UniffiLib.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_validate_signing_key,
+        _UniffiConverterTypeParameterSetId.lower(param),
+        _UniffiConverterBytes.lower(signing_key)))
 
 
-class _UniffiFfiConverterTypeParameterSetId(_UniffiConverterRustBuffer):
-    @staticmethod
-    def read(buf):
-        variant = buf.read_i32()
-        if variant == 1:
-            return ParameterSetId.SHAKE128S
-        if variant == 2:
-            return ParameterSetId.SHAKE128F
-        if variant == 3:
-            return ParameterSetId.SHAKE192S
-        if variant == 4:
-            return ParameterSetId.SHAKE192F
-        if variant == 5:
-            return ParameterSetId.SHAKE256S
-        if variant == 6:
-            return ParameterSetId.SHAKE256F
-        if variant == 7:
-            return ParameterSetId.SHA2_128S
-        if variant == 8:
-            return ParameterSetId.SHA2_128F
-        if variant == 9:
-            return ParameterSetId.SHA2_192S
-        if variant == 10:
-            return ParameterSetId.SHA2_192F
-        if variant == 11:
-            return ParameterSetId.SHA2_256S
-        if variant == 12:
-            return ParameterSetId.SHA2_256F
-        raise InternalError("Raw enum value doesn't match any cases")
+def slh_dsa_verify(param: "ParameterSetId",verifying_key: "bytes",msg: "bytes",ctx: "bytes",signature: "bytes") -> "VerifyResult":
+    _UniffiConverterTypeParameterSetId.check_lower(param)
+    
+    _UniffiConverterBytes.check_lower(verifying_key)
+    
+    _UniffiConverterBytes.check_lower(msg)
+    
+    _UniffiConverterBytes.check_lower(ctx)
+    
+    _UniffiConverterBytes.check_lower(signature)
+    
+    return _UniffiConverterTypeVerifyResult.lift(_uniffi_rust_call(_UniffiLib.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_verify,
+        _UniffiConverterTypeParameterSetId.lower(param),
+        _UniffiConverterBytes.lower(verifying_key),
+        _UniffiConverterBytes.lower(msg),
+        _UniffiConverterBytes.lower(ctx),
+        _UniffiConverterBytes.lower(signature)))
 
-    @staticmethod
-    def check_lower(value):
-        if value == ParameterSetId.SHAKE128S:
-            return
-        if value == ParameterSetId.SHAKE128F:
-            return
-        if value == ParameterSetId.SHAKE192S:
-            return
-        if value == ParameterSetId.SHAKE192F:
-            return
-        if value == ParameterSetId.SHAKE256S:
-            return
-        if value == ParameterSetId.SHAKE256F:
-            return
-        if value == ParameterSetId.SHA2_128S:
-            return
-        if value == ParameterSetId.SHA2_128F:
-            return
-        if value == ParameterSetId.SHA2_192S:
-            return
-        if value == ParameterSetId.SHA2_192F:
-            return
-        if value == ParameterSetId.SHA2_256S:
-            return
-        if value == ParameterSetId.SHA2_256F:
-            return
-        raise ValueError(value)
 
-    @staticmethod
-    def write(value, buf):
-        if value == ParameterSetId.SHAKE128S:
-            buf.write_i32(1)
-        if value == ParameterSetId.SHAKE128F:
-            buf.write_i32(2)
-        if value == ParameterSetId.SHAKE192S:
-            buf.write_i32(3)
-        if value == ParameterSetId.SHAKE192F:
-            buf.write_i32(4)
-        if value == ParameterSetId.SHAKE256S:
-            buf.write_i32(5)
-        if value == ParameterSetId.SHAKE256F:
-            buf.write_i32(6)
-        if value == ParameterSetId.SHA2_128S:
-            buf.write_i32(7)
-        if value == ParameterSetId.SHA2_128F:
-            buf.write_i32(8)
-        if value == ParameterSetId.SHA2_192S:
-            buf.write_i32(9)
-        if value == ParameterSetId.SHA2_192F:
-            buf.write_i32(10)
-        if value == ParameterSetId.SHA2_256S:
-            buf.write_i32(11)
-        if value == ParameterSetId.SHA2_256F:
-            buf.write_i32(12)
+def slh_dsa_verify_after_sign_enabled() -> "bool":
+    return _UniffiConverterBool.lift(_uniffi_rust_call(_UniffiLib.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_verify_after_sign_enabled,))
 
 
+def slh_dsa_verifying_key_from_cose_key(encoded: "bytes") -> "DecodeKeyResult":
+    _UniffiConverterBytes.check_lower(encoded)
+    
+    return _UniffiConverterTypeDecodeKeyResult.lift(_uniffi_rust_call(_UniffiLib.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_verifying_key_from_cose_key,
+        _UniffiConverterBytes.lower(encoded)))
 
-class _UniffiFfiConverterString:
-    @staticmethod
-    def check_lower(value):
-        if not isinstance(value, str):
-            raise TypeError("argument must be str, not {}".format(type(value).__name__))
-        return value
 
-    @staticmethod
-    def read(buf):
-        size = buf.read_i32()
-        if size < 0:
-            raise InternalError("Unexpected negative string length")
-        utf8_bytes = buf.read(size)
-        return utf8_bytes.decode("utf-8")
+def slh_dsa_verifying_key_from_signing_key(param: "ParameterSetId",signing_key: "bytes") -> "KeyResult":
+    _UniffiConverterTypeParameterSetId.check_lower(param)
+    
+    _UniffiConverterBytes.check_lower(signing_key)
+    
+    return _UniffiConverterTypeKeyResult.lift(_uniffi_rust_call(_UniffiLib.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_verifying_key_from_signing_key,
+        _UniffiConverterTypeParameterSetId.lower(param),
+        _UniffiConverterBytes.lower(signing_key)))
 
-    @staticmethod
-    def write(value, buf):
-        utf8_bytes = value.encode("utf-8")
-        buf.write_i32(len(utf8_bytes))
-        buf.write(utf8_bytes)
 
-    @staticmethod
-    def lift(buf):
-        with buf.consume_with_stream() as stream:
-            return stream.read(stream.remaining()).decode("utf-8")
+def slh_dsa_verifying_key_from_signing_key_validated(param: "ParameterSetId",signing_key: "bytes") -> "KeyResult":
+    _UniffiConverterTypeParameterSetId.check_lower(param)
+    
+    _UniffiConverterBytes.check_lower(signing_key)
+    
+    return _UniffiConverterTypeKeyResult.lift(_uniffi_rust_call(_UniffiLib.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_verifying_key_from_signing_key_validated,
+        _UniffiConverterTypeParameterSetId.lower(param),
+        _UniffiConverterBytes.lower(signing_key)))
 
-    @staticmethod
-    def lower(value):
-        with _UniffiRustBuffer.alloc_with_builder() as builder:
-            builder.write(value.encode("utf-8"))
-            return builder.finalize()
 
-class _UniffiFfiConverterUInt64(_UniffiConverterPrimitiveInt):
-    CLASS_NAME = "u64"
-    VALUE_MIN = 0
-    VALUE_MAX = 2**64
+def slh_dsa_verifying_key_from_spki(encoded: "bytes") -> "DecodeKeyResult":
+    _UniffiConverterBytes.check_lower(encoded)
+    
+    return _UniffiConverterTypeDecodeKeyResult.lift(_uniffi_rust_call(_UniffiLib.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_verifying_key_from_spki,
+        _UniffiConverterBytes.lower(encoded)))
 
-    @staticmethod
-    def read(buf):
-        return buf.read_u64()
 
-    @staticmethod
-    def write(value, buf):
-        buf.write_u64(value)
+def slh_dsa_verifying_key_len(param: "ParameterSetId") -> "int":
+    _UniffiConverterTypeParameterSetId.check_lower(param)
+    
+    return _UniffiConverterUInt64.lift(_uniffi_rust_call(_UniffiLib.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_verifying_key_len,
+        _UniffiConverterTypeParameterSetId.lower(param)))
 
-class _UniffiFfiConverterUInt8(_UniffiConverterPrimitiveInt):
-    CLASS_NAME = "u8"
-    VALUE_MIN = 0
-    VALUE_MAX = 2**8
 
-    @staticmethod
-    def read(buf):
-        return buf.read_u8()
+def slh_dsa_verifying_key_to_cose_key(param: "ParameterSetId",verifying_key: "bytes") -> "EncodeResult":
+    _UniffiConverterTypeParameterSetId.check_lower(param)
+    
+    _UniffiConverterBytes.check_lower(verifying_key)
+    
+    return _UniffiConverterTypeEncodeResult.lift(_uniffi_rust_call(_UniffiLib.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_verifying_key_to_cose_key,
+        _UniffiConverterTypeParameterSetId.lower(param),
+        _UniffiConverterBytes.lower(verifying_key)))
+
+
+def slh_dsa_verifying_key_to_spki(param: "ParameterSetId",verifying_key: "bytes",pem: "bool") -> "EncodeResult":
+    _UniffiConverterTypeParameterSetId.check_lower(param)
+    
+    _UniffiConverterBytes.check_lower(verifying_key)
+    
+    _UniffiConverterBool.check_lower(pem)
+    
+    return _UniffiConverterTypeEncodeResult.lift(_uniffi_rust_call(_UniffiLib.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_verifying_key_to_spki,
+        _UniffiConverterTypeParameterSetId.lower(param),
+        _UniffiConverterBytes.lower(verifying_key),
+        _UniffiConverterBool.lower(pem)))
 
-    @staticmethod
-    def write(value, buf):
-        buf.write_u8(value)
-def slh_dsa_keypair_generate(param: ParameterSetId) -> Keypair:
-    
-    _UniffiFfiConverterTypeParameterSetId.check_lower(param)
-    _uniffi_lowered_args = (
-        _UniffiFfiConverterTypeParameterSetId.lower(param),
-    )
-    _uniffi_lift_return = _UniffiFfiConverterTypeKeypair.lift
-    _uniffi_error_converter = None
-    _uniffi_ffi_result = _uniffi_rust_call_with_error(
-        _uniffi_error_converter,
-        _UniffiLib.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_keypair_generate,
-        *_uniffi_lowered_args,
-    )
-    return _uniffi_lift_return(_uniffi_ffi_result)
-def slh_dsa_parameter_name(param: ParameterSetId) -> str:
-    
-    _UniffiFfiConverterTypeParameterSetId.check_lower(param)
-    _uniffi_lowered_args = (
-        _UniffiFfiConverterTypeParameterSetId.lower(param),
-    )
-    _uniffi_lift_return = _UniffiFfiConverterString.lift
-    _uniffi_error_converter = None
-    _uniffi_ffi_result = _uniffi_rust_call_with_error(
-        _uniffi_error_converter,
-        _UniffiLib.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_parameter_name,
-        *_uniffi_lowered_args,
-    )
-    return _uniffi_lift_return(_uniffi_ffi_result)
-def slh_dsa_sign(param: ParameterSetId,signing_key: bytes,msg: bytes,ctx: bytes) -> SignResult:
-    
-    _UniffiFfiConverterTypeParameterSetId.check_lower(param)
-    
-    _UniffiFfiConverterBytes.check_lower(signing_key)
-    
-    _UniffiFfiConverterBytes.check_lower(msg)
-    
-    _UniffiFfiConverterBytes.check_lower(ctx)
-    _uniffi_lowered_args = (
-        _UniffiFfiConverterTypeParameterSetId.lower(param),
-        _UniffiFfiConverterBytes.lower(signing_key),
-        _UniffiFfiConverterBytes.lower(msg),
-        _UniffiFfiConverterBytes.lower(ctx),
-    )
-    _uniffi_lift_return = _UniffiFfiConverterTypeSignResult.lift
-    _uniffi_error_converter = None
-    _uniffi_ffi_result = _uniffi_rust_call_with_error(
-        _uniffi_error_converter,
-        _UniffiLib.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_sign,
-        *_uniffi_lowered_args,
-    )
-    return _uniffi_lift_return(_uniffi_ffi_result)
-def slh_dsa_sign_deterministic(param: ParameterSetId,signing_key: bytes,msg: bytes,ctx: bytes) -> SignResult:
-    
-    _UniffiFfiConverterTypeParameterSetId.check_lower(param)
-    
-    _UniffiFfiConverterBytes.check_lower(signing_key)
-    
-    _UniffiFfiConverterBytes.check_lower(msg)
-    
-    _UniffiFfiConverterBytes.check_lower(ctx)
-    _uniffi_lowered_args = (
-        _UniffiFfiConverterTypeParameterSetId.lower(param),
-        _UniffiFfiConverterBytes.lower(signing_key),
-        _UniffiFfiConverterBytes.lower(msg),
-        _UniffiFfiConverterBytes.lower(ctx),
-    )
-    _uniffi_lift_return = _UniffiFfiConverterTypeSignResult.lift
-    _uniffi_error_converter = None
-    _uniffi_ffi_result = _uniffi_rust_call_with_error(
-        _uniffi_error_converter,
-        _UniffiLib.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_sign_deterministic,
-        *_uniffi_lowered_args,
-    )
-    return _uniffi_lift_return(_uniffi_ffi_result)
-def slh_dsa_signature_len(param: ParameterSetId) -> int:
-    
-    _UniffiFfiConverterTypeParameterSetId.check_lower(param)
-    _uniffi_lowered_args = (
-        _UniffiFfiConverterTypeParameterSetId.lower(param),
-    )
-    _uniffi_lift_return = _UniffiFfiConverterUInt64.lift
-    _uniffi_error_converter = None
-    _uniffi_ffi_result = _uniffi_rust_call_with_error(
-        _uniffi_error_converter,
-        _UniffiLib.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_signature_len,
-        *_uniffi_lowered_args,
-    )
-    return _uniffi_lift_return(_uniffi_ffi_result)
-def slh_dsa_signing_key_len(param: ParameterSetId) -> int:
-    
-    _UniffiFfiConverterTypeParameterSetId.check_lower(param)
-    _uniffi_lowered_args = (
-        _UniffiFfiConverterTypeParameterSetId.lower(param),
-    )
-    _uniffi_lift_return = _UniffiFfiConverterUInt64.lift
-    _uniffi_error_converter = None
-    _uniffi_ffi_result = _uniffi_rust_call_with_error(
-        _uniffi_error_converter,
-        _UniffiLib.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_signing_key_len,
-        *_uniffi_lowered_args,
-    )
-    return _uniffi_lift_return(_uniffi_ffi_result)
-def slh_dsa_verify(param: ParameterSetId,verifying_key: bytes,msg: bytes,ctx: bytes,signature: bytes) -> VerifyResult:
-    
-    _UniffiFfiConverterTypeParameterSetId.check_lower(param)
-    
-    _UniffiFfiConverterBytes.check_lower(verifying_key)
-    
-    _UniffiFfiConverterBytes.check_lower(msg)
-    
-    _UniffiFfiConverterBytes.check_lower(ctx)
-    
-    _UniffiFfiConverterBytes.check_lower(signature)
-    _uniffi_lowered_args = (
-        _UniffiFfiConverterTypeParameterSetId.lower(param),
-        _UniffiFfiConverterBytes.lower(verifying_key),
-        _UniffiFfiConverterBytes.lower(msg),
-        _UniffiFfiConverterBytes.lower(ctx),
-        _UniffiFfiConverterBytes.lower(signature),
-    )
-    _uniffi_lift_return = _UniffiFfiConverterTypeVerifyResult.lift
-    _uniffi_error_converter = None
-    _uniffi_ffi_result = _uniffi_rust_call_with_error(
-        _uniffi_error_converter,
-        _UniffiLib.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_verify,
-        *_uniffi_lowered_args,
-    )
-    return _uniffi_lift_return(_uniffi_ffi_result)
-def slh_dsa_verifying_key_from_signing_key(param: ParameterSetId,signing_key: bytes) -> KeyResult:
-    
-    _UniffiFfiConverterTypeParameterSetId.check_lower(param)
-    
-    _UniffiFfiConverterBytes.check_lower(signing_key)
-    _uniffi_lowered_args = (
-        _UniffiFfiConverterTypeParameterSetId.lower(param),
-        _UniffiFfiConverterBytes.lower(signing_key),
-    )
-    _uniffi_lift_return = _UniffiFfiConverterTypeKeyResult.lift
-    _uniffi_error_converter = None
-    _uniffi_ffi_result = _uniffi_rust_call_with_error(
-        _uniffi_error_converter,
-        _UniffiLib.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_verifying_key_from_signing_key,
-        *_uniffi_lowered_args,
-    )
-    return _uniffi_lift_return(_uniffi_ffi_result)
-def slh_dsa_verifying_key_len(param: ParameterSetId) -> int:
-    
-    _UniffiFfiConverterTypeParameterSetId.check_lower(param)
-    _uniffi_lowered_args = (
-        _UniffiFfiConverterTypeParameterSetId.lower(param),
-    )
-    _uniffi_lift_return = _UniffiFfiConverterUInt64.lift
-    _uniffi_error_converter = None
-    _uniffi_ffi_result = _uniffi_rust_call_with_error(
-        _uniffi_error_converter,
-        _UniffiLib.uniffi_slh_dsa_uniffi_fn_func_slh_dsa_verifying_key_len,
-        *_uniffi_lowered_args,
-    )
-    return _uniffi_lift_return(_uniffi_ffi_result)
 
 __all__ = [
     "InternalError",
-    "SlhDsaStatus",
+    "Aead",
+    "FingerprintSource",
+    "HashFamily",
+    "Kdf",
     "ParameterSetId",
+    "SignatureMode",
+    "SlhDsaStatus",
+    "SubjectAltName",
+    "CoseSign1Info",
+    "CsrExtension",
+    "CsrInfo",
+    "CsrSubject",
+    "DecodeKeyResult",
+    "EncodeResult",
+    "EnvelopeInfo",
+    "FingerprintResult",
     "KeyResult",
     "Keypair",
+    "ParameterSetInfo",
+    "SignBatchResult",
     "SignResult",
     "VerifyResult",
+    "slh_dsa_cose_sign1_create",
+    "slh_dsa_cose_sign1_verify",
+    "slh_dsa_csr_create",
+    "slh_dsa_csr_verify",
+    "slh_dsa_envelope_decode",
+    "slh_dsa_envelope_sign",
+    "slh_dsa_envelope_verify",
+    "slh_dsa_fingerprint",
+    "slh_dsa_key_file_len",
+    "slh_dsa_keypair_from_seed",
     "slh_dsa_keypair_generate",
     "slh_dsa_parameter_name",
+    "slh_dsa_parameter_set_from_name",
+    "slh_dsa_parameter_set_from_oid",
+    "slh_dsa_parameter_sets",
+    "slh_dsa_seed_len",
+    "slh_dsa_set_verify_after_sign",
     "slh_dsa_sign",
+    "slh_dsa_sign_batch",
+    "slh_dsa_sign_checked",
     "slh_dsa_sign_deterministic",
     "slh_dsa_signature_len",
+    "slh_dsa_signing_key_from_cose_key",
+    "slh_dsa_signing_key_from_encrypted_pkcs8",
+    "slh_dsa_signing_key_from_key_file",
+    "slh_dsa_signing_key_from_pkcs8",
     "slh_dsa_signing_key_len",
+    "slh_dsa_signing_key_to_cose_key",
+    "slh_dsa_signing_key_to_encrypted_pkcs8",
+    "slh_dsa_signing_key_to_key_file",
+    "slh_dsa_signing_key_to_pkcs8",
+    "slh_dsa_validate_signing_key",
     "slh_dsa_verify",
+    "slh_dsa_verify_after_sign_enabled",
+    "slh_dsa_verifying_key_from_cose_key",
     "slh_dsa_verifying_key_from_signing_key",
+    "slh_dsa_verifying_key_from_signing_key_validated",
+    "slh_dsa_verifying_key_from_spki",
     "slh_dsa_verifying_key_len",
-]
\ No newline at end of file
+    "slh_dsa_verifying_key_to_cose_key",
+    "slh_dsa_verifying_key_to_spki",
+]
+
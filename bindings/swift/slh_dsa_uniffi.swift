@@ -352,31 +352,21 @@ private func uniffiTraitInterfaceCallWithError<T, E>(
         callStatus.pointee.errorBuf = FfiConverterString.lower(String(describing: error))
     }
 }
-// Initial value and increment amount for handles. 
-// These ensure that SWIFT handles always have the lowest bit set
-fileprivate let UNIFFI_HANDLEMAP_INITIAL: UInt64 = 1
-fileprivate let UNIFFI_HANDLEMAP_DELTA: UInt64 = 2
-
 fileprivate final class UniffiHandleMap<T>: @unchecked Sendable {
     // All mutation happens with this lock held, which is why we implement @unchecked Sendable.
     private let lock = NSLock()
     private var map: [UInt64: T] = [:]
-    private var currentHandle: UInt64 = UNIFFI_HANDLEMAP_INITIAL
+    private var currentHandle: UInt64 = 1
 
     func insert(obj: T) -> UInt64 {
         lock.withLock {
-            return doInsert(obj)
+            let handle = currentHandle
+            currentHandle += 1
+            map[handle] = obj
+            return handle
         }
     }
 
-    // Low-level insert function, this assumes `lock` is held.
-    private func doInsert(_ obj: T) -> UInt64 {
-        let handle = currentHandle
-        currentHandle += UNIFFI_HANDLEMAP_DELTA
-        map[handle] = obj
-        return handle
-    }
-
      func get(handle: UInt64) throws -> T {
         try lock.withLock {
             guard let obj = map[handle] else {
@@ -386,15 +376,6 @@ fileprivate final class UniffiHandleMap<T>: @unchecked Sendable {
         }
     }
 
-     func clone(handle: UInt64) throws -> UInt64 {
-        try lock.withLock {
-            guard let obj = map[handle] else {
-                throw UniffiInternalError.unexpectedStaleHandle
-            }
-            return doInsert(obj)
-        }
-    }
-
     @discardableResult
     func remove(handle: UInt64) throws -> T {
         try lock.withLock {
@@ -416,6 +397,38 @@ fileprivate final class UniffiHandleMap<T>: @unchecked Sendable {
 // Public interface members begin here.
 
 
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterUInt8: FfiConverterPrimitive {
+    typealias FfiType = UInt8
+    typealias SwiftType = UInt8
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> UInt8 {
+        return try lift(readInt(&buf))
+    }
+
+    public static func write(_ value: UInt8, into buf: inout [UInt8]) {
+        writeInt(&buf, lower(value))
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterUInt32: FfiConverterPrimitive {
+    typealias FfiType = UInt32
+    typealias SwiftType = UInt32
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> UInt32 {
+        return try lift(readInt(&buf))
+    }
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        writeInt(&buf, lower(value))
+    }
+}
+
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
@@ -516,39 +529,81 @@ fileprivate struct FfiConverterData: FfiConverterRustBuffer {
 }
 
 
-public struct KeyResult: Equatable, Hashable {
+public struct CoseSign1Info {
     public var status: SlhDsaStatus
-    public var verifyingKey: Data
+    public var valid: Bool
+    public var param: ParameterSetId?
+    public var keyId: Data?
+    public var payload: Data?
 
     // Default memberwise initializers are never public by default, so we
     // declare one manually.
-    public init(status: SlhDsaStatus, verifyingKey: Data) {
+    public init(status: SlhDsaStatus, valid: Bool, param: ParameterSetId?, keyId: Data?, payload: Data?) {
         self.status = status
-        self.verifyingKey = verifyingKey
+        self.valid = valid
+        self.param = param
+        self.keyId = keyId
+        self.payload = payload
     }
-
-    
 }
 
 #if compiler(>=6)
-extension KeyResult: Sendable {}
+extension CoseSign1Info: Sendable {}
 #endif
 
+
+extension CoseSign1Info: Equatable, Hashable {
+    public static func ==(lhs: CoseSign1Info, rhs: CoseSign1Info) -> Bool {
+        if lhs.status != rhs.status {
+            return false
+        }
+        if lhs.valid != rhs.valid {
+            return false
+        }
+        if lhs.param != rhs.param {
+            return false
+        }
+        if lhs.keyId != rhs.keyId {
+            return false
+        }
+        if lhs.payload != rhs.payload {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(status)
+        hasher.combine(valid)
+        hasher.combine(param)
+        hasher.combine(keyId)
+        hasher.combine(payload)
+    }
+}
+
+
+
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeKeyResult: FfiConverterRustBuffer {
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> KeyResult {
+public struct FfiConverterTypeCoseSign1Info: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> CoseSign1Info {
         return
-            try KeyResult(
+            try CoseSign1Info(
                 status: FfiConverterTypeSlhDsaStatus.read(from: &buf), 
-                verifyingKey: FfiConverterData.read(from: &buf)
+                valid: FfiConverterBool.read(from: &buf), 
+                param: FfiConverterOptionTypeParameterSetId.read(from: &buf), 
+                keyId: FfiConverterOptionData.read(from: &buf), 
+                payload: FfiConverterOptionData.read(from: &buf)
         )
     }
 
-    public static func write(_ value: KeyResult, into buf: inout [UInt8]) {
+    public static func write(_ value: CoseSign1Info, into buf: inout [UInt8]) {
         FfiConverterTypeSlhDsaStatus.write(value.status, into: &buf)
-        FfiConverterData.write(value.verifyingKey, into: &buf)
+        FfiConverterBool.write(value.valid, into: &buf)
+        FfiConverterOptionTypeParameterSetId.write(value.param, into: &buf)
+        FfiConverterOptionData.write(value.keyId, into: &buf)
+        FfiConverterOptionData.write(value.payload, into: &buf)
     }
 }
 
@@ -556,51 +611,77 @@ public struct FfiConverterTypeKeyResult: FfiConverterRustBuffer {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeKeyResult_lift(_ buf: RustBuffer) throws -> KeyResult {
-    return try FfiConverterTypeKeyResult.lift(buf)
+public func FfiConverterTypeCoseSign1Info_lift(_ buf: RustBuffer) throws -> CoseSign1Info {
+    return try FfiConverterTypeCoseSign1Info.lift(buf)
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeKeyResult_lower(_ value: KeyResult) -> RustBuffer {
-    return FfiConverterTypeKeyResult.lower(value)
+public func FfiConverterTypeCoseSign1Info_lower(_ value: CoseSign1Info) -> RustBuffer {
+    return FfiConverterTypeCoseSign1Info.lower(value)
 }
 
 
-public struct Keypair: Equatable, Hashable {
-    public var signingKey: Data
-    public var verifyingKey: Data
+public struct CsrExtension {
+    public var oid: String
+    public var critical: Bool
+    public var value: Data
 
     // Default memberwise initializers are never public by default, so we
     // declare one manually.
-    public init(signingKey: Data, verifyingKey: Data) {
-        self.signingKey = signingKey
-        self.verifyingKey = verifyingKey
+    public init(oid: String, critical: Bool, value: Data) {
+        self.oid = oid
+        self.critical = critical
+        self.value = value
     }
-
-    
 }
 
 #if compiler(>=6)
-extension Keypair: Sendable {}
+extension CsrExtension: Sendable {}
 #endif
 
+
+extension CsrExtension: Equatable, Hashable {
+    public static func ==(lhs: CsrExtension, rhs: CsrExtension) -> Bool {
+        if lhs.oid != rhs.oid {
+            return false
+        }
+        if lhs.critical != rhs.critical {
+            return false
+        }
+        if lhs.value != rhs.value {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(oid)
+        hasher.combine(critical)
+        hasher.combine(value)
+    }
+}
+
+
+
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeKeypair: FfiConverterRustBuffer {
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Keypair {
+public struct FfiConverterTypeCsrExtension: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> CsrExtension {
         return
-            try Keypair(
-                signingKey: FfiConverterData.read(from: &buf), 
-                verifyingKey: FfiConverterData.read(from: &buf)
+            try CsrExtension(
+                oid: FfiConverterString.read(from: &buf), 
+                critical: FfiConverterBool.read(from: &buf), 
+                value: FfiConverterData.read(from: &buf)
         )
     }
 
-    public static func write(_ value: Keypair, into buf: inout [UInt8]) {
-        FfiConverterData.write(value.signingKey, into: &buf)
-        FfiConverterData.write(value.verifyingKey, into: &buf)
+    public static func write(_ value: CsrExtension, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.oid, into: &buf)
+        FfiConverterBool.write(value.critical, into: &buf)
+        FfiConverterData.write(value.value, into: &buf)
     }
 }
 
@@ -608,51 +689,109 @@ public struct FfiConverterTypeKeypair: FfiConverterRustBuffer {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeKeypair_lift(_ buf: RustBuffer) throws -> Keypair {
-    return try FfiConverterTypeKeypair.lift(buf)
+public func FfiConverterTypeCsrExtension_lift(_ buf: RustBuffer) throws -> CsrExtension {
+    return try FfiConverterTypeCsrExtension.lift(buf)
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeKeypair_lower(_ value: Keypair) -> RustBuffer {
-    return FfiConverterTypeKeypair.lower(value)
+public func FfiConverterTypeCsrExtension_lower(_ value: CsrExtension) -> RustBuffer {
+    return FfiConverterTypeCsrExtension.lower(value)
 }
 
 
-public struct SignResult: Equatable, Hashable {
+public struct CsrInfo {
     public var status: SlhDsaStatus
-    public var signature: Data
+    public var valid: Bool
+    public var param: ParameterSetId?
+    public var verifyingKey: Data
+    public var subject: CsrSubject
+    public var subjectAltNames: [SubjectAltName]
+    public var extensions: [CsrExtension]
 
     // Default memberwise initializers are never public by default, so we
     // declare one manually.
-    public init(status: SlhDsaStatus, signature: Data) {
+    public init(status: SlhDsaStatus, valid: Bool, param: ParameterSetId?, verifyingKey: Data, subject: CsrSubject, subjectAltNames: [SubjectAltName], extensions: [CsrExtension]) {
         self.status = status
-        self.signature = signature
+        self.valid = valid
+        self.param = param
+        self.verifyingKey = verifyingKey
+        self.subject = subject
+        self.subjectAltNames = subjectAltNames
+        self.extensions = extensions
     }
-
-    
 }
 
 #if compiler(>=6)
-extension SignResult: Sendable {}
+extension CsrInfo: Sendable {}
 #endif
 
+
+extension CsrInfo: Equatable, Hashable {
+    public static func ==(lhs: CsrInfo, rhs: CsrInfo) -> Bool {
+        if lhs.status != rhs.status {
+            return false
+        }
+        if lhs.valid != rhs.valid {
+            return false
+        }
+        if lhs.param != rhs.param {
+            return false
+        }
+        if lhs.verifyingKey != rhs.verifyingKey {
+            return false
+        }
+        if lhs.subject != rhs.subject {
+            return false
+        }
+        if lhs.subjectAltNames != rhs.subjectAltNames {
+            return false
+        }
+        if lhs.extensions != rhs.extensions {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(status)
+        hasher.combine(valid)
+        hasher.combine(param)
+        hasher.combine(verifyingKey)
+        hasher.combine(subject)
+        hasher.combine(subjectAltNames)
+        hasher.combine(extensions)
+    }
+}
+
+
+
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeSignResult: FfiConverterRustBuffer {
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SignResult {
+public struct FfiConverterTypeCsrInfo: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> CsrInfo {
         return
-            try SignResult(
+            try CsrInfo(
                 status: FfiConverterTypeSlhDsaStatus.read(from: &buf), 
-                signature: FfiConverterData.read(from: &buf)
+                valid: FfiConverterBool.read(from: &buf), 
+                param: FfiConverterOptionTypeParameterSetId.read(from: &buf), 
+                verifyingKey: FfiConverterData.read(from: &buf), 
+                subject: FfiConverterTypeCsrSubject.read(from: &buf), 
+                subjectAltNames: FfiConverterSequenceTypeSubjectAltName.read(from: &buf), 
+                extensions: FfiConverterSequenceTypeCsrExtension.read(from: &buf)
         )
     }
 
-    public static func write(_ value: SignResult, into buf: inout [UInt8]) {
+    public static func write(_ value: CsrInfo, into buf: inout [UInt8]) {
         FfiConverterTypeSlhDsaStatus.write(value.status, into: &buf)
-        FfiConverterData.write(value.signature, into: &buf)
+        FfiConverterBool.write(value.valid, into: &buf)
+        FfiConverterOptionTypeParameterSetId.write(value.param, into: &buf)
+        FfiConverterData.write(value.verifyingKey, into: &buf)
+        FfiConverterTypeCsrSubject.write(value.subject, into: &buf)
+        FfiConverterSequenceTypeSubjectAltName.write(value.subjectAltNames, into: &buf)
+        FfiConverterSequenceTypeCsrExtension.write(value.extensions, into: &buf)
     }
 }
 
@@ -660,51 +799,163 @@ public struct FfiConverterTypeSignResult: FfiConverterRustBuffer {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeSignResult_lift(_ buf: RustBuffer) throws -> SignResult {
-    return try FfiConverterTypeSignResult.lift(buf)
+public func FfiConverterTypeCsrInfo_lift(_ buf: RustBuffer) throws -> CsrInfo {
+    return try FfiConverterTypeCsrInfo.lift(buf)
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeSignResult_lower(_ value: SignResult) -> RustBuffer {
-    return FfiConverterTypeSignResult.lower(value)
+public func FfiConverterTypeCsrInfo_lower(_ value: CsrInfo) -> RustBuffer {
+    return FfiConverterTypeCsrInfo.lower(value)
+}
+
+
+public struct CsrSubject {
+    public var commonName: String?
+    public var organization: String?
+    public var organizationalUnit: String?
+    public var country: String?
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(commonName: String?, organization: String?, organizationalUnit: String?, country: String?) {
+        self.commonName = commonName
+        self.organization = organization
+        self.organizationalUnit = organizationalUnit
+        self.country = country
+    }
+}
+
+#if compiler(>=6)
+extension CsrSubject: Sendable {}
+#endif
+
+
+extension CsrSubject: Equatable, Hashable {
+    public static func ==(lhs: CsrSubject, rhs: CsrSubject) -> Bool {
+        if lhs.commonName != rhs.commonName {
+            return false
+        }
+        if lhs.organization != rhs.organization {
+            return false
+        }
+        if lhs.organizationalUnit != rhs.organizationalUnit {
+            return false
+        }
+        if lhs.country != rhs.country {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(commonName)
+        hasher.combine(organization)
+        hasher.combine(organizationalUnit)
+        hasher.combine(country)
+    }
+}
+
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeCsrSubject: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> CsrSubject {
+        return
+            try CsrSubject(
+                commonName: FfiConverterOptionString.read(from: &buf), 
+                organization: FfiConverterOptionString.read(from: &buf), 
+                organizationalUnit: FfiConverterOptionString.read(from: &buf), 
+                country: FfiConverterOptionString.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: CsrSubject, into buf: inout [UInt8]) {
+        FfiConverterOptionString.write(value.commonName, into: &buf)
+        FfiConverterOptionString.write(value.organization, into: &buf)
+        FfiConverterOptionString.write(value.organizationalUnit, into: &buf)
+        FfiConverterOptionString.write(value.country, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeCsrSubject_lift(_ buf: RustBuffer) throws -> CsrSubject {
+    return try FfiConverterTypeCsrSubject.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeCsrSubject_lower(_ value: CsrSubject) -> RustBuffer {
+    return FfiConverterTypeCsrSubject.lower(value)
 }
 
 
-public struct VerifyResult: Equatable, Hashable {
+public struct DecodeKeyResult {
     public var status: SlhDsaStatus
-    public var valid: Bool
+    public var param: ParameterSetId?
+    public var key: Data
 
     // Default memberwise initializers are never public by default, so we
     // declare one manually.
-    public init(status: SlhDsaStatus, valid: Bool) {
+    public init(status: SlhDsaStatus, param: ParameterSetId?, key: Data) {
         self.status = status
-        self.valid = valid
+        self.param = param
+        self.key = key
     }
-
-    
 }
 
 #if compiler(>=6)
-extension VerifyResult: Sendable {}
+extension DecodeKeyResult: Sendable {}
 #endif
 
+
+extension DecodeKeyResult: Equatable, Hashable {
+    public static func ==(lhs: DecodeKeyResult, rhs: DecodeKeyResult) -> Bool {
+        if lhs.status != rhs.status {
+            return false
+        }
+        if lhs.param != rhs.param {
+            return false
+        }
+        if lhs.key != rhs.key {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(status)
+        hasher.combine(param)
+        hasher.combine(key)
+    }
+}
+
+
+
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeVerifyResult: FfiConverterRustBuffer {
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> VerifyResult {
+public struct FfiConverterTypeDecodeKeyResult: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> DecodeKeyResult {
         return
-            try VerifyResult(
+            try DecodeKeyResult(
                 status: FfiConverterTypeSlhDsaStatus.read(from: &buf), 
-                valid: FfiConverterBool.read(from: &buf)
+                param: FfiConverterOptionTypeParameterSetId.read(from: &buf), 
+                key: FfiConverterData.read(from: &buf)
         )
     }
 
-    public static func write(_ value: VerifyResult, into buf: inout [UInt8]) {
+    public static func write(_ value: DecodeKeyResult, into buf: inout [UInt8]) {
         FfiConverterTypeSlhDsaStatus.write(value.status, into: &buf)
-        FfiConverterBool.write(value.valid, into: &buf)
+        FfiConverterOptionTypeParameterSetId.write(value.param, into: &buf)
+        FfiConverterData.write(value.key, into: &buf)
     }
 }
 
@@ -712,132 +963,1510 @@ public struct FfiConverterTypeVerifyResult: FfiConverterRustBuffer {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeVerifyResult_lift(_ buf: RustBuffer) throws -> VerifyResult {
-    return try FfiConverterTypeVerifyResult.lift(buf)
+public func FfiConverterTypeDecodeKeyResult_lift(_ buf: RustBuffer) throws -> DecodeKeyResult {
+    return try FfiConverterTypeDecodeKeyResult.lift(buf)
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeVerifyResult_lower(_ value: VerifyResult) -> RustBuffer {
-    return FfiConverterTypeVerifyResult.lower(value)
+public func FfiConverterTypeDecodeKeyResult_lower(_ value: DecodeKeyResult) -> RustBuffer {
+    return FfiConverterTypeDecodeKeyResult.lower(value)
 }
 
-// Note that we don't yet support `indirect` for enums.
-// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
 
-public enum ParameterSetId: Equatable, Hashable {
-    
-    case shake128s
-    case shake128f
-    case shake192s
-    case shake192f
-    case shake256s
-    case shake256f
-    case sha2128s
-    case sha2128f
-    case sha2192s
-    case sha2192f
-    case sha2256s
-    case sha2256f
+public struct EncodeResult {
+    public var status: SlhDsaStatus
+    public var encoded: Data
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(status: SlhDsaStatus, encoded: Data) {
+        self.status = status
+        self.encoded = encoded
+    }
+}
 
+#if compiler(>=6)
+extension EncodeResult: Sendable {}
+#endif
 
 
+extension EncodeResult: Equatable, Hashable {
+    public static func ==(lhs: EncodeResult, rhs: EncodeResult) -> Bool {
+        if lhs.status != rhs.status {
+            return false
+        }
+        if lhs.encoded != rhs.encoded {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(status)
+        hasher.combine(encoded)
+    }
 }
 
-#if compiler(>=6)
-extension ParameterSetId: Sendable {}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
 #endif
+public struct FfiConverterTypeEncodeResult: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> EncodeResult {
+        return
+            try EncodeResult(
+                status: FfiConverterTypeSlhDsaStatus.read(from: &buf), 
+                encoded: FfiConverterData.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: EncodeResult, into buf: inout [UInt8]) {
+        FfiConverterTypeSlhDsaStatus.write(value.status, into: &buf)
+        FfiConverterData.write(value.encoded, into: &buf)
+    }
+}
+
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeParameterSetId: FfiConverterRustBuffer {
-    typealias SwiftType = ParameterSetId
+public func FfiConverterTypeEncodeResult_lift(_ buf: RustBuffer) throws -> EncodeResult {
+    return try FfiConverterTypeEncodeResult.lift(buf)
+}
 
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> ParameterSetId {
-        let variant: Int32 = try readInt(&buf)
-        switch variant {
-        
-        case 1: return .shake128s
-        
-        case 2: return .shake128f
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeEncodeResult_lower(_ value: EncodeResult) -> RustBuffer {
+    return FfiConverterTypeEncodeResult.lower(value)
+}
+
+
+public struct EnvelopeInfo {
+    public var status: SlhDsaStatus
+    public var valid: Bool
+    public var param: ParameterSetId?
+    public var mode: SignatureMode?
+    public var keyId: Data?
+    public var context: Data
+    public var payload: Data?
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(status: SlhDsaStatus, valid: Bool, param: ParameterSetId?, mode: SignatureMode?, keyId: Data?, context: Data, payload: Data?) {
+        self.status = status
+        self.valid = valid
+        self.param = param
+        self.mode = mode
+        self.keyId = keyId
+        self.context = context
+        self.payload = payload
+    }
+}
+
+#if compiler(>=6)
+extension EnvelopeInfo: Sendable {}
+#endif
+
+
+extension EnvelopeInfo: Equatable, Hashable {
+    public static func ==(lhs: EnvelopeInfo, rhs: EnvelopeInfo) -> Bool {
+        if lhs.status != rhs.status {
+            return false
+        }
+        if lhs.valid != rhs.valid {
+            return false
+        }
+        if lhs.param != rhs.param {
+            return false
+        }
+        if lhs.mode != rhs.mode {
+            return false
+        }
+        if lhs.keyId != rhs.keyId {
+            return false
+        }
+        if lhs.context != rhs.context {
+            return false
+        }
+        if lhs.payload != rhs.payload {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(status)
+        hasher.combine(valid)
+        hasher.combine(param)
+        hasher.combine(mode)
+        hasher.combine(keyId)
+        hasher.combine(context)
+        hasher.combine(payload)
+    }
+}
+
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeEnvelopeInfo: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> EnvelopeInfo {
+        return
+            try EnvelopeInfo(
+                status: FfiConverterTypeSlhDsaStatus.read(from: &buf), 
+                valid: FfiConverterBool.read(from: &buf), 
+                param: FfiConverterOptionTypeParameterSetId.read(from: &buf), 
+                mode: FfiConverterOptionTypeSignatureMode.read(from: &buf), 
+                keyId: FfiConverterOptionData.read(from: &buf), 
+                context: FfiConverterData.read(from: &buf), 
+                payload: FfiConverterOptionData.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: EnvelopeInfo, into buf: inout [UInt8]) {
+        FfiConverterTypeSlhDsaStatus.write(value.status, into: &buf)
+        FfiConverterBool.write(value.valid, into: &buf)
+        FfiConverterOptionTypeParameterSetId.write(value.param, into: &buf)
+        FfiConverterOptionTypeSignatureMode.write(value.mode, into: &buf)
+        FfiConverterOptionData.write(value.keyId, into: &buf)
+        FfiConverterData.write(value.context, into: &buf)
+        FfiConverterOptionData.write(value.payload, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeEnvelopeInfo_lift(_ buf: RustBuffer) throws -> EnvelopeInfo {
+    return try FfiConverterTypeEnvelopeInfo.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeEnvelopeInfo_lower(_ value: EnvelopeInfo) -> RustBuffer {
+    return FfiConverterTypeEnvelopeInfo.lower(value)
+}
+
+
+public struct FingerprintResult {
+    public var status: SlhDsaStatus
+    public var fingerprint: Data
+    public var hex: String
+    public var base32: String
+    public var words: String
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(status: SlhDsaStatus, fingerprint: Data, hex: String, base32: String, words: String) {
+        self.status = status
+        self.fingerprint = fingerprint
+        self.hex = hex
+        self.base32 = base32
+        self.words = words
+    }
+}
+
+#if compiler(>=6)
+extension FingerprintResult: Sendable {}
+#endif
+
+
+extension FingerprintResult: Equatable, Hashable {
+    public static func ==(lhs: FingerprintResult, rhs: FingerprintResult) -> Bool {
+        if lhs.status != rhs.status {
+            return false
+        }
+        if lhs.fingerprint != rhs.fingerprint {
+            return false
+        }
+        if lhs.hex != rhs.hex {
+            return false
+        }
+        if lhs.base32 != rhs.base32 {
+            return false
+        }
+        if lhs.words != rhs.words {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(status)
+        hasher.combine(fingerprint)
+        hasher.combine(hex)
+        hasher.combine(base32)
+        hasher.combine(words)
+    }
+}
+
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeFingerprintResult: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> FingerprintResult {
+        return
+            try FingerprintResult(
+                status: FfiConverterTypeSlhDsaStatus.read(from: &buf), 
+                fingerprint: FfiConverterData.read(from: &buf), 
+                hex: FfiConverterString.read(from: &buf), 
+                base32: FfiConverterString.read(from: &buf), 
+                words: FfiConverterString.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: FingerprintResult, into buf: inout [UInt8]) {
+        FfiConverterTypeSlhDsaStatus.write(value.status, into: &buf)
+        FfiConverterData.write(value.fingerprint, into: &buf)
+        FfiConverterString.write(value.hex, into: &buf)
+        FfiConverterString.write(value.base32, into: &buf)
+        FfiConverterString.write(value.words, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFingerprintResult_lift(_ buf: RustBuffer) throws -> FingerprintResult {
+    return try FfiConverterTypeFingerprintResult.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFingerprintResult_lower(_ value: FingerprintResult) -> RustBuffer {
+    return FfiConverterTypeFingerprintResult.lower(value)
+}
+
+
+public struct KeyResult {
+    public var status: SlhDsaStatus
+    public var verifyingKey: Data
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(status: SlhDsaStatus, verifyingKey: Data) {
+        self.status = status
+        self.verifyingKey = verifyingKey
+    }
+}
+
+#if compiler(>=6)
+extension KeyResult: Sendable {}
+#endif
+
+
+extension KeyResult: Equatable, Hashable {
+    public static func ==(lhs: KeyResult, rhs: KeyResult) -> Bool {
+        if lhs.status != rhs.status {
+            return false
+        }
+        if lhs.verifyingKey != rhs.verifyingKey {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(status)
+        hasher.combine(verifyingKey)
+    }
+}
+
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeKeyResult: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> KeyResult {
+        return
+            try KeyResult(
+                status: FfiConverterTypeSlhDsaStatus.read(from: &buf), 
+                verifyingKey: FfiConverterData.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: KeyResult, into buf: inout [UInt8]) {
+        FfiConverterTypeSlhDsaStatus.write(value.status, into: &buf)
+        FfiConverterData.write(value.verifyingKey, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeKeyResult_lift(_ buf: RustBuffer) throws -> KeyResult {
+    return try FfiConverterTypeKeyResult.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeKeyResult_lower(_ value: KeyResult) -> RustBuffer {
+    return FfiConverterTypeKeyResult.lower(value)
+}
+
+
+public struct Keypair {
+    public var status: SlhDsaStatus
+    public var signingKey: Data
+    public var verifyingKey: Data
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(status: SlhDsaStatus, signingKey: Data, verifyingKey: Data) {
+        self.status = status
+        self.signingKey = signingKey
+        self.verifyingKey = verifyingKey
+    }
+}
+
+#if compiler(>=6)
+extension Keypair: Sendable {}
+#endif
+
+
+extension Keypair: Equatable, Hashable {
+    public static func ==(lhs: Keypair, rhs: Keypair) -> Bool {
+        if lhs.status != rhs.status {
+            return false
+        }
+        if lhs.signingKey != rhs.signingKey {
+            return false
+        }
+        if lhs.verifyingKey != rhs.verifyingKey {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(status)
+        hasher.combine(signingKey)
+        hasher.combine(verifyingKey)
+    }
+}
+
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeKeypair: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Keypair {
+        return
+            try Keypair(
+                status: FfiConverterTypeSlhDsaStatus.read(from: &buf), 
+                signingKey: FfiConverterData.read(from: &buf), 
+                verifyingKey: FfiConverterData.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: Keypair, into buf: inout [UInt8]) {
+        FfiConverterTypeSlhDsaStatus.write(value.status, into: &buf)
+        FfiConverterData.write(value.signingKey, into: &buf)
+        FfiConverterData.write(value.verifyingKey, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeKeypair_lift(_ buf: RustBuffer) throws -> Keypair {
+    return try FfiConverterTypeKeypair.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeKeypair_lower(_ value: Keypair) -> RustBuffer {
+    return FfiConverterTypeKeypair.lower(value)
+}
+
+
+public struct ParameterSetInfo {
+    public var param: ParameterSetId
+    public var name: String
+    public var hashFamily: HashFamily
+    public var fast: Bool
+    public var n: UInt32
+    public var h: UInt32
+    public var d: UInt32
+    public var a: UInt32
+    public var k: UInt32
+    public var w: UInt32
+    public var securityCategory: UInt8
+    public var oid: String
+    public var signingKeyLen: UInt64
+    public var verifyingKeyLen: UInt64
+    public var signatureLen: UInt64
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(param: ParameterSetId, name: String, hashFamily: HashFamily, fast: Bool, n: UInt32, h: UInt32, d: UInt32, a: UInt32, k: UInt32, w: UInt32, securityCategory: UInt8, oid: String, signingKeyLen: UInt64, verifyingKeyLen: UInt64, signatureLen: UInt64) {
+        self.param = param
+        self.name = name
+        self.hashFamily = hashFamily
+        self.fast = fast
+        self.n = n
+        self.h = h
+        self.d = d
+        self.a = a
+        self.k = k
+        self.w = w
+        self.securityCategory = securityCategory
+        self.oid = oid
+        self.signingKeyLen = signingKeyLen
+        self.verifyingKeyLen = verifyingKeyLen
+        self.signatureLen = signatureLen
+    }
+}
+
+#if compiler(>=6)
+extension ParameterSetInfo: Sendable {}
+#endif
+
+
+extension ParameterSetInfo: Equatable, Hashable {
+    public static func ==(lhs: ParameterSetInfo, rhs: ParameterSetInfo) -> Bool {
+        if lhs.param != rhs.param {
+            return false
+        }
+        if lhs.name != rhs.name {
+            return false
+        }
+        if lhs.hashFamily != rhs.hashFamily {
+            return false
+        }
+        if lhs.fast != rhs.fast {
+            return false
+        }
+        if lhs.n != rhs.n {
+            return false
+        }
+        if lhs.h != rhs.h {
+            return false
+        }
+        if lhs.d != rhs.d {
+            return false
+        }
+        if lhs.a != rhs.a {
+            return false
+        }
+        if lhs.k != rhs.k {
+            return false
+        }
+        if lhs.w != rhs.w {
+            return false
+        }
+        if lhs.securityCategory != rhs.securityCategory {
+            return false
+        }
+        if lhs.oid != rhs.oid {
+            return false
+        }
+        if lhs.signingKeyLen != rhs.signingKeyLen {
+            return false
+        }
+        if lhs.verifyingKeyLen != rhs.verifyingKeyLen {
+            return false
+        }
+        if lhs.signatureLen != rhs.signatureLen {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(param)
+        hasher.combine(name)
+        hasher.combine(hashFamily)
+        hasher.combine(fast)
+        hasher.combine(n)
+        hasher.combine(h)
+        hasher.combine(d)
+        hasher.combine(a)
+        hasher.combine(k)
+        hasher.combine(w)
+        hasher.combine(securityCategory)
+        hasher.combine(oid)
+        hasher.combine(signingKeyLen)
+        hasher.combine(verifyingKeyLen)
+        hasher.combine(signatureLen)
+    }
+}
+
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeParameterSetInfo: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> ParameterSetInfo {
+        return
+            try ParameterSetInfo(
+                param: FfiConverterTypeParameterSetId.read(from: &buf), 
+                name: FfiConverterString.read(from: &buf), 
+                hashFamily: FfiConverterTypeHashFamily.read(from: &buf), 
+                fast: FfiConverterBool.read(from: &buf), 
+                n: FfiConverterUInt32.read(from: &buf), 
+                h: FfiConverterUInt32.read(from: &buf), 
+                d: FfiConverterUInt32.read(from: &buf), 
+                a: FfiConverterUInt32.read(from: &buf), 
+                k: FfiConverterUInt32.read(from: &buf), 
+                w: FfiConverterUInt32.read(from: &buf), 
+                securityCategory: FfiConverterUInt8.read(from: &buf), 
+                oid: FfiConverterString.read(from: &buf), 
+                signingKeyLen: FfiConverterUInt64.read(from: &buf), 
+                verifyingKeyLen: FfiConverterUInt64.read(from: &buf), 
+                signatureLen: FfiConverterUInt64.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: ParameterSetInfo, into buf: inout [UInt8]) {
+        FfiConverterTypeParameterSetId.write(value.param, into: &buf)
+        FfiConverterString.write(value.name, into: &buf)
+        FfiConverterTypeHashFamily.write(value.hashFamily, into: &buf)
+        FfiConverterBool.write(value.fast, into: &buf)
+        FfiConverterUInt32.write(value.n, into: &buf)
+        FfiConverterUInt32.write(value.h, into: &buf)
+        FfiConverterUInt32.write(value.d, into: &buf)
+        FfiConverterUInt32.write(value.a, into: &buf)
+        FfiConverterUInt32.write(value.k, into: &buf)
+        FfiConverterUInt32.write(value.w, into: &buf)
+        FfiConverterUInt8.write(value.securityCategory, into: &buf)
+        FfiConverterString.write(value.oid, into: &buf)
+        FfiConverterUInt64.write(value.signingKeyLen, into: &buf)
+        FfiConverterUInt64.write(value.verifyingKeyLen, into: &buf)
+        FfiConverterUInt64.write(value.signatureLen, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeParameterSetInfo_lift(_ buf: RustBuffer) throws -> ParameterSetInfo {
+    return try FfiConverterTypeParameterSetInfo.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeParameterSetInfo_lower(_ value: ParameterSetInfo) -> RustBuffer {
+    return FfiConverterTypeParameterSetInfo.lower(value)
+}
+
+
+public struct SignBatchResult {
+    public var status: SlhDsaStatus
+    public var signatures: [Data]
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(status: SlhDsaStatus, signatures: [Data]) {
+        self.status = status
+        self.signatures = signatures
+    }
+}
+
+#if compiler(>=6)
+extension SignBatchResult: Sendable {}
+#endif
+
+
+extension SignBatchResult: Equatable, Hashable {
+    public static func ==(lhs: SignBatchResult, rhs: SignBatchResult) -> Bool {
+        if lhs.status != rhs.status {
+            return false
+        }
+        if lhs.signatures != rhs.signatures {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(status)
+        hasher.combine(signatures)
+    }
+}
+
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeSignBatchResult: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SignBatchResult {
+        return
+            try SignBatchResult(
+                status: FfiConverterTypeSlhDsaStatus.read(from: &buf), 
+                signatures: FfiConverterSequenceData.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: SignBatchResult, into buf: inout [UInt8]) {
+        FfiConverterTypeSlhDsaStatus.write(value.status, into: &buf)
+        FfiConverterSequenceData.write(value.signatures, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeSignBatchResult_lift(_ buf: RustBuffer) throws -> SignBatchResult {
+    return try FfiConverterTypeSignBatchResult.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeSignBatchResult_lower(_ value: SignBatchResult) -> RustBuffer {
+    return FfiConverterTypeSignBatchResult.lower(value)
+}
+
+
+public struct SignResult {
+    public var status: SlhDsaStatus
+    public var signature: Data
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(status: SlhDsaStatus, signature: Data) {
+        self.status = status
+        self.signature = signature
+    }
+}
+
+#if compiler(>=6)
+extension SignResult: Sendable {}
+#endif
+
+
+extension SignResult: Equatable, Hashable {
+    public static func ==(lhs: SignResult, rhs: SignResult) -> Bool {
+        if lhs.status != rhs.status {
+            return false
+        }
+        if lhs.signature != rhs.signature {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(status)
+        hasher.combine(signature)
+    }
+}
+
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeSignResult: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SignResult {
+        return
+            try SignResult(
+                status: FfiConverterTypeSlhDsaStatus.read(from: &buf), 
+                signature: FfiConverterData.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: SignResult, into buf: inout [UInt8]) {
+        FfiConverterTypeSlhDsaStatus.write(value.status, into: &buf)
+        FfiConverterData.write(value.signature, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeSignResult_lift(_ buf: RustBuffer) throws -> SignResult {
+    return try FfiConverterTypeSignResult.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeSignResult_lower(_ value: SignResult) -> RustBuffer {
+    return FfiConverterTypeSignResult.lower(value)
+}
+
+
+public struct VerifyResult {
+    public var status: SlhDsaStatus
+    public var valid: Bool
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(status: SlhDsaStatus, valid: Bool) {
+        self.status = status
+        self.valid = valid
+    }
+}
+
+#if compiler(>=6)
+extension VerifyResult: Sendable {}
+#endif
+
+
+extension VerifyResult: Equatable, Hashable {
+    public static func ==(lhs: VerifyResult, rhs: VerifyResult) -> Bool {
+        if lhs.status != rhs.status {
+            return false
+        }
+        if lhs.valid != rhs.valid {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(status)
+        hasher.combine(valid)
+    }
+}
+
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeVerifyResult: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> VerifyResult {
+        return
+            try VerifyResult(
+                status: FfiConverterTypeSlhDsaStatus.read(from: &buf), 
+                valid: FfiConverterBool.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: VerifyResult, into buf: inout [UInt8]) {
+        FfiConverterTypeSlhDsaStatus.write(value.status, into: &buf)
+        FfiConverterBool.write(value.valid, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeVerifyResult_lift(_ buf: RustBuffer) throws -> VerifyResult {
+    return try FfiConverterTypeVerifyResult.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeVerifyResult_lower(_ value: VerifyResult) -> RustBuffer {
+    return FfiConverterTypeVerifyResult.lower(value)
+}
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+
+public enum Aead {
+    
+    case chaCha20Poly1305
+    case aes256Gcm
+}
+
+
+#if compiler(>=6)
+extension Aead: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeAead: FfiConverterRustBuffer {
+    typealias SwiftType = Aead
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Aead {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .chaCha20Poly1305
+        
+        case 2: return .aes256Gcm
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: Aead, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .chaCha20Poly1305:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .aes256Gcm:
+            writeInt(&buf, Int32(2))
+        
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeAead_lift(_ buf: RustBuffer) throws -> Aead {
+    return try FfiConverterTypeAead.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeAead_lower(_ value: Aead) -> RustBuffer {
+    return FfiConverterTypeAead.lower(value)
+}
+
+
+extension Aead: Equatable, Hashable {}
+
+
+
+
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+
+public enum FingerprintSource {
+    
+    case raw
+    case spki
+}
+
+
+#if compiler(>=6)
+extension FingerprintSource: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeFingerprintSource: FfiConverterRustBuffer {
+    typealias SwiftType = FingerprintSource
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> FingerprintSource {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .raw
+        
+        case 2: return .spki
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: FingerprintSource, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .raw:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .spki:
+            writeInt(&buf, Int32(2))
+        
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFingerprintSource_lift(_ buf: RustBuffer) throws -> FingerprintSource {
+    return try FfiConverterTypeFingerprintSource.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFingerprintSource_lower(_ value: FingerprintSource) -> RustBuffer {
+    return FfiConverterTypeFingerprintSource.lower(value)
+}
+
+
+extension FingerprintSource: Equatable, Hashable {}
+
+
+
+
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+
+public enum HashFamily {
+    
+    case sha2
+    case shake
+}
+
+
+#if compiler(>=6)
+extension HashFamily: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeHashFamily: FfiConverterRustBuffer {
+    typealias SwiftType = HashFamily
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> HashFamily {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .sha2
+        
+        case 2: return .shake
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: HashFamily, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .sha2:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .shake:
+            writeInt(&buf, Int32(2))
+        
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeHashFamily_lift(_ buf: RustBuffer) throws -> HashFamily {
+    return try FfiConverterTypeHashFamily.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeHashFamily_lower(_ value: HashFamily) -> RustBuffer {
+    return FfiConverterTypeHashFamily.lower(value)
+}
+
+
+extension HashFamily: Equatable, Hashable {}
+
+
+
+
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+
+public enum Kdf {
+    
+    case argon2id
+    case scrypt
+}
+
+
+#if compiler(>=6)
+extension Kdf: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeKdf: FfiConverterRustBuffer {
+    typealias SwiftType = Kdf
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Kdf {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .argon2id
+        
+        case 2: return .scrypt
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: Kdf, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .argon2id:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .scrypt:
+            writeInt(&buf, Int32(2))
+        
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeKdf_lift(_ buf: RustBuffer) throws -> Kdf {
+    return try FfiConverterTypeKdf.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeKdf_lower(_ value: Kdf) -> RustBuffer {
+    return FfiConverterTypeKdf.lower(value)
+}
+
+
+extension Kdf: Equatable, Hashable {}
+
+
+
+
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+
+public enum ParameterSetId {
+    
+    case shake128s
+    case shake128f
+    case shake192s
+    case shake192f
+    case shake256s
+    case shake256f
+    case sha2128s
+    case sha2128f
+    case sha2192s
+    case sha2192f
+    case sha2256s
+    case sha2256f
+}
+
+
+#if compiler(>=6)
+extension ParameterSetId: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeParameterSetId: FfiConverterRustBuffer {
+    typealias SwiftType = ParameterSetId
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> ParameterSetId {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .shake128s
+        
+        case 2: return .shake128f
+        
+        case 3: return .shake192s
+        
+        case 4: return .shake192f
+        
+        case 5: return .shake256s
+        
+        case 6: return .shake256f
+        
+        case 7: return .sha2128s
+        
+        case 8: return .sha2128f
+        
+        case 9: return .sha2192s
+        
+        case 10: return .sha2192f
+        
+        case 11: return .sha2256s
+        
+        case 12: return .sha2256f
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: ParameterSetId, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .shake128s:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .shake128f:
+            writeInt(&buf, Int32(2))
+        
+        
+        case .shake192s:
+            writeInt(&buf, Int32(3))
+        
+        
+        case .shake192f:
+            writeInt(&buf, Int32(4))
+        
+        
+        case .shake256s:
+            writeInt(&buf, Int32(5))
+        
+        
+        case .shake256f:
+            writeInt(&buf, Int32(6))
+        
+        
+        case .sha2128s:
+            writeInt(&buf, Int32(7))
+        
+        
+        case .sha2128f:
+            writeInt(&buf, Int32(8))
+        
+        
+        case .sha2192s:
+            writeInt(&buf, Int32(9))
+        
+        
+        case .sha2192f:
+            writeInt(&buf, Int32(10))
+        
+        
+        case .sha2256s:
+            writeInt(&buf, Int32(11))
+        
+        
+        case .sha2256f:
+            writeInt(&buf, Int32(12))
+        
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeParameterSetId_lift(_ buf: RustBuffer) throws -> ParameterSetId {
+    return try FfiConverterTypeParameterSetId.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeParameterSetId_lower(_ value: ParameterSetId) -> RustBuffer {
+    return FfiConverterTypeParameterSetId.lower(value)
+}
+
+
+extension ParameterSetId: Equatable, Hashable {}
+
+
+
+
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+
+public enum SignatureMode {
+    
+    case pure
+    case preHashSha256
+    case preHashSha512
+    case preHashShake128
+    case preHashShake256
+}
+
+
+#if compiler(>=6)
+extension SignatureMode: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeSignatureMode: FfiConverterRustBuffer {
+    typealias SwiftType = SignatureMode
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SignatureMode {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .pure
+        
+        case 2: return .preHashSha256
+        
+        case 3: return .preHashSha512
+        
+        case 4: return .preHashShake128
+        
+        case 5: return .preHashShake256
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: SignatureMode, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .pure:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .preHashSha256:
+            writeInt(&buf, Int32(2))
+        
+        
+        case .preHashSha512:
+            writeInt(&buf, Int32(3))
+        
+        
+        case .preHashShake128:
+            writeInt(&buf, Int32(4))
         
-        case 3: return .shake192s
         
-        case 4: return .shake192f
+        case .preHashShake256:
+            writeInt(&buf, Int32(5))
         
-        case 5: return .shake256s
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeSignatureMode_lift(_ buf: RustBuffer) throws -> SignatureMode {
+    return try FfiConverterTypeSignatureMode.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeSignatureMode_lower(_ value: SignatureMode) -> RustBuffer {
+    return FfiConverterTypeSignatureMode.lower(value)
+}
+
+
+extension SignatureMode: Equatable, Hashable {}
+
+
+
+
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+
+public enum SlhDsaStatus {
+    
+    case ok
+    case invalidParam
+    case invalidLength
+    case decodeError
+    case verifyFailed
+    case contextTooLong
+    case malformedSigningKey
+    case malformedVerifyingKey
+    case malformedSignature
+    case parameterSetMismatch
+    case rngFailure
+    case unsupportedAlgorithm
+    case malformedEncoding
+    case invalidCertificate
+    case certificateExpired
+    case untrustedChain
+    case tokenExpired
+    case audienceMismatch
+    case selfTestFailed
+    case faultDetected
+    case decryptionFailed
+}
+
+
+#if compiler(>=6)
+extension SlhDsaStatus: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeSlhDsaStatus: FfiConverterRustBuffer {
+    typealias SwiftType = SlhDsaStatus
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SlhDsaStatus {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
         
-        case 6: return .shake256f
+        case 1: return .ok
         
-        case 7: return .sha2128s
+        case 2: return .invalidParam
         
-        case 8: return .sha2128f
+        case 3: return .invalidLength
         
-        case 9: return .sha2192s
+        case 4: return .decodeError
         
-        case 10: return .sha2192f
+        case 5: return .verifyFailed
         
-        case 11: return .sha2256s
+        case 6: return .contextTooLong
         
-        case 12: return .sha2256f
+        case 7: return .malformedSigningKey
+        
+        case 8: return .malformedVerifyingKey
+        
+        case 9: return .malformedSignature
+        
+        case 10: return .parameterSetMismatch
+        
+        case 11: return .rngFailure
+        
+        case 12: return .unsupportedAlgorithm
+        
+        case 13: return .malformedEncoding
+        
+        case 14: return .invalidCertificate
+        
+        case 15: return .certificateExpired
+        
+        case 16: return .untrustedChain
+        
+        case 17: return .tokenExpired
+        
+        case 18: return .audienceMismatch
+        
+        case 19: return .selfTestFailed
+        
+        case 20: return .faultDetected
+        
+        case 21: return .decryptionFailed
         
         default: throw UniffiInternalError.unexpectedEnumCase
         }
     }
 
-    public static func write(_ value: ParameterSetId, into buf: inout [UInt8]) {
+    public static func write(_ value: SlhDsaStatus, into buf: inout [UInt8]) {
         switch value {
         
         
-        case .shake128s:
+        case .ok:
             writeInt(&buf, Int32(1))
         
         
-        case .shake128f:
+        case .invalidParam:
             writeInt(&buf, Int32(2))
         
         
-        case .shake192s:
+        case .invalidLength:
             writeInt(&buf, Int32(3))
         
         
-        case .shake192f:
+        case .decodeError:
             writeInt(&buf, Int32(4))
         
         
-        case .shake256s:
+        case .verifyFailed:
             writeInt(&buf, Int32(5))
         
         
-        case .shake256f:
+        case .contextTooLong:
             writeInt(&buf, Int32(6))
         
         
-        case .sha2128s:
+        case .malformedSigningKey:
             writeInt(&buf, Int32(7))
         
         
-        case .sha2128f:
+        case .malformedVerifyingKey:
             writeInt(&buf, Int32(8))
         
         
-        case .sha2192s:
+        case .malformedSignature:
             writeInt(&buf, Int32(9))
         
         
-        case .sha2192f:
+        case .parameterSetMismatch:
             writeInt(&buf, Int32(10))
         
         
-        case .sha2256s:
+        case .rngFailure:
             writeInt(&buf, Int32(11))
         
         
-        case .sha2256f:
+        case .unsupportedAlgorithm:
             writeInt(&buf, Int32(12))
         
+        
+        case .malformedEncoding:
+            writeInt(&buf, Int32(13))
+        
+        
+        case .invalidCertificate:
+            writeInt(&buf, Int32(14))
+        
+        
+        case .certificateExpired:
+            writeInt(&buf, Int32(15))
+        
+        
+        case .untrustedChain:
+            writeInt(&buf, Int32(16))
+        
+        
+        case .tokenExpired:
+            writeInt(&buf, Int32(17))
+        
+        
+        case .audienceMismatch:
+            writeInt(&buf, Int32(18))
+        
+        
+        case .selfTestFailed:
+            writeInt(&buf, Int32(19))
+        
+        
+        case .faultDetected:
+            writeInt(&buf, Int32(20))
+        
+        
+        case .decryptionFailed:
+            writeInt(&buf, Int32(21))
+        
         }
     }
 }
@@ -846,103 +2475,412 @@ public struct FfiConverterTypeParameterSetId: FfiConverterRustBuffer {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeParameterSetId_lift(_ buf: RustBuffer) throws -> ParameterSetId {
-    return try FfiConverterTypeParameterSetId.lift(buf)
+public func FfiConverterTypeSlhDsaStatus_lift(_ buf: RustBuffer) throws -> SlhDsaStatus {
+    return try FfiConverterTypeSlhDsaStatus.lift(buf)
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeParameterSetId_lower(_ value: ParameterSetId) -> RustBuffer {
-    return FfiConverterTypeParameterSetId.lower(value)
+public func FfiConverterTypeSlhDsaStatus_lower(_ value: SlhDsaStatus) -> RustBuffer {
+    return FfiConverterTypeSlhDsaStatus.lower(value)
 }
 
 
-// Note that we don't yet support `indirect` for enums.
-// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+extension SlhDsaStatus: Equatable, Hashable {}
+
 
-public enum SlhDsaStatus: Equatable, Hashable {
-    
-    case ok
-    case invalidParam
-    case invalidLength
-    case decodeError
-    case verifyFailed
 
 
 
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+
+public enum SubjectAltName {
+    
+    case dns(name: String
+    )
+    case email(address: String
+    )
+    case uri(uri: String
+    )
+    case ip(address: Data
+    )
 }
 
+
 #if compiler(>=6)
-extension SlhDsaStatus: Sendable {}
+extension SubjectAltName: Sendable {}
 #endif
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeSlhDsaStatus: FfiConverterRustBuffer {
-    typealias SwiftType = SlhDsaStatus
+public struct FfiConverterTypeSubjectAltName: FfiConverterRustBuffer {
+    typealias SwiftType = SubjectAltName
 
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SlhDsaStatus {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SubjectAltName {
         let variant: Int32 = try readInt(&buf)
         switch variant {
         
-        case 1: return .ok
-        
-        case 2: return .invalidParam
+        case 1: return .dns(name: try FfiConverterString.read(from: &buf)
+        )
         
-        case 3: return .invalidLength
+        case 2: return .email(address: try FfiConverterString.read(from: &buf)
+        )
         
-        case 4: return .decodeError
+        case 3: return .uri(uri: try FfiConverterString.read(from: &buf)
+        )
         
-        case 5: return .verifyFailed
+        case 4: return .ip(address: try FfiConverterData.read(from: &buf)
+        )
         
         default: throw UniffiInternalError.unexpectedEnumCase
         }
     }
 
-    public static func write(_ value: SlhDsaStatus, into buf: inout [UInt8]) {
-        switch value {
-        
-        
-        case .ok:
-            writeInt(&buf, Int32(1))
-        
-        
-        case .invalidParam:
-            writeInt(&buf, Int32(2))
-        
-        
-        case .invalidLength:
-            writeInt(&buf, Int32(3))
-        
-        
-        case .decodeError:
-            writeInt(&buf, Int32(4))
-        
-        
-        case .verifyFailed:
-            writeInt(&buf, Int32(5))
-        
+    public static func write(_ value: SubjectAltName, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case let .dns(name):
+            writeInt(&buf, Int32(1))
+            FfiConverterString.write(name, into: &buf)
+            
+        
+        case let .email(address):
+            writeInt(&buf, Int32(2))
+            FfiConverterString.write(address, into: &buf)
+            
+        
+        case let .uri(uri):
+            writeInt(&buf, Int32(3))
+            FfiConverterString.write(uri, into: &buf)
+            
+        
+        case let .ip(address):
+            writeInt(&buf, Int32(4))
+            FfiConverterData.write(address, into: &buf)
+            
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeSubjectAltName_lift(_ buf: RustBuffer) throws -> SubjectAltName {
+    return try FfiConverterTypeSubjectAltName.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeSubjectAltName_lower(_ value: SubjectAltName) -> RustBuffer {
+    return FfiConverterTypeSubjectAltName.lower(value)
+}
+
+
+extension SubjectAltName: Equatable, Hashable {}
+
+
+
+
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionString: FfiConverterRustBuffer {
+    typealias SwiftType = String?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterString.write(value, into: &buf)
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterString.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionData: FfiConverterRustBuffer {
+    typealias SwiftType = Data?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterData.write(value, into: &buf)
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterData.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionTypeParameterSetId: FfiConverterRustBuffer {
+    typealias SwiftType = ParameterSetId?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterTypeParameterSetId.write(value, into: &buf)
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterTypeParameterSetId.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionTypeSignatureMode: FfiConverterRustBuffer {
+    typealias SwiftType = SignatureMode?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterTypeSignatureMode.write(value, into: &buf)
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterTypeSignatureMode.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceData: FfiConverterRustBuffer {
+    typealias SwiftType = [Data]
+
+    public static func write(_ value: [Data], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterData.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [Data] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [Data]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterData.read(from: &buf))
+        }
+        return seq
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceTypeCsrExtension: FfiConverterRustBuffer {
+    typealias SwiftType = [CsrExtension]
+
+    public static func write(_ value: [CsrExtension], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeCsrExtension.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [CsrExtension] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [CsrExtension]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeCsrExtension.read(from: &buf))
         }
+        return seq
     }
 }
 
-
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeSlhDsaStatus_lift(_ buf: RustBuffer) throws -> SlhDsaStatus {
-    return try FfiConverterTypeSlhDsaStatus.lift(buf)
+fileprivate struct FfiConverterSequenceTypeParameterSetInfo: FfiConverterRustBuffer {
+    typealias SwiftType = [ParameterSetInfo]
+
+    public static func write(_ value: [ParameterSetInfo], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeParameterSetInfo.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [ParameterSetInfo] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [ParameterSetInfo]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeParameterSetInfo.read(from: &buf))
+        }
+        return seq
+    }
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeSlhDsaStatus_lower(_ value: SlhDsaStatus) -> RustBuffer {
-    return FfiConverterTypeSlhDsaStatus.lower(value)
-}
+fileprivate struct FfiConverterSequenceTypeSubjectAltName: FfiConverterRustBuffer {
+    typealias SwiftType = [SubjectAltName]
+
+    public static func write(_ value: [SubjectAltName], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeSubjectAltName.write(item, into: &buf)
+        }
+    }
 
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [SubjectAltName] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [SubjectAltName]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeSubjectAltName.read(from: &buf))
+        }
+        return seq
+    }
+}
+public func slhDsaCoseSign1Create(param: ParameterSetId, signingKey: Data, payload: Data, keyId: Data?, externalAad: Data, detached: Bool) -> EncodeResult  {
+    return try!  FfiConverterTypeEncodeResult_lift(try! rustCall() {
+    uniffi_slh_dsa_uniffi_fn_func_slh_dsa_cose_sign1_create(
+        FfiConverterTypeParameterSetId_lower(param),
+        FfiConverterData.lower(signingKey),
+        FfiConverterData.lower(payload),
+        FfiConverterOptionData.lower(keyId),
+        FfiConverterData.lower(externalAad),
+        FfiConverterBool.lower(detached),$0
+    )
+})
+}
+public func slhDsaCoseSign1Verify(verifyingKey: Data, encoded: Data, detachedPayload: Data?, externalAad: Data) -> CoseSign1Info  {
+    return try!  FfiConverterTypeCoseSign1Info_lift(try! rustCall() {
+    uniffi_slh_dsa_uniffi_fn_func_slh_dsa_cose_sign1_verify(
+        FfiConverterData.lower(verifyingKey),
+        FfiConverterData.lower(encoded),
+        FfiConverterOptionData.lower(detachedPayload),
+        FfiConverterData.lower(externalAad),$0
+    )
+})
+}
+public func slhDsaCsrCreate(param: ParameterSetId, signingKey: Data, subject: CsrSubject, subjectAltNames: [SubjectAltName], extensions: [CsrExtension], pem: Bool) -> EncodeResult  {
+    return try!  FfiConverterTypeEncodeResult_lift(try! rustCall() {
+    uniffi_slh_dsa_uniffi_fn_func_slh_dsa_csr_create(
+        FfiConverterTypeParameterSetId_lower(param),
+        FfiConverterData.lower(signingKey),
+        FfiConverterTypeCsrSubject_lower(subject),
+        FfiConverterSequenceTypeSubjectAltName.lower(subjectAltNames),
+        FfiConverterSequenceTypeCsrExtension.lower(extensions),
+        FfiConverterBool.lower(pem),$0
+    )
+})
+}
+public func slhDsaCsrVerify(encoded: Data) -> CsrInfo  {
+    return try!  FfiConverterTypeCsrInfo_lift(try! rustCall() {
+    uniffi_slh_dsa_uniffi_fn_func_slh_dsa_csr_verify(
+        FfiConverterData.lower(encoded),$0
+    )
+})
+}
+public func slhDsaEnvelopeDecode(encoded: Data) -> EnvelopeInfo  {
+    return try!  FfiConverterTypeEnvelopeInfo_lift(try! rustCall() {
+    uniffi_slh_dsa_uniffi_fn_func_slh_dsa_envelope_decode(
+        FfiConverterData.lower(encoded),$0
+    )
+})
+}
+public func slhDsaEnvelopeSign(param: ParameterSetId, signingKey: Data, payload: Data, ctx: Data, keyId: Data?, mode: SignatureMode, detached: Bool) -> EncodeResult  {
+    return try!  FfiConverterTypeEncodeResult_lift(try! rustCall() {
+    uniffi_slh_dsa_uniffi_fn_func_slh_dsa_envelope_sign(
+        FfiConverterTypeParameterSetId_lower(param),
+        FfiConverterData.lower(signingKey),
+        FfiConverterData.lower(payload),
+        FfiConverterData.lower(ctx),
+        FfiConverterOptionData.lower(keyId),
+        FfiConverterTypeSignatureMode_lower(mode),
+        FfiConverterBool.lower(detached),$0
+    )
+})
+}
+public func slhDsaEnvelopeVerify(verifyingKey: Data, encoded: Data, detachedPayload: Data?) -> EnvelopeInfo  {
+    return try!  FfiConverterTypeEnvelopeInfo_lift(try! rustCall() {
+    uniffi_slh_dsa_uniffi_fn_func_slh_dsa_envelope_verify(
+        FfiConverterData.lower(verifyingKey),
+        FfiConverterData.lower(encoded),
+        FfiConverterOptionData.lower(detachedPayload),$0
+    )
+})
+}
+public func slhDsaFingerprint(param: ParameterSetId, verifyingKey: Data, source: FingerprintSource) -> FingerprintResult  {
+    return try!  FfiConverterTypeFingerprintResult_lift(try! rustCall() {
+    uniffi_slh_dsa_uniffi_fn_func_slh_dsa_fingerprint(
+        FfiConverterTypeParameterSetId_lower(param),
+        FfiConverterData.lower(verifyingKey),
+        FfiConverterTypeFingerprintSource_lower(source),$0
+    )
+})
+}
+public func slhDsaKeyFileLen(param: ParameterSetId) -> UInt64  {
+    return try!  FfiConverterUInt64.lift(try! rustCall() {
+    uniffi_slh_dsa_uniffi_fn_func_slh_dsa_key_file_len(
+        FfiConverterTypeParameterSetId_lower(param),$0
+    )
+})
+}
+public func slhDsaKeypairFromSeed(param: ParameterSetId, skSeed: Data, skPrf: Data, pkSeed: Data) -> Keypair  {
+    return try!  FfiConverterTypeKeypair_lift(try! rustCall() {
+    uniffi_slh_dsa_uniffi_fn_func_slh_dsa_keypair_from_seed(
+        FfiConverterTypeParameterSetId_lower(param),
+        FfiConverterData.lower(skSeed),
+        FfiConverterData.lower(skPrf),
+        FfiConverterData.lower(pkSeed),$0
+    )
+})
+}
 public func slhDsaKeypairGenerate(param: ParameterSetId) -> Keypair  {
     return try!  FfiConverterTypeKeypair_lift(try! rustCall() {
     uniffi_slh_dsa_uniffi_fn_func_slh_dsa_keypair_generate(
@@ -957,6 +2895,39 @@ public func slhDsaParameterName(param: ParameterSetId) -> String  {
     )
 })
 }
+public func slhDsaParameterSetFromName(name: String) -> ParameterSetId?  {
+    return try!  FfiConverterOptionTypeParameterSetId.lift(try! rustCall() {
+    uniffi_slh_dsa_uniffi_fn_func_slh_dsa_parameter_set_from_name(
+        FfiConverterString.lower(name),$0
+    )
+})
+}
+public func slhDsaParameterSetFromOid(oid: String) -> ParameterSetId?  {
+    return try!  FfiConverterOptionTypeParameterSetId.lift(try! rustCall() {
+    uniffi_slh_dsa_uniffi_fn_func_slh_dsa_parameter_set_from_oid(
+        FfiConverterString.lower(oid),$0
+    )
+})
+}
+public func slhDsaParameterSets() -> [ParameterSetInfo]  {
+    return try!  FfiConverterSequenceTypeParameterSetInfo.lift(try! rustCall() {
+    uniffi_slh_dsa_uniffi_fn_func_slh_dsa_parameter_sets($0
+    )
+})
+}
+public func slhDsaSeedLen(param: ParameterSetId) -> UInt64  {
+    return try!  FfiConverterUInt64.lift(try! rustCall() {
+    uniffi_slh_dsa_uniffi_fn_func_slh_dsa_seed_len(
+        FfiConverterTypeParameterSetId_lower(param),$0
+    )
+})
+}
+public func slhDsaSetVerifyAfterSign(enabled: Bool)  {try! rustCall() {
+    uniffi_slh_dsa_uniffi_fn_func_slh_dsa_set_verify_after_sign(
+        FfiConverterBool.lower(enabled),$0
+    )
+}
+}
 public func slhDsaSign(param: ParameterSetId, signingKey: Data, msg: Data, ctx: Data) -> SignResult  {
     return try!  FfiConverterTypeSignResult_lift(try! rustCall() {
     uniffi_slh_dsa_uniffi_fn_func_slh_dsa_sign(
@@ -967,6 +2938,26 @@ public func slhDsaSign(param: ParameterSetId, signingKey: Data, msg: Data, ctx:
     )
 })
 }
+public func slhDsaSignBatch(param: ParameterSetId, signingKey: Data, msgs: [Data], ctx: Data) -> SignBatchResult  {
+    return try!  FfiConverterTypeSignBatchResult_lift(try! rustCall() {
+    uniffi_slh_dsa_uniffi_fn_func_slh_dsa_sign_batch(
+        FfiConverterTypeParameterSetId_lower(param),
+        FfiConverterData.lower(signingKey),
+        FfiConverterSequenceData.lower(msgs),
+        FfiConverterData.lower(ctx),$0
+    )
+})
+}
+public func slhDsaSignChecked(param: ParameterSetId, signingKey: Data, msg: Data, ctx: Data) -> SignResult  {
+    return try!  FfiConverterTypeSignResult_lift(try! rustCall() {
+    uniffi_slh_dsa_uniffi_fn_func_slh_dsa_sign_checked(
+        FfiConverterTypeParameterSetId_lower(param),
+        FfiConverterData.lower(signingKey),
+        FfiConverterData.lower(msg),
+        FfiConverterData.lower(ctx),$0
+    )
+})
+}
 public func slhDsaSignDeterministic(param: ParameterSetId, signingKey: Data, msg: Data, ctx: Data) -> SignResult  {
     return try!  FfiConverterTypeSignResult_lift(try! rustCall() {
     uniffi_slh_dsa_uniffi_fn_func_slh_dsa_sign_deterministic(
@@ -984,6 +2975,36 @@ public func slhDsaSignatureLen(param: ParameterSetId) -> UInt64  {
     )
 })
 }
+public func slhDsaSigningKeyFromCoseKey(encoded: Data) -> DecodeKeyResult  {
+    return try!  FfiConverterTypeDecodeKeyResult_lift(try! rustCall() {
+    uniffi_slh_dsa_uniffi_fn_func_slh_dsa_signing_key_from_cose_key(
+        FfiConverterData.lower(encoded),$0
+    )
+})
+}
+public func slhDsaSigningKeyFromEncryptedPkcs8(encoded: Data, passphrase: Data) -> DecodeKeyResult  {
+    return try!  FfiConverterTypeDecodeKeyResult_lift(try! rustCall() {
+    uniffi_slh_dsa_uniffi_fn_func_slh_dsa_signing_key_from_encrypted_pkcs8(
+        FfiConverterData.lower(encoded),
+        FfiConverterData.lower(passphrase),$0
+    )
+})
+}
+public func slhDsaSigningKeyFromKeyFile(encoded: Data, passphrase: Data) -> DecodeKeyResult  {
+    return try!  FfiConverterTypeDecodeKeyResult_lift(try! rustCall() {
+    uniffi_slh_dsa_uniffi_fn_func_slh_dsa_signing_key_from_key_file(
+        FfiConverterData.lower(encoded),
+        FfiConverterData.lower(passphrase),$0
+    )
+})
+}
+public func slhDsaSigningKeyFromPkcs8(encoded: Data) -> DecodeKeyResult  {
+    return try!  FfiConverterTypeDecodeKeyResult_lift(try! rustCall() {
+    uniffi_slh_dsa_uniffi_fn_func_slh_dsa_signing_key_from_pkcs8(
+        FfiConverterData.lower(encoded),$0
+    )
+})
+}
 public func slhDsaSigningKeyLen(param: ParameterSetId) -> UInt64  {
     return try!  FfiConverterUInt64.lift(try! rustCall() {
     uniffi_slh_dsa_uniffi_fn_func_slh_dsa_signing_key_len(
@@ -991,6 +3012,52 @@ public func slhDsaSigningKeyLen(param: ParameterSetId) -> UInt64  {
     )
 })
 }
+public func slhDsaSigningKeyToCoseKey(param: ParameterSetId, signingKey: Data) -> EncodeResult  {
+    return try!  FfiConverterTypeEncodeResult_lift(try! rustCall() {
+    uniffi_slh_dsa_uniffi_fn_func_slh_dsa_signing_key_to_cose_key(
+        FfiConverterTypeParameterSetId_lower(param),
+        FfiConverterData.lower(signingKey),$0
+    )
+})
+}
+public func slhDsaSigningKeyToEncryptedPkcs8(param: ParameterSetId, signingKey: Data, passphrase: Data, pem: Bool) -> EncodeResult  {
+    return try!  FfiConverterTypeEncodeResult_lift(try! rustCall() {
+    uniffi_slh_dsa_uniffi_fn_func_slh_dsa_signing_key_to_encrypted_pkcs8(
+        FfiConverterTypeParameterSetId_lower(param),
+        FfiConverterData.lower(signingKey),
+        FfiConverterData.lower(passphrase),
+        FfiConverterBool.lower(pem),$0
+    )
+})
+}
+public func slhDsaSigningKeyToKeyFile(param: ParameterSetId, signingKey: Data, passphrase: Data, kdf: Kdf, aead: Aead) -> EncodeResult  {
+    return try!  FfiConverterTypeEncodeResult_lift(try! rustCall() {
+    uniffi_slh_dsa_uniffi_fn_func_slh_dsa_signing_key_to_key_file(
+        FfiConverterTypeParameterSetId_lower(param),
+        FfiConverterData.lower(signingKey),
+        FfiConverterData.lower(passphrase),
+        FfiConverterTypeKdf_lower(kdf),
+        FfiConverterTypeAead_lower(aead),$0
+    )
+})
+}
+public func slhDsaSigningKeyToPkcs8(param: ParameterSetId, signingKey: Data, pem: Bool) -> EncodeResult  {
+    return try!  FfiConverterTypeEncodeResult_lift(try! rustCall() {
+    uniffi_slh_dsa_uniffi_fn_func_slh_dsa_signing_key_to_pkcs8(
+        FfiConverterTypeParameterSetId_lower(param),
+        FfiConverterData.lower(signingKey),
+        FfiConverterBool.lower(pem),$0
+    )
+})
+}
+public func slhDsaValidateSigningKey(param: ParameterSetId, signingKey: Data) -> SlhDsaStatus  {
+    return try!  FfiConverterTypeSlhDsaStatus_lift(try! rustCall() {
+    uniffi_slh_dsa_uniffi_fn_func_slh_dsa_validate_signing_key(
+        FfiConverterTypeParameterSetId_lower(param),
+        FfiConverterData.lower(signingKey),$0
+    )
+})
+}
 public func slhDsaVerify(param: ParameterSetId, verifyingKey: Data, msg: Data, ctx: Data, signature: Data) -> VerifyResult  {
     return try!  FfiConverterTypeVerifyResult_lift(try! rustCall() {
     uniffi_slh_dsa_uniffi_fn_func_slh_dsa_verify(
@@ -1002,6 +3069,19 @@ public func slhDsaVerify(param: ParameterSetId, verifyingKey: Data, msg: Data, c
     )
 })
 }
+public func slhDsaVerifyAfterSignEnabled() -> Bool  {
+    return try!  FfiConverterBool.lift(try! rustCall() {
+    uniffi_slh_dsa_uniffi_fn_func_slh_dsa_verify_after_sign_enabled($0
+    )
+})
+}
+public func slhDsaVerifyingKeyFromCoseKey(encoded: Data) -> DecodeKeyResult  {
+    return try!  FfiConverterTypeDecodeKeyResult_lift(try! rustCall() {
+    uniffi_slh_dsa_uniffi_fn_func_slh_dsa_verifying_key_from_cose_key(
+        FfiConverterData.lower(encoded),$0
+    )
+})
+}
 public func slhDsaVerifyingKeyFromSigningKey(param: ParameterSetId, signingKey: Data) -> KeyResult  {
     return try!  FfiConverterTypeKeyResult_lift(try! rustCall() {
     uniffi_slh_dsa_uniffi_fn_func_slh_dsa_verifying_key_from_signing_key(
@@ -1010,6 +3090,21 @@ public func slhDsaVerifyingKeyFromSigningKey(param: ParameterSetId, signingKey:
     )
 })
 }
+public func slhDsaVerifyingKeyFromSigningKeyValidated(param: ParameterSetId, signingKey: Data) -> KeyResult  {
+    return try!  FfiConverterTypeKeyResult_lift(try! rustCall() {
+    uniffi_slh_dsa_uniffi_fn_func_slh_dsa_verifying_key_from_signing_key_validated(
+        FfiConverterTypeParameterSetId_lower(param),
+        FfiConverterData.lower(signingKey),$0
+    )
+})
+}
+public func slhDsaVerifyingKeyFromSpki(encoded: Data) -> DecodeKeyResult  {
+    return try!  FfiConverterTypeDecodeKeyResult_lift(try! rustCall() {
+    uniffi_slh_dsa_uniffi_fn_func_slh_dsa_verifying_key_from_spki(
+        FfiConverterData.lower(encoded),$0
+    )
+})
+}
 public func slhDsaVerifyingKeyLen(param: ParameterSetId) -> UInt64  {
     return try!  FfiConverterUInt64.lift(try! rustCall() {
     uniffi_slh_dsa_uniffi_fn_func_slh_dsa_verifying_key_len(
@@ -1017,6 +3112,23 @@ public func slhDsaVerifyingKeyLen(param: ParameterSetId) -> UInt64  {
     )
 })
 }
+public func slhDsaVerifyingKeyToCoseKey(param: ParameterSetId, verifyingKey: Data) -> EncodeResult  {
+    return try!  FfiConverterTypeEncodeResult_lift(try! rustCall() {
+    uniffi_slh_dsa_uniffi_fn_func_slh_dsa_verifying_key_to_cose_key(
+        FfiConverterTypeParameterSetId_lower(param),
+        FfiConverterData.lower(verifyingKey),$0
+    )
+})
+}
+public func slhDsaVerifyingKeyToSpki(param: ParameterSetId, verifyingKey: Data, pem: Bool) -> EncodeResult  {
+    return try!  FfiConverterTypeEncodeResult_lift(try! rustCall() {
+    uniffi_slh_dsa_uniffi_fn_func_slh_dsa_verifying_key_to_spki(
+        FfiConverterTypeParameterSetId_lower(param),
+        FfiConverterData.lower(verifyingKey),
+        FfiConverterBool.lower(pem),$0
+    )
+})
+}
 
 private enum InitializationResult {
     case ok
@@ -1027,39 +3139,135 @@ private enum InitializationResult {
 // the code inside is only computed once.
 private let initializationResult: InitializationResult = {
     // Get the bindings contract version from our ComponentInterface
-    let bindings_contract_version = 30
+    let bindings_contract_version = 29
     // Get the scaffolding contract version by calling the into the dylib
     let scaffolding_contract_version = ffi_slh_dsa_uniffi_uniffi_contract_version()
     if bindings_contract_version != scaffolding_contract_version {
         return InitializationResult.contractVersionMismatch
     }
+    if (uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_cose_sign1_create() != 11547) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_cose_sign1_verify() != 34918) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_csr_create() != 38104) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_csr_verify() != 54660) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_envelope_decode() != 43712) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_envelope_sign() != 62941) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_envelope_verify() != 55803) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_fingerprint() != 23530) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_key_file_len() != 21678) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_keypair_from_seed() != 31227) {
+        return InitializationResult.apiChecksumMismatch
+    }
     if (uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_keypair_generate() != 7757) {
         return InitializationResult.apiChecksumMismatch
     }
     if (uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_parameter_name() != 63153) {
         return InitializationResult.apiChecksumMismatch
     }
+    if (uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_parameter_set_from_name() != 56401) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_parameter_set_from_oid() != 48394) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_parameter_sets() != 26697) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_seed_len() != 18769) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_set_verify_after_sign() != 6374) {
+        return InitializationResult.apiChecksumMismatch
+    }
     if (uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_sign() != 51560) {
         return InitializationResult.apiChecksumMismatch
     }
+    if (uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_sign_batch() != 55765) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_sign_checked() != 30107) {
+        return InitializationResult.apiChecksumMismatch
+    }
     if (uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_sign_deterministic() != 23979) {
         return InitializationResult.apiChecksumMismatch
     }
     if (uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_signature_len() != 62991) {
         return InitializationResult.apiChecksumMismatch
     }
+    if (uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_signing_key_from_cose_key() != 34229) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_signing_key_from_encrypted_pkcs8() != 19253) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_signing_key_from_key_file() != 53092) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_signing_key_from_pkcs8() != 17809) {
+        return InitializationResult.apiChecksumMismatch
+    }
     if (uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_signing_key_len() != 40535) {
         return InitializationResult.apiChecksumMismatch
     }
+    if (uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_signing_key_to_cose_key() != 51163) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_signing_key_to_encrypted_pkcs8() != 36675) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_signing_key_to_key_file() != 43469) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_signing_key_to_pkcs8() != 52043) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_validate_signing_key() != 6415) {
+        return InitializationResult.apiChecksumMismatch
+    }
     if (uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_verify() != 22241) {
         return InitializationResult.apiChecksumMismatch
     }
+    if (uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_verify_after_sign_enabled() != 43959) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_verifying_key_from_cose_key() != 62128) {
+        return InitializationResult.apiChecksumMismatch
+    }
     if (uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_verifying_key_from_signing_key() != 64766) {
         return InitializationResult.apiChecksumMismatch
     }
+    if (uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_verifying_key_from_signing_key_validated() != 37694) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_verifying_key_from_spki() != 1557) {
+        return InitializationResult.apiChecksumMismatch
+    }
     if (uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_verifying_key_len() != 16179) {
         return InitializationResult.apiChecksumMismatch
     }
+    if (uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_verifying_key_to_cose_key() != 45780) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_verifying_key_to_spki() != 37838) {
+        return InitializationResult.apiChecksumMismatch
+    }
 
     return InitializationResult.ok
 }()
@@ -50,9 +50,9 @@ typedef void (*UniffiRustFutureContinuationCallback)(uint64_t, int8_t
     );
 
 #endif
-#ifndef UNIFFI_FFIDEF_FOREIGN_FUTURE_DROPPED_CALLBACK
-#define UNIFFI_FFIDEF_FOREIGN_FUTURE_DROPPED_CALLBACK
-typedef void (*UniffiForeignFutureDroppedCallback)(uint64_t
+#ifndef UNIFFI_FFIDEF_FOREIGN_FUTURE_FREE
+#define UNIFFI_FFIDEF_FOREIGN_FUTURE_FREE
+typedef void (*UniffiForeignFutureFree)(uint64_t
     );
 
 #endif
@@ -62,186 +62,244 @@ typedef void (*UniffiCallbackInterfaceFree)(uint64_t
     );
 
 #endif
-#ifndef UNIFFI_FFIDEF_CALLBACK_INTERFACE_CLONE
-#define UNIFFI_FFIDEF_CALLBACK_INTERFACE_CLONE
-typedef uint64_t (*UniffiCallbackInterfaceClone)(uint64_t
-    );
-
-#endif
-#ifndef UNIFFI_FFIDEF_FOREIGN_FUTURE_DROPPED_CALLBACK_STRUCT
-#define UNIFFI_FFIDEF_FOREIGN_FUTURE_DROPPED_CALLBACK_STRUCT
-typedef struct UniffiForeignFutureDroppedCallbackStruct {
+#ifndef UNIFFI_FFIDEF_FOREIGN_FUTURE
+#define UNIFFI_FFIDEF_FOREIGN_FUTURE
+typedef struct UniffiForeignFuture {
     uint64_t handle;
-    UniffiForeignFutureDroppedCallback _Nonnull free;
-} UniffiForeignFutureDroppedCallbackStruct;
+    UniffiForeignFutureFree _Nonnull free;
+} UniffiForeignFuture;
 
 #endif
-#ifndef UNIFFI_FFIDEF_FOREIGN_FUTURE_RESULT_U8
-#define UNIFFI_FFIDEF_FOREIGN_FUTURE_RESULT_U8
-typedef struct UniffiForeignFutureResultU8 {
+#ifndef UNIFFI_FFIDEF_FOREIGN_FUTURE_STRUCT_U8
+#define UNIFFI_FFIDEF_FOREIGN_FUTURE_STRUCT_U8
+typedef struct UniffiForeignFutureStructU8 {
     uint8_t returnValue;
     RustCallStatus callStatus;
-} UniffiForeignFutureResultU8;
+} UniffiForeignFutureStructU8;
 
 #endif
 #ifndef UNIFFI_FFIDEF_FOREIGN_FUTURE_COMPLETE_U8
 #define UNIFFI_FFIDEF_FOREIGN_FUTURE_COMPLETE_U8
-typedef void (*UniffiForeignFutureCompleteU8)(uint64_t, UniffiForeignFutureResultU8
+typedef void (*UniffiForeignFutureCompleteU8)(uint64_t, UniffiForeignFutureStructU8
     );
 
 #endif
-#ifndef UNIFFI_FFIDEF_FOREIGN_FUTURE_RESULT_I8
-#define UNIFFI_FFIDEF_FOREIGN_FUTURE_RESULT_I8
-typedef struct UniffiForeignFutureResultI8 {
+#ifndef UNIFFI_FFIDEF_FOREIGN_FUTURE_STRUCT_I8
+#define UNIFFI_FFIDEF_FOREIGN_FUTURE_STRUCT_I8
+typedef struct UniffiForeignFutureStructI8 {
     int8_t returnValue;
     RustCallStatus callStatus;
-} UniffiForeignFutureResultI8;
+} UniffiForeignFutureStructI8;
 
 #endif
 #ifndef UNIFFI_FFIDEF_FOREIGN_FUTURE_COMPLETE_I8
 #define UNIFFI_FFIDEF_FOREIGN_FUTURE_COMPLETE_I8
-typedef void (*UniffiForeignFutureCompleteI8)(uint64_t, UniffiForeignFutureResultI8
+typedef void (*UniffiForeignFutureCompleteI8)(uint64_t, UniffiForeignFutureStructI8
     );
 
 #endif
-#ifndef UNIFFI_FFIDEF_FOREIGN_FUTURE_RESULT_U16
-#define UNIFFI_FFIDEF_FOREIGN_FUTURE_RESULT_U16
-typedef struct UniffiForeignFutureResultU16 {
+#ifndef UNIFFI_FFIDEF_FOREIGN_FUTURE_STRUCT_U16
+#define UNIFFI_FFIDEF_FOREIGN_FUTURE_STRUCT_U16
+typedef struct UniffiForeignFutureStructU16 {
     uint16_t returnValue;
     RustCallStatus callStatus;
-} UniffiForeignFutureResultU16;
+} UniffiForeignFutureStructU16;
 
 #endif
 #ifndef UNIFFI_FFIDEF_FOREIGN_FUTURE_COMPLETE_U16
 #define UNIFFI_FFIDEF_FOREIGN_FUTURE_COMPLETE_U16
-typedef void (*UniffiForeignFutureCompleteU16)(uint64_t, UniffiForeignFutureResultU16
+typedef void (*UniffiForeignFutureCompleteU16)(uint64_t, UniffiForeignFutureStructU16
     );
 
 #endif
-#ifndef UNIFFI_FFIDEF_FOREIGN_FUTURE_RESULT_I16
-#define UNIFFI_FFIDEF_FOREIGN_FUTURE_RESULT_I16
-typedef struct UniffiForeignFutureResultI16 {
+#ifndef UNIFFI_FFIDEF_FOREIGN_FUTURE_STRUCT_I16
+#define UNIFFI_FFIDEF_FOREIGN_FUTURE_STRUCT_I16
+typedef struct UniffiForeignFutureStructI16 {
     int16_t returnValue;
     RustCallStatus callStatus;
-} UniffiForeignFutureResultI16;
+} UniffiForeignFutureStructI16;
 
 #endif
 #ifndef UNIFFI_FFIDEF_FOREIGN_FUTURE_COMPLETE_I16
 #define UNIFFI_FFIDEF_FOREIGN_FUTURE_COMPLETE_I16
-typedef void (*UniffiForeignFutureCompleteI16)(uint64_t, UniffiForeignFutureResultI16
+typedef void (*UniffiForeignFutureCompleteI16)(uint64_t, UniffiForeignFutureStructI16
     );
 
 #endif
-#ifndef UNIFFI_FFIDEF_FOREIGN_FUTURE_RESULT_U32
-#define UNIFFI_FFIDEF_FOREIGN_FUTURE_RESULT_U32
-typedef struct UniffiForeignFutureResultU32 {
+#ifndef UNIFFI_FFIDEF_FOREIGN_FUTURE_STRUCT_U32
+#define UNIFFI_FFIDEF_FOREIGN_FUTURE_STRUCT_U32
+typedef struct UniffiForeignFutureStructU32 {
     uint32_t returnValue;
     RustCallStatus callStatus;
-} UniffiForeignFutureResultU32;
+} UniffiForeignFutureStructU32;
 
 #endif
 #ifndef UNIFFI_FFIDEF_FOREIGN_FUTURE_COMPLETE_U32
 #define UNIFFI_FFIDEF_FOREIGN_FUTURE_COMPLETE_U32
-typedef void (*UniffiForeignFutureCompleteU32)(uint64_t, UniffiForeignFutureResultU32
+typedef void (*UniffiForeignFutureCompleteU32)(uint64_t, UniffiForeignFutureStructU32
     );
 
 #endif
-#ifndef UNIFFI_FFIDEF_FOREIGN_FUTURE_RESULT_I32
-#define UNIFFI_FFIDEF_FOREIGN_FUTURE_RESULT_I32
-typedef struct UniffiForeignFutureResultI32 {
+#ifndef UNIFFI_FFIDEF_FOREIGN_FUTURE_STRUCT_I32
+#define UNIFFI_FFIDEF_FOREIGN_FUTURE_STRUCT_I32
+typedef struct UniffiForeignFutureStructI32 {
     int32_t returnValue;
     RustCallStatus callStatus;
-} UniffiForeignFutureResultI32;
+} UniffiForeignFutureStructI32;
 
 #endif
 #ifndef UNIFFI_FFIDEF_FOREIGN_FUTURE_COMPLETE_I32
 #define UNIFFI_FFIDEF_FOREIGN_FUTURE_COMPLETE_I32
-typedef void (*UniffiForeignFutureCompleteI32)(uint64_t, UniffiForeignFutureResultI32
+typedef void (*UniffiForeignFutureCompleteI32)(uint64_t, UniffiForeignFutureStructI32
     );
 
 #endif
-#ifndef UNIFFI_FFIDEF_FOREIGN_FUTURE_RESULT_U64
-#define UNIFFI_FFIDEF_FOREIGN_FUTURE_RESULT_U64
-typedef struct UniffiForeignFutureResultU64 {
+#ifndef UNIFFI_FFIDEF_FOREIGN_FUTURE_STRUCT_U64
+#define UNIFFI_FFIDEF_FOREIGN_FUTURE_STRUCT_U64
+typedef struct UniffiForeignFutureStructU64 {
     uint64_t returnValue;
     RustCallStatus callStatus;
-} UniffiForeignFutureResultU64;
+} UniffiForeignFutureStructU64;
 
 #endif
 #ifndef UNIFFI_FFIDEF_FOREIGN_FUTURE_COMPLETE_U64
 #define UNIFFI_FFIDEF_FOREIGN_FUTURE_COMPLETE_U64
-typedef void (*UniffiForeignFutureCompleteU64)(uint64_t, UniffiForeignFutureResultU64
+typedef void (*UniffiForeignFutureCompleteU64)(uint64_t, UniffiForeignFutureStructU64
     );
 
 #endif
-#ifndef UNIFFI_FFIDEF_FOREIGN_FUTURE_RESULT_I64
-#define UNIFFI_FFIDEF_FOREIGN_FUTURE_RESULT_I64
-typedef struct UniffiForeignFutureResultI64 {
+#ifndef UNIFFI_FFIDEF_FOREIGN_FUTURE_STRUCT_I64
+#define UNIFFI_FFIDEF_FOREIGN_FUTURE_STRUCT_I64
+typedef struct UniffiForeignFutureStructI64 {
     int64_t returnValue;
     RustCallStatus callStatus;
-} UniffiForeignFutureResultI64;
+} UniffiForeignFutureStructI64;
 
 #endif
 #ifndef UNIFFI_FFIDEF_FOREIGN_FUTURE_COMPLETE_I64
 #define UNIFFI_FFIDEF_FOREIGN_FUTURE_COMPLETE_I64
-typedef void (*UniffiForeignFutureCompleteI64)(uint64_t, UniffiForeignFutureResultI64
+typedef void (*UniffiForeignFutureCompleteI64)(uint64_t, UniffiForeignFutureStructI64
     );
 
 #endif
-#ifndef UNIFFI_FFIDEF_FOREIGN_FUTURE_RESULT_F32
-#define UNIFFI_FFIDEF_FOREIGN_FUTURE_RESULT_F32
-typedef struct UniffiForeignFutureResultF32 {
+#ifndef UNIFFI_FFIDEF_FOREIGN_FUTURE_STRUCT_F32
+#define UNIFFI_FFIDEF_FOREIGN_FUTURE_STRUCT_F32
+typedef struct UniffiForeignFutureStructF32 {
     float returnValue;
     RustCallStatus callStatus;
-} UniffiForeignFutureResultF32;
+} UniffiForeignFutureStructF32;
 
 #endif
 #ifndef UNIFFI_FFIDEF_FOREIGN_FUTURE_COMPLETE_F32
 #define UNIFFI_FFIDEF_FOREIGN_FUTURE_COMPLETE_F32
-typedef void (*UniffiForeignFutureCompleteF32)(uint64_t, UniffiForeignFutureResultF32
+typedef void (*UniffiForeignFutureCompleteF32)(uint64_t, UniffiForeignFutureStructF32
     );
 
 #endif
-#ifndef UNIFFI_FFIDEF_FOREIGN_FUTURE_RESULT_F64
-#define UNIFFI_FFIDEF_FOREIGN_FUTURE_RESULT_F64
-typedef struct UniffiForeignFutureResultF64 {
+#ifndef UNIFFI_FFIDEF_FOREIGN_FUTURE_STRUCT_F64
+#define UNIFFI_FFIDEF_FOREIGN_FUTURE_STRUCT_F64
+typedef struct UniffiForeignFutureStructF64 {
     double returnValue;
     RustCallStatus callStatus;
-} UniffiForeignFutureResultF64;
+} UniffiForeignFutureStructF64;
 
 #endif
 #ifndef UNIFFI_FFIDEF_FOREIGN_FUTURE_COMPLETE_F64
 #define UNIFFI_FFIDEF_FOREIGN_FUTURE_COMPLETE_F64
-typedef void (*UniffiForeignFutureCompleteF64)(uint64_t, UniffiForeignFutureResultF64
+typedef void (*UniffiForeignFutureCompleteF64)(uint64_t, UniffiForeignFutureStructF64
+    );
+
+#endif
+#ifndef UNIFFI_FFIDEF_FOREIGN_FUTURE_STRUCT_POINTER
+#define UNIFFI_FFIDEF_FOREIGN_FUTURE_STRUCT_POINTER
+typedef struct UniffiForeignFutureStructPointer {
+    void*_Nonnull returnValue;
+    RustCallStatus callStatus;
+} UniffiForeignFutureStructPointer;
+
+#endif
+#ifndef UNIFFI_FFIDEF_FOREIGN_FUTURE_COMPLETE_POINTER
+#define UNIFFI_FFIDEF_FOREIGN_FUTURE_COMPLETE_POINTER
+typedef void (*UniffiForeignFutureCompletePointer)(uint64_t, UniffiForeignFutureStructPointer
     );
 
 #endif
-#ifndef UNIFFI_FFIDEF_FOREIGN_FUTURE_RESULT_RUST_BUFFER
-#define UNIFFI_FFIDEF_FOREIGN_FUTURE_RESULT_RUST_BUFFER
-typedef struct UniffiForeignFutureResultRustBuffer {
+#ifndef UNIFFI_FFIDEF_FOREIGN_FUTURE_STRUCT_RUST_BUFFER
+#define UNIFFI_FFIDEF_FOREIGN_FUTURE_STRUCT_RUST_BUFFER
+typedef struct UniffiForeignFutureStructRustBuffer {
     RustBuffer returnValue;
     RustCallStatus callStatus;
-} UniffiForeignFutureResultRustBuffer;
+} UniffiForeignFutureStructRustBuffer;
 
 #endif
 #ifndef UNIFFI_FFIDEF_FOREIGN_FUTURE_COMPLETE_RUST_BUFFER
 #define UNIFFI_FFIDEF_FOREIGN_FUTURE_COMPLETE_RUST_BUFFER
-typedef void (*UniffiForeignFutureCompleteRustBuffer)(uint64_t, UniffiForeignFutureResultRustBuffer
+typedef void (*UniffiForeignFutureCompleteRustBuffer)(uint64_t, UniffiForeignFutureStructRustBuffer
     );
 
 #endif
-#ifndef UNIFFI_FFIDEF_FOREIGN_FUTURE_RESULT_VOID
-#define UNIFFI_FFIDEF_FOREIGN_FUTURE_RESULT_VOID
-typedef struct UniffiForeignFutureResultVoid {
+#ifndef UNIFFI_FFIDEF_FOREIGN_FUTURE_STRUCT_VOID
+#define UNIFFI_FFIDEF_FOREIGN_FUTURE_STRUCT_VOID
+typedef struct UniffiForeignFutureStructVoid {
     RustCallStatus callStatus;
-} UniffiForeignFutureResultVoid;
+} UniffiForeignFutureStructVoid;
 
 #endif
 #ifndef UNIFFI_FFIDEF_FOREIGN_FUTURE_COMPLETE_VOID
 #define UNIFFI_FFIDEF_FOREIGN_FUTURE_COMPLETE_VOID
-typedef void (*UniffiForeignFutureCompleteVoid)(uint64_t, UniffiForeignFutureResultVoid
+typedef void (*UniffiForeignFutureCompleteVoid)(uint64_t, UniffiForeignFutureStructVoid
     );
 
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SLH_DSA_UNIFFI_FN_FUNC_SLH_DSA_COSE_SIGN1_CREATE
+#define UNIFFI_FFIDEF_UNIFFI_SLH_DSA_UNIFFI_FN_FUNC_SLH_DSA_COSE_SIGN1_CREATE
+RustBuffer uniffi_slh_dsa_uniffi_fn_func_slh_dsa_cose_sign1_create(RustBuffer param, RustBuffer signing_key, RustBuffer payload, RustBuffer key_id, RustBuffer external_aad, int8_t detached, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SLH_DSA_UNIFFI_FN_FUNC_SLH_DSA_COSE_SIGN1_VERIFY
+#define UNIFFI_FFIDEF_UNIFFI_SLH_DSA_UNIFFI_FN_FUNC_SLH_DSA_COSE_SIGN1_VERIFY
+RustBuffer uniffi_slh_dsa_uniffi_fn_func_slh_dsa_cose_sign1_verify(RustBuffer verifying_key, RustBuffer encoded, RustBuffer detached_payload, RustBuffer external_aad, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SLH_DSA_UNIFFI_FN_FUNC_SLH_DSA_CSR_CREATE
+#define UNIFFI_FFIDEF_UNIFFI_SLH_DSA_UNIFFI_FN_FUNC_SLH_DSA_CSR_CREATE
+RustBuffer uniffi_slh_dsa_uniffi_fn_func_slh_dsa_csr_create(RustBuffer param, RustBuffer signing_key, RustBuffer subject, RustBuffer subject_alt_names, RustBuffer extensions, int8_t pem, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SLH_DSA_UNIFFI_FN_FUNC_SLH_DSA_CSR_VERIFY
+#define UNIFFI_FFIDEF_UNIFFI_SLH_DSA_UNIFFI_FN_FUNC_SLH_DSA_CSR_VERIFY
+RustBuffer uniffi_slh_dsa_uniffi_fn_func_slh_dsa_csr_verify(RustBuffer encoded, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SLH_DSA_UNIFFI_FN_FUNC_SLH_DSA_ENVELOPE_DECODE
+#define UNIFFI_FFIDEF_UNIFFI_SLH_DSA_UNIFFI_FN_FUNC_SLH_DSA_ENVELOPE_DECODE
+RustBuffer uniffi_slh_dsa_uniffi_fn_func_slh_dsa_envelope_decode(RustBuffer encoded, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SLH_DSA_UNIFFI_FN_FUNC_SLH_DSA_ENVELOPE_SIGN
+#define UNIFFI_FFIDEF_UNIFFI_SLH_DSA_UNIFFI_FN_FUNC_SLH_DSA_ENVELOPE_SIGN
+RustBuffer uniffi_slh_dsa_uniffi_fn_func_slh_dsa_envelope_sign(RustBuffer param, RustBuffer signing_key, RustBuffer payload, RustBuffer ctx, RustBuffer key_id, RustBuffer mode, int8_t detached, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SLH_DSA_UNIFFI_FN_FUNC_SLH_DSA_ENVELOPE_VERIFY
+#define UNIFFI_FFIDEF_UNIFFI_SLH_DSA_UNIFFI_FN_FUNC_SLH_DSA_ENVELOPE_VERIFY
+RustBuffer uniffi_slh_dsa_uniffi_fn_func_slh_dsa_envelope_verify(RustBuffer verifying_key, RustBuffer encoded, RustBuffer detached_payload, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SLH_DSA_UNIFFI_FN_FUNC_SLH_DSA_FINGERPRINT
+#define UNIFFI_FFIDEF_UNIFFI_SLH_DSA_UNIFFI_FN_FUNC_SLH_DSA_FINGERPRINT
+RustBuffer uniffi_slh_dsa_uniffi_fn_func_slh_dsa_fingerprint(RustBuffer param, RustBuffer verifying_key, RustBuffer source, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SLH_DSA_UNIFFI_FN_FUNC_SLH_DSA_KEY_FILE_LEN
+#define UNIFFI_FFIDEF_UNIFFI_SLH_DSA_UNIFFI_FN_FUNC_SLH_DSA_KEY_FILE_LEN
+uint64_t uniffi_slh_dsa_uniffi_fn_func_slh_dsa_key_file_len(RustBuffer param, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SLH_DSA_UNIFFI_FN_FUNC_SLH_DSA_KEYPAIR_FROM_SEED
+#define UNIFFI_FFIDEF_UNIFFI_SLH_DSA_UNIFFI_FN_FUNC_SLH_DSA_KEYPAIR_FROM_SEED
+RustBuffer uniffi_slh_dsa_uniffi_fn_func_slh_dsa_keypair_from_seed(RustBuffer param, RustBuffer sk_seed, RustBuffer sk_prf, RustBuffer pk_seed, RustCallStatus *_Nonnull out_status
+);
 #endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_SLH_DSA_UNIFFI_FN_FUNC_SLH_DSA_KEYPAIR_GENERATE
 #define UNIFFI_FFIDEF_UNIFFI_SLH_DSA_UNIFFI_FN_FUNC_SLH_DSA_KEYPAIR_GENERATE
@@ -253,11 +311,47 @@ RustBuffer uniffi_slh_dsa_uniffi_fn_func_slh_dsa_keypair_generate(RustBuffer par
 RustBuffer uniffi_slh_dsa_uniffi_fn_func_slh_dsa_parameter_name(RustBuffer param, RustCallStatus *_Nonnull out_status
 );
 #endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SLH_DSA_UNIFFI_FN_FUNC_SLH_DSA_PARAMETER_SET_FROM_NAME
+#define UNIFFI_FFIDEF_UNIFFI_SLH_DSA_UNIFFI_FN_FUNC_SLH_DSA_PARAMETER_SET_FROM_NAME
+RustBuffer uniffi_slh_dsa_uniffi_fn_func_slh_dsa_parameter_set_from_name(RustBuffer name, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SLH_DSA_UNIFFI_FN_FUNC_SLH_DSA_PARAMETER_SET_FROM_OID
+#define UNIFFI_FFIDEF_UNIFFI_SLH_DSA_UNIFFI_FN_FUNC_SLH_DSA_PARAMETER_SET_FROM_OID
+RustBuffer uniffi_slh_dsa_uniffi_fn_func_slh_dsa_parameter_set_from_oid(RustBuffer oid, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SLH_DSA_UNIFFI_FN_FUNC_SLH_DSA_PARAMETER_SETS
+#define UNIFFI_FFIDEF_UNIFFI_SLH_DSA_UNIFFI_FN_FUNC_SLH_DSA_PARAMETER_SETS
+RustBuffer uniffi_slh_dsa_uniffi_fn_func_slh_dsa_parameter_sets(RustCallStatus *_Nonnull out_status
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SLH_DSA_UNIFFI_FN_FUNC_SLH_DSA_SEED_LEN
+#define UNIFFI_FFIDEF_UNIFFI_SLH_DSA_UNIFFI_FN_FUNC_SLH_DSA_SEED_LEN
+uint64_t uniffi_slh_dsa_uniffi_fn_func_slh_dsa_seed_len(RustBuffer param, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SLH_DSA_UNIFFI_FN_FUNC_SLH_DSA_SET_VERIFY_AFTER_SIGN
+#define UNIFFI_FFIDEF_UNIFFI_SLH_DSA_UNIFFI_FN_FUNC_SLH_DSA_SET_VERIFY_AFTER_SIGN
+void uniffi_slh_dsa_uniffi_fn_func_slh_dsa_set_verify_after_sign(int8_t enabled, RustCallStatus *_Nonnull out_status
+);
+#endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_SLH_DSA_UNIFFI_FN_FUNC_SLH_DSA_SIGN
 #define UNIFFI_FFIDEF_UNIFFI_SLH_DSA_UNIFFI_FN_FUNC_SLH_DSA_SIGN
 RustBuffer uniffi_slh_dsa_uniffi_fn_func_slh_dsa_sign(RustBuffer param, RustBuffer signing_key, RustBuffer msg, RustBuffer ctx, RustCallStatus *_Nonnull out_status
 );
 #endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SLH_DSA_UNIFFI_FN_FUNC_SLH_DSA_SIGN_BATCH
+#define UNIFFI_FFIDEF_UNIFFI_SLH_DSA_UNIFFI_FN_FUNC_SLH_DSA_SIGN_BATCH
+RustBuffer uniffi_slh_dsa_uniffi_fn_func_slh_dsa_sign_batch(RustBuffer param, RustBuffer signing_key, RustBuffer msgs, RustBuffer ctx, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SLH_DSA_UNIFFI_FN_FUNC_SLH_DSA_SIGN_CHECKED
+#define UNIFFI_FFIDEF_UNIFFI_SLH_DSA_UNIFFI_FN_FUNC_SLH_DSA_SIGN_CHECKED
+RustBuffer uniffi_slh_dsa_uniffi_fn_func_slh_dsa_sign_checked(RustBuffer param, RustBuffer signing_key, RustBuffer msg, RustBuffer ctx, RustCallStatus *_Nonnull out_status
+);
+#endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_SLH_DSA_UNIFFI_FN_FUNC_SLH_DSA_SIGN_DETERMINISTIC
 #define UNIFFI_FFIDEF_UNIFFI_SLH_DSA_UNIFFI_FN_FUNC_SLH_DSA_SIGN_DETERMINISTIC
 RustBuffer uniffi_slh_dsa_uniffi_fn_func_slh_dsa_sign_deterministic(RustBuffer param, RustBuffer signing_key, RustBuffer msg, RustBuffer ctx, RustCallStatus *_Nonnull out_status
@@ -268,26 +362,102 @@ RustBuffer uniffi_slh_dsa_uniffi_fn_func_slh_dsa_sign_deterministic(RustBuffer p
 uint64_t uniffi_slh_dsa_uniffi_fn_func_slh_dsa_signature_len(RustBuffer param, RustCallStatus *_Nonnull out_status
 );
 #endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SLH_DSA_UNIFFI_FN_FUNC_SLH_DSA_SIGNING_KEY_FROM_COSE_KEY
+#define UNIFFI_FFIDEF_UNIFFI_SLH_DSA_UNIFFI_FN_FUNC_SLH_DSA_SIGNING_KEY_FROM_COSE_KEY
+RustBuffer uniffi_slh_dsa_uniffi_fn_func_slh_dsa_signing_key_from_cose_key(RustBuffer encoded, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SLH_DSA_UNIFFI_FN_FUNC_SLH_DSA_SIGNING_KEY_FROM_ENCRYPTED_PKCS8
+#define UNIFFI_FFIDEF_UNIFFI_SLH_DSA_UNIFFI_FN_FUNC_SLH_DSA_SIGNING_KEY_FROM_ENCRYPTED_PKCS8
+RustBuffer uniffi_slh_dsa_uniffi_fn_func_slh_dsa_signing_key_from_encrypted_pkcs8(RustBuffer encoded, RustBuffer passphrase, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SLH_DSA_UNIFFI_FN_FUNC_SLH_DSA_SIGNING_KEY_FROM_KEY_FILE
+#define UNIFFI_FFIDEF_UNIFFI_SLH_DSA_UNIFFI_FN_FUNC_SLH_DSA_SIGNING_KEY_FROM_KEY_FILE
+RustBuffer uniffi_slh_dsa_uniffi_fn_func_slh_dsa_signing_key_from_key_file(RustBuffer encoded, RustBuffer passphrase, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SLH_DSA_UNIFFI_FN_FUNC_SLH_DSA_SIGNING_KEY_FROM_PKCS8
+#define UNIFFI_FFIDEF_UNIFFI_SLH_DSA_UNIFFI_FN_FUNC_SLH_DSA_SIGNING_KEY_FROM_PKCS8
+RustBuffer uniffi_slh_dsa_uniffi_fn_func_slh_dsa_signing_key_from_pkcs8(RustBuffer encoded, RustCallStatus *_Nonnull out_status
+);
+#endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_SLH_DSA_UNIFFI_FN_FUNC_SLH_DSA_SIGNING_KEY_LEN
 #define UNIFFI_FFIDEF_UNIFFI_SLH_DSA_UNIFFI_FN_FUNC_SLH_DSA_SIGNING_KEY_LEN
 uint64_t uniffi_slh_dsa_uniffi_fn_func_slh_dsa_signing_key_len(RustBuffer param, RustCallStatus *_Nonnull out_status
 );
 #endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SLH_DSA_UNIFFI_FN_FUNC_SLH_DSA_SIGNING_KEY_TO_COSE_KEY
+#define UNIFFI_FFIDEF_UNIFFI_SLH_DSA_UNIFFI_FN_FUNC_SLH_DSA_SIGNING_KEY_TO_COSE_KEY
+RustBuffer uniffi_slh_dsa_uniffi_fn_func_slh_dsa_signing_key_to_cose_key(RustBuffer param, RustBuffer signing_key, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SLH_DSA_UNIFFI_FN_FUNC_SLH_DSA_SIGNING_KEY_TO_ENCRYPTED_PKCS8
+#define UNIFFI_FFIDEF_UNIFFI_SLH_DSA_UNIFFI_FN_FUNC_SLH_DSA_SIGNING_KEY_TO_ENCRYPTED_PKCS8
+RustBuffer uniffi_slh_dsa_uniffi_fn_func_slh_dsa_signing_key_to_encrypted_pkcs8(RustBuffer param, RustBuffer signing_key, RustBuffer passphrase, int8_t pem, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SLH_DSA_UNIFFI_FN_FUNC_SLH_DSA_SIGNING_KEY_TO_KEY_FILE
+#define UNIFFI_FFIDEF_UNIFFI_SLH_DSA_UNIFFI_FN_FUNC_SLH_DSA_SIGNING_KEY_TO_KEY_FILE
+RustBuffer uniffi_slh_dsa_uniffi_fn_func_slh_dsa_signing_key_to_key_file(RustBuffer param, RustBuffer signing_key, RustBuffer passphrase, RustBuffer kdf, RustBuffer aead, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SLH_DSA_UNIFFI_FN_FUNC_SLH_DSA_SIGNING_KEY_TO_PKCS8
+#define UNIFFI_FFIDEF_UNIFFI_SLH_DSA_UNIFFI_FN_FUNC_SLH_DSA_SIGNING_KEY_TO_PKCS8
+RustBuffer uniffi_slh_dsa_uniffi_fn_func_slh_dsa_signing_key_to_pkcs8(RustBuffer param, RustBuffer signing_key, int8_t pem, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SLH_DSA_UNIFFI_FN_FUNC_SLH_DSA_VALIDATE_SIGNING_KEY
+#define UNIFFI_FFIDEF_UNIFFI_SLH_DSA_UNIFFI_FN_FUNC_SLH_DSA_VALIDATE_SIGNING_KEY
+RustBuffer uniffi_slh_dsa_uniffi_fn_func_slh_dsa_validate_signing_key(RustBuffer param, RustBuffer signing_key, RustCallStatus *_Nonnull out_status
+);
+#endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_SLH_DSA_UNIFFI_FN_FUNC_SLH_DSA_VERIFY
 #define UNIFFI_FFIDEF_UNIFFI_SLH_DSA_UNIFFI_FN_FUNC_SLH_DSA_VERIFY
 RustBuffer uniffi_slh_dsa_uniffi_fn_func_slh_dsa_verify(RustBuffer param, RustBuffer verifying_key, RustBuffer msg, RustBuffer ctx, RustBuffer signature, RustCallStatus *_Nonnull out_status
 );
 #endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SLH_DSA_UNIFFI_FN_FUNC_SLH_DSA_VERIFY_AFTER_SIGN_ENABLED
+#define UNIFFI_FFIDEF_UNIFFI_SLH_DSA_UNIFFI_FN_FUNC_SLH_DSA_VERIFY_AFTER_SIGN_ENABLED
+int8_t uniffi_slh_dsa_uniffi_fn_func_slh_dsa_verify_after_sign_enabled(RustCallStatus *_Nonnull out_status
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SLH_DSA_UNIFFI_FN_FUNC_SLH_DSA_VERIFYING_KEY_FROM_COSE_KEY
+#define UNIFFI_FFIDEF_UNIFFI_SLH_DSA_UNIFFI_FN_FUNC_SLH_DSA_VERIFYING_KEY_FROM_COSE_KEY
+RustBuffer uniffi_slh_dsa_uniffi_fn_func_slh_dsa_verifying_key_from_cose_key(RustBuffer encoded, RustCallStatus *_Nonnull out_status
+);
+#endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_SLH_DSA_UNIFFI_FN_FUNC_SLH_DSA_VERIFYING_KEY_FROM_SIGNING_KEY
 #define UNIFFI_FFIDEF_UNIFFI_SLH_DSA_UNIFFI_FN_FUNC_SLH_DSA_VERIFYING_KEY_FROM_SIGNING_KEY
 RustBuffer uniffi_slh_dsa_uniffi_fn_func_slh_dsa_verifying_key_from_signing_key(RustBuffer param, RustBuffer signing_key, RustCallStatus *_Nonnull out_status
 );
 #endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SLH_DSA_UNIFFI_FN_FUNC_SLH_DSA_VERIFYING_KEY_FROM_SIGNING_KEY_VALIDATED
+#define UNIFFI_FFIDEF_UNIFFI_SLH_DSA_UNIFFI_FN_FUNC_SLH_DSA_VERIFYING_KEY_FROM_SIGNING_KEY_VALIDATED
+RustBuffer uniffi_slh_dsa_uniffi_fn_func_slh_dsa_verifying_key_from_signing_key_validated(RustBuffer param, RustBuffer signing_key, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SLH_DSA_UNIFFI_FN_FUNC_SLH_DSA_VERIFYING_KEY_FROM_SPKI
+#define UNIFFI_FFIDEF_UNIFFI_SLH_DSA_UNIFFI_FN_FUNC_SLH_DSA_VERIFYING_KEY_FROM_SPKI
+RustBuffer uniffi_slh_dsa_uniffi_fn_func_slh_dsa_verifying_key_from_spki(RustBuffer encoded, RustCallStatus *_Nonnull out_status
+);
+#endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_SLH_DSA_UNIFFI_FN_FUNC_SLH_DSA_VERIFYING_KEY_LEN
 #define UNIFFI_FFIDEF_UNIFFI_SLH_DSA_UNIFFI_FN_FUNC_SLH_DSA_VERIFYING_KEY_LEN
 uint64_t uniffi_slh_dsa_uniffi_fn_func_slh_dsa_verifying_key_len(RustBuffer param, RustCallStatus *_Nonnull out_status
 );
 #endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SLH_DSA_UNIFFI_FN_FUNC_SLH_DSA_VERIFYING_KEY_TO_COSE_KEY
+#define UNIFFI_FFIDEF_UNIFFI_SLH_DSA_UNIFFI_FN_FUNC_SLH_DSA_VERIFYING_KEY_TO_COSE_KEY
+RustBuffer uniffi_slh_dsa_uniffi_fn_func_slh_dsa_verifying_key_to_cose_key(RustBuffer param, RustBuffer verifying_key, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SLH_DSA_UNIFFI_FN_FUNC_SLH_DSA_VERIFYING_KEY_TO_SPKI
+#define UNIFFI_FFIDEF_UNIFFI_SLH_DSA_UNIFFI_FN_FUNC_SLH_DSA_VERIFYING_KEY_TO_SPKI
+RustBuffer uniffi_slh_dsa_uniffi_fn_func_slh_dsa_verifying_key_to_spki(RustBuffer param, RustBuffer verifying_key, int8_t pem, RustCallStatus *_Nonnull out_status
+);
+#endif
 #ifndef UNIFFI_FFIDEF_FFI_SLH_DSA_UNIFFI_RUSTBUFFER_ALLOC
 #define UNIFFI_FFIDEF_FFI_SLH_DSA_UNIFFI_RUSTBUFFER_ALLOC
 RustBuffer ffi_slh_dsa_uniffi_rustbuffer_alloc(uint64_t size, RustCallStatus *_Nonnull out_status
@@ -508,6 +678,26 @@ void ffi_slh_dsa_uniffi_rust_future_free_f64(uint64_t handle
 double ffi_slh_dsa_uniffi_rust_future_complete_f64(uint64_t handle, RustCallStatus *_Nonnull out_status
 );
 #endif
+#ifndef UNIFFI_FFIDEF_FFI_SLH_DSA_UNIFFI_RUST_FUTURE_POLL_POINTER
+#define UNIFFI_FFIDEF_FFI_SLH_DSA_UNIFFI_RUST_FUTURE_POLL_POINTER
+void ffi_slh_dsa_uniffi_rust_future_poll_pointer(uint64_t handle, UniffiRustFutureContinuationCallback _Nonnull callback, uint64_t callback_data
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_SLH_DSA_UNIFFI_RUST_FUTURE_CANCEL_POINTER
+#define UNIFFI_FFIDEF_FFI_SLH_DSA_UNIFFI_RUST_FUTURE_CANCEL_POINTER
+void ffi_slh_dsa_uniffi_rust_future_cancel_pointer(uint64_t handle
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_SLH_DSA_UNIFFI_RUST_FUTURE_FREE_POINTER
+#define UNIFFI_FFIDEF_FFI_SLH_DSA_UNIFFI_RUST_FUTURE_FREE_POINTER
+void ffi_slh_dsa_uniffi_rust_future_free_pointer(uint64_t handle
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_SLH_DSA_UNIFFI_RUST_FUTURE_COMPLETE_POINTER
+#define UNIFFI_FFIDEF_FFI_SLH_DSA_UNIFFI_RUST_FUTURE_COMPLETE_POINTER
+void*_Nonnull ffi_slh_dsa_uniffi_rust_future_complete_pointer(uint64_t handle, RustCallStatus *_Nonnull out_status
+);
+#endif
 #ifndef UNIFFI_FFIDEF_FFI_SLH_DSA_UNIFFI_RUST_FUTURE_POLL_RUST_BUFFER
 #define UNIFFI_FFIDEF_FFI_SLH_DSA_UNIFFI_RUST_FUTURE_POLL_RUST_BUFFER
 void ffi_slh_dsa_uniffi_rust_future_poll_rust_buffer(uint64_t handle, UniffiRustFutureContinuationCallback _Nonnull callback, uint64_t callback_data
@@ -546,6 +736,66 @@ void ffi_slh_dsa_uniffi_rust_future_free_void(uint64_t handle
 #ifndef UNIFFI_FFIDEF_FFI_SLH_DSA_UNIFFI_RUST_FUTURE_COMPLETE_VOID
 #define UNIFFI_FFIDEF_FFI_SLH_DSA_UNIFFI_RUST_FUTURE_COMPLETE_VOID
 void ffi_slh_dsa_uniffi_rust_future_complete_void(uint64_t handle, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SLH_DSA_UNIFFI_CHECKSUM_FUNC_SLH_DSA_COSE_SIGN1_CREATE
+#define UNIFFI_FFIDEF_UNIFFI_SLH_DSA_UNIFFI_CHECKSUM_FUNC_SLH_DSA_COSE_SIGN1_CREATE
+uint16_t uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_cose_sign1_create(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SLH_DSA_UNIFFI_CHECKSUM_FUNC_SLH_DSA_COSE_SIGN1_VERIFY
+#define UNIFFI_FFIDEF_UNIFFI_SLH_DSA_UNIFFI_CHECKSUM_FUNC_SLH_DSA_COSE_SIGN1_VERIFY
+uint16_t uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_cose_sign1_verify(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SLH_DSA_UNIFFI_CHECKSUM_FUNC_SLH_DSA_CSR_CREATE
+#define UNIFFI_FFIDEF_UNIFFI_SLH_DSA_UNIFFI_CHECKSUM_FUNC_SLH_DSA_CSR_CREATE
+uint16_t uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_csr_create(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SLH_DSA_UNIFFI_CHECKSUM_FUNC_SLH_DSA_CSR_VERIFY
+#define UNIFFI_FFIDEF_UNIFFI_SLH_DSA_UNIFFI_CHECKSUM_FUNC_SLH_DSA_CSR_VERIFY
+uint16_t uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_csr_verify(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SLH_DSA_UNIFFI_CHECKSUM_FUNC_SLH_DSA_ENVELOPE_DECODE
+#define UNIFFI_FFIDEF_UNIFFI_SLH_DSA_UNIFFI_CHECKSUM_FUNC_SLH_DSA_ENVELOPE_DECODE
+uint16_t uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_envelope_decode(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SLH_DSA_UNIFFI_CHECKSUM_FUNC_SLH_DSA_ENVELOPE_SIGN
+#define UNIFFI_FFIDEF_UNIFFI_SLH_DSA_UNIFFI_CHECKSUM_FUNC_SLH_DSA_ENVELOPE_SIGN
+uint16_t uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_envelope_sign(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SLH_DSA_UNIFFI_CHECKSUM_FUNC_SLH_DSA_ENVELOPE_VERIFY
+#define UNIFFI_FFIDEF_UNIFFI_SLH_DSA_UNIFFI_CHECKSUM_FUNC_SLH_DSA_ENVELOPE_VERIFY
+uint16_t uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_envelope_verify(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SLH_DSA_UNIFFI_CHECKSUM_FUNC_SLH_DSA_FINGERPRINT
+#define UNIFFI_FFIDEF_UNIFFI_SLH_DSA_UNIFFI_CHECKSUM_FUNC_SLH_DSA_FINGERPRINT
+uint16_t uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_fingerprint(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SLH_DSA_UNIFFI_CHECKSUM_FUNC_SLH_DSA_KEY_FILE_LEN
+#define UNIFFI_FFIDEF_UNIFFI_SLH_DSA_UNIFFI_CHECKSUM_FUNC_SLH_DSA_KEY_FILE_LEN
+uint16_t uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_key_file_len(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SLH_DSA_UNIFFI_CHECKSUM_FUNC_SLH_DSA_KEYPAIR_FROM_SEED
+#define UNIFFI_FFIDEF_UNIFFI_SLH_DSA_UNIFFI_CHECKSUM_FUNC_SLH_DSA_KEYPAIR_FROM_SEED
+uint16_t uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_keypair_from_seed(void
+    
 );
 #endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_SLH_DSA_UNIFFI_CHECKSUM_FUNC_SLH_DSA_KEYPAIR_GENERATE
@@ -558,12 +808,54 @@ uint16_t uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_keypair_generate(void
 #define UNIFFI_FFIDEF_UNIFFI_SLH_DSA_UNIFFI_CHECKSUM_FUNC_SLH_DSA_PARAMETER_NAME
 uint16_t uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_parameter_name(void
     
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SLH_DSA_UNIFFI_CHECKSUM_FUNC_SLH_DSA_PARAMETER_SET_FROM_NAME
+#define UNIFFI_FFIDEF_UNIFFI_SLH_DSA_UNIFFI_CHECKSUM_FUNC_SLH_DSA_PARAMETER_SET_FROM_NAME
+uint16_t uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_parameter_set_from_name(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SLH_DSA_UNIFFI_CHECKSUM_FUNC_SLH_DSA_PARAMETER_SET_FROM_OID
+#define UNIFFI_FFIDEF_UNIFFI_SLH_DSA_UNIFFI_CHECKSUM_FUNC_SLH_DSA_PARAMETER_SET_FROM_OID
+uint16_t uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_parameter_set_from_oid(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SLH_DSA_UNIFFI_CHECKSUM_FUNC_SLH_DSA_PARAMETER_SETS
+#define UNIFFI_FFIDEF_UNIFFI_SLH_DSA_UNIFFI_CHECKSUM_FUNC_SLH_DSA_PARAMETER_SETS
+uint16_t uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_parameter_sets(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SLH_DSA_UNIFFI_CHECKSUM_FUNC_SLH_DSA_SEED_LEN
+#define UNIFFI_FFIDEF_UNIFFI_SLH_DSA_UNIFFI_CHECKSUM_FUNC_SLH_DSA_SEED_LEN
+uint16_t uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_seed_len(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SLH_DSA_UNIFFI_CHECKSUM_FUNC_SLH_DSA_SET_VERIFY_AFTER_SIGN
+#define UNIFFI_FFIDEF_UNIFFI_SLH_DSA_UNIFFI_CHECKSUM_FUNC_SLH_DSA_SET_VERIFY_AFTER_SIGN
+uint16_t uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_set_verify_after_sign(void
+    
 );
 #endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_SLH_DSA_UNIFFI_CHECKSUM_FUNC_SLH_DSA_SIGN
 #define UNIFFI_FFIDEF_UNIFFI_SLH_DSA_UNIFFI_CHECKSUM_FUNC_SLH_DSA_SIGN
 uint16_t uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_sign(void
     
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SLH_DSA_UNIFFI_CHECKSUM_FUNC_SLH_DSA_SIGN_BATCH
+#define UNIFFI_FFIDEF_UNIFFI_SLH_DSA_UNIFFI_CHECKSUM_FUNC_SLH_DSA_SIGN_BATCH
+uint16_t uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_sign_batch(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SLH_DSA_UNIFFI_CHECKSUM_FUNC_SLH_DSA_SIGN_CHECKED
+#define UNIFFI_FFIDEF_UNIFFI_SLH_DSA_UNIFFI_CHECKSUM_FUNC_SLH_DSA_SIGN_CHECKED
+uint16_t uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_sign_checked(void
+    
 );
 #endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_SLH_DSA_UNIFFI_CHECKSUM_FUNC_SLH_DSA_SIGN_DETERMINISTIC
@@ -576,30 +868,120 @@ uint16_t uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_sign_deterministic(void
 #define UNIFFI_FFIDEF_UNIFFI_SLH_DSA_UNIFFI_CHECKSUM_FUNC_SLH_DSA_SIGNATURE_LEN
 uint16_t uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_signature_len(void
     
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SLH_DSA_UNIFFI_CHECKSUM_FUNC_SLH_DSA_SIGNING_KEY_FROM_COSE_KEY
+#define UNIFFI_FFIDEF_UNIFFI_SLH_DSA_UNIFFI_CHECKSUM_FUNC_SLH_DSA_SIGNING_KEY_FROM_COSE_KEY
+uint16_t uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_signing_key_from_cose_key(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SLH_DSA_UNIFFI_CHECKSUM_FUNC_SLH_DSA_SIGNING_KEY_FROM_ENCRYPTED_PKCS8
+#define UNIFFI_FFIDEF_UNIFFI_SLH_DSA_UNIFFI_CHECKSUM_FUNC_SLH_DSA_SIGNING_KEY_FROM_ENCRYPTED_PKCS8
+uint16_t uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_signing_key_from_encrypted_pkcs8(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SLH_DSA_UNIFFI_CHECKSUM_FUNC_SLH_DSA_SIGNING_KEY_FROM_KEY_FILE
+#define UNIFFI_FFIDEF_UNIFFI_SLH_DSA_UNIFFI_CHECKSUM_FUNC_SLH_DSA_SIGNING_KEY_FROM_KEY_FILE
+uint16_t uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_signing_key_from_key_file(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SLH_DSA_UNIFFI_CHECKSUM_FUNC_SLH_DSA_SIGNING_KEY_FROM_PKCS8
+#define UNIFFI_FFIDEF_UNIFFI_SLH_DSA_UNIFFI_CHECKSUM_FUNC_SLH_DSA_SIGNING_KEY_FROM_PKCS8
+uint16_t uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_signing_key_from_pkcs8(void
+    
 );
 #endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_SLH_DSA_UNIFFI_CHECKSUM_FUNC_SLH_DSA_SIGNING_KEY_LEN
 #define UNIFFI_FFIDEF_UNIFFI_SLH_DSA_UNIFFI_CHECKSUM_FUNC_SLH_DSA_SIGNING_KEY_LEN
 uint16_t uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_signing_key_len(void
     
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SLH_DSA_UNIFFI_CHECKSUM_FUNC_SLH_DSA_SIGNING_KEY_TO_COSE_KEY
+#define UNIFFI_FFIDEF_UNIFFI_SLH_DSA_UNIFFI_CHECKSUM_FUNC_SLH_DSA_SIGNING_KEY_TO_COSE_KEY
+uint16_t uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_signing_key_to_cose_key(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SLH_DSA_UNIFFI_CHECKSUM_FUNC_SLH_DSA_SIGNING_KEY_TO_ENCRYPTED_PKCS8
+#define UNIFFI_FFIDEF_UNIFFI_SLH_DSA_UNIFFI_CHECKSUM_FUNC_SLH_DSA_SIGNING_KEY_TO_ENCRYPTED_PKCS8
+uint16_t uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_signing_key_to_encrypted_pkcs8(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SLH_DSA_UNIFFI_CHECKSUM_FUNC_SLH_DSA_SIGNING_KEY_TO_KEY_FILE
+#define UNIFFI_FFIDEF_UNIFFI_SLH_DSA_UNIFFI_CHECKSUM_FUNC_SLH_DSA_SIGNING_KEY_TO_KEY_FILE
+uint16_t uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_signing_key_to_key_file(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SLH_DSA_UNIFFI_CHECKSUM_FUNC_SLH_DSA_SIGNING_KEY_TO_PKCS8
+#define UNIFFI_FFIDEF_UNIFFI_SLH_DSA_UNIFFI_CHECKSUM_FUNC_SLH_DSA_SIGNING_KEY_TO_PKCS8
+uint16_t uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_signing_key_to_pkcs8(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SLH_DSA_UNIFFI_CHECKSUM_FUNC_SLH_DSA_VALIDATE_SIGNING_KEY
+#define UNIFFI_FFIDEF_UNIFFI_SLH_DSA_UNIFFI_CHECKSUM_FUNC_SLH_DSA_VALIDATE_SIGNING_KEY
+uint16_t uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_validate_signing_key(void
+    
 );
 #endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_SLH_DSA_UNIFFI_CHECKSUM_FUNC_SLH_DSA_VERIFY
 #define UNIFFI_FFIDEF_UNIFFI_SLH_DSA_UNIFFI_CHECKSUM_FUNC_SLH_DSA_VERIFY
 uint16_t uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_verify(void
     
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SLH_DSA_UNIFFI_CHECKSUM_FUNC_SLH_DSA_VERIFY_AFTER_SIGN_ENABLED
+#define UNIFFI_FFIDEF_UNIFFI_SLH_DSA_UNIFFI_CHECKSUM_FUNC_SLH_DSA_VERIFY_AFTER_SIGN_ENABLED
+uint16_t uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_verify_after_sign_enabled(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SLH_DSA_UNIFFI_CHECKSUM_FUNC_SLH_DSA_VERIFYING_KEY_FROM_COSE_KEY
+#define UNIFFI_FFIDEF_UNIFFI_SLH_DSA_UNIFFI_CHECKSUM_FUNC_SLH_DSA_VERIFYING_KEY_FROM_COSE_KEY
+uint16_t uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_verifying_key_from_cose_key(void
+    
 );
 #endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_SLH_DSA_UNIFFI_CHECKSUM_FUNC_SLH_DSA_VERIFYING_KEY_FROM_SIGNING_KEY
 #define UNIFFI_FFIDEF_UNIFFI_SLH_DSA_UNIFFI_CHECKSUM_FUNC_SLH_DSA_VERIFYING_KEY_FROM_SIGNING_KEY
 uint16_t uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_verifying_key_from_signing_key(void
     
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SLH_DSA_UNIFFI_CHECKSUM_FUNC_SLH_DSA_VERIFYING_KEY_FROM_SIGNING_KEY_VALIDATED
+#define UNIFFI_FFIDEF_UNIFFI_SLH_DSA_UNIFFI_CHECKSUM_FUNC_SLH_DSA_VERIFYING_KEY_FROM_SIGNING_KEY_VALIDATED
+uint16_t uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_verifying_key_from_signing_key_validated(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SLH_DSA_UNIFFI_CHECKSUM_FUNC_SLH_DSA_VERIFYING_KEY_FROM_SPKI
+#define UNIFFI_FFIDEF_UNIFFI_SLH_DSA_UNIFFI_CHECKSUM_FUNC_SLH_DSA_VERIFYING_KEY_FROM_SPKI
+uint16_t uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_verifying_key_from_spki(void
+    
 );
 #endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_SLH_DSA_UNIFFI_CHECKSUM_FUNC_SLH_DSA_VERIFYING_KEY_LEN
 #define UNIFFI_FFIDEF_UNIFFI_SLH_DSA_UNIFFI_CHECKSUM_FUNC_SLH_DSA_VERIFYING_KEY_LEN
 uint16_t uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_verifying_key_len(void
     
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SLH_DSA_UNIFFI_CHECKSUM_FUNC_SLH_DSA_VERIFYING_KEY_TO_COSE_KEY
+#define UNIFFI_FFIDEF_UNIFFI_SLH_DSA_UNIFFI_CHECKSUM_FUNC_SLH_DSA_VERIFYING_KEY_TO_COSE_KEY
+uint16_t uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_verifying_key_to_cose_key(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_SLH_DSA_UNIFFI_CHECKSUM_FUNC_SLH_DSA_VERIFYING_KEY_TO_SPKI
+#define UNIFFI_FFIDEF_UNIFFI_SLH_DSA_UNIFFI_CHECKSUM_FUNC_SLH_DSA_VERIFYING_KEY_TO_SPKI
+uint16_t uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_verifying_key_to_spki(void
+    
 );
 #endif
 #ifndef UNIFFI_FFIDEF_FFI_SLH_DSA_UNIFFI_UNIFFI_CONTRACT_VERSION
@@ -23,6 +23,72 @@ interface NativeModuleInterface {
     buffer: Uint8Array,
     uniffi_out_err: UniffiRustCallStatus,
   ): string;
+  ubrn_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_cose_sign1_create(
+    param: Uint8Array,
+    signingKey: Uint8Array,
+    payload: Uint8Array,
+    keyId: Uint8Array,
+    externalAad: Uint8Array,
+    detached: number,
+    uniffi_out_err: UniffiRustCallStatus,
+  ): Uint8Array;
+  ubrn_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_cose_sign1_verify(
+    verifyingKey: Uint8Array,
+    encoded: Uint8Array,
+    detachedPayload: Uint8Array,
+    externalAad: Uint8Array,
+    uniffi_out_err: UniffiRustCallStatus,
+  ): Uint8Array;
+  ubrn_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_csr_create(
+    param: Uint8Array,
+    signingKey: Uint8Array,
+    subject: Uint8Array,
+    subjectAltNames: Uint8Array,
+    extensions: Uint8Array,
+    pem: number,
+    uniffi_out_err: UniffiRustCallStatus,
+  ): Uint8Array;
+  ubrn_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_csr_verify(
+    encoded: Uint8Array,
+    uniffi_out_err: UniffiRustCallStatus,
+  ): Uint8Array;
+  ubrn_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_envelope_decode(
+    encoded: Uint8Array,
+    uniffi_out_err: UniffiRustCallStatus,
+  ): Uint8Array;
+  ubrn_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_envelope_sign(
+    param: Uint8Array,
+    signingKey: Uint8Array,
+    payload: Uint8Array,
+    ctx: Uint8Array,
+    keyId: Uint8Array,
+    mode: Uint8Array,
+    detached: number,
+    uniffi_out_err: UniffiRustCallStatus,
+  ): Uint8Array;
+  ubrn_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_envelope_verify(
+    verifyingKey: Uint8Array,
+    encoded: Uint8Array,
+    detachedPayload: Uint8Array,
+    uniffi_out_err: UniffiRustCallStatus,
+  ): Uint8Array;
+  ubrn_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_fingerprint(
+    param: Uint8Array,
+    verifyingKey: Uint8Array,
+    source: Uint8Array,
+    uniffi_out_err: UniffiRustCallStatus,
+  ): Uint8Array;
+  ubrn_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_key_file_len(
+    param: Uint8Array,
+    uniffi_out_err: UniffiRustCallStatus,
+  ): bigint;
+  ubrn_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_keypair_from_seed(
+    param: Uint8Array,
+    skSeed: Uint8Array,
+    skPrf: Uint8Array,
+    pkSeed: Uint8Array,
+    uniffi_out_err: UniffiRustCallStatus,
+  ): Uint8Array;
   ubrn_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_keypair_generate(
     param: Uint8Array,
     uniffi_out_err: UniffiRustCallStatus,
@@ -31,6 +97,25 @@ interface NativeModuleInterface {
     param: Uint8Array,
     uniffi_out_err: UniffiRustCallStatus,
   ): Uint8Array;
+  ubrn_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_parameter_set_from_name(
+    name: Uint8Array,
+    uniffi_out_err: UniffiRustCallStatus,
+  ): Uint8Array;
+  ubrn_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_parameter_set_from_oid(
+    oid: Uint8Array,
+    uniffi_out_err: UniffiRustCallStatus,
+  ): Uint8Array;
+  ubrn_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_parameter_sets(
+    uniffi_out_err: UniffiRustCallStatus,
+  ): Uint8Array;
+  ubrn_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_seed_len(
+    param: Uint8Array,
+    uniffi_out_err: UniffiRustCallStatus,
+  ): bigint;
+  ubrn_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_set_verify_after_sign(
+    enabled: number,
+    uniffi_out_err: UniffiRustCallStatus,
+  ): void;
   ubrn_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_sign(
     param: Uint8Array,
     signingKey: Uint8Array,
@@ -38,6 +123,20 @@ interface NativeModuleInterface {
     ctx: Uint8Array,
     uniffi_out_err: UniffiRustCallStatus,
   ): Uint8Array;
+  ubrn_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_sign_batch(
+    param: Uint8Array,
+    signingKey: Uint8Array,
+    msgs: Uint8Array,
+    ctx: Uint8Array,
+    uniffi_out_err: UniffiRustCallStatus,
+  ): Uint8Array;
+  ubrn_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_sign_checked(
+    param: Uint8Array,
+    signingKey: Uint8Array,
+    msg: Uint8Array,
+    ctx: Uint8Array,
+    uniffi_out_err: UniffiRustCallStatus,
+  ): Uint8Array;
   ubrn_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_sign_deterministic(
     param: Uint8Array,
     signingKey: Uint8Array,
@@ -49,10 +148,59 @@ interface NativeModuleInterface {
     param: Uint8Array,
     uniffi_out_err: UniffiRustCallStatus,
   ): bigint;
+  ubrn_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_signing_key_from_cose_key(
+    encoded: Uint8Array,
+    uniffi_out_err: UniffiRustCallStatus,
+  ): Uint8Array;
+  ubrn_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_signing_key_from_encrypted_pkcs8(
+    encoded: Uint8Array,
+    passphrase: Uint8Array,
+    uniffi_out_err: UniffiRustCallStatus,
+  ): Uint8Array;
+  ubrn_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_signing_key_from_key_file(
+    encoded: Uint8Array,
+    passphrase: Uint8Array,
+    uniffi_out_err: UniffiRustCallStatus,
+  ): Uint8Array;
+  ubrn_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_signing_key_from_pkcs8(
+    encoded: Uint8Array,
+    uniffi_out_err: UniffiRustCallStatus,
+  ): Uint8Array;
   ubrn_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_signing_key_len(
     param: Uint8Array,
     uniffi_out_err: UniffiRustCallStatus,
   ): bigint;
+  ubrn_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_signing_key_to_cose_key(
+    param: Uint8Array,
+    signingKey: Uint8Array,
+    uniffi_out_err: UniffiRustCallStatus,
+  ): Uint8Array;
+  ubrn_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_signing_key_to_encrypted_pkcs8(
+    param: Uint8Array,
+    signingKey: Uint8Array,
+    passphrase: Uint8Array,
+    pem: number,
+    uniffi_out_err: UniffiRustCallStatus,
+  ): Uint8Array;
+  ubrn_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_signing_key_to_key_file(
+    param: Uint8Array,
+    signingKey: Uint8Array,
+    passphrase: Uint8Array,
+    kdf: Uint8Array,
+    aead: Uint8Array,
+    uniffi_out_err: UniffiRustCallStatus,
+  ): Uint8Array;
+  ubrn_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_signing_key_to_pkcs8(
+    param: Uint8Array,
+    signingKey: Uint8Array,
+    pem: number,
+    uniffi_out_err: UniffiRustCallStatus,
+  ): Uint8Array;
+  ubrn_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_validate_signing_key(
+    param: Uint8Array,
+    signingKey: Uint8Array,
+    uniffi_out_err: UniffiRustCallStatus,
+  ): Uint8Array;
   ubrn_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_verify(
     param: Uint8Array,
     verifyingKey: Uint8Array,
@@ -61,24 +209,83 @@ interface NativeModuleInterface {
     signature: Uint8Array,
     uniffi_out_err: UniffiRustCallStatus,
   ): Uint8Array;
+  ubrn_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_verify_after_sign_enabled(
+    uniffi_out_err: UniffiRustCallStatus,
+  ): number;
+  ubrn_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_verifying_key_from_cose_key(
+    encoded: Uint8Array,
+    uniffi_out_err: UniffiRustCallStatus,
+  ): Uint8Array;
   ubrn_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_verifying_key_from_signing_key(
     param: Uint8Array,
     signingKey: Uint8Array,
     uniffi_out_err: UniffiRustCallStatus,
   ): Uint8Array;
+  ubrn_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_verifying_key_from_signing_key_validated(
+    param: Uint8Array,
+    signingKey: Uint8Array,
+    uniffi_out_err: UniffiRustCallStatus,
+  ): Uint8Array;
+  ubrn_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_verifying_key_from_spki(
+    encoded: Uint8Array,
+    uniffi_out_err: UniffiRustCallStatus,
+  ): Uint8Array;
   ubrn_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_verifying_key_len(
     param: Uint8Array,
     uniffi_out_err: UniffiRustCallStatus,
   ): bigint;
+  ubrn_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_verifying_key_to_cose_key(
+    param: Uint8Array,
+    verifyingKey: Uint8Array,
+    uniffi_out_err: UniffiRustCallStatus,
+  ): Uint8Array;
+  ubrn_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_verifying_key_to_spki(
+    param: Uint8Array,
+    verifyingKey: Uint8Array,
+    pem: number,
+    uniffi_out_err: UniffiRustCallStatus,
+  ): Uint8Array;
+  ubrn_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_cose_sign1_create(): number;
+  ubrn_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_cose_sign1_verify(): number;
+  ubrn_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_csr_create(): number;
+  ubrn_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_csr_verify(): number;
+  ubrn_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_envelope_decode(): number;
+  ubrn_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_envelope_sign(): number;
+  ubrn_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_envelope_verify(): number;
+  ubrn_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_fingerprint(): number;
+  ubrn_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_key_file_len(): number;
+  ubrn_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_keypair_from_seed(): number;
   ubrn_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_keypair_generate(): number;
   ubrn_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_parameter_name(): number;
+  ubrn_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_parameter_set_from_name(): number;
+  ubrn_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_parameter_set_from_oid(): number;
+  ubrn_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_parameter_sets(): number;
+  ubrn_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_seed_len(): number;
+  ubrn_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_set_verify_after_sign(): number;
   ubrn_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_sign(): number;
+  ubrn_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_sign_batch(): number;
+  ubrn_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_sign_checked(): number;
   ubrn_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_sign_deterministic(): number;
   ubrn_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_signature_len(): number;
+  ubrn_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_signing_key_from_cose_key(): number;
+  ubrn_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_signing_key_from_encrypted_pkcs8(): number;
+  ubrn_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_signing_key_from_key_file(): number;
+  ubrn_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_signing_key_from_pkcs8(): number;
   ubrn_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_signing_key_len(): number;
+  ubrn_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_signing_key_to_cose_key(): number;
+  ubrn_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_signing_key_to_encrypted_pkcs8(): number;
+  ubrn_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_signing_key_to_key_file(): number;
+  ubrn_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_signing_key_to_pkcs8(): number;
+  ubrn_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_validate_signing_key(): number;
   ubrn_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_verify(): number;
+  ubrn_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_verify_after_sign_enabled(): number;
+  ubrn_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_verifying_key_from_cose_key(): number;
   ubrn_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_verifying_key_from_signing_key(): number;
+  ubrn_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_verifying_key_from_signing_key_validated(): number;
+  ubrn_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_verifying_key_from_spki(): number;
   ubrn_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_verifying_key_len(): number;
+  ubrn_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_verifying_key_to_cose_key(): number;
+  ubrn_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_verifying_key_to_spki(): number;
   ubrn_ffi_slh_dsa_uniffi_uniffi_contract_version(): number;
 }
 
@@ -33,15 +33,21 @@ import nativeModule, {
 import {
   type UniffiByteArray,
   AbstractFfiConverterByteArray,
+  FfiConverterArray,
   FfiConverterArrayBuffer,
   FfiConverterBool,
   FfiConverterInt32,
+  FfiConverterOptional,
+  FfiConverterUInt32,
   FfiConverterUInt64,
+  FfiConverterUInt8,
   RustBuffer,
+  UniffiEnum,
   UniffiInternalError,
   UniffiRustCaller,
   uniffiCreateFfiConverterString,
   uniffiCreateRecord,
+  uniffiTypeNameSymbol,
 } from "uniffi-bindgen-react-native";
 
 // Get converters from the other files, if any.
@@ -55,6 +61,202 @@ const uniffiIsDebug =
   false;
 // Public interface members begin here.
 
+export function slhDsaCoseSign1Create(
+  param: ParameterSetId,
+  signingKey: ArrayBuffer,
+  payload: ArrayBuffer,
+  keyId: ArrayBuffer | undefined,
+  externalAad: ArrayBuffer,
+  detached: boolean,
+): EncodeResult {
+  return FfiConverterTypeEncodeResult.lift(
+    uniffiCaller.rustCall(
+      /*caller:*/ (callStatus) => {
+        return nativeModule().ubrn_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_cose_sign1_create(
+          FfiConverterTypeParameterSetId.lower(param),
+          FfiConverterArrayBuffer.lower(signingKey),
+          FfiConverterArrayBuffer.lower(payload),
+          FfiConverterOptionalArrayBuffer.lower(keyId),
+          FfiConverterArrayBuffer.lower(externalAad),
+          FfiConverterBool.lower(detached),
+          callStatus,
+        );
+      },
+      /*liftString:*/ FfiConverterString.lift,
+    ),
+  );
+}
+export function slhDsaCoseSign1Verify(
+  verifyingKey: ArrayBuffer,
+  encoded: ArrayBuffer,
+  detachedPayload: ArrayBuffer | undefined,
+  externalAad: ArrayBuffer,
+): CoseSign1Info {
+  return FfiConverterTypeCoseSign1Info.lift(
+    uniffiCaller.rustCall(
+      /*caller:*/ (callStatus) => {
+        return nativeModule().ubrn_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_cose_sign1_verify(
+          FfiConverterArrayBuffer.lower(verifyingKey),
+          FfiConverterArrayBuffer.lower(encoded),
+          FfiConverterOptionalArrayBuffer.lower(detachedPayload),
+          FfiConverterArrayBuffer.lower(externalAad),
+          callStatus,
+        );
+      },
+      /*liftString:*/ FfiConverterString.lift,
+    ),
+  );
+}
+export function slhDsaCsrCreate(
+  param: ParameterSetId,
+  signingKey: ArrayBuffer,
+  subject: CsrSubject,
+  subjectAltNames: Array<SubjectAltName>,
+  extensions: Array<CsrExtension>,
+  pem: boolean,
+): EncodeResult {
+  return FfiConverterTypeEncodeResult.lift(
+    uniffiCaller.rustCall(
+      /*caller:*/ (callStatus) => {
+        return nativeModule().ubrn_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_csr_create(
+          FfiConverterTypeParameterSetId.lower(param),
+          FfiConverterArrayBuffer.lower(signingKey),
+          FfiConverterTypeCsrSubject.lower(subject),
+          FfiConverterArrayTypeSubjectAltName.lower(subjectAltNames),
+          FfiConverterArrayTypeCsrExtension.lower(extensions),
+          FfiConverterBool.lower(pem),
+          callStatus,
+        );
+      },
+      /*liftString:*/ FfiConverterString.lift,
+    ),
+  );
+}
+export function slhDsaCsrVerify(encoded: ArrayBuffer): CsrInfo {
+  return FfiConverterTypeCsrInfo.lift(
+    uniffiCaller.rustCall(
+      /*caller:*/ (callStatus) => {
+        return nativeModule().ubrn_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_csr_verify(
+          FfiConverterArrayBuffer.lower(encoded),
+          callStatus,
+        );
+      },
+      /*liftString:*/ FfiConverterString.lift,
+    ),
+  );
+}
+export function slhDsaEnvelopeDecode(encoded: ArrayBuffer): EnvelopeInfo {
+  return FfiConverterTypeEnvelopeInfo.lift(
+    uniffiCaller.rustCall(
+      /*caller:*/ (callStatus) => {
+        return nativeModule().ubrn_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_envelope_decode(
+          FfiConverterArrayBuffer.lower(encoded),
+          callStatus,
+        );
+      },
+      /*liftString:*/ FfiConverterString.lift,
+    ),
+  );
+}
+export function slhDsaEnvelopeSign(
+  param: ParameterSetId,
+  signingKey: ArrayBuffer,
+  payload: ArrayBuffer,
+  ctx: ArrayBuffer,
+  keyId: ArrayBuffer | undefined,
+  mode: SignatureMode,
+  detached: boolean,
+): EncodeResult {
+  return FfiConverterTypeEncodeResult.lift(
+    uniffiCaller.rustCall(
+      /*caller:*/ (callStatus) => {
+        return nativeModule().ubrn_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_envelope_sign(
+          FfiConverterTypeParameterSetId.lower(param),
+          FfiConverterArrayBuffer.lower(signingKey),
+          FfiConverterArrayBuffer.lower(payload),
+          FfiConverterArrayBuffer.lower(ctx),
+          FfiConverterOptionalArrayBuffer.lower(keyId),
+          FfiConverterTypeSignatureMode.lower(mode),
+          FfiConverterBool.lower(detached),
+          callStatus,
+        );
+      },
+      /*liftString:*/ FfiConverterString.lift,
+    ),
+  );
+}
+export function slhDsaEnvelopeVerify(
+  verifyingKey: ArrayBuffer,
+  encoded: ArrayBuffer,
+  detachedPayload: ArrayBuffer | undefined,
+): EnvelopeInfo {
+  return FfiConverterTypeEnvelopeInfo.lift(
+    uniffiCaller.rustCall(
+      /*caller:*/ (callStatus) => {
+        return nativeModule().ubrn_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_envelope_verify(
+          FfiConverterArrayBuffer.lower(verifyingKey),
+          FfiConverterArrayBuffer.lower(encoded),
+          FfiConverterOptionalArrayBuffer.lower(detachedPayload),
+          callStatus,
+        );
+      },
+      /*liftString:*/ FfiConverterString.lift,
+    ),
+  );
+}
+export function slhDsaFingerprint(
+  param: ParameterSetId,
+  verifyingKey: ArrayBuffer,
+  source: FingerprintSource,
+): FingerprintResult {
+  return FfiConverterTypeFingerprintResult.lift(
+    uniffiCaller.rustCall(
+      /*caller:*/ (callStatus) => {
+        return nativeModule().ubrn_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_fingerprint(
+          FfiConverterTypeParameterSetId.lower(param),
+          FfiConverterArrayBuffer.lower(verifyingKey),
+          FfiConverterTypeFingerprintSource.lower(source),
+          callStatus,
+        );
+      },
+      /*liftString:*/ FfiConverterString.lift,
+    ),
+  );
+}
+export function slhDsaKeyFileLen(param: ParameterSetId): /*u64*/ bigint {
+  return FfiConverterUInt64.lift(
+    uniffiCaller.rustCall(
+      /*caller:*/ (callStatus) => {
+        return nativeModule().ubrn_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_key_file_len(
+          FfiConverterTypeParameterSetId.lower(param),
+          callStatus,
+        );
+      },
+      /*liftString:*/ FfiConverterString.lift,
+    ),
+  );
+}
+export function slhDsaKeypairFromSeed(
+  param: ParameterSetId,
+  skSeed: ArrayBuffer,
+  skPrf: ArrayBuffer,
+  pkSeed: ArrayBuffer,
+): Keypair {
+  return FfiConverterTypeKeypair.lift(
+    uniffiCaller.rustCall(
+      /*caller:*/ (callStatus) => {
+        return nativeModule().ubrn_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_keypair_from_seed(
+          FfiConverterTypeParameterSetId.lower(param),
+          FfiConverterArrayBuffer.lower(skSeed),
+          FfiConverterArrayBuffer.lower(skPrf),
+          FfiConverterArrayBuffer.lower(pkSeed),
+          callStatus,
+        );
+      },
+      /*liftString:*/ FfiConverterString.lift,
+    ),
+  );
+}
 export function slhDsaKeypairGenerate(param: ParameterSetId): Keypair {
   return FfiConverterTypeKeypair.lift(
     uniffiCaller.rustCall(
@@ -81,6 +283,72 @@ export function slhDsaParameterName(param: ParameterSetId): string {
     ),
   );
 }
+export function slhDsaParameterSetFromName(
+  name: string,
+): ParameterSetId | undefined {
+  return FfiConverterOptionalTypeParameterSetId.lift(
+    uniffiCaller.rustCall(
+      /*caller:*/ (callStatus) => {
+        return nativeModule().ubrn_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_parameter_set_from_name(
+          FfiConverterString.lower(name),
+          callStatus,
+        );
+      },
+      /*liftString:*/ FfiConverterString.lift,
+    ),
+  );
+}
+export function slhDsaParameterSetFromOid(
+  oid: string,
+): ParameterSetId | undefined {
+  return FfiConverterOptionalTypeParameterSetId.lift(
+    uniffiCaller.rustCall(
+      /*caller:*/ (callStatus) => {
+        return nativeModule().ubrn_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_parameter_set_from_oid(
+          FfiConverterString.lower(oid),
+          callStatus,
+        );
+      },
+      /*liftString:*/ FfiConverterString.lift,
+    ),
+  );
+}
+export function slhDsaParameterSets(): Array<ParameterSetInfo> {
+  return FfiConverterArrayTypeParameterSetInfo.lift(
+    uniffiCaller.rustCall(
+      /*caller:*/ (callStatus) => {
+        return nativeModule().ubrn_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_parameter_sets(
+          callStatus,
+        );
+      },
+      /*liftString:*/ FfiConverterString.lift,
+    ),
+  );
+}
+export function slhDsaSeedLen(param: ParameterSetId): /*u64*/ bigint {
+  return FfiConverterUInt64.lift(
+    uniffiCaller.rustCall(
+      /*caller:*/ (callStatus) => {
+        return nativeModule().ubrn_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_seed_len(
+          FfiConverterTypeParameterSetId.lower(param),
+          callStatus,
+        );
+      },
+      /*liftString:*/ FfiConverterString.lift,
+    ),
+  );
+}
+export function slhDsaSetVerifyAfterSign(enabled: boolean): void {
+  uniffiCaller.rustCall(
+    /*caller:*/ (callStatus) => {
+      nativeModule().ubrn_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_set_verify_after_sign(
+        FfiConverterBool.lower(enabled),
+        callStatus,
+      );
+    },
+    /*liftString:*/ FfiConverterString.lift,
+  );
+}
 export function slhDsaSign(
   param: ParameterSetId,
   signingKey: ArrayBuffer,
@@ -102,6 +370,48 @@ export function slhDsaSign(
     ),
   );
 }
+export function slhDsaSignBatch(
+  param: ParameterSetId,
+  signingKey: ArrayBuffer,
+  msgs: Array<ArrayBuffer>,
+  ctx: ArrayBuffer,
+): SignBatchResult {
+  return FfiConverterTypeSignBatchResult.lift(
+    uniffiCaller.rustCall(
+      /*caller:*/ (callStatus) => {
+        return nativeModule().ubrn_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_sign_batch(
+          FfiConverterTypeParameterSetId.lower(param),
+          FfiConverterArrayBuffer.lower(signingKey),
+          FfiConverterArrayArrayBuffer.lower(msgs),
+          FfiConverterArrayBuffer.lower(ctx),
+          callStatus,
+        );
+      },
+      /*liftString:*/ FfiConverterString.lift,
+    ),
+  );
+}
+export function slhDsaSignChecked(
+  param: ParameterSetId,
+  signingKey: ArrayBuffer,
+  msg: ArrayBuffer,
+  ctx: ArrayBuffer,
+): SignResult {
+  return FfiConverterTypeSignResult.lift(
+    uniffiCaller.rustCall(
+      /*caller:*/ (callStatus) => {
+        return nativeModule().ubrn_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_sign_checked(
+          FfiConverterTypeParameterSetId.lower(param),
+          FfiConverterArrayBuffer.lower(signingKey),
+          FfiConverterArrayBuffer.lower(msg),
+          FfiConverterArrayBuffer.lower(ctx),
+          callStatus,
+        );
+      },
+      /*liftString:*/ FfiConverterString.lift,
+    ),
+  );
+}
 export function slhDsaSignDeterministic(
   param: ParameterSetId,
   signingKey: ArrayBuffer,
@@ -136,6 +446,70 @@ export function slhDsaSignatureLen(param: ParameterSetId): /*u64*/ bigint {
     ),
   );
 }
+export function slhDsaSigningKeyFromCoseKey(
+  encoded: ArrayBuffer,
+): DecodeKeyResult {
+  return FfiConverterTypeDecodeKeyResult.lift(
+    uniffiCaller.rustCall(
+      /*caller:*/ (callStatus) => {
+        return nativeModule().ubrn_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_signing_key_from_cose_key(
+          FfiConverterArrayBuffer.lower(encoded),
+          callStatus,
+        );
+      },
+      /*liftString:*/ FfiConverterString.lift,
+    ),
+  );
+}
+export function slhDsaSigningKeyFromEncryptedPkcs8(
+  encoded: ArrayBuffer,
+  passphrase: ArrayBuffer,
+): DecodeKeyResult {
+  return FfiConverterTypeDecodeKeyResult.lift(
+    uniffiCaller.rustCall(
+      /*caller:*/ (callStatus) => {
+        return nativeModule().ubrn_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_signing_key_from_encrypted_pkcs8(
+          FfiConverterArrayBuffer.lower(encoded),
+          FfiConverterArrayBuffer.lower(passphrase),
+          callStatus,
+        );
+      },
+      /*liftString:*/ FfiConverterString.lift,
+    ),
+  );
+}
+export function slhDsaSigningKeyFromKeyFile(
+  encoded: ArrayBuffer,
+  passphrase: ArrayBuffer,
+): DecodeKeyResult {
+  return FfiConverterTypeDecodeKeyResult.lift(
+    uniffiCaller.rustCall(
+      /*caller:*/ (callStatus) => {
+        return nativeModule().ubrn_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_signing_key_from_key_file(
+          FfiConverterArrayBuffer.lower(encoded),
+          FfiConverterArrayBuffer.lower(passphrase),
+          callStatus,
+        );
+      },
+      /*liftString:*/ FfiConverterString.lift,
+    ),
+  );
+}
+export function slhDsaSigningKeyFromPkcs8(
+  encoded: ArrayBuffer,
+): DecodeKeyResult {
+  return FfiConverterTypeDecodeKeyResult.lift(
+    uniffiCaller.rustCall(
+      /*caller:*/ (callStatus) => {
+        return nativeModule().ubrn_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_signing_key_from_pkcs8(
+          FfiConverterArrayBuffer.lower(encoded),
+          callStatus,
+        );
+      },
+      /*liftString:*/ FfiConverterString.lift,
+    ),
+  );
+}
 export function slhDsaSigningKeyLen(param: ParameterSetId): /*u64*/ bigint {
   return FfiConverterUInt64.lift(
     uniffiCaller.rustCall(
@@ -149,22 +523,16 @@ export function slhDsaSigningKeyLen(param: ParameterSetId): /*u64*/ bigint {
     ),
   );
 }
-export function slhDsaVerify(
+export function slhDsaSigningKeyToCoseKey(
   param: ParameterSetId,
-  verifyingKey: ArrayBuffer,
-  msg: ArrayBuffer,
-  ctx: ArrayBuffer,
-  signature: ArrayBuffer,
-): VerifyResult {
-  return FfiConverterTypeVerifyResult.lift(
+  signingKey: ArrayBuffer,
+): EncodeResult {
+  return FfiConverterTypeEncodeResult.lift(
     uniffiCaller.rustCall(
       /*caller:*/ (callStatus) => {
-        return nativeModule().ubrn_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_verify(
+        return nativeModule().ubrn_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_signing_key_to_cose_key(
           FfiConverterTypeParameterSetId.lower(param),
-          FfiConverterArrayBuffer.lower(verifyingKey),
-          FfiConverterArrayBuffer.lower(msg),
-          FfiConverterArrayBuffer.lower(ctx),
-          FfiConverterArrayBuffer.lower(signature),
+          FfiConverterArrayBuffer.lower(signingKey),
           callStatus,
         );
       },
@@ -172,16 +540,20 @@ export function slhDsaVerify(
     ),
   );
 }
-export function slhDsaVerifyingKeyFromSigningKey(
+export function slhDsaSigningKeyToEncryptedPkcs8(
   param: ParameterSetId,
   signingKey: ArrayBuffer,
-): KeyResult {
-  return FfiConverterTypeKeyResult.lift(
+  passphrase: ArrayBuffer,
+  pem: boolean,
+): EncodeResult {
+  return FfiConverterTypeEncodeResult.lift(
     uniffiCaller.rustCall(
       /*caller:*/ (callStatus) => {
-        return nativeModule().ubrn_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_verifying_key_from_signing_key(
+        return nativeModule().ubrn_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_signing_key_to_encrypted_pkcs8(
           FfiConverterTypeParameterSetId.lower(param),
           FfiConverterArrayBuffer.lower(signingKey),
+          FfiConverterArrayBuffer.lower(passphrase),
+          FfiConverterBool.lower(pem),
           callStatus,
         );
       },
@@ -189,12 +561,22 @@ export function slhDsaVerifyingKeyFromSigningKey(
     ),
   );
 }
-export function slhDsaVerifyingKeyLen(param: ParameterSetId): /*u64*/ bigint {
-  return FfiConverterUInt64.lift(
+export function slhDsaSigningKeyToKeyFile(
+  param: ParameterSetId,
+  signingKey: ArrayBuffer,
+  passphrase: ArrayBuffer,
+  kdf: Kdf,
+  aead: Aead,
+): EncodeResult {
+  return FfiConverterTypeEncodeResult.lift(
     uniffiCaller.rustCall(
       /*caller:*/ (callStatus) => {
-        return nativeModule().ubrn_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_verifying_key_len(
+        return nativeModule().ubrn_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_signing_key_to_key_file(
           FfiConverterTypeParameterSetId.lower(param),
+          FfiConverterArrayBuffer.lower(signingKey),
+          FfiConverterArrayBuffer.lower(passphrase),
+          FfiConverterTypeKdf.lower(kdf),
+          FfiConverterTypeAead.lower(aead),
           callStatus,
         );
       },
@@ -202,6 +584,734 @@ export function slhDsaVerifyingKeyLen(param: ParameterSetId): /*u64*/ bigint {
     ),
   );
 }
+export function slhDsaSigningKeyToPkcs8(
+  param: ParameterSetId,
+  signingKey: ArrayBuffer,
+  pem: boolean,
+): EncodeResult {
+  return FfiConverterTypeEncodeResult.lift(
+    uniffiCaller.rustCall(
+      /*caller:*/ (callStatus) => {
+        return nativeModule().ubrn_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_signing_key_to_pkcs8(
+          FfiConverterTypeParameterSetId.lower(param),
+          FfiConverterArrayBuffer.lower(signingKey),
+          FfiConverterBool.lower(pem),
+          callStatus,
+        );
+      },
+      /*liftString:*/ FfiConverterString.lift,
+    ),
+  );
+}
+export function slhDsaValidateSigningKey(
+  param: ParameterSetId,
+  signingKey: ArrayBuffer,
+): SlhDsaStatus {
+  return FfiConverterTypeSlhDsaStatus.lift(
+    uniffiCaller.rustCall(
+      /*caller:*/ (callStatus) => {
+        return nativeModule().ubrn_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_validate_signing_key(
+          FfiConverterTypeParameterSetId.lower(param),
+          FfiConverterArrayBuffer.lower(signingKey),
+          callStatus,
+        );
+      },
+      /*liftString:*/ FfiConverterString.lift,
+    ),
+  );
+}
+export function slhDsaVerify(
+  param: ParameterSetId,
+  verifyingKey: ArrayBuffer,
+  msg: ArrayBuffer,
+  ctx: ArrayBuffer,
+  signature: ArrayBuffer,
+): VerifyResult {
+  return FfiConverterTypeVerifyResult.lift(
+    uniffiCaller.rustCall(
+      /*caller:*/ (callStatus) => {
+        return nativeModule().ubrn_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_verify(
+          FfiConverterTypeParameterSetId.lower(param),
+          FfiConverterArrayBuffer.lower(verifyingKey),
+          FfiConverterArrayBuffer.lower(msg),
+          FfiConverterArrayBuffer.lower(ctx),
+          FfiConverterArrayBuffer.lower(signature),
+          callStatus,
+        );
+      },
+      /*liftString:*/ FfiConverterString.lift,
+    ),
+  );
+}
+export function slhDsaVerifyAfterSignEnabled(): boolean {
+  return FfiConverterBool.lift(
+    uniffiCaller.rustCall(
+      /*caller:*/ (callStatus) => {
+        return nativeModule().ubrn_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_verify_after_sign_enabled(
+          callStatus,
+        );
+      },
+      /*liftString:*/ FfiConverterString.lift,
+    ),
+  );
+}
+export function slhDsaVerifyingKeyFromCoseKey(
+  encoded: ArrayBuffer,
+): DecodeKeyResult {
+  return FfiConverterTypeDecodeKeyResult.lift(
+    uniffiCaller.rustCall(
+      /*caller:*/ (callStatus) => {
+        return nativeModule().ubrn_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_verifying_key_from_cose_key(
+          FfiConverterArrayBuffer.lower(encoded),
+          callStatus,
+        );
+      },
+      /*liftString:*/ FfiConverterString.lift,
+    ),
+  );
+}
+export function slhDsaVerifyingKeyFromSigningKey(
+  param: ParameterSetId,
+  signingKey: ArrayBuffer,
+): KeyResult {
+  return FfiConverterTypeKeyResult.lift(
+    uniffiCaller.rustCall(
+      /*caller:*/ (callStatus) => {
+        return nativeModule().ubrn_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_verifying_key_from_signing_key(
+          FfiConverterTypeParameterSetId.lower(param),
+          FfiConverterArrayBuffer.lower(signingKey),
+          callStatus,
+        );
+      },
+      /*liftString:*/ FfiConverterString.lift,
+    ),
+  );
+}
+export function slhDsaVerifyingKeyFromSigningKeyValidated(
+  param: ParameterSetId,
+  signingKey: ArrayBuffer,
+): KeyResult {
+  return FfiConverterTypeKeyResult.lift(
+    uniffiCaller.rustCall(
+      /*caller:*/ (callStatus) => {
+        return nativeModule().ubrn_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_verifying_key_from_signing_key_validated(
+          FfiConverterTypeParameterSetId.lower(param),
+          FfiConverterArrayBuffer.lower(signingKey),
+          callStatus,
+        );
+      },
+      /*liftString:*/ FfiConverterString.lift,
+    ),
+  );
+}
+export function slhDsaVerifyingKeyFromSpki(
+  encoded: ArrayBuffer,
+): DecodeKeyResult {
+  return FfiConverterTypeDecodeKeyResult.lift(
+    uniffiCaller.rustCall(
+      /*caller:*/ (callStatus) => {
+        return nativeModule().ubrn_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_verifying_key_from_spki(
+          FfiConverterArrayBuffer.lower(encoded),
+          callStatus,
+        );
+      },
+      /*liftString:*/ FfiConverterString.lift,
+    ),
+  );
+}
+export function slhDsaVerifyingKeyLen(param: ParameterSetId): /*u64*/ bigint {
+  return FfiConverterUInt64.lift(
+    uniffiCaller.rustCall(
+      /*caller:*/ (callStatus) => {
+        return nativeModule().ubrn_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_verifying_key_len(
+          FfiConverterTypeParameterSetId.lower(param),
+          callStatus,
+        );
+      },
+      /*liftString:*/ FfiConverterString.lift,
+    ),
+  );
+}
+export function slhDsaVerifyingKeyToCoseKey(
+  param: ParameterSetId,
+  verifyingKey: ArrayBuffer,
+): EncodeResult {
+  return FfiConverterTypeEncodeResult.lift(
+    uniffiCaller.rustCall(
+      /*caller:*/ (callStatus) => {
+        return nativeModule().ubrn_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_verifying_key_to_cose_key(
+          FfiConverterTypeParameterSetId.lower(param),
+          FfiConverterArrayBuffer.lower(verifyingKey),
+          callStatus,
+        );
+      },
+      /*liftString:*/ FfiConverterString.lift,
+    ),
+  );
+}
+export function slhDsaVerifyingKeyToSpki(
+  param: ParameterSetId,
+  verifyingKey: ArrayBuffer,
+  pem: boolean,
+): EncodeResult {
+  return FfiConverterTypeEncodeResult.lift(
+    uniffiCaller.rustCall(
+      /*caller:*/ (callStatus) => {
+        return nativeModule().ubrn_uniffi_slh_dsa_uniffi_fn_func_slh_dsa_verifying_key_to_spki(
+          FfiConverterTypeParameterSetId.lower(param),
+          FfiConverterArrayBuffer.lower(verifyingKey),
+          FfiConverterBool.lower(pem),
+          callStatus,
+        );
+      },
+      /*liftString:*/ FfiConverterString.lift,
+    ),
+  );
+}
+
+export type CoseSign1Info = {
+  status: SlhDsaStatus;
+  valid: boolean;
+  param: ParameterSetId | undefined;
+  keyId: ArrayBuffer | undefined;
+  payload: ArrayBuffer | undefined;
+};
+
+/**
+ * Generated factory for {@link CoseSign1Info} record objects.
+ */
+export const CoseSign1Info = (() => {
+  const defaults = () => ({});
+  const create = (() => {
+    return uniffiCreateRecord<CoseSign1Info, ReturnType<typeof defaults>>(
+      defaults,
+    );
+  })();
+  return Object.freeze({
+    /**
+     * Create a frozen instance of {@link CoseSign1Info}, with defaults specified
+     * in Rust, in the {@link slh_dsa_uniffi} crate.
+     */
+    create,
+
+    /**
+     * Create a frozen instance of {@link CoseSign1Info}, with defaults specified
+     * in Rust, in the {@link slh_dsa_uniffi} crate.
+     */
+    new: create,
+
+    /**
+     * Defaults specified in the {@link slh_dsa_uniffi} crate.
+     */
+    defaults: () => Object.freeze(defaults()) as Partial<CoseSign1Info>,
+  });
+})();
+
+const FfiConverterTypeCoseSign1Info = (() => {
+  type TypeName = CoseSign1Info;
+  class FFIConverter extends AbstractFfiConverterByteArray<TypeName> {
+    read(from: RustBuffer): TypeName {
+      return {
+        status: FfiConverterTypeSlhDsaStatus.read(from),
+        valid: FfiConverterBool.read(from),
+        param: FfiConverterOptionalTypeParameterSetId.read(from),
+        keyId: FfiConverterOptionalArrayBuffer.read(from),
+        payload: FfiConverterOptionalArrayBuffer.read(from),
+      };
+    }
+    write(value: TypeName, into: RustBuffer): void {
+      FfiConverterTypeSlhDsaStatus.write(value.status, into);
+      FfiConverterBool.write(value.valid, into);
+      FfiConverterOptionalTypeParameterSetId.write(value.param, into);
+      FfiConverterOptionalArrayBuffer.write(value.keyId, into);
+      FfiConverterOptionalArrayBuffer.write(value.payload, into);
+    }
+    allocationSize(value: TypeName): number {
+      return (
+        FfiConverterTypeSlhDsaStatus.allocationSize(value.status) +
+        FfiConverterBool.allocationSize(value.valid) +
+        FfiConverterOptionalTypeParameterSetId.allocationSize(value.param) +
+        FfiConverterOptionalArrayBuffer.allocationSize(value.keyId) +
+        FfiConverterOptionalArrayBuffer.allocationSize(value.payload)
+      );
+    }
+  }
+  return new FFIConverter();
+})();
+
+export type CsrExtension = {
+  oid: string;
+  critical: boolean;
+  value: ArrayBuffer;
+};
+
+/**
+ * Generated factory for {@link CsrExtension} record objects.
+ */
+export const CsrExtension = (() => {
+  const defaults = () => ({});
+  const create = (() => {
+    return uniffiCreateRecord<CsrExtension, ReturnType<typeof defaults>>(
+      defaults,
+    );
+  })();
+  return Object.freeze({
+    /**
+     * Create a frozen instance of {@link CsrExtension}, with defaults specified
+     * in Rust, in the {@link slh_dsa_uniffi} crate.
+     */
+    create,
+
+    /**
+     * Create a frozen instance of {@link CsrExtension}, with defaults specified
+     * in Rust, in the {@link slh_dsa_uniffi} crate.
+     */
+    new: create,
+
+    /**
+     * Defaults specified in the {@link slh_dsa_uniffi} crate.
+     */
+    defaults: () => Object.freeze(defaults()) as Partial<CsrExtension>,
+  });
+})();
+
+const FfiConverterTypeCsrExtension = (() => {
+  type TypeName = CsrExtension;
+  class FFIConverter extends AbstractFfiConverterByteArray<TypeName> {
+    read(from: RustBuffer): TypeName {
+      return {
+        oid: FfiConverterString.read(from),
+        critical: FfiConverterBool.read(from),
+        value: FfiConverterArrayBuffer.read(from),
+      };
+    }
+    write(value: TypeName, into: RustBuffer): void {
+      FfiConverterString.write(value.oid, into);
+      FfiConverterBool.write(value.critical, into);
+      FfiConverterArrayBuffer.write(value.value, into);
+    }
+    allocationSize(value: TypeName): number {
+      return (
+        FfiConverterString.allocationSize(value.oid) +
+        FfiConverterBool.allocationSize(value.critical) +
+        FfiConverterArrayBuffer.allocationSize(value.value)
+      );
+    }
+  }
+  return new FFIConverter();
+})();
+
+export type CsrInfo = {
+  status: SlhDsaStatus;
+  valid: boolean;
+  param: ParameterSetId | undefined;
+  verifyingKey: ArrayBuffer;
+  subject: CsrSubject;
+  subjectAltNames: Array<SubjectAltName>;
+  extensions: Array<CsrExtension>;
+};
+
+/**
+ * Generated factory for {@link CsrInfo} record objects.
+ */
+export const CsrInfo = (() => {
+  const defaults = () => ({});
+  const create = (() => {
+    return uniffiCreateRecord<CsrInfo, ReturnType<typeof defaults>>(defaults);
+  })();
+  return Object.freeze({
+    /**
+     * Create a frozen instance of {@link CsrInfo}, with defaults specified
+     * in Rust, in the {@link slh_dsa_uniffi} crate.
+     */
+    create,
+
+    /**
+     * Create a frozen instance of {@link CsrInfo}, with defaults specified
+     * in Rust, in the {@link slh_dsa_uniffi} crate.
+     */
+    new: create,
+
+    /**
+     * Defaults specified in the {@link slh_dsa_uniffi} crate.
+     */
+    defaults: () => Object.freeze(defaults()) as Partial<CsrInfo>,
+  });
+})();
+
+const FfiConverterTypeCsrInfo = (() => {
+  type TypeName = CsrInfo;
+  class FFIConverter extends AbstractFfiConverterByteArray<TypeName> {
+    read(from: RustBuffer): TypeName {
+      return {
+        status: FfiConverterTypeSlhDsaStatus.read(from),
+        valid: FfiConverterBool.read(from),
+        param: FfiConverterOptionalTypeParameterSetId.read(from),
+        verifyingKey: FfiConverterArrayBuffer.read(from),
+        subject: FfiConverterTypeCsrSubject.read(from),
+        subjectAltNames: FfiConverterArrayTypeSubjectAltName.read(from),
+        extensions: FfiConverterArrayTypeCsrExtension.read(from),
+      };
+    }
+    write(value: TypeName, into: RustBuffer): void {
+      FfiConverterTypeSlhDsaStatus.write(value.status, into);
+      FfiConverterBool.write(value.valid, into);
+      FfiConverterOptionalTypeParameterSetId.write(value.param, into);
+      FfiConverterArrayBuffer.write(value.verifyingKey, into);
+      FfiConverterTypeCsrSubject.write(value.subject, into);
+      FfiConverterArrayTypeSubjectAltName.write(value.subjectAltNames, into);
+      FfiConverterArrayTypeCsrExtension.write(value.extensions, into);
+    }
+    allocationSize(value: TypeName): number {
+      return (
+        FfiConverterTypeSlhDsaStatus.allocationSize(value.status) +
+        FfiConverterBool.allocationSize(value.valid) +
+        FfiConverterOptionalTypeParameterSetId.allocationSize(value.param) +
+        FfiConverterArrayBuffer.allocationSize(value.verifyingKey) +
+        FfiConverterTypeCsrSubject.allocationSize(value.subject) +
+        FfiConverterArrayTypeSubjectAltName.allocationSize(
+          value.subjectAltNames,
+        ) +
+        FfiConverterArrayTypeCsrExtension.allocationSize(value.extensions)
+      );
+    }
+  }
+  return new FFIConverter();
+})();
+
+export type CsrSubject = {
+  commonName: string | undefined;
+  organization: string | undefined;
+  organizationalUnit: string | undefined;
+  country: string | undefined;
+};
+
+/**
+ * Generated factory for {@link CsrSubject} record objects.
+ */
+export const CsrSubject = (() => {
+  const defaults = () => ({});
+  const create = (() => {
+    return uniffiCreateRecord<CsrSubject, ReturnType<typeof defaults>>(
+      defaults,
+    );
+  })();
+  return Object.freeze({
+    /**
+     * Create a frozen instance of {@link CsrSubject}, with defaults specified
+     * in Rust, in the {@link slh_dsa_uniffi} crate.
+     */
+    create,
+
+    /**
+     * Create a frozen instance of {@link CsrSubject}, with defaults specified
+     * in Rust, in the {@link slh_dsa_uniffi} crate.
+     */
+    new: create,
+
+    /**
+     * Defaults specified in the {@link slh_dsa_uniffi} crate.
+     */
+    defaults: () => Object.freeze(defaults()) as Partial<CsrSubject>,
+  });
+})();
+
+const FfiConverterTypeCsrSubject = (() => {
+  type TypeName = CsrSubject;
+  class FFIConverter extends AbstractFfiConverterByteArray<TypeName> {
+    read(from: RustBuffer): TypeName {
+      return {
+        commonName: FfiConverterOptionalString.read(from),
+        organization: FfiConverterOptionalString.read(from),
+        organizationalUnit: FfiConverterOptionalString.read(from),
+        country: FfiConverterOptionalString.read(from),
+      };
+    }
+    write(value: TypeName, into: RustBuffer): void {
+      FfiConverterOptionalString.write(value.commonName, into);
+      FfiConverterOptionalString.write(value.organization, into);
+      FfiConverterOptionalString.write(value.organizationalUnit, into);
+      FfiConverterOptionalString.write(value.country, into);
+    }
+    allocationSize(value: TypeName): number {
+      return (
+        FfiConverterOptionalString.allocationSize(value.commonName) +
+        FfiConverterOptionalString.allocationSize(value.organization) +
+        FfiConverterOptionalString.allocationSize(value.organizationalUnit) +
+        FfiConverterOptionalString.allocationSize(value.country)
+      );
+    }
+  }
+  return new FFIConverter();
+})();
+
+export type DecodeKeyResult = {
+  status: SlhDsaStatus;
+  param: ParameterSetId | undefined;
+  key: ArrayBuffer;
+};
+
+/**
+ * Generated factory for {@link DecodeKeyResult} record objects.
+ */
+export const DecodeKeyResult = (() => {
+  const defaults = () => ({});
+  const create = (() => {
+    return uniffiCreateRecord<DecodeKeyResult, ReturnType<typeof defaults>>(
+      defaults,
+    );
+  })();
+  return Object.freeze({
+    /**
+     * Create a frozen instance of {@link DecodeKeyResult}, with defaults specified
+     * in Rust, in the {@link slh_dsa_uniffi} crate.
+     */
+    create,
+
+    /**
+     * Create a frozen instance of {@link DecodeKeyResult}, with defaults specified
+     * in Rust, in the {@link slh_dsa_uniffi} crate.
+     */
+    new: create,
+
+    /**
+     * Defaults specified in the {@link slh_dsa_uniffi} crate.
+     */
+    defaults: () => Object.freeze(defaults()) as Partial<DecodeKeyResult>,
+  });
+})();
+
+const FfiConverterTypeDecodeKeyResult = (() => {
+  type TypeName = DecodeKeyResult;
+  class FFIConverter extends AbstractFfiConverterByteArray<TypeName> {
+    read(from: RustBuffer): TypeName {
+      return {
+        status: FfiConverterTypeSlhDsaStatus.read(from),
+        param: FfiConverterOptionalTypeParameterSetId.read(from),
+        key: FfiConverterArrayBuffer.read(from),
+      };
+    }
+    write(value: TypeName, into: RustBuffer): void {
+      FfiConverterTypeSlhDsaStatus.write(value.status, into);
+      FfiConverterOptionalTypeParameterSetId.write(value.param, into);
+      FfiConverterArrayBuffer.write(value.key, into);
+    }
+    allocationSize(value: TypeName): number {
+      return (
+        FfiConverterTypeSlhDsaStatus.allocationSize(value.status) +
+        FfiConverterOptionalTypeParameterSetId.allocationSize(value.param) +
+        FfiConverterArrayBuffer.allocationSize(value.key)
+      );
+    }
+  }
+  return new FFIConverter();
+})();
+
+export type EncodeResult = {
+  status: SlhDsaStatus;
+  encoded: ArrayBuffer;
+};
+
+/**
+ * Generated factory for {@link EncodeResult} record objects.
+ */
+export const EncodeResult = (() => {
+  const defaults = () => ({});
+  const create = (() => {
+    return uniffiCreateRecord<EncodeResult, ReturnType<typeof defaults>>(
+      defaults,
+    );
+  })();
+  return Object.freeze({
+    /**
+     * Create a frozen instance of {@link EncodeResult}, with defaults specified
+     * in Rust, in the {@link slh_dsa_uniffi} crate.
+     */
+    create,
+
+    /**
+     * Create a frozen instance of {@link EncodeResult}, with defaults specified
+     * in Rust, in the {@link slh_dsa_uniffi} crate.
+     */
+    new: create,
+
+    /**
+     * Defaults specified in the {@link slh_dsa_uniffi} crate.
+     */
+    defaults: () => Object.freeze(defaults()) as Partial<EncodeResult>,
+  });
+})();
+
+const FfiConverterTypeEncodeResult = (() => {
+  type TypeName = EncodeResult;
+  class FFIConverter extends AbstractFfiConverterByteArray<TypeName> {
+    read(from: RustBuffer): TypeName {
+      return {
+        status: FfiConverterTypeSlhDsaStatus.read(from),
+        encoded: FfiConverterArrayBuffer.read(from),
+      };
+    }
+    write(value: TypeName, into: RustBuffer): void {
+      FfiConverterTypeSlhDsaStatus.write(value.status, into);
+      FfiConverterArrayBuffer.write(value.encoded, into);
+    }
+    allocationSize(value: TypeName): number {
+      return (
+        FfiConverterTypeSlhDsaStatus.allocationSize(value.status) +
+        FfiConverterArrayBuffer.allocationSize(value.encoded)
+      );
+    }
+  }
+  return new FFIConverter();
+})();
+
+export type EnvelopeInfo = {
+  status: SlhDsaStatus;
+  valid: boolean;
+  param: ParameterSetId | undefined;
+  mode: SignatureMode | undefined;
+  keyId: ArrayBuffer | undefined;
+  context: ArrayBuffer;
+  payload: ArrayBuffer | undefined;
+};
+
+/**
+ * Generated factory for {@link EnvelopeInfo} record objects.
+ */
+export const EnvelopeInfo = (() => {
+  const defaults = () => ({});
+  const create = (() => {
+    return uniffiCreateRecord<EnvelopeInfo, ReturnType<typeof defaults>>(
+      defaults,
+    );
+  })();
+  return Object.freeze({
+    /**
+     * Create a frozen instance of {@link EnvelopeInfo}, with defaults specified
+     * in Rust, in the {@link slh_dsa_uniffi} crate.
+     */
+    create,
+
+    /**
+     * Create a frozen instance of {@link EnvelopeInfo}, with defaults specified
+     * in Rust, in the {@link slh_dsa_uniffi} crate.
+     */
+    new: create,
+
+    /**
+     * Defaults specified in the {@link slh_dsa_uniffi} crate.
+     */
+    defaults: () => Object.freeze(defaults()) as Partial<EnvelopeInfo>,
+  });
+})();
+
+const FfiConverterTypeEnvelopeInfo = (() => {
+  type TypeName = EnvelopeInfo;
+  class FFIConverter extends AbstractFfiConverterByteArray<TypeName> {
+    read(from: RustBuffer): TypeName {
+      return {
+        status: FfiConverterTypeSlhDsaStatus.read(from),
+        valid: FfiConverterBool.read(from),
+        param: FfiConverterOptionalTypeParameterSetId.read(from),
+        mode: FfiConverterOptionalTypeSignatureMode.read(from),
+        keyId: FfiConverterOptionalArrayBuffer.read(from),
+        context: FfiConverterArrayBuffer.read(from),
+        payload: FfiConverterOptionalArrayBuffer.read(from),
+      };
+    }
+    write(value: TypeName, into: RustBuffer): void {
+      FfiConverterTypeSlhDsaStatus.write(value.status, into);
+      FfiConverterBool.write(value.valid, into);
+      FfiConverterOptionalTypeParameterSetId.write(value.param, into);
+      FfiConverterOptionalTypeSignatureMode.write(value.mode, into);
+      FfiConverterOptionalArrayBuffer.write(value.keyId, into);
+      FfiConverterArrayBuffer.write(value.context, into);
+      FfiConverterOptionalArrayBuffer.write(value.payload, into);
+    }
+    allocationSize(value: TypeName): number {
+      return (
+        FfiConverterTypeSlhDsaStatus.allocationSize(value.status) +
+        FfiConverterBool.allocationSize(value.valid) +
+        FfiConverterOptionalTypeParameterSetId.allocationSize(value.param) +
+        FfiConverterOptionalTypeSignatureMode.allocationSize(value.mode) +
+        FfiConverterOptionalArrayBuffer.allocationSize(value.keyId) +
+        FfiConverterArrayBuffer.allocationSize(value.context) +
+        FfiConverterOptionalArrayBuffer.allocationSize(value.payload)
+      );
+    }
+  }
+  return new FFIConverter();
+})();
+
+export type FingerprintResult = {
+  status: SlhDsaStatus;
+  fingerprint: ArrayBuffer;
+  hex: string;
+  base32: string;
+  words: string;
+};
+
+/**
+ * Generated factory for {@link FingerprintResult} record objects.
+ */
+export const FingerprintResult = (() => {
+  const defaults = () => ({});
+  const create = (() => {
+    return uniffiCreateRecord<FingerprintResult, ReturnType<typeof defaults>>(
+      defaults,
+    );
+  })();
+  return Object.freeze({
+    /**
+     * Create a frozen instance of {@link FingerprintResult}, with defaults specified
+     * in Rust, in the {@link slh_dsa_uniffi} crate.
+     */
+    create,
+
+    /**
+     * Create a frozen instance of {@link FingerprintResult}, with defaults specified
+     * in Rust, in the {@link slh_dsa_uniffi} crate.
+     */
+    new: create,
+
+    /**
+     * Defaults specified in the {@link slh_dsa_uniffi} crate.
+     */
+    defaults: () => Object.freeze(defaults()) as Partial<FingerprintResult>,
+  });
+})();
+
+const FfiConverterTypeFingerprintResult = (() => {
+  type TypeName = FingerprintResult;
+  class FFIConverter extends AbstractFfiConverterByteArray<TypeName> {
+    read(from: RustBuffer): TypeName {
+      return {
+        status: FfiConverterTypeSlhDsaStatus.read(from),
+        fingerprint: FfiConverterArrayBuffer.read(from),
+        hex: FfiConverterString.read(from),
+        base32: FfiConverterString.read(from),
+        words: FfiConverterString.read(from),
+      };
+    }
+    write(value: TypeName, into: RustBuffer): void {
+      FfiConverterTypeSlhDsaStatus.write(value.status, into);
+      FfiConverterArrayBuffer.write(value.fingerprint, into);
+      FfiConverterString.write(value.hex, into);
+      FfiConverterString.write(value.base32, into);
+      FfiConverterString.write(value.words, into);
+    }
+    allocationSize(value: TypeName): number {
+      return (
+        FfiConverterTypeSlhDsaStatus.allocationSize(value.status) +
+        FfiConverterArrayBuffer.allocationSize(value.fingerprint) +
+        FfiConverterString.allocationSize(value.hex) +
+        FfiConverterString.allocationSize(value.base32) +
+        FfiConverterString.allocationSize(value.words)
+      );
+    }
+  }
+  return new FFIConverter();
+})();
 
 export type KeyResult = {
   status: SlhDsaStatus;
@@ -260,6 +1370,7 @@ const FfiConverterTypeKeyResult = (() => {
 })();
 
 export type Keypair = {
+  status: SlhDsaStatus;
   signingKey: ArrayBuffer;
   verifyingKey: ArrayBuffer;
 };
@@ -297,16 +1408,19 @@ const FfiConverterTypeKeypair = (() => {
   class FFIConverter extends AbstractFfiConverterByteArray<TypeName> {
     read(from: RustBuffer): TypeName {
       return {
+        status: FfiConverterTypeSlhDsaStatus.read(from),
         signingKey: FfiConverterArrayBuffer.read(from),
         verifyingKey: FfiConverterArrayBuffer.read(from),
       };
     }
     write(value: TypeName, into: RustBuffer): void {
+      FfiConverterTypeSlhDsaStatus.write(value.status, into);
       FfiConverterArrayBuffer.write(value.signingKey, into);
       FfiConverterArrayBuffer.write(value.verifyingKey, into);
     }
     allocationSize(value: TypeName): number {
       return (
+        FfiConverterTypeSlhDsaStatus.allocationSize(value.status) +
         FfiConverterArrayBuffer.allocationSize(value.signingKey) +
         FfiConverterArrayBuffer.allocationSize(value.verifyingKey)
       );
@@ -315,6 +1429,174 @@ const FfiConverterTypeKeypair = (() => {
   return new FFIConverter();
 })();
 
+export type ParameterSetInfo = {
+  param: ParameterSetId;
+  name: string;
+  hashFamily: HashFamily;
+  fast: boolean;
+  n: /*u32*/ number;
+  h: /*u32*/ number;
+  d: /*u32*/ number;
+  a: /*u32*/ number;
+  k: /*u32*/ number;
+  w: /*u32*/ number;
+  securityCategory: /*u8*/ number;
+  oid: string;
+  signingKeyLen: /*u64*/ bigint;
+  verifyingKeyLen: /*u64*/ bigint;
+  signatureLen: /*u64*/ bigint;
+};
+
+/**
+ * Generated factory for {@link ParameterSetInfo} record objects.
+ */
+export const ParameterSetInfo = (() => {
+  const defaults = () => ({});
+  const create = (() => {
+    return uniffiCreateRecord<ParameterSetInfo, ReturnType<typeof defaults>>(
+      defaults,
+    );
+  })();
+  return Object.freeze({
+    /**
+     * Create a frozen instance of {@link ParameterSetInfo}, with defaults specified
+     * in Rust, in the {@link slh_dsa_uniffi} crate.
+     */
+    create,
+
+    /**
+     * Create a frozen instance of {@link ParameterSetInfo}, with defaults specified
+     * in Rust, in the {@link slh_dsa_uniffi} crate.
+     */
+    new: create,
+
+    /**
+     * Defaults specified in the {@link slh_dsa_uniffi} crate.
+     */
+    defaults: () => Object.freeze(defaults()) as Partial<ParameterSetInfo>,
+  });
+})();
+
+const FfiConverterTypeParameterSetInfo = (() => {
+  type TypeName = ParameterSetInfo;
+  class FFIConverter extends AbstractFfiConverterByteArray<TypeName> {
+    read(from: RustBuffer): TypeName {
+      return {
+        param: FfiConverterTypeParameterSetId.read(from),
+        name: FfiConverterString.read(from),
+        hashFamily: FfiConverterTypeHashFamily.read(from),
+        fast: FfiConverterBool.read(from),
+        n: FfiConverterUInt32.read(from),
+        h: FfiConverterUInt32.read(from),
+        d: FfiConverterUInt32.read(from),
+        a: FfiConverterUInt32.read(from),
+        k: FfiConverterUInt32.read(from),
+        w: FfiConverterUInt32.read(from),
+        securityCategory: FfiConverterUInt8.read(from),
+        oid: FfiConverterString.read(from),
+        signingKeyLen: FfiConverterUInt64.read(from),
+        verifyingKeyLen: FfiConverterUInt64.read(from),
+        signatureLen: FfiConverterUInt64.read(from),
+      };
+    }
+    write(value: TypeName, into: RustBuffer): void {
+      FfiConverterTypeParameterSetId.write(value.param, into);
+      FfiConverterString.write(value.name, into);
+      FfiConverterTypeHashFamily.write(value.hashFamily, into);
+      FfiConverterBool.write(value.fast, into);
+      FfiConverterUInt32.write(value.n, into);
+      FfiConverterUInt32.write(value.h, into);
+      FfiConverterUInt32.write(value.d, into);
+      FfiConverterUInt32.write(value.a, into);
+      FfiConverterUInt32.write(value.k, into);
+      FfiConverterUInt32.write(value.w, into);
+      FfiConverterUInt8.write(value.securityCategory, into);
+      FfiConverterString.write(value.oid, into);
+      FfiConverterUInt64.write(value.signingKeyLen, into);
+      FfiConverterUInt64.write(value.verifyingKeyLen, into);
+      FfiConverterUInt64.write(value.signatureLen, into);
+    }
+    allocationSize(value: TypeName): number {
+      return (
+        FfiConverterTypeParameterSetId.allocationSize(value.param) +
+        FfiConverterString.allocationSize(value.name) +
+        FfiConverterTypeHashFamily.allocationSize(value.hashFamily) +
+        FfiConverterBool.allocationSize(value.fast) +
+        FfiConverterUInt32.allocationSize(value.n) +
+        FfiConverterUInt32.allocationSize(value.h) +
+        FfiConverterUInt32.allocationSize(value.d) +
+        FfiConverterUInt32.allocationSize(value.a) +
+        FfiConverterUInt32.allocationSize(value.k) +
+        FfiConverterUInt32.allocationSize(value.w) +
+        FfiConverterUInt8.allocationSize(value.securityCategory) +
+        FfiConverterString.allocationSize(value.oid) +
+        FfiConverterUInt64.allocationSize(value.signingKeyLen) +
+        FfiConverterUInt64.allocationSize(value.verifyingKeyLen) +
+        FfiConverterUInt64.allocationSize(value.signatureLen)
+      );
+    }
+  }
+  return new FFIConverter();
+})();
+
+export type SignBatchResult = {
+  status: SlhDsaStatus;
+  signatures: Array<ArrayBuffer>;
+};
+
+/**
+ * Generated factory for {@link SignBatchResult} record objects.
+ */
+export const SignBatchResult = (() => {
+  const defaults = () => ({});
+  const create = (() => {
+    return uniffiCreateRecord<SignBatchResult, ReturnType<typeof defaults>>(
+      defaults,
+    );
+  })();
+  return Object.freeze({
+    /**
+     * Create a frozen instance of {@link SignBatchResult}, with defaults specified
+     * in Rust, in the {@link slh_dsa_uniffi} crate.
+     */
+    create,
+
+    /**
+     * Create a frozen instance of {@link SignBatchResult}, with defaults specified
+     * in Rust, in the {@link slh_dsa_uniffi} crate.
+     */
+    new: create,
+
+    /**
+     * Defaults specified in the {@link slh_dsa_uniffi} crate.
+     */
+    defaults: () => Object.freeze(defaults()) as Partial<SignBatchResult>,
+  });
+})();
+
+const FfiConverterTypeSignBatchResult = (() => {
+  type TypeName = SignBatchResult;
+  class FFIConverter extends AbstractFfiConverterByteArray<TypeName> {
+    read(from: RustBuffer): TypeName {
+      return {
+        status: FfiConverterTypeSlhDsaStatus.read(from),
+        signatures: FfiConverterArrayArrayBuffer.read(from),
+      };
+    }
+    write(value: TypeName, into: RustBuffer): void {
+      FfiConverterTypeSlhDsaStatus.write(value.status, into);
+      FfiConverterArrayArrayBuffer.write(value.signatures, into);
+    }
+    allocationSize(value: TypeName): number {
+      return (
+        FfiConverterTypeSlhDsaStatus.allocationSize(value.status) +
+        FfiConverterArrayArrayBuffer.allocationSize(value.signatures)
+      );
+    }
+  }
+  return new FFIConverter();
+})();
+
 export type SignResult = {
   status: SlhDsaStatus;
   signature: ArrayBuffer;
@@ -456,6 +1738,142 @@ const stringConverter = {
 };
 const FfiConverterString = uniffiCreateFfiConverterString(stringConverter);
 
+export enum Aead {
+  ChaCha20Poly1305,
+  Aes256Gcm,
+}
+
+const FfiConverterTypeAead = (() => {
+  const ordinalConverter = FfiConverterInt32;
+  type TypeName = Aead;
+  class FFIConverter extends AbstractFfiConverterByteArray<TypeName> {
+    read(from: RustBuffer): TypeName {
+      switch (ordinalConverter.read(from)) {
+        case 1:
+          return Aead.ChaCha20Poly1305;
+        case 2:
+          return Aead.Aes256Gcm;
+        default:
+          throw new UniffiInternalError.UnexpectedEnumCase();
+      }
+    }
+    write(value: TypeName, into: RustBuffer): void {
+      switch (value) {
+        case Aead.ChaCha20Poly1305:
+          return ordinalConverter.write(1, into);
+        case Aead.Aes256Gcm:
+          return ordinalConverter.write(2, into);
+      }
+    }
+    allocationSize(value: TypeName): number {
+      return ordinalConverter.allocationSize(0);
+    }
+  }
+  return new FFIConverter();
+})();
+
+export enum FingerprintSource {
+  Raw,
+  Spki,
+}
+
+const FfiConverterTypeFingerprintSource = (() => {
+  const ordinalConverter = FfiConverterInt32;
+  type TypeName = FingerprintSource;
+  class FFIConverter extends AbstractFfiConverterByteArray<TypeName> {
+    read(from: RustBuffer): TypeName {
+      switch (ordinalConverter.read(from)) {
+        case 1:
+          return FingerprintSource.Raw;
+        case 2:
+          return FingerprintSource.Spki;
+        default:
+          throw new UniffiInternalError.UnexpectedEnumCase();
+      }
+    }
+    write(value: TypeName, into: RustBuffer): void {
+      switch (value) {
+        case FingerprintSource.Raw:
+          return ordinalConverter.write(1, into);
+        case FingerprintSource.Spki:
+          return ordinalConverter.write(2, into);
+      }
+    }
+    allocationSize(value: TypeName): number {
+      return ordinalConverter.allocationSize(0);
+    }
+  }
+  return new FFIConverter();
+})();
+
+export enum HashFamily {
+  Sha2,
+  Shake,
+}
+
+const FfiConverterTypeHashFamily = (() => {
+  const ordinalConverter = FfiConverterInt32;
+  type TypeName = HashFamily;
+  class FFIConverter extends AbstractFfiConverterByteArray<TypeName> {
+    read(from: RustBuffer): TypeName {
+      switch (ordinalConverter.read(from)) {
+        case 1:
+          return HashFamily.Sha2;
+        case 2:
+          return HashFamily.Shake;
+        default:
+          throw new UniffiInternalError.UnexpectedEnumCase();
+      }
+    }
+    write(value: TypeName, into: RustBuffer): void {
+      switch (value) {
+        case HashFamily.Sha2:
+          return ordinalConverter.write(1, into);
+        case HashFamily.Shake:
+          return ordinalConverter.write(2, into);
+      }
+    }
+    allocationSize(value: TypeName): number {
+      return ordinalConverter.allocationSize(0);
+    }
+  }
+  return new FFIConverter();
+})();
+
+export enum Kdf {
+  Argon2id,
+  Scrypt,
+}
+
+const FfiConverterTypeKdf = (() => {
+  const ordinalConverter = FfiConverterInt32;
+  type TypeName = Kdf;
+  class FFIConverter extends AbstractFfiConverterByteArray<TypeName> {
+    read(from: RustBuffer): TypeName {
+      switch (ordinalConverter.read(from)) {
+        case 1:
+          return Kdf.Argon2id;
+        case 2:
+          return Kdf.Scrypt;
+        default:
+          throw new UniffiInternalError.UnexpectedEnumCase();
+      }
+    }
+    write(value: TypeName, into: RustBuffer): void {
+      switch (value) {
+        case Kdf.Argon2id:
+          return ordinalConverter.write(1, into);
+        case Kdf.Scrypt:
+          return ordinalConverter.write(2, into);
+      }
+    }
+    allocationSize(value: TypeName): number {
+      return ordinalConverter.allocationSize(0);
+    }
+  }
+  return new FFIConverter();
+})();
+
 export enum ParameterSetId {
   Shake128s,
   Shake128f,
@@ -540,12 +1958,77 @@ const FfiConverterTypeParameterSetId = (() => {
   return new FFIConverter();
 })();
 
+export enum SignatureMode {
+  Pure,
+  PreHashSha256,
+  PreHashSha512,
+  PreHashShake128,
+  PreHashShake256,
+}
+
+const FfiConverterTypeSignatureMode = (() => {
+  const ordinalConverter = FfiConverterInt32;
+  type TypeName = SignatureMode;
+  class FFIConverter extends AbstractFfiConverterByteArray<TypeName> {
+    read(from: RustBuffer): TypeName {
+      switch (ordinalConverter.read(from)) {
+        case 1:
+          return SignatureMode.Pure;
+        case 2:
+          return SignatureMode.PreHashSha256;
+        case 3:
+          return SignatureMode.PreHashSha512;
+        case 4:
+          return SignatureMode.PreHashShake128;
+        case 5:
+          return SignatureMode.PreHashShake256;
+        default:
+          throw new UniffiInternalError.UnexpectedEnumCase();
+      }
+    }
+    write(value: TypeName, into: RustBuffer): void {
+      switch (value) {
+        case SignatureMode.Pure:
+          return ordinalConverter.write(1, into);
+        case SignatureMode.PreHashSha256:
+          return ordinalConverter.write(2, into);
+        case SignatureMode.PreHashSha512:
+          return ordinalConverter.write(3, into);
+        case SignatureMode.PreHashShake128:
+          return ordinalConverter.write(4, into);
+        case SignatureMode.PreHashShake256:
+          return ordinalConverter.write(5, into);
+      }
+    }
+    allocationSize(value: TypeName): number {
+      return ordinalConverter.allocationSize(0);
+    }
+  }
+  return new FFIConverter();
+})();
+
 export enum SlhDsaStatus {
   Ok,
   InvalidParam,
   InvalidLength,
   DecodeError,
   VerifyFailed,
+  ContextTooLong,
+  MalformedSigningKey,
+  MalformedVerifyingKey,
+  MalformedSignature,
+  ParameterSetMismatch,
+  RngFailure,
+  UnsupportedAlgorithm,
+  MalformedEncoding,
+  InvalidCertificate,
+  CertificateExpired,
+  UntrustedChain,
+  TokenExpired,
+  AudienceMismatch,
+  SelfTestFailed,
+  FaultDetected,
+  DecryptionFailed,
 }
 
 const FfiConverterTypeSlhDsaStatus = (() => {
@@ -564,6 +2047,38 @@ const FfiConverterTypeSlhDsaStatus = (() => {
           return SlhDsaStatus.DecodeError;
         case 5:
           return SlhDsaStatus.VerifyFailed;
+        case 6:
+          return SlhDsaStatus.ContextTooLong;
+        case 7:
+          return SlhDsaStatus.MalformedSigningKey;
+        case 8:
+          return SlhDsaStatus.MalformedVerifyingKey;
+        case 9:
+          return SlhDsaStatus.MalformedSignature;
+        case 10:
+          return SlhDsaStatus.ParameterSetMismatch;
+        case 11:
+          return SlhDsaStatus.RngFailure;
+        case 12:
+          return SlhDsaStatus.UnsupportedAlgorithm;
+        case 13:
+          return SlhDsaStatus.MalformedEncoding;
+        case 14:
+          return SlhDsaStatus.InvalidCertificate;
+        case 15:
+          return SlhDsaStatus.CertificateExpired;
+        case 16:
+          return SlhDsaStatus.UntrustedChain;
+        case 17:
+          return SlhDsaStatus.TokenExpired;
+        case 18:
+          return SlhDsaStatus.AudienceMismatch;
+        case 19:
+          return SlhDsaStatus.SelfTestFailed;
+        case 20:
+          return SlhDsaStatus.FaultDetected;
+        case 21:
+          return SlhDsaStatus.DecryptionFailed;
         default:
           throw new UniffiInternalError.UnexpectedEnumCase();
       }
@@ -580,6 +2095,38 @@ const FfiConverterTypeSlhDsaStatus = (() => {
           return ordinalConverter.write(4, into);
         case SlhDsaStatus.VerifyFailed:
           return ordinalConverter.write(5, into);
+        case SlhDsaStatus.ContextTooLong:
+          return ordinalConverter.write(6, into);
+        case SlhDsaStatus.MalformedSigningKey:
+          return ordinalConverter.write(7, into);
+        case SlhDsaStatus.MalformedVerifyingKey:
+          return ordinalConverter.write(8, into);
+        case SlhDsaStatus.MalformedSignature:
+          return ordinalConverter.write(9, into);
+        case SlhDsaStatus.ParameterSetMismatch:
+          return ordinalConverter.write(10, into);
+        case SlhDsaStatus.RngFailure:
+          return ordinalConverter.write(11, into);
+        case SlhDsaStatus.UnsupportedAlgorithm:
+          return ordinalConverter.write(12, into);
+        case SlhDsaStatus.MalformedEncoding:
+          return ordinalConverter.write(13, into);
+        case SlhDsaStatus.InvalidCertificate:
+          return ordinalConverter.write(14, into);
+        case SlhDsaStatus.CertificateExpired:
+          return ordinalConverter.write(15, into);
+        case SlhDsaStatus.UntrustedChain:
+          return ordinalConverter.write(16, into);
+        case SlhDsaStatus.TokenExpired:
+          return ordinalConverter.write(17, into);
+        case SlhDsaStatus.AudienceMismatch:
+          return ordinalConverter.write(18, into);
+        case SlhDsaStatus.SelfTestFailed:
+          return ordinalConverter.write(19, into);
+        case SlhDsaStatus.FaultDetected:
+          return ordinalConverter.write(20, into);
+        case SlhDsaStatus.DecryptionFailed:
+          return ordinalConverter.write(21, into);
       }
     }
     allocationSize(value: TypeName): number {
@@ -589,6 +2136,252 @@ const FfiConverterTypeSlhDsaStatus = (() => {
   return new FFIConverter();
 })();
 
+// Enum: SubjectAltName
+export enum SubjectAltName_Tags {
+  Dns = "Dns",
+  Email = "Email",
+  Uri = "Uri",
+  Ip = "Ip",
+}
+export const SubjectAltName = (() => {
+  type Dns__interface = {
+    tag: SubjectAltName_Tags.Dns;
+    inner: Readonly<{ name: string }>;
+  };
+
+  class Dns_ extends UniffiEnum implements Dns__interface {
+    /**
+     * @private
+     * This field is private and should not be used, use `tag` instead.
+     */
+    readonly [uniffiTypeNameSymbol] = "SubjectAltName";
+    readonly tag = SubjectAltName_Tags.Dns;
+    readonly inner: Readonly<{ name: string }>;
+    constructor(inner: { name: string }) {
+      super("SubjectAltName", "Dns");
+      this.inner = Object.freeze(inner);
+    }
+
+    static new(inner: { name: string }): Dns_ {
+      return new Dns_(inner);
+    }
+
+    static instanceOf(obj: any): obj is Dns_ {
+      return obj.tag === SubjectAltName_Tags.Dns;
+    }
+  }
+
+  type Email__interface = {
+    tag: SubjectAltName_Tags.Email;
+    inner: Readonly<{ address: string }>;
+  };
+
+  class Email_ extends UniffiEnum implements Email__interface {
+    /**
+     * @private
+     * This field is private and should not be used, use `tag` instead.
+     */
+    readonly [uniffiTypeNameSymbol] = "SubjectAltName";
+    readonly tag = SubjectAltName_Tags.Email;
+    readonly inner: Readonly<{ address: string }>;
+    constructor(inner: { address: string }) {
+      super("SubjectAltName", "Email");
+      this.inner = Object.freeze(inner);
+    }
+
+    static new(inner: { address: string }): Email_ {
+      return new Email_(inner);
+    }
+
+    static instanceOf(obj: any): obj is Email_ {
+      return obj.tag === SubjectAltName_Tags.Email;
+    }
+  }
+
+  type Uri__interface = {
+    tag: SubjectAltName_Tags.Uri;
+    inner: Readonly<{ uri: string }>;
+  };
+
+  class Uri_ extends UniffiEnum implements Uri__interface {
+    /**
+     * @private
+     * This field is private and should not be used, use `tag` instead.
+     */
+    readonly [uniffiTypeNameSymbol] = "SubjectAltName";
+    readonly tag = SubjectAltName_Tags.Uri;
+    readonly inner: Readonly<{ uri: string }>;
+    constructor(inner: { uri: string }) {
+      super("SubjectAltName", "Uri");
+      this.inner = Object.freeze(inner);
+    }
+
+    static new(inner: { uri: string }): Uri_ {
+      return new Uri_(inner);
+    }
+
+    static instanceOf(obj: any): obj is Uri_ {
+      return obj.tag === SubjectAltName_Tags.Uri;
+    }
+  }
+
+  type Ip__interface = {
+    tag: SubjectAltName_Tags.Ip;
+    inner: Readonly<{ address: ArrayBuffer }>;
+  };
+
+  class Ip_ extends UniffiEnum implements Ip__interface {
+    /**
+     * @private
+     * This field is private and should not be used, use `tag` instead.
+     */
+    readonly [uniffiTypeNameSymbol] = "SubjectAltName";
+    readonly tag = SubjectAltName_Tags.Ip;
+    readonly inner: Readonly<{ address: ArrayBuffer }>;
+    constructor(inner: { address: ArrayBuffer }) {
+      super("SubjectAltName", "Ip");
+      this.inner = Object.freeze(inner);
+    }
+
+    static new(inner: { address: ArrayBuffer }): Ip_ {
+      return new Ip_(inner);
+    }
+
+    static instanceOf(obj: any): obj is Ip_ {
+      return obj.tag === SubjectAltName_Tags.Ip;
+    }
+  }
+
+  function instanceOf(obj: any): obj is SubjectAltName {
+    return obj[uniffiTypeNameSymbol] === "SubjectAltName";
+  }
+
+  return Object.freeze({
+    instanceOf,
+    Dns: Dns_,
+    Email: Email_,
+    Uri: Uri_,
+    Ip: Ip_,
+  });
+})();
+
+export type SubjectAltName = InstanceType<
+  (typeof SubjectAltName)[keyof Omit<typeof SubjectAltName, "instanceOf">]
+>;
+
+// FfiConverter for enum SubjectAltName
+const FfiConverterTypeSubjectAltName = (() => {
+  const ordinalConverter = FfiConverterInt32;
+  type TypeName = SubjectAltName;
+  class FFIConverter extends AbstractFfiConverterByteArray<TypeName> {
+    read(from: RustBuffer): TypeName {
+      switch (ordinalConverter.read(from)) {
+        case 1:
+          return new SubjectAltName.Dns({
+            name: FfiConverterString.read(from),
+          });
+        case 2:
+          return new SubjectAltName.Email({
+            address: FfiConverterString.read(from),
+          });
+        case 3:
+          return new SubjectAltName.Uri({ uri: FfiConverterString.read(from) });
+        case 4:
+          return new SubjectAltName.Ip({
+            address: FfiConverterArrayBuffer.read(from),
+          });
+        default:
+          throw new UniffiInternalError.UnexpectedEnumCase();
+      }
+    }
+    write(value: TypeName, into: RustBuffer): void {
+      switch (value.tag) {
+        case SubjectAltName_Tags.Dns: {
+          ordinalConverter.write(1, into);
+          const inner = value.inner;
+          FfiConverterString.write(inner.name, into);
+          return;
+        }
+        case SubjectAltName_Tags.Email: {
+          ordinalConverter.write(2, into);
+          const inner = value.inner;
+          FfiConverterString.write(inner.address, into);
+          return;
+        }
+        case SubjectAltName_Tags.Uri: {
+          ordinalConverter.write(3, into);
+          const inner = value.inner;
+          FfiConverterString.write(inner.uri, into);
+          return;
+        }
+        case SubjectAltName_Tags.Ip: {
+          ordinalConverter.write(4, into);
+          const inner = value.inner;
+          FfiConverterArrayBuffer.write(inner.address, into);
+          return;
+        }
+        default:
+          // Throwing from here means that SubjectAltName_Tags hasn't matched an ordinal.
+          throw new UniffiInternalError.UnexpectedEnumCase();
+      }
+    }
+    allocationSize(value: TypeName): number {
+      switch (value.tag) {
+        case SubjectAltName_Tags.Dns: {
+          const inner = value.inner;
+          let size = ordinalConverter.allocationSize(1);
+          size += FfiConverterString.allocationSize(inner.name);
+          return size;
+        }
+        case SubjectAltName_Tags.Email: {
+          const inner = value.inner;
+          let size = ordinalConverter.allocationSize(2);
+          size += FfiConverterString.allocationSize(inner.address);
+          return size;
+        }
+        case SubjectAltName_Tags.Uri: {
+          const inner = value.inner;
+          let size = ordinalConverter.allocationSize(3);
+          size += FfiConverterString.allocationSize(inner.uri);
+          return size;
+        }
+        case SubjectAltName_Tags.Ip: {
+          const inner = value.inner;
+          let size = ordinalConverter.allocationSize(4);
+          size += FfiConverterArrayBuffer.allocationSize(inner.address);
+          return size;
+        }
+        default:
+          throw new UniffiInternalError.UnexpectedEnumCase();
+      }
+    }
+  }
+  return new FFIConverter();
+})();
+
+const FfiConverterOptionalArrayBuffer = new FfiConverterOptional(
+  FfiConverterArrayBuffer,
+);
+const FfiConverterOptionalString = new FfiConverterOptional(FfiConverterString);
+const FfiConverterOptionalTypeParameterSetId = new FfiConverterOptional(
+  FfiConverterTypeParameterSetId,
+);
+const FfiConverterOptionalTypeSignatureMode = new FfiConverterOptional(
+  FfiConverterTypeSignatureMode,
+);
+const FfiConverterArrayArrayBuffer = new FfiConverterArray(
+  FfiConverterArrayBuffer,
+);
+const FfiConverterArrayTypeCsrExtension = new FfiConverterArray(
+  FfiConverterTypeCsrExtension,
+);
+const FfiConverterArrayTypeParameterSetInfo = new FfiConverterArray(
+  FfiConverterTypeParameterSetInfo,
+);
+const FfiConverterArrayTypeSubjectAltName = new FfiConverterArray(
+  FfiConverterTypeSubjectAltName,
+);
+
 /**
  * This should be called before anything else.
  *
@@ -611,6 +2404,86 @@ function uniffiEnsureInitialized() {
       bindingsContractVersion,
     );
   }
+  if (
+    nativeModule().ubrn_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_cose_sign1_create() !==
+    11547
+  ) {
+    throw new UniffiInternalError.ApiChecksumMismatch(
+      "uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_cose_sign1_create",
+    );
+  }
+  if (
+    nativeModule().ubrn_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_cose_sign1_verify() !==
+    34918
+  ) {
+    throw new UniffiInternalError.ApiChecksumMismatch(
+      "uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_cose_sign1_verify",
+    );
+  }
+  if (
+    nativeModule().ubrn_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_csr_create() !==
+    38104
+  ) {
+    throw new UniffiInternalError.ApiChecksumMismatch(
+      "uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_csr_create",
+    );
+  }
+  if (
+    nativeModule().ubrn_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_csr_verify() !==
+    54660
+  ) {
+    throw new UniffiInternalError.ApiChecksumMismatch(
+      "uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_csr_verify",
+    );
+  }
+  if (
+    nativeModule().ubrn_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_envelope_decode() !==
+    43712
+  ) {
+    throw new UniffiInternalError.ApiChecksumMismatch(
+      "uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_envelope_decode",
+    );
+  }
+  if (
+    nativeModule().ubrn_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_envelope_sign() !==
+    62941
+  ) {
+    throw new UniffiInternalError.ApiChecksumMismatch(
+      "uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_envelope_sign",
+    );
+  }
+  if (
+    nativeModule().ubrn_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_envelope_verify() !==
+    55803
+  ) {
+    throw new UniffiInternalError.ApiChecksumMismatch(
+      "uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_envelope_verify",
+    );
+  }
+  if (
+    nativeModule().ubrn_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_fingerprint() !==
+    23530
+  ) {
+    throw new UniffiInternalError.ApiChecksumMismatch(
+      "uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_fingerprint",
+    );
+  }
+  if (
+    nativeModule().ubrn_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_key_file_len() !==
+    21678
+  ) {
+    throw new UniffiInternalError.ApiChecksumMismatch(
+      "uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_key_file_len",
+    );
+  }
+  if (
+    nativeModule().ubrn_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_keypair_from_seed() !==
+    31227
+  ) {
+    throw new UniffiInternalError.ApiChecksumMismatch(
+      "uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_keypair_from_seed",
+    );
+  }
   if (
     nativeModule().ubrn_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_keypair_generate() !==
     7757
@@ -627,6 +2500,46 @@ function uniffiEnsureInitialized() {
       "uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_parameter_name",
     );
   }
+  if (
+    nativeModule().ubrn_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_parameter_set_from_name() !==
+    56401
+  ) {
+    throw new UniffiInternalError.ApiChecksumMismatch(
+      "uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_parameter_set_from_name",
+    );
+  }
+  if (
+    nativeModule().ubrn_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_parameter_set_from_oid() !==
+    48394
+  ) {
+    throw new UniffiInternalError.ApiChecksumMismatch(
+      "uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_parameter_set_from_oid",
+    );
+  }
+  if (
+    nativeModule().ubrn_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_parameter_sets() !==
+    26697
+  ) {
+    throw new UniffiInternalError.ApiChecksumMismatch(
+      "uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_parameter_sets",
+    );
+  }
+  if (
+    nativeModule().ubrn_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_seed_len() !==
+    18769
+  ) {
+    throw new UniffiInternalError.ApiChecksumMismatch(
+      "uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_seed_len",
+    );
+  }
+  if (
+    nativeModule().ubrn_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_set_verify_after_sign() !==
+    6374
+  ) {
+    throw new UniffiInternalError.ApiChecksumMismatch(
+      "uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_set_verify_after_sign",
+    );
+  }
   if (
     nativeModule().ubrn_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_sign() !==
     51560
@@ -635,6 +2548,22 @@ function uniffiEnsureInitialized() {
       "uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_sign",
     );
   }
+  if (
+    nativeModule().ubrn_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_sign_batch() !==
+    55765
+  ) {
+    throw new UniffiInternalError.ApiChecksumMismatch(
+      "uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_sign_batch",
+    );
+  }
+  if (
+    nativeModule().ubrn_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_sign_checked() !==
+    30107
+  ) {
+    throw new UniffiInternalError.ApiChecksumMismatch(
+      "uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_sign_checked",
+    );
+  }
   if (
     nativeModule().ubrn_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_sign_deterministic() !==
     23979
@@ -651,6 +2580,38 @@ function uniffiEnsureInitialized() {
       "uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_signature_len",
     );
   }
+  if (
+    nativeModule().ubrn_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_signing_key_from_cose_key() !==
+    34229
+  ) {
+    throw new UniffiInternalError.ApiChecksumMismatch(
+      "uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_signing_key_from_cose_key",
+    );
+  }
+  if (
+    nativeModule().ubrn_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_signing_key_from_encrypted_pkcs8() !==
+    19253
+  ) {
+    throw new UniffiInternalError.ApiChecksumMismatch(
+      "uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_signing_key_from_encrypted_pkcs8",
+    );
+  }
+  if (
+    nativeModule().ubrn_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_signing_key_from_key_file() !==
+    53092
+  ) {
+    throw new UniffiInternalError.ApiChecksumMismatch(
+      "uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_signing_key_from_key_file",
+    );
+  }
+  if (
+    nativeModule().ubrn_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_signing_key_from_pkcs8() !==
+    17809
+  ) {
+    throw new UniffiInternalError.ApiChecksumMismatch(
+      "uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_signing_key_from_pkcs8",
+    );
+  }
   if (
     nativeModule().ubrn_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_signing_key_len() !==
     40535
@@ -659,6 +2620,46 @@ function uniffiEnsureInitialized() {
       "uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_signing_key_len",
     );
   }
+  if (
+    nativeModule().ubrn_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_signing_key_to_cose_key() !==
+    51163
+  ) {
+    throw new UniffiInternalError.ApiChecksumMismatch(
+      "uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_signing_key_to_cose_key",
+    );
+  }
+  if (
+    nativeModule().ubrn_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_signing_key_to_encrypted_pkcs8() !==
+    36675
+  ) {
+    throw new UniffiInternalError.ApiChecksumMismatch(
+      "uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_signing_key_to_encrypted_pkcs8",
+    );
+  }
+  if (
+    nativeModule().ubrn_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_signing_key_to_key_file() !==
+    43469
+  ) {
+    throw new UniffiInternalError.ApiChecksumMismatch(
+      "uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_signing_key_to_key_file",
+    );
+  }
+  if (
+    nativeModule().ubrn_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_signing_key_to_pkcs8() !==
+    52043
+  ) {
+    throw new UniffiInternalError.ApiChecksumMismatch(
+      "uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_signing_key_to_pkcs8",
+    );
+  }
+  if (
+    nativeModule().ubrn_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_validate_signing_key() !==
+    6415
+  ) {
+    throw new UniffiInternalError.ApiChecksumMismatch(
+      "uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_validate_signing_key",
+    );
+  }
   if (
     nativeModule().ubrn_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_verify() !==
     22241
@@ -667,6 +2668,22 @@ function uniffiEnsureInitialized() {
       "uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_verify",
     );
   }
+  if (
+    nativeModule().ubrn_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_verify_after_sign_enabled() !==
+    43959
+  ) {
+    throw new UniffiInternalError.ApiChecksumMismatch(
+      "uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_verify_after_sign_enabled",
+    );
+  }
+  if (
+    nativeModule().ubrn_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_verifying_key_from_cose_key() !==
+    62128
+  ) {
+    throw new UniffiInternalError.ApiChecksumMismatch(
+      "uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_verifying_key_from_cose_key",
+    );
+  }
   if (
     nativeModule().ubrn_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_verifying_key_from_signing_key() !==
     64766
@@ -675,6 +2692,22 @@ function uniffiEnsureInitialized() {
       "uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_verifying_key_from_signing_key",
     );
   }
+  if (
+    nativeModule().ubrn_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_verifying_key_from_signing_key_validated() !==
+    37694
+  ) {
+    throw new UniffiInternalError.ApiChecksumMismatch(
+      "uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_verifying_key_from_signing_key_validated",
+    );
+  }
+  if (
+    nativeModule().ubrn_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_verifying_key_from_spki() !==
+    1557
+  ) {
+    throw new UniffiInternalError.ApiChecksumMismatch(
+      "uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_verifying_key_from_spki",
+    );
+  }
   if (
     nativeModule().ubrn_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_verifying_key_len() !==
     16179
@@ -683,16 +2716,48 @@ function uniffiEnsureInitialized() {
       "uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_verifying_key_len",
     );
   }
+  if (
+    nativeModule().ubrn_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_verifying_key_to_cose_key() !==
+    45780
+  ) {
+    throw new UniffiInternalError.ApiChecksumMismatch(
+      "uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_verifying_key_to_cose_key",
+    );
+  }
+  if (
+    nativeModule().ubrn_uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_verifying_key_to_spki() !==
+    37838
+  ) {
+    throw new UniffiInternalError.ApiChecksumMismatch(
+      "uniffi_slh_dsa_uniffi_checksum_func_slh_dsa_verifying_key_to_spki",
+    );
+  }
 }
 
 export default Object.freeze({
   initialize: uniffiEnsureInitialized,
   converters: {
+    FfiConverterTypeAead,
+    FfiConverterTypeCoseSign1Info,
+    FfiConverterTypeCsrExtension,
+    FfiConverterTypeCsrInfo,
+    FfiConverterTypeCsrSubject,
+    FfiConverterTypeDecodeKeyResult,
+    FfiConverterTypeEncodeResult,
+    FfiConverterTypeEnvelopeInfo,
+    FfiConverterTypeFingerprintResult,
+    FfiConverterTypeFingerprintSource,
+    FfiConverterTypeHashFamily,
+    FfiConverterTypeKdf,
     FfiConverterTypeKeyResult,
     FfiConverterTypeKeypair,
     FfiConverterTypeParameterSetId,
+    FfiConverterTypeParameterSetInfo,
+    FfiConverterTypeSignBatchResult,
     FfiConverterTypeSignResult,
+    FfiConverterTypeSignatureMode,
     FfiConverterTypeSlhDsaStatus,
+    FfiConverterTypeSubjectAltName,
     FfiConverterTypeVerifyResult,
   },
 });
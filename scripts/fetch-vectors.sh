@@ -0,0 +1,25 @@
+#!/usr/bin/env bash
+set -euo pipefail
+
+ROOT_DIR="$(cd "$(dirname "${BASH_SOURCE[0]}")/.." && pwd)"
+ACVP_DIR="${ROOT_DIR}/slh-dsa-core/tests/vectors/acvp"
+
+# Downloads the NIST ACVP-Server SLH-DSA files into tests/vectors/acvp, where
+# tests/acvp.rs reads them. ACVP_REF must name a release tag or commit of
+# usnistgov/ACVP-Server so the committed files can be traced back; it is
+# recorded in tests/vectors/acvp/ACVP_REF.
+ACVP_REF="${ACVP_REF:-}"
+if [[ -z "${ACVP_REF}" || "${ACVP_REF}" == "master" ]]; then
+  echo "Set ACVP_REF to an ACVP-Server tag or commit (not master)." >&2
+  exit 1
+fi
+ACVP_BASE="https://raw.githubusercontent.com/usnistgov/ACVP-Server/${ACVP_REF}/gen-val/json-files"
+
+for mode in SLH-DSA-keyGen-FIPS205 SLH-DSA-sigGen-FIPS205 SLH-DSA-sigVer-FIPS205; do
+  mkdir -p "${ACVP_DIR}/${mode}"
+  for file in prompt.json expectedResults.json; do
+    echo "Fetching ${mode}/${file}"
+    curl -fsSL "${ACVP_BASE}/${mode}/${file}" -o "${ACVP_DIR}/${mode}/${file}"
+  done
+done
+echo "${ACVP_REF}" > "${ACVP_DIR}/ACVP_REF"
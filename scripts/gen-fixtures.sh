@@ -0,0 +1,51 @@
+#!/usr/bin/env bash
+set -euo pipefail
+
+ROOT_DIR="$(cd "$(dirname "${BASH_SOURCE[0]}")/.." && pwd)"
+FIXTURE_DIR="${ROOT_DIR}/slh-dsa-core/tests/vectors/openssl"
+OPENSSL="${OPENSSL:-openssl}"
+
+# Regenerates the OpenSSL 3.5 (or later) interop fixtures read by the x509,
+# csr, cms and keyfile unit tests. Keys come from fixed seeds; signatures are
+# randomized, so rerunning changes every file except content.txt.
+ALG="SLH-DSA-SHA2-128f"
+ROOT_SEED="$(printf '%02x' $(seq 0 47))"
+LEAF_SEED="$(printf '%02x' $(seq 48 95))"
+PASSPHRASE="openssl-fixture"
+
+WORK_DIR="$(mktemp -d)"
+trap 'rm -rf "${WORK_DIR}"' EXIT
+mkdir -p "${FIXTURE_DIR}"
+
+"${OPENSSL}" genpkey -algorithm "${ALG}" -pkeyopt "hexseed:${ROOT_SEED}" -out "${WORK_DIR}/root.key"
+"${OPENSSL}" genpkey -algorithm "${ALG}" -pkeyopt "hexseed:${LEAF_SEED}" -out "${WORK_DIR}/leaf.key"
+
+"${OPENSSL}" req -x509 -new -key "${WORK_DIR}/root.key" \
+  -subj "/C=US/O=OpenSSL fixture/CN=OpenSSL Root" \
+  -not_before 20250101000000Z -not_after 20491231235959Z -set_serial 1 \
+  -addext "basicConstraints=critical,CA:TRUE" \
+  -addext "keyUsage=critical,keyCertSign,cRLSign" \
+  -outform DER -out "${FIXTURE_DIR}/root.der"
+
+"${OPENSSL}" req -new -key "${WORK_DIR}/leaf.key" -subj "/CN=leaf.example" \
+  -addext "subjectAltName=DNS:leaf.example,DNS:www.leaf.example" \
+  -addext "keyUsage=critical,digitalSignature" \
+  -outform DER -out "${FIXTURE_DIR}/leaf-csr.der"
+
+printf '[leaf]\nbasicConstraints=critical,CA:FALSE\nkeyUsage=critical,digitalSignature\nsubjectAltName=DNS:leaf.example\n' \
+  > "${WORK_DIR}/leaf.cnf"
+"${OPENSSL}" x509 -req -inform DER -in "${FIXTURE_DIR}/leaf-csr.der" \
+  -CA "${FIXTURE_DIR}/root.der" -CAform DER -CAkey "${WORK_DIR}/root.key" \
+  -not_before 20250101000000Z -not_after 20350101000000Z -set_serial 2 \
+  -extfile "${WORK_DIR}/leaf.cnf" -extensions leaf \
+  -outform DER -out "${FIXTURE_DIR}/leaf.der"
+
+printf 'CMS fixture content\n' > "${FIXTURE_DIR}/content.txt"
+"${OPENSSL}" x509 -inform DER -in "${FIXTURE_DIR}/leaf.der" -out "${WORK_DIR}/leaf.pem"
+"${OPENSSL}" cms -sign -md sha256 -binary -signer "${WORK_DIR}/leaf.pem" -inkey "${WORK_DIR}/leaf.key" \
+  -in "${FIXTURE_DIR}/content.txt" -outform DER -out "${FIXTURE_DIR}/cms-detached.der"
+"${OPENSSL}" cms -sign -md sha256 -binary -nodetach -signer "${WORK_DIR}/leaf.pem" -inkey "${WORK_DIR}/leaf.key" \
+  -in "${FIXTURE_DIR}/content.txt" -outform DER -out "${FIXTURE_DIR}/cms-attached.der"
+
+"${OPENSSL}" pkcs8 -topk8 -scrypt -in "${WORK_DIR}/leaf.key" -passout "pass:${PASSPHRASE}" \
+  -outform DER -out "${FIXTURE_DIR}/leaf-encrypted.der"
@@ -0,0 +1,304 @@
+#!/usr/bin/env python3
+"""Generate the FIPS 205 conformance vectors committed under slh-dsa-core/tests/vectors.
+
+Every key and signature comes from OpenSSL (3.5 or later), an implementation
+independent of this repository:
+
+- acvp-openssl/SLH-DSA-{keyGen,sigGen,sigVer}-FIPS205/{prompt,expectedResults}.json
+  in the ACVP-Server JSON layout. HashSLH-DSA messages are formatted per
+  FIPS 205 Algorithm 23 here and signed through OpenSSL's internal interface.
+  These are a cross-check only; the NIST ACVP-Server files under acvp/ come
+  from scripts/fetch-vectors.sh.
+- kat/<parameter set>.rsp in the PQCgenKAT_sign layout, using the same
+  AES-256 CTR_DRBG as the reference rng.c. These are also an OpenSSL
+  cross-check: the reference C implementation does not produce them.
+
+Requires the `cryptography` package. Set OPENSSL to pick the binary.
+"""
+
+import hashlib
+import json
+import os
+import pathlib
+import subprocess
+import tempfile
+
+from cryptography.hazmat.primitives.ciphers import Cipher, algorithms, modes
+
+ROOT = pathlib.Path(__file__).resolve().parents[1]
+VECTOR_DIR = ROOT / "slh-dsa-core" / "tests" / "vectors"
+OPENSSL = os.environ.get("OPENSSL", "openssl")
+
+PARAMETER_SETS = [
+    ("SLH-DSA-SHAKE-128s", 16),
+    ("SLH-DSA-SHAKE-128f", 16),
+    ("SLH-DSA-SHAKE-192s", 24),
+    ("SLH-DSA-SHAKE-192f", 24),
+    ("SLH-DSA-SHAKE-256s", 32),
+    ("SLH-DSA-SHAKE-256f", 32),
+    ("SLH-DSA-SHA2-128s", 16),
+    ("SLH-DSA-SHA2-128f", 16),
+    ("SLH-DSA-SHA2-192s", 24),
+    ("SLH-DSA-SHA2-192f", 24),
+    ("SLH-DSA-SHA2-256s", 32),
+    ("SLH-DSA-SHA2-256f", 32),
+]
+
+# Last arc of the NIST hash OID 2.16.840.1.101.3.4.2.x, and the digest function.
+HASH_ALGS = {
+    "SHA2-256": (0x01, lambda m: hashlib.sha256(m).digest()),
+    "SHA2-384": (0x02, lambda m: hashlib.sha384(m).digest()),
+    "SHA2-512": (0x03, lambda m: hashlib.sha512(m).digest()),
+    "SHA2-224": (0x04, lambda m: hashlib.sha224(m).digest()),
+    "SHA3-256": (0x08, lambda m: hashlib.sha3_256(m).digest()),
+    "SHAKE-128": (0x0B, lambda m: hashlib.shake_128(m).digest(32)),
+    "SHAKE-256": (0x0C, lambda m: hashlib.shake_256(m).digest(64)),
+}
+
+# Signing interfaces exercised per parameter set, rotated so that every
+# combination is covered without signing each one under all twelve sets.
+SIG_GEN_MODES = [
+    ("internal", "pure", True, None),
+    ("internal", "pure", False, None),
+    ("external", "pure", True, None),
+    ("external", "pure", False, None),
+    ("external", "preHash", True, "SHA2-256"),
+    ("external", "preHash", False, "SHA2-512"),
+    ("external", "preHash", True, "SHAKE-128"),
+    ("external", "preHash", False, "SHAKE-256"),
+    ("external", "preHash", True, "SHA2-384"),
+    ("external", "preHash", True, "SHA3-256"),
+    ("external", "preHash", False, "SHA2-224"),
+    ("external", "pure", True, None),
+]
+
+
+class AesCtrDrbg:
+    """AES-256 CTR_DRBG without derivation function, as in the PQC reference rng.c."""
+
+    def __init__(self, entropy_input):
+        self.key = bytes(32)
+        self.v = bytearray(16)
+        self._update(entropy_input)
+
+    def random_bytes(self, length):
+        out = b"".join(self._next_block() for _ in range((length + 15) // 16))
+        self._update(None)
+        return out[:length]
+
+    def _next_block(self):
+        for i in reversed(range(16)):
+            self.v[i] = (self.v[i] + 1) & 0xFF
+            if self.v[i]:
+                break
+        encryptor = Cipher(algorithms.AES(self.key), modes.ECB()).encryptor()
+        return encryptor.update(bytes(self.v)) + encryptor.finalize()
+
+    def _update(self, provided_data):
+        temp = b"".join(self._next_block() for _ in range(3))
+        if provided_data is not None:
+            temp = bytes(a ^ b for a, b in zip(temp, provided_data))
+        self.key = temp[:32]
+        self.v = bytearray(temp[32:])
+
+
+class OpenSsl:
+    def __init__(self, workdir):
+        self.workdir = pathlib.Path(workdir)
+        self.private_keys = {}
+
+    def run(self, *args):
+        return subprocess.run([OPENSSL, *args], check=True, capture_output=True).stdout
+
+    def keygen(self, name, n, seed):
+        """Return (sk, pk) for SK.seed || SK.prf || PK.seed."""
+        assert len(seed) == 3 * n
+        der = self.run(
+            "genpkey", "-algorithm", name, "-pkeyopt", f"hexseed:{seed.hex()}",
+            "-outform", "DER",
+        )
+        sk = der[-4 * n:]
+        assert sk[: 3 * n] == seed
+        self.private_keys[sk] = der
+        return sk, sk[2 * n:]
+
+    def sign(self, n, sk, msg, *, internal, ctx=b"", opt_rand=None):
+        key = self.workdir / "key.der"
+        key.write_bytes(self.private_keys[sk])
+        data = self.workdir / "msg.bin"
+        data.write_bytes(msg)
+        args = ["pkeyutl", "-sign", "-inkey", str(key), "-keyform", "DER", "-rawin",
+                "-in", str(data)]
+        if internal:
+            args += ["-pkeyopt", "message-encoding:0"]
+        elif ctx:
+            args += ["-pkeyopt", f"hexcontext-string:{ctx.hex()}"]
+        if opt_rand is None:
+            args += ["-pkeyopt", "deterministic:1"]
+        else:
+            assert len(opt_rand) == n
+            args += ["-pkeyopt", f"hextest-entropy:{opt_rand.hex()}"]
+        return self.run(*args)
+
+
+def hash_message_prime(hash_alg, ctx, msg):
+    arc, digest = HASH_ALGS[hash_alg]
+    oid = bytes([0x06, 0x09, 0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x02, arc])
+    return bytes([1, len(ctx)]) + ctx + oid + digest(msg)
+
+
+def vector_set(mode, groups):
+    return {
+        "vsId": 0,
+        "algorithm": "SLH-DSA",
+        "mode": mode,
+        "revision": "FIPS205",
+        "isSample": True,
+        "testGroups": groups,
+    }
+
+
+def write_json(mode, prompt, expected):
+    directory = VECTOR_DIR / "acvp-openssl" / f"SLH-DSA-{mode}-FIPS205"
+    directory.mkdir(parents=True, exist_ok=True)
+    for file, groups in (("prompt.json", prompt), ("expectedResults.json", expected)):
+        text = json.dumps(vector_set(mode, groups), indent=2)
+        (directory / file).write_text(text + "\n")
+
+
+def sign_case(ssl, n, sk, interface, pre_hash, hash_alg, msg, ctx, opt_rand):
+    if interface == "internal":
+        return ssl.sign(n, sk, msg, internal=True, opt_rand=opt_rand)
+    if pre_hash == "preHash":
+        msg_prime = hash_message_prime(hash_alg, ctx, msg)
+        return ssl.sign(n, sk, msg_prime, internal=True, opt_rand=opt_rand)
+    return ssl.sign(n, sk, msg, internal=False, ctx=ctx, opt_rand=opt_rand)
+
+
+def case_fields(interface, pre_hash, hash_alg, msg, ctx):
+    fields = {"messageLength": 8 * len(msg), "message": msg.hex().upper()}
+    if interface == "external":
+        fields["contextLength"] = 8 * len(ctx)
+        fields["context"] = ctx.hex().upper()
+    if pre_hash == "preHash":
+        fields["hashAlg"] = hash_alg
+    return fields
+
+
+def generate_acvp(ssl, drbg):
+    keygen_prompt, keygen_expected = [], []
+    siggen_prompt, siggen_expected = [], []
+    sigver_prompt, sigver_expected = [], []
+
+    for index, (name, n) in enumerate(PARAMETER_SETS):
+        tg_id = index + 1
+        tests, results = [], []
+        for tc in range(2):
+            seeds = drbg.random_bytes(3 * n)
+            sk, pk = ssl.keygen(name, n, seeds)
+            tests.append({
+                "tcId": 2 * index + tc + 1,
+                "skSeed": seeds[:n].hex().upper(),
+                "skPrf": seeds[n: 2 * n].hex().upper(),
+                "pkSeed": seeds[2 * n:].hex().upper(),
+            })
+            results.append({"tcId": 2 * index + tc + 1, "sk": sk.hex().upper(),
+                            "pk": pk.hex().upper()})
+        keygen_prompt.append({"tgId": tg_id, "testType": "AFT", "parameterSet": name,
+                              "tests": tests})
+        keygen_expected.append({"tgId": tg_id, "tests": results})
+
+        sk, pk = ssl.keygen(name, n, drbg.random_bytes(3 * n))
+        for offset, slot in enumerate((index, (index + 5) % len(SIG_GEN_MODES))):
+            interface, pre_hash, deterministic, hash_alg = SIG_GEN_MODES[slot]
+            tg_id = 2 * index + offset + 1
+            msg = drbg.random_bytes(17 + 13 * slot)
+            ctx = drbg.random_bytes(slot % 4 * 3) if interface == "external" else b""
+            opt_rand = None if deterministic else drbg.random_bytes(n)
+            signature = sign_case(ssl, n, sk, interface, pre_hash, hash_alg, msg, ctx,
+                                  opt_rand)
+
+            case = {"tcId": tg_id, "sk": sk.hex().upper(),
+                    **case_fields(interface, pre_hash, hash_alg, msg, ctx)}
+            if opt_rand is not None:
+                case["additionalRandomness"] = opt_rand.hex().upper()
+            siggen_prompt.append({
+                "tgId": tg_id, "testType": "AFT", "parameterSet": name,
+                "deterministic": deterministic, "signatureInterface": interface,
+                "preHash": pre_hash, "tests": [case],
+            })
+            siggen_expected.append({"tgId": tg_id, "tests": [
+                {"tcId": tg_id, "signature": signature.hex().upper()}]})
+
+            # sigVer: the same message with a valid signature, or a corrupted one.
+            valid = offset == 0
+            if not valid:
+                corrupted = bytearray(signature)
+                corrupted[len(corrupted) // 2] ^= 0x01
+                signature = bytes(corrupted)
+            sigver_prompt.append({
+                "tgId": tg_id, "testType": "AFT", "parameterSet": name,
+                "signatureInterface": interface, "preHash": pre_hash,
+                "tests": [{
+                    "tcId": tg_id, "pk": pk.hex().upper(),
+                    **case_fields(interface, pre_hash, hash_alg, msg, ctx),
+                    "signature": signature.hex().upper(),
+                    "reason": "valid signature and message - signature should verify successfully"
+                    if valid else "modified signature - signature should not verify",
+                }],
+            })
+            sigver_expected.append({"tgId": tg_id, "tests": [
+                {"tcId": tg_id, "testPassed": valid}]})
+
+    write_json("keyGen", keygen_prompt, keygen_expected)
+    write_json("sigGen", siggen_prompt, siggen_expected)
+    write_json("sigVer", sigver_prompt, sigver_expected)
+
+
+def generate_kat(ssl, version):
+    """One PQCgenKAT_sign entry per parameter set: keygen and signing seeded per entry."""
+    directory = VECTOR_DIR / "kat"
+    directory.mkdir(parents=True, exist_ok=True)
+    for name, n in PARAMETER_SETS:
+        drbg = AesCtrDrbg(bytes(range(48)))
+        seed = drbg.random_bytes(48)
+        msg = drbg.random_bytes(33)
+
+        entry_drbg = AesCtrDrbg(seed)
+        sk, pk = ssl.keygen(name, n, entry_drbg.random_bytes(3 * n))
+        opt_rand = entry_drbg.random_bytes(n)
+        sm = ssl.sign(n, sk, msg, internal=True, opt_rand=opt_rand) + msg
+
+        lines = [
+            f"# {name}",
+            f"# generated by scripts/gen-vectors.py with {version}",
+            "",
+            "count = 0",
+            f"seed = {seed.hex().upper()}",
+            f"mlen = {len(msg)}",
+            f"msg = {msg.hex().upper()}",
+            f"pk = {pk.hex().upper()}",
+            f"sk = {sk.hex().upper()}",
+            f"smlen = {len(sm)}",
+            f"sm = {sm.hex().upper()}",
+            "",
+        ]
+        (directory / f"{name}.rsp").write_text("\n".join(lines) + "\n")
+
+
+def main():
+    version = subprocess.run([OPENSSL, "version"], check=True, capture_output=True,
+                             text=True).stdout.strip()
+    major, minor = (int(part) for part in version.split()[1].split(".")[:2])
+    if (major, minor) < (3, 5):
+        raise SystemExit(f"{version} has no SLH-DSA; OpenSSL 3.5 or later is required")
+
+    with tempfile.TemporaryDirectory() as workdir:
+        ssl = OpenSsl(workdir)
+        generate_acvp(ssl, AesCtrDrbg(hashlib.sha384(b"slh-dsa-core ACVP").digest()))
+        generate_kat(ssl, version)
+    print(f"wrote vectors under {VECTOR_DIR} with {version}")
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,20 @@
+#!/usr/bin/env bash
+set -euo pipefail
+
+ROOT_DIR="$(cd "$(dirname "${BASH_SOURCE[0]}")/.." && pwd)"
+cd "${ROOT_DIR}"
+
+cargo fmt --all -- --check
+cargo clippy --workspace --all-targets -- -D warnings
+
+# Each slh-dsa-core feature on its own, so code shared between features is
+# checked for the narrowest configuration that compiles it.
+cargo clippy -p slh-dsa-core --all-targets -- -D warnings
+for feature in parallel zeroize pkcs8 x509 cms cose jose serde fips keyfile; do
+  echo "clippy: slh-dsa-core --features ${feature}"
+  cargo clippy -p slh-dsa-core --all-targets --features "${feature}" -- -D warnings
+done
+
+# The generated C header must compile on its own.
+cargo build -p slh-dsa-ffi
+"${CC:-cc}" -fsyntax-only -Wall -Werror -x c slh-dsa-ffi/include/slh_dsa.h
@@ -4,6 +4,7 @@ set -euo pipefail
 ROOT_DIR="$(cd "$(dirname "${BASH_SOURCE[0]}")/.." && pwd)"
 cd "${ROOT_DIR}"
 
+./scripts/lint.sh
 ./scripts/build.sh
 ./scripts/gen-bindings.sh
 
@@ -0,0 +1,191 @@
+//! PKCS#8 `OneAsymmetricKey` and SubjectPublicKeyInfo encoding with the FIPS 205 OIDs.
+
+use crate::{
+    DynSigningKey, DynVerifyingKey, ParameterSet, ParameterSetId, Sha2_128f, Sha2_128s, Sha2_192f,
+    Sha2_192s, Sha2_256f, Sha2_256s, Shake128f, Shake128s, Shake192f, Shake192s, Shake256f,
+    Shake256s, SlhDsaError,
+};
+
+#[cfg(any(feature = "x509", feature = "keyfile"))]
+use alloc::vec::Vec;
+
+use pkcs8::der::pem::PemLabel;
+use pkcs8::der::{self, Decode, SecretDocument};
+#[cfg(any(feature = "x509", feature = "keyfile"))]
+use pkcs8::der::{Encode, Header, Length, Tag};
+use pkcs8::spki;
+use pkcs8::{
+    EncodePrivateKey, EncodePublicKey, ObjectIdentifier, PrivateKeyInfoRef, SubjectPublicKeyInfoRef,
+};
+
+impl ParameterSetId {
+    /// `id-slh-dsa-*` algorithm identifier OID for this parameter set.
+    pub const fn oid(self) -> ObjectIdentifier {
+        match self {
+            Self::Shake128s => Shake128s::ALGORITHM_OID,
+            Self::Shake128f => Shake128f::ALGORITHM_OID,
+            Self::Shake192s => Shake192s::ALGORITHM_OID,
+            Self::Shake192f => Shake192f::ALGORITHM_OID,
+            Self::Shake256s => Shake256s::ALGORITHM_OID,
+            Self::Shake256f => Shake256f::ALGORITHM_OID,
+            Self::Sha2_128s => Sha2_128s::ALGORITHM_OID,
+            Self::Sha2_128f => Sha2_128f::ALGORITHM_OID,
+            Self::Sha2_192s => Sha2_192s::ALGORITHM_OID,
+            Self::Sha2_192f => Sha2_192f::ALGORITHM_OID,
+            Self::Sha2_256s => Sha2_256s::ALGORITHM_OID,
+            Self::Sha2_256f => Sha2_256f::ALGORITHM_OID,
+        }
+    }
+
+    /// Parameter set identified by an `id-slh-dsa-*` OID.
+    pub fn from_oid(oid: ObjectIdentifier) -> Option<Self> {
+        Self::ALL.into_iter().find(|param| param.oid() == oid)
+    }
+}
+
+fn parameter_set_for(oid: ObjectIdentifier) -> spki::Result<ParameterSetId> {
+    ParameterSetId::from_oid(oid).ok_or(spki::Error::OidUnknown { oid })
+}
+
+/// Encode `parts` as the contents of a single TLV with the given tag.
+#[cfg(any(feature = "x509", feature = "keyfile"))]
+pub(crate) fn tlv(tag: Tag, parts: &[&[u8]]) -> der::Result<Vec<u8>> {
+    let len: usize = parts.iter().map(|part| part.len()).sum();
+    let mut out = Vec::with_capacity(len + 6);
+    Header::new(tag, Length::try_from(len)?).encode_to_vec(&mut out)?;
+    for part in parts {
+        out.extend_from_slice(part);
+    }
+    Ok(out)
+}
+
+#[cfg(any(feature = "x509", feature = "keyfile"))]
+pub(crate) fn sequence(parts: &[&[u8]]) -> der::Result<Vec<u8>> {
+    tlv(Tag::Sequence, parts)
+}
+
+/// PEM documents start with an encapsulation boundary; anything else is treated as DER.
+pub(crate) fn as_pem(encoded: &[u8]) -> Option<Result<&str, SlhDsaError>> {
+    encoded
+        .trim_ascii_start()
+        .starts_with(b"-----BEGIN ")
+        .then(|| core::str::from_utf8(encoded).map_err(|_| SlhDsaError::MalformedEncoding))
+}
+
+impl TryFrom<PrivateKeyInfoRef<'_>> for DynSigningKey {
+    type Error = pkcs8::Error;
+
+    fn try_from(private_key_info: PrivateKeyInfoRef<'_>) -> pkcs8::Result<Self> {
+        let param = parameter_set_for(private_key_info.algorithm.oid)?;
+        Self::from_bytes(param, private_key_info.private_key.as_bytes())
+            .map_err(|_| pkcs8::KeyError::Invalid.into())
+    }
+}
+
+impl EncodePrivateKey for DynSigningKey {
+    fn to_pkcs8_der(&self) -> pkcs8::Result<SecretDocument> {
+        with_variant!(self, Self, |sk| sk.to_pkcs8_der())
+    }
+}
+
+impl TryFrom<SubjectPublicKeyInfoRef<'_>> for DynVerifyingKey {
+    type Error = spki::Error;
+
+    fn try_from(spki: SubjectPublicKeyInfoRef<'_>) -> spki::Result<Self> {
+        let param = parameter_set_for(spki.algorithm.oid)?;
+        let bytes = spki
+            .subject_public_key
+            .as_bytes()
+            .ok_or(spki::Error::KeyMalformed)?;
+        Self::from_bytes(param, bytes).map_err(|_| spki::Error::KeyMalformed)
+    }
+}
+
+impl EncodePublicKey for DynVerifyingKey {
+    fn to_public_key_der(&self) -> spki::Result<der::Document> {
+        with_variant!(self, Self, |vk| vk.to_public_key_der())
+    }
+}
+
+impl DynSigningKey {
+    /// Decode a PKCS#8 private key in DER or PEM form, taking the parameter set from its OID.
+    pub fn from_pkcs8(encoded: &[u8]) -> Result<Self, SlhDsaError> {
+        let document;
+        let der = match as_pem(encoded) {
+            Some(pem) => {
+                let (label, doc) = SecretDocument::from_pem(pem?)?;
+                PrivateKeyInfoRef::validate_pem_label(label).map_err(der::Error::from)?;
+                document = doc;
+                document.as_bytes()
+            }
+            None => encoded,
+        };
+        Self::from_private_key_info(PrivateKeyInfoRef::from_der(der)?)
+    }
+
+    /// Key in a decoded `PrivateKeyInfo`. Unlike the [`TryFrom`] conversion,
+    /// which can only report a malformed key, this keeps errors such as
+    /// [`SlhDsaError::SelfTestFailed`].
+    pub(crate) fn from_private_key_info(
+        private_key_info: PrivateKeyInfoRef<'_>,
+    ) -> Result<Self, SlhDsaError> {
+        let param = parameter_set_for(private_key_info.algorithm.oid)?;
+        Self::from_bytes(param, private_key_info.private_key.as_bytes())
+    }
+}
+
+impl DynVerifyingKey {
+    /// Decode a SubjectPublicKeyInfo in DER or PEM form, taking the parameter set from its OID.
+    pub fn from_spki(encoded: &[u8]) -> Result<Self, SlhDsaError> {
+        let document;
+        let der = match as_pem(encoded) {
+            Some(pem) => {
+                let (label, doc) = der::Document::from_pem(pem?)?;
+                SubjectPublicKeyInfoRef::validate_pem_label(label).map_err(der::Error::from)?;
+                document = doc;
+                document.as_bytes()
+            }
+            None => encoded,
+        };
+        Self::from_public_key_info(SubjectPublicKeyInfoRef::from_der(der)?)
+    }
+
+    /// Key in a decoded SubjectPublicKeyInfo, keeping errors such as
+    /// [`SlhDsaError::SelfTestFailed`] that the [`TryFrom`] conversion cannot.
+    pub(crate) fn from_public_key_info(
+        spki: SubjectPublicKeyInfoRef<'_>,
+    ) -> Result<Self, SlhDsaError> {
+        let param = parameter_set_for(spki.algorithm.oid)?;
+        let bytes = spki
+            .subject_public_key
+            .as_bytes()
+            .ok_or(SlhDsaError::MalformedVerifyingKey)?;
+        Self::from_bytes(param, bytes)
+    }
+}
+
+impl From<pkcs8::Error> for SlhDsaError {
+    fn from(err: pkcs8::Error) -> Self {
+        match err {
+            pkcs8::Error::PublicKey(err) => err.into(),
+            pkcs8::Error::KeyMalformed(_) => Self::MalformedSigningKey,
+            _ => Self::MalformedEncoding,
+        }
+    }
+}
+
+impl From<der::Error> for SlhDsaError {
+    fn from(_: der::Error) -> Self {
+        Self::MalformedEncoding
+    }
+}
+
+impl From<spki::Error> for SlhDsaError {
+    fn from(err: spki::Error) -> Self {
+        match err {
+            spki::Error::OidUnknown { .. } => Self::UnsupportedAlgorithm,
+            spki::Error::KeyMalformed => Self::MalformedVerifyingKey,
+            _ => Self::MalformedEncoding,
+        }
+    }
+}
@@ -0,0 +1,72 @@
+//! Sign or verify many messages in one call, in parallel when the `parallel` feature is enabled.
+
+use crate::error::check_context;
+use crate::secret::Secret;
+use crate::{DynSignature, DynSigningKey, DynVerifyingKey, SlhDsaError};
+
+use alloc::vec;
+use alloc::vec::Vec;
+use rand_core::TryCryptoRng;
+
+/// One signature to check in [`verify_batch`]; items may use different parameter sets.
+#[derive(Copy, Clone, Debug)]
+pub struct BatchVerifyItem<'a> {
+    pub verifying_key: &'a DynVerifyingKey,
+    pub msg: &'a [u8],
+    pub ctx: &'a [u8],
+    pub signature: &'a DynSignature,
+}
+
+impl BatchVerifyItem<'_> {
+    fn verify(&self) -> Result<(), SlhDsaError> {
+        self.verifying_key
+            .verify(self.msg, self.ctx, self.signature)
+    }
+}
+
+/// Verify every item and return one result per item, in input order.
+///
+/// With the `parallel` feature the items are spread across the rayon global thread pool.
+pub fn verify_batch(items: &[BatchVerifyItem<'_>]) -> Vec<Result<(), SlhDsaError>> {
+    #[cfg(feature = "parallel")]
+    {
+        use rayon::prelude::*;
+        items.par_iter().map(BatchVerifyItem::verify).collect()
+    }
+    #[cfg(not(feature = "parallel"))]
+    {
+        items.iter().map(BatchVerifyItem::verify).collect()
+    }
+}
+
+/// Sign every message with one key and context, returning signatures in input order.
+///
+/// The per-message randomizers are drawn from `rng` up front, so only the signing
+/// itself runs on the rayon thread pool when the `parallel` feature is enabled.
+pub fn sign_batch<R: TryCryptoRng>(
+    signing_key: &DynSigningKey,
+    rng: &mut R,
+    msgs: &[&[u8]],
+    ctx: &[u8],
+) -> Result<Vec<DynSignature>, SlhDsaError> {
+    check_context(ctx)?;
+    let n = crate::seed_len_for(signing_key.parameter_set());
+    let mut randomizers = Secret(vec![0u8; n * msgs.len()]);
+    rng.try_fill_bytes(&mut randomizers)
+        .map_err(|_| SlhDsaError::RngFailure)?;
+
+    let sign =
+        |(msg, opt_rand): (&&[u8], &[u8])| signing_key.sign_with_opt_rand(msg, ctx, Some(opt_rand));
+    #[cfg(feature = "parallel")]
+    {
+        use rayon::prelude::*;
+        msgs.par_iter()
+            .zip(randomizers.par_chunks(n))
+            .map(sign)
+            .collect()
+    }
+    #[cfg(not(feature = "parallel"))]
+    {
+        msgs.iter().zip(randomizers.chunks(n)).map(sign).collect()
+    }
+}
@@ -0,0 +1,740 @@
+//! CMS `SignedData` (RFC 5652) with SLH-DSA signers, following RFC 9814.
+//!
+//! Signatures are pure SLH-DSA with an empty context, computed over the DER
+//! `SignedAttributes` when they are present and over the content otherwise.
+//! The message digest in the signed attributes uses the hash function RFC 9814
+//! pairs with the signer's parameter set.
+
+use crate::x509::{
+    algorithm_identifier, context_specific, parameter_set_of, sequence, tlv, Certificate, Name,
+};
+use crate::{prehash, DynSignature, DynSigningKey, ParameterSetId, PreHashAlgorithm, SlhDsaError};
+
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+
+use pkcs8::der::asn1::{AnyRef, OctetStringRef, UintRef};
+use pkcs8::der::pem::LineEnding;
+use pkcs8::der::{self, Decode, Document, Encode, Header, Reader, SliceReader, Tag, TagNumber};
+use pkcs8::spki::AlgorithmIdentifierRef;
+use pkcs8::ObjectIdentifier;
+use rand_core::TryCryptoRng;
+
+/// `id-data`, the content type for arbitrary octets.
+pub const ID_DATA: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.840.113549.1.7.1");
+const ID_SIGNED_DATA: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.840.113549.1.7.2");
+const CONTENT_TYPE_ATTRIBUTE: ObjectIdentifier =
+    ObjectIdentifier::new_unwrap("1.2.840.113549.1.9.3");
+const MESSAGE_DIGEST_ATTRIBUTE: ObjectIdentifier =
+    ObjectIdentifier::new_unwrap("1.2.840.113549.1.9.4");
+
+/// PEM label for CMS structures (RFC 7468 section 9).
+const PEM_LABEL: &str = "CMS";
+
+/// Digest algorithm RFC 9814 section 4 pairs with each parameter set.
+pub const fn digest_algorithm(param: ParameterSetId) -> PreHashAlgorithm {
+    match param {
+        ParameterSetId::Sha2_128s | ParameterSetId::Sha2_128f => PreHashAlgorithm::Sha256,
+        ParameterSetId::Sha2_192s
+        | ParameterSetId::Sha2_192f
+        | ParameterSetId::Sha2_256s
+        | ParameterSetId::Sha2_256f => PreHashAlgorithm::Sha512,
+        ParameterSetId::Shake128s | ParameterSetId::Shake128f => PreHashAlgorithm::Shake128,
+        ParameterSetId::Shake192s
+        | ParameterSetId::Shake192f
+        | ParameterSetId::Shake256s
+        | ParameterSetId::Shake256f => PreHashAlgorithm::Shake256,
+    }
+}
+
+fn digest_algorithm_from(
+    algorithm: &AlgorithmIdentifierRef<'_>,
+) -> Result<PreHashAlgorithm, SlhDsaError> {
+    // RFC 5754 allows an explicit NULL for the SHA-2 parameters.
+    if algorithm
+        .parameters
+        .is_some_and(|params| params != AnyRef::NULL)
+    {
+        return Err(SlhDsaError::UnsupportedAlgorithm);
+    }
+    let oid = algorithm.oid.to_der()?;
+    [
+        PreHashAlgorithm::Sha256,
+        PreHashAlgorithm::Sha512,
+        PreHashAlgorithm::Shake128,
+        PreHashAlgorithm::Shake256,
+    ]
+    .into_iter()
+    .find(|candidate| candidate.oid_der() == oid.as_slice())
+    .ok_or(SlhDsaError::UnsupportedAlgorithm)
+}
+
+/// DER `SET OF`: elements sorted by their encodings.
+fn set_of(tag: Tag, mut elements: Vec<Vec<u8>>) -> der::Result<Vec<u8>> {
+    elements.sort();
+    elements.dedup();
+    let elements: Vec<&[u8]> = elements.iter().map(Vec::as_slice).collect();
+    tlv(tag, &elements)
+}
+
+fn attribute(oid: ObjectIdentifier, value: &[u8]) -> der::Result<Vec<u8>> {
+    sequence(&[&oid.to_der()?, &tlv(Tag::Set, &[value])?])
+}
+
+/// How a `SignerInfo` names the certificate of its signer.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum SignerIdentifier {
+    /// Issuer name and serial number of the signer's certificate.
+    IssuerAndSerialNumber {
+        /// Certificate issuer.
+        issuer: Name,
+        /// Certificate serial number without leading zero octets.
+        serial_number: Vec<u8>,
+    },
+    /// `SubjectKeyIdentifier` of the signer's certificate.
+    SubjectKeyIdentifier(Vec<u8>),
+}
+
+impl SignerIdentifier {
+    /// Whether `certificate` is the one named.
+    pub fn matches(&self, certificate: &Certificate) -> bool {
+        match self {
+            Self::IssuerAndSerialNumber {
+                issuer,
+                serial_number,
+            } => certificate.issuer() == issuer && certificate.serial_number() == serial_number,
+            Self::SubjectKeyIdentifier(key_id) => certificate.subject_key_id() == Some(key_id),
+        }
+    }
+}
+
+/// One signer of a [`SignedData`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SignerInfo {
+    sid: SignerIdentifier,
+    digest_algorithm: PreHashAlgorithm,
+    /// `SignedAttributes` re-tagged as a SET, exactly as signed.
+    signed_attributes: Option<Vec<u8>>,
+    content_type: Option<ObjectIdentifier>,
+    message_digest: Option<Vec<u8>>,
+    signature_param: ParameterSetId,
+    signature: Vec<u8>,
+}
+
+impl SignerInfo {
+    /// Certificate the signer claims to hold.
+    pub fn signer_identifier(&self) -> &SignerIdentifier {
+        &self.sid
+    }
+
+    /// Digest algorithm used for the message-digest attribute.
+    pub fn digest_algorithm(&self) -> PreHashAlgorithm {
+        self.digest_algorithm
+    }
+
+    /// Parameter set of the signature.
+    pub fn signature_parameter_set(&self) -> ParameterSetId {
+        self.signature_param
+    }
+
+    /// Whether the signature covers signed attributes rather than the content itself.
+    pub fn has_signed_attributes(&self) -> bool {
+        self.signed_attributes.is_some()
+    }
+
+    fn verify(
+        &self,
+        content_type: ObjectIdentifier,
+        content: &[u8],
+        signer: &Certificate,
+    ) -> Result<(), SlhDsaError> {
+        let key = signer.verifying_key();
+        if key.parameter_set() != self.signature_param {
+            return Err(SlhDsaError::ParameterSetMismatch);
+        }
+        let signature = DynSignature::from_bytes(self.signature_param, &self.signature)?;
+
+        match &self.signed_attributes {
+            Some(signed_attributes) => {
+                let digest = prehash(self.digest_algorithm, content);
+                if self.content_type != Some(content_type)
+                    || self.message_digest.as_deref() != Some(digest.as_bytes())
+                {
+                    return Err(SlhDsaError::VerificationFailed);
+                }
+                key.verify(signed_attributes, &[], &signature)
+            }
+            // RFC 5652 section 5.3: other content types require signed attributes.
+            None if content_type != ID_DATA => Err(SlhDsaError::MalformedEncoding),
+            None => key.verify(content, &[], &signature),
+        }
+    }
+
+    fn decode_from<'a, R: Reader<'a>>(reader: &mut R) -> Result<Self, SlhDsaError> {
+        reader.sequence(|info| {
+            u8::decode(info)?;
+            let sid = if Tag::peek(info)? == Tag::Sequence {
+                info.sequence(|sid| {
+                    Ok::<_, SlhDsaError>(SignerIdentifier::IssuerAndSerialNumber {
+                        issuer: Name::from_der(sid.tlv_bytes()?)?,
+                        serial_number: UintRef::decode(sid)?.as_bytes().to_vec(),
+                    })
+                })?
+            } else {
+                let header = Header::decode(info)?;
+                if header.tag() != TagNumber(0).context_specific(false) {
+                    return Err(SlhDsaError::MalformedEncoding);
+                }
+                SignerIdentifier::SubjectKeyIdentifier(info.read_slice(header.length())?.to_vec())
+            };
+            let digest_algorithm = digest_algorithm_from(&AlgorithmIdentifierRef::decode(info)?)?;
+
+            let mut signer_info = Self {
+                sid,
+                digest_algorithm,
+                signed_attributes: None,
+                content_type: None,
+                message_digest: None,
+                signature_param: ParameterSetId::Shake128s,
+                signature: Vec::new(),
+            };
+            if Tag::peek(info)? == TagNumber(0).context_specific(true) {
+                let mut signed_attributes = info.tlv_bytes()?.to_vec();
+                // The signature covers the EXPLICIT SET OF encoding (RFC 5652 section 5.4).
+                signed_attributes[0] = 0x31;
+                signer_info.decode_signed_attributes(&signed_attributes)?;
+                signer_info.signed_attributes = Some(signed_attributes);
+            }
+
+            signer_info.signature_param = parameter_set_of(&AlgorithmIdentifierRef::decode(info)?)?;
+            let signature = <&OctetStringRef>::decode(info)?.as_bytes();
+            if signature.len() != crate::sig_len_for(signer_info.signature_param) {
+                return Err(SlhDsaError::MalformedSignature);
+            }
+            signer_info.signature = signature.to_vec();
+            if !info.is_finished() {
+                // unsignedAttrs
+                info.tlv_bytes()?;
+            }
+            Ok(signer_info)
+        })
+    }
+
+    fn decode_signed_attributes(&mut self, der: &[u8]) -> Result<(), SlhDsaError> {
+        let mut reader = SliceReader::new(der)?;
+        let header = Header::decode(&mut reader)?;
+        let mut seen = Vec::new();
+        reader.read_nested(header.length(), |attributes| {
+            while !attributes.is_finished() {
+                attributes.sequence(|attribute| {
+                    let oid = ObjectIdentifier::decode(attribute)?;
+                    if seen.contains(&oid) {
+                        return Err(SlhDsaError::MalformedEncoding);
+                    }
+                    seen.push(oid);
+
+                    let values = Header::decode(attribute)?;
+                    if values.tag() != Tag::Set {
+                        return Err(SlhDsaError::MalformedEncoding);
+                    }
+                    attribute.read_nested(values.length(), |value| {
+                        match oid {
+                            CONTENT_TYPE_ATTRIBUTE => {
+                                self.content_type = Some(ObjectIdentifier::decode(value)?)
+                            }
+                            MESSAGE_DIGEST_ATTRIBUTE => {
+                                let digest = <&OctetStringRef>::decode(value)?;
+                                self.message_digest = Some(digest.as_bytes().to_vec())
+                            }
+                            _ => {
+                                value.read_slice(values.length())?;
+                            }
+                        }
+                        Ok::<_, SlhDsaError>(())
+                    })
+                })?;
+            }
+            Ok::<_, SlhDsaError>(())
+        })?;
+        reader.finish()?;
+
+        // Both attributes are mandatory once any signed attribute is present.
+        if self.content_type.is_none() || self.message_digest.is_none() {
+            return Err(SlhDsaError::MalformedEncoding);
+        }
+        Ok(())
+    }
+}
+
+/// Builds a `SignedData` with a single SLH-DSA signer.
+#[derive(Clone, Debug)]
+pub struct SignedDataBuilder {
+    content_type: ObjectIdentifier,
+    content: Vec<u8>,
+    detached: bool,
+    signed_attributes: bool,
+    certificates: Vec<Certificate>,
+}
+
+impl SignedDataBuilder {
+    /// Sign `content` as `id-data`, encapsulated, with signed attributes.
+    pub fn new(content: &[u8]) -> Self {
+        Self {
+            content_type: ID_DATA,
+            content: content.to_vec(),
+            detached: false,
+            signed_attributes: true,
+            certificates: Vec::new(),
+        }
+    }
+
+    /// Use a content type other than `id-data`, e.g. `id-ct-firmwarePackage`.
+    pub fn content_type(mut self, content_type: ObjectIdentifier) -> Self {
+        self.content_type = content_type;
+        self
+    }
+
+    /// Leave the content out of the `SignedData`; verifiers must supply it.
+    pub fn detached(mut self, detached: bool) -> Self {
+        self.detached = detached;
+        self
+    }
+
+    /// Sign the content directly instead of over signed attributes.
+    ///
+    /// Only allowed for `id-data` content.
+    pub fn signed_attributes(mut self, signed_attributes: bool) -> Self {
+        self.signed_attributes = signed_attributes;
+        self
+    }
+
+    /// Embed an additional certificate, typically an intermediate CA.
+    pub fn certificate(mut self, certificate: Certificate) -> Self {
+        self.certificates.push(certificate);
+        self
+    }
+
+    /// Sign as the holder of `signer`, embedding that certificate.
+    pub fn sign<R: TryCryptoRng>(
+        self,
+        signer: &Certificate,
+        signing_key: &DynSigningKey,
+        rng: &mut R,
+    ) -> Result<SignedData, SlhDsaError> {
+        if signing_key.verifying_key() != *signer.verifying_key() {
+            return Err(SlhDsaError::InvalidCertificate);
+        }
+        if !self.signed_attributes && self.content_type != ID_DATA {
+            return Err(SlhDsaError::MalformedEncoding);
+        }
+
+        let param = signing_key.parameter_set();
+        let digest_algorithm = digest_algorithm(param);
+        let digest_algorithm_id = sequence(&[digest_algorithm.oid_der()])?;
+
+        let signed_attributes = if self.signed_attributes {
+            let digest = prehash(digest_algorithm, &self.content);
+            let mut attributes = [
+                attribute(CONTENT_TYPE_ATTRIBUTE, &self.content_type.to_der()?)?,
+                attribute(
+                    MESSAGE_DIGEST_ATTRIBUTE,
+                    &<&OctetStringRef>::try_from(digest.as_bytes())?.to_der()?,
+                )?,
+            ];
+            attributes.sort();
+            Some(attributes)
+        } else {
+            None
+        };
+
+        let (signature, signed_attributes) = match &signed_attributes {
+            Some(attributes) => {
+                let parts = [attributes[0].as_slice(), attributes[1].as_slice()];
+                let signature = signing_key.try_sign_with_rng(rng, &tlv(Tag::Set, &parts)?, &[])?;
+                (signature, context_specific(0, true, &parts)?)
+            }
+            None => (
+                signing_key.try_sign_with_rng(rng, &self.content, &[])?,
+                Vec::new(),
+            ),
+        };
+
+        let sid = sequence(&[
+            &signer.issuer().to_der()?,
+            &UintRef::new(signer.serial_number())?.to_der()?,
+        ])?;
+        let signer_info = sequence(&[
+            &1u8.to_der()?,
+            &sid,
+            &digest_algorithm_id,
+            &signed_attributes,
+            &algorithm_identifier(param)?,
+            &<&OctetStringRef>::try_from(signature.to_vec().as_slice())?.to_der()?,
+        ])?;
+
+        let content = if self.detached {
+            Vec::new()
+        } else {
+            let content = <&OctetStringRef>::try_from(self.content.as_slice())?.to_der()?;
+            context_specific(0, true, &[&content])?
+        };
+        let encapsulated = sequence(&[&self.content_type.to_der()?, &content])?;
+
+        let mut certificates = vec![signer.to_der().to_vec()];
+        certificates.extend(self.certificates.iter().map(|cert| cert.to_der().to_vec()));
+        let certificates = set_of(TagNumber(0).context_specific(true), certificates)?;
+
+        let version: u8 = if self.content_type == ID_DATA { 1 } else { 3 };
+        let signed_data = sequence(&[
+            &version.to_der()?,
+            &set_of(Tag::Set, vec![digest_algorithm_id])?,
+            &encapsulated,
+            &certificates,
+            &set_of(Tag::Set, vec![signer_info])?,
+        ])?;
+        let content_info = sequence(&[
+            &ID_SIGNED_DATA.to_der()?,
+            &context_specific(0, true, &[&signed_data])?,
+        ])?;
+        SignedData::from_der(&content_info)
+    }
+}
+
+/// Parsed CMS `ContentInfo` holding a `SignedData`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SignedData {
+    der: Vec<u8>,
+    content_type: ObjectIdentifier,
+    content: Option<Vec<u8>>,
+    certificates: Vec<Certificate>,
+    signer_infos: Vec<SignerInfo>,
+}
+
+impl SignedData {
+    /// Decode a `ContentInfo` in DER or PEM form.
+    pub fn decode(encoded: &[u8]) -> Result<Self, SlhDsaError> {
+        match crate::asn1::as_pem(encoded) {
+            Some(pem) => Self::from_pem(pem?),
+            None => Self::from_der(encoded),
+        }
+    }
+
+    /// Decode a PEM `CMS` document.
+    pub fn from_pem(pem: &str) -> Result<Self, SlhDsaError> {
+        let (label, doc) = Document::from_pem(pem)?;
+        if label != PEM_LABEL {
+            return Err(SlhDsaError::MalformedEncoding);
+        }
+        Self::from_der(doc.as_bytes())
+    }
+
+    /// Decode a DER `ContentInfo`. Signatures are not checked; call [`Self::verify`].
+    ///
+    /// Embedded certificates that are not SLH-DSA certificates are skipped.
+    pub fn from_der(der: &[u8]) -> Result<Self, SlhDsaError> {
+        let mut reader = SliceReader::new(der)?;
+        let signed_data = reader.sequence(|content_info| {
+            if ObjectIdentifier::decode(content_info)? != ID_SIGNED_DATA {
+                return Err(SlhDsaError::UnsupportedAlgorithm);
+            }
+            let header = Header::decode(content_info)?;
+            if header.tag() != TagNumber(0).context_specific(true) {
+                return Err(SlhDsaError::MalformedEncoding);
+            }
+            content_info.read_nested(header.length(), |r| Self::decode_signed_data(r, der))
+        })?;
+        reader.finish()?;
+        Ok(signed_data)
+    }
+
+    fn decode_signed_data<'a, R: Reader<'a>>(
+        reader: &mut R,
+        der: &[u8],
+    ) -> Result<Self, SlhDsaError> {
+        reader.sequence(|signed_data| {
+            u8::decode(signed_data)?;
+            let digest_algorithms = Header::decode(signed_data)?;
+            if digest_algorithms.tag() != Tag::Set {
+                return Err(SlhDsaError::MalformedEncoding);
+            }
+            signed_data.read_slice(digest_algorithms.length())?;
+
+            let (content_type, content) = signed_data.sequence(|encapsulated| {
+                let content_type = ObjectIdentifier::decode(encapsulated)?;
+                let content = if encapsulated.is_finished() {
+                    None
+                } else {
+                    let header = Header::decode(encapsulated)?;
+                    if header.tag() != TagNumber(0).context_specific(true) {
+                        return Err(SlhDsaError::MalformedEncoding);
+                    }
+                    let content = encapsulated.read_nested(header.length(), |r| {
+                        <&OctetStringRef>::decode(r).map(|octets| octets.as_bytes().to_vec())
+                    })?;
+                    Some(content)
+                };
+                Ok::<_, SlhDsaError>((content_type, content))
+            })?;
+
+            let mut certificates = Vec::new();
+            while Tag::peek(signed_data)? != Tag::Set {
+                let header = Header::decode(signed_data)?;
+                if header.tag() != TagNumber(0).context_specific(true) {
+                    // crls
+                    signed_data.read_slice(header.length())?;
+                    continue;
+                }
+                signed_data.read_nested(header.length(), |set| {
+                    while !set.is_finished() {
+                        let is_certificate = Tag::peek(set)? == Tag::Sequence;
+                        let encoded = set.tlv_bytes()?;
+                        if !is_certificate {
+                            continue;
+                        }
+                        match Certificate::from_der(encoded) {
+                            Ok(certificate) => certificates.push(certificate),
+                            Err(SlhDsaError::UnsupportedAlgorithm) => {}
+                            Err(err) => return Err(err),
+                        }
+                    }
+                    Ok(())
+                })?;
+            }
+
+            let signer_infos = Header::decode(signed_data)?;
+            let signer_infos = signed_data.read_nested(signer_infos.length(), |set| {
+                let mut signer_infos = Vec::new();
+                while !set.is_finished() {
+                    signer_infos.push(SignerInfo::decode_from(set)?);
+                }
+                Ok::<_, SlhDsaError>(signer_infos)
+            })?;
+
+            Ok(Self {
+                der: der.to_vec(),
+                content_type,
+                content,
+                certificates,
+                signer_infos,
+            })
+        })
+    }
+
+    /// DER encoding of the `ContentInfo`.
+    pub fn to_der(&self) -> &[u8] {
+        &self.der
+    }
+
+    /// PEM encoding with LF line endings.
+    pub fn to_pem(&self) -> Result<String, SlhDsaError> {
+        Ok(
+            der::pem::encode_string(PEM_LABEL, LineEnding::LF, &self.der)
+                .map_err(der::Error::from)?,
+        )
+    }
+
+    /// Type of the signed content.
+    pub fn content_type(&self) -> ObjectIdentifier {
+        self.content_type
+    }
+
+    /// Encapsulated content, or `None` for a detached signature.
+    pub fn content(&self) -> Option<&[u8]> {
+        self.content.as_deref()
+    }
+
+    /// Embedded SLH-DSA certificates.
+    pub fn certificates(&self) -> &[Certificate] {
+        &self.certificates
+    }
+
+    /// Signers, in encoding order.
+    pub fn signer_infos(&self) -> &[SignerInfo] {
+        &self.signer_infos
+    }
+
+    /// Check every signer's signature and return their certificates.
+    ///
+    /// Pass the content for a detached signature. Signer certificates are
+    /// looked up among the embedded ones and `extra_certificates`; the caller
+    /// still decides whether to trust them, e.g. with
+    /// [`verify_chain`](crate::x509::verify_chain).
+    pub fn verify(
+        &self,
+        detached_content: Option<&[u8]>,
+        extra_certificates: &[Certificate],
+    ) -> Result<Vec<Certificate>, SlhDsaError> {
+        let content = match (self.content.as_deref(), detached_content) {
+            (Some(content), None) | (None, Some(content)) => content,
+            (Some(content), Some(detached)) if content == detached => content,
+            _ => return Err(SlhDsaError::MalformedEncoding),
+        };
+        if self.signer_infos.is_empty() {
+            return Err(SlhDsaError::VerificationFailed);
+        }
+
+        self.signer_infos
+            .iter()
+            .map(|signer_info| {
+                let signer = self
+                    .certificates
+                    .iter()
+                    .chain(extra_certificates)
+                    .find(|cert| signer_info.sid.matches(cert))
+                    .ok_or(SlhDsaError::UntrustedChain)?;
+                signer_info.verify(self.content_type, content, signer)?;
+                Ok(signer.clone())
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_rng::TestRng;
+    use crate::x509::{verify_chain, CertificateBuilder};
+    use core::slice::from_ref;
+    use core::time::Duration;
+
+    const OPENSSL_ROOT: &[u8] = include_bytes!("../tests/vectors/openssl/root.der");
+    const OPENSSL_LEAF: &[u8] = include_bytes!("../tests/vectors/openssl/leaf.der");
+    const OPENSSL_CONTENT: &[u8] = include_bytes!("../tests/vectors/openssl/content.txt");
+    const OPENSSL_DETACHED: &[u8] = include_bytes!("../tests/vectors/openssl/cms-detached.der");
+    const OPENSSL_ATTACHED: &[u8] = include_bytes!("../tests/vectors/openssl/cms-attached.der");
+
+    const CONTENT: &[u8] = b"firmware image";
+    /// 2030-01-01T00:00:00Z.
+    const NOW: Duration = Duration::from_secs(1_893_456_000);
+
+    struct Signer {
+        root: Certificate,
+        cert: Certificate,
+        sk: DynSigningKey,
+    }
+
+    fn signer(rng: &mut TestRng) -> Signer {
+        let (not_before, not_after) = (Duration::ZERO, Duration::from_secs(2_524_607_999));
+        let root_sk = DynSigningKey::try_generate(ParameterSetId::Shake128f, rng).unwrap();
+        let root = CertificateBuilder::new(
+            Name::new().with_common_name("Root").unwrap(),
+            root_sk.verifying_key(),
+            &[1],
+            not_before,
+            not_after,
+        )
+        .certificate_authority(None)
+        .self_sign(&root_sk, rng)
+        .unwrap();
+        let sk = DynSigningKey::try_generate(ParameterSetId::Sha2_128f, rng).unwrap();
+        let cert = CertificateBuilder::new(
+            Name::new().with_common_name("Signer").unwrap(),
+            sk.verifying_key(),
+            &[2],
+            not_before,
+            not_after,
+        )
+        .sign(&root, &root_sk, rng)
+        .unwrap();
+        Signer { root, cert, sk }
+    }
+
+    #[test]
+    fn detached_with_signed_attributes_round_trips() {
+        let mut rng = TestRng::new(15);
+        let signer = signer(&mut rng);
+        let signed = SignedDataBuilder::new(CONTENT)
+            .detached(true)
+            .sign(&signer.cert, &signer.sk, &mut rng)
+            .unwrap();
+
+        let decoded = SignedData::decode(signed.to_der()).unwrap();
+        assert_eq!(decoded, signed);
+        assert_eq!(decoded.content(), None);
+        let signer_info = &decoded.signer_infos()[0];
+        assert!(signer_info.has_signed_attributes());
+        assert_eq!(signer_info.digest_algorithm(), PreHashAlgorithm::Sha256);
+        assert!(signer_info.signer_identifier().matches(&signer.cert));
+
+        let signers = decoded.verify(Some(CONTENT), &[]).unwrap();
+        assert_eq!(signers, [signer.cert]);
+        assert_eq!(verify_chain(&signers, from_ref(&signer.root), NOW), Ok(()));
+        assert_eq!(
+            decoded.verify(Some(b"firmware imagE"), &[]),
+            Err(SlhDsaError::VerificationFailed)
+        );
+        assert_eq!(
+            decoded.verify(None, &[]),
+            Err(SlhDsaError::MalformedEncoding)
+        );
+    }
+
+    #[test]
+    fn encapsulated_with_signed_attributes_round_trips() {
+        let mut rng = TestRng::new(15);
+        let signer = signer(&mut rng);
+        let signed = SignedDataBuilder::new(CONTENT)
+            .sign(&signer.cert, &signer.sk, &mut rng)
+            .unwrap();
+
+        let decoded = SignedData::decode(signed.to_pem().unwrap().as_bytes()).unwrap();
+        assert_eq!(decoded.content(), Some(CONTENT));
+        assert_eq!(decoded.content_type(), ID_DATA);
+        assert!(decoded.signer_infos()[0].has_signed_attributes());
+        assert_eq!(decoded.verify(None, &[]).unwrap(), from_ref(&signer.cert));
+        assert!(decoded.verify(Some(CONTENT), &[]).is_ok());
+        assert_eq!(
+            decoded.verify(Some(b"other"), &[]),
+            Err(SlhDsaError::MalformedEncoding)
+        );
+
+        let mut der = signed.to_der().to_vec();
+        let at = der
+            .windows(CONTENT.len())
+            .position(|window| window == CONTENT)
+            .unwrap();
+        der[at] ^= 0x20;
+        assert_eq!(
+            SignedData::from_der(&der).unwrap().verify(None, &[]),
+            Err(SlhDsaError::VerificationFailed)
+        );
+    }
+
+    #[test]
+    fn content_without_signed_attributes_verifies() {
+        let mut rng = TestRng::new(15);
+        let signer = signer(&mut rng);
+        let signed = SignedDataBuilder::new(CONTENT)
+            .signed_attributes(false)
+            .sign(&signer.cert, &signer.sk, &mut rng)
+            .unwrap();
+
+        assert!(!signed.signer_infos()[0].has_signed_attributes());
+        assert!(signed.verify(None, &[]).is_ok());
+    }
+
+    #[test]
+    fn openssl_signed_data_verifies() {
+        let root = Certificate::from_der(OPENSSL_ROOT).unwrap();
+        let leaf = Certificate::from_der(OPENSSL_LEAF).unwrap();
+
+        let detached = SignedData::from_der(OPENSSL_DETACHED).unwrap();
+        assert_eq!(detached.content(), None);
+        let attached = SignedData::from_der(OPENSSL_ATTACHED).unwrap();
+        assert_eq!(attached.content(), Some(OPENSSL_CONTENT));
+
+        for (signed, content) in [(&detached, Some(OPENSSL_CONTENT)), (&attached, None)] {
+            let signer_info = &signed.signer_infos()[0];
+            assert!(signer_info.has_signed_attributes());
+            assert_eq!(signer_info.digest_algorithm(), PreHashAlgorithm::Sha256);
+            let signers = signed.verify(content, &[]).unwrap();
+            assert_eq!(signers, from_ref(&leaf));
+            assert_eq!(verify_chain(&signers, from_ref(&root), NOW), Ok(()));
+        }
+        assert_eq!(
+            detached.verify(Some(b"tampered"), &[]),
+            Err(SlhDsaError::VerificationFailed)
+        );
+    }
+}
@@ -0,0 +1,546 @@
+//! COSE_Key and COSE_Sign1 (RFC 9052) encoding for SLH-DSA.
+//!
+//! Keys use the `AKP` key type from the COSE post-quantum drafts, with the raw
+//! FIPS 205 encodings in the `pub` and `priv` parameters. Algorithm identifiers
+//! follow draft-ietf-cose-sphincs-plus and are provisional until IANA assigns
+//! them; parameter sets the draft does not list have no COSE identifier.
+//! COSE_Sign1 signatures are pure SLH-DSA with an empty context over the
+//! `Sig_structure`.
+
+use crate::{DynSignature, DynSigningKey, DynVerifyingKey, ParameterSetId, SlhDsaError};
+
+use alloc::boxed::Box;
+use alloc::vec;
+use alloc::vec::Vec;
+
+use ciborium::Value;
+use rand_core::TryCryptoRng;
+
+/// COSE key type for algorithm key pairs (`AKP`).
+pub const KTY_AKP: i64 = 7;
+
+/// CBOR tag of a COSE_Sign1 message.
+pub const COSE_SIGN1_TAG: u64 = 18;
+
+const KEY_KTY: i64 = 1;
+const KEY_ALG: i64 = 3;
+const KEY_PUB: i64 = -1;
+const KEY_PRIV: i64 = -2;
+
+const HEADER_ALG: i64 = 1;
+const HEADER_KID: i64 = 4;
+
+impl ParameterSetId {
+    /// COSE algorithm identifier, if the draft assigns one to this parameter set.
+    pub const fn cose_algorithm(self) -> Option<i64> {
+        match self {
+            Self::Sha2_128s => Some(-51),
+            Self::Shake128s => Some(-52),
+            Self::Sha2_128f => Some(-53),
+            _ => None,
+        }
+    }
+
+    /// Parameter set identified by a COSE algorithm identifier.
+    pub fn from_cose_algorithm(alg: i64) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|param| param.cose_algorithm() == Some(alg))
+    }
+}
+
+fn cose_algorithm(param: ParameterSetId) -> Result<i64, SlhDsaError> {
+    param
+        .cose_algorithm()
+        .ok_or(SlhDsaError::UnsupportedAlgorithm)
+}
+
+fn encode(value: &Value) -> Result<Vec<u8>, SlhDsaError> {
+    let mut encoded = Vec::new();
+    ciborium::into_writer(value, &mut encoded).map_err(|_| SlhDsaError::MalformedEncoding)?;
+    Ok(encoded)
+}
+
+fn decode(mut encoded: &[u8]) -> Result<Value, SlhDsaError> {
+    let value = ciborium::from_reader(&mut encoded).map_err(|_| SlhDsaError::MalformedEncoding)?;
+    if !encoded.is_empty() {
+        return Err(SlhDsaError::MalformedEncoding);
+    }
+    Ok(value)
+}
+
+fn int(value: i64) -> Value {
+    Value::Integer(value.into())
+}
+
+fn as_int(value: &Value) -> Result<i64, SlhDsaError> {
+    value
+        .as_integer()
+        .and_then(|value| i64::try_from(value).ok())
+        .ok_or(SlhDsaError::MalformedEncoding)
+}
+
+fn as_bytes(value: &Value) -> Result<&[u8], SlhDsaError> {
+    value
+        .as_bytes()
+        .map(Vec::as_slice)
+        .ok_or(SlhDsaError::MalformedEncoding)
+}
+
+/// Map entries with integer labels; duplicate labels are rejected (RFC 9052 section 14).
+fn labels(value: &Value) -> Result<Vec<(i64, &Value)>, SlhDsaError> {
+    let entries = value.as_map().ok_or(SlhDsaError::MalformedEncoding)?;
+    let mut labels: Vec<(i64, &Value)> = Vec::with_capacity(entries.len());
+    for (label, value) in entries {
+        // Text labels are private-use; none of them matter here.
+        if label.is_text() {
+            continue;
+        }
+        let label = as_int(label)?;
+        if labels.iter().any(|(seen, _)| *seen == label) {
+            return Err(SlhDsaError::MalformedEncoding);
+        }
+        labels.push((label, value));
+    }
+    Ok(labels)
+}
+
+fn lookup<'a>(labels: &[(i64, &'a Value)], label: i64) -> Option<&'a Value> {
+    labels
+        .iter()
+        .find(|(candidate, _)| *candidate == label)
+        .map(|(_, value)| *value)
+}
+
+/// Decoded AKP key: parameter set, `pub` and `priv`.
+type AkpKey = (ParameterSetId, Option<Vec<u8>>, Option<Vec<u8>>);
+
+fn encode_key(
+    param: ParameterSetId,
+    public: &[u8],
+    private: Option<&[u8]>,
+) -> Result<Vec<u8>, SlhDsaError> {
+    // Core deterministic encoding order: 1, 3, -1, -2.
+    let mut entries = vec![
+        (int(KEY_KTY), int(KTY_AKP)),
+        (int(KEY_ALG), int(cose_algorithm(param)?)),
+        (int(KEY_PUB), Value::Bytes(public.to_vec())),
+    ];
+    if let Some(private) = private {
+        entries.push((int(KEY_PRIV), Value::Bytes(private.to_vec())));
+    }
+    encode(&Value::Map(entries))
+}
+
+fn decode_key(encoded: &[u8]) -> Result<AkpKey, SlhDsaError> {
+    let key = decode(encoded)?;
+    let labels = labels(&key)?;
+    let kty = lookup(&labels, KEY_KTY).ok_or(SlhDsaError::MalformedEncoding)?;
+    if as_int(kty)? != KTY_AKP {
+        return Err(SlhDsaError::UnsupportedAlgorithm);
+    }
+    let alg = lookup(&labels, KEY_ALG).ok_or(SlhDsaError::MalformedEncoding)?;
+    let param = ParameterSetId::from_cose_algorithm(as_int(alg)?)
+        .ok_or(SlhDsaError::UnsupportedAlgorithm)?;
+    let public = lookup(&labels, KEY_PUB)
+        .map(as_bytes)
+        .transpose()?
+        .map(<[u8]>::to_vec);
+    let private = lookup(&labels, KEY_PRIV)
+        .map(as_bytes)
+        .transpose()?
+        .map(<[u8]>::to_vec);
+    Ok((param, public, private))
+}
+
+impl DynVerifyingKey {
+    /// Encode as an AKP COSE_Key with `kty`, `alg` and `pub`.
+    pub fn to_cose_key(&self) -> Result<Vec<u8>, SlhDsaError> {
+        encode_key(self.parameter_set(), &self.to_vec(), None)
+    }
+
+    /// Decode an AKP COSE_Key, taking the parameter set from its `alg`.
+    pub fn from_cose_key(encoded: &[u8]) -> Result<Self, SlhDsaError> {
+        let (param, public, _) = decode_key(encoded)?;
+        Self::from_bytes(param, &public.ok_or(SlhDsaError::MalformedVerifyingKey)?)
+    }
+}
+
+impl DynSigningKey {
+    /// Encode as an AKP COSE_Key carrying both `pub` and `priv`.
+    pub fn to_cose_key(&self) -> Result<Vec<u8>, SlhDsaError> {
+        encode_key(
+            self.parameter_set(),
+            &self.verifying_key().to_vec(),
+            Some(&self.to_vec()),
+        )
+    }
+
+    /// Decode an AKP COSE_Key with a `priv` parameter.
+    ///
+    /// When `pub` is present it must match the signing key.
+    pub fn from_cose_key(encoded: &[u8]) -> Result<Self, SlhDsaError> {
+        let (param, public, private) = decode_key(encoded)?;
+        let key = Self::from_bytes(param, &private.ok_or(SlhDsaError::MalformedSigningKey)?)?;
+        if public.is_some_and(|public| public != key.verifying_key().to_vec()) {
+            return Err(SlhDsaError::MalformedSigningKey);
+        }
+        Ok(key)
+    }
+}
+
+/// `Sig_structure` for a COSE_Sign1 (RFC 9052 section 4.4).
+fn to_be_signed(
+    protected: &[u8],
+    external_aad: &[u8],
+    payload: &[u8],
+) -> Result<Vec<u8>, SlhDsaError> {
+    encode(&Value::Array(vec![
+        Value::Text("Signature1".into()),
+        Value::Bytes(protected.to_vec()),
+        Value::Bytes(external_aad.to_vec()),
+        Value::Bytes(payload.to_vec()),
+    ]))
+}
+
+/// Builds a COSE_Sign1 message.
+#[derive(Clone, Debug)]
+pub struct CoseSign1Builder {
+    payload: Vec<u8>,
+    key_id: Option<Vec<u8>>,
+    external_aad: Vec<u8>,
+    detached: bool,
+}
+
+impl CoseSign1Builder {
+    /// Sign `payload`, carried inside the message.
+    pub fn new(payload: &[u8]) -> Self {
+        Self {
+            payload: payload.to_vec(),
+            key_id: None,
+            external_aad: Vec::new(),
+            detached: false,
+        }
+    }
+
+    /// Put a `kid` in the unprotected header.
+    pub fn key_id(mut self, key_id: &[u8]) -> Self {
+        self.key_id = Some(key_id.to_vec());
+        self
+    }
+
+    /// Bind application data that is not sent; verifiers must supply the same bytes.
+    pub fn external_aad(mut self, external_aad: &[u8]) -> Self {
+        self.external_aad = external_aad.to_vec();
+        self
+    }
+
+    /// Send a nil payload; verifiers must supply the payload.
+    pub fn detached(mut self, detached: bool) -> Self {
+        self.detached = detached;
+        self
+    }
+
+    /// Sign with `signing_key`, whose parameter set determines the `alg` header.
+    pub fn sign<R: TryCryptoRng>(
+        self,
+        signing_key: &DynSigningKey,
+        rng: &mut R,
+    ) -> Result<CoseSign1, SlhDsaError> {
+        let param = signing_key.parameter_set();
+        let protected = encode(&Value::Map(vec![(
+            int(HEADER_ALG),
+            int(cose_algorithm(param)?),
+        )]))?;
+        let tbs = to_be_signed(&protected, &self.external_aad, &self.payload)?;
+        let signature = signing_key.try_sign_with_rng(rng, &tbs, &[])?;
+
+        Ok(CoseSign1 {
+            protected,
+            param,
+            key_id: self.key_id,
+            payload: (!self.detached).then_some(self.payload),
+            signature: signature.to_vec(),
+        })
+    }
+}
+
+/// Parsed COSE_Sign1 message signed with SLH-DSA.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CoseSign1 {
+    /// Serialized protected header, exactly as signed.
+    protected: Vec<u8>,
+    param: ParameterSetId,
+    key_id: Option<Vec<u8>>,
+    payload: Option<Vec<u8>>,
+    signature: Vec<u8>,
+}
+
+impl CoseSign1 {
+    /// Decode a COSE_Sign1, tagged or untagged. The signature is not checked;
+    /// call [`Self::verify`].
+    pub fn from_cbor(encoded: &[u8]) -> Result<Self, SlhDsaError> {
+        let message = match decode(encoded)? {
+            Value::Tag(COSE_SIGN1_TAG, message) => *message,
+            Value::Tag(..) => return Err(SlhDsaError::MalformedEncoding),
+            message => message,
+        };
+        let [protected, unprotected, payload, signature] = <[Value; 4]>::try_from(
+            message
+                .into_array()
+                .map_err(|_| SlhDsaError::MalformedEncoding)?,
+        )
+        .map_err(|_| SlhDsaError::MalformedEncoding)?;
+
+        let protected = protected
+            .into_bytes()
+            .map_err(|_| SlhDsaError::MalformedEncoding)?;
+        let protected_header = if protected.is_empty() {
+            Value::Map(Vec::new())
+        } else {
+            decode(&protected)?
+        };
+        let protected_labels = labels(&protected_header)?;
+        let unprotected_labels = labels(&unprotected)?;
+        // A label may appear in only one of the two buckets (RFC 9052 section 3).
+        if protected_labels
+            .iter()
+            .any(|(label, _)| lookup(&unprotected_labels, *label).is_some())
+        {
+            return Err(SlhDsaError::MalformedEncoding);
+        }
+
+        // The algorithm must be integrity protected.
+        let alg = lookup(&protected_labels, HEADER_ALG).ok_or(SlhDsaError::MalformedEncoding)?;
+        let param = ParameterSetId::from_cose_algorithm(as_int(alg)?)
+            .ok_or(SlhDsaError::UnsupportedAlgorithm)?;
+        let key_id = lookup(&protected_labels, HEADER_KID)
+            .or_else(|| lookup(&unprotected_labels, HEADER_KID))
+            .map(as_bytes)
+            .transpose()?
+            .map(<[u8]>::to_vec);
+
+        let payload = match payload {
+            Value::Null => None,
+            payload => Some(
+                payload
+                    .into_bytes()
+                    .map_err(|_| SlhDsaError::MalformedEncoding)?,
+            ),
+        };
+        let signature = signature
+            .into_bytes()
+            .map_err(|_| SlhDsaError::MalformedSignature)?;
+        if signature.len() != crate::sig_len_for(param) {
+            return Err(SlhDsaError::MalformedSignature);
+        }
+
+        Ok(Self {
+            protected,
+            param,
+            key_id,
+            payload,
+            signature,
+        })
+    }
+
+    /// Tagged CBOR encoding.
+    pub fn to_cbor(&self) -> Result<Vec<u8>, SlhDsaError> {
+        let unprotected = match &self.key_id {
+            Some(key_id) => vec![(int(HEADER_KID), Value::Bytes(key_id.clone()))],
+            None => Vec::new(),
+        };
+        encode(&Value::Tag(
+            COSE_SIGN1_TAG,
+            Box::new(Value::Array(vec![
+                Value::Bytes(self.protected.clone()),
+                Value::Map(unprotected),
+                self.payload.clone().map_or(Value::Null, Value::Bytes),
+                Value::Bytes(self.signature.clone()),
+            ])),
+        ))
+    }
+
+    /// Parameter set named by the protected `alg` header.
+    pub fn parameter_set(&self) -> ParameterSetId {
+        self.param
+    }
+
+    /// `kid` header, if any.
+    pub fn key_id(&self) -> Option<&[u8]> {
+        self.key_id.as_deref()
+    }
+
+    /// Attached payload, or `None` for a detached message.
+    pub fn payload(&self) -> Option<&[u8]> {
+        self.payload.as_deref()
+    }
+
+    /// Check the signature, supplying the payload for a detached message.
+    pub fn verify(
+        &self,
+        verifying_key: &DynVerifyingKey,
+        detached_payload: Option<&[u8]>,
+        external_aad: &[u8],
+    ) -> Result<(), SlhDsaError> {
+        let payload = match (self.payload.as_deref(), detached_payload) {
+            (Some(payload), None) | (None, Some(payload)) => payload,
+            _ => return Err(SlhDsaError::MalformedEncoding),
+        };
+        if verifying_key.parameter_set() != self.param {
+            return Err(SlhDsaError::ParameterSetMismatch);
+        }
+        let signature = DynSignature::from_bytes(self.param, &self.signature)?;
+        let tbs = to_be_signed(&self.protected, external_aad, payload)?;
+        verifying_key.verify(&tbs, &[], &signature)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_rng::TestRng;
+
+    fn signing_key(rng: &mut TestRng) -> DynSigningKey {
+        DynSigningKey::try_generate(ParameterSetId::Sha2_128f, rng).unwrap()
+    }
+
+    #[test]
+    fn algorithm_identifiers() {
+        for (param, alg) in [
+            (ParameterSetId::Sha2_128s, -51),
+            (ParameterSetId::Shake128s, -52),
+            (ParameterSetId::Sha2_128f, -53),
+        ] {
+            assert_eq!(param.cose_algorithm(), Some(alg));
+            assert_eq!(ParameterSetId::from_cose_algorithm(alg), Some(param));
+        }
+        assert_eq!(ParameterSetId::Shake256f.cose_algorithm(), None);
+        assert_eq!(ParameterSetId::from_cose_algorithm(-7), None);
+    }
+
+    #[test]
+    fn cose_key_round_trip() {
+        let mut rng = TestRng::new(16);
+        let sk = signing_key(&mut rng);
+        let vk = sk.verifying_key();
+
+        let public = vk.to_cose_key().unwrap();
+        let labels = decode(&public).unwrap();
+        assert_eq!(
+            labels,
+            Value::Map(vec![
+                (int(KEY_KTY), int(KTY_AKP)),
+                (int(KEY_ALG), int(-53)),
+                (int(KEY_PUB), Value::Bytes(vk.to_vec())),
+            ])
+        );
+        assert_eq!(DynVerifyingKey::from_cose_key(&public), Ok(vk.clone()));
+        assert_eq!(
+            DynSigningKey::from_cose_key(&public).err(),
+            Some(SlhDsaError::MalformedSigningKey)
+        );
+
+        let private = sk.to_cose_key().unwrap();
+        assert_eq!(DynSigningKey::from_cose_key(&private), Ok(sk.clone()));
+        assert_eq!(DynVerifyingKey::from_cose_key(&private), Ok(vk));
+
+        let other = signing_key(&mut rng);
+        let mismatched = encode_key(
+            ParameterSetId::Sha2_128f,
+            &other.verifying_key().to_vec(),
+            Some(&sk.to_vec()),
+        )
+        .unwrap();
+        assert_eq!(
+            DynSigningKey::from_cose_key(&mismatched).err(),
+            Some(SlhDsaError::MalformedSigningKey)
+        );
+
+        let unassigned = DynSigningKey::try_generate(ParameterSetId::Shake256f, &mut rng).unwrap();
+        assert_eq!(
+            unassigned.verifying_key().to_cose_key(),
+            Err(SlhDsaError::UnsupportedAlgorithm)
+        );
+    }
+
+    #[test]
+    fn cose_key_rejects_other_key_types_and_duplicate_labels() {
+        let ec2 = encode(&Value::Map(vec![
+            (int(KEY_KTY), int(2)),
+            (int(KEY_ALG), int(-53)),
+        ]))
+        .unwrap();
+        assert_eq!(
+            DynVerifyingKey::from_cose_key(&ec2),
+            Err(SlhDsaError::UnsupportedAlgorithm)
+        );
+
+        let duplicate = encode(&Value::Map(vec![
+            (int(KEY_KTY), int(KTY_AKP)),
+            (int(KEY_ALG), int(-53)),
+            (int(KEY_ALG), int(-51)),
+        ]))
+        .unwrap();
+        assert_eq!(
+            DynVerifyingKey::from_cose_key(&duplicate),
+            Err(SlhDsaError::MalformedEncoding)
+        );
+    }
+
+    #[test]
+    fn sign1_round_trip() {
+        let mut rng = TestRng::new(16);
+        let sk = signing_key(&mut rng);
+        let vk = sk.verifying_key();
+
+        let message = CoseSign1Builder::new(b"payload")
+            .key_id(b"device-1")
+            .sign(&sk, &mut rng)
+            .unwrap();
+        let encoded = message.to_cbor().unwrap();
+        assert_eq!(encoded[0], 0xd2, "tag 18");
+        let decoded = CoseSign1::from_cbor(&encoded).unwrap();
+        assert_eq!(decoded, message);
+        assert_eq!(decoded.parameter_set(), ParameterSetId::Sha2_128f);
+        assert_eq!(decoded.key_id(), Some(&b"device-1"[..]));
+        assert_eq!(decoded.payload(), Some(&b"payload"[..]));
+        assert_eq!(
+            decode(&decoded.protected).unwrap(),
+            Value::Map(vec![(int(HEADER_ALG), int(-53))])
+        );
+        assert_eq!(decoded.verify(&vk, None, &[]), Ok(()));
+        assert_eq!(
+            decoded.verify(&vk, None, b"aad"),
+            Err(SlhDsaError::VerificationFailed)
+        );
+        assert_eq!(
+            decoded.verify(&vk, Some(b"payload"), &[]),
+            Err(SlhDsaError::MalformedEncoding)
+        );
+    }
+
+    #[test]
+    fn detached_sign1_round_trip() {
+        let mut rng = TestRng::new(16);
+        let sk = signing_key(&mut rng);
+        let vk = sk.verifying_key();
+
+        let message = CoseSign1Builder::new(b"payload")
+            .detached(true)
+            .external_aad(b"aad")
+            .sign(&sk, &mut rng)
+            .unwrap();
+        let decoded = CoseSign1::from_cbor(&message.to_cbor().unwrap()).unwrap();
+        assert_eq!(decoded.payload(), None);
+        assert_eq!(decoded.verify(&vk, Some(b"payload"), b"aad"), Ok(()));
+        assert_eq!(
+            decoded.verify(&vk, Some(b"payloaD"), b"aad"),
+            Err(SlhDsaError::VerificationFailed)
+        );
+        assert_eq!(
+            decoded.verify(&vk, None, b"aad"),
+            Err(SlhDsaError::MalformedEncoding)
+        );
+    }
+}
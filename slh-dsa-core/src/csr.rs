@@ -0,0 +1,372 @@
+//! PKCS#10 certification requests (RFC 2986) signed with SLH-DSA.
+//!
+//! The request signature is pure SLH-DSA with an empty context over the DER
+//! `CertificationRequestInfo`, and doubles as proof of possession of the
+//! signing key. Requested extensions travel in a PKCS#9 `extensionRequest`
+//! attribute.
+
+use crate::x509::{
+    algorithm_identifier, context_specific, decode_extensions, parameter_set_of, sequence, tlv,
+    Extension, GeneralName, Name,
+};
+use crate::{DynSignature, DynSigningKey, DynVerifyingKey, ParameterSetId, SlhDsaError};
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use pkcs8::der::asn1::BitStringRef;
+use pkcs8::der::pem::LineEnding;
+use pkcs8::der::{self, Decode, Document, Encode, Header, Reader, SliceReader, Tag, TagNumber};
+use pkcs8::spki::{AlgorithmIdentifierRef, SubjectPublicKeyInfoRef};
+use pkcs8::{EncodePublicKey, ObjectIdentifier};
+use rand_core::TryCryptoRng;
+
+/// PKCS#9 `extensionRequest` attribute type.
+const EXTENSION_REQUEST: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.840.113549.1.9.14");
+
+/// PEM label for certification requests.
+const PEM_LABEL: &str = "CERTIFICATE REQUEST";
+
+/// Builds and signs a `CertificationRequest`.
+#[derive(Clone, Debug)]
+pub struct CsrBuilder {
+    subject: Name,
+    subject_alt_names: Vec<GeneralName>,
+    extensions: Vec<Extension>,
+}
+
+impl CsrBuilder {
+    /// Request for a certificate naming `subject`.
+    pub fn new(subject: Name) -> Self {
+        Self {
+            subject,
+            subject_alt_names: Vec::new(),
+            extensions: Vec::new(),
+        }
+    }
+
+    /// Add a subject alternative name; all of them are requested in one extension.
+    pub fn subject_alt_name(mut self, name: GeneralName) -> Self {
+        self.subject_alt_names.push(name);
+        self
+    }
+
+    /// Request an additional extension.
+    pub fn extension(mut self, extension: Extension) -> Self {
+        self.extensions.push(extension);
+        self
+    }
+
+    /// Sign the request with the subject's signing key.
+    pub fn sign<R: TryCryptoRng>(
+        self,
+        signing_key: &DynSigningKey,
+        rng: &mut R,
+    ) -> Result<CertificationRequest, SlhDsaError> {
+        let info = self.to_info_der(&signing_key.verifying_key())?;
+        let signature = signing_key.try_sign_with_rng(rng, &info, &[])?.to_vec();
+        let der = sequence(&[
+            &info,
+            &algorithm_identifier(signing_key.parameter_set())?,
+            &BitStringRef::from_bytes(&signature)?.to_der()?,
+        ])?;
+        CertificationRequest::from_der(&der)
+    }
+
+    fn to_info_der(&self, subject_key: &DynVerifyingKey) -> Result<Vec<u8>, SlhDsaError> {
+        let mut extensions = self.extensions.clone();
+        if !self.subject_alt_names.is_empty() {
+            extensions.push(Extension::subject_alt_name(&self.subject_alt_names)?);
+        }
+        if extensions
+            .iter()
+            .enumerate()
+            .any(|(i, ext)| extensions[..i].iter().any(|seen| seen.oid == ext.oid))
+        {
+            return Err(SlhDsaError::MalformedEncoding);
+        }
+
+        let attributes = if extensions.is_empty() {
+            Vec::new()
+        } else {
+            let extensions = extensions
+                .iter()
+                .map(Extension::to_der)
+                .collect::<der::Result<Vec<_>>>()?;
+            let extensions: Vec<&[u8]> = extensions.iter().map(Vec::as_slice).collect();
+            let values = tlv(Tag::Set, &[&sequence(&extensions)?])?;
+            sequence(&[&EXTENSION_REQUEST.to_der()?, &values])?
+        };
+
+        Ok(sequence(&[
+            &0u8.to_der()?,
+            &self.subject.to_der()?,
+            subject_key.to_public_key_der()?.as_bytes(),
+            &context_specific(0, true, &[&attributes])?,
+        ])?)
+    }
+}
+
+/// Parsed PKCS#10 certification request with an SLH-DSA subject key.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CertificationRequest {
+    der: Vec<u8>,
+    info: Vec<u8>,
+    subject: Name,
+    subject_key: DynVerifyingKey,
+    extensions: Vec<Extension>,
+    subject_alt_names: Vec<GeneralName>,
+    signature_param: ParameterSetId,
+    signature: Vec<u8>,
+}
+
+impl CertificationRequest {
+    /// Decode a request in DER or PEM form.
+    pub fn decode(encoded: &[u8]) -> Result<Self, SlhDsaError> {
+        match crate::asn1::as_pem(encoded) {
+            Some(pem) => Self::from_pem(pem?),
+            None => Self::from_der(encoded),
+        }
+    }
+
+    /// Decode a PEM `CERTIFICATE REQUEST`.
+    pub fn from_pem(pem: &str) -> Result<Self, SlhDsaError> {
+        let (label, doc) = Document::from_pem(pem)?;
+        if label != PEM_LABEL {
+            return Err(SlhDsaError::MalformedEncoding);
+        }
+        Self::from_der(doc.as_bytes())
+    }
+
+    /// Decode a DER request. The signature is not checked; call [`Self::verify`].
+    pub fn from_der(der: &[u8]) -> Result<Self, SlhDsaError> {
+        let mut reader = SliceReader::new(der)?;
+        let (info, algorithm, signature) = reader.sequence(|csr| {
+            let info = csr.tlv_bytes()?;
+            let algorithm = AlgorithmIdentifierRef::decode(csr)?;
+            let signature = BitStringRef::decode(csr)?;
+            Ok::<_, der::Error>((info, algorithm, signature))
+        })?;
+        reader.finish()?;
+
+        let signature_param = parameter_set_of(&algorithm)?;
+        let signature = signature
+            .as_bytes()
+            .ok_or(SlhDsaError::MalformedSignature)?;
+        if signature.len() != crate::sig_len_for(signature_param) {
+            return Err(SlhDsaError::MalformedSignature);
+        }
+
+        let mut reader = SliceReader::new(info)?;
+        let (subject, subject_key, extensions) = reader.sequence(|info_reader| {
+            if u8::decode(info_reader)? != 0 {
+                return Err(SlhDsaError::MalformedEncoding);
+            }
+            let subject = Name::from_der(info_reader.tlv_bytes()?)?;
+            let subject_key = DynVerifyingKey::from_public_key_info(
+                SubjectPublicKeyInfoRef::decode(info_reader)?,
+            )?;
+
+            let header = Header::decode(info_reader)?;
+            if header.tag() != TagNumber(0).context_specific(true) {
+                return Err(SlhDsaError::MalformedEncoding);
+            }
+            let extensions = info_reader.read_nested(header.length(), decode_attributes)?;
+            Ok((subject, subject_key, extensions))
+        })?;
+        reader.finish()?;
+
+        let subject_alt_names = match extensions
+            .iter()
+            .find(|ext| ext.oid == Extension::SUBJECT_ALT_NAME)
+        {
+            Some(ext) => GeneralName::decode_all(&ext.value)?,
+            None => Vec::new(),
+        };
+
+        Ok(Self {
+            der: der.to_vec(),
+            info: info.to_vec(),
+            subject,
+            subject_key,
+            extensions,
+            subject_alt_names,
+            signature_param,
+            signature: signature.to_vec(),
+        })
+    }
+
+    /// DER encoding.
+    pub fn to_der(&self) -> &[u8] {
+        &self.der
+    }
+
+    /// PEM encoding with LF line endings.
+    pub fn to_pem(&self) -> Result<String, SlhDsaError> {
+        Ok(
+            der::pem::encode_string(PEM_LABEL, LineEnding::LF, &self.der)
+                .map_err(der::Error::from)?,
+        )
+    }
+
+    /// Requested subject name.
+    pub fn subject(&self) -> &Name {
+        &self.subject
+    }
+
+    /// Subject public key the certificate would certify.
+    pub fn verifying_key(&self) -> &DynVerifyingKey {
+        &self.subject_key
+    }
+
+    /// Requested extensions, including any `SubjectAltName`.
+    pub fn extensions(&self) -> &[Extension] {
+        &self.extensions
+    }
+
+    /// Subject alternative names of the supported forms.
+    pub fn subject_alt_names(&self) -> &[GeneralName] {
+        &self.subject_alt_names
+    }
+
+    /// Check proof of possession: the request must be signed by its own subject key.
+    pub fn verify(&self) -> Result<(), SlhDsaError> {
+        if self.signature_param != self.subject_key.parameter_set() {
+            return Err(SlhDsaError::ParameterSetMismatch);
+        }
+        let signature = DynSignature::from_bytes(self.signature_param, &self.signature)?;
+        self.subject_key.verify(&self.info, &[], &signature)
+    }
+}
+
+/// Decode the `attributes` set, returning the contents of any `extensionRequest`.
+fn decode_attributes<'a, R: Reader<'a>>(reader: &mut R) -> Result<Vec<Extension>, SlhDsaError> {
+    let mut extensions = None;
+    while !reader.is_finished() {
+        let found = reader.sequence(|attribute| {
+            let oid = ObjectIdentifier::decode(attribute)?;
+            let header = Header::decode(attribute)?;
+            if header.tag() != Tag::Set {
+                return Err(SlhDsaError::MalformedEncoding);
+            }
+            if oid != EXTENSION_REQUEST {
+                attribute.read_slice(header.length())?;
+                return Ok(None);
+            }
+            attribute
+                .read_nested(header.length(), decode_extensions)
+                .map(Some)
+        })?;
+        if let Some(found) = found {
+            if extensions.replace(found).is_some() {
+                return Err(SlhDsaError::MalformedEncoding);
+            }
+        }
+    }
+    Ok(extensions.unwrap_or_default())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_rng::TestRng;
+    use alloc::vec;
+
+    const OPENSSL_REQUEST: &[u8] = include_bytes!("../tests/vectors/openssl/leaf-csr.der");
+
+    const KEY_USAGE: ObjectIdentifier = ObjectIdentifier::new_unwrap("2.5.29.15");
+
+    /// Flip one byte of `needle`, which must occur once in `der`.
+    fn tamper(der: &[u8], needle: &[u8]) -> Vec<u8> {
+        let at = der
+            .windows(needle.len())
+            .position(|window| window == needle)
+            .unwrap();
+        let mut tampered = der.to_vec();
+        tampered[at] ^= 0x20;
+        tampered
+    }
+
+    fn signed_request() -> CertificationRequest {
+        let mut rng = TestRng::new(14);
+        let sk = DynSigningKey::try_generate(ParameterSetId::Shake128f, &mut rng).unwrap();
+        CsrBuilder::new(Name::new().with_common_name("csr.example").unwrap())
+            .subject_alt_name(GeneralName::Dns("csr.example".into()))
+            .subject_alt_name(GeneralName::Email("admin@csr.example".into()))
+            .subject_alt_name(GeneralName::Ip(vec![192, 0, 2, 1]))
+            .extension(Extension {
+                oid: KEY_USAGE,
+                critical: true,
+                value: vec![0x03, 0x02, 0x07, 0x80],
+            })
+            .sign(&sk, &mut rng)
+            .unwrap()
+    }
+
+    #[test]
+    fn request_with_extensions_verifies() {
+        let request = signed_request();
+        assert_eq!(request.verify(), Ok(()));
+        assert_eq!(
+            request.subject().common_name().as_deref(),
+            Some("csr.example")
+        );
+        assert_eq!(
+            request.subject_alt_names(),
+            [
+                GeneralName::Dns("csr.example".into()),
+                GeneralName::Email("admin@csr.example".into()),
+                GeneralName::Ip(vec![192, 0, 2, 1]),
+            ]
+        );
+        let key_usage = request
+            .extensions()
+            .iter()
+            .find(|ext| ext.oid == KEY_USAGE)
+            .unwrap();
+        assert!(key_usage.critical);
+
+        let decoded = CertificationRequest::decode(request.to_pem().unwrap().as_bytes()).unwrap();
+        assert_eq!(decoded, request);
+        assert_eq!(decoded.verify(), Ok(()));
+    }
+
+    #[test]
+    fn tampered_request_fails() {
+        let request = signed_request();
+        let tampered =
+            CertificationRequest::from_der(&tamper(request.to_der(), b"admin@")).unwrap();
+        assert_eq!(tampered.verify(), Err(SlhDsaError::VerificationFailed));
+
+        let mut der = request.to_der().to_vec();
+        *der.last_mut().unwrap() ^= 1;
+        let tampered = CertificationRequest::from_der(&der).unwrap();
+        assert_eq!(tampered.verify(), Err(SlhDsaError::VerificationFailed));
+    }
+
+    #[test]
+    fn openssl_request_verifies() {
+        let request = CertificationRequest::from_der(OPENSSL_REQUEST).unwrap();
+        assert_eq!(request.to_der(), OPENSSL_REQUEST);
+        assert_eq!(request.verify(), Ok(()));
+        assert_eq!(
+            request.subject().common_name().as_deref(),
+            Some("leaf.example")
+        );
+        assert_eq!(
+            request.subject_alt_names(),
+            [
+                GeneralName::Dns("leaf.example".into()),
+                GeneralName::Dns("www.leaf.example".into()),
+            ]
+        );
+        assert!(request
+            .extensions()
+            .iter()
+            .any(|ext| ext.oid == KEY_USAGE && ext.critical));
+
+        let tampered =
+            CertificationRequest::from_der(&tamper(OPENSSL_REQUEST, b"www.leaf")).unwrap();
+        assert_eq!(tampered.verify(), Err(SlhDsaError::VerificationFailed));
+    }
+}
@@ -0,0 +1,422 @@
+//! Runtime-dispatched keys and signatures keyed by [`ParameterSetId`].
+
+use crate::{
+    sig_len, sk_len, vk_len, ParameterSetId, PreHashDigest, Sha2_128f, Sha2_128s, Sha2_192f,
+    Sha2_192s, Sha2_256f, Sha2_256s, Shake128f, Shake128s, Shake192f, Shake192s, Shake256f,
+    Shake256s, Signature, SigningKey, SlhDsaError, VerifyingKey,
+};
+
+use alloc::vec::Vec;
+use rand_core::{CryptoRng, TryCryptoRng};
+
+/// Run `$body` with `$P` bound to the parameter set type selected by `$param`.
+macro_rules! with_parameter_set {
+    ($param:expr, |$P:ident| $body:expr) => {
+        match $param {
+            ParameterSetId::Shake128s => {
+                type $P = Shake128s;
+                $body
+            }
+            ParameterSetId::Shake128f => {
+                type $P = Shake128f;
+                $body
+            }
+            ParameterSetId::Shake192s => {
+                type $P = Shake192s;
+                $body
+            }
+            ParameterSetId::Shake192f => {
+                type $P = Shake192f;
+                $body
+            }
+            ParameterSetId::Shake256s => {
+                type $P = Shake256s;
+                $body
+            }
+            ParameterSetId::Shake256f => {
+                type $P = Shake256f;
+                $body
+            }
+            ParameterSetId::Sha2_128s => {
+                type $P = Sha2_128s;
+                $body
+            }
+            ParameterSetId::Sha2_128f => {
+                type $P = Sha2_128f;
+                $body
+            }
+            ParameterSetId::Sha2_192s => {
+                type $P = Sha2_192s;
+                $body
+            }
+            ParameterSetId::Sha2_192f => {
+                type $P = Sha2_192f;
+                $body
+            }
+            ParameterSetId::Sha2_256s => {
+                type $P = Sha2_256s;
+                $body
+            }
+            ParameterSetId::Sha2_256f => {
+                type $P = Sha2_256f;
+                $body
+            }
+        }
+    };
+}
+
+/// Run `$body` with `$inner` bound to the typed value wrapped by a dynamic enum.
+macro_rules! with_variant {
+    ($value:expr, $Enum:ident, |$inner:ident| $body:expr) => {
+        match $value {
+            $Enum::Shake128s($inner) => $body,
+            $Enum::Shake128f($inner) => $body,
+            $Enum::Shake192s($inner) => $body,
+            $Enum::Shake192f($inner) => $body,
+            $Enum::Shake256s($inner) => $body,
+            $Enum::Shake256f($inner) => $body,
+            $Enum::Sha2_128s($inner) => $body,
+            $Enum::Sha2_128f($inner) => $body,
+            $Enum::Sha2_192s($inner) => $body,
+            $Enum::Sha2_192f($inner) => $body,
+            $Enum::Sha2_256s($inner) => $body,
+            $Enum::Sha2_256f($inner) => $body,
+        }
+    };
+}
+
+/// Like [`with_variant`], for a key and signature that must share a parameter set.
+macro_rules! with_matching_variants {
+    ($key:expr, $sig:expr, |$k:ident, $s:ident| $body:expr, $mismatch:expr) => {
+        match ($key, $sig) {
+            (DynVerifyingKey::Shake128s($k), DynSignature::Shake128s($s)) => $body,
+            (DynVerifyingKey::Shake128f($k), DynSignature::Shake128f($s)) => $body,
+            (DynVerifyingKey::Shake192s($k), DynSignature::Shake192s($s)) => $body,
+            (DynVerifyingKey::Shake192f($k), DynSignature::Shake192f($s)) => $body,
+            (DynVerifyingKey::Shake256s($k), DynSignature::Shake256s($s)) => $body,
+            (DynVerifyingKey::Shake256f($k), DynSignature::Shake256f($s)) => $body,
+            (DynVerifyingKey::Sha2_128s($k), DynSignature::Sha2_128s($s)) => $body,
+            (DynVerifyingKey::Sha2_128f($k), DynSignature::Sha2_128f($s)) => $body,
+            (DynVerifyingKey::Sha2_192s($k), DynSignature::Sha2_192s($s)) => $body,
+            (DynVerifyingKey::Sha2_192f($k), DynSignature::Sha2_192f($s)) => $body,
+            (DynVerifyingKey::Sha2_256s($k), DynSignature::Sha2_256s($s)) => $body,
+            (DynVerifyingKey::Sha2_256f($k), DynSignature::Sha2_256f($s)) => $body,
+            _ => $mismatch,
+        }
+    };
+}
+
+/// Define a dynamic enum over every parameter set, plus `From` impls for each typed value.
+macro_rules! dyn_enum {
+    ($(#[$meta:meta])* $Enum:ident, $Inner:ident, $len:ident, $malformed:ident) => {
+        $(#[$meta])*
+        #[derive(Clone, Debug, Eq, PartialEq)]
+        pub enum $Enum {
+            Shake128s($Inner<Shake128s>),
+            Shake128f($Inner<Shake128f>),
+            Shake192s($Inner<Shake192s>),
+            Shake192f($Inner<Shake192f>),
+            Shake256s($Inner<Shake256s>),
+            Shake256f($Inner<Shake256f>),
+            Sha2_128s($Inner<Sha2_128s>),
+            Sha2_128f($Inner<Sha2_128f>),
+            Sha2_192s($Inner<Sha2_192s>),
+            Sha2_192f($Inner<Sha2_192f>),
+            Sha2_256s($Inner<Sha2_256s>),
+            Sha2_256f($Inner<Sha2_256f>),
+        }
+
+        dyn_enum!(@from $Enum, $Inner, Shake128s, Shake128f, Shake192s, Shake192f, Shake256s,
+            Shake256f, Sha2_128s, Sha2_128f, Sha2_192s, Sha2_192f, Sha2_256s, Sha2_256f);
+
+        impl $Enum {
+            /// Parameter set of the wrapped value.
+            pub const fn parameter_set(&self) -> ParameterSetId {
+                match self {
+                    Self::Shake128s(_) => ParameterSetId::Shake128s,
+                    Self::Shake128f(_) => ParameterSetId::Shake128f,
+                    Self::Shake192s(_) => ParameterSetId::Shake192s,
+                    Self::Shake192f(_) => ParameterSetId::Shake192f,
+                    Self::Shake256s(_) => ParameterSetId::Shake256s,
+                    Self::Shake256f(_) => ParameterSetId::Shake256f,
+                    Self::Sha2_128s(_) => ParameterSetId::Sha2_128s,
+                    Self::Sha2_128f(_) => ParameterSetId::Sha2_128f,
+                    Self::Sha2_192s(_) => ParameterSetId::Sha2_192s,
+                    Self::Sha2_192f(_) => ParameterSetId::Sha2_192f,
+                    Self::Sha2_256s(_) => ParameterSetId::Sha2_256s,
+                    Self::Sha2_256f(_) => ParameterSetId::Sha2_256f,
+                }
+            }
+
+            /// Parse the raw encoding for the given parameter set.
+            pub fn from_bytes(param: ParameterSetId, bytes: &[u8]) -> Result<Self, SlhDsaError> {
+                #[cfg(feature = "fips")]
+                crate::fips::ensure(param)?;
+                with_parameter_set!(param, |P| {
+                    if bytes.len() != $len::<P>() {
+                        return Err(SlhDsaError::InvalidLength);
+                    }
+                    $Inner::<P>::try_from(bytes)
+                        .map(Self::from)
+                        .map_err(|_| SlhDsaError::$malformed)
+                })
+            }
+
+            /// Serialize to the raw FIPS 205 encoding.
+            pub fn to_vec(&self) -> Vec<u8> {
+                with_variant!(self, Self, |inner| inner.to_bytes().to_vec())
+            }
+        }
+    };
+    (@from $Enum:ident, $Inner:ident, $($P:ident),*) => {
+        $(
+            impl From<$Inner<$P>> for $Enum {
+                fn from(value: $Inner<$P>) -> Self {
+                    Self::$P(value)
+                }
+            }
+        )*
+    };
+}
+
+dyn_enum!(
+    /// A signing key whose parameter set is chosen at runtime.
+    DynSigningKey,
+    SigningKey,
+    sk_len,
+    MalformedSigningKey
+);
+dyn_enum!(
+    /// A verifying key whose parameter set is chosen at runtime.
+    DynVerifyingKey,
+    VerifyingKey,
+    vk_len,
+    MalformedVerifyingKey
+);
+dyn_enum!(
+    /// A signature whose parameter set is chosen at runtime.
+    DynSignature,
+    Signature,
+    sig_len,
+    MalformedSignature
+);
+
+/// The wrapped [`SigningKey`] wipes its secret seeds when dropped.
+#[cfg(feature = "zeroize")]
+impl zeroize::ZeroizeOnDrop for DynSigningKey {}
+
+impl DynSigningKey {
+    /// Generate a fresh signing key for the given parameter set.
+    ///
+    /// # Panics
+    ///
+    /// With the `fips` feature, panics if a self-test fails; see
+    /// [`crate::generate_keypair`].
+    pub fn generate<R: CryptoRng>(param: ParameterSetId, rng: &mut R) -> Self {
+        with_parameter_set!(param, |P| Self::from(
+            crate::generate_keypair::<P, R>(rng).0
+        ))
+    }
+
+    /// Generate a fresh signing key from a fallible RNG.
+    pub fn try_generate<R: TryCryptoRng>(
+        param: ParameterSetId,
+        rng: &mut R,
+    ) -> Result<Self, SlhDsaError> {
+        with_parameter_set!(param, |P| crate::try_generate_keypair::<P, R>(rng)
+            .map(|(sk, _)| Self::from(sk)))
+    }
+
+    /// Derive a signing key from explicit seeds (FIPS 205 `slh_keygen_internal`).
+    pub fn from_seed(
+        param: ParameterSetId,
+        sk_seed: &[u8],
+        sk_prf: &[u8],
+        pk_seed: &[u8],
+    ) -> Result<Self, SlhDsaError> {
+        with_parameter_set!(param, |P| crate::keypair_from_seed::<P>(
+            sk_seed, sk_prf, pk_seed
+        )
+        .map(|(sk, _)| Self::from(sk)))
+    }
+
+    /// Parse a signing key and check it with [`Self::validate`].
+    pub fn from_bytes_validated(param: ParameterSetId, bytes: &[u8]) -> Result<Self, SlhDsaError> {
+        let signing_key = Self::from_bytes(param, bytes)?;
+        signing_key.validate()?;
+        Ok(signing_key)
+    }
+
+    /// Recompute PK.root from the key's seeds and compare it with the embedded
+    /// one, see [`crate::validate_signing_key`].
+    pub fn validate(&self) -> Result<(), SlhDsaError> {
+        with_variant!(self, Self, |sk| crate::validate_signing_key(sk))
+    }
+
+    /// Verifying key embedded in this signing key.
+    pub fn verifying_key(&self) -> DynVerifyingKey {
+        with_variant!(self, Self, |sk| DynVerifyingKey::from(sk.as_ref().clone()))
+    }
+
+    /// Sign a message with a context string using a caller-supplied RNG.
+    pub fn sign_with_rng<R: CryptoRng>(
+        &self,
+        rng: &mut R,
+        msg: &[u8],
+        ctx: &[u8],
+    ) -> Result<DynSignature, SlhDsaError> {
+        with_variant!(self, Self, |sk| crate::sign_with_rng(sk, rng, msg, ctx)
+            .map(DynSignature::from))
+    }
+
+    /// Sign a message with a context string using a fallible RNG.
+    pub fn try_sign_with_rng<R: TryCryptoRng>(
+        &self,
+        rng: &mut R,
+        msg: &[u8],
+        ctx: &[u8],
+    ) -> Result<DynSignature, SlhDsaError> {
+        with_variant!(self, Self, |sk| crate::try_sign_with_rng(sk, rng, msg, ctx)
+            .map(DynSignature::from))
+    }
+
+    /// Sign with a fallible RNG and verify the signature before returning it,
+    /// see [`crate::sign_checked`].
+    pub fn sign_checked<R: TryCryptoRng>(
+        &self,
+        rng: &mut R,
+        msg: &[u8],
+        ctx: &[u8],
+    ) -> Result<DynSignature, SlhDsaError> {
+        with_variant!(self, Self, |sk| crate::sign_checked(sk, rng, msg, ctx)
+            .map(DynSignature::from))
+    }
+
+    /// Deterministically sign and verify the signature before returning it.
+    pub fn sign_checked_deterministic(
+        &self,
+        msg: &[u8],
+        ctx: &[u8],
+    ) -> Result<DynSignature, SlhDsaError> {
+        with_variant!(self, Self, |sk| crate::sign_checked_deterministic(
+            sk, msg, ctx
+        )
+        .map(DynSignature::from))
+    }
+
+    /// Deterministically sign a message with a context string.
+    pub fn sign_deterministic(&self, msg: &[u8], ctx: &[u8]) -> Result<DynSignature, SlhDsaError> {
+        with_variant!(self, Self, |sk| crate::sign_deterministic(sk, msg, ctx)
+            .map(DynSignature::from))
+    }
+
+    pub(crate) fn sign_with_opt_rand(
+        &self,
+        msg: &[u8],
+        ctx: &[u8],
+        opt_rand: Option<&[u8]>,
+    ) -> Result<DynSignature, SlhDsaError> {
+        with_variant!(self, Self, |sk| crate::sign_with_opt_rand(
+            sk, msg, ctx, opt_rand
+        )
+        .map(DynSignature::from))
+    }
+
+    /// Sign a pre-formatted message M' with an optional explicit opt_rand.
+    pub fn sign_internal(
+        &self,
+        msg_prime: &[u8],
+        opt_rand: Option<&[u8]>,
+    ) -> Result<DynSignature, SlhDsaError> {
+        with_variant!(self, Self, |sk| crate::sign_internal(
+            sk, msg_prime, opt_rand
+        )
+        .map(DynSignature::from))
+    }
+
+    /// Sign a pre-hashed message with a context string using a caller-supplied RNG.
+    pub fn sign_prehash_with_rng<R: CryptoRng>(
+        &self,
+        rng: &mut R,
+        digest: &PreHashDigest,
+        ctx: &[u8],
+    ) -> Result<DynSignature, SlhDsaError> {
+        with_variant!(self, Self, |sk| crate::sign_prehash_with_rng(
+            sk, rng, digest, ctx
+        )
+        .map(DynSignature::from))
+    }
+
+    /// Sign a pre-hashed message with a context string using a fallible RNG.
+    pub fn try_sign_prehash_with_rng<R: TryCryptoRng>(
+        &self,
+        rng: &mut R,
+        digest: &PreHashDigest,
+        ctx: &[u8],
+    ) -> Result<DynSignature, SlhDsaError> {
+        with_variant!(self, Self, |sk| crate::try_sign_prehash_with_rng(
+            sk, rng, digest, ctx
+        )
+        .map(DynSignature::from))
+    }
+
+    /// Deterministically sign a pre-hashed message with a context string.
+    pub fn sign_prehash_deterministic(
+        &self,
+        digest: &PreHashDigest,
+        ctx: &[u8],
+    ) -> Result<DynSignature, SlhDsaError> {
+        with_variant!(self, Self, |sk| crate::sign_prehash_deterministic(
+            sk, digest, ctx
+        )
+        .map(DynSignature::from))
+    }
+}
+
+impl DynVerifyingKey {
+    /// Verify a signature with a context string.
+    ///
+    /// Fails if the signature belongs to a different parameter set than the key.
+    pub fn verify(
+        &self,
+        msg: &[u8],
+        ctx: &[u8],
+        signature: &DynSignature,
+    ) -> Result<(), SlhDsaError> {
+        with_matching_variants!(
+            self,
+            signature,
+            |vk, sig| crate::verify_with_context(vk, msg, ctx, sig),
+            Err(SlhDsaError::ParameterSetMismatch)
+        )
+    }
+
+    /// Verify a signature over a pre-formatted message M'.
+    pub fn verify_internal(
+        &self,
+        msg_prime: &[u8],
+        signature: &DynSignature,
+    ) -> Result<(), SlhDsaError> {
+        with_matching_variants!(
+            self,
+            signature,
+            |vk, sig| crate::verify_internal(vk, msg_prime, sig),
+            Err(SlhDsaError::ParameterSetMismatch)
+        )
+    }
+
+    /// Verify a HashSLH-DSA signature over a pre-hashed message.
+    pub fn verify_prehash(
+        &self,
+        digest: &PreHashDigest,
+        ctx: &[u8],
+        signature: &DynSignature,
+    ) -> Result<(), SlhDsaError> {
+        with_matching_variants!(
+            self,
+            signature,
+            |vk, sig| crate::verify_prehash(vk, digest, ctx, sig),
+            Err(SlhDsaError::ParameterSetMismatch)
+        )
+    }
+}
@@ -0,0 +1,514 @@
+//! Self-describing binary envelope for SLH-DSA signatures.
+//!
+//! An envelope carries everything a verifier needs besides the key: the
+//! parameter set, whether the message was pre-hashed, the context string, an
+//! optional key ID and, unless detached, the signed payload. All integers are
+//! big-endian:
+//!
+//! ```text
+//! magic "SLHE" | version | parameter set | mode | flags
+//! [key ID length (1) | key ID]          if flags & 0x01
+//! context length (1) | context
+//! signature                             sig_len_for(parameter set) bytes
+//! [payload length (4) | payload]        if flags & 0x02
+//! ```
+//!
+//! Parsing is strict: unknown versions, modes or flag bits, empty key IDs,
+//! oversized payloads and trailing bytes are all rejected.
+
+use crate::{
+    prehash, DynSignature, DynSigningKey, DynVerifyingKey, ParameterSetId, PreHashAlgorithm,
+    SlhDsaError,
+};
+
+use alloc::vec::Vec;
+
+use rand_core::TryCryptoRng;
+
+/// Leading bytes of every envelope.
+pub const MAGIC: [u8; 4] = *b"SLHE";
+
+/// Envelope format version written by this crate.
+pub const VERSION: u8 = 1;
+
+/// Longest key ID an envelope can carry.
+pub const MAX_KEY_ID_LEN: usize = 255;
+
+/// Largest embedded payload [`Envelope::from_bytes`] accepts.
+pub const DEFAULT_MAX_PAYLOAD_LEN: usize = 16 * 1024 * 1024;
+
+const FLAG_KEY_ID: u8 = 0x01;
+const FLAG_PAYLOAD: u8 = 0x02;
+
+/// How the payload was turned into the signed message.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum SignatureMode {
+    /// Pure SLH-DSA over the payload itself.
+    Pure,
+    /// HashSLH-DSA over a digest of the payload.
+    PreHash(PreHashAlgorithm),
+}
+
+impl SignatureMode {
+    /// Wire value of this mode.
+    pub const fn to_u8(self) -> u8 {
+        match self {
+            Self::Pure => 0,
+            Self::PreHash(PreHashAlgorithm::Sha256) => 1,
+            Self::PreHash(PreHashAlgorithm::Sha512) => 2,
+            Self::PreHash(PreHashAlgorithm::Shake128) => 3,
+            Self::PreHash(PreHashAlgorithm::Shake256) => 4,
+        }
+    }
+
+    /// Mode with the given wire value.
+    pub const fn from_u8(value: u8) -> Option<Self> {
+        match value {
+            0 => Some(Self::Pure),
+            1 => Some(Self::PreHash(PreHashAlgorithm::Sha256)),
+            2 => Some(Self::PreHash(PreHashAlgorithm::Sha512)),
+            3 => Some(Self::PreHash(PreHashAlgorithm::Shake128)),
+            4 => Some(Self::PreHash(PreHashAlgorithm::Shake256)),
+            _ => None,
+        }
+    }
+}
+
+/// Builds a signed envelope.
+#[derive(Clone, Debug)]
+pub struct EnvelopeBuilder {
+    payload: Vec<u8>,
+    key_id: Option<Vec<u8>>,
+    context: Vec<u8>,
+    mode: SignatureMode,
+    detached: bool,
+}
+
+impl EnvelopeBuilder {
+    /// Sign `payload`, carried inside the envelope.
+    pub fn new(payload: &[u8]) -> Self {
+        Self {
+            payload: payload.to_vec(),
+            key_id: None,
+            context: Vec::new(),
+            mode: SignatureMode::Pure,
+            detached: false,
+        }
+    }
+
+    /// Record which key signed the envelope (1 to 255 bytes).
+    pub fn key_id(mut self, key_id: &[u8]) -> Self {
+        self.key_id = Some(key_id.to_vec());
+        self
+    }
+
+    /// FIPS 205 context string, at most 255 bytes.
+    pub fn context(mut self, context: &[u8]) -> Self {
+        self.context = context.to_vec();
+        self
+    }
+
+    /// Sign with pure SLH-DSA (the default) or HashSLH-DSA.
+    pub fn mode(mut self, mode: SignatureMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Leave the payload out; verifiers must supply it.
+    pub fn detached(mut self, detached: bool) -> Self {
+        self.detached = detached;
+        self
+    }
+
+    /// Sign with `signing_key`, whose parameter set is recorded in the envelope.
+    pub fn sign<R: TryCryptoRng>(
+        self,
+        signing_key: &DynSigningKey,
+        rng: &mut R,
+    ) -> Result<Envelope, SlhDsaError> {
+        if let Some(key_id) = &self.key_id {
+            if key_id.is_empty() || key_id.len() > MAX_KEY_ID_LEN {
+                return Err(SlhDsaError::InvalidLength);
+            }
+        }
+        if !self.detached && u32::try_from(self.payload.len()).is_err() {
+            return Err(SlhDsaError::InvalidLength);
+        }
+        let signature = match self.mode {
+            SignatureMode::Pure => {
+                signing_key.try_sign_with_rng(rng, &self.payload, &self.context)?
+            }
+            SignatureMode::PreHash(algorithm) => signing_key.try_sign_prehash_with_rng(
+                rng,
+                &prehash(algorithm, &self.payload),
+                &self.context,
+            )?,
+        };
+
+        Ok(Envelope {
+            mode: self.mode,
+            key_id: self.key_id,
+            context: self.context,
+            signature,
+            payload: (!self.detached).then_some(self.payload),
+        })
+    }
+}
+
+/// Parsed signed envelope.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Envelope {
+    mode: SignatureMode,
+    key_id: Option<Vec<u8>>,
+    context: Vec<u8>,
+    signature: DynSignature,
+    payload: Option<Vec<u8>>,
+}
+
+/// Cursor over envelope bytes; every short read is a malformed encoding.
+struct Reader<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, len: usize) -> Result<&'a [u8], SlhDsaError> {
+        if self.bytes.len() < len {
+            return Err(SlhDsaError::MalformedEncoding);
+        }
+        let (head, rest) = self.bytes.split_at(len);
+        self.bytes = rest;
+        Ok(head)
+    }
+
+    fn u8(&mut self) -> Result<u8, SlhDsaError> {
+        Ok(self.take(1)?[0])
+    }
+
+    fn u32(&mut self) -> Result<u32, SlhDsaError> {
+        let bytes = self.take(4)?;
+        Ok(u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+    }
+}
+
+impl Envelope {
+    /// Decode an envelope, accepting payloads up to [`DEFAULT_MAX_PAYLOAD_LEN`].
+    /// The signature is not checked; call [`Self::verify`].
+    pub fn from_bytes(encoded: &[u8]) -> Result<Self, SlhDsaError> {
+        Self::from_bytes_with_limit(encoded, DEFAULT_MAX_PAYLOAD_LEN)
+    }
+
+    /// Decode an envelope whose embedded payload is at most `max_payload_len` bytes.
+    pub fn from_bytes_with_limit(
+        encoded: &[u8],
+        max_payload_len: usize,
+    ) -> Result<Self, SlhDsaError> {
+        let mut reader = Reader { bytes: encoded };
+        if reader.take(MAGIC.len())? != MAGIC || reader.u8()? != VERSION {
+            return Err(SlhDsaError::MalformedEncoding);
+        }
+        let param = ParameterSetId::from_u32(reader.u8()?.into())
+            .ok_or(SlhDsaError::UnsupportedAlgorithm)?;
+        let mode = SignatureMode::from_u8(reader.u8()?).ok_or(SlhDsaError::UnsupportedAlgorithm)?;
+        let flags = reader.u8()?;
+        if flags & !(FLAG_KEY_ID | FLAG_PAYLOAD) != 0 {
+            return Err(SlhDsaError::MalformedEncoding);
+        }
+
+        let key_id = if flags & FLAG_KEY_ID != 0 {
+            let len = reader.u8()?;
+            if len == 0 {
+                return Err(SlhDsaError::MalformedEncoding);
+            }
+            Some(reader.take(len.into())?.to_vec())
+        } else {
+            None
+        };
+        let context_len = reader.u8()?;
+        let context = reader.take(context_len.into())?.to_vec();
+        let signature = reader
+            .take(crate::sig_len_for(param))
+            .map_err(|_| SlhDsaError::MalformedSignature)?;
+        let signature = DynSignature::from_bytes(param, signature)?;
+
+        let payload = if flags & FLAG_PAYLOAD != 0 {
+            let len = reader.u32()? as usize;
+            if len > max_payload_len {
+                return Err(SlhDsaError::InvalidLength);
+            }
+            Some(reader.take(len)?.to_vec())
+        } else {
+            None
+        };
+        if !reader.bytes.is_empty() {
+            return Err(SlhDsaError::MalformedEncoding);
+        }
+
+        Ok(Self {
+            mode,
+            key_id,
+            context,
+            signature,
+            payload,
+        })
+    }
+
+    /// Binary encoding.
+    pub fn to_vec(&self) -> Vec<u8> {
+        let param = self.parameter_set();
+        let mut flags = 0;
+        if self.key_id.is_some() {
+            flags |= FLAG_KEY_ID;
+        }
+        if self.payload.is_some() {
+            flags |= FLAG_PAYLOAD;
+        }
+
+        let mut out =
+            Vec::with_capacity(MAGIC.len() + 6 + crate::sig_len_for(param) + self.context.len());
+        out.extend_from_slice(&MAGIC);
+        out.extend_from_slice(&[VERSION, param as u8, self.mode.to_u8(), flags]);
+        if let Some(key_id) = &self.key_id {
+            out.push(key_id.len() as u8);
+            out.extend_from_slice(key_id);
+        }
+        out.push(self.context.len() as u8);
+        out.extend_from_slice(&self.context);
+        out.extend_from_slice(&self.signature.to_vec());
+        if let Some(payload) = &self.payload {
+            out.extend_from_slice(&(payload.len() as u32).to_be_bytes());
+            out.extend_from_slice(payload);
+        }
+        out
+    }
+
+    /// Parameter set the signature was made with.
+    pub fn parameter_set(&self) -> ParameterSetId {
+        self.signature.parameter_set()
+    }
+
+    /// Pure or pre-hash signing.
+    pub fn mode(&self) -> SignatureMode {
+        self.mode
+    }
+
+    /// Key ID, if any.
+    pub fn key_id(&self) -> Option<&[u8]> {
+        self.key_id.as_deref()
+    }
+
+    /// Context string the signature is bound to.
+    pub fn context(&self) -> &[u8] {
+        &self.context
+    }
+
+    /// The signature itself.
+    pub fn signature(&self) -> &DynSignature {
+        &self.signature
+    }
+
+    /// Embedded payload, or `None` for a detached envelope.
+    pub fn payload(&self) -> Option<&[u8]> {
+        self.payload.as_deref()
+    }
+
+    /// Check the signature, supplying the payload for a detached envelope.
+    pub fn verify(
+        &self,
+        verifying_key: &DynVerifyingKey,
+        detached_payload: Option<&[u8]>,
+    ) -> Result<(), SlhDsaError> {
+        let payload = match (self.payload.as_deref(), detached_payload) {
+            (Some(payload), None) | (None, Some(payload)) => payload,
+            _ => return Err(SlhDsaError::MalformedEncoding),
+        };
+        match self.mode {
+            SignatureMode::Pure => verifying_key.verify(payload, &self.context, &self.signature),
+            SignatureMode::PreHash(algorithm) => verifying_key.verify_prehash(
+                &prehash(algorithm, payload),
+                &self.context,
+                &self.signature,
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_rng::TestRng;
+
+    const PAYLOAD: &[u8] = b"release-1.2.3.tar.gz";
+    const MODES: [SignatureMode; 5] = [
+        SignatureMode::Pure,
+        SignatureMode::PreHash(PreHashAlgorithm::Sha256),
+        SignatureMode::PreHash(PreHashAlgorithm::Sha512),
+        SignatureMode::PreHash(PreHashAlgorithm::Shake128),
+        SignatureMode::PreHash(PreHashAlgorithm::Shake256),
+    ];
+    /// Offset of the flags byte, after the magic, version, parameter set and mode.
+    const FLAGS: usize = MAGIC.len() + 3;
+
+    fn signing_key(rng: &mut TestRng) -> DynSigningKey {
+        DynSigningKey::try_generate(ParameterSetId::Shake128f, rng).unwrap()
+    }
+
+    fn attached(rng: &mut TestRng) -> (DynSigningKey, Vec<u8>) {
+        let sk = signing_key(rng);
+        let envelope = EnvelopeBuilder::new(PAYLOAD)
+            .key_id(b"release-key")
+            .sign(&sk, rng)
+            .unwrap();
+        (sk, envelope.to_vec())
+    }
+
+    #[test]
+    fn wire_values_round_trip() {
+        for mode in MODES {
+            assert_eq!(SignatureMode::from_u8(mode.to_u8()), Some(mode));
+        }
+        assert_eq!(SignatureMode::from_u8(5), None);
+    }
+
+    #[test]
+    fn attached_round_trip_in_every_mode() {
+        let mut rng = TestRng::new(19);
+        let sk = signing_key(&mut rng);
+        let vk = sk.verifying_key();
+        for mode in MODES {
+            let envelope = EnvelopeBuilder::new(PAYLOAD)
+                .key_id(b"release-key")
+                .context(b"ctx")
+                .mode(mode)
+                .sign(&sk, &mut rng)
+                .unwrap();
+            let decoded = Envelope::from_bytes(&envelope.to_vec()).unwrap();
+            assert_eq!(decoded, envelope);
+            assert_eq!(decoded.mode(), mode);
+            assert_eq!(decoded.parameter_set(), ParameterSetId::Shake128f);
+            assert_eq!(decoded.key_id(), Some(&b"release-key"[..]));
+            assert_eq!(decoded.context(), b"ctx");
+            assert_eq!(decoded.payload(), Some(PAYLOAD));
+            assert_eq!(decoded.verify(&vk, None), Ok(()), "{mode:?}");
+        }
+    }
+
+    #[test]
+    fn detached_round_trip_in_every_mode() {
+        let mut rng = TestRng::new(19);
+        let sk = signing_key(&mut rng);
+        let vk = sk.verifying_key();
+        for mode in MODES {
+            let envelope = EnvelopeBuilder::new(PAYLOAD)
+                .mode(mode)
+                .detached(true)
+                .sign(&sk, &mut rng)
+                .unwrap();
+            let encoded = envelope.to_vec();
+            assert_eq!(encoded[FLAGS], 0);
+            assert_eq!(
+                encoded.len(),
+                MAGIC.len() + 5 + crate::sig_len_for(ParameterSetId::Shake128f)
+            );
+            let decoded = Envelope::from_bytes(&encoded).unwrap();
+            assert_eq!(decoded.payload(), None);
+            assert_eq!(decoded.verify(&vk, Some(PAYLOAD)), Ok(()), "{mode:?}");
+            assert_eq!(
+                decoded.verify(&vk, Some(b"release-1.2.4.tar.gz")),
+                Err(SlhDsaError::VerificationFailed)
+            );
+            assert_eq!(
+                decoded.verify(&vk, None),
+                Err(SlhDsaError::MalformedEncoding)
+            );
+        }
+    }
+
+    #[test]
+    fn embedded_and_detached_payload_together_are_rejected() {
+        let mut rng = TestRng::new(19);
+        let (sk, encoded) = attached(&mut rng);
+        let envelope = Envelope::from_bytes(&encoded).unwrap();
+        assert_eq!(
+            envelope.verify(&sk.verifying_key(), Some(PAYLOAD)),
+            Err(SlhDsaError::MalformedEncoding)
+        );
+    }
+
+    #[test]
+    fn malformed_envelopes_are_rejected() {
+        let mut rng = TestRng::new(19);
+        let (_, encoded) = attached(&mut rng);
+
+        let mut trailing = encoded.clone();
+        trailing.push(0);
+        assert_eq!(
+            Envelope::from_bytes(&trailing),
+            Err(SlhDsaError::MalformedEncoding)
+        );
+
+        let mut bad_magic = encoded.clone();
+        bad_magic[0] ^= 1;
+        assert_eq!(
+            Envelope::from_bytes(&bad_magic),
+            Err(SlhDsaError::MalformedEncoding)
+        );
+
+        let mut bad_version = encoded.clone();
+        bad_version[MAGIC.len()] = VERSION + 1;
+        assert_eq!(
+            Envelope::from_bytes(&bad_version),
+            Err(SlhDsaError::MalformedEncoding)
+        );
+
+        let mut unknown_flag = encoded.clone();
+        unknown_flag[FLAGS] |= 0x04;
+        assert_eq!(
+            Envelope::from_bytes(&unknown_flag),
+            Err(SlhDsaError::MalformedEncoding)
+        );
+
+        let mut empty_key_id = encoded.clone();
+        empty_key_id[FLAGS + 1] = 0;
+        assert_eq!(
+            Envelope::from_bytes(&empty_key_id),
+            Err(SlhDsaError::MalformedEncoding)
+        );
+
+        let mut unknown_mode = encoded.clone();
+        unknown_mode[FLAGS - 1] = 5;
+        assert_eq!(
+            Envelope::from_bytes(&unknown_mode),
+            Err(SlhDsaError::UnsupportedAlgorithm)
+        );
+
+        assert_eq!(
+            Envelope::from_bytes(&encoded[..encoded.len() - 1]),
+            Err(SlhDsaError::MalformedEncoding)
+        );
+    }
+
+    #[test]
+    fn payload_over_the_limit_is_rejected() {
+        let mut rng = TestRng::new(19);
+        let (_, encoded) = attached(&mut rng);
+        assert!(Envelope::from_bytes_with_limit(&encoded, PAYLOAD.len()).is_ok());
+        assert_eq!(
+            Envelope::from_bytes_with_limit(&encoded, PAYLOAD.len() - 1),
+            Err(SlhDsaError::InvalidLength)
+        );
+    }
+
+    #[test]
+    fn key_id_length_is_checked_when_signing() {
+        let mut rng = TestRng::new(19);
+        let sk = signing_key(&mut rng);
+        for key_id in [&[][..], &[0; MAX_KEY_ID_LEN + 1]] {
+            assert_eq!(
+                EnvelopeBuilder::new(PAYLOAD)
+                    .key_id(key_id)
+                    .sign(&sk, &mut rng),
+                Err(SlhDsaError::InvalidLength)
+            );
+        }
+    }
+}
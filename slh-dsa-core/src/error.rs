@@ -0,0 +1,91 @@
+//! Error type shared by all slh-dsa-core operations.
+
+use core::fmt;
+
+/// Maximum length of a FIPS 205 context string.
+pub const MAX_CONTEXT_LEN: usize = 255;
+
+/// Reasons an SLH-DSA operation can fail.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum SlhDsaError {
+    /// The context string is longer than 255 bytes.
+    ContextTooLong,
+    /// The signing key bytes could not be decoded.
+    MalformedSigningKey,
+    /// The verifying key bytes could not be decoded.
+    MalformedVerifyingKey,
+    /// The signature bytes could not be decoded.
+    MalformedSignature,
+    /// An input does not have the length required by the parameter set.
+    InvalidLength,
+    /// The key and signature belong to different parameter sets.
+    ParameterSetMismatch,
+    /// The random number generator failed to produce output.
+    RngFailure,
+    /// The signature is not valid for the message, context and key.
+    VerificationFailed,
+    /// An encoded key names an algorithm other than SLH-DSA.
+    UnsupportedAlgorithm,
+    /// DER, PEM or CBOR input could not be parsed.
+    MalformedEncoding,
+    /// A certificate is malformed, or its issuer is not allowed to sign it.
+    InvalidCertificate,
+    /// A certificate is outside its validity period.
+    CertificateExpired,
+    /// A certificate chain does not lead to a trust anchor.
+    UntrustedChain,
+    /// A token is expired or not yet valid.
+    TokenExpired,
+    /// A token is not intended for this audience.
+    AudienceMismatch,
+    /// A self-test failed; the crate refuses further operations.
+    SelfTestFailed,
+    /// A freshly produced signature did not verify, which points to a fault
+    /// during signing; the signature was discarded.
+    FaultDetected,
+    /// An encrypted key could not be decrypted: the passphrase is wrong or
+    /// the data was modified.
+    DecryptionFailed,
+}
+
+impl SlhDsaError {
+    /// Short human-readable description.
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::ContextTooLong => "context string longer than 255 bytes",
+            Self::MalformedSigningKey => "malformed signing key",
+            Self::MalformedVerifyingKey => "malformed verifying key",
+            Self::MalformedSignature => "malformed signature",
+            Self::InvalidLength => "invalid length",
+            Self::ParameterSetMismatch => "parameter set mismatch",
+            Self::RngFailure => "random number generator failure",
+            Self::VerificationFailed => "signature verification failed",
+            Self::UnsupportedAlgorithm => "unsupported algorithm identifier",
+            Self::MalformedEncoding => "malformed DER, PEM or CBOR encoding",
+            Self::InvalidCertificate => "invalid certificate",
+            Self::CertificateExpired => "certificate outside its validity period",
+            Self::UntrustedChain => "certificate chain does not lead to a trust anchor",
+            Self::TokenExpired => "token outside its validity period",
+            Self::AudienceMismatch => "token not intended for this audience",
+            Self::SelfTestFailed => "self-test failed",
+            Self::FaultDetected => "signature failed verify-after-sign check",
+            Self::DecryptionFailed => "wrong passphrase or corrupted encrypted key",
+        }
+    }
+}
+
+impl fmt::Display for SlhDsaError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl core::error::Error for SlhDsaError {}
+
+/// Reject context strings that FIPS 205 does not allow.
+pub(crate) fn check_context(ctx: &[u8]) -> Result<u8, SlhDsaError> {
+    if ctx.len() > MAX_CONTEXT_LEN {
+        return Err(SlhDsaError::ContextTooLong);
+    }
+    Ok(ctx.len() as u8)
+}
@@ -0,0 +1,82 @@
+//! Verify-after-sign countermeasure against fault injection.
+//!
+//! A fault injected while the hypertree is being signed can produce a
+//! signature that leaks WOTS+ or FORS secrets, letting an attacker forge
+//! others. Checking each fresh signature against the signing key's own
+//! verifying key keeps a faulty signature from ever being returned.
+//! [`sign_checked`](crate::sign_checked) applies the check to one call;
+//! [`set_verify_after_sign`] applies it to every signing call in the process.
+
+use crate::{ParameterSet, Signature, SigningKey, SlhDsaError};
+
+use core::sync::atomic::{AtomicBool, Ordering};
+
+static VERIFY_AFTER_SIGN: AtomicBool = AtomicBool::new(false);
+
+/// Verify every signature this crate produces before returning it.
+pub fn set_verify_after_sign(enabled: bool) {
+    VERIFY_AFTER_SIGN.store(enabled, Ordering::Relaxed);
+}
+
+/// Whether [`set_verify_after_sign`] is in effect.
+pub fn verify_after_sign_enabled() -> bool {
+    VERIFY_AFTER_SIGN.load(Ordering::Relaxed)
+}
+
+/// Verify `signature` over the FIPS 205 message M' made of `msg_prime` parts.
+pub(crate) fn check_signature<P: ParameterSet>(
+    signing_key: &SigningKey<P>,
+    msg_prime: &[&[u8]],
+    signature: &Signature<P>,
+) -> Result<(), SlhDsaError> {
+    signing_key
+        .as_ref()
+        .slh_verify_internal(msg_prime, signature)
+        .map_err(|_| SlhDsaError::FaultDetected)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_rng::TestRng;
+    use crate::{DynSigningKey, ParameterSetId, Shake128f};
+
+    #[test]
+    fn sign_checked_returns_a_valid_signature() {
+        let mut rng = TestRng::new(23);
+        let (sk, vk) = crate::try_generate_keypair::<Shake128f, _>(&mut rng).unwrap();
+        let signature = crate::sign_checked(&sk, &mut rng, b"message", b"ctx").unwrap();
+        assert_eq!(
+            crate::verify_with_context(&vk, b"message", b"ctx", &signature),
+            Ok(())
+        );
+        let signature = crate::sign_checked_deterministic(&sk, b"message", b"ctx").unwrap();
+        assert_eq!(
+            signature,
+            crate::sign_deterministic(&sk, b"message", b"ctx").unwrap()
+        );
+
+        let sk = DynSigningKey::try_generate(ParameterSetId::Shake128f, &mut rng).unwrap();
+        let signature = sk.sign_checked(&mut rng, b"message", &[]).unwrap();
+        assert_eq!(
+            sk.verifying_key().verify(b"message", &[], &signature),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn corrupted_signature_is_a_fault() {
+        let mut rng = TestRng::new(23);
+        let (sk, _) = crate::try_generate_keypair::<Shake128f, _>(&mut rng).unwrap();
+        let signature = sk.slh_sign_internal(&[b"message"], None);
+        assert_eq!(check_signature(&sk, &[b"message"], &signature), Ok(()));
+
+        let mut bytes = signature.to_bytes().to_vec();
+        bytes[0] ^= 1;
+        let corrupted = Signature::<Shake128f>::try_from(bytes.as_slice()).unwrap();
+        assert_eq!(
+            check_signature(&sk, &[b"message"], &corrupted),
+            Err(SlhDsaError::FaultDetected)
+        );
+    }
+}
@@ -0,0 +1,245 @@
+//! Key fingerprints: SHA-256 identifiers for verifying keys, with text renderings
+//! for logs and side-by-side human comparison.
+//!
+//! Two inputs are supported. [`DynVerifyingKey::fingerprint`] hashes the
+//! parameter set ID byte followed by the raw FIPS 205 verifying key and needs no
+//! features. [`DynVerifyingKey::spki_fingerprint`] hashes the DER
+//! SubjectPublicKeyInfo, matching what X.509 tooling computes, and needs the
+//! `pkcs8` feature. The two differ for the same key, so pick one per deployment.
+
+use crate::DynVerifyingKey;
+#[cfg(feature = "pkcs8")]
+use crate::SlhDsaError;
+
+use alloc::string::{String, ToString};
+use core::fmt;
+
+use sha2::{Digest, Sha256};
+
+/// Length of a fingerprint in bytes.
+pub const FINGERPRINT_LEN: usize = 32;
+
+/// SHA-256 fingerprint of a verifying key.
+///
+/// The bytes are suitable as an envelope or COSE key ID. `Display` renders
+/// lowercase hex.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct Fingerprint([u8; FINGERPRINT_LEN]);
+
+impl Fingerprint {
+    /// Wrap a fingerprint computed elsewhere.
+    pub const fn from_bytes(bytes: [u8; FINGERPRINT_LEN]) -> Self {
+        Self(bytes)
+    }
+
+    /// Digest bytes.
+    pub const fn as_bytes(&self) -> &[u8; FINGERPRINT_LEN] {
+        &self.0
+    }
+
+    /// Lowercase hex, 64 characters.
+    pub fn to_hex(&self) -> String {
+        self.to_string()
+    }
+
+    /// Unpadded RFC 4648 base32, 52 characters.
+    pub fn to_base32(&self) -> String {
+        let mut out = String::with_capacity(FINGERPRINT_LEN.div_ceil(5) * 8);
+        let mut buffer = 0u16;
+        let mut bits = 0;
+        for byte in self.0 {
+            buffer = (buffer << 8) | u16::from(byte);
+            bits += 8;
+            while bits >= 5 {
+                bits -= 5;
+                out.push(char::from(BASE32[usize::from((buffer >> bits) & 0x1f)]));
+            }
+        }
+        if bits > 0 {
+            out.push(char::from(
+                BASE32[usize::from((buffer << (5 - bits)) & 0x1f)],
+            ));
+        }
+        out
+    }
+
+    /// PGP word list rendering: one word per byte, alternating the even and odd
+    /// lists so swapped or repeated words are noticed when read aloud.
+    pub fn to_words(&self) -> String {
+        let mut out = String::new();
+        for (index, byte) in self.0.iter().enumerate() {
+            if index > 0 {
+                out.push(' ');
+            }
+            let words = if index % 2 == 0 {
+                &EVEN_WORDS
+            } else {
+                &ODD_WORDS
+            };
+            out.push_str(words[usize::from(*byte)]);
+        }
+        out
+    }
+}
+
+impl fmt::Display for Fingerprint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for byte in self.0 {
+            write!(f, "{byte:02x}")?;
+        }
+        Ok(())
+    }
+}
+
+impl AsRef<[u8]> for Fingerprint {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl DynVerifyingKey {
+    /// SHA-256 over the parameter set ID byte and the raw verifying key.
+    pub fn fingerprint(&self) -> Fingerprint {
+        let digest = Sha256::new()
+            .chain_update([self.parameter_set() as u8])
+            .chain_update(self.to_vec())
+            .finalize();
+        Fingerprint(digest.into())
+    }
+
+    /// SHA-256 over the DER SubjectPublicKeyInfo.
+    #[cfg(feature = "pkcs8")]
+    pub fn spki_fingerprint(&self) -> Result<Fingerprint, SlhDsaError> {
+        use pkcs8::EncodePublicKey;
+
+        let spki = self.to_public_key_der()?;
+        Ok(Fingerprint(Sha256::digest(spki.as_bytes()).into()))
+    }
+}
+
+const BASE32: &[u8; 32] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ234567";
+
+/// PGP word list, two-syllable words used at even byte positions.
+#[rustfmt::skip]
+const EVEN_WORDS: [&str; 256] = [
+    "aardvark", "absurd", "accrue", "acme", "adrift", "adult", "afflict", "ahead", "aimless",
+    "Algol", "allow", "alone", "ammo", "ancient", "apple", "artist", "assume", "Athens",
+    "atlas", "Aztec", "baboon", "backfield", "backward", "banjo", "beaming", "bedlamp",
+    "beehive", "beeswax", "befriend", "Belfast", "berserk", "billiard", "bison", "blackjack",
+    "blockade", "blowtorch", "bluebird", "bombast", "bookshelf", "brackish", "breadline",
+    "breakup", "brickyard", "briefcase", "Burbank", "button", "buzzard", "cement", "chairlift",
+    "chatter", "checkup", "chisel", "choking", "chopper", "Christmas", "clamshell", "classic",
+    "classroom", "cleanup", "clockwork", "cobra", "commence", "concert", "cowbell", "crackdown",
+    "cranky", "crowfoot", "crucial", "crumpled", "crusade", "cubic", "dashboard", "deadbolt",
+    "deckhand", "dogsled", "dragnet", "drainage", "dreadful", "drifter", "dropper", "drumbeat",
+    "drunken", "Dupont", "dwelling", "eating", "edict", "egghead", "eightball", "endorse",
+    "endow", "enlist", "erase", "escape", "exceed", "eyeglass", "eyetooth", "facial", "fallout",
+    "flagpole", "flatfoot", "flytrap", "fracture", "framework", "freedom", "frighten",
+    "gazelle", "Geiger", "glitter", "glucose", "goggles", "goldfish", "gremlin", "guidance",
+    "hamlet", "highchair", "hockey", "indoors", "indulge", "inverse", "involve", "island",
+    "jawbone", "keyboard", "kickoff", "kiwi", "klaxon", "locale", "lockup", "merit", "minnow",
+    "miser", "Mohawk", "mural", "music", "necklace", "Neptune", "newborn", "nightbird",
+    "Oakland", "obtuse", "offload", "optic", "orca", "payday", "peachy", "pheasant", "physique",
+    "playhouse", "Pluto", "preclude", "prefer", "preshrunk", "printer", "prowler", "pupil",
+    "puppy", "python", "quadrant", "quiver", "quota", "ragtime", "ratchet", "rebirth", "reform",
+    "regain", "reindeer", "rematch", "repay", "retouch", "revenge", "reward", "rhythm",
+    "ribcage", "ringbolt", "robust", "rocker", "ruffled", "sailboat", "sawdust", "scallion",
+    "scenic", "scorecard", "Scotland", "seabird", "select", "sentence", "shadow", "shamrock",
+    "showgirl", "skullcap", "skydive", "slingshot", "slowdown", "snapline", "snapshot",
+    "snowcap", "snowslide", "solo", "southward", "soybean", "spaniel", "spearhead", "spellbind",
+    "spheroid", "spigot", "spindle", "spyglass", "stagehand", "stagnate", "stairway",
+    "standard", "stapler", "steamship", "sterling", "stockman", "stopwatch", "stormy", "sugar",
+    "surmount", "suspense", "sweatband", "swelter", "tactics", "talon", "tapeworm", "tempest",
+    "tiger", "tissue", "tonic", "topmost", "tracker", "transit", "trauma", "treadmill",
+    "Trojan", "trouble", "tumor", "tunnel", "tycoon", "uncut", "unearth", "unwind", "uproot",
+    "upset", "upshot", "vapor", "village", "virus", "Vulcan", "waffle", "wallet", "watchword",
+    "wayside", "willow", "woodlark", "Zulu",
+];
+
+/// PGP word list, three-syllable words used at odd byte positions.
+#[rustfmt::skip]
+const ODD_WORDS: [&str; 256] = [
+    "adroitness", "adviser", "aftermath", "aggregate", "alkali", "almighty", "amulet",
+    "amusement", "antenna", "applicant", "Apollo", "armistice", "article", "asteroid",
+    "Atlantic", "atmosphere", "autopsy", "Babylon", "backwater", "barbecue", "belowground",
+    "bifocals", "bodyguard", "bookseller", "borderline", "bottomless", "Bradbury", "bravado",
+    "Brazilian", "breakaway", "Burlington", "businessman", "butterfat", "Camelot", "candidate",
+    "cannonball", "Capricorn", "caravan", "caretaker", "celebrate", "cellulose", "certify",
+    "chambermaid", "Cherokee", "Chicago", "clergyman", "coherence", "combustion", "commando",
+    "company", "component", "concurrent", "confidence", "conformist", "congregate", "consensus",
+    "consulting", "corporate", "corrosion", "councilman", "crossover", "crucifix", "cumbersome",
+    "customer", "Dakota", "decadence", "December", "decimal", "designing", "detector",
+    "detergent", "determine", "dictator", "dinosaur", "direction", "disable", "disbelief",
+    "disruptive", "distortion", "document", "embezzle", "enchanting", "enrollment",
+    "enterprise", "equation", "equipment", "escapade", "Eskimo", "everyday", "examine",
+    "existence", "exodus", "fascinate", "filament", "finicky", "forever", "fortitude",
+    "frequency", "gadgetry", "Galveston", "getaway", "glossary", "gossamer", "graduate",
+    "gravity", "guitarist", "hamburger", "Hamilton", "handiwork", "hazardous", "headwaters",
+    "hemisphere", "hesitate", "hideaway", "holiness", "hurricane", "hydraulic", "impartial",
+    "impetus", "inception", "indigo", "inertia", "infancy", "inferno", "informant", "insincere",
+    "insurgent", "integrate", "intention", "inventive", "Istanbul", "Jamaica", "Jupiter",
+    "leprosy", "letterhead", "liberty", "maritime", "matchmaker", "maverick", "Medusa",
+    "megaton", "microscope", "microwave", "midsummer", "millionaire", "miracle", "misnomer",
+    "molasses", "molecule", "Montana", "monument", "mosquito", "narrative", "nebula",
+    "newsletter", "Norwegian", "October", "Ohio", "onlooker", "opulent", "Orlando",
+    "outfielder", "Pacific", "pandemic", "Pandora", "paperweight", "paragon", "paragraph",
+    "paramount", "passenger", "pedigree", "Pegasus", "penetrate", "perceptive", "performance",
+    "pharmacy", "phonetic", "photograph", "pioneer", "pocketful", "politeness", "positive",
+    "potato", "processor", "provincial", "proximate", "puberty", "publisher", "pyramid",
+    "quantity", "racketeer", "rebellion", "recipe", "recover", "repellent", "replica",
+    "reproduce", "resistor", "responsive", "retraction", "retrieval", "retrospect", "revenue",
+    "revival", "revolver", "sandalwood", "sardonic", "Saturday", "savagery", "scavenger",
+    "sensation", "sociable", "souvenir", "specialist", "speculate", "stethoscope", "stupendous",
+    "supportive", "surrender", "suspicious", "sympathy", "tambourine", "telephone", "therapist",
+    "tobacco", "tolerance", "tomorrow", "torpedo", "tradition", "travesty", "trombonist",
+    "truncated", "typewriter", "ultimate", "undaunted", "underfoot", "unicorn", "unify",
+    "universe", "unravel", "upcoming", "vacancy", "vagabond", "vertigo", "Virginia", "visitor",
+    "vocalist", "voyager", "warranty", "Waterloo", "whimsical", "Wichita", "Wilmington",
+    "Wyoming", "yesteryear", "Yucatan",
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ParameterSetId;
+
+    /// SLH-DSA-SHAKE-128f key 00 01 .. 1f; the digest was computed with Python's hashlib.
+    fn fixed_key() -> DynVerifyingKey {
+        let bytes: [u8; 32] = core::array::from_fn(|i| i as u8);
+        DynVerifyingKey::from_bytes(ParameterSetId::Shake128f, &bytes).unwrap()
+    }
+
+    #[test]
+    fn fixed_key_renderings() {
+        let fingerprint = fixed_key().fingerprint();
+        assert_eq!(
+            fingerprint.to_hex(),
+            "121e01fd47d8c2ecdb10fa6f0a51a97a48cebd0de5231f274f5076a03e371868"
+        );
+        assert_eq!(
+            fingerprint.to_base32(),
+            "CIPAD7KH3DBOZWYQ7JXQUUNJPJEM5PIN4URR6J2PKB3KAPRXDBUA"
+        );
+        assert_eq!(
+            fingerprint.to_words(),
+            "atlas Burlington absurd Wyoming dashboard stupendous snapshot unicorn suspense \
+             autopsy wallet hemisphere allow enchanting revenge infancy deadbolt sardonic \
+             skullcap asteroid topmost cannonball billiard celebrate dropper embezzle inverse \
+             Orlando concert consensus beaming gravity"
+        );
+        assert_eq!(fingerprint.to_string(), fingerprint.to_hex());
+        assert_eq!(
+            Fingerprint::from_bytes(*fingerprint.as_bytes()),
+            fingerprint
+        );
+    }
+
+    #[test]
+    fn word_lists_alternate() {
+        let fingerprint =
+            Fingerprint::from_bytes([0x00, 0x00, 0xff, 0xff].repeat(8).try_into().unwrap());
+        assert!(fingerprint
+            .to_words()
+            .starts_with("aardvark adroitness Zulu Yucatan aardvark"));
+    }
+}
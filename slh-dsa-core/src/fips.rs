@@ -0,0 +1,221 @@
+//! FIPS 140-3 style self-tests, enabled by the `fips` feature.
+//!
+//! The first time a parameter set is used, its cryptographic algorithm
+//! self-test derives a key pair from fixed seeds, signs a fixed message
+//! deterministically and verifies the result, comparing the verifying key and
+//! signature against known SHA-256 digests. Signing dominates the cost, so the
+//! first use of a small-signature (`s`) set takes noticeably longer. Every key
+//! pair this crate generates also goes through a pairwise consistency test.
+//!
+//! Any failure puts the crate into a permanent error state: from then on every
+//! key generation, key decoding, signing and verification call returns
+//! [`SlhDsaError::SelfTestFailed`].
+
+use crate::{
+    ParameterSet, ParameterSetId, Sha2_128f, Sha2_128s, Sha2_192f, Sha2_192s, Sha2_256f, Sha2_256s,
+    Shake128f, Shake128s, Shake192f, Shake192s, Shake256f, Shake256s, SigningKey, SlhDsaError,
+};
+
+use core::sync::atomic::{AtomicBool, Ordering};
+
+use sha2::{Digest, Sha256};
+
+/// Seed material for the known-answer test; each seed takes the next n bytes.
+const CAST_SEEDS: [u8; 96] = {
+    let mut seeds = [0u8; 96];
+    let mut i = 0;
+    while i < seeds.len() {
+        seeds[i] = i as u8;
+        i += 1;
+    }
+    seeds
+};
+
+const CAST_MSG: &[u8] = b"SLH-DSA cryptographic algorithm self-test";
+const PCT_MSG: &[u8] = b"SLH-DSA pairwise consistency test";
+
+/// SHA-256 digests of the expected verifying key and signature.
+struct KnownAnswer {
+    verifying_key: [u8; 32],
+    signature: [u8; 32],
+}
+
+const fn kat(verifying_key: &str, signature: &str) -> KnownAnswer {
+    KnownAnswer {
+        verifying_key: unhex(verifying_key),
+        signature: unhex(signature),
+    }
+}
+
+const fn unhex(hex: &str) -> [u8; 32] {
+    const fn nibble(c: u8) -> u8 {
+        match c {
+            b'0'..=b'9' => c - b'0',
+            b'a'..=b'f' => c - b'a' + 10,
+            _ => panic!("invalid hex digit"),
+        }
+    }
+
+    let hex = hex.as_bytes();
+    assert!(hex.len() == 64);
+    let mut out = [0u8; 32];
+    let mut i = 0;
+    while i < out.len() {
+        out[i] = nibble(hex[2 * i]) << 4 | nibble(hex[2 * i + 1]);
+        i += 1;
+    }
+    out
+}
+
+/// Known answers for every parameter set, in `ParameterSetId` order.
+///
+/// Produced by running this crate: each key pair comes from
+/// `keypair_from_seed` with `CAST_SEEDS` split into SK.seed, SK.prf and PK.seed,
+/// and each signature from `slh_sign_internal` over `CAST_MSG` without
+/// randomness; the entries are SHA-256 of the raw verifying key and signature.
+/// They were cross-checked against OpenSSL 3.5, which produces the same
+/// signatures from the same PKCS#8 key with `pkeyutl -sign -rawin -pkeyopt
+/// deterministic:1 -pkeyopt message-encoding:0` and verifies them.
+#[rustfmt::skip]
+const KNOWN_ANSWERS: [KnownAnswer; 12] = [
+    // SLH-DSA-SHAKE-128s
+    kat("00bcf55c114dd213b09d26bf41dbc06a423ca37c5a52d912c0d8a376ca99bf9f", "2030af791884da3c4e40ce82c2e95a141f75dda7b5481ce52cdfadaaa9b87248"),
+    // SLH-DSA-SHAKE-128f
+    kat("99197c0060fec43eaefd42ebb68848f66cf3b2db814670dee2ac5c37d4aade64", "fb56f6f8c34986bb9b35b6a4fa3d19f3812bb84f6f12731121bb5f21fa6b2e3a"),
+    // SLH-DSA-SHAKE-192s
+    kat("ffb7e28bb0e77087cbbf2ebcb443675845b1641feac49ac4b3245fb7dea60c9a", "b520e75f7deb7a81b32677c47047390824317d59c7fab6b68e952128a3d329a6"),
+    // SLH-DSA-SHAKE-192f
+    kat("9fe0ba0705788e84b64e4b9135e57653f10d86f151fefd650148a345b31dc5a9", "f8b6d4c25dbfd81686fb3b325c31d9ea1dc16989aa5f4290f2efc308c2e2629e"),
+    // SLH-DSA-SHAKE-256s
+    kat("00080bd75052db3647b01a476d5c3b36b7f8623012c24fb5faac608db02ecef5", "5b93c0202b0e920ec7d42bbf432352b2766624943a8845161124477fcaad018b"),
+    // SLH-DSA-SHAKE-256f
+    kat("dd5905dec5af9c92a466e9bae1935ecdc05a4b714604999effae812b1ce3ae5e", "5f96f569a573a7c66e2efae6d12fa978fd10d4752077d2f17a0db4c58de5e0f6"),
+    // SLH-DSA-SHA2-128s
+    kat("9d5b69aa74c4939468bae5108568a4d0926f2532c96b0742eda0a7eff4c99c3e", "9613955b66eb0d4fed84f03b784dfa21156e910c4f1b9d3e312094f6f3bd8664"),
+    // SLH-DSA-SHA2-128f
+    kat("f577b74987c6313ffa546b8885ca8450c7f427c20f0d9b52d166264016b8beba", "acda8904f0e33076c2cfed355ecbe24e4494cf9c7d8277b2e85161ce322ad904"),
+    // SLH-DSA-SHA2-192s
+    kat("ea0e06991d977e4766be1a49fedf2132dcf7361e36e41e999e1b9d2923685743", "32a04bcfe8921dd9dcf8d7322c681a5182d0aac40320079ea95e8a86fef71253"),
+    // SLH-DSA-SHA2-192f
+    kat("305951b67f8a79021a7f6ad01ac0e4ecc111f7c8aeb07579741431f1e6cbe9d9", "3fc463f4bc4fb052523fd0f2d4f7c21de1ee98517c83164d55bed08e0a57b8ef"),
+    // SLH-DSA-SHA2-256s
+    kat("77bc2592929e69ec3a8546a07b032c8c61db4b1241f89d06821a842e39bb8edd", "0c94ba5c695bdfc83565f6c51842c46cb5848238747be0010ce4b696719889a6"),
+    // SLH-DSA-SHA2-256f
+    kat("6aea562ed5cc1b0883f84bb6e198b32f0a00e62fc96da86efc1b199b4ab09a22", "40d40503247d5c5fcd4b925deebb6dfb18953a5f9fb61d1895ca5d04086c7f7a"),
+];
+
+static FAILED: AtomicBool = AtomicBool::new(false);
+static TESTED: [AtomicBool; 12] = [const { AtomicBool::new(false) }; 12];
+
+/// Whether every self-test run so far has passed.
+pub fn is_operational() -> bool {
+    !FAILED.load(Ordering::Acquire)
+}
+
+/// Run the known-answer self-test for `param` now, even if it already passed.
+pub fn self_test(param: ParameterSetId) -> Result<(), SlhDsaError> {
+    check_operational()?;
+    let index = param as usize - 1;
+    let passed = with_parameter_set!(param, |P| cast::<P>(&KNOWN_ANSWERS[index]));
+    record(passed)?;
+    TESTED[index].store(true, Ordering::Release);
+    Ok(())
+}
+
+/// Run the known-answer self-test for every parameter set.
+pub fn self_test_all() -> Result<(), SlhDsaError> {
+    ParameterSetId::ALL.into_iter().try_for_each(self_test)
+}
+
+/// Enter the error state as if a self-test had failed.
+///
+/// Meant for exercising an application's handling of
+/// [`SlhDsaError::SelfTestFailed`]; like a real failure, it lasts for the rest
+/// of the process.
+pub fn inject_failure() {
+    FAILED.store(true, Ordering::Release);
+}
+
+/// Refuse to run after a failure, and self-test `param` on its first use.
+pub(crate) fn ensure(param: ParameterSetId) -> Result<(), SlhDsaError> {
+    check_operational()?;
+    if TESTED[param as usize - 1].load(Ordering::Acquire) {
+        return Ok(());
+    }
+    self_test(param)
+}
+
+/// [`ensure`] for a parameter set given as a type.
+pub(crate) fn ensure_for<P: ParameterSet>() -> Result<(), SlhDsaError> {
+    ParameterSetId::from_name(P::NAME)
+        .ok_or(SlhDsaError::UnsupportedAlgorithm)
+        .and_then(ensure)
+}
+
+/// Sign and verify with a freshly generated key pair before it is released.
+pub(crate) fn pairwise_consistency_test<P: ParameterSet>(
+    signing_key: &SigningKey<P>,
+) -> Result<(), SlhDsaError> {
+    let signature = signing_key.slh_sign_internal(&[PCT_MSG], None);
+    let verified = signing_key
+        .as_ref()
+        .slh_verify_internal(&[PCT_MSG], &signature)
+        .is_ok();
+    record(verified)
+}
+
+fn check_operational() -> Result<(), SlhDsaError> {
+    match is_operational() {
+        true => Ok(()),
+        false => Err(SlhDsaError::SelfTestFailed),
+    }
+}
+
+fn record(passed: bool) -> Result<(), SlhDsaError> {
+    if !passed {
+        FAILED.store(true, Ordering::Release);
+        return Err(SlhDsaError::SelfTestFailed);
+    }
+    Ok(())
+}
+
+fn cast<P: ParameterSet>(expected: &KnownAnswer) -> bool {
+    let n = crate::seed_len::<P>();
+    let signing_key = SigningKey::<P>::slh_keygen_internal(
+        &CAST_SEEDS[..n],
+        &CAST_SEEDS[n..2 * n],
+        &CAST_SEEDS[2 * n..3 * n],
+    );
+    let verifying_key = signing_key.as_ref();
+    if Sha256::digest(verifying_key.to_bytes())[..] != expected.verifying_key {
+        return false;
+    }
+
+    let signature = signing_key.slh_sign_internal(&[CAST_MSG], None);
+    Sha256::digest(signature.to_bytes())[..] == expected.signature
+        && verifying_key
+            .slh_verify_internal(&[CAST_MSG], &signature)
+            .is_ok()
+}
+
+#[cfg(all(test, feature = "fips"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn known_answers_pass_for_every_parameter_set() {
+        assert_eq!(self_test_all(), Ok(()));
+        assert!(is_operational());
+        assert!(TESTED.iter().all(|tested| tested.load(Ordering::Acquire)));
+    }
+
+    #[test]
+    fn infallible_keygen_runs_the_self_tests() {
+        let mut rng = crate::test_rng::TestRng::new(22);
+        let (signing_key, verifying_key) = crate::generate_keypair::<Shake128f, _>(&mut rng);
+        assert_eq!(signing_key.as_ref(), &verifying_key);
+        assert!(TESTED[ParameterSetId::Shake128f as usize - 1].load(Ordering::Acquire));
+        let dyn_key = crate::DynSigningKey::generate(ParameterSetId::Shake128f, &mut rng);
+        assert_eq!(dyn_key.parameter_set(), ParameterSetId::Shake128f);
+    }
+}
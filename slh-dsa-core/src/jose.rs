@@ -0,0 +1,601 @@
+//! JOSE encodings for SLH-DSA: AKP JSON Web Keys (JWK), JWS and JWT.
+//!
+//! The JOSE `alg` value of a parameter set is its FIPS 205 name, as in the
+//! JOSE and COSE SLH-DSA draft. Keys carry the raw FIPS 205 encodings,
+//! base64url encoded, in `pub` and `priv`. JWS signatures are pure SLH-DSA with
+//! an empty context over the JWS signing input.
+
+use crate::{DynSignature, DynSigningKey, DynVerifyingKey, ParameterSetId, SlhDsaError};
+
+use alloc::borrow::ToOwned;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::time::Duration;
+
+use base64ct::{Base64UrlUnpadded, Encoding};
+use rand_core::TryCryptoRng;
+use serde_json::{Map, Value};
+use sha2::{Digest, Sha256};
+
+/// JWK key type for algorithm key pairs.
+pub const KTY_AKP: &str = "AKP";
+
+impl ParameterSetId {
+    /// JOSE `alg` header value.
+    pub const fn jose_algorithm(self) -> &'static str {
+        self.name()
+    }
+
+    /// Parameter set identified by a JOSE `alg` value.
+    pub fn from_jose_algorithm(alg: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|param| param.jose_algorithm() == alg)
+    }
+}
+
+fn decode_base64url(encoded: &str) -> Result<Vec<u8>, SlhDsaError> {
+    Base64UrlUnpadded::decode_vec(encoded).map_err(|_| SlhDsaError::MalformedEncoding)
+}
+
+fn parse_object(json: &[u8]) -> Result<Map<String, Value>, SlhDsaError> {
+    match serde_json::from_slice(json) {
+        Ok(Value::Object(object)) => Ok(object),
+        _ => Err(SlhDsaError::MalformedEncoding),
+    }
+}
+
+/// Optional string member; any other JSON type is an encoding error.
+fn string_member<'a>(
+    object: &'a Map<String, Value>,
+    name: &str,
+) -> Result<Option<&'a str>, SlhDsaError> {
+    object
+        .get(name)
+        .map(|value| value.as_str().ok_or(SlhDsaError::MalformedEncoding))
+        .transpose()
+}
+
+fn algorithm_member(object: &Map<String, Value>) -> Result<ParameterSetId, SlhDsaError> {
+    let alg = string_member(object, "alg")?.ok_or(SlhDsaError::MalformedEncoding)?;
+    ParameterSetId::from_jose_algorithm(alg).ok_or(SlhDsaError::UnsupportedAlgorithm)
+}
+
+fn encode_jwk(param: ParameterSetId, public: &[u8], private: Option<&[u8]>) -> String {
+    // Members serialize in lexicographic order, which RFC 7638 thumbprints rely on.
+    let mut jwk = Map::new();
+    jwk.insert("alg".into(), param.jose_algorithm().into());
+    jwk.insert("kty".into(), KTY_AKP.into());
+    jwk.insert(
+        "pub".into(),
+        Base64UrlUnpadded::encode_string(public).into(),
+    );
+    if let Some(private) = private {
+        jwk.insert(
+            "priv".into(),
+            Base64UrlUnpadded::encode_string(private).into(),
+        );
+    }
+    Value::Object(jwk).to_string()
+}
+
+/// Decoded AKP key: parameter set, `pub` and `priv`.
+type AkpKey = (ParameterSetId, Option<Vec<u8>>, Option<Vec<u8>>);
+
+fn decode_jwk(json: &str) -> Result<AkpKey, SlhDsaError> {
+    let jwk = parse_object(json.as_bytes())?;
+    if string_member(&jwk, "kty")? != Some(KTY_AKP) {
+        return Err(SlhDsaError::UnsupportedAlgorithm);
+    }
+    let param = algorithm_member(&jwk)?;
+    let public = string_member(&jwk, "pub")?
+        .map(decode_base64url)
+        .transpose()?;
+    let private = string_member(&jwk, "priv")?
+        .map(decode_base64url)
+        .transpose()?;
+    Ok((param, public, private))
+}
+
+impl DynVerifyingKey {
+    /// Encode as an AKP JWK with `alg`, `kty` and `pub`.
+    pub fn to_jwk(&self) -> String {
+        encode_jwk(self.parameter_set(), &self.to_vec(), None)
+    }
+
+    /// Decode an AKP JWK, taking the parameter set from its `alg`.
+    pub fn from_jwk(json: &str) -> Result<Self, SlhDsaError> {
+        let (param, public, _) = decode_jwk(json)?;
+        Self::from_bytes(param, &public.ok_or(SlhDsaError::MalformedVerifyingKey)?)
+    }
+
+    /// RFC 7638 JWK thumbprint: base64url SHA-256 over the required members.
+    pub fn jwk_thumbprint(&self) -> String {
+        Base64UrlUnpadded::encode_string(&Sha256::digest(self.to_jwk()))
+    }
+}
+
+impl DynSigningKey {
+    /// Encode as an AKP JWK carrying both `pub` and `priv`.
+    pub fn to_jwk(&self) -> String {
+        encode_jwk(
+            self.parameter_set(),
+            &self.verifying_key().to_vec(),
+            Some(&self.to_vec()),
+        )
+    }
+
+    /// Decode an AKP JWK with a `priv` member.
+    ///
+    /// When `pub` is present it must match the signing key.
+    pub fn from_jwk(json: &str) -> Result<Self, SlhDsaError> {
+        let (param, public, private) = decode_jwk(json)?;
+        let key = Self::from_bytes(param, &private.ok_or(SlhDsaError::MalformedSigningKey)?)?;
+        if public.is_some_and(|public| public != key.verifying_key().to_vec()) {
+            return Err(SlhDsaError::MalformedSigningKey);
+        }
+        Ok(key)
+    }
+}
+
+/// Builds a JWS over an arbitrary payload.
+#[derive(Clone, Debug)]
+pub struct JwsBuilder {
+    payload: Vec<u8>,
+    key_id: Option<String>,
+    header_type: Option<String>,
+}
+
+impl JwsBuilder {
+    /// Sign `payload`.
+    pub fn new(payload: &[u8]) -> Self {
+        Self {
+            payload: payload.to_vec(),
+            key_id: None,
+            header_type: None,
+        }
+    }
+
+    /// Put a `kid` in the protected header.
+    pub fn key_id(mut self, key_id: &str) -> Self {
+        self.key_id = Some(key_id.to_owned());
+        self
+    }
+
+    /// Put a `typ` in the protected header, e.g. `JWT`.
+    pub fn header_type(mut self, header_type: &str) -> Self {
+        self.header_type = Some(header_type.to_owned());
+        self
+    }
+
+    /// Sign with `signing_key`, whose parameter set determines the `alg` header.
+    pub fn sign<R: TryCryptoRng>(
+        self,
+        signing_key: &DynSigningKey,
+        rng: &mut R,
+    ) -> Result<Jws, SlhDsaError> {
+        let param = signing_key.parameter_set();
+        let mut header = Map::new();
+        header.insert("alg".into(), param.jose_algorithm().into());
+        if let Some(key_id) = &self.key_id {
+            header.insert("kid".into(), key_id.as_str().into());
+        }
+        if let Some(header_type) = &self.header_type {
+            header.insert("typ".into(), header_type.as_str().into());
+        }
+
+        let protected =
+            Base64UrlUnpadded::encode_string(Value::Object(header).to_string().as_bytes());
+        let encoded_payload = Base64UrlUnpadded::encode_string(&self.payload);
+        let signing_input = format!("{protected}.{encoded_payload}");
+        let signature = signing_key.try_sign_with_rng(rng, signing_input.as_bytes(), &[])?;
+
+        Ok(Jws {
+            protected,
+            encoded_payload,
+            payload: self.payload,
+            param,
+            key_id: self.key_id,
+            header_type: self.header_type,
+            signature: signature.to_vec(),
+        })
+    }
+}
+
+/// Parsed JWS with a single SLH-DSA signature.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Jws {
+    /// base64url protected header, exactly as signed.
+    protected: String,
+    /// base64url payload, exactly as signed.
+    encoded_payload: String,
+    payload: Vec<u8>,
+    param: ParameterSetId,
+    key_id: Option<String>,
+    header_type: Option<String>,
+    signature: Vec<u8>,
+}
+
+impl Jws {
+    /// Decode the compact serialization. The signature is not checked; call
+    /// [`Self::verify`].
+    pub fn from_compact(compact: &str) -> Result<Self, SlhDsaError> {
+        let mut parts = compact.split('.');
+        match (parts.next(), parts.next(), parts.next(), parts.next()) {
+            (Some(protected), Some(payload), Some(signature), None) => {
+                Self::from_parts(protected, payload, signature)
+            }
+            _ => Err(SlhDsaError::MalformedEncoding),
+        }
+    }
+
+    /// Decode the flattened or general JSON serialization with one signature.
+    pub fn from_json(json: &str) -> Result<Self, SlhDsaError> {
+        let jws = parse_object(json.as_bytes())?;
+        let payload = string_member(&jws, "payload")?.ok_or(SlhDsaError::MalformedEncoding)?;
+        let signer = match jws.get("signatures") {
+            Some(Value::Array(signatures)) => match signatures.as_slice() {
+                [Value::Object(signer)] => signer,
+                _ => return Err(SlhDsaError::MalformedEncoding),
+            },
+            Some(_) => return Err(SlhDsaError::MalformedEncoding),
+            None => &jws,
+        };
+        let protected =
+            string_member(signer, "protected")?.ok_or(SlhDsaError::MalformedEncoding)?;
+        let signature =
+            string_member(signer, "signature")?.ok_or(SlhDsaError::MalformedEncoding)?;
+        Self::from_parts(protected, payload, signature)
+    }
+
+    fn from_parts(protected: &str, payload: &str, signature: &str) -> Result<Self, SlhDsaError> {
+        let header = parse_object(&decode_base64url(protected)?)?;
+        // No extension is understood, so any critical one must be refused (RFC 7515 section 4.1.11).
+        if header.contains_key("crit") {
+            return Err(SlhDsaError::UnsupportedAlgorithm);
+        }
+        let param = algorithm_member(&header)?;
+        let key_id = string_member(&header, "kid")?.map(str::to_owned);
+        let header_type = string_member(&header, "typ")?.map(str::to_owned);
+
+        let signature = decode_base64url(signature).map_err(|_| SlhDsaError::MalformedSignature)?;
+        if signature.len() != crate::sig_len_for(param) {
+            return Err(SlhDsaError::MalformedSignature);
+        }
+
+        Ok(Self {
+            protected: protected.to_owned(),
+            encoded_payload: payload.to_owned(),
+            payload: decode_base64url(payload)?,
+            param,
+            key_id,
+            header_type,
+            signature,
+        })
+    }
+
+    /// Compact serialization.
+    pub fn to_compact(&self) -> String {
+        format!(
+            "{}.{}.{}",
+            self.protected,
+            self.encoded_payload,
+            Base64UrlUnpadded::encode_string(&self.signature)
+        )
+    }
+
+    /// Flattened JSON serialization.
+    pub fn to_json(&self) -> String {
+        let mut jws = Map::new();
+        jws.insert("payload".into(), self.encoded_payload.as_str().into());
+        jws.insert("protected".into(), self.protected.as_str().into());
+        jws.insert(
+            "signature".into(),
+            Base64UrlUnpadded::encode_string(&self.signature).into(),
+        );
+        Value::Object(jws).to_string()
+    }
+
+    /// Parameter set named by the `alg` header.
+    pub fn parameter_set(&self) -> ParameterSetId {
+        self.param
+    }
+
+    /// `kid` header, if any.
+    pub fn key_id(&self) -> Option<&str> {
+        self.key_id.as_deref()
+    }
+
+    /// `typ` header, if any.
+    pub fn header_type(&self) -> Option<&str> {
+        self.header_type.as_deref()
+    }
+
+    /// Decoded payload.
+    pub fn payload(&self) -> &[u8] {
+        &self.payload
+    }
+
+    /// Check the signature against `verifying_key`.
+    pub fn verify(&self, verifying_key: &DynVerifyingKey) -> Result<(), SlhDsaError> {
+        if verifying_key.parameter_set() != self.param {
+            return Err(SlhDsaError::ParameterSetMismatch);
+        }
+        let signature = DynSignature::from_bytes(self.param, &self.signature)?;
+        let signing_input = format!("{}.{}", self.protected, self.encoded_payload);
+        verifying_key.verify(signing_input.as_bytes(), &[], &signature)
+    }
+}
+
+/// Issue a compact JWT with `typ: JWT` over a JSON object of claims.
+pub fn issue_jwt<R: TryCryptoRng>(
+    signing_key: &DynSigningKey,
+    claims: &str,
+    key_id: Option<&str>,
+    rng: &mut R,
+) -> Result<String, SlhDsaError> {
+    parse_object(claims.as_bytes())?;
+    let mut builder = JwsBuilder::new(claims.as_bytes()).header_type("JWT");
+    if let Some(key_id) = key_id {
+        builder = builder.key_id(key_id);
+    }
+    Ok(builder.sign(signing_key, rng)?.to_compact())
+}
+
+/// Claim checks applied by [`validate_jwt`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct JwtValidation {
+    now: Duration,
+    leeway: Duration,
+    audience: Option<String>,
+}
+
+impl JwtValidation {
+    /// Validate at `now`, measured from the Unix epoch, with no leeway and no audience.
+    pub fn new(now: Duration) -> Self {
+        Self {
+            now,
+            leeway: Duration::ZERO,
+            audience: None,
+        }
+    }
+
+    /// Clock skew tolerated on `exp` and `nbf`.
+    pub fn leeway(mut self, leeway: Duration) -> Self {
+        self.leeway = leeway;
+        self
+    }
+
+    /// Require `aud` to name this audience.
+    pub fn audience(mut self, audience: &str) -> Self {
+        self.audience = Some(audience.to_owned());
+        self
+    }
+}
+
+fn numeric_date(claims: &Map<String, Value>, name: &str) -> Result<Option<f64>, SlhDsaError> {
+    claims
+        .get(name)
+        .map(|value| value.as_f64().ok_or(SlhDsaError::MalformedEncoding))
+        .transpose()
+}
+
+/// Verify a compact JWT and check `exp`, `nbf` and `aud`, returning its claims JSON.
+///
+/// `aud` must be present and name the expected audience when `validation` sets one,
+/// and absent otherwise.
+pub fn validate_jwt(
+    token: &str,
+    verifying_key: &DynVerifyingKey,
+    validation: &JwtValidation,
+) -> Result<String, SlhDsaError> {
+    let jws = Jws::from_compact(token)?;
+    jws.verify(verifying_key)?;
+    let claims = parse_object(jws.payload())?;
+
+    let now = validation.now.as_secs_f64();
+    let leeway = validation.leeway.as_secs_f64();
+    if numeric_date(&claims, "exp")?.is_some_and(|exp| now >= exp + leeway)
+        || numeric_date(&claims, "nbf")?.is_some_and(|nbf| now + leeway < nbf)
+    {
+        return Err(SlhDsaError::TokenExpired);
+    }
+
+    let accepted = match (claims.get("aud"), &validation.audience) {
+        (None, None) => true,
+        (Some(Value::String(aud)), Some(audience)) => aud == audience,
+        (Some(Value::Array(auds)), Some(audience)) => auds
+            .iter()
+            .any(|aud| aud.as_str() == Some(audience.as_str())),
+        (Some(Value::String(_) | Value::Array(_)), None) | (None, Some(_)) => false,
+        (Some(_), _) => return Err(SlhDsaError::MalformedEncoding),
+    };
+    if !accepted {
+        return Err(SlhDsaError::AudienceMismatch);
+    }
+
+    String::from_utf8(jws.payload().to_vec()).map_err(|_| SlhDsaError::MalformedEncoding)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_rng::TestRng;
+
+    fn signing_key(rng: &mut TestRng) -> DynSigningKey {
+        DynSigningKey::try_generate(ParameterSetId::Shake128f, rng).unwrap()
+    }
+
+    fn validation(now: u64) -> JwtValidation {
+        JwtValidation::new(Duration::from_secs(now))
+    }
+
+    #[test]
+    fn jwk_round_trip() {
+        let mut rng = TestRng::new(17);
+        let sk = signing_key(&mut rng);
+        let vk = sk.verifying_key();
+
+        let public = vk.to_jwk();
+        assert_eq!(DynVerifyingKey::from_jwk(&public), Ok(vk.clone()));
+        assert_eq!(
+            DynSigningKey::from_jwk(&public).err(),
+            Some(SlhDsaError::MalformedSigningKey)
+        );
+        let private = sk.to_jwk();
+        assert_eq!(DynSigningKey::from_jwk(&private), Ok(sk));
+        assert_eq!(DynVerifyingKey::from_jwk(&private), Ok(vk));
+
+        assert_eq!(
+            DynVerifyingKey::from_jwk(&public.replace("AKP", "OKP")),
+            Err(SlhDsaError::UnsupportedAlgorithm)
+        );
+        assert_eq!(
+            DynVerifyingKey::from_jwk(&public.replace("SLH-DSA-SHAKE-128f", "EdDSA")),
+            Err(SlhDsaError::UnsupportedAlgorithm)
+        );
+    }
+
+    #[test]
+    fn thumbprint_covers_alg_kty_and_pub() {
+        let mut rng = TestRng::new(17);
+        let sk = signing_key(&mut rng);
+        let vk = sk.verifying_key();
+
+        let required = format!(
+            r#"{{"alg":"SLH-DSA-SHAKE-128f","kty":"AKP","pub":"{}"}}"#,
+            Base64UrlUnpadded::encode_string(&vk.to_vec())
+        );
+        assert_eq!(vk.to_jwk(), required);
+        assert_eq!(
+            vk.jwk_thumbprint(),
+            Base64UrlUnpadded::encode_string(&Sha256::digest(required))
+        );
+        // `priv` and any optional member stay out of the thumbprint.
+        let with_kid = sk.to_jwk().replace('{', r#"{"kid":"device-1","#);
+        let decoded = DynSigningKey::from_jwk(&with_kid).unwrap();
+        assert_eq!(
+            decoded.verifying_key().jwk_thumbprint(),
+            vk.jwk_thumbprint()
+        );
+    }
+
+    #[test]
+    fn jws_compact_sign_and_verify() {
+        let mut rng = TestRng::new(17);
+        let sk = signing_key(&mut rng);
+        let vk = sk.verifying_key();
+
+        let jws = JwsBuilder::new(b"payload")
+            .key_id("device-1")
+            .sign(&sk, &mut rng)
+            .unwrap();
+        let compact = jws.to_compact();
+        let decoded = Jws::from_compact(&compact).unwrap();
+        assert_eq!(decoded, jws);
+        assert_eq!(decoded.parameter_set(), ParameterSetId::Shake128f);
+        assert_eq!(decoded.key_id(), Some("device-1"));
+        assert_eq!(decoded.payload(), b"payload");
+        assert_eq!(decoded.verify(&vk), Ok(()));
+        assert_eq!(Jws::from_json(&jws.to_json()), Ok(jws));
+
+        let (signing_input, signature) = compact.rsplit_once('.').unwrap();
+        let (header, _) = signing_input.split_once('.').unwrap();
+        let tampered = format!(
+            "{header}.{}.{signature}",
+            Base64UrlUnpadded::encode_string(b"payloaD")
+        );
+        assert_eq!(
+            Jws::from_compact(&tampered).unwrap().verify(&vk),
+            Err(SlhDsaError::VerificationFailed)
+        );
+        assert_eq!(
+            Jws::from_compact(&format!("{compact}.")),
+            Err(SlhDsaError::MalformedEncoding)
+        );
+    }
+
+    #[test]
+    fn critical_header_is_rejected() {
+        let mut rng = TestRng::new(17);
+        let sk = signing_key(&mut rng);
+        let compact = JwsBuilder::new(b"payload")
+            .sign(&sk, &mut rng)
+            .unwrap()
+            .to_compact();
+        let (_, rest) = compact.split_once('.').unwrap();
+        let header = Base64UrlUnpadded::encode_string(
+            br#"{"alg":"SLH-DSA-SHAKE-128f","crit":["exp"],"exp":1}"#,
+        );
+        assert_eq!(
+            Jws::from_compact(&format!("{header}.{rest}")),
+            Err(SlhDsaError::UnsupportedAlgorithm)
+        );
+    }
+
+    #[test]
+    fn jwt_time_claims_at_the_leeway_edges() {
+        let mut rng = TestRng::new(17);
+        let sk = signing_key(&mut rng);
+        let vk = sk.verifying_key();
+        let leeway = Duration::from_secs(10);
+
+        let expiring = issue_jwt(&sk, r#"{"exp":1000}"#, None, &mut rng).unwrap();
+        assert!(validate_jwt(&expiring, &vk, &validation(999)).is_ok());
+        assert_eq!(
+            validate_jwt(&expiring, &vk, &validation(1000)),
+            Err(SlhDsaError::TokenExpired)
+        );
+        assert!(validate_jwt(&expiring, &vk, &validation(1009).leeway(leeway)).is_ok());
+        assert_eq!(
+            validate_jwt(&expiring, &vk, &validation(1010).leeway(leeway)),
+            Err(SlhDsaError::TokenExpired)
+        );
+
+        let pending = issue_jwt(&sk, r#"{"nbf":1000}"#, None, &mut rng).unwrap();
+        assert!(validate_jwt(&pending, &vk, &validation(1000)).is_ok());
+        assert_eq!(
+            validate_jwt(&pending, &vk, &validation(999)),
+            Err(SlhDsaError::TokenExpired)
+        );
+        assert!(validate_jwt(&pending, &vk, &validation(990).leeway(leeway)).is_ok());
+        assert_eq!(
+            validate_jwt(&pending, &vk, &validation(989).leeway(leeway)),
+            Err(SlhDsaError::TokenExpired)
+        );
+    }
+
+    #[test]
+    fn jwt_audience() {
+        let mut rng = TestRng::new(17);
+        let sk = signing_key(&mut rng);
+        let vk = sk.verifying_key();
+        let expected = validation(0).audience("api");
+
+        let single = issue_jwt(&sk, r#"{"aud":"api"}"#, Some("k1"), &mut rng).unwrap();
+        assert_eq!(
+            validate_jwt(&single, &vk, &expected).as_deref(),
+            Ok(r#"{"aud":"api"}"#)
+        );
+        assert_eq!(
+            Jws::from_compact(&single).unwrap().header_type(),
+            Some("JWT")
+        );
+        let listed = issue_jwt(&sk, r#"{"aud":["web","api"]}"#, None, &mut rng).unwrap();
+        assert!(validate_jwt(&listed, &vk, &expected).is_ok());
+
+        let other = issue_jwt(&sk, r#"{"aud":"web"}"#, None, &mut rng).unwrap();
+        let missing = issue_jwt(&sk, "{}", None, &mut rng).unwrap();
+        for (token, checks) in [
+            (&other, &expected),
+            (&missing, &expected),
+            (&single, &validation(0)),
+        ] {
+            assert_eq!(
+                validate_jwt(token, &vk, checks),
+                Err(SlhDsaError::AudienceMismatch)
+            );
+        }
+    }
+}
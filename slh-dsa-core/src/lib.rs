@@ -1,16 +1,67 @@
 #![no_std]
 //! Core SLH-DSA wrappers and helpers built on the `slh-dsa` crate.
 
+extern crate alloc;
+
+#[cfg(feature = "pkcs8")]
+pub use pkcs8;
+pub use slh_dsa::signature;
+pub use slh_dsa::{
+    ParameterSet, Signature, SignatureLen, SigningKey, SigningKeyLen, VerifyingKey, VerifyingKeyLen,
+};
 pub use slh_dsa::{
     Sha2_128f, Sha2_128s, Sha2_192f, Sha2_192s, Sha2_256f, Sha2_256s, Shake128f, Shake128s,
     Shake192f, Shake192s, Shake256f, Shake256s,
 };
-pub use slh_dsa::signature;
-pub use slh_dsa::{ParameterSet, Signature, SignatureLen, SigningKey, SigningKeyLen, VerifyingKey, VerifyingKeyLen};
 
-use hybrid_array::Array;
-use rand_core::{CryptoRng, RngCore};
 use hybrid_array::typenum::Unsigned;
+use hybrid_array::Array;
+use rand_core::{CryptoRng, TryCryptoRng};
+use secret::Secret;
+
+#[macro_use]
+mod dynamic;
+#[cfg(feature = "pkcs8")]
+mod asn1;
+mod batch;
+#[cfg(feature = "cms")]
+pub mod cms;
+#[cfg(feature = "cose")]
+pub mod cose;
+#[cfg(feature = "x509")]
+pub mod csr;
+pub mod envelope;
+mod error;
+mod fault;
+mod fingerprint;
+#[cfg(feature = "fips")]
+pub mod fips;
+#[cfg(feature = "jose")]
+pub mod jose;
+#[cfg(feature = "keyfile")]
+pub mod keyfile;
+mod params;
+mod prehash;
+mod secret;
+#[cfg(feature = "serde")]
+mod serde_impls;
+#[cfg(test)]
+mod test_rng;
+#[cfg(feature = "x509")]
+pub mod x509;
+
+pub use batch::{sign_batch, verify_batch, BatchVerifyItem};
+pub use dynamic::{DynSignature, DynSigningKey, DynVerifyingKey};
+pub use error::{SlhDsaError, MAX_CONTEXT_LEN};
+pub use fault::{set_verify_after_sign, verify_after_sign_enabled};
+pub use fingerprint::{Fingerprint, FINGERPRINT_LEN};
+pub use params::{HashFamily, ParameterSetInfo, PARAMETER_SETS};
+pub use prehash::{
+    prehash, sign_prehash_deterministic, sign_prehash_with_rng, try_sign_prehash_with_rng,
+    verify_prehash, PreHashAlgorithm, PreHashDigest, PreHasher, MAX_PREHASH_LEN,
+};
+#[cfg(feature = "serde")]
+pub use serde_impls::ExposedSigningKey;
 
 /// Supported SLH-DSA parameter sets.
 #[repr(C)]
@@ -52,43 +103,122 @@ impl ParameterSetId {
 
     /// Human-readable FIPS-205 parameter set name.
     pub const fn name(self) -> &'static str {
-        match self {
-            Self::Shake128s => "SLH-DSA-SHAKE-128s",
-            Self::Shake128f => "SLH-DSA-SHAKE-128f",
-            Self::Shake192s => "SLH-DSA-SHAKE-192s",
-            Self::Shake192f => "SLH-DSA-SHAKE-192f",
-            Self::Shake256s => "SLH-DSA-SHAKE-256s",
-            Self::Shake256f => "SLH-DSA-SHAKE-256f",
-            Self::Sha2_128s => "SLH-DSA-SHA2-128s",
-            Self::Sha2_128f => "SLH-DSA-SHA2-128f",
-            Self::Sha2_192s => "SLH-DSA-SHA2-192s",
-            Self::Sha2_192f => "SLH-DSA-SHA2-192f",
-            Self::Sha2_256s => "SLH-DSA-SHA2-256s",
-            Self::Sha2_256f => "SLH-DSA-SHA2-256f",
-        }
+        self.info().name
     }
 }
 
 /// Generate a signing and verifying key pair using the provided RNG.
-pub fn generate_keypair<P: ParameterSet, R: CryptoRng + RngCore>(
+///
+/// # Panics
+///
+/// With the `fips` feature, panics if a self-test or the pairwise consistency
+/// test fails; [`try_generate_keypair`] reports [`SlhDsaError::SelfTestFailed`]
+/// instead.
+pub fn generate_keypair<P: ParameterSet, R: CryptoRng>(
     rng: &mut R,
 ) -> (SigningKey<P>, VerifyingKey<P>) {
-    let signing_key = SigningKey::<P>::new(rng);
+    #[cfg(feature = "fips")]
+    {
+        try_generate_keypair::<P, R>(rng).expect("SLH-DSA self-test failed")
+    }
+    #[cfg(not(feature = "fips"))]
+    {
+        let signing_key = SigningKey::<P>::new(rng);
+        let verifying_key = signing_key.as_ref().clone();
+        (signing_key, verifying_key)
+    }
+}
+
+/// Generate a key pair from a fallible RNG, reporting entropy failures instead of panicking.
+pub fn try_generate_keypair<P: ParameterSet, R: TryCryptoRng>(
+    rng: &mut R,
+) -> Result<(SigningKey<P>, VerifyingKey<P>), SlhDsaError> {
+    let n = seed_len::<P>();
+    let mut seeds = Secret(Array::<u8, P::SkLen>::default());
+    rng.try_fill_bytes(&mut seeds.as_mut_slice()[..3 * n])
+        .map_err(|_| SlhDsaError::RngFailure)?;
+    let (sk_seed, rest) = seeds.as_slice().split_at(n);
+    let (sk_prf, rest) = rest.split_at(n);
+    keypair_from_seed::<P>(sk_seed, sk_prf, &rest[..n])
+}
+
+/// Derive a key pair from explicit seeds (FIPS 205 `slh_keygen_internal`).
+///
+/// Each seed must be exactly n bytes long, see [`seed_len_for`].
+pub fn keypair_from_seed<P: ParameterSet>(
+    sk_seed: &[u8],
+    sk_prf: &[u8],
+    pk_seed: &[u8],
+) -> Result<(SigningKey<P>, VerifyingKey<P>), SlhDsaError> {
+    let n = seed_len::<P>();
+    if sk_seed.len() != n || sk_prf.len() != n || pk_seed.len() != n {
+        return Err(SlhDsaError::InvalidLength);
+    }
+    #[cfg(feature = "fips")]
+    fips::ensure_for::<P>()?;
+    let signing_key = SigningKey::<P>::slh_keygen_internal(sk_seed, sk_prf, pk_seed);
+    #[cfg(feature = "fips")]
+    fips::pairwise_consistency_test(&signing_key)?;
     let verifying_key = signing_key.as_ref().clone();
-    (signing_key, verifying_key)
+    Ok((signing_key, verifying_key))
+}
+
+/// Check that the PK.root embedded in a signing key matches the one recomputed
+/// from its SK.seed and PK.seed.
+///
+/// Decoding only checks the length, so a corrupted or tampered key would
+/// otherwise sign without error and produce signatures that never verify.
+/// Returns [`SlhDsaError::MalformedSigningKey`] on mismatch.
+pub fn validate_signing_key<P: ParameterSet>(
+    signing_key: &SigningKey<P>,
+) -> Result<(), SlhDsaError> {
+    let n = seed_len::<P>();
+    let bytes = Secret(signing_key.to_bytes());
+    let (sk_seed, rest) = bytes.split_at(n);
+    let (sk_prf, rest) = rest.split_at(n);
+    let recomputed = SigningKey::<P>::slh_keygen_internal(sk_seed, sk_prf, &rest[..n]);
+    if recomputed.as_ref() != signing_key.as_ref() {
+        return Err(SlhDsaError::MalformedSigningKey);
+    }
+    Ok(())
 }
 
 /// Sign a message with a context string using a caller-supplied RNG.
-pub fn sign_with_rng<P: ParameterSet, R: CryptoRng + RngCore>(
+pub fn sign_with_rng<P: ParameterSet, R: CryptoRng>(
+    signing_key: &SigningKey<P>,
+    rng: &mut R,
+    msg: &[u8],
+    ctx: &[u8],
+) -> Result<Signature<P>, SlhDsaError> {
+    try_sign_with_rng(signing_key, rng, msg, ctx)
+}
+
+/// Sign a message with a context string using a fallible RNG.
+pub fn try_sign_with_rng<P: ParameterSet, R: TryCryptoRng>(
+    signing_key: &SigningKey<P>,
+    rng: &mut R,
+    msg: &[u8],
+    ctx: &[u8],
+) -> Result<Signature<P>, SlhDsaError> {
+    sign_randomized(
+        signing_key,
+        rng,
+        msg,
+        ctx,
+        fault::verify_after_sign_enabled(),
+    )
+}
+
+/// Sign like [`try_sign_with_rng`], then verify the signature against the key's
+/// own verifying key before returning it, reporting [`SlhDsaError::FaultDetected`]
+/// on mismatch.
+pub fn sign_checked<P: ParameterSet, R: TryCryptoRng>(
     signing_key: &SigningKey<P>,
     rng: &mut R,
     msg: &[u8],
     ctx: &[u8],
-) -> Result<Signature<P>, signature::Error> {
-    let mut buffer = Array::<u8, P::SkLen>::default();
-    rng.fill_bytes(buffer.as_mut_slice());
-    let n = sk_len::<P>() / 4;
-    signing_key.try_sign_with_context(msg, ctx, Some(&buffer.as_slice()[..n]))
+) -> Result<Signature<P>, SlhDsaError> {
+    sign_randomized(signing_key, rng, msg, ctx, true)
 }
 
 /// Deterministically sign a message with a context string.
@@ -96,8 +226,72 @@ pub fn sign_deterministic<P: ParameterSet>(
     signing_key: &SigningKey<P>,
     msg: &[u8],
     ctx: &[u8],
-) -> Result<Signature<P>, signature::Error> {
-    signing_key.try_sign_with_context(msg, ctx, None)
+) -> Result<Signature<P>, SlhDsaError> {
+    sign_with_opt_rand(signing_key, msg, ctx, None)
+}
+
+/// Deterministic counterpart of [`sign_checked`].
+pub fn sign_checked_deterministic<P: ParameterSet>(
+    signing_key: &SigningKey<P>,
+    msg: &[u8],
+    ctx: &[u8],
+) -> Result<Signature<P>, SlhDsaError> {
+    sign_pure(signing_key, msg, ctx, None, true)
+}
+
+fn sign_randomized<P: ParameterSet, R: TryCryptoRng>(
+    signing_key: &SigningKey<P>,
+    rng: &mut R,
+    msg: &[u8],
+    ctx: &[u8],
+    checked: bool,
+) -> Result<Signature<P>, SlhDsaError> {
+    error::check_context(ctx)?;
+    let mut buffer = Secret(Array::<u8, P::SkLen>::default());
+    rng.try_fill_bytes(buffer.as_mut_slice())
+        .map_err(|_| SlhDsaError::RngFailure)?;
+    let n = seed_len::<P>();
+    sign_pure(
+        signing_key,
+        msg,
+        ctx,
+        Some(&buffer.as_slice()[..n]),
+        checked,
+    )
+}
+
+pub(crate) fn sign_with_opt_rand<P: ParameterSet>(
+    signing_key: &SigningKey<P>,
+    msg: &[u8],
+    ctx: &[u8],
+    opt_rand: Option<&[u8]>,
+) -> Result<Signature<P>, SlhDsaError> {
+    sign_pure(
+        signing_key,
+        msg,
+        ctx,
+        opt_rand,
+        fault::verify_after_sign_enabled(),
+    )
+}
+
+fn sign_pure<P: ParameterSet>(
+    signing_key: &SigningKey<P>,
+    msg: &[u8],
+    ctx: &[u8],
+    opt_rand: Option<&[u8]>,
+    checked: bool,
+) -> Result<Signature<P>, SlhDsaError> {
+    let ctx_len = error::check_context(ctx)?;
+    #[cfg(feature = "fips")]
+    fips::ensure_for::<P>()?;
+    let signature = signing_key
+        .try_sign_with_context(msg, ctx, opt_rand)
+        .map_err(|_| SlhDsaError::ContextTooLong)?;
+    if checked {
+        fault::check_signature(signing_key, &[&[0, ctx_len], ctx, msg], &signature)?;
+    }
+    Ok(signature)
 }
 
 /// Verify a signature with a context string.
@@ -106,72 +300,124 @@ pub fn verify_with_context<P: ParameterSet>(
     msg: &[u8],
     ctx: &[u8],
     signature: &Signature<P>,
-) -> Result<(), signature::Error> {
-    verifying_key.try_verify_with_context(msg, ctx, signature)
+) -> Result<(), SlhDsaError> {
+    error::check_context(ctx)?;
+    #[cfg(feature = "fips")]
+    fips::ensure_for::<P>()?;
+    verifying_key
+        .try_verify_with_context(msg, ctx, signature)
+        .map_err(|_| SlhDsaError::VerificationFailed)
 }
 
-fn sk_len<P: ParameterSet>() -> usize {
+/// Sign a pre-formatted message M' (FIPS 205 `slh_sign_internal`).
+///
+/// `opt_rand` must be n bytes when present; `None` uses PK.seed as in deterministic signing.
+pub fn sign_internal<P: ParameterSet>(
+    signing_key: &SigningKey<P>,
+    msg_prime: &[u8],
+    opt_rand: Option<&[u8]>,
+) -> Result<Signature<P>, SlhDsaError> {
+    if opt_rand.is_some_and(|rand| rand.len() != seed_len::<P>()) {
+        return Err(SlhDsaError::InvalidLength);
+    }
+    #[cfg(feature = "fips")]
+    fips::ensure_for::<P>()?;
+    let signature = signing_key.slh_sign_internal(&[msg_prime], opt_rand);
+    if fault::verify_after_sign_enabled() {
+        fault::check_signature(signing_key, &[msg_prime], &signature)?;
+    }
+    Ok(signature)
+}
+
+/// Verify a signature over a pre-formatted message M' (FIPS 205 `slh_verify_internal`).
+pub fn verify_internal<P: ParameterSet>(
+    verifying_key: &VerifyingKey<P>,
+    msg_prime: &[u8],
+    signature: &Signature<P>,
+) -> Result<(), SlhDsaError> {
+    #[cfg(feature = "fips")]
+    fips::ensure_for::<P>()?;
+    verifying_key
+        .slh_verify_internal(&[msg_prime], signature)
+        .map_err(|_| SlhDsaError::VerificationFailed)
+}
+
+pub(crate) fn sk_len<P: ParameterSet>() -> usize {
     <P::SkLen as Unsigned>::USIZE
 }
 
-fn vk_len<P: ParameterSet>() -> usize {
+pub(crate) fn seed_len<P: ParameterSet>() -> usize {
+    sk_len::<P>() / 4
+}
+
+pub(crate) fn vk_len<P: ParameterSet>() -> usize {
     <P::VkLen as Unsigned>::USIZE
 }
 
-fn sig_len<P: ParameterSet>() -> usize {
+pub(crate) fn sig_len<P: ParameterSet>() -> usize {
     <P::SigLen as Unsigned>::USIZE
 }
 
 /// Byte length of a signing key for the given parameter set.
-pub fn sk_len_for(param: ParameterSetId) -> Option<usize> {
-    Some(match param {
-        ParameterSetId::Shake128s => sk_len::<Shake128s>(),
-        ParameterSetId::Shake128f => sk_len::<Shake128f>(),
-        ParameterSetId::Shake192s => sk_len::<Shake192s>(),
-        ParameterSetId::Shake192f => sk_len::<Shake192f>(),
-        ParameterSetId::Shake256s => sk_len::<Shake256s>(),
-        ParameterSetId::Shake256f => sk_len::<Shake256f>(),
-        ParameterSetId::Sha2_128s => sk_len::<Sha2_128s>(),
-        ParameterSetId::Sha2_128f => sk_len::<Sha2_128f>(),
-        ParameterSetId::Sha2_192s => sk_len::<Sha2_192s>(),
-        ParameterSetId::Sha2_192f => sk_len::<Sha2_192f>(),
-        ParameterSetId::Sha2_256s => sk_len::<Sha2_256s>(),
-        ParameterSetId::Sha2_256f => sk_len::<Sha2_256f>(),
-    })
+pub const fn sk_len_for(param: ParameterSetId) -> usize {
+    param.info().signing_key_len
 }
 
 /// Byte length of a verifying key for the given parameter set.
-pub fn vk_len_for(param: ParameterSetId) -> Option<usize> {
-    Some(match param {
-        ParameterSetId::Shake128s => vk_len::<Shake128s>(),
-        ParameterSetId::Shake128f => vk_len::<Shake128f>(),
-        ParameterSetId::Shake192s => vk_len::<Shake192s>(),
-        ParameterSetId::Shake192f => vk_len::<Shake192f>(),
-        ParameterSetId::Shake256s => vk_len::<Shake256s>(),
-        ParameterSetId::Shake256f => vk_len::<Shake256f>(),
-        ParameterSetId::Sha2_128s => vk_len::<Sha2_128s>(),
-        ParameterSetId::Sha2_128f => vk_len::<Sha2_128f>(),
-        ParameterSetId::Sha2_192s => vk_len::<Sha2_192s>(),
-        ParameterSetId::Sha2_192f => vk_len::<Sha2_192f>(),
-        ParameterSetId::Sha2_256s => vk_len::<Sha2_256s>(),
-        ParameterSetId::Sha2_256f => vk_len::<Sha2_256f>(),
-    })
+pub const fn vk_len_for(param: ParameterSetId) -> usize {
+    param.info().verifying_key_len
 }
 
 /// Byte length of a signature for the given parameter set.
-pub fn sig_len_for(param: ParameterSetId) -> Option<usize> {
-    Some(match param {
-        ParameterSetId::Shake128s => sig_len::<Shake128s>(),
-        ParameterSetId::Shake128f => sig_len::<Shake128f>(),
-        ParameterSetId::Shake192s => sig_len::<Shake192s>(),
-        ParameterSetId::Shake192f => sig_len::<Shake192f>(),
-        ParameterSetId::Shake256s => sig_len::<Shake256s>(),
-        ParameterSetId::Shake256f => sig_len::<Shake256f>(),
-        ParameterSetId::Sha2_128s => sig_len::<Sha2_128s>(),
-        ParameterSetId::Sha2_128f => sig_len::<Sha2_128f>(),
-        ParameterSetId::Sha2_192s => sig_len::<Sha2_192s>(),
-        ParameterSetId::Sha2_192f => sig_len::<Sha2_192f>(),
-        ParameterSetId::Sha2_256s => sig_len::<Sha2_256s>(),
-        ParameterSetId::Sha2_256f => sig_len::<Sha2_256f>(),
-    })
+pub const fn sig_len_for(param: ParameterSetId) -> usize {
+    param.info().signature_len
+}
+
+/// Byte length n of each key generation seed for the given parameter set.
+pub const fn seed_len_for(param: ParameterSetId) -> usize {
+    param.info().n
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec::Vec;
+
+    /// A SLH-DSA-SHAKE-128f signing key with one byte of the embedded PK.root flipped.
+    fn tampered_key() -> (DynSigningKey, Vec<u8>) {
+        let sk = DynSigningKey::from_seed(ParameterSetId::Shake128f, &[1; 16], &[2; 16], &[3; 16])
+            .unwrap();
+        let mut bytes = sk.to_vec();
+        *bytes.last_mut().unwrap() ^= 1;
+        (sk, bytes)
+    }
+
+    #[test]
+    fn validate_signing_key_detects_a_wrong_pk_root() {
+        let (sk, tampered) = tampered_key();
+        assert_eq!(sk.validate(), Ok(()));
+        let generic = SigningKey::<Shake128f>::try_from(sk.to_vec().as_slice()).unwrap();
+        assert_eq!(validate_signing_key(&generic), Ok(()));
+
+        let generic = SigningKey::<Shake128f>::try_from(tampered.as_slice()).unwrap();
+        assert_eq!(
+            validate_signing_key(&generic),
+            Err(SlhDsaError::MalformedSigningKey)
+        );
+        let decoded = DynSigningKey::from_bytes(ParameterSetId::Shake128f, &tampered).unwrap();
+        assert_eq!(decoded.validate(), Err(SlhDsaError::MalformedSigningKey));
+    }
+
+    #[test]
+    fn from_bytes_validated_rejects_a_wrong_pk_root() {
+        let (sk, tampered) = tampered_key();
+        assert_eq!(
+            DynSigningKey::from_bytes_validated(ParameterSetId::Shake128f, &sk.to_vec()),
+            Ok(sk)
+        );
+        assert_eq!(
+            DynSigningKey::from_bytes_validated(ParameterSetId::Shake128f, &tampered).err(),
+            Some(SlhDsaError::MalformedSigningKey)
+        );
+    }
 }
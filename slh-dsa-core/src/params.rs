@@ -0,0 +1,148 @@
+//! FIPS 205 parameter set metadata (Table 2) and name/OID lookups.
+
+use crate::{
+    ParameterSetId, Sha2_128f, Sha2_128s, Sha2_192f, Sha2_192s, Sha2_256f, Sha2_256s, Shake128f,
+    Shake128s, Shake192f, Shake192s, Shake256f, Shake256s, SignatureLen, SigningKeyLen,
+    SlhDsaError, VerifyingKeyLen,
+};
+
+use core::ffi::CStr;
+use core::fmt;
+use core::str::FromStr;
+use hybrid_array::typenum::Unsigned;
+
+/// Hash function family a parameter set is instantiated with.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum HashFamily {
+    Sha2 = 0,
+    Shake = 1,
+}
+
+/// Parameters and encoded sizes of one parameter set.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct ParameterSetInfo {
+    pub id: ParameterSetId,
+    /// FIPS 205 name, e.g. `SLH-DSA-SHA2-128s`.
+    pub name: &'static str,
+    pub hash_family: HashFamily,
+    /// `f` (fast signing) rather than `s` (small signatures).
+    pub fast: bool,
+    /// Security parameter in bytes.
+    pub n: usize,
+    /// Total hypertree height.
+    pub h: usize,
+    /// Hypertree layers.
+    pub d: usize,
+    /// FORS tree height.
+    pub a: usize,
+    /// Number of FORS trees.
+    pub k: usize,
+    /// Winternitz parameter.
+    pub w: usize,
+    /// NIST security category (1, 3 or 5).
+    pub security_category: u8,
+    /// `id-slh-dsa-*` object identifier in dotted form.
+    pub oid: &'static str,
+    /// `oid` with a trailing NUL, for C callers.
+    pub oid_cstr: &'static CStr,
+    pub signing_key_len: usize,
+    pub verifying_key_len: usize,
+    pub signature_len: usize,
+}
+
+const fn nul_terminated(s: &'static str) -> &'static CStr {
+    match CStr::from_bytes_with_nul(s.as_bytes()) {
+        Ok(s) => s,
+        Err(_) => panic!("string must end in its only NUL"),
+    }
+}
+
+macro_rules! parameter_set_info {
+    ($P:ident, $name:literal, $hash:ident, $fast:literal,
+     $n:literal, $h:literal, $d:literal, $a:literal, $k:literal, $category:literal, $oid:literal) => {
+        ParameterSetInfo {
+            id: ParameterSetId::$P,
+            name: $name,
+            hash_family: HashFamily::$hash,
+            fast: $fast,
+            n: $n,
+            h: $h,
+            d: $d,
+            a: $a,
+            k: $k,
+            w: 16,
+            security_category: $category,
+            oid: $oid,
+            oid_cstr: nul_terminated(concat!($oid, "\0")),
+            signing_key_len: <<$P as SigningKeyLen>::SkLen as Unsigned>::USIZE,
+            verifying_key_len: <<$P as VerifyingKeyLen>::VkLen as Unsigned>::USIZE,
+            signature_len: <<$P as SignatureLen>::SigLen as Unsigned>::USIZE,
+        }
+    };
+}
+
+/// Every parameter set, in `ParameterSetId` order.
+#[rustfmt::skip]
+pub const PARAMETER_SETS: [ParameterSetInfo; 12] = [
+    //                  id         name                  hash   fast   n   h   d   a   k   cat OID
+    parameter_set_info!(Shake128s, "SLH-DSA-SHAKE-128s", Shake, false, 16, 63, 7,  12, 14, 1, "2.16.840.1.101.3.4.3.26"),
+    parameter_set_info!(Shake128f, "SLH-DSA-SHAKE-128f", Shake, true,  16, 66, 22, 6,  33, 1, "2.16.840.1.101.3.4.3.27"),
+    parameter_set_info!(Shake192s, "SLH-DSA-SHAKE-192s", Shake, false, 24, 63, 7,  14, 17, 3, "2.16.840.1.101.3.4.3.28"),
+    parameter_set_info!(Shake192f, "SLH-DSA-SHAKE-192f", Shake, true,  24, 66, 22, 8,  33, 3, "2.16.840.1.101.3.4.3.29"),
+    parameter_set_info!(Shake256s, "SLH-DSA-SHAKE-256s", Shake, false, 32, 64, 8,  14, 22, 5, "2.16.840.1.101.3.4.3.30"),
+    parameter_set_info!(Shake256f, "SLH-DSA-SHAKE-256f", Shake, true,  32, 68, 17, 9,  35, 5, "2.16.840.1.101.3.4.3.31"),
+    parameter_set_info!(Sha2_128s, "SLH-DSA-SHA2-128s",  Sha2,  false, 16, 63, 7,  12, 14, 1, "2.16.840.1.101.3.4.3.20"),
+    parameter_set_info!(Sha2_128f, "SLH-DSA-SHA2-128f",  Sha2,  true,  16, 66, 22, 6,  33, 1, "2.16.840.1.101.3.4.3.21"),
+    parameter_set_info!(Sha2_192s, "SLH-DSA-SHA2-192s",  Sha2,  false, 24, 63, 7,  14, 17, 3, "2.16.840.1.101.3.4.3.22"),
+    parameter_set_info!(Sha2_192f, "SLH-DSA-SHA2-192f",  Sha2,  true,  24, 66, 22, 8,  33, 3, "2.16.840.1.101.3.4.3.23"),
+    parameter_set_info!(Sha2_256s, "SLH-DSA-SHA2-256s",  Sha2,  false, 32, 64, 8,  14, 22, 5, "2.16.840.1.101.3.4.3.24"),
+    parameter_set_info!(Sha2_256f, "SLH-DSA-SHA2-256f",  Sha2,  true,  32, 68, 17, 9,  35, 5, "2.16.840.1.101.3.4.3.25"),
+];
+
+impl ParameterSetId {
+    /// Every parameter set, in discriminant order.
+    pub const ALL: [Self; 12] = [
+        Self::Shake128s,
+        Self::Shake128f,
+        Self::Shake192s,
+        Self::Shake192f,
+        Self::Shake256s,
+        Self::Shake256f,
+        Self::Sha2_128s,
+        Self::Sha2_128f,
+        Self::Sha2_192s,
+        Self::Sha2_192f,
+        Self::Sha2_256s,
+        Self::Sha2_256f,
+    ];
+
+    /// Metadata for this parameter set.
+    pub const fn info(self) -> &'static ParameterSetInfo {
+        &PARAMETER_SETS[self as usize - 1]
+    }
+
+    /// Parameter set with the given FIPS 205 name.
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|param| param.name() == name)
+    }
+
+    /// Parameter set identified by a dotted `id-slh-dsa-*` OID.
+    pub fn from_oid_str(oid: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|param| param.info().oid == oid)
+    }
+}
+
+impl fmt::Display for ParameterSetId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for ParameterSetId {
+    type Err = SlhDsaError;
+
+    fn from_str(name: &str) -> Result<Self, SlhDsaError> {
+        Self::from_name(name).ok_or(SlhDsaError::UnsupportedAlgorithm)
+    }
+}
@@ -0,0 +1,231 @@
+//! HashSLH-DSA (pre-hash) signing and verification as defined in FIPS 205 section 10.2.
+
+use crate::error::check_context;
+use crate::secret::Secret;
+use crate::{ParameterSet, Signature, SigningKey, SlhDsaError, VerifyingKey};
+
+use hybrid_array::Array;
+use rand_core::{CryptoRng, TryCryptoRng};
+use sha2::{Digest, Sha256, Sha512};
+use sha3::digest::{ExtendableOutput, Update};
+use sha3::{Shake128, Shake256};
+
+/// Largest digest produced by any supported pre-hash function.
+pub const MAX_PREHASH_LEN: usize = 64;
+
+/// Domain separator for HashSLH-DSA messages (pure SLH-DSA uses 0).
+const PREHASH_DOMAIN: u8 = 1;
+
+/// Pre-hash functions approved for HashSLH-DSA.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum PreHashAlgorithm {
+    Sha256,
+    Sha512,
+    Shake128,
+    Shake256,
+}
+
+impl PreHashAlgorithm {
+    /// DER-encoded object identifier of the hash function, as embedded in M'.
+    pub const fn oid_der(self) -> &'static [u8] {
+        match self {
+            Self::Sha256 => &[
+                0x06, 0x09, 0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x02, 0x01,
+            ],
+            Self::Sha512 => &[
+                0x06, 0x09, 0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x02, 0x03,
+            ],
+            Self::Shake128 => &[
+                0x06, 0x09, 0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x02, 0x0b,
+            ],
+            Self::Shake256 => &[
+                0x06, 0x09, 0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x02, 0x0c,
+            ],
+        }
+    }
+
+    /// Length in bytes of the digest PH(M).
+    pub const fn digest_len(self) -> usize {
+        match self {
+            Self::Sha256 | Self::Shake128 => 32,
+            Self::Sha512 | Self::Shake256 => 64,
+        }
+    }
+}
+
+/// A pre-hash digest PH(M) tagged with the function that produced it.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct PreHashDigest {
+    algorithm: PreHashAlgorithm,
+    bytes: [u8; MAX_PREHASH_LEN],
+}
+
+impl PreHashDigest {
+    /// Wrap a digest computed elsewhere, checking its length against the algorithm.
+    pub fn from_bytes(algorithm: PreHashAlgorithm, digest: &[u8]) -> Result<Self, SlhDsaError> {
+        if digest.len() != algorithm.digest_len() {
+            return Err(SlhDsaError::InvalidLength);
+        }
+        let mut bytes = [0u8; MAX_PREHASH_LEN];
+        bytes[..digest.len()].copy_from_slice(digest);
+        Ok(Self { algorithm, bytes })
+    }
+
+    /// Pre-hash function that produced this digest.
+    pub const fn algorithm(&self) -> PreHashAlgorithm {
+        self.algorithm
+    }
+
+    /// Digest bytes.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes[..self.algorithm.digest_len()]
+    }
+}
+
+#[derive(Clone)]
+enum PreHasherState {
+    Sha256(Sha256),
+    Sha512(Sha512),
+    Shake128(Shake128),
+    Shake256(Shake256),
+}
+
+/// Incremental PH(M) computation, so large messages can be streamed.
+#[derive(Clone)]
+pub struct PreHasher {
+    state: PreHasherState,
+}
+
+impl PreHasher {
+    /// Start a new pre-hash computation.
+    pub fn new(algorithm: PreHashAlgorithm) -> Self {
+        let state = match algorithm {
+            PreHashAlgorithm::Sha256 => PreHasherState::Sha256(Sha256::new()),
+            PreHashAlgorithm::Sha512 => PreHasherState::Sha512(Sha512::new()),
+            PreHashAlgorithm::Shake128 => PreHasherState::Shake128(Shake128::default()),
+            PreHashAlgorithm::Shake256 => PreHasherState::Shake256(Shake256::default()),
+        };
+        Self { state }
+    }
+
+    /// Pre-hash function used by this hasher.
+    pub fn algorithm(&self) -> PreHashAlgorithm {
+        match self.state {
+            PreHasherState::Sha256(_) => PreHashAlgorithm::Sha256,
+            PreHasherState::Sha512(_) => PreHashAlgorithm::Sha512,
+            PreHasherState::Shake128(_) => PreHashAlgorithm::Shake128,
+            PreHasherState::Shake256(_) => PreHashAlgorithm::Shake256,
+        }
+    }
+
+    /// Absorb the next chunk of the message.
+    pub fn update(&mut self, data: &[u8]) {
+        match &mut self.state {
+            PreHasherState::Sha256(hasher) => Digest::update(hasher, data),
+            PreHasherState::Sha512(hasher) => Digest::update(hasher, data),
+            PreHasherState::Shake128(hasher) => Update::update(hasher, data),
+            PreHasherState::Shake256(hasher) => Update::update(hasher, data),
+        }
+    }
+
+    /// Finish the computation and return PH(M).
+    pub fn finalize(self) -> PreHashDigest {
+        let algorithm = self.algorithm();
+        let mut bytes = [0u8; MAX_PREHASH_LEN];
+        let out = &mut bytes[..algorithm.digest_len()];
+        match self.state {
+            PreHasherState::Sha256(hasher) => out.copy_from_slice(&hasher.finalize()),
+            PreHasherState::Sha512(hasher) => out.copy_from_slice(&hasher.finalize()),
+            PreHasherState::Shake128(hasher) => hasher.finalize_xof_into(out),
+            PreHasherState::Shake256(hasher) => hasher.finalize_xof_into(out),
+        }
+        PreHashDigest { algorithm, bytes }
+    }
+}
+
+/// Compute PH(M) over a message held in memory.
+pub fn prehash(algorithm: PreHashAlgorithm, msg: &[u8]) -> PreHashDigest {
+    let mut hasher = PreHasher::new(algorithm);
+    hasher.update(msg);
+    hasher.finalize()
+}
+
+/// Sign a pre-hashed message with a context string using a caller-supplied RNG.
+pub fn sign_prehash_with_rng<P: ParameterSet, R: CryptoRng>(
+    signing_key: &SigningKey<P>,
+    rng: &mut R,
+    digest: &PreHashDigest,
+    ctx: &[u8],
+) -> Result<Signature<P>, SlhDsaError> {
+    try_sign_prehash_with_rng(signing_key, rng, digest, ctx)
+}
+
+/// Sign a pre-hashed message with a context string using a fallible RNG.
+pub fn try_sign_prehash_with_rng<P: ParameterSet, R: TryCryptoRng>(
+    signing_key: &SigningKey<P>,
+    rng: &mut R,
+    digest: &PreHashDigest,
+    ctx: &[u8],
+) -> Result<Signature<P>, SlhDsaError> {
+    let mut buffer = Secret(Array::<u8, P::SkLen>::default());
+    rng.try_fill_bytes(buffer.as_mut_slice())
+        .map_err(|_| SlhDsaError::RngFailure)?;
+    let n = crate::seed_len::<P>();
+    sign_prehash_inner(signing_key, digest, ctx, Some(&buffer.as_slice()[..n]))
+}
+
+/// Deterministically sign a pre-hashed message with a context string.
+pub fn sign_prehash_deterministic<P: ParameterSet>(
+    signing_key: &SigningKey<P>,
+    digest: &PreHashDigest,
+    ctx: &[u8],
+) -> Result<Signature<P>, SlhDsaError> {
+    sign_prehash_inner(signing_key, digest, ctx, None)
+}
+
+/// Verify a HashSLH-DSA signature over a pre-hashed message.
+pub fn verify_prehash<P: ParameterSet>(
+    verifying_key: &VerifyingKey<P>,
+    digest: &PreHashDigest,
+    ctx: &[u8],
+    signature: &Signature<P>,
+) -> Result<(), SlhDsaError> {
+    let ctx_len = [check_context(ctx)?];
+    #[cfg(feature = "fips")]
+    crate::fips::ensure_for::<P>()?;
+    let algorithm = digest.algorithm();
+    let msg = [
+        &[PREHASH_DOMAIN][..],
+        &ctx_len,
+        ctx,
+        algorithm.oid_der(),
+        digest.as_bytes(),
+    ];
+    verifying_key
+        .slh_verify_internal(&msg, signature)
+        .map_err(|_| SlhDsaError::VerificationFailed)
+}
+
+fn sign_prehash_inner<P: ParameterSet>(
+    signing_key: &SigningKey<P>,
+    digest: &PreHashDigest,
+    ctx: &[u8],
+    opt_rand: Option<&[u8]>,
+) -> Result<Signature<P>, SlhDsaError> {
+    let ctx_len = [check_context(ctx)?];
+    #[cfg(feature = "fips")]
+    crate::fips::ensure_for::<P>()?;
+    let algorithm = digest.algorithm();
+    let msg = [
+        &[PREHASH_DOMAIN][..],
+        &ctx_len,
+        ctx,
+        algorithm.oid_der(),
+        digest.as_bytes(),
+    ];
+    let signature = signing_key.slh_sign_internal(&msg, opt_rand);
+    if crate::fault::verify_after_sign_enabled() {
+        crate::fault::check_signature(signing_key, &msg, &signature)?;
+    }
+    Ok(signature)
+}
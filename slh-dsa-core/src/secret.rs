@@ -0,0 +1,27 @@
+//! Scratch buffers for secret material, wiped on drop when the `zeroize` feature is enabled.
+
+use core::ops::{Deref, DerefMut};
+
+/// Owns a byte buffer holding seeds or randomizers and clears it when dropped.
+pub(crate) struct Secret<T: AsMut<[u8]>>(pub(crate) T);
+
+impl<T: AsMut<[u8]>> Deref for Secret<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T: AsMut<[u8]>> DerefMut for Secret<T> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.0
+    }
+}
+
+impl<T: AsMut<[u8]>> Drop for Secret<T> {
+    fn drop(&mut self) {
+        #[cfg(feature = "zeroize")]
+        zeroize::Zeroize::zeroize(self.0.as_mut());
+    }
+}
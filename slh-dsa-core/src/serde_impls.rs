@@ -0,0 +1,447 @@
+//! Optional `serde` support.
+//!
+//! `ParameterSetId` serializes as its FIPS 205 name. Verifying keys and
+//! signatures serialize as a struct holding the parameter set and the raw
+//! encoding, written as lowercase hex (keys) or padded base64 (signatures) in
+//! human-readable formats and as bytes otherwise. Signing keys are never
+//! serialized implicitly; wrap them in [`ExposedSigningKey`] to opt in.
+
+use crate::secret::Secret;
+use crate::{DynSignature, DynSigningKey, DynVerifyingKey, ParameterSetId};
+
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt;
+
+use base64ct::{Base64, Encoding};
+use serde::de::{self, DeserializeSeed, MapAccess, SeqAccess, Unexpected, Visitor};
+use serde::ser::{self, SerializeStruct};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+const PARAMETER_SET: &str = "parameter_set";
+const KEY_FIELDS: &[&str] = &[PARAMETER_SET, "key"];
+const SIGNATURE_FIELDS: &[&str] = &[PARAMETER_SET, "signature"];
+
+impl Serialize for ParameterSetId {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.name())
+    }
+}
+
+impl<'de> Deserialize<'de> for ParameterSetId {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct NameVisitor;
+
+        impl Visitor<'_> for NameVisitor {
+            type Value = ParameterSetId;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("an SLH-DSA parameter set name")
+            }
+
+            fn visit_str<E: de::Error>(self, name: &str) -> Result<ParameterSetId, E> {
+                ParameterSetId::from_name(name)
+                    .ok_or_else(|| E::invalid_value(Unexpected::Str(name), &self))
+            }
+        }
+
+        deserializer.deserialize_str(NameVisitor)
+    }
+}
+
+/// Text form of raw bytes in human-readable formats.
+#[derive(Copy, Clone)]
+enum Text {
+    Hex,
+    Base64,
+}
+
+/// Raw bytes, written as text or bytes depending on the format.
+struct Raw<'a> {
+    bytes: &'a [u8],
+    text: Text,
+}
+
+impl Serialize for Raw<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if !serializer.is_human_readable() {
+            return serializer.serialize_bytes(self.bytes);
+        }
+        // The buffer may hold an encoded signing key, so it is wiped like one.
+        let encoded = match self.text {
+            Text::Hex => {
+                let mut buf = Secret(vec![0u8; self.bytes.len() * 2]);
+                hex::encode_to_slice(self.bytes, &mut buf).map_err(ser::Error::custom)?;
+                buf
+            }
+            Text::Base64 => {
+                let mut buf = Secret(vec![0u8; Base64::encoded_len(self.bytes)]);
+                Base64::encode(self.bytes, &mut buf).map_err(ser::Error::custom)?;
+                buf
+            }
+        };
+        serializer.serialize_str(core::str::from_utf8(&encoded).map_err(ser::Error::custom)?)
+    }
+}
+
+/// Reads what [`Raw`] writes.
+struct RawSeed(Text);
+
+impl<'de> DeserializeSeed<'de> for RawSeed {
+    type Value = Secret<Vec<u8>>;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_str(self)
+        } else {
+            deserializer.deserialize_byte_buf(self)
+        }
+    }
+}
+
+impl<'de> Visitor<'de> for RawSeed {
+    type Value = Secret<Vec<u8>>;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            Text::Hex => f.write_str("a hex string or bytes"),
+            Text::Base64 => f.write_str("a base64 string or bytes"),
+        }
+    }
+
+    fn visit_str<E: de::Error>(self, encoded: &str) -> Result<Self::Value, E> {
+        let invalid = || E::invalid_value(Unexpected::Str(encoded), &self);
+        match self.0 {
+            Text::Hex => {
+                let mut bytes = Secret(vec![0u8; encoded.len() / 2]);
+                hex::decode_to_slice(encoded, &mut bytes).map_err(|_| invalid())?;
+                Ok(bytes)
+            }
+            Text::Base64 => Base64::decode_vec(encoded)
+                .map(Secret)
+                .map_err(|_| invalid()),
+        }
+    }
+
+    fn visit_bytes<E: de::Error>(self, bytes: &[u8]) -> Result<Self::Value, E> {
+        Ok(Secret(bytes.to_vec()))
+    }
+
+    fn visit_byte_buf<E: de::Error>(self, bytes: Vec<u8>) -> Result<Self::Value, E> {
+        Ok(Secret(bytes))
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut bytes = Secret(Vec::with_capacity(seq.size_hint().unwrap_or(0)));
+        while let Some(byte) = seq.next_element()? {
+            bytes.push(byte);
+        }
+        Ok(bytes)
+    }
+}
+
+fn serialize_parts<S: Serializer>(
+    serializer: S,
+    name: &'static str,
+    fields: &'static [&'static str],
+    param: ParameterSetId,
+    bytes: &[u8],
+    text: Text,
+) -> Result<S::Ok, S::Error> {
+    let mut state = serializer.serialize_struct(name, 2)?;
+    state.serialize_field(fields[0], &param)?;
+    state.serialize_field(fields[1], &Raw { bytes, text })?;
+    state.end()
+}
+
+enum Field {
+    ParameterSet,
+    Value,
+}
+
+/// Field names of a `{ parameter_set, <value> }` struct.
+struct FieldSeed(&'static [&'static str]);
+
+impl<'de> DeserializeSeed<'de> for FieldSeed {
+    type Value = Field;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Field, D::Error> {
+        deserializer.deserialize_identifier(self)
+    }
+}
+
+impl Visitor<'_> for FieldSeed {
+    type Value = Field;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "`{}` or `{}`", self.0[0], self.0[1])
+    }
+
+    fn visit_str<E: de::Error>(self, field: &str) -> Result<Field, E> {
+        if field == self.0[0] {
+            Ok(Field::ParameterSet)
+        } else if field == self.0[1] {
+            Ok(Field::Value)
+        } else {
+            Err(E::unknown_field(field, self.0))
+        }
+    }
+}
+
+struct PartsVisitor {
+    fields: &'static [&'static str],
+    text: Text,
+}
+
+impl<'de> Visitor<'de> for PartsVisitor {
+    type Value = (ParameterSetId, Secret<Vec<u8>>);
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "a struct with `{}` and `{}`",
+            self.fields[0], self.fields[1]
+        )
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let param = seq
+            .next_element()?
+            .ok_or_else(|| de::Error::invalid_length(0, &self))?;
+        let bytes = seq
+            .next_element_seed(RawSeed(self.text))?
+            .ok_or_else(|| de::Error::invalid_length(1, &self))?;
+        Ok((param, bytes))
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        let mut param = None;
+        let mut bytes = None;
+        while let Some(field) = map.next_key_seed(FieldSeed(self.fields))? {
+            match field {
+                Field::ParameterSet if param.is_none() => param = Some(map.next_value()?),
+                Field::Value if bytes.is_none() => {
+                    bytes = Some(map.next_value_seed(RawSeed(self.text))?)
+                }
+                Field::ParameterSet => return Err(de::Error::duplicate_field(self.fields[0])),
+                Field::Value => return Err(de::Error::duplicate_field(self.fields[1])),
+            }
+        }
+        let param = param.ok_or_else(|| de::Error::missing_field(self.fields[0]))?;
+        let bytes = bytes.ok_or_else(|| de::Error::missing_field(self.fields[1]))?;
+        Ok((param, bytes))
+    }
+}
+
+fn deserialize_parts<'de, D: Deserializer<'de>>(
+    deserializer: D,
+    name: &'static str,
+    fields: &'static [&'static str],
+    text: Text,
+) -> Result<(ParameterSetId, Secret<Vec<u8>>), D::Error> {
+    deserializer.deserialize_struct(name, fields, PartsVisitor { fields, text })
+}
+
+impl Serialize for DynVerifyingKey {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let bytes = self.to_vec();
+        serialize_parts(
+            serializer,
+            "DynVerifyingKey",
+            KEY_FIELDS,
+            self.parameter_set(),
+            &bytes,
+            Text::Hex,
+        )
+    }
+}
+
+impl<'de> Deserialize<'de> for DynVerifyingKey {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let (param, bytes) =
+            deserialize_parts(deserializer, "DynVerifyingKey", KEY_FIELDS, Text::Hex)?;
+        Self::from_bytes(param, &bytes).map_err(de::Error::custom)
+    }
+}
+
+impl Serialize for DynSignature {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let bytes = self.to_vec();
+        serialize_parts(
+            serializer,
+            "DynSignature",
+            SIGNATURE_FIELDS,
+            self.parameter_set(),
+            &bytes,
+            Text::Base64,
+        )
+    }
+}
+
+impl<'de> Deserialize<'de> for DynSignature {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let (param, bytes) =
+            deserialize_parts(deserializer, "DynSignature", SIGNATURE_FIELDS, Text::Base64)?;
+        Self::from_bytes(param, &bytes).map_err(de::Error::custom)
+    }
+}
+
+/// Opt-in wrapper that lets a signing key be serialized, in the same shape as
+/// [`DynVerifyingKey`] with the hex-encoded FIPS 205 signing key.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ExposedSigningKey(pub DynSigningKey);
+
+impl From<DynSigningKey> for ExposedSigningKey {
+    fn from(signing_key: DynSigningKey) -> Self {
+        Self(signing_key)
+    }
+}
+
+impl Serialize for ExposedSigningKey {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let bytes = Secret(self.0.to_vec());
+        serialize_parts(
+            serializer,
+            "ExposedSigningKey",
+            KEY_FIELDS,
+            self.0.parameter_set(),
+            &bytes,
+            Text::Hex,
+        )
+    }
+}
+
+impl<'de> Deserialize<'de> for ExposedSigningKey {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let (param, bytes) =
+            deserialize_parts(deserializer, "ExposedSigningKey", KEY_FIELDS, Text::Hex)?;
+        DynSigningKey::from_bytes(param, &bytes)
+            .map(Self)
+            .map_err(de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_rng::TestRng;
+
+    extern crate std;
+    use std::format;
+    use std::string::{String, ToString};
+
+    fn to_cbor<T: Serialize>(value: &T) -> Vec<u8> {
+        let mut encoded = Vec::new();
+        ciborium::into_writer(value, &mut encoded).unwrap();
+        encoded
+    }
+
+    fn from_cbor<T: de::DeserializeOwned>(encoded: &[u8]) -> T {
+        ciborium::from_reader(encoded).unwrap()
+    }
+
+    fn json_error<T: de::DeserializeOwned + fmt::Debug>(json: &str) -> String {
+        serde_json::from_str::<T>(json).unwrap_err().to_string()
+    }
+
+    #[test]
+    fn parameter_set_uses_the_fips_name() {
+        for param in ParameterSetId::ALL {
+            let json = serde_json::to_string(&param).unwrap();
+            assert_eq!(json, format!("\"{}\"", param.name()));
+            assert_eq!(
+                serde_json::from_str::<ParameterSetId>(&json).unwrap(),
+                param
+            );
+        }
+        assert!(serde_json::from_str::<ParameterSetId>("\"SLH-DSA-SHA2-128x\"").is_err());
+    }
+
+    #[test]
+    fn json_round_trip() {
+        let sk =
+            DynSigningKey::try_generate(ParameterSetId::Shake128f, &mut TestRng::new(20)).unwrap();
+        let vk = sk.verifying_key();
+        let json = serde_json::to_string(&vk).unwrap();
+        assert_eq!(
+            json,
+            format!(
+                r#"{{"parameter_set":"SLH-DSA-SHAKE-128f","key":"{}"}}"#,
+                hex::encode(vk.to_vec())
+            )
+        );
+        assert_eq!(serde_json::from_str::<DynVerifyingKey>(&json).unwrap(), vk);
+
+        // 7856 bytes is not a multiple of three, so the base64 is padded.
+        let bytes: Vec<u8> = (0..7856).map(|i| i as u8).collect();
+        let signature = DynSignature::from_bytes(ParameterSetId::Sha2_128s, &bytes).unwrap();
+        let json = serde_json::to_string(&signature).unwrap();
+        assert_eq!(
+            json,
+            format!(
+                r#"{{"parameter_set":"SLH-DSA-SHA2-128s","signature":"{}"}}"#,
+                Base64::encode_string(&bytes)
+            )
+        );
+        assert!(json.ends_with("=\"}"));
+        assert_eq!(
+            serde_json::from_str::<DynSignature>(&json).unwrap(),
+            signature
+        );
+
+        let exposed = ExposedSigningKey(sk);
+        let json = serde_json::to_string(&exposed).unwrap();
+        assert!(json.contains(&hex::encode(exposed.0.to_vec())));
+        assert_eq!(
+            serde_json::from_str::<ExposedSigningKey>(&json).unwrap(),
+            exposed
+        );
+    }
+
+    #[test]
+    fn binary_round_trip() {
+        let sk =
+            DynSigningKey::try_generate(ParameterSetId::Sha2_128f, &mut TestRng::new(20)).unwrap();
+        let vk = sk.verifying_key();
+        let signature = sk
+            .try_sign_with_rng(&mut TestRng::new(21), b"message", &[])
+            .unwrap();
+
+        let encoded = to_cbor(&vk);
+        // Raw bytes, not text: the 32-byte key follows a byte string header.
+        let key = vk.to_vec();
+        assert!(encoded
+            .windows(key.len() + 2)
+            .any(|w| w[..2] == [0x58, 32] && w[2..] == key));
+        assert_eq!(from_cbor::<DynVerifyingKey>(&encoded), vk);
+        assert_eq!(from_cbor::<DynSignature>(&to_cbor(&signature)), signature);
+        let exposed = ExposedSigningKey(sk);
+        assert_eq!(from_cbor::<ExposedSigningKey>(&to_cbor(&exposed)), exposed);
+    }
+
+    #[test]
+    fn duplicate_and_unknown_fields_are_rejected() {
+        let vk = DynSigningKey::try_generate(ParameterSetId::Shake128f, &mut TestRng::new(20))
+            .unwrap()
+            .verifying_key();
+        let key = hex::encode(vk.to_vec());
+
+        let duplicate =
+            format!(r#"{{"parameter_set":"SLH-DSA-SHAKE-128f","key":"{key}","key":"{key}"}}"#);
+        assert!(json_error::<DynVerifyingKey>(&duplicate).contains("duplicate field `key`"));
+        let duplicate = format!(
+            r#"{{"parameter_set":"SLH-DSA-SHAKE-128f","parameter_set":"SLH-DSA-SHAKE-128f","key":"{key}"}}"#
+        );
+        assert!(
+            json_error::<DynVerifyingKey>(&duplicate).contains("duplicate field `parameter_set`")
+        );
+        let unknown =
+            format!(r#"{{"parameter_set":"SLH-DSA-SHAKE-128f","key":"{key}","comment":"x"}}"#);
+        assert!(json_error::<DynVerifyingKey>(&unknown).contains("unknown field `comment`"));
+        let missing = r#"{"parameter_set":"SLH-DSA-SHAKE-128f"}"#;
+        assert!(json_error::<DynVerifyingKey>(missing).contains("missing field `key`"));
+
+        let signature = r#"{"parameter_set":"SLH-DSA-SHA2-128s","key":"00"}"#;
+        assert!(json_error::<DynSignature>(signature).contains("unknown field `key`"));
+    }
+}
@@ -0,0 +1,991 @@
+//! X.509 v3 certificates signed with SLH-DSA.
+//!
+//! Follows the LAMPS profile for SLH-DSA in the Internet X.509 PKI: both
+//! `signature` fields carry the `id-slh-dsa-*` OID with absent parameters, and
+//! the signature is pure SLH-DSA with an empty context over the DER-encoded
+//! `TBSCertificate`.
+
+pub(crate) use crate::asn1::{sequence, tlv};
+use crate::{DynSignature, DynSigningKey, DynVerifyingKey, ParameterSetId, SlhDsaError};
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::time::Duration;
+
+use pkcs8::der::asn1::{
+    AnyRef, BitStringRef, GeneralizedTime, OctetStringRef, PrintableStringRef, UintRef, UtcTime,
+    Utf8StringRef,
+};
+use pkcs8::der::pem::LineEnding;
+use pkcs8::der::{self, Decode, Document, Encode, Header, Reader, SliceReader, Tag, TagNumber};
+use pkcs8::spki::{AlgorithmIdentifierRef, SubjectPublicKeyInfoRef};
+use pkcs8::{EncodePublicKey, ObjectIdentifier};
+use rand_core::TryCryptoRng;
+use sha2::{Digest, Sha256};
+
+const BASIC_CONSTRAINTS: ObjectIdentifier = ObjectIdentifier::new_unwrap("2.5.29.19");
+const KEY_USAGE: ObjectIdentifier = ObjectIdentifier::new_unwrap("2.5.29.15");
+const SUBJECT_KEY_IDENTIFIER: ObjectIdentifier = ObjectIdentifier::new_unwrap("2.5.29.14");
+const AUTHORITY_KEY_IDENTIFIER: ObjectIdentifier = ObjectIdentifier::new_unwrap("2.5.29.35");
+
+/// PEM label for certificates.
+const PEM_LABEL: &str = "CERTIFICATE";
+
+pub(crate) fn context_specific(
+    number: u32,
+    constructed: bool,
+    parts: &[&[u8]],
+) -> der::Result<Vec<u8>> {
+    tlv(TagNumber(number).context_specific(constructed), parts)
+}
+
+/// `AlgorithmIdentifier` for a parameter set; SLH-DSA identifiers never carry parameters.
+pub(crate) fn algorithm_identifier(param: ParameterSetId) -> der::Result<Vec<u8>> {
+    sequence(&[&param.oid().to_der()?])
+}
+
+/// Parameter set named by an `AlgorithmIdentifier`, rejecting any parameters.
+pub(crate) fn parameter_set_of(
+    algorithm: &AlgorithmIdentifierRef<'_>,
+) -> Result<ParameterSetId, SlhDsaError> {
+    if algorithm.parameters.is_some() {
+        return Err(SlhDsaError::UnsupportedAlgorithm);
+    }
+    ParameterSetId::from_oid(algorithm.oid).ok_or(SlhDsaError::UnsupportedAlgorithm)
+}
+
+/// RFC 7093 method 1 key identifier: the leftmost 160 bits of SHA-256 over the public key.
+pub(crate) fn key_identifier(key: &DynVerifyingKey) -> Vec<u8> {
+    Sha256::digest(key.to_vec())[..20].to_vec()
+}
+
+/// `Time`, as UTCTime through 2049 and GeneralizedTime afterwards (RFC 5280 section 4.1.2.5).
+fn encode_time(time: Duration) -> der::Result<Vec<u8>> {
+    let time = Duration::from_secs(time.as_secs());
+    match UtcTime::from_unix_duration(time) {
+        Ok(utc) => utc.to_der(),
+        Err(_) => GeneralizedTime::from_unix_duration(time)?.to_der(),
+    }
+}
+
+fn decode_time<'a, R: Reader<'a>>(reader: &mut R) -> Result<Duration, SlhDsaError> {
+    match Tag::peek(reader)? {
+        Tag::UtcTime => Ok(UtcTime::decode(reader)?.to_unix_duration()),
+        Tag::GeneralizedTime => Ok(GeneralizedTime::decode(reader)?.to_unix_duration()),
+        _ => Err(SlhDsaError::MalformedEncoding),
+    }
+}
+
+/// Distinguished name, kept as its DER-encoded relative distinguished names.
+///
+/// Names are compared octet for octet, which is how certificates issued by
+/// this module always match their issuer.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Name {
+    rdns: Vec<Vec<u8>>,
+}
+
+impl Name {
+    /// `id-at-commonName`.
+    pub const COMMON_NAME: ObjectIdentifier = ObjectIdentifier::new_unwrap("2.5.4.3");
+    /// `id-at-countryName`.
+    pub const COUNTRY: ObjectIdentifier = ObjectIdentifier::new_unwrap("2.5.4.6");
+    /// `id-at-organizationName`.
+    pub const ORGANIZATION: ObjectIdentifier = ObjectIdentifier::new_unwrap("2.5.4.10");
+    /// `id-at-organizationalUnitName`.
+    pub const ORGANIZATIONAL_UNIT: ObjectIdentifier = ObjectIdentifier::new_unwrap("2.5.4.11");
+
+    /// Empty name.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Append a single-valued RDN holding `value` as a UTF8String.
+    pub fn with_attribute(self, oid: ObjectIdentifier, value: &str) -> Result<Self, SlhDsaError> {
+        let value = Utf8StringRef::new(value)?.to_der()?;
+        self.push(oid, &value)
+    }
+
+    /// Append a common name.
+    pub fn with_common_name(self, value: &str) -> Result<Self, SlhDsaError> {
+        self.with_attribute(Self::COMMON_NAME, value)
+    }
+
+    /// Append an organization.
+    pub fn with_organization(self, value: &str) -> Result<Self, SlhDsaError> {
+        self.with_attribute(Self::ORGANIZATION, value)
+    }
+
+    /// Append an organizational unit.
+    pub fn with_organizational_unit(self, value: &str) -> Result<Self, SlhDsaError> {
+        self.with_attribute(Self::ORGANIZATIONAL_UNIT, value)
+    }
+
+    /// Append a two-letter country code, encoded as a PrintableString.
+    pub fn with_country(self, code: &str) -> Result<Self, SlhDsaError> {
+        if code.len() != 2 {
+            return Err(SlhDsaError::InvalidLength);
+        }
+        let value = PrintableStringRef::new(code)?.to_der()?;
+        self.push(Self::COUNTRY, &value)
+    }
+
+    fn push(mut self, oid: ObjectIdentifier, value: &[u8]) -> Result<Self, SlhDsaError> {
+        let attribute = sequence(&[&oid.to_der()?, value])?;
+        self.rdns.push(tlv(Tag::Set, &[&attribute])?);
+        Ok(self)
+    }
+
+    /// First string value of the given attribute type.
+    pub fn attribute(&self, oid: ObjectIdentifier) -> Option<String> {
+        self.rdns.iter().find_map(|rdn| {
+            let mut reader = SliceReader::new(rdn).ok()?;
+            let header = Header::decode(&mut reader).ok()?;
+            reader
+                .read_nested(header.length(), |set| {
+                    while !set.is_finished() {
+                        let found = set.sequence(|attribute| {
+                            let kind = ObjectIdentifier::decode(attribute)?;
+                            let value = AnyRef::decode(attribute)?;
+                            Ok::<_, der::Error>((kind == oid).then_some(value))
+                        })?;
+                        if let Some(value) = found {
+                            return Ok(core::str::from_utf8(value.value()).ok().map(String::from));
+                        }
+                    }
+                    Ok::<_, der::Error>(None)
+                })
+                .ok()
+                .flatten()
+        })
+    }
+
+    /// First common name.
+    pub fn common_name(&self) -> Option<String> {
+        self.attribute(Self::COMMON_NAME)
+    }
+
+    /// DER encoding of the `Name`.
+    pub fn to_der(&self) -> Result<Vec<u8>, SlhDsaError> {
+        let rdns: Vec<&[u8]> = self.rdns.iter().map(Vec::as_slice).collect();
+        Ok(sequence(&rdns)?)
+    }
+
+    /// Decode a DER `Name`.
+    pub fn from_der(der: &[u8]) -> Result<Self, SlhDsaError> {
+        let mut reader = SliceReader::new(der)?;
+        let name = Self::decode_from(&mut reader)?;
+        reader.finish()?;
+        Ok(name)
+    }
+
+    fn decode_from<'a, R: Reader<'a>>(reader: &mut R) -> Result<Self, SlhDsaError> {
+        reader.sequence(|rdns| {
+            let mut name = Self::new();
+            while !rdns.is_finished() {
+                if Tag::peek(rdns)? != Tag::Set {
+                    return Err(SlhDsaError::MalformedEncoding);
+                }
+                name.rdns.push(rdns.tlv_bytes()?.to_vec());
+            }
+            Ok(name)
+        })
+    }
+}
+
+/// Set of `KeyUsage` bits (RFC 5280 section 4.2.1.3) allowed for SLH-DSA keys.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub struct KeyUsage(u16);
+
+impl KeyUsage {
+    /// `digitalSignature`.
+    pub const DIGITAL_SIGNATURE: Self = Self(1 << 0);
+    /// `nonRepudiation`, also called `contentCommitment`.
+    pub const NON_REPUDIATION: Self = Self(1 << 1);
+    /// `keyCertSign`.
+    pub const KEY_CERT_SIGN: Self = Self(1 << 5);
+    /// `cRLSign`.
+    pub const CRL_SIGN: Self = Self(1 << 6);
+
+    /// Raw bits, with bit `i` of the ASN.1 BIT STRING at `1 << i`.
+    pub const fn bits(self) -> u16 {
+        self.0
+    }
+
+    /// Whether every bit of `other` is set.
+    pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// Bits set in either.
+    pub const fn union(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+
+    fn to_der(self) -> der::Result<Vec<u8>> {
+        let mut bytes = [0u8; 2];
+        for bit in 0..16 {
+            if self.0 & (1 << bit) != 0 {
+                bytes[bit / 8] |= 0x80 >> (bit % 8);
+            }
+        }
+        let len = if bytes[1] != 0 {
+            2
+        } else {
+            usize::from(bytes[0] != 0)
+        };
+        let unused = bytes[..len]
+            .last()
+            .map_or(0, |last| last.trailing_zeros() as u8);
+        BitStringRef::new(unused, &bytes[..len])?.to_der()
+    }
+
+    fn from_bit_string(bits: BitStringRef<'_>) -> Self {
+        let mut usage = 0u16;
+        for (i, byte) in bits.raw_bytes().iter().take(2).enumerate() {
+            for j in 0..8 {
+                if byte & (0x80 >> j) != 0 {
+                    usage |= 1 << (i * 8 + j);
+                }
+            }
+        }
+        Self(usage)
+    }
+}
+
+/// `BasicConstraints` extension (RFC 5280 section 4.2.1.9).
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct BasicConstraints {
+    /// Whether the subject is a certificate authority.
+    pub ca: bool,
+    /// Maximum number of non-self-issued intermediate certificates below this one.
+    pub path_len: Option<u8>,
+}
+
+impl BasicConstraints {
+    fn to_der(self) -> der::Result<Vec<u8>> {
+        let ca = if self.ca { true.to_der()? } else { Vec::new() };
+        let path_len = match self.path_len {
+            Some(len) => len.to_der()?,
+            None => Vec::new(),
+        };
+        sequence(&[&ca, &path_len])
+    }
+
+    fn from_der(der: &[u8]) -> Result<Self, SlhDsaError> {
+        let mut reader = SliceReader::new(der)?;
+        let constraints = reader.sequence(|seq| {
+            let ca = match Tag::peek(seq) {
+                Ok(Tag::Boolean) => bool::decode(seq)?,
+                _ => false,
+            };
+            let path_len = if seq.is_finished() {
+                None
+            } else {
+                Some(u8::decode(seq)?)
+            };
+            Ok::<_, SlhDsaError>(Self { ca, path_len })
+        })?;
+        reader.finish()?;
+        Ok(constraints)
+    }
+}
+
+/// `GeneralName` forms supported in a `SubjectAltName` extension.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum GeneralName {
+    /// `rfc822Name`: an email address.
+    Email(String),
+    /// `dNSName`.
+    Dns(String),
+    /// `uniformResourceIdentifier`.
+    Uri(String),
+    /// `iPAddress`: 4 octets for IPv4 or 16 for IPv6.
+    Ip(Vec<u8>),
+}
+
+impl GeneralName {
+    fn to_der(&self) -> Result<Vec<u8>, SlhDsaError> {
+        let (number, value) = match self {
+            Self::Email(text) | Self::Dns(text) | Self::Uri(text) if !text.is_ascii() => {
+                return Err(SlhDsaError::MalformedEncoding)
+            }
+            Self::Ip(address) if address.len() != 4 && address.len() != 16 => {
+                return Err(SlhDsaError::InvalidLength)
+            }
+            Self::Email(text) => (1, text.as_bytes()),
+            Self::Dns(text) => (2, text.as_bytes()),
+            Self::Uri(text) => (6, text.as_bytes()),
+            Self::Ip(address) => (7, address.as_slice()),
+        };
+        Ok(context_specific(number, false, &[value])?)
+    }
+
+    /// Decode `GeneralNames`, skipping forms other than the ones above.
+    pub(crate) fn decode_all(der: &[u8]) -> Result<Vec<Self>, SlhDsaError> {
+        let mut reader = SliceReader::new(der)?;
+        let names = reader.sequence(|seq| {
+            let mut names = Vec::new();
+            while !seq.is_finished() {
+                let header = Header::decode(seq)?;
+                let value = seq.read_slice(header.length())?;
+                let text = || {
+                    core::str::from_utf8(value)
+                        .map(String::from)
+                        .map_err(|_| SlhDsaError::MalformedEncoding)
+                };
+                let tag = header.tag();
+                if !tag.is_context_specific() {
+                    return Err(SlhDsaError::MalformedEncoding);
+                }
+                match tag.number().value() {
+                    1 => names.push(Self::Email(text()?)),
+                    2 => names.push(Self::Dns(text()?)),
+                    6 => names.push(Self::Uri(text()?)),
+                    7 => names.push(Self::Ip(value.to_vec())),
+                    _ => {}
+                }
+            }
+            Ok::<_, SlhDsaError>(names)
+        })?;
+        reader.finish()?;
+        Ok(names)
+    }
+}
+
+/// Certificate or request extension with its DER-encoded `extnValue` contents.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Extension {
+    /// Extension type.
+    pub oid: ObjectIdentifier,
+    /// Whether a relying party that does not understand the extension must reject it.
+    pub critical: bool,
+    /// DER encoding of the extension value, without the wrapping OCTET STRING.
+    pub value: Vec<u8>,
+}
+
+impl Extension {
+    /// `id-ce-subjectAltName`.
+    pub const SUBJECT_ALT_NAME: ObjectIdentifier = ObjectIdentifier::new_unwrap("2.5.29.17");
+
+    /// Non-critical `SubjectAltName` extension listing `names`.
+    pub fn subject_alt_name(names: &[GeneralName]) -> Result<Self, SlhDsaError> {
+        let names = names
+            .iter()
+            .map(GeneralName::to_der)
+            .collect::<Result<Vec<_>, _>>()?;
+        let names: Vec<&[u8]> = names.iter().map(Vec::as_slice).collect();
+        Ok(Self {
+            oid: Self::SUBJECT_ALT_NAME,
+            critical: false,
+            value: sequence(&names)?,
+        })
+    }
+
+    pub(crate) fn to_der(&self) -> der::Result<Vec<u8>> {
+        let critical = if self.critical {
+            true.to_der()?
+        } else {
+            Vec::new()
+        };
+        let value = <&OctetStringRef>::try_from(self.value.as_slice())?.to_der()?;
+        sequence(&[&self.oid.to_der()?, &critical, &value])
+    }
+}
+
+/// Decode `Extensions`, rejecting repeated extension types (RFC 5280 section 4.2).
+pub(crate) fn decode_extensions<'a, R: Reader<'a>>(
+    reader: &mut R,
+) -> Result<Vec<Extension>, SlhDsaError> {
+    reader.sequence(|seq| {
+        let mut extensions: Vec<Extension> = Vec::new();
+        while !seq.is_finished() {
+            let extension = seq.sequence(|ext| {
+                let oid = ObjectIdentifier::decode(ext)?;
+                let critical = match Tag::peek(ext)? {
+                    Tag::Boolean => bool::decode(ext)?,
+                    _ => false,
+                };
+                let value = <&OctetStringRef>::decode(ext)?.as_bytes().to_vec();
+                Ok::<_, der::Error>(Extension {
+                    oid,
+                    critical,
+                    value,
+                })
+            })?;
+            if extensions.iter().any(|seen| seen.oid == extension.oid) {
+                return Err(SlhDsaError::MalformedEncoding);
+            }
+            extensions.push(extension);
+        }
+        Ok(extensions)
+    })
+}
+
+/// Builds and signs a `TBSCertificate` for an SLH-DSA subject key.
+#[derive(Clone, Debug)]
+pub struct CertificateBuilder {
+    subject: Name,
+    subject_key: DynVerifyingKey,
+    serial: Vec<u8>,
+    not_before: Duration,
+    not_after: Duration,
+    basic_constraints: BasicConstraints,
+    key_usage: KeyUsage,
+}
+
+impl CertificateBuilder {
+    /// End-entity certificate for `subject_key`, valid from `not_before` to
+    /// `not_after` (both measured from the Unix epoch).
+    ///
+    /// `serial` is a big-endian positive integer of at most 20 octets.
+    pub fn new(
+        subject: Name,
+        subject_key: DynVerifyingKey,
+        serial: &[u8],
+        not_before: Duration,
+        not_after: Duration,
+    ) -> Self {
+        Self {
+            subject,
+            subject_key,
+            serial: serial.to_vec(),
+            not_before,
+            not_after,
+            basic_constraints: BasicConstraints {
+                ca: false,
+                path_len: None,
+            },
+            key_usage: KeyUsage::DIGITAL_SIGNATURE,
+        }
+    }
+
+    /// Make the subject a certificate authority that may issue certificates
+    /// through at most `path_len` further intermediates.
+    pub fn certificate_authority(mut self, path_len: Option<u8>) -> Self {
+        self.basic_constraints = BasicConstraints { ca: true, path_len };
+        self.key_usage = KeyUsage::DIGITAL_SIGNATURE
+            .union(KeyUsage::KEY_CERT_SIGN)
+            .union(KeyUsage::CRL_SIGN);
+        self
+    }
+
+    /// Replace the default key usage.
+    pub fn key_usage(mut self, key_usage: KeyUsage) -> Self {
+        self.key_usage = key_usage;
+        self
+    }
+
+    /// Self-sign with the subject's own signing key.
+    pub fn self_sign<R: TryCryptoRng>(
+        self,
+        signing_key: &DynSigningKey,
+        rng: &mut R,
+    ) -> Result<Certificate, SlhDsaError> {
+        if signing_key.verifying_key() != self.subject_key {
+            return Err(SlhDsaError::InvalidCertificate);
+        }
+        let issuer = self.subject.clone();
+        let authority_key_id = key_identifier(&self.subject_key);
+        let tbs = self.to_tbs_der(&issuer, signing_key.parameter_set(), &authority_key_id)?;
+        Certificate::sign_tbs(tbs, signing_key, rng)
+    }
+
+    /// Sign as `issuer`, which must be a CA certificate for `issuer_key`.
+    pub fn sign<R: TryCryptoRng>(
+        self,
+        issuer: &Certificate,
+        issuer_key: &DynSigningKey,
+        rng: &mut R,
+    ) -> Result<Certificate, SlhDsaError> {
+        if issuer_key.verifying_key() != issuer.subject_key {
+            return Err(SlhDsaError::InvalidCertificate);
+        }
+        issuer.check_can_issue(0)?;
+        let authority_key_id = issuer
+            .subject_key_id
+            .clone()
+            .unwrap_or_else(|| key_identifier(&issuer.subject_key));
+        let tbs = self.to_tbs_der(
+            &issuer.subject,
+            issuer_key.parameter_set(),
+            &authority_key_id,
+        )?;
+        Certificate::sign_tbs(tbs, issuer_key, rng)
+    }
+
+    fn to_tbs_der(
+        &self,
+        issuer: &Name,
+        signature_param: ParameterSetId,
+        authority_key_id: &[u8],
+    ) -> Result<Vec<u8>, SlhDsaError> {
+        let serial = UintRef::new(&self.serial)?;
+        if serial.is_empty() || serial.as_bytes().len() > 20 || self.not_before > self.not_after {
+            return Err(SlhDsaError::InvalidCertificate);
+        }
+
+        let version = context_specific(0, true, &[&2u8.to_der()?])?;
+        let validity = sequence(&[
+            &encode_time(self.not_before)?,
+            &encode_time(self.not_after)?,
+        ])?;
+        let spki = self.subject_key.to_public_key_der()?;
+
+        let subject_key_id =
+            <&OctetStringRef>::try_from(key_identifier(&self.subject_key).as_slice())?.to_der()?;
+        let authority_key_id = sequence(&[&context_specific(0, false, &[authority_key_id])?])?;
+        let extensions = [
+            (BASIC_CONSTRAINTS, true, self.basic_constraints.to_der()?),
+            (KEY_USAGE, true, self.key_usage.to_der()?),
+            (SUBJECT_KEY_IDENTIFIER, false, subject_key_id),
+            (AUTHORITY_KEY_IDENTIFIER, false, authority_key_id),
+        ]
+        .into_iter()
+        .map(|(oid, critical, value)| {
+            Extension {
+                oid,
+                critical,
+                value,
+            }
+            .to_der()
+        })
+        .collect::<der::Result<Vec<_>>>()?;
+        let extensions: Vec<&[u8]> = extensions.iter().map(Vec::as_slice).collect();
+        let extensions = context_specific(3, true, &[&sequence(&extensions)?])?;
+
+        Ok(sequence(&[
+            &version,
+            &serial.to_der()?,
+            &algorithm_identifier(signature_param)?,
+            &issuer.to_der()?,
+            &validity,
+            &self.subject.to_der()?,
+            spki.as_bytes(),
+            &extensions,
+        ])?)
+    }
+}
+
+/// Parsed X.509 v3 certificate with an SLH-DSA subject key and signature.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Certificate {
+    der: Vec<u8>,
+    tbs: Vec<u8>,
+    serial: Vec<u8>,
+    issuer: Name,
+    subject: Name,
+    not_before: Duration,
+    not_after: Duration,
+    subject_key: DynVerifyingKey,
+    basic_constraints: Option<BasicConstraints>,
+    key_usage: Option<KeyUsage>,
+    subject_key_id: Option<Vec<u8>>,
+    signature_param: ParameterSetId,
+    signature: Vec<u8>,
+}
+
+impl Certificate {
+    fn sign_tbs<R: TryCryptoRng>(
+        tbs: Vec<u8>,
+        signing_key: &DynSigningKey,
+        rng: &mut R,
+    ) -> Result<Self, SlhDsaError> {
+        let signature = signing_key.try_sign_with_rng(rng, &tbs, &[])?.to_vec();
+        let der = sequence(&[
+            &tbs,
+            &algorithm_identifier(signing_key.parameter_set())?,
+            &BitStringRef::from_bytes(&signature)?.to_der()?,
+        ])?;
+        Self::from_der(&der)
+    }
+
+    /// Decode a certificate in DER or PEM form.
+    pub fn decode(encoded: &[u8]) -> Result<Self, SlhDsaError> {
+        match crate::asn1::as_pem(encoded) {
+            Some(pem) => Self::from_pem(pem?),
+            None => Self::from_der(encoded),
+        }
+    }
+
+    /// Decode a PEM `CERTIFICATE`.
+    pub fn from_pem(pem: &str) -> Result<Self, SlhDsaError> {
+        let (label, doc) = Document::from_pem(pem)?;
+        if label != PEM_LABEL {
+            return Err(SlhDsaError::MalformedEncoding);
+        }
+        Self::from_der(doc.as_bytes())
+    }
+
+    /// Decode a DER certificate.
+    pub fn from_der(der: &[u8]) -> Result<Self, SlhDsaError> {
+        let mut reader = SliceReader::new(der)?;
+        let (tbs, algorithm, signature) = reader.sequence(|cert| {
+            let tbs = cert.tlv_bytes()?;
+            let algorithm = AlgorithmIdentifierRef::decode(cert)?;
+            let signature = BitStringRef::decode(cert)?;
+            Ok::<_, der::Error>((tbs, algorithm, signature))
+        })?;
+        reader.finish()?;
+
+        let param = parameter_set_of(&algorithm)?;
+        let signature = signature
+            .as_bytes()
+            .ok_or(SlhDsaError::MalformedSignature)?;
+        if signature.len() != crate::sig_len_for(param) {
+            return Err(SlhDsaError::MalformedSignature);
+        }
+
+        let mut reader = SliceReader::new(tbs)?;
+        let cert = reader.sequence(|tbs_reader| {
+            let version = Header::peek(tbs_reader)?;
+            if version.tag() != TagNumber(0).context_specific(true) {
+                return Err(SlhDsaError::InvalidCertificate);
+            }
+            Header::decode(tbs_reader)?;
+            if tbs_reader.read_nested(version.length(), u8::decode)? != 2 {
+                return Err(SlhDsaError::InvalidCertificate);
+            }
+
+            let serial = UintRef::decode(tbs_reader)?.as_bytes().to_vec();
+            if AlgorithmIdentifierRef::decode(tbs_reader)? != algorithm {
+                return Err(SlhDsaError::InvalidCertificate);
+            }
+            let issuer = Name::decode_from(tbs_reader)?;
+            let (not_before, not_after) = tbs_reader.sequence(|validity| {
+                Ok::<_, SlhDsaError>((decode_time(validity)?, decode_time(validity)?))
+            })?;
+            let subject = Name::decode_from(tbs_reader)?;
+            let subject_key = DynVerifyingKey::from_public_key_info(
+                SubjectPublicKeyInfoRef::decode(tbs_reader)?,
+            )?;
+
+            let mut cert = Self {
+                der: der.to_vec(),
+                tbs: tbs.to_vec(),
+                serial,
+                issuer,
+                subject,
+                not_before,
+                not_after,
+                subject_key,
+                basic_constraints: None,
+                key_usage: None,
+                subject_key_id: None,
+                signature_param: param,
+                signature: signature.to_vec(),
+            };
+            while !tbs_reader.is_finished() {
+                let header = Header::decode(tbs_reader)?;
+                if header.tag() == TagNumber(3).context_specific(true) {
+                    let extensions = tbs_reader.read_nested(header.length(), decode_extensions)?;
+                    cert.apply_extensions(&extensions)?;
+                } else {
+                    // issuerUniqueID and subjectUniqueID are not used.
+                    tbs_reader.read_slice(header.length())?;
+                }
+            }
+            Ok(cert)
+        })?;
+        reader.finish()?;
+        Ok(cert)
+    }
+
+    fn apply_extensions(&mut self, extensions: &[Extension]) -> Result<(), SlhDsaError> {
+        for extension in extensions {
+            match extension.oid {
+                BASIC_CONSTRAINTS => {
+                    self.basic_constraints = Some(BasicConstraints::from_der(&extension.value)?)
+                }
+                KEY_USAGE => {
+                    let bits = BitStringRef::from_der(&extension.value)?;
+                    self.key_usage = Some(KeyUsage::from_bit_string(bits))
+                }
+                SUBJECT_KEY_IDENTIFIER => {
+                    let key_id = <&OctetStringRef>::from_der(&extension.value)?;
+                    self.subject_key_id = Some(key_id.as_bytes().to_vec())
+                }
+                AUTHORITY_KEY_IDENTIFIER => {}
+                _ if extension.critical => return Err(SlhDsaError::InvalidCertificate),
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+
+    /// DER encoding.
+    pub fn to_der(&self) -> &[u8] {
+        &self.der
+    }
+
+    /// PEM encoding with LF line endings.
+    pub fn to_pem(&self) -> Result<String, SlhDsaError> {
+        Ok(
+            der::pem::encode_string(PEM_LABEL, LineEnding::LF, &self.der)
+                .map_err(der::Error::from)?,
+        )
+    }
+
+    /// Big-endian serial number without leading zero octets.
+    pub fn serial_number(&self) -> &[u8] {
+        &self.serial
+    }
+
+    /// Issuer name.
+    pub fn issuer(&self) -> &Name {
+        &self.issuer
+    }
+
+    /// Subject name.
+    pub fn subject(&self) -> &Name {
+        &self.subject
+    }
+
+    /// Start of the validity period, measured from the Unix epoch.
+    pub fn not_before(&self) -> Duration {
+        self.not_before
+    }
+
+    /// End of the validity period, measured from the Unix epoch.
+    pub fn not_after(&self) -> Duration {
+        self.not_after
+    }
+
+    /// Subject public key.
+    pub fn verifying_key(&self) -> &DynVerifyingKey {
+        &self.subject_key
+    }
+
+    /// Parameter set the issuer signed with.
+    pub fn signature_parameter_set(&self) -> ParameterSetId {
+        self.signature_param
+    }
+
+    /// `BasicConstraints` extension, if present.
+    pub fn basic_constraints(&self) -> Option<BasicConstraints> {
+        self.basic_constraints
+    }
+
+    /// `KeyUsage` extension, if present.
+    pub fn key_usage(&self) -> Option<KeyUsage> {
+        self.key_usage
+    }
+
+    /// `SubjectKeyIdentifier` extension, if present.
+    pub fn subject_key_id(&self) -> Option<&[u8]> {
+        self.subject_key_id.as_deref()
+    }
+
+    /// Whether the subject is a certificate authority.
+    pub fn is_ca(&self) -> bool {
+        self.basic_constraints
+            .is_some_and(|constraints| constraints.ca)
+    }
+
+    /// Whether the issuer and subject names are the same.
+    pub fn is_self_issued(&self) -> bool {
+        self.issuer == self.subject
+    }
+
+    /// Whether `now` (measured from the Unix epoch) is inside the validity period.
+    pub fn is_valid_at(&self, now: Duration) -> bool {
+        self.not_before <= now && now <= self.not_after
+    }
+
+    /// Check the certificate signature against the issuer's verifying key.
+    pub fn verify_signature(&self, issuer_key: &DynVerifyingKey) -> Result<(), SlhDsaError> {
+        let signature = DynSignature::from_bytes(self.signature_param, &self.signature)?;
+        issuer_key.verify(&self.tbs, &[], &signature)
+    }
+
+    /// Reject issuers that are not CAs or whose path length is exceeded by
+    /// `intermediates` non-self-issued certificates below them.
+    fn check_can_issue(&self, intermediates: usize) -> Result<(), SlhDsaError> {
+        let Some(constraints) = self.basic_constraints.filter(|constraints| constraints.ca) else {
+            return Err(SlhDsaError::InvalidCertificate);
+        };
+        if constraints
+            .path_len
+            .is_some_and(|path_len| intermediates > usize::from(path_len))
+        {
+            return Err(SlhDsaError::InvalidCertificate);
+        }
+        if self
+            .key_usage
+            .is_some_and(|usage| !usage.contains(KeyUsage::KEY_CERT_SIGN))
+        {
+            return Err(SlhDsaError::InvalidCertificate);
+        }
+        Ok(())
+    }
+}
+
+/// Verify a certification path at time `now` (measured from the Unix epoch).
+///
+/// `chain` starts with the end-entity certificate and lists each issuer in
+/// turn. It may end with a trust anchor; otherwise the last certificate must
+/// be issued by one of `trust_anchors`. Every certificate must be within its
+/// validity period, every issuer must be a CA allowed to sign certificates at
+/// that depth, and every signature must verify.
+pub fn verify_chain(
+    chain: &[Certificate],
+    trust_anchors: &[Certificate],
+    now: Duration,
+) -> Result<(), SlhDsaError> {
+    let last = chain.last().ok_or(SlhDsaError::InvalidCertificate)?;
+    let anchor = if trust_anchors.contains(last) {
+        None
+    } else {
+        let anchor = trust_anchors
+            .iter()
+            .find(|anchor| {
+                anchor.subject == last.issuer && last.verify_signature(&anchor.subject_key).is_ok()
+            })
+            .ok_or(SlhDsaError::UntrustedChain)?;
+        Some(anchor)
+    };
+    let path: Vec<&Certificate> = chain.iter().chain(anchor).collect();
+
+    if !path.iter().all(|cert| cert.is_valid_at(now)) {
+        return Err(SlhDsaError::CertificateExpired);
+    }
+    for (depth, pair) in path.windows(2).enumerate() {
+        let (cert, issuer) = (pair[0], pair[1]);
+        if cert.issuer != issuer.subject {
+            return Err(SlhDsaError::UntrustedChain);
+        }
+        let intermediates = path[1..=depth]
+            .iter()
+            .filter(|cert| !cert.is_self_issued())
+            .count();
+        issuer.check_can_issue(intermediates)?;
+        cert.verify_signature(&issuer.subject_key)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_rng::TestRng;
+    use core::slice::from_ref;
+
+    const OPENSSL_ROOT: &[u8] = include_bytes!("../tests/vectors/openssl/root.der");
+    const OPENSSL_LEAF: &[u8] = include_bytes!("../tests/vectors/openssl/leaf.der");
+
+    /// 2030-01-01T00:00:00Z, inside every validity period below.
+    const NOW: Duration = Duration::from_secs(1_893_456_000);
+    /// 2036-01-01T00:00:00Z, after the leaves expire.
+    const LATER: Duration = Duration::from_secs(2_082_758_400);
+    const NOT_BEFORE: Duration = Duration::from_secs(1_735_689_600);
+    const NOT_AFTER: Duration = Duration::from_secs(2_524_607_999);
+
+    fn issue_root(name: &str, rng: &mut TestRng) -> (DynSigningKey, Certificate) {
+        let sk = DynSigningKey::try_generate(ParameterSetId::Sha2_128f, rng).unwrap();
+        let subject = Name::new().with_common_name(name).unwrap();
+        let cert =
+            CertificateBuilder::new(subject, sk.verifying_key(), &[1], NOT_BEFORE, NOT_AFTER)
+                .certificate_authority(None)
+                .self_sign(&sk, rng)
+                .unwrap();
+        (sk, cert)
+    }
+
+    fn issue_leaf(
+        issuer: &Certificate,
+        issuer_sk: &DynSigningKey,
+        rng: &mut TestRng,
+    ) -> Certificate {
+        let sk = DynSigningKey::try_generate(ParameterSetId::Shake128f, rng).unwrap();
+        let subject = Name::new().with_common_name("leaf.example").unwrap();
+        CertificateBuilder::new(
+            subject,
+            sk.verifying_key(),
+            &[2],
+            NOT_BEFORE,
+            LATER - Duration::from_secs(86_400),
+        )
+        .key_usage(KeyUsage::DIGITAL_SIGNATURE)
+        .sign(issuer, issuer_sk, rng)
+        .unwrap()
+    }
+
+    #[test]
+    fn root_to_leaf_chain_verifies() {
+        let mut rng = TestRng::new(13);
+        let (root_sk, root) = issue_root("Root", &mut rng);
+        let leaf = issue_leaf(&root, &root_sk, &mut rng);
+
+        assert_eq!(leaf.issuer(), root.subject());
+        assert_eq!(
+            Certificate::decode(leaf.to_pem().unwrap().as_bytes()).unwrap(),
+            leaf
+        );
+        assert_eq!(verify_chain(from_ref(&leaf), from_ref(&root), NOW), Ok(()));
+        assert_eq!(verify_chain(&[leaf, root.clone()], &[root], NOW), Ok(()));
+    }
+
+    #[test]
+    fn expired_leaf_is_rejected() {
+        let mut rng = TestRng::new(13);
+        let (root_sk, root) = issue_root("Root", &mut rng);
+        let leaf = issue_leaf(&root, &root_sk, &mut rng);
+
+        assert!(!leaf.is_valid_at(LATER));
+        assert_eq!(
+            verify_chain(&[leaf], &[root], LATER),
+            Err(SlhDsaError::CertificateExpired)
+        );
+    }
+
+    #[test]
+    fn wrong_issuer_is_untrusted() {
+        let mut rng = TestRng::new(13);
+        let (root_sk, root) = issue_root("Root", &mut rng);
+        let (_, impostor) = issue_root("Root", &mut rng);
+        let (_, other) = issue_root("Other Root", &mut rng);
+        let leaf = issue_leaf(&root, &root_sk, &mut rng);
+
+        assert_eq!(
+            verify_chain(from_ref(&leaf), &[impostor.clone(), other], NOW),
+            Err(SlhDsaError::UntrustedChain)
+        );
+        assert_eq!(
+            verify_chain(&[leaf, root], &[impostor], NOW),
+            Err(SlhDsaError::UntrustedChain)
+        );
+    }
+
+    #[test]
+    fn openssl_chain_verifies() {
+        let root = Certificate::from_der(OPENSSL_ROOT).unwrap();
+        let leaf = Certificate::from_der(OPENSSL_LEAF).unwrap();
+
+        assert_eq!(leaf.to_der(), OPENSSL_LEAF);
+        assert_eq!(
+            root.subject().common_name().as_deref(),
+            Some("OpenSSL Root")
+        );
+        assert_eq!(
+            leaf.subject().common_name().as_deref(),
+            Some("leaf.example")
+        );
+        assert_eq!(leaf.serial_number(), &[2]);
+        assert_eq!(leaf.signature_parameter_set(), ParameterSetId::Sha2_128f);
+        assert!(root.is_ca() && !leaf.is_ca());
+        assert_eq!(verify_chain(from_ref(&leaf), from_ref(&root), NOW), Ok(()));
+        assert_eq!(
+            verify_chain(from_ref(&leaf), from_ref(&root), LATER),
+            Err(SlhDsaError::CertificateExpired)
+        );
+
+        let mut tampered = OPENSSL_LEAF.to_vec();
+        *tampered.last_mut().unwrap() ^= 1;
+        let tampered = Certificate::from_der(&tampered).unwrap();
+        assert!(tampered.verify_signature(root.verifying_key()).is_err());
+        assert_eq!(
+            verify_chain(&[tampered], &[root], NOW),
+            Err(SlhDsaError::UntrustedChain)
+        );
+    }
+}
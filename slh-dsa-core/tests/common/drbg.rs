@@ -0,0 +1,60 @@
+//! AES-256 CTR_DRBG without derivation function, as used by NIST's `PQCgenKAT_sign`.
+//!
+//! This reproduces `randombytes_init`/`randombytes` from the reference `rng.c` so
+//! the 48-byte seeds in `.rsp` files yield the same keys and randomizers.
+
+use aes::cipher::generic_array::GenericArray;
+use aes::cipher::{BlockEncrypt, KeyInit};
+use aes::Aes256;
+
+pub struct AesCtrDrbg {
+    key: [u8; 32],
+    v: [u8; 16],
+}
+
+impl AesCtrDrbg {
+    /// Equivalent of `randombytes_init(entropy_input, NULL, 256)`.
+    pub fn new(entropy_input: &[u8; 48]) -> Self {
+        let mut drbg = Self {
+            key: [0u8; 32],
+            v: [0u8; 16],
+        };
+        drbg.update(Some(entropy_input));
+        drbg
+    }
+
+    /// Equivalent of `randombytes(out, out.len())`.
+    pub fn fill_bytes(&mut self, out: &mut [u8]) {
+        for chunk in out.chunks_mut(16) {
+            let block = self.next_block();
+            chunk.copy_from_slice(&block[..chunk.len()]);
+        }
+        self.update(None);
+    }
+
+    fn next_block(&mut self) -> [u8; 16] {
+        for byte in self.v.iter_mut().rev() {
+            *byte = byte.wrapping_add(1);
+            if *byte != 0 {
+                break;
+            }
+        }
+        let mut block = GenericArray::clone_from_slice(&self.v);
+        Aes256::new(GenericArray::from_slice(&self.key)).encrypt_block(&mut block);
+        block.into()
+    }
+
+    fn update(&mut self, provided_data: Option<&[u8; 48]>) {
+        let mut temp = [0u8; 48];
+        for chunk in temp.chunks_mut(16) {
+            chunk.copy_from_slice(&self.next_block());
+        }
+        if let Some(data) = provided_data {
+            for (t, d) in temp.iter_mut().zip(data) {
+                *t ^= d;
+            }
+        }
+        self.key.copy_from_slice(&temp[..32]);
+        self.v.copy_from_slice(&temp[32..]);
+    }
+}
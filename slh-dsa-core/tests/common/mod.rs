@@ -0,0 +1,32 @@
+//! Helpers shared by the conformance test runners.
+
+#![allow(dead_code)]
+
+pub mod drbg;
+
+use slh_dsa_core::ParameterSetId;
+use std::path::PathBuf;
+
+/// Every parameter set, in `ParameterSetId` discriminant order.
+pub fn all_parameter_sets() -> Vec<ParameterSetId> {
+    (1..=12).filter_map(ParameterSetId::from_u32).collect()
+}
+
+/// Look up a parameter set by its FIPS 205 name, e.g. `SLH-DSA-SHA2-128s`.
+pub fn parameter_set_by_name(name: &str) -> Option<ParameterSetId> {
+    all_parameter_sets()
+        .into_iter()
+        .find(|param| param.name() == name)
+}
+
+/// Directory holding the vector files for one runner.
+pub fn vector_dir(kind: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("vectors")
+        .join(kind)
+}
+
+pub fn decode_hex(value: &str) -> Vec<u8> {
+    hex::decode(value).unwrap_or_else(|err| panic!("invalid hex {value:?}: {err}"))
+}
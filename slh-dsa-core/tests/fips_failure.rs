@@ -0,0 +1,28 @@
+//! Key imports in the failed self-test state.
+//!
+//! The error state lasts for the rest of the process, so this runs in its own
+//! test binary rather than alongside the unit tests.
+
+#![cfg(all(feature = "fips", feature = "pkcs8"))]
+
+use slh_dsa_core::pkcs8::{EncodePrivateKey, EncodePublicKey};
+use slh_dsa_core::{fips, DynSigningKey, DynVerifyingKey, ParameterSetId, SlhDsaError};
+
+#[test]
+fn imports_report_self_test_failed() {
+    let sk =
+        DynSigningKey::from_seed(ParameterSetId::Shake128f, &[1; 16], &[2; 16], &[3; 16]).unwrap();
+    let pkcs8 = sk.to_pkcs8_der().unwrap();
+    let spki = sk.verifying_key().to_public_key_der().unwrap();
+
+    fips::inject_failure();
+    assert!(!fips::is_operational());
+    assert_eq!(
+        DynSigningKey::from_pkcs8(pkcs8.as_bytes()),
+        Err(SlhDsaError::SelfTestFailed)
+    );
+    assert_eq!(
+        DynVerifyingKey::from_spki(spki.as_bytes()),
+        Err(SlhDsaError::SelfTestFailed)
+    );
+}
@@ -0,0 +1,68 @@
+//! The process-wide verify-after-sign switch.
+//!
+//! Turning the switch on changes every signing call in the process, so this
+//! runs in its own test binary rather than alongside the unit tests, which
+//! expect the default state.
+
+use core::convert::Infallible;
+
+use rand_core::{TryCryptoRng, TryRng};
+use slh_dsa_core::{
+    keypair_from_seed, set_verify_after_sign, sign_deterministic, sign_internal, try_sign_with_rng,
+    verify_after_sign_enabled, verify_internal, verify_with_context, DynSigningKey, ParameterSetId,
+    Shake128f,
+};
+
+/// Repeats one byte; enough for opt_rand, which only needs to be fixed here.
+struct RepeatRng(u8);
+
+impl TryRng for RepeatRng {
+    type Error = Infallible;
+
+    fn try_next_u32(&mut self) -> Result<u32, Self::Error> {
+        Ok(u32::from_le_bytes([self.0; 4]))
+    }
+
+    fn try_next_u64(&mut self) -> Result<u64, Self::Error> {
+        Ok(u64::from_le_bytes([self.0; 8]))
+    }
+
+    fn try_fill_bytes(&mut self, dst: &mut [u8]) -> Result<(), Self::Error> {
+        dst.fill(self.0);
+        Ok(())
+    }
+}
+
+impl TryCryptoRng for RepeatRng {}
+
+#[test]
+fn global_switch_checks_every_signature() {
+    let (sk, vk) = keypair_from_seed::<Shake128f>(&[1; 16], &[2; 16], &[3; 16]).unwrap();
+    let dyn_sk =
+        DynSigningKey::from_seed(ParameterSetId::Shake128f, &[1; 16], &[2; 16], &[3; 16]).unwrap();
+    let mut rng = RepeatRng(23);
+    assert!(!verify_after_sign_enabled());
+
+    set_verify_after_sign(true);
+    assert!(verify_after_sign_enabled());
+    let randomized = try_sign_with_rng(&sk, &mut rng, b"message", &[]).unwrap();
+    let deterministic = sign_deterministic(&sk, b"message", b"ctx").unwrap();
+    let internal = sign_internal(&sk, b"message", None).unwrap();
+    let dynamic = dyn_sk.try_sign_with_rng(&mut rng, b"message", &[]).unwrap();
+    set_verify_after_sign(false);
+    assert!(!verify_after_sign_enabled());
+
+    assert_eq!(
+        verify_with_context(&vk, b"message", &[], &randomized),
+        Ok(())
+    );
+    assert_eq!(
+        verify_with_context(&vk, b"message", b"ctx", &deterministic),
+        Ok(())
+    );
+    assert_eq!(verify_internal(&vk, b"message", &internal), Ok(()));
+    assert_eq!(
+        dyn_sk.verifying_key().verify(b"message", &[], &dynamic),
+        Ok(())
+    );
+}
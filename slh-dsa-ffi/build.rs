@@ -6,11 +6,30 @@ fn main() {
     let out_dir = PathBuf::from(&crate_dir).join("include");
     let header_path = out_dir.join("slh_dsa.h");
 
+    // Only types and functions: parsing slh-dsa-core would otherwise pull in
+    // its Rust-only constants.
+    let config = cbindgen::Config {
+        export: cbindgen::ExportConfig {
+            item_types: vec![
+                cbindgen::ItemType::Enums,
+                cbindgen::ItemType::Structs,
+                cbindgen::ItemType::Typedefs,
+                cbindgen::ItemType::Functions,
+            ],
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
     cbindgen::Builder::new()
+        .with_config(config)
         .with_crate(crate_dir)
         .with_language(cbindgen::Language::C)
         .with_header("#include <stdbool.h>\n#include <stddef.h>\n#include <stdint.h>\n")
         .with_include_guard("SLH_DSA_H")
+        // ParameterSetId is defined in slh-dsa-core and used directly in the C API.
+        .with_parse_deps(true)
+        .with_parse_include(&["slh-dsa-core"])
         .generate()
         .expect("Unable to generate bindings")
         .write_to_file(header_path);
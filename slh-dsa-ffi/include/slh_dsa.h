@@ -11,6 +11,88 @@
 #include <stdint.h>
 #include <stdlib.h>
 
+/**
+ * Supported SLH-DSA parameter sets.
+ */
+typedef enum ParameterSetId {
+  Shake128s = 1,
+  Shake128f = 2,
+  Shake192s = 3,
+  Shake192f = 4,
+  Shake256s = 5,
+  Shake256f = 6,
+  Sha2_128s = 7,
+  Sha2_128f = 8,
+  Sha2_192s = 9,
+  Sha2_192f = 10,
+  Sha2_256s = 11,
+  Sha2_256f = 12,
+} ParameterSetId;
+
+/**
+ * Cipher sealing the signing key in a key file.
+ */
+typedef enum SlhDsaAead {
+  SlhDsaAead_ChaCha20Poly1305 = 0,
+  SlhDsaAead_Aes256Gcm = 1,
+} SlhDsaAead;
+
+/**
+ * Text rendering of a key fingerprint.
+ */
+typedef enum SlhDsaFingerprintFormat {
+  SlhDsaFingerprintFormat_Hex = 0,
+  SlhDsaFingerprintFormat_Base32 = 1,
+  SlhDsaFingerprintFormat_Words = 2,
+} SlhDsaFingerprintFormat;
+
+/**
+ * What a key fingerprint is computed over.
+ */
+typedef enum SlhDsaFingerprintSource {
+  /**
+   * The parameter set ID byte followed by the raw verifying key.
+   */
+  SlhDsaFingerprintSource_Raw = 0,
+  /**
+   * The DER SubjectPublicKeyInfo.
+   */
+  SlhDsaFingerprintSource_Spki = 1,
+} SlhDsaFingerprintSource;
+
+/**
+ * Hash function family a parameter set is instantiated with.
+ */
+typedef enum SlhDsaHashFamily {
+  SlhDsaHashFamily_Sha2 = 0,
+  SlhDsaHashFamily_Shake = 1,
+} SlhDsaHashFamily;
+
+/**
+ * Passphrase KDF for key files, at the library's default costs.
+ */
+typedef enum SlhDsaKdf {
+  /**
+   * Argon2id with 19 MiB, two iterations and one lane.
+   */
+  SlhDsaKdf_Argon2id = 0,
+  /**
+   * scrypt with N = 2^17, r = 8 and p = 1.
+   */
+  SlhDsaKdf_Scrypt = 1,
+} SlhDsaKdf;
+
+/**
+ * How an envelope's payload is signed: pure SLH-DSA or HashSLH-DSA with the named digest.
+ */
+typedef enum SlhDsaSignatureMode {
+  SlhDsaSignatureMode_Pure = 0,
+  SlhDsaSignatureMode_PreHashSha256 = 1,
+  SlhDsaSignatureMode_PreHashSha512 = 2,
+  SlhDsaSignatureMode_PreHashShake128 = 3,
+  SlhDsaSignatureMode_PreHashShake256 = 4,
+} SlhDsaSignatureMode;
+
 typedef enum SlhDsaStatus {
   Ok = 0,
   NullPtr = 1,
@@ -18,23 +100,108 @@ typedef enum SlhDsaStatus {
   InvalidLength = 3,
   DecodeError = 4,
   VerifyFailed = 5,
+  ContextTooLong = 6,
+  MalformedSigningKey = 7,
+  MalformedVerifyingKey = 8,
+  MalformedSignature = 9,
+  ParameterSetMismatch = 10,
+  RngFailure = 11,
+  UnsupportedAlgorithm = 12,
+  MalformedEncoding = 13,
+  InvalidCertificate = 14,
+  CertificateExpired = 15,
+  UntrustedChain = 16,
+  TokenExpired = 17,
+  AudienceMismatch = 18,
+  SelfTestFailed = 19,
+  FaultDetected = 20,
+  DecryptionFailed = 21,
 } SlhDsaStatus;
 
-const char *slh_dsa_parameter_name(ParameterSetId param);
+/**
+ * Metadata for one parameter set, as returned by `slh_dsa_parameter_sets`.
+ *
+ * `name` and `oid` point to static NUL-terminated strings.
+ */
+typedef struct SlhDsaParameterSetInfo {
+  enum ParameterSetId param;
+  const char *name;
+  enum SlhDsaHashFamily hash_family;
+  bool fast;
+  uintptr_t n;
+  uintptr_t h;
+  uintptr_t d;
+  uintptr_t a;
+  uintptr_t k;
+  uintptr_t w;
+  uint8_t security_category;
+  const char *oid;
+  uintptr_t signing_key_len;
+  uintptr_t verifying_key_len;
+  uintptr_t signature_len;
+} SlhDsaParameterSetInfo;
+
+/**
+ * One signature to check in `slh_dsa_verify_batch`.
+ */
+typedef struct SlhDsaVerifyItem {
+  enum ParameterSetId param;
+  const uint8_t *verifying_key;
+  uintptr_t verifying_key_len;
+  const uint8_t *msg;
+  uintptr_t msg_len;
+  const uint8_t *ctx;
+  uintptr_t ctx_len;
+  const uint8_t *signature;
+  uintptr_t signature_len;
+} SlhDsaVerifyItem;
+
+const char *slh_dsa_parameter_name(enum ParameterSetId param);
+
+/**
+ * Copy the metadata table into `out`, always reporting the number of parameter sets in `out_len`.
+ */
+enum SlhDsaStatus slh_dsa_parameter_sets(struct SlhDsaParameterSetInfo *out,
+                                         uintptr_t out_capacity,
+                                         uintptr_t *out_len);
 
-uintptr_t slh_dsa_signing_key_len(ParameterSetId param);
+/**
+ * Look up a parameter set by its FIPS 205 name, e.g. `SLH-DSA-SHA2-128s`.
+ */
+enum SlhDsaStatus slh_dsa_parameter_set_from_name(const char *name, enum ParameterSetId *param_out);
 
-uintptr_t slh_dsa_verifying_key_len(ParameterSetId param);
+/**
+ * Look up a parameter set by its dotted OID, e.g. `2.16.840.1.101.3.4.3.20`.
+ */
+enum SlhDsaStatus slh_dsa_parameter_set_from_oid(const char *oid, enum ParameterSetId *param_out);
 
-uintptr_t slh_dsa_signature_len(ParameterSetId param);
+uintptr_t slh_dsa_signing_key_len(enum ParameterSetId param);
 
-enum SlhDsaStatus slh_dsa_keypair_generate(ParameterSetId param,
+uintptr_t slh_dsa_verifying_key_len(enum ParameterSetId param);
+
+uintptr_t slh_dsa_signature_len(enum ParameterSetId param);
+
+uintptr_t slh_dsa_seed_len(enum ParameterSetId param);
+
+enum SlhDsaStatus slh_dsa_keypair_generate(enum ParameterSetId param,
                                            uint8_t *signing_key_out,
                                            uintptr_t signing_key_len,
                                            uint8_t *verifying_key_out,
                                            uintptr_t verifying_key_len);
 
-enum SlhDsaStatus slh_dsa_sign(ParameterSetId param,
+enum SlhDsaStatus slh_dsa_keypair_from_seed(enum ParameterSetId param,
+                                            const uint8_t *sk_seed,
+                                            uintptr_t sk_seed_len,
+                                            const uint8_t *sk_prf,
+                                            uintptr_t sk_prf_len,
+                                            const uint8_t *pk_seed,
+                                            uintptr_t pk_seed_len,
+                                            uint8_t *signing_key_out,
+                                            uintptr_t signing_key_len,
+                                            uint8_t *verifying_key_out,
+                                            uintptr_t verifying_key_len);
+
+enum SlhDsaStatus slh_dsa_sign(enum ParameterSetId param,
                                const uint8_t *signing_key,
                                uintptr_t signing_key_len,
                                const uint8_t *msg,
@@ -44,7 +211,22 @@ enum SlhDsaStatus slh_dsa_sign(ParameterSetId param,
                                uint8_t *signature_out,
                                uintptr_t signature_len);
 
-enum SlhDsaStatus slh_dsa_sign_deterministic(ParameterSetId param,
+/**
+ * Sign `count` messages with one key, writing the signatures back to back into
+ * `signatures_out`, which must hold `count * slh_dsa_signature_len(param)` bytes.
+ */
+enum SlhDsaStatus slh_dsa_sign_batch(enum ParameterSetId param,
+                                     const uint8_t *signing_key,
+                                     uintptr_t signing_key_len,
+                                     const uint8_t *const *msgs,
+                                     const uintptr_t *msg_lens,
+                                     uintptr_t count,
+                                     const uint8_t *ctx,
+                                     uintptr_t ctx_len,
+                                     uint8_t *signatures_out,
+                                     uintptr_t signatures_len);
+
+enum SlhDsaStatus slh_dsa_sign_deterministic(enum ParameterSetId param,
                                              const uint8_t *signing_key,
                                              uintptr_t signing_key_len,
                                              const uint8_t *msg,
@@ -54,13 +236,45 @@ enum SlhDsaStatus slh_dsa_sign_deterministic(ParameterSetId param,
                                              uint8_t *signature_out,
                                              uintptr_t signature_len);
 
-enum SlhDsaStatus slh_dsa_verifying_key_from_signing_key(ParameterSetId param,
+/**
+ * Sign like `slh_dsa_sign`, then verify the signature before returning it.
+ * Returns `FaultDetected` instead of a signature that does not verify.
+ */
+enum SlhDsaStatus slh_dsa_sign_checked(enum ParameterSetId param,
+                                       const uint8_t *signing_key,
+                                       uintptr_t signing_key_len,
+                                       const uint8_t *msg,
+                                       uintptr_t msg_len,
+                                       const uint8_t *ctx,
+                                       uintptr_t ctx_len,
+                                       uint8_t *signature_out,
+                                       uintptr_t signature_len);
+
+enum SlhDsaStatus slh_dsa_verifying_key_from_signing_key(enum ParameterSetId param,
                                                          const uint8_t *signing_key,
                                                          uintptr_t signing_key_len,
                                                          uint8_t *verifying_key_out,
                                                          uintptr_t verifying_key_len);
 
-enum SlhDsaStatus slh_dsa_verify(ParameterSetId param,
+/**
+ * Like `slh_dsa_verifying_key_from_signing_key`, but first checks the signing key with
+ * `slh_dsa_validate_signing_key`.
+ */
+enum SlhDsaStatus slh_dsa_verifying_key_from_signing_key_validated(enum ParameterSetId param,
+                                                                   const uint8_t *signing_key,
+                                                                   uintptr_t signing_key_len,
+                                                                   uint8_t *verifying_key_out,
+                                                                   uintptr_t verifying_key_len);
+
+/**
+ * Recompute PK.root from the signing key's SK.seed and PK.seed and compare it with
+ * the embedded one. Returns `MalformedSigningKey` for a corrupted or tampered key.
+ */
+enum SlhDsaStatus slh_dsa_validate_signing_key(enum ParameterSetId param,
+                                               const uint8_t *signing_key,
+                                               uintptr_t signing_key_len);
+
+enum SlhDsaStatus slh_dsa_verify(enum ParameterSetId param,
                                  const uint8_t *verifying_key,
                                  uintptr_t verifying_key_len,
                                  const uint8_t *msg,
@@ -70,13 +284,233 @@ enum SlhDsaStatus slh_dsa_verify(ParameterSetId param,
                                  const uint8_t *signature,
                                  uintptr_t signature_len);
 
+/**
+ * Verify `count` items, writing one status per item to `statuses_out`.
+ *
+ * The return value only reports problems with the arguments themselves.
+ */
+enum SlhDsaStatus slh_dsa_verify_batch(const struct SlhDsaVerifyItem *items,
+                                       uintptr_t count,
+                                       enum SlhDsaStatus *statuses_out);
+
+/**
+ * Encode a signing key as PKCS#8 DER, or PEM text without a trailing NUL when `pem` is set.
+ *
+ * `out_len` receives the encoded size even when `out_capacity` is too small.
+ */
+enum SlhDsaStatus slh_dsa_signing_key_to_pkcs8(enum ParameterSetId param,
+                                               const uint8_t *signing_key,
+                                               uintptr_t signing_key_len,
+                                               bool pem,
+                                               uint8_t *out,
+                                               uintptr_t out_capacity,
+                                               uintptr_t *out_len);
+
+/**
+ * Decode a PKCS#8 signing key (DER or PEM), reporting its parameter set through `param_out`.
+ */
+enum SlhDsaStatus slh_dsa_signing_key_from_pkcs8(const uint8_t *encoded,
+                                                 uintptr_t encoded_len,
+                                                 enum ParameterSetId *param_out,
+                                                 uint8_t *signing_key_out,
+                                                 uintptr_t signing_key_capacity,
+                                                 uintptr_t *signing_key_len);
+
+/**
+ * Encode a verifying key as SubjectPublicKeyInfo DER, or PEM text when `pem` is set.
+ *
+ * `out_len` receives the encoded size even when `out_capacity` is too small.
+ */
+enum SlhDsaStatus slh_dsa_verifying_key_to_spki(enum ParameterSetId param,
+                                                const uint8_t *verifying_key,
+                                                uintptr_t verifying_key_len,
+                                                bool pem,
+                                                uint8_t *out,
+                                                uintptr_t out_capacity,
+                                                uintptr_t *out_len);
+
+/**
+ * Decode a SubjectPublicKeyInfo (DER or PEM), reporting its parameter set through `param_out`.
+ */
+enum SlhDsaStatus slh_dsa_verifying_key_from_spki(const uint8_t *encoded,
+                                                  uintptr_t encoded_len,
+                                                  enum ParameterSetId *param_out,
+                                                  uint8_t *verifying_key_out,
+                                                  uintptr_t verifying_key_capacity,
+                                                  uintptr_t *verifying_key_len);
+
+/**
+ * Byte length of a key file from `slh_dsa_signing_key_to_key_file` for `param`.
+ */
+uintptr_t slh_dsa_key_file_len(enum ParameterSetId param);
+
+/**
+ * Encrypt a signing key under `passphrase` into a key file for storage at rest.
+ * The parameter set and KDF costs are authenticated along with the key.
+ *
+ * `out_len` receives the encoded size even when `out_capacity` is too small.
+ */
+enum SlhDsaStatus slh_dsa_signing_key_to_key_file(enum ParameterSetId param,
+                                                  const uint8_t *signing_key,
+                                                  uintptr_t signing_key_len,
+                                                  const uint8_t *passphrase,
+                                                  uintptr_t passphrase_len,
+                                                  enum SlhDsaKdf kdf,
+                                                  enum SlhDsaAead aead,
+                                                  uint8_t *out,
+                                                  uintptr_t out_capacity,
+                                                  uintptr_t *out_len);
+
+/**
+ * Decrypt a key file from `slh_dsa_signing_key_to_key_file`, reporting its parameter
+ * set through `param_out`. A wrong passphrase or a modified file returns `DecryptionFailed`.
+ */
+enum SlhDsaStatus slh_dsa_signing_key_from_key_file(const uint8_t *encoded,
+                                                    uintptr_t encoded_len,
+                                                    const uint8_t *passphrase,
+                                                    uintptr_t passphrase_len,
+                                                    enum ParameterSetId *param_out,
+                                                    uint8_t *signing_key_out,
+                                                    uintptr_t signing_key_capacity,
+                                                    uintptr_t *signing_key_len);
+
+/**
+ * Encrypt a signing key under `passphrase` as a PKCS#8 `EncryptedPrivateKeyInfo`
+ * (PBES2 with scrypt and AES-256-CBC), DER or PEM text when `pem` is set.
+ *
+ * `out_len` receives the encoded size even when `out_capacity` is too small.
+ */
+enum SlhDsaStatus slh_dsa_signing_key_to_encrypted_pkcs8(enum ParameterSetId param,
+                                                         const uint8_t *signing_key,
+                                                         uintptr_t signing_key_len,
+                                                         const uint8_t *passphrase,
+                                                         uintptr_t passphrase_len,
+                                                         bool pem,
+                                                         uint8_t *out,
+                                                         uintptr_t out_capacity,
+                                                         uintptr_t *out_len);
+
+/**
+ * Decrypt a PKCS#8 `EncryptedPrivateKeyInfo` (DER or PEM), reporting its parameter set
+ * through `param_out`. Only PBES2 with scrypt and AES-256-CBC is supported.
+ */
+enum SlhDsaStatus slh_dsa_signing_key_from_encrypted_pkcs8(const uint8_t *encoded,
+                                                           uintptr_t encoded_len,
+                                                           const uint8_t *passphrase,
+                                                           uintptr_t passphrase_len,
+                                                           enum ParameterSetId *param_out,
+                                                           uint8_t *signing_key_out,
+                                                           uintptr_t signing_key_capacity,
+                                                           uintptr_t *signing_key_len);
+
+/**
+ * Sign `payload` into a self-describing envelope. An empty `key_id` is left out;
+ * `detached` leaves the payload out.
+ *
+ * `out_len` receives the encoded size even when `out_capacity` is too small.
+ */
+enum SlhDsaStatus slh_dsa_envelope_sign(enum ParameterSetId param,
+                                        const uint8_t *signing_key,
+                                        uintptr_t signing_key_len,
+                                        const uint8_t *payload,
+                                        uintptr_t payload_len,
+                                        const uint8_t *ctx,
+                                        uintptr_t ctx_len,
+                                        const uint8_t *key_id,
+                                        uintptr_t key_id_len,
+                                        enum SlhDsaSignatureMode mode,
+                                        bool detached,
+                                        uint8_t *out,
+                                        uintptr_t out_capacity,
+                                        uintptr_t *out_len);
+
+/**
+ * Parse an envelope without checking its signature, so the caller can pick the
+ * verifying key. `key_id_len` is set to 0 when the envelope has no key ID.
+ */
+enum SlhDsaStatus slh_dsa_envelope_decode(const uint8_t *encoded,
+                                          uintptr_t encoded_len,
+                                          enum ParameterSetId *param_out,
+                                          enum SlhDsaSignatureMode *mode_out,
+                                          uint8_t *key_id_out,
+                                          uintptr_t key_id_capacity,
+                                          uintptr_t *key_id_len);
+
+/**
+ * Verify an envelope with a key of the parameter set it names. Set `detached` and pass
+ * the payload for a detached envelope; otherwise the embedded payload is copied to
+ * `payload_out` once the signature checks out.
+ */
+enum SlhDsaStatus slh_dsa_envelope_verify(const uint8_t *verifying_key,
+                                          uintptr_t verifying_key_len,
+                                          const uint8_t *encoded,
+                                          uintptr_t encoded_len,
+                                          bool detached,
+                                          const uint8_t *detached_payload,
+                                          uintptr_t detached_payload_len,
+                                          uint8_t *payload_out,
+                                          uintptr_t payload_capacity,
+                                          uintptr_t *payload_len);
+
+/**
+ * Compute the SHA-256 fingerprint of a verifying key over `source`.
+ *
+ * The fingerprint is 32 bytes; `out_len` receives that size even when `out_capacity` is too small.
+ */
+enum SlhDsaStatus slh_dsa_fingerprint(enum ParameterSetId param,
+                                      const uint8_t *verifying_key,
+                                      uintptr_t verifying_key_len,
+                                      enum SlhDsaFingerprintSource source,
+                                      uint8_t *out,
+                                      uintptr_t out_capacity,
+                                      uintptr_t *out_len);
+
+/**
+ * Render a fingerprint from `slh_dsa_fingerprint` as text. The output is not
+ * NUL-terminated.
+ *
+ * `out_len` receives the text length even when `out_capacity` is too small.
+ */
+enum SlhDsaStatus slh_dsa_fingerprint_to_string(const uint8_t *fingerprint,
+                                                uintptr_t fingerprint_len,
+                                                enum SlhDsaFingerprintFormat format,
+                                                uint8_t *out,
+                                                uintptr_t out_capacity,
+                                                uintptr_t *out_len);
+
+/**
+ * Run the known-answer self-test for `param` now. Parameter sets are also
+ * self-tested on first use; after any failure every operation returns
+ * `SelfTestFailed`. Only available when built with the `fips` feature.
+ */
+enum SlhDsaStatus slh_dsa_self_test(enum ParameterSetId param);
+
+/**
+ * Run the known-answer self-test for every parameter set. Only available when
+ * built with the `fips` feature.
+ */
+enum SlhDsaStatus slh_dsa_self_test_all(void);
+
+/**
+ * Whether no self-test has failed. Only available when built with the `fips` feature.
+ */
+bool slh_dsa_is_operational(void);
+
+/**
+ * Verify every signature before returning it, in all signing functions. A
+ * signature that does not verify is discarded and `FaultDetected` returned.
+ */
+void slh_dsa_set_verify_after_sign(bool enabled);
+
+bool slh_dsa_verify_after_sign_enabled(void);
+
 const char *slh_dsa_status_string(enum SlhDsaStatus status);
 
 bool slh_dsa_parameter_set_is_valid(uint32_t raw);
 
-ParameterSetId slh_dsa_parameter_set_from_u32(uint32_t raw);
+enum ParameterSetId slh_dsa_parameter_set_from_u32(uint32_t raw);
 
-uint32_t slh_dsa_parameter_set_to_u32(ParameterSetId param);
+uint32_t slh_dsa_parameter_set_to_u32(enum ParameterSetId param);
 
 bool slh_dsa_signature_verify_result_to_bool(enum SlhDsaStatus status);
 
@@ -1,13 +1,15 @@
 //! C-compatible FFI wrapper for slh-dsa-core.
 
 use getrandom::fill;
-use rand_core::{CryptoRng, RngCore};
+use rand_core::{TryCryptoRng, TryRng};
+use slh_dsa_core::envelope::{Envelope, EnvelopeBuilder, SignatureMode};
+use slh_dsa_core::keyfile::{self, Aead, Kdf, KeyFileOptions};
+use slh_dsa_core::pkcs8::{EncodePrivateKey, EncodePublicKey, LineEnding};
 use slh_dsa_core::{
-    ParameterSet, ParameterSetId, Signature, SigningKey, VerifyingKey, Sha2_128f, Sha2_128s,
-    Sha2_192f, Sha2_192s, Sha2_256f, Sha2_256s, Shake128f, Shake128s, Shake192f, Shake192s,
-    Shake256f, Shake256s,
+    sign_batch, verify_batch, BatchVerifyItem, DynSignature, DynSigningKey, DynVerifyingKey,
+    Fingerprint, HashFamily, ParameterSetId, PreHashAlgorithm, SlhDsaError, FINGERPRINT_LEN,
 };
-use std::ffi::c_char;
+use std::ffi::{c_char, CStr};
 use std::ptr;
 
 #[repr(C)]
@@ -19,31 +21,231 @@ pub enum SlhDsaStatus {
     InvalidLength = 3,
     DecodeError = 4,
     VerifyFailed = 5,
+    ContextTooLong = 6,
+    MalformedSigningKey = 7,
+    MalformedVerifyingKey = 8,
+    MalformedSignature = 9,
+    ParameterSetMismatch = 10,
+    RngFailure = 11,
+    UnsupportedAlgorithm = 12,
+    MalformedEncoding = 13,
+    InvalidCertificate = 14,
+    CertificateExpired = 15,
+    UntrustedChain = 16,
+    TokenExpired = 17,
+    AudienceMismatch = 18,
+    SelfTestFailed = 19,
+    FaultDetected = 20,
+    DecryptionFailed = 21,
+}
+
+impl From<SlhDsaError> for SlhDsaStatus {
+    fn from(err: SlhDsaError) -> Self {
+        match err {
+            SlhDsaError::ContextTooLong => SlhDsaStatus::ContextTooLong,
+            SlhDsaError::MalformedSigningKey => SlhDsaStatus::MalformedSigningKey,
+            SlhDsaError::MalformedVerifyingKey => SlhDsaStatus::MalformedVerifyingKey,
+            SlhDsaError::MalformedSignature => SlhDsaStatus::MalformedSignature,
+            SlhDsaError::InvalidLength => SlhDsaStatus::InvalidLength,
+            SlhDsaError::ParameterSetMismatch => SlhDsaStatus::ParameterSetMismatch,
+            SlhDsaError::RngFailure => SlhDsaStatus::RngFailure,
+            SlhDsaError::VerificationFailed => SlhDsaStatus::VerifyFailed,
+            SlhDsaError::UnsupportedAlgorithm => SlhDsaStatus::UnsupportedAlgorithm,
+            SlhDsaError::MalformedEncoding => SlhDsaStatus::MalformedEncoding,
+            SlhDsaError::InvalidCertificate => SlhDsaStatus::InvalidCertificate,
+            SlhDsaError::CertificateExpired => SlhDsaStatus::CertificateExpired,
+            SlhDsaError::UntrustedChain => SlhDsaStatus::UntrustedChain,
+            SlhDsaError::TokenExpired => SlhDsaStatus::TokenExpired,
+            SlhDsaError::AudienceMismatch => SlhDsaStatus::AudienceMismatch,
+            SlhDsaError::SelfTestFailed => SlhDsaStatus::SelfTestFailed,
+            SlhDsaError::FaultDetected => SlhDsaStatus::FaultDetected,
+            SlhDsaError::DecryptionFailed => SlhDsaStatus::DecryptionFailed,
+        }
+    }
 }
 
 pub use slh_dsa_core::ParameterSetId as SlhDsaParameterSetId;
 
+/// One signature to check in `slh_dsa_verify_batch`.
+#[repr(C)]
+#[derive(Copy, Clone, Debug)]
+pub struct SlhDsaVerifyItem {
+    pub param: ParameterSetId,
+    pub verifying_key: *const u8,
+    pub verifying_key_len: usize,
+    pub msg: *const u8,
+    pub msg_len: usize,
+    pub ctx: *const u8,
+    pub ctx_len: usize,
+    pub signature: *const u8,
+    pub signature_len: usize,
+}
+
+/// Metadata for one parameter set, as returned by `slh_dsa_parameter_sets`.
+///
+/// `name` and `oid` point to static NUL-terminated strings.
+#[repr(C)]
+#[derive(Copy, Clone, Debug)]
+pub struct SlhDsaParameterSetInfo {
+    pub param: ParameterSetId,
+    pub name: *const c_char,
+    pub hash_family: SlhDsaHashFamily,
+    pub fast: bool,
+    pub n: usize,
+    pub h: usize,
+    pub d: usize,
+    pub a: usize,
+    pub k: usize,
+    pub w: usize,
+    pub security_category: u8,
+    pub oid: *const c_char,
+    pub signing_key_len: usize,
+    pub verifying_key_len: usize,
+    pub signature_len: usize,
+}
+
+/// Hash function family a parameter set is instantiated with.
+/// cbindgen:prefix-with-name
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum SlhDsaHashFamily {
+    Sha2 = 0,
+    Shake = 1,
+}
+
+impl From<HashFamily> for SlhDsaHashFamily {
+    fn from(family: HashFamily) -> Self {
+        match family {
+            HashFamily::Sha2 => Self::Sha2,
+            HashFamily::Shake => Self::Shake,
+        }
+    }
+}
+
+/// How an envelope's payload is signed: pure SLH-DSA or HashSLH-DSA with the named digest.
+/// cbindgen:prefix-with-name
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum SlhDsaSignatureMode {
+    Pure = 0,
+    PreHashSha256 = 1,
+    PreHashSha512 = 2,
+    PreHashShake128 = 3,
+    PreHashShake256 = 4,
+}
+
+impl From<SlhDsaSignatureMode> for SignatureMode {
+    fn from(mode: SlhDsaSignatureMode) -> Self {
+        match mode {
+            SlhDsaSignatureMode::Pure => Self::Pure,
+            SlhDsaSignatureMode::PreHashSha256 => Self::PreHash(PreHashAlgorithm::Sha256),
+            SlhDsaSignatureMode::PreHashSha512 => Self::PreHash(PreHashAlgorithm::Sha512),
+            SlhDsaSignatureMode::PreHashShake128 => Self::PreHash(PreHashAlgorithm::Shake128),
+            SlhDsaSignatureMode::PreHashShake256 => Self::PreHash(PreHashAlgorithm::Shake256),
+        }
+    }
+}
+
+impl From<SignatureMode> for SlhDsaSignatureMode {
+    fn from(mode: SignatureMode) -> Self {
+        match mode {
+            SignatureMode::Pure => Self::Pure,
+            SignatureMode::PreHash(PreHashAlgorithm::Sha256) => Self::PreHashSha256,
+            SignatureMode::PreHash(PreHashAlgorithm::Sha512) => Self::PreHashSha512,
+            SignatureMode::PreHash(PreHashAlgorithm::Shake128) => Self::PreHashShake128,
+            SignatureMode::PreHash(PreHashAlgorithm::Shake256) => Self::PreHashShake256,
+        }
+    }
+}
+
+/// What a key fingerprint is computed over.
+/// cbindgen:prefix-with-name
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum SlhDsaFingerprintSource {
+    /// The parameter set ID byte followed by the raw verifying key.
+    Raw = 0,
+    /// The DER SubjectPublicKeyInfo.
+    Spki = 1,
+}
+
+/// Text rendering of a key fingerprint.
+/// cbindgen:prefix-with-name
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum SlhDsaFingerprintFormat {
+    Hex = 0,
+    Base32 = 1,
+    Words = 2,
+}
+
+/// Passphrase KDF for key files, at the library's default costs.
+/// cbindgen:prefix-with-name
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum SlhDsaKdf {
+    /// Argon2id with 19 MiB, two iterations and one lane.
+    Argon2id = 0,
+    /// scrypt with N = 2^17, r = 8 and p = 1.
+    Scrypt = 1,
+}
+
+impl From<SlhDsaKdf> for Kdf {
+    fn from(kdf: SlhDsaKdf) -> Self {
+        match kdf {
+            SlhDsaKdf::Argon2id => Self::ARGON2ID,
+            SlhDsaKdf::Scrypt => Self::SCRYPT,
+        }
+    }
+}
+
+/// Cipher sealing the signing key in a key file.
+/// cbindgen:prefix-with-name
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum SlhDsaAead {
+    ChaCha20Poly1305 = 0,
+    Aes256Gcm = 1,
+}
+
+impl From<SlhDsaAead> for Aead {
+    fn from(aead: SlhDsaAead) -> Self {
+        match aead {
+            SlhDsaAead::ChaCha20Poly1305 => Self::ChaCha20Poly1305,
+            SlhDsaAead::Aes256Gcm => Self::Aes256Gcm,
+        }
+    }
+}
+
+/// Heap copy of secret key bytes, wiped on drop when the `zeroize` feature is enabled.
+#[cfg(feature = "zeroize")]
+type SecretBytes = zeroize::Zeroizing<Vec<u8>>;
+#[cfg(not(feature = "zeroize"))]
+type SecretBytes = Vec<u8>;
+
 struct OsRng;
 
-impl RngCore for OsRng {
-    fn next_u32(&mut self) -> u32 {
+impl TryRng for OsRng {
+    type Error = getrandom::Error;
+
+    fn try_next_u32(&mut self) -> Result<u32, Self::Error> {
         let mut bytes = [0u8; 4];
-        fill(&mut bytes).expect("OsRng failure");
-        u32::from_le_bytes(bytes)
+        fill(&mut bytes)?;
+        Ok(u32::from_le_bytes(bytes))
     }
 
-    fn next_u64(&mut self) -> u64 {
+    fn try_next_u64(&mut self) -> Result<u64, Self::Error> {
         let mut bytes = [0u8; 8];
-        fill(&mut bytes).expect("OsRng failure");
-        u64::from_le_bytes(bytes)
+        fill(&mut bytes)?;
+        Ok(u64::from_le_bytes(bytes))
     }
 
-    fn fill_bytes(&mut self, dst: &mut [u8]) {
-        fill(dst).expect("OsRng failure");
+    fn try_fill_bytes(&mut self, dst: &mut [u8]) -> Result<(), Self::Error> {
+        fill(dst)
     }
 }
 
-impl CryptoRng for OsRng {}
+impl TryCryptoRng for OsRng {}
 
 fn read_slice<'a>(ptr: *const u8, len: usize) -> Result<&'a [u8], SlhDsaStatus> {
     if ptr.is_null() {
@@ -67,213 +269,500 @@ fn write_slice<'a>(ptr: *mut u8, len: usize) -> Result<&'a mut [u8], SlhDsaStatu
     Ok(unsafe { std::slice::from_raw_parts_mut(ptr, len) })
 }
 
+fn read_array<'a, T>(ptr: *const T, count: usize) -> Result<&'a [T], SlhDsaStatus> {
+    if ptr.is_null() {
+        if count == 0 {
+            return Ok(&[]);
+        }
+        return Err(SlhDsaStatus::NullPtr);
+    }
+    // SAFETY: caller guarantees the pointer is valid for count elements.
+    Ok(unsafe { std::slice::from_raw_parts(ptr, count) })
+}
+
+fn write_array<'a, T>(ptr: *mut T, count: usize) -> Result<&'a mut [T], SlhDsaStatus> {
+    if ptr.is_null() {
+        if count == 0 {
+            return Ok(&mut []);
+        }
+        return Err(SlhDsaStatus::NullPtr);
+    }
+    // SAFETY: caller guarantees the pointer is valid for count elements.
+    Ok(unsafe { std::slice::from_raw_parts_mut(ptr, count) })
+}
+
+/// Copy a variable-length result out, always reporting the required size in `out_len`.
+fn write_output(
+    bytes: &[u8],
+    out: *mut u8,
+    out_capacity: usize,
+    out_len: *mut usize,
+) -> Result<(), SlhDsaStatus> {
+    if out_len.is_null() {
+        return Err(SlhDsaStatus::NullPtr);
+    }
+    // SAFETY: checked for null above; caller guarantees it is valid for writes.
+    unsafe { out_len.write(bytes.len()) };
+    if out_capacity < bytes.len() {
+        return Err(SlhDsaStatus::InvalidLength);
+    }
+    write_slice(out, bytes.len())?.copy_from_slice(bytes);
+    Ok(())
+}
+
 fn parameter_name(param: ParameterSetId) -> *const c_char {
     match param {
-        ParameterSetId::Shake128s => b"SLH-DSA-SHAKE-128s\0".as_ptr() as *const c_char,
-        ParameterSetId::Shake128f => b"SLH-DSA-SHAKE-128f\0".as_ptr() as *const c_char,
-        ParameterSetId::Shake192s => b"SLH-DSA-SHAKE-192s\0".as_ptr() as *const c_char,
-        ParameterSetId::Shake192f => b"SLH-DSA-SHAKE-192f\0".as_ptr() as *const c_char,
-        ParameterSetId::Shake256s => b"SLH-DSA-SHAKE-256s\0".as_ptr() as *const c_char,
-        ParameterSetId::Shake256f => b"SLH-DSA-SHAKE-256f\0".as_ptr() as *const c_char,
-        ParameterSetId::Sha2_128s => b"SLH-DSA-SHA2-128s\0".as_ptr() as *const c_char,
-        ParameterSetId::Sha2_128f => b"SLH-DSA-SHA2-128f\0".as_ptr() as *const c_char,
-        ParameterSetId::Sha2_192s => b"SLH-DSA-SHA2-192s\0".as_ptr() as *const c_char,
-        ParameterSetId::Sha2_192f => b"SLH-DSA-SHA2-192f\0".as_ptr() as *const c_char,
-        ParameterSetId::Sha2_256s => b"SLH-DSA-SHA2-256s\0".as_ptr() as *const c_char,
-        ParameterSetId::Sha2_256f => b"SLH-DSA-SHA2-256f\0".as_ptr() as *const c_char,
+        ParameterSetId::Shake128s => c"SLH-DSA-SHAKE-128s".as_ptr(),
+        ParameterSetId::Shake128f => c"SLH-DSA-SHAKE-128f".as_ptr(),
+        ParameterSetId::Shake192s => c"SLH-DSA-SHAKE-192s".as_ptr(),
+        ParameterSetId::Shake192f => c"SLH-DSA-SHAKE-192f".as_ptr(),
+        ParameterSetId::Shake256s => c"SLH-DSA-SHAKE-256s".as_ptr(),
+        ParameterSetId::Shake256f => c"SLH-DSA-SHAKE-256f".as_ptr(),
+        ParameterSetId::Sha2_128s => c"SLH-DSA-SHA2-128s".as_ptr(),
+        ParameterSetId::Sha2_128f => c"SLH-DSA-SHA2-128f".as_ptr(),
+        ParameterSetId::Sha2_192s => c"SLH-DSA-SHA2-192s".as_ptr(),
+        ParameterSetId::Sha2_192f => c"SLH-DSA-SHA2-192f".as_ptr(),
+        ParameterSetId::Sha2_256s => c"SLH-DSA-SHA2-256s".as_ptr(),
+        ParameterSetId::Sha2_256f => c"SLH-DSA-SHA2-256f".as_ptr(),
+    }
+}
+
+fn parameter_set_info(param: ParameterSetId) -> SlhDsaParameterSetInfo {
+    let info = param.info();
+    SlhDsaParameterSetInfo {
+        param,
+        name: parameter_name(param),
+        hash_family: info.hash_family.into(),
+        fast: info.fast,
+        n: info.n,
+        h: info.h,
+        d: info.d,
+        a: info.a,
+        k: info.k,
+        w: info.w,
+        security_category: info.security_category,
+        oid: info.oid_cstr.as_ptr(),
+        signing_key_len: info.signing_key_len,
+        verifying_key_len: info.verifying_key_len,
+        signature_len: info.signature_len,
     }
 }
 
+fn write_parameter_sets(
+    out: *mut SlhDsaParameterSetInfo,
+    out_capacity: usize,
+    out_len: *mut usize,
+) -> Result<(), SlhDsaStatus> {
+    if out_len.is_null() {
+        return Err(SlhDsaStatus::NullPtr);
+    }
+    let count = ParameterSetId::ALL.len();
+    // SAFETY: checked for null above; caller guarantees it is valid for writes.
+    unsafe { out_len.write(count) };
+    if out_capacity < count {
+        return Err(SlhDsaStatus::InvalidLength);
+    }
+    if out.is_null() {
+        return Err(SlhDsaStatus::NullPtr);
+    }
+    // SAFETY: checked for null above; caller guarantees room for out_capacity entries.
+    let entries = unsafe { std::slice::from_raw_parts_mut(out, count) };
+    for (entry, param) in entries.iter_mut().zip(ParameterSetId::ALL) {
+        *entry = parameter_set_info(param);
+    }
+    Ok(())
+}
+
+fn read_str<'a>(ptr: *const c_char) -> Result<&'a str, SlhDsaStatus> {
+    if ptr.is_null() {
+        return Err(SlhDsaStatus::NullPtr);
+    }
+    // SAFETY: caller guarantees a valid NUL-terminated string.
+    unsafe { CStr::from_ptr(ptr) }
+        .to_str()
+        .map_err(|_| SlhDsaStatus::InvalidParam)
+}
+
 fn sk_len(param: ParameterSetId) -> usize {
-    slh_dsa_core::sk_len_for(param).unwrap_or(0)
+    slh_dsa_core::sk_len_for(param)
 }
 
 fn vk_len(param: ParameterSetId) -> usize {
-    slh_dsa_core::vk_len_for(param).unwrap_or(0)
+    slh_dsa_core::vk_len_for(param)
 }
 
 fn sig_len(param: ParameterSetId) -> usize {
-    slh_dsa_core::sig_len_for(param).unwrap_or(0)
+    slh_dsa_core::sig_len_for(param)
+}
+
+fn seed_len(param: ParameterSetId) -> usize {
+    slh_dsa_core::seed_len_for(param)
 }
 
-fn keypair_generate<P: ParameterSet>(sk_out: &mut [u8], vk_out: &mut [u8]) -> Result<(), SlhDsaStatus> {
+fn keypair_generate(
+    param: ParameterSetId,
+    sk_out: &mut [u8],
+    vk_out: &mut [u8],
+) -> Result<(), SlhDsaStatus> {
     let mut rng = OsRng;
-    let (sk, vk) = slh_dsa_core::generate_keypair::<P, _>(&mut rng);
-    let sk_bytes = sk.to_bytes();
-    let vk_bytes = vk.to_bytes();
-    sk_out.copy_from_slice(sk_bytes.as_slice());
-    vk_out.copy_from_slice(vk_bytes.as_slice());
+    let sk = DynSigningKey::try_generate(param, &mut rng)?;
+    let vk = sk.verifying_key();
+    sk_out.copy_from_slice(&SecretBytes::from(sk.to_vec()));
+    vk_out.copy_from_slice(&vk.to_vec());
+    Ok(())
+}
+
+fn keypair_from_seed(
+    param: ParameterSetId,
+    sk_seed: &[u8],
+    sk_prf: &[u8],
+    pk_seed: &[u8],
+    sk_out: &mut [u8],
+    vk_out: &mut [u8],
+) -> Result<(), SlhDsaStatus> {
+    let sk = DynSigningKey::from_seed(param, sk_seed, sk_prf, pk_seed)?;
+    let vk = sk.verifying_key();
+    sk_out.copy_from_slice(&SecretBytes::from(sk.to_vec()));
+    vk_out.copy_from_slice(&vk.to_vec());
     Ok(())
 }
 
-fn sign<P: ParameterSet>(
+fn sign(
+    param: ParameterSetId,
     sk_bytes: &[u8],
     msg: &[u8],
     ctx: &[u8],
     sig_out: &mut [u8],
+    checked: bool,
+) -> Result<(), SlhDsaStatus> {
+    let sk = DynSigningKey::from_bytes(param, sk_bytes)?;
+    let mut rng = OsRng;
+    let sig = if checked {
+        sk.sign_checked(&mut rng, msg, ctx)?
+    } else {
+        sk.try_sign_with_rng(&mut rng, msg, ctx)?
+    };
+    sig_out.copy_from_slice(&sig.to_vec());
+    Ok(())
+}
+
+fn sign_many(
+    param: ParameterSetId,
+    sk_bytes: &[u8],
+    msgs: &[&[u8]],
+    ctx: &[u8],
+    sigs_out: &mut [u8],
 ) -> Result<(), SlhDsaStatus> {
-    let sk = SigningKey::<P>::try_from(sk_bytes).map_err(|_| SlhDsaStatus::DecodeError)?;
+    let sk = DynSigningKey::from_bytes(param, sk_bytes)?;
     let mut rng = OsRng;
-    let sig = slh_dsa_core::sign_with_rng::<P, _>(&sk, &mut rng, msg, ctx)
-        .map_err(|_| SlhDsaStatus::InvalidParam)?;
-    let sig_bytes = sig.to_bytes();
-    sig_out.copy_from_slice(sig_bytes.as_slice());
+    let sigs = sign_batch(&sk, &mut rng, msgs, ctx)?;
+    for (out, sig) in sigs_out.chunks_mut(sig_len(param)).zip(&sigs) {
+        out.copy_from_slice(&sig.to_vec());
+    }
     Ok(())
 }
 
-fn sign_deterministic<P: ParameterSet>(
+fn sign_deterministic(
+    param: ParameterSetId,
     sk_bytes: &[u8],
     msg: &[u8],
     ctx: &[u8],
     sig_out: &mut [u8],
 ) -> Result<(), SlhDsaStatus> {
-    let sk = SigningKey::<P>::try_from(sk_bytes).map_err(|_| SlhDsaStatus::DecodeError)?;
-    let sig = slh_dsa_core::sign_deterministic::<P>(&sk, msg, ctx)
-        .map_err(|_| SlhDsaStatus::InvalidParam)?;
-    let sig_bytes = sig.to_bytes();
-    sig_out.copy_from_slice(sig_bytes.as_slice());
+    let sk = DynSigningKey::from_bytes(param, sk_bytes)?;
+    let sig = sk.sign_deterministic(msg, ctx)?;
+    sig_out.copy_from_slice(&sig.to_vec());
     Ok(())
 }
 
-fn verifying_key_from_signing_key<P: ParameterSet>(
+fn verifying_key_from_signing_key(
+    param: ParameterSetId,
     sk_bytes: &[u8],
     vk_out: &mut [u8],
+    validated: bool,
 ) -> Result<(), SlhDsaStatus> {
-    let sk = SigningKey::<P>::try_from(sk_bytes).map_err(|_| SlhDsaStatus::DecodeError)?;
-    let vk = sk.as_ref().clone();
-    let vk_bytes = vk.to_bytes();
-    vk_out.copy_from_slice(vk_bytes.as_slice());
+    let sk = if validated {
+        DynSigningKey::from_bytes_validated(param, sk_bytes)?
+    } else {
+        DynSigningKey::from_bytes(param, sk_bytes)?
+    };
+    vk_out.copy_from_slice(&sk.verifying_key().to_vec());
     Ok(())
 }
 
-fn verify<P: ParameterSet>(
+fn verify(
+    param: ParameterSetId,
     vk_bytes: &[u8],
     msg: &[u8],
     ctx: &[u8],
     sig_bytes: &[u8],
 ) -> Result<(), SlhDsaStatus> {
-    let vk = VerifyingKey::<P>::try_from(vk_bytes).map_err(|_| SlhDsaStatus::DecodeError)?;
-    let sig = Signature::<P>::try_from(sig_bytes).map_err(|_| SlhDsaStatus::DecodeError)?;
-    slh_dsa_core::verify_with_context::<P>(&vk, msg, ctx, &sig)
-        .map_err(|_| SlhDsaStatus::VerifyFailed)
+    let vk = DynVerifyingKey::from_bytes(param, vk_bytes)?;
+    let sig = DynSignature::from_bytes(param, sig_bytes)?;
+    vk.verify(msg, ctx, &sig)?;
+    Ok(())
 }
 
-fn dispatch_keypair(
+fn signing_key_to_pkcs8(
     param: ParameterSetId,
-    sk_out: &mut [u8],
-    vk_out: &mut [u8],
-) -> Result<(), SlhDsaStatus> {
-    match param {
-        ParameterSetId::Shake128s => keypair_generate::<Shake128s>(sk_out, vk_out),
-        ParameterSetId::Shake128f => keypair_generate::<Shake128f>(sk_out, vk_out),
-        ParameterSetId::Shake192s => keypair_generate::<Shake192s>(sk_out, vk_out),
-        ParameterSetId::Shake192f => keypair_generate::<Shake192f>(sk_out, vk_out),
-        ParameterSetId::Shake256s => keypair_generate::<Shake256s>(sk_out, vk_out),
-        ParameterSetId::Shake256f => keypair_generate::<Shake256f>(sk_out, vk_out),
-        ParameterSetId::Sha2_128s => keypair_generate::<Sha2_128s>(sk_out, vk_out),
-        ParameterSetId::Sha2_128f => keypair_generate::<Sha2_128f>(sk_out, vk_out),
-        ParameterSetId::Sha2_192s => keypair_generate::<Sha2_192s>(sk_out, vk_out),
-        ParameterSetId::Sha2_192f => keypair_generate::<Sha2_192f>(sk_out, vk_out),
-        ParameterSetId::Sha2_256s => keypair_generate::<Sha2_256s>(sk_out, vk_out),
-        ParameterSetId::Sha2_256f => keypair_generate::<Sha2_256f>(sk_out, vk_out),
-    }
-}
-
-fn dispatch_sign(
+    sk_bytes: &[u8],
+    pem: bool,
+) -> Result<SecretBytes, SlhDsaStatus> {
+    let sk = DynSigningKey::from_bytes(param, sk_bytes)?;
+    let encoded = if pem {
+        let pem = sk.to_pkcs8_pem(LineEnding::LF).map_err(SlhDsaError::from)?;
+        SecretBytes::from(pem.as_bytes().to_vec())
+    } else {
+        let der = sk.to_pkcs8_der().map_err(SlhDsaError::from)?;
+        SecretBytes::from(der.as_bytes().to_vec())
+    };
+    Ok(encoded)
+}
+
+fn verifying_key_to_spki(
+    param: ParameterSetId,
+    vk_bytes: &[u8],
+    pem: bool,
+) -> Result<Vec<u8>, SlhDsaStatus> {
+    let vk = DynVerifyingKey::from_bytes(param, vk_bytes)?;
+    let encoded = if pem {
+        vk.to_public_key_pem(LineEnding::LF)
+            .map_err(SlhDsaError::from)?
+            .into_bytes()
+    } else {
+        vk.to_public_key_der()
+            .map_err(SlhDsaError::from)?
+            .into_vec()
+    };
+    Ok(encoded)
+}
+
+fn signing_key_to_key_file(
     param: ParameterSetId,
     sk_bytes: &[u8],
-    msg: &[u8],
-    ctx: &[u8],
-    sig_out: &mut [u8],
-) -> Result<(), SlhDsaStatus> {
-    match param {
-        ParameterSetId::Shake128s => sign::<Shake128s>(sk_bytes, msg, ctx, sig_out),
-        ParameterSetId::Shake128f => sign::<Shake128f>(sk_bytes, msg, ctx, sig_out),
-        ParameterSetId::Shake192s => sign::<Shake192s>(sk_bytes, msg, ctx, sig_out),
-        ParameterSetId::Shake192f => sign::<Shake192f>(sk_bytes, msg, ctx, sig_out),
-        ParameterSetId::Shake256s => sign::<Shake256s>(sk_bytes, msg, ctx, sig_out),
-        ParameterSetId::Shake256f => sign::<Shake256f>(sk_bytes, msg, ctx, sig_out),
-        ParameterSetId::Sha2_128s => sign::<Sha2_128s>(sk_bytes, msg, ctx, sig_out),
-        ParameterSetId::Sha2_128f => sign::<Sha2_128f>(sk_bytes, msg, ctx, sig_out),
-        ParameterSetId::Sha2_192s => sign::<Sha2_192s>(sk_bytes, msg, ctx, sig_out),
-        ParameterSetId::Sha2_192f => sign::<Sha2_192f>(sk_bytes, msg, ctx, sig_out),
-        ParameterSetId::Sha2_256s => sign::<Sha2_256s>(sk_bytes, msg, ctx, sig_out),
-        ParameterSetId::Sha2_256f => sign::<Sha2_256f>(sk_bytes, msg, ctx, sig_out),
-    }
-}
-
-fn dispatch_sign_deterministic(
+    passphrase: &[u8],
+    kdf: SlhDsaKdf,
+    aead: SlhDsaAead,
+) -> Result<Vec<u8>, SlhDsaStatus> {
+    let sk = DynSigningKey::from_bytes(param, sk_bytes)?;
+    let options = KeyFileOptions {
+        kdf: kdf.into(),
+        aead: aead.into(),
+    };
+    let mut rng = OsRng;
+    Ok(sk.to_key_file(passphrase, options, &mut rng)?)
+}
+
+fn signing_key_to_encrypted_pkcs8(
     param: ParameterSetId,
     sk_bytes: &[u8],
-    msg: &[u8],
-    ctx: &[u8],
-    sig_out: &mut [u8],
+    passphrase: &[u8],
+    pem: bool,
+) -> Result<Vec<u8>, SlhDsaStatus> {
+    let sk = DynSigningKey::from_bytes(param, sk_bytes)?;
+    let mut rng = OsRng;
+    let encoded = if pem {
+        sk.to_encrypted_pkcs8_pem(passphrase, &mut rng)?
+            .into_bytes()
+    } else {
+        sk.to_encrypted_pkcs8_der(passphrase, &mut rng)?
+    };
+    Ok(encoded)
+}
+
+/// Report a decrypted signing key and its parameter set.
+fn write_signing_key(
+    sk: &DynSigningKey,
+    param_out: *mut ParameterSetId,
+    signing_key_out: *mut u8,
+    signing_key_capacity: usize,
+    signing_key_len: *mut usize,
 ) -> Result<(), SlhDsaStatus> {
-    match param {
-        ParameterSetId::Shake128s => sign_deterministic::<Shake128s>(sk_bytes, msg, ctx, sig_out),
-        ParameterSetId::Shake128f => sign_deterministic::<Shake128f>(sk_bytes, msg, ctx, sig_out),
-        ParameterSetId::Shake192s => sign_deterministic::<Shake192s>(sk_bytes, msg, ctx, sig_out),
-        ParameterSetId::Shake192f => sign_deterministic::<Shake192f>(sk_bytes, msg, ctx, sig_out),
-        ParameterSetId::Shake256s => sign_deterministic::<Shake256s>(sk_bytes, msg, ctx, sig_out),
-        ParameterSetId::Shake256f => sign_deterministic::<Shake256f>(sk_bytes, msg, ctx, sig_out),
-        ParameterSetId::Sha2_128s => sign_deterministic::<Sha2_128s>(sk_bytes, msg, ctx, sig_out),
-        ParameterSetId::Sha2_128f => sign_deterministic::<Sha2_128f>(sk_bytes, msg, ctx, sig_out),
-        ParameterSetId::Sha2_192s => sign_deterministic::<Sha2_192s>(sk_bytes, msg, ctx, sig_out),
-        ParameterSetId::Sha2_192f => sign_deterministic::<Sha2_192f>(sk_bytes, msg, ctx, sig_out),
-        ParameterSetId::Sha2_256s => sign_deterministic::<Sha2_256s>(sk_bytes, msg, ctx, sig_out),
-        ParameterSetId::Sha2_256f => sign_deterministic::<Sha2_256f>(sk_bytes, msg, ctx, sig_out),
-    }
-}
-
-fn dispatch_vk_from_sk(
+    let sk_bytes = SecretBytes::from(sk.to_vec());
+    write_parameter_set(sk.parameter_set(), param_out)?;
+    write_output(
+        &sk_bytes,
+        signing_key_out,
+        signing_key_capacity,
+        signing_key_len,
+    )
+}
+
+fn write_parameter_set(
     param: ParameterSetId,
-    sk_bytes: &[u8],
-    vk_out: &mut [u8],
+    param_out: *mut ParameterSetId,
 ) -> Result<(), SlhDsaStatus> {
-    match param {
-        ParameterSetId::Shake128s => verifying_key_from_signing_key::<Shake128s>(sk_bytes, vk_out),
-        ParameterSetId::Shake128f => verifying_key_from_signing_key::<Shake128f>(sk_bytes, vk_out),
-        ParameterSetId::Shake192s => verifying_key_from_signing_key::<Shake192s>(sk_bytes, vk_out),
-        ParameterSetId::Shake192f => verifying_key_from_signing_key::<Shake192f>(sk_bytes, vk_out),
-        ParameterSetId::Shake256s => verifying_key_from_signing_key::<Shake256s>(sk_bytes, vk_out),
-        ParameterSetId::Shake256f => verifying_key_from_signing_key::<Shake256f>(sk_bytes, vk_out),
-        ParameterSetId::Sha2_128s => verifying_key_from_signing_key::<Sha2_128s>(sk_bytes, vk_out),
-        ParameterSetId::Sha2_128f => verifying_key_from_signing_key::<Sha2_128f>(sk_bytes, vk_out),
-        ParameterSetId::Sha2_192s => verifying_key_from_signing_key::<Sha2_192s>(sk_bytes, vk_out),
-        ParameterSetId::Sha2_192f => verifying_key_from_signing_key::<Sha2_192f>(sk_bytes, vk_out),
-        ParameterSetId::Sha2_256s => verifying_key_from_signing_key::<Sha2_256s>(sk_bytes, vk_out),
-        ParameterSetId::Sha2_256f => verifying_key_from_signing_key::<Sha2_256f>(sk_bytes, vk_out),
-    }
-}
-
-fn dispatch_verify(
+    if param_out.is_null() {
+        return Err(SlhDsaStatus::NullPtr);
+    }
+    // SAFETY: checked for null above; caller guarantees it is valid for writes.
+    unsafe { param_out.write(param) };
+    Ok(())
+}
+
+fn write_signature_mode(
+    mode: SlhDsaSignatureMode,
+    mode_out: *mut SlhDsaSignatureMode,
+) -> Result<(), SlhDsaStatus> {
+    if mode_out.is_null() {
+        return Err(SlhDsaStatus::NullPtr);
+    }
+    // SAFETY: checked for null above; caller guarantees it is valid for writes.
+    unsafe { mode_out.write(mode) };
+    Ok(())
+}
+
+type ParsedVerifyItem<'a> = (DynVerifyingKey, &'a [u8], &'a [u8], DynSignature);
+
+fn parse_verify_item<'a>(item: &SlhDsaVerifyItem) -> Result<ParsedVerifyItem<'a>, SlhDsaStatus> {
+    if item.verifying_key_len != vk_len(item.param) || item.signature_len != sig_len(item.param) {
+        return Err(SlhDsaStatus::InvalidLength);
+    }
+
+    let vk_bytes = read_slice(item.verifying_key, item.verifying_key_len)?;
+    let msg = read_slice(item.msg, item.msg_len)?;
+    let ctx = read_slice(item.ctx, item.ctx_len)?;
+    let sig_bytes = read_slice(item.signature, item.signature_len)?;
+    let vk = DynVerifyingKey::from_bytes(item.param, vk_bytes)?;
+    let sig = DynSignature::from_bytes(item.param, sig_bytes)?;
+    Ok((vk, msg, ctx, sig))
+}
+
+fn verify_many(items: &[SlhDsaVerifyItem], statuses: &mut [SlhDsaStatus]) {
+    let parsed: Vec<_> = items.iter().map(parse_verify_item).collect();
+    let batch: Vec<_> = parsed
+        .iter()
+        .filter_map(|item| item.as_ref().ok())
+        .map(|(vk, msg, ctx, sig)| BatchVerifyItem {
+            verifying_key: vk,
+            msg,
+            ctx,
+            signature: sig,
+        })
+        .collect();
+    let mut results = verify_batch(&batch).into_iter();
+
+    for (status, item) in statuses.iter_mut().zip(&parsed) {
+        *status = match item {
+            Ok(_) => match results.next() {
+                Some(Ok(())) => SlhDsaStatus::Ok,
+                Some(Err(err)) => err.into(),
+                None => SlhDsaStatus::VerifyFailed,
+            },
+            Err(err) => *err,
+        };
+    }
+}
+
+fn envelope_sign(
     param: ParameterSetId,
-    vk_bytes: &[u8],
-    msg: &[u8],
+    sk_bytes: &[u8],
+    payload: &[u8],
     ctx: &[u8],
-    sig_bytes: &[u8],
-) -> Result<(), SlhDsaStatus> {
-    match param {
-        ParameterSetId::Shake128s => verify::<Shake128s>(vk_bytes, msg, ctx, sig_bytes),
-        ParameterSetId::Shake128f => verify::<Shake128f>(vk_bytes, msg, ctx, sig_bytes),
-        ParameterSetId::Shake192s => verify::<Shake192s>(vk_bytes, msg, ctx, sig_bytes),
-        ParameterSetId::Shake192f => verify::<Shake192f>(vk_bytes, msg, ctx, sig_bytes),
-        ParameterSetId::Shake256s => verify::<Shake256s>(vk_bytes, msg, ctx, sig_bytes),
-        ParameterSetId::Shake256f => verify::<Shake256f>(vk_bytes, msg, ctx, sig_bytes),
-        ParameterSetId::Sha2_128s => verify::<Sha2_128s>(vk_bytes, msg, ctx, sig_bytes),
-        ParameterSetId::Sha2_128f => verify::<Sha2_128f>(vk_bytes, msg, ctx, sig_bytes),
-        ParameterSetId::Sha2_192s => verify::<Sha2_192s>(vk_bytes, msg, ctx, sig_bytes),
-        ParameterSetId::Sha2_192f => verify::<Sha2_192f>(vk_bytes, msg, ctx, sig_bytes),
-        ParameterSetId::Sha2_256s => verify::<Sha2_256s>(vk_bytes, msg, ctx, sig_bytes),
-        ParameterSetId::Sha2_256f => verify::<Sha2_256f>(vk_bytes, msg, ctx, sig_bytes),
+    key_id: &[u8],
+    mode: SlhDsaSignatureMode,
+    detached: bool,
+) -> Result<Vec<u8>, SlhDsaStatus> {
+    let sk = DynSigningKey::from_bytes(param, sk_bytes)?;
+    let mut builder = EnvelopeBuilder::new(payload)
+        .context(ctx)
+        .mode(mode.into())
+        .detached(detached);
+    if !key_id.is_empty() {
+        builder = builder.key_id(key_id);
+    }
+
+    let mut rng = OsRng;
+    Ok(builder.sign(&sk, &mut rng)?.to_vec())
+}
+
+fn envelope_verify(
+    vk_bytes: &[u8],
+    encoded: &[u8],
+    detached_payload: Option<&[u8]>,
+) -> Result<Envelope, SlhDsaStatus> {
+    let envelope = Envelope::from_bytes(encoded)?;
+    let vk = DynVerifyingKey::from_bytes(envelope.parameter_set(), vk_bytes)?;
+    envelope.verify(&vk, detached_payload)?;
+    Ok(envelope)
+}
+
+fn fingerprint(
+    param: ParameterSetId,
+    vk_bytes: &[u8],
+    source: SlhDsaFingerprintSource,
+) -> Result<Fingerprint, SlhDsaStatus> {
+    let vk = DynVerifyingKey::from_bytes(param, vk_bytes)?;
+    match source {
+        SlhDsaFingerprintSource::Raw => Ok(vk.fingerprint()),
+        SlhDsaFingerprintSource::Spki => Ok(vk.spki_fingerprint()?),
     }
 }
 
+fn fingerprint_to_string(
+    fingerprint: &[u8],
+    format: SlhDsaFingerprintFormat,
+) -> Result<String, SlhDsaStatus> {
+    let bytes =
+        <[u8; FINGERPRINT_LEN]>::try_from(fingerprint).map_err(|_| SlhDsaStatus::InvalidLength)?;
+    let fingerprint = Fingerprint::from_bytes(bytes);
+    Ok(match format {
+        SlhDsaFingerprintFormat::Hex => fingerprint.to_hex(),
+        SlhDsaFingerprintFormat::Base32 => fingerprint.to_base32(),
+        SlhDsaFingerprintFormat::Words => fingerprint.to_words(),
+    })
+}
+
 #[no_mangle]
 pub extern "C" fn slh_dsa_parameter_name(param: ParameterSetId) -> *const c_char {
     parameter_name(param)
 }
 
+/// Copy the metadata table into `out`, always reporting the number of parameter sets in `out_len`.
+#[no_mangle]
+pub extern "C" fn slh_dsa_parameter_sets(
+    out: *mut SlhDsaParameterSetInfo,
+    out_capacity: usize,
+    out_len: *mut usize,
+) -> SlhDsaStatus {
+    match write_parameter_sets(out, out_capacity, out_len) {
+        Ok(()) => SlhDsaStatus::Ok,
+        Err(status) => status,
+    }
+}
+
+/// Look up a parameter set by its FIPS 205 name, e.g. `SLH-DSA-SHA2-128s`.
+#[no_mangle]
+pub extern "C" fn slh_dsa_parameter_set_from_name(
+    name: *const c_char,
+    param_out: *mut ParameterSetId,
+) -> SlhDsaStatus {
+    let result = read_str(name).and_then(|name| {
+        let param = ParameterSetId::from_name(name).ok_or(SlhDsaStatus::UnsupportedAlgorithm)?;
+        write_parameter_set(param, param_out)
+    });
+    match result {
+        Ok(()) => SlhDsaStatus::Ok,
+        Err(status) => status,
+    }
+}
+
+/// Look up a parameter set by its dotted OID, e.g. `2.16.840.1.101.3.4.3.20`.
+#[no_mangle]
+pub extern "C" fn slh_dsa_parameter_set_from_oid(
+    oid: *const c_char,
+    param_out: *mut ParameterSetId,
+) -> SlhDsaStatus {
+    let result = read_str(oid).and_then(|oid| {
+        let param = ParameterSetId::from_oid_str(oid).ok_or(SlhDsaStatus::UnsupportedAlgorithm)?;
+        write_parameter_set(param, param_out)
+    });
+    match result {
+        Ok(()) => SlhDsaStatus::Ok,
+        Err(status) => status,
+    }
+}
+
 #[no_mangle]
 pub extern "C" fn slh_dsa_signing_key_len(param: ParameterSetId) -> usize {
     sk_len(param)
@@ -289,6 +778,11 @@ pub extern "C" fn slh_dsa_signature_len(param: ParameterSetId) -> usize {
     sig_len(param)
 }
 
+#[no_mangle]
+pub extern "C" fn slh_dsa_seed_len(param: ParameterSetId) -> usize {
+    seed_len(param)
+}
+
 #[no_mangle]
 pub extern "C" fn slh_dsa_keypair_generate(
     param: ParameterSetId,
@@ -312,55 +806,74 @@ pub extern "C" fn slh_dsa_keypair_generate(
         Err(status) => return status,
     };
 
-    match dispatch_keypair(param, sk_out, vk_out) {
+    match keypair_generate(param, sk_out, vk_out) {
         Ok(()) => SlhDsaStatus::Ok,
         Err(status) => status,
     }
 }
 
 #[no_mangle]
-pub extern "C" fn slh_dsa_sign(
+pub extern "C" fn slh_dsa_keypair_from_seed(
     param: ParameterSetId,
-    signing_key: *const u8,
+    sk_seed: *const u8,
+    sk_seed_len: usize,
+    sk_prf: *const u8,
+    sk_prf_len: usize,
+    pk_seed: *const u8,
+    pk_seed_len: usize,
+    signing_key_out: *mut u8,
     signing_key_len: usize,
-    msg: *const u8,
-    msg_len: usize,
-    ctx: *const u8,
-    ctx_len: usize,
-    signature_out: *mut u8,
-    signature_len: usize,
+    verifying_key_out: *mut u8,
+    verifying_key_len: usize,
 ) -> SlhDsaStatus {
+    let expected_seed = seed_len(param);
     let expected_sk = sk_len(param);
-    let expected_sig = sig_len(param);
-    if signing_key_len != expected_sk || signature_len != expected_sig {
+    let expected_vk = vk_len(param);
+    if sk_seed_len != expected_seed
+        || sk_prf_len != expected_seed
+        || pk_seed_len != expected_seed
+        || signing_key_len != expected_sk
+        || verifying_key_len != expected_vk
+    {
         return SlhDsaStatus::InvalidLength;
     }
 
-    let sk_bytes = match read_slice(signing_key, signing_key_len) {
+    let sk_seed_bytes = match read_slice(sk_seed, sk_seed_len) {
         Ok(slice) => slice,
         Err(status) => return status,
     };
-    let msg_bytes = match read_slice(msg, msg_len) {
+    let sk_prf_bytes = match read_slice(sk_prf, sk_prf_len) {
         Ok(slice) => slice,
         Err(status) => return status,
     };
-    let ctx_bytes = match read_slice(ctx, ctx_len) {
+    let pk_seed_bytes = match read_slice(pk_seed, pk_seed_len) {
         Ok(slice) => slice,
         Err(status) => return status,
     };
-    let sig_out = match write_slice(signature_out, signature_len) {
+    let sk_out = match write_slice(signing_key_out, signing_key_len) {
+        Ok(slice) => slice,
+        Err(status) => return status,
+    };
+    let vk_out = match write_slice(verifying_key_out, verifying_key_len) {
         Ok(slice) => slice,
         Err(status) => return status,
     };
 
-    match dispatch_sign(param, sk_bytes, msg_bytes, ctx_bytes, sig_out) {
+    match keypair_from_seed(
+        param,
+        sk_seed_bytes,
+        sk_prf_bytes,
+        pk_seed_bytes,
+        sk_out,
+        vk_out,
+    ) {
         Ok(()) => SlhDsaStatus::Ok,
         Err(status) => status,
     }
 }
 
 #[no_mangle]
-pub extern "C" fn slh_dsa_sign_deterministic(
+pub extern "C" fn slh_dsa_sign(
     param: ParameterSetId,
     signing_key: *const u8,
     signing_key_len: usize,
@@ -394,23 +907,30 @@ pub extern "C" fn slh_dsa_sign_deterministic(
         Err(status) => return status,
     };
 
-    match dispatch_sign_deterministic(param, sk_bytes, msg_bytes, ctx_bytes, sig_out) {
+    match sign(param, sk_bytes, msg_bytes, ctx_bytes, sig_out, false) {
         Ok(()) => SlhDsaStatus::Ok,
         Err(status) => status,
     }
 }
 
+/// Sign `count` messages with one key, writing the signatures back to back into
+/// `signatures_out`, which must hold `count * slh_dsa_signature_len(param)` bytes.
 #[no_mangle]
-pub extern "C" fn slh_dsa_verifying_key_from_signing_key(
+pub extern "C" fn slh_dsa_sign_batch(
     param: ParameterSetId,
     signing_key: *const u8,
     signing_key_len: usize,
-    verifying_key_out: *mut u8,
-    verifying_key_len: usize,
+    msgs: *const *const u8,
+    msg_lens: *const usize,
+    count: usize,
+    ctx: *const u8,
+    ctx_len: usize,
+    signatures_out: *mut u8,
+    signatures_len: usize,
 ) -> SlhDsaStatus {
     let expected_sk = sk_len(param);
-    let expected_vk = vk_len(param);
-    if signing_key_len != expected_sk || verifying_key_len != expected_vk {
+    let expected_sigs = sig_len(param).checked_mul(count);
+    if signing_key_len != expected_sk || expected_sigs != Some(signatures_len) {
         return SlhDsaStatus::InvalidLength;
     }
 
@@ -418,12 +938,199 @@ pub extern "C" fn slh_dsa_verifying_key_from_signing_key(
         Ok(slice) => slice,
         Err(status) => return status,
     };
-    let vk_out = match write_slice(verifying_key_out, verifying_key_len) {
+    let ctx_bytes = match read_slice(ctx, ctx_len) {
         Ok(slice) => slice,
         Err(status) => return status,
     };
+    let sigs_out = match write_slice(signatures_out, signatures_len) {
+        Ok(slice) => slice,
+        Err(status) => return status,
+    };
+    let msg_ptrs = match read_array(msgs, count) {
+        Ok(slice) => slice,
+        Err(status) => return status,
+    };
+    let msg_lens = match read_array(msg_lens, count) {
+        Ok(slice) => slice,
+        Err(status) => return status,
+    };
+    let msg_bytes = match msg_ptrs
+        .iter()
+        .zip(msg_lens)
+        .map(|(&ptr, &len)| read_slice(ptr, len))
+        .collect::<Result<Vec<_>, _>>()
+    {
+        Ok(slices) => slices,
+        Err(status) => return status,
+    };
 
-    match dispatch_vk_from_sk(param, sk_bytes, vk_out) {
+    match sign_many(param, sk_bytes, &msg_bytes, ctx_bytes, sigs_out) {
+        Ok(()) => SlhDsaStatus::Ok,
+        Err(status) => status,
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn slh_dsa_sign_deterministic(
+    param: ParameterSetId,
+    signing_key: *const u8,
+    signing_key_len: usize,
+    msg: *const u8,
+    msg_len: usize,
+    ctx: *const u8,
+    ctx_len: usize,
+    signature_out: *mut u8,
+    signature_len: usize,
+) -> SlhDsaStatus {
+    let expected_sk = sk_len(param);
+    let expected_sig = sig_len(param);
+    if signing_key_len != expected_sk || signature_len != expected_sig {
+        return SlhDsaStatus::InvalidLength;
+    }
+
+    let sk_bytes = match read_slice(signing_key, signing_key_len) {
+        Ok(slice) => slice,
+        Err(status) => return status,
+    };
+    let msg_bytes = match read_slice(msg, msg_len) {
+        Ok(slice) => slice,
+        Err(status) => return status,
+    };
+    let ctx_bytes = match read_slice(ctx, ctx_len) {
+        Ok(slice) => slice,
+        Err(status) => return status,
+    };
+    let sig_out = match write_slice(signature_out, signature_len) {
+        Ok(slice) => slice,
+        Err(status) => return status,
+    };
+
+    match sign_deterministic(param, sk_bytes, msg_bytes, ctx_bytes, sig_out) {
+        Ok(()) => SlhDsaStatus::Ok,
+        Err(status) => status,
+    }
+}
+
+/// Sign like `slh_dsa_sign`, then verify the signature before returning it.
+/// Returns `FaultDetected` instead of a signature that does not verify.
+#[no_mangle]
+pub extern "C" fn slh_dsa_sign_checked(
+    param: ParameterSetId,
+    signing_key: *const u8,
+    signing_key_len: usize,
+    msg: *const u8,
+    msg_len: usize,
+    ctx: *const u8,
+    ctx_len: usize,
+    signature_out: *mut u8,
+    signature_len: usize,
+) -> SlhDsaStatus {
+    let expected_sk = sk_len(param);
+    let expected_sig = sig_len(param);
+    if signing_key_len != expected_sk || signature_len != expected_sig {
+        return SlhDsaStatus::InvalidLength;
+    }
+
+    let sk_bytes = match read_slice(signing_key, signing_key_len) {
+        Ok(slice) => slice,
+        Err(status) => return status,
+    };
+    let msg_bytes = match read_slice(msg, msg_len) {
+        Ok(slice) => slice,
+        Err(status) => return status,
+    };
+    let ctx_bytes = match read_slice(ctx, ctx_len) {
+        Ok(slice) => slice,
+        Err(status) => return status,
+    };
+    let sig_out = match write_slice(signature_out, signature_len) {
+        Ok(slice) => slice,
+        Err(status) => return status,
+    };
+
+    match sign(param, sk_bytes, msg_bytes, ctx_bytes, sig_out, true) {
+        Ok(()) => SlhDsaStatus::Ok,
+        Err(status) => status,
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn slh_dsa_verifying_key_from_signing_key(
+    param: ParameterSetId,
+    signing_key: *const u8,
+    signing_key_len: usize,
+    verifying_key_out: *mut u8,
+    verifying_key_len: usize,
+) -> SlhDsaStatus {
+    let expected_sk = sk_len(param);
+    let expected_vk = vk_len(param);
+    if signing_key_len != expected_sk || verifying_key_len != expected_vk {
+        return SlhDsaStatus::InvalidLength;
+    }
+
+    let sk_bytes = match read_slice(signing_key, signing_key_len) {
+        Ok(slice) => slice,
+        Err(status) => return status,
+    };
+    let vk_out = match write_slice(verifying_key_out, verifying_key_len) {
+        Ok(slice) => slice,
+        Err(status) => return status,
+    };
+
+    match verifying_key_from_signing_key(param, sk_bytes, vk_out, false) {
+        Ok(()) => SlhDsaStatus::Ok,
+        Err(status) => status,
+    }
+}
+
+/// Like `slh_dsa_verifying_key_from_signing_key`, but first checks the signing key with
+/// `slh_dsa_validate_signing_key`.
+#[no_mangle]
+pub extern "C" fn slh_dsa_verifying_key_from_signing_key_validated(
+    param: ParameterSetId,
+    signing_key: *const u8,
+    signing_key_len: usize,
+    verifying_key_out: *mut u8,
+    verifying_key_len: usize,
+) -> SlhDsaStatus {
+    let expected_sk = sk_len(param);
+    let expected_vk = vk_len(param);
+    if signing_key_len != expected_sk || verifying_key_len != expected_vk {
+        return SlhDsaStatus::InvalidLength;
+    }
+
+    let sk_bytes = match read_slice(signing_key, signing_key_len) {
+        Ok(slice) => slice,
+        Err(status) => return status,
+    };
+    let vk_out = match write_slice(verifying_key_out, verifying_key_len) {
+        Ok(slice) => slice,
+        Err(status) => return status,
+    };
+
+    match verifying_key_from_signing_key(param, sk_bytes, vk_out, true) {
+        Ok(()) => SlhDsaStatus::Ok,
+        Err(status) => status,
+    }
+}
+
+/// Recompute PK.root from the signing key's SK.seed and PK.seed and compare it with
+/// the embedded one. Returns `MalformedSigningKey` for a corrupted or tampered key.
+#[no_mangle]
+pub extern "C" fn slh_dsa_validate_signing_key(
+    param: ParameterSetId,
+    signing_key: *const u8,
+    signing_key_len: usize,
+) -> SlhDsaStatus {
+    if signing_key_len != sk_len(param) {
+        return SlhDsaStatus::InvalidLength;
+    }
+
+    let result = read_slice(signing_key, signing_key_len).and_then(|sk_bytes| {
+        DynSigningKey::from_bytes(param, sk_bytes)?.validate()?;
+        Ok(())
+    });
+    match result {
         Ok(()) => SlhDsaStatus::Ok,
         Err(status) => status,
     }
@@ -464,21 +1171,507 @@ pub extern "C" fn slh_dsa_verify(
         Err(status) => return status,
     };
 
-    match dispatch_verify(param, vk_bytes, msg_bytes, ctx_bytes, sig_bytes) {
+    match verify(param, vk_bytes, msg_bytes, ctx_bytes, sig_bytes) {
         Ok(()) => SlhDsaStatus::Ok,
         Err(status) => status,
     }
 }
 
+/// Verify `count` items, writing one status per item to `statuses_out`.
+///
+/// The return value only reports problems with the arguments themselves.
+#[no_mangle]
+pub extern "C" fn slh_dsa_verify_batch(
+    items: *const SlhDsaVerifyItem,
+    count: usize,
+    statuses_out: *mut SlhDsaStatus,
+) -> SlhDsaStatus {
+    let items = match read_array(items, count) {
+        Ok(slice) => slice,
+        Err(status) => return status,
+    };
+    let statuses = match write_array(statuses_out, count) {
+        Ok(slice) => slice,
+        Err(status) => return status,
+    };
+    verify_many(items, statuses);
+    SlhDsaStatus::Ok
+}
+
+/// Encode a signing key as PKCS#8 DER, or PEM text without a trailing NUL when `pem` is set.
+///
+/// `out_len` receives the encoded size even when `out_capacity` is too small.
+#[no_mangle]
+pub extern "C" fn slh_dsa_signing_key_to_pkcs8(
+    param: ParameterSetId,
+    signing_key: *const u8,
+    signing_key_len: usize,
+    pem: bool,
+    out: *mut u8,
+    out_capacity: usize,
+    out_len: *mut usize,
+) -> SlhDsaStatus {
+    if signing_key_len != sk_len(param) {
+        return SlhDsaStatus::InvalidLength;
+    }
+
+    let sk_bytes = match read_slice(signing_key, signing_key_len) {
+        Ok(slice) => slice,
+        Err(status) => return status,
+    };
+
+    match signing_key_to_pkcs8(param, sk_bytes, pem)
+        .and_then(|encoded| write_output(&encoded, out, out_capacity, out_len))
+    {
+        Ok(()) => SlhDsaStatus::Ok,
+        Err(status) => status,
+    }
+}
+
+/// Decode a PKCS#8 signing key (DER or PEM), reporting its parameter set through `param_out`.
+#[no_mangle]
+pub extern "C" fn slh_dsa_signing_key_from_pkcs8(
+    encoded: *const u8,
+    encoded_len: usize,
+    param_out: *mut ParameterSetId,
+    signing_key_out: *mut u8,
+    signing_key_capacity: usize,
+    signing_key_len: *mut usize,
+) -> SlhDsaStatus {
+    let encoded_bytes = match read_slice(encoded, encoded_len) {
+        Ok(slice) => slice,
+        Err(status) => return status,
+    };
+
+    let sk = match DynSigningKey::from_pkcs8(encoded_bytes) {
+        Ok(key) => key,
+        Err(err) => return err.into(),
+    };
+    let sk_bytes = SecretBytes::from(sk.to_vec());
+    match write_parameter_set(sk.parameter_set(), param_out).and_then(|()| {
+        write_output(
+            &sk_bytes,
+            signing_key_out,
+            signing_key_capacity,
+            signing_key_len,
+        )
+    }) {
+        Ok(()) => SlhDsaStatus::Ok,
+        Err(status) => status,
+    }
+}
+
+/// Encode a verifying key as SubjectPublicKeyInfo DER, or PEM text when `pem` is set.
+///
+/// `out_len` receives the encoded size even when `out_capacity` is too small.
+#[no_mangle]
+pub extern "C" fn slh_dsa_verifying_key_to_spki(
+    param: ParameterSetId,
+    verifying_key: *const u8,
+    verifying_key_len: usize,
+    pem: bool,
+    out: *mut u8,
+    out_capacity: usize,
+    out_len: *mut usize,
+) -> SlhDsaStatus {
+    if verifying_key_len != vk_len(param) {
+        return SlhDsaStatus::InvalidLength;
+    }
+
+    let vk_bytes = match read_slice(verifying_key, verifying_key_len) {
+        Ok(slice) => slice,
+        Err(status) => return status,
+    };
+
+    match verifying_key_to_spki(param, vk_bytes, pem)
+        .and_then(|encoded| write_output(&encoded, out, out_capacity, out_len))
+    {
+        Ok(()) => SlhDsaStatus::Ok,
+        Err(status) => status,
+    }
+}
+
+/// Decode a SubjectPublicKeyInfo (DER or PEM), reporting its parameter set through `param_out`.
+#[no_mangle]
+pub extern "C" fn slh_dsa_verifying_key_from_spki(
+    encoded: *const u8,
+    encoded_len: usize,
+    param_out: *mut ParameterSetId,
+    verifying_key_out: *mut u8,
+    verifying_key_capacity: usize,
+    verifying_key_len: *mut usize,
+) -> SlhDsaStatus {
+    let encoded_bytes = match read_slice(encoded, encoded_len) {
+        Ok(slice) => slice,
+        Err(status) => return status,
+    };
+
+    let vk = match DynVerifyingKey::from_spki(encoded_bytes) {
+        Ok(key) => key,
+        Err(err) => return err.into(),
+    };
+    match write_parameter_set(vk.parameter_set(), param_out).and_then(|()| {
+        write_output(
+            &vk.to_vec(),
+            verifying_key_out,
+            verifying_key_capacity,
+            verifying_key_len,
+        )
+    }) {
+        Ok(()) => SlhDsaStatus::Ok,
+        Err(status) => status,
+    }
+}
+
+/// Byte length of a key file from `slh_dsa_signing_key_to_key_file` for `param`.
+#[no_mangle]
+pub extern "C" fn slh_dsa_key_file_len(param: ParameterSetId) -> usize {
+    keyfile::encoded_len(param)
+}
+
+/// Encrypt a signing key under `passphrase` into a key file for storage at rest.
+/// The parameter set and KDF costs are authenticated along with the key.
+///
+/// `out_len` receives the encoded size even when `out_capacity` is too small.
+#[no_mangle]
+pub extern "C" fn slh_dsa_signing_key_to_key_file(
+    param: ParameterSetId,
+    signing_key: *const u8,
+    signing_key_len: usize,
+    passphrase: *const u8,
+    passphrase_len: usize,
+    kdf: SlhDsaKdf,
+    aead: SlhDsaAead,
+    out: *mut u8,
+    out_capacity: usize,
+    out_len: *mut usize,
+) -> SlhDsaStatus {
+    if signing_key_len != sk_len(param) {
+        return SlhDsaStatus::InvalidLength;
+    }
+
+    let result = read_slice(signing_key, signing_key_len).and_then(|sk_bytes| {
+        let passphrase = read_slice(passphrase, passphrase_len)?;
+        let encoded = signing_key_to_key_file(param, sk_bytes, passphrase, kdf, aead)?;
+        write_output(&encoded, out, out_capacity, out_len)
+    });
+    match result {
+        Ok(()) => SlhDsaStatus::Ok,
+        Err(status) => status,
+    }
+}
+
+/// Decrypt a key file from `slh_dsa_signing_key_to_key_file`, reporting its parameter
+/// set through `param_out`. A wrong passphrase or a modified file returns `DecryptionFailed`.
+#[no_mangle]
+pub extern "C" fn slh_dsa_signing_key_from_key_file(
+    encoded: *const u8,
+    encoded_len: usize,
+    passphrase: *const u8,
+    passphrase_len: usize,
+    param_out: *mut ParameterSetId,
+    signing_key_out: *mut u8,
+    signing_key_capacity: usize,
+    signing_key_len: *mut usize,
+) -> SlhDsaStatus {
+    let result = read_slice(encoded, encoded_len).and_then(|encoded_bytes| {
+        let passphrase = read_slice(passphrase, passphrase_len)?;
+        let sk = DynSigningKey::from_key_file(encoded_bytes, passphrase)?;
+        write_signing_key(
+            &sk,
+            param_out,
+            signing_key_out,
+            signing_key_capacity,
+            signing_key_len,
+        )
+    });
+    match result {
+        Ok(()) => SlhDsaStatus::Ok,
+        Err(status) => status,
+    }
+}
+
+/// Encrypt a signing key under `passphrase` as a PKCS#8 `EncryptedPrivateKeyInfo`
+/// (PBES2 with scrypt and AES-256-CBC), DER or PEM text when `pem` is set.
+///
+/// `out_len` receives the encoded size even when `out_capacity` is too small.
+#[no_mangle]
+pub extern "C" fn slh_dsa_signing_key_to_encrypted_pkcs8(
+    param: ParameterSetId,
+    signing_key: *const u8,
+    signing_key_len: usize,
+    passphrase: *const u8,
+    passphrase_len: usize,
+    pem: bool,
+    out: *mut u8,
+    out_capacity: usize,
+    out_len: *mut usize,
+) -> SlhDsaStatus {
+    if signing_key_len != sk_len(param) {
+        return SlhDsaStatus::InvalidLength;
+    }
+
+    let result = read_slice(signing_key, signing_key_len).and_then(|sk_bytes| {
+        let passphrase = read_slice(passphrase, passphrase_len)?;
+        let encoded = signing_key_to_encrypted_pkcs8(param, sk_bytes, passphrase, pem)?;
+        write_output(&encoded, out, out_capacity, out_len)
+    });
+    match result {
+        Ok(()) => SlhDsaStatus::Ok,
+        Err(status) => status,
+    }
+}
+
+/// Decrypt a PKCS#8 `EncryptedPrivateKeyInfo` (DER or PEM), reporting its parameter set
+/// through `param_out`. Only PBES2 with scrypt and AES-256-CBC is supported.
+#[no_mangle]
+pub extern "C" fn slh_dsa_signing_key_from_encrypted_pkcs8(
+    encoded: *const u8,
+    encoded_len: usize,
+    passphrase: *const u8,
+    passphrase_len: usize,
+    param_out: *mut ParameterSetId,
+    signing_key_out: *mut u8,
+    signing_key_capacity: usize,
+    signing_key_len: *mut usize,
+) -> SlhDsaStatus {
+    let result = read_slice(encoded, encoded_len).and_then(|encoded_bytes| {
+        let passphrase = read_slice(passphrase, passphrase_len)?;
+        let sk = DynSigningKey::from_encrypted_pkcs8(encoded_bytes, passphrase)?;
+        write_signing_key(
+            &sk,
+            param_out,
+            signing_key_out,
+            signing_key_capacity,
+            signing_key_len,
+        )
+    });
+    match result {
+        Ok(()) => SlhDsaStatus::Ok,
+        Err(status) => status,
+    }
+}
+
+/// Sign `payload` into a self-describing envelope. An empty `key_id` is left out;
+/// `detached` leaves the payload out.
+///
+/// `out_len` receives the encoded size even when `out_capacity` is too small.
+#[no_mangle]
+pub extern "C" fn slh_dsa_envelope_sign(
+    param: ParameterSetId,
+    signing_key: *const u8,
+    signing_key_len: usize,
+    payload: *const u8,
+    payload_len: usize,
+    ctx: *const u8,
+    ctx_len: usize,
+    key_id: *const u8,
+    key_id_len: usize,
+    mode: SlhDsaSignatureMode,
+    detached: bool,
+    out: *mut u8,
+    out_capacity: usize,
+    out_len: *mut usize,
+) -> SlhDsaStatus {
+    if signing_key_len != sk_len(param) {
+        return SlhDsaStatus::InvalidLength;
+    }
+
+    let result = read_slice(signing_key, signing_key_len).and_then(|sk_bytes| {
+        let payload = read_slice(payload, payload_len)?;
+        let ctx = read_slice(ctx, ctx_len)?;
+        let key_id = read_slice(key_id, key_id_len)?;
+        let encoded = envelope_sign(param, sk_bytes, payload, ctx, key_id, mode, detached)?;
+        write_output(&encoded, out, out_capacity, out_len)
+    });
+    match result {
+        Ok(()) => SlhDsaStatus::Ok,
+        Err(status) => status,
+    }
+}
+
+/// Parse an envelope without checking its signature, so the caller can pick the
+/// verifying key. `key_id_len` is set to 0 when the envelope has no key ID.
+#[no_mangle]
+pub extern "C" fn slh_dsa_envelope_decode(
+    encoded: *const u8,
+    encoded_len: usize,
+    param_out: *mut ParameterSetId,
+    mode_out: *mut SlhDsaSignatureMode,
+    key_id_out: *mut u8,
+    key_id_capacity: usize,
+    key_id_len: *mut usize,
+) -> SlhDsaStatus {
+    let result = read_slice(encoded, encoded_len).and_then(|encoded| {
+        let envelope = Envelope::from_bytes(encoded)?;
+        write_parameter_set(envelope.parameter_set(), param_out)?;
+        write_signature_mode(envelope.mode().into(), mode_out)?;
+        write_output(
+            envelope.key_id().unwrap_or_default(),
+            key_id_out,
+            key_id_capacity,
+            key_id_len,
+        )
+    });
+    match result {
+        Ok(()) => SlhDsaStatus::Ok,
+        Err(status) => status,
+    }
+}
+
+/// Verify an envelope with a key of the parameter set it names. Set `detached` and pass
+/// the payload for a detached envelope; otherwise the embedded payload is copied to
+/// `payload_out` once the signature checks out.
+#[no_mangle]
+pub extern "C" fn slh_dsa_envelope_verify(
+    verifying_key: *const u8,
+    verifying_key_len: usize,
+    encoded: *const u8,
+    encoded_len: usize,
+    detached: bool,
+    detached_payload: *const u8,
+    detached_payload_len: usize,
+    payload_out: *mut u8,
+    payload_capacity: usize,
+    payload_len: *mut usize,
+) -> SlhDsaStatus {
+    let result = read_slice(verifying_key, verifying_key_len).and_then(|vk_bytes| {
+        let encoded = read_slice(encoded, encoded_len)?;
+        let detached_payload = match detached {
+            true => Some(read_slice(detached_payload, detached_payload_len)?),
+            false => None,
+        };
+        let envelope = envelope_verify(vk_bytes, encoded, detached_payload)?;
+        write_output(
+            envelope.payload().unwrap_or_default(),
+            payload_out,
+            payload_capacity,
+            payload_len,
+        )
+    });
+    match result {
+        Ok(()) => SlhDsaStatus::Ok,
+        Err(status) => status,
+    }
+}
+
+/// Compute the SHA-256 fingerprint of a verifying key over `source`.
+///
+/// The fingerprint is 32 bytes; `out_len` receives that size even when `out_capacity` is too small.
+#[no_mangle]
+pub extern "C" fn slh_dsa_fingerprint(
+    param: ParameterSetId,
+    verifying_key: *const u8,
+    verifying_key_len: usize,
+    source: SlhDsaFingerprintSource,
+    out: *mut u8,
+    out_capacity: usize,
+    out_len: *mut usize,
+) -> SlhDsaStatus {
+    if verifying_key_len != vk_len(param) {
+        return SlhDsaStatus::InvalidLength;
+    }
+
+    let result = read_slice(verifying_key, verifying_key_len).and_then(|vk_bytes| {
+        let fingerprint = fingerprint(param, vk_bytes, source)?;
+        write_output(fingerprint.as_bytes(), out, out_capacity, out_len)
+    });
+    match result {
+        Ok(()) => SlhDsaStatus::Ok,
+        Err(status) => status,
+    }
+}
+
+/// Render a fingerprint from `slh_dsa_fingerprint` as text. The output is not
+/// NUL-terminated.
+///
+/// `out_len` receives the text length even when `out_capacity` is too small.
+#[no_mangle]
+pub extern "C" fn slh_dsa_fingerprint_to_string(
+    fingerprint: *const u8,
+    fingerprint_len: usize,
+    format: SlhDsaFingerprintFormat,
+    out: *mut u8,
+    out_capacity: usize,
+    out_len: *mut usize,
+) -> SlhDsaStatus {
+    let result = read_slice(fingerprint, fingerprint_len).and_then(|fingerprint| {
+        let text = fingerprint_to_string(fingerprint, format)?;
+        write_output(text.as_bytes(), out, out_capacity, out_len)
+    });
+    match result {
+        Ok(()) => SlhDsaStatus::Ok,
+        Err(status) => status,
+    }
+}
+
+/// Run the known-answer self-test for `param` now. Parameter sets are also
+/// self-tested on first use; after any failure every operation returns
+/// `SelfTestFailed`. Only available when built with the `fips` feature.
+#[cfg(feature = "fips")]
+#[no_mangle]
+pub extern "C" fn slh_dsa_self_test(param: ParameterSetId) -> SlhDsaStatus {
+    match slh_dsa_core::fips::self_test(param) {
+        Ok(()) => SlhDsaStatus::Ok,
+        Err(err) => err.into(),
+    }
+}
+
+/// Run the known-answer self-test for every parameter set. Only available when
+/// built with the `fips` feature.
+#[cfg(feature = "fips")]
+#[no_mangle]
+pub extern "C" fn slh_dsa_self_test_all() -> SlhDsaStatus {
+    match slh_dsa_core::fips::self_test_all() {
+        Ok(()) => SlhDsaStatus::Ok,
+        Err(err) => err.into(),
+    }
+}
+
+/// Whether no self-test has failed. Only available when built with the `fips` feature.
+#[cfg(feature = "fips")]
+#[no_mangle]
+pub extern "C" fn slh_dsa_is_operational() -> bool {
+    slh_dsa_core::fips::is_operational()
+}
+
+/// Verify every signature before returning it, in all signing functions. A
+/// signature that does not verify is discarded and `FaultDetected` returned.
+#[no_mangle]
+pub extern "C" fn slh_dsa_set_verify_after_sign(enabled: bool) {
+    slh_dsa_core::set_verify_after_sign(enabled);
+}
+
+#[no_mangle]
+pub extern "C" fn slh_dsa_verify_after_sign_enabled() -> bool {
+    slh_dsa_core::verify_after_sign_enabled()
+}
+
 #[no_mangle]
 pub extern "C" fn slh_dsa_status_string(status: SlhDsaStatus) -> *const c_char {
     match status {
-        SlhDsaStatus::Ok => b"OK\0".as_ptr() as *const c_char,
-        SlhDsaStatus::NullPtr => b"Null pointer\0".as_ptr() as *const c_char,
-        SlhDsaStatus::InvalidParam => b"Invalid parameter\0".as_ptr() as *const c_char,
-        SlhDsaStatus::InvalidLength => b"Invalid length\0".as_ptr() as *const c_char,
-        SlhDsaStatus::DecodeError => b"Decode error\0".as_ptr() as *const c_char,
-        SlhDsaStatus::VerifyFailed => b"Verification failed\0".as_ptr() as *const c_char,
+        SlhDsaStatus::Ok => c"OK".as_ptr(),
+        SlhDsaStatus::NullPtr => c"Null pointer".as_ptr(),
+        SlhDsaStatus::InvalidParam => c"Invalid parameter".as_ptr(),
+        SlhDsaStatus::InvalidLength => c"Invalid length".as_ptr(),
+        SlhDsaStatus::DecodeError => c"Decode error".as_ptr(),
+        SlhDsaStatus::VerifyFailed => c"Verification failed".as_ptr(),
+        SlhDsaStatus::ContextTooLong => c"Context too long".as_ptr(),
+        SlhDsaStatus::MalformedSigningKey => c"Malformed signing key".as_ptr(),
+        SlhDsaStatus::MalformedVerifyingKey => c"Malformed verifying key".as_ptr(),
+        SlhDsaStatus::MalformedSignature => c"Malformed signature".as_ptr(),
+        SlhDsaStatus::ParameterSetMismatch => c"Parameter set mismatch".as_ptr(),
+        SlhDsaStatus::RngFailure => c"RNG failure".as_ptr(),
+        SlhDsaStatus::UnsupportedAlgorithm => c"Unsupported algorithm".as_ptr(),
+        SlhDsaStatus::MalformedEncoding => c"Malformed encoding".as_ptr(),
+        SlhDsaStatus::InvalidCertificate => c"Invalid certificate".as_ptr(),
+        SlhDsaStatus::CertificateExpired => c"Certificate expired".as_ptr(),
+        SlhDsaStatus::UntrustedChain => c"Untrusted chain".as_ptr(),
+        SlhDsaStatus::TokenExpired => c"Token expired".as_ptr(),
+        SlhDsaStatus::AudienceMismatch => c"Audience mismatch".as_ptr(),
+        SlhDsaStatus::SelfTestFailed => c"Self-test failed".as_ptr(),
+        SlhDsaStatus::FaultDetected => c"Fault detected".as_ptr(),
+        SlhDsaStatus::DecryptionFailed => c"Decryption failed".as_ptr(),
     }
 }
 
@@ -503,6 +1696,7 @@ pub extern "C" fn slh_dsa_signature_verify_result_to_bool(status: SlhDsaStatus)
 }
 
 #[no_mangle]
+#[allow(clippy::not_unsafe_ptr_arg_deref)]
 pub extern "C" fn slh_dsa_zeroize(ptr: *mut u8, len: usize) {
     if ptr.is_null() || len == 0 {
         return;
@@ -512,3 +1706,72 @@ pub extern "C" fn slh_dsa_zeroize(ptr: *mut u8, len: usize) {
         ptr::write_bytes(ptr, 0, len);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PARAM: ParameterSetId = ParameterSetId::Shake128f;
+
+    /// A signing key and a copy with one byte of the embedded PK.root flipped.
+    fn keys() -> (Vec<u8>, Vec<u8>) {
+        let sk = DynSigningKey::from_seed(PARAM, &[1; 16], &[2; 16], &[3; 16])
+            .unwrap()
+            .to_vec();
+        let mut tampered = sk.clone();
+        *tampered.last_mut().unwrap() ^= 1;
+        (sk, tampered)
+    }
+
+    fn derive(sk: &[u8], validated: bool) -> (SlhDsaStatus, Vec<u8>) {
+        let mut vk = vec![0u8; vk_len(PARAM)];
+        let derive = if validated {
+            slh_dsa_verifying_key_from_signing_key_validated
+        } else {
+            slh_dsa_verifying_key_from_signing_key
+        };
+        let status = derive(PARAM, sk.as_ptr(), sk.len(), vk.as_mut_ptr(), vk.len());
+        (status, vk)
+    }
+
+    #[test]
+    fn validate_signing_key_reports_a_wrong_pk_root() {
+        let (sk, tampered) = keys();
+        assert_eq!(
+            slh_dsa_validate_signing_key(PARAM, sk.as_ptr(), sk.len()),
+            SlhDsaStatus::Ok
+        );
+        assert_eq!(
+            slh_dsa_validate_signing_key(PARAM, tampered.as_ptr(), tampered.len()),
+            SlhDsaStatus::MalformedSigningKey
+        );
+    }
+
+    #[test]
+    fn validated_derivation_rejects_a_wrong_pk_root() {
+        let (sk, tampered) = keys();
+        let (status, vk) = derive(&sk, true);
+        assert_eq!(status, SlhDsaStatus::Ok);
+        assert_eq!(vk, sk[sk.len() - vk.len()..]);
+
+        assert_eq!(derive(&tampered, false).0, SlhDsaStatus::Ok);
+        assert_eq!(derive(&tampered, true).0, SlhDsaStatus::MalformedSigningKey);
+    }
+
+    #[test]
+    fn parameter_set_info_oids_match_the_core_table() {
+        for param in ParameterSetId::ALL {
+            let info = parameter_set_info(param);
+            // SAFETY: `oid` points to a static NUL-terminated string.
+            let oid = unsafe { CStr::from_ptr(info.oid) };
+            assert_eq!(oid.to_str(), Ok(param.info().oid));
+
+            let mut parsed = ParameterSetId::Shake128s;
+            assert_eq!(
+                slh_dsa_parameter_set_from_oid(info.oid, &mut parsed),
+                SlhDsaStatus::Ok
+            );
+            assert_eq!(parsed, param);
+        }
+    }
+}
@@ -13,15 +13,61 @@ export enum ParameterSetId {
   Sha2_256f = "Sha2_256f",
 }
 
+export enum HashFamily {
+  Sha2 = "Sha2",
+  Shake = "Shake",
+}
+
+export enum SignatureMode {
+  Pure = "Pure",
+  PreHashSha256 = "PreHashSha256",
+  PreHashSha512 = "PreHashSha512",
+  PreHashShake128 = "PreHashShake128",
+  PreHashShake256 = "PreHashShake256",
+}
+
+export enum FingerprintSource {
+  Raw = "Raw",
+  Spki = "Spki",
+}
+
+/** Passphrase KDF for key files, at the library's default costs. */
+export enum Kdf {
+  Argon2id = "Argon2id",
+  Scrypt = "Scrypt",
+}
+
+export enum Aead {
+  ChaCha20Poly1305 = "ChaCha20Poly1305",
+  Aes256Gcm = "Aes256Gcm",
+}
+
 export enum SlhDsaStatus {
   Ok = "Ok",
   InvalidParam = "InvalidParam",
   InvalidLength = "InvalidLength",
   DecodeError = "DecodeError",
   VerifyFailed = "VerifyFailed",
+  ContextTooLong = "ContextTooLong",
+  MalformedSigningKey = "MalformedSigningKey",
+  MalformedVerifyingKey = "MalformedVerifyingKey",
+  MalformedSignature = "MalformedSignature",
+  ParameterSetMismatch = "ParameterSetMismatch",
+  RngFailure = "RngFailure",
+  UnsupportedAlgorithm = "UnsupportedAlgorithm",
+  MalformedEncoding = "MalformedEncoding",
+  InvalidCertificate = "InvalidCertificate",
+  CertificateExpired = "CertificateExpired",
+  UntrustedChain = "UntrustedChain",
+  TokenExpired = "TokenExpired",
+  AudienceMismatch = "AudienceMismatch",
+  SelfTestFailed = "SelfTestFailed",
+  FaultDetected = "FaultDetected",
+  DecryptionFailed = "DecryptionFailed",
 }
 
 export type Keypair = {
+  status: SlhDsaStatus;
   signingKey: Buffer;
   verifyingKey: Buffer;
 };
@@ -31,27 +77,128 @@ export type SignResult = {
   signature: Buffer;
 };
 
+export type SignBatchResult = {
+  status: SlhDsaStatus;
+  signatures: Buffer[];
+};
+
 export type VerifyResult = {
   status: SlhDsaStatus;
   valid: boolean;
 };
 
+export type VerifyBatchItem = {
+  param: ParameterSetId;
+  verifyingKey: Buffer;
+  msg: Buffer;
+  ctx: Buffer;
+  signature: Buffer;
+};
+
 export type KeyResult = {
   status: SlhDsaStatus;
   verifyingKey: Buffer;
 };
 
+export type EncodeResult = {
+  status: SlhDsaStatus;
+  encoded: Buffer;
+};
+
+export type DecodeKeyResult = {
+  status: SlhDsaStatus;
+  param?: ParameterSetId;
+  key: Buffer;
+};
+
+export type JoseResult = {
+  status: SlhDsaStatus;
+  encoded: string;
+};
+
+export type JwsInfo = {
+  status: SlhDsaStatus;
+  valid: boolean;
+  param?: ParameterSetId;
+  keyId?: string;
+  payload: Buffer;
+};
+
+export type JwtInfo = {
+  status: SlhDsaStatus;
+  valid: boolean;
+  claims: string;
+};
+
+export type EnvelopeInfo = {
+  status: SlhDsaStatus;
+  valid: boolean;
+  param?: ParameterSetId;
+  mode?: SignatureMode;
+  keyId?: Buffer;
+  context: Buffer;
+  payload?: Buffer;
+};
+
+export type FingerprintResult = {
+  status: SlhDsaStatus;
+  fingerprint: Buffer;
+  hex: string;
+  base32: string;
+  words: string;
+};
+
+export type ParameterSetInfo = {
+  param: ParameterSetId;
+  name: string;
+  hashFamily: HashFamily;
+  fast: boolean;
+  n: number;
+  h: number;
+  d: number;
+  a: number;
+  k: number;
+  w: number;
+  securityCategory: number;
+  oid: string;
+  signingKeyLen: number;
+  verifyingKeyLen: number;
+  signatureLen: number;
+};
+
 export function slhDsaParameterName(param: ParameterSetId): string;
+export function slhDsaParameterSets(): ParameterSetInfo[];
+export function slhDsaParameterSetFromName(name: string): ParameterSetId | null;
+export function slhDsaParameterSetFromOid(oid: string): ParameterSetId | null;
 export function slhDsaSigningKeyLen(param: ParameterSetId): number;
 export function slhDsaVerifyingKeyLen(param: ParameterSetId): number;
 export function slhDsaSignatureLen(param: ParameterSetId): number;
+export function slhDsaSeedLen(param: ParameterSetId): number;
 export function slhDsaKeypairGenerate(param: ParameterSetId): Keypair;
+export function slhDsaKeypairFromSeed(
+  param: ParameterSetId,
+  skSeed: Buffer,
+  skPrf: Buffer,
+  pkSeed: Buffer
+): Keypair;
 export function slhDsaSign(
   param: ParameterSetId,
   signingKey: Buffer,
   msg: Buffer,
   ctx: Buffer
 ): SignResult;
+export function slhDsaSignChecked(
+  param: ParameterSetId,
+  signingKey: Buffer,
+  msg: Buffer,
+  ctx: Buffer
+): SignResult;
+export function slhDsaSignBatch(
+  param: ParameterSetId,
+  signingKey: Buffer,
+  msgs: Buffer[],
+  ctx: Buffer
+): SignBatchResult;
 export function slhDsaSignDeterministic(
   param: ParameterSetId,
   signingKey: Buffer,
@@ -62,6 +209,14 @@ export function slhDsaVerifyingKeyFromSigningKey(
   param: ParameterSetId,
   signingKey: Buffer
 ): KeyResult;
+export function slhDsaVerifyingKeyFromSigningKeyValidated(
+  param: ParameterSetId,
+  signingKey: Buffer
+): KeyResult;
+export function slhDsaValidateSigningKey(
+  param: ParameterSetId,
+  signingKey: Buffer
+): SlhDsaStatus;
 export function slhDsaVerify(
   param: ParameterSetId,
   verifyingKey: Buffer,
@@ -69,3 +224,96 @@ export function slhDsaVerify(
   ctx: Buffer,
   signature: Buffer
 ): VerifyResult;
+export function slhDsaVerifyBatch(items: VerifyBatchItem[]): VerifyResult[];
+export function slhDsaSigningKeyToPkcs8(
+  param: ParameterSetId,
+  signingKey: Buffer,
+  pem: boolean
+): EncodeResult;
+export function slhDsaSigningKeyFromPkcs8(encoded: Buffer): DecodeKeyResult;
+export function slhDsaVerifyingKeyToSpki(
+  param: ParameterSetId,
+  verifyingKey: Buffer,
+  pem: boolean
+): EncodeResult;
+export function slhDsaVerifyingKeyFromSpki(encoded: Buffer): DecodeKeyResult;
+export function slhDsaKeyFileLen(param: ParameterSetId): number;
+export function slhDsaSigningKeyToKeyFile(
+  param: ParameterSetId,
+  signingKey: Buffer,
+  passphrase: Buffer,
+  kdf: Kdf,
+  aead: Aead
+): EncodeResult;
+export function slhDsaSigningKeyFromKeyFile(
+  encoded: Buffer,
+  passphrase: Buffer
+): DecodeKeyResult;
+export function slhDsaSigningKeyToEncryptedPkcs8(
+  param: ParameterSetId,
+  signingKey: Buffer,
+  passphrase: Buffer,
+  pem: boolean
+): EncodeResult;
+export function slhDsaSigningKeyFromEncryptedPkcs8(
+  encoded: Buffer,
+  passphrase: Buffer
+): DecodeKeyResult;
+export function slhDsaSigningKeyToJwk(
+  param: ParameterSetId,
+  signingKey: Buffer
+): JoseResult;
+export function slhDsaSigningKeyFromJwk(jwk: string): DecodeKeyResult;
+export function slhDsaVerifyingKeyToJwk(
+  param: ParameterSetId,
+  verifyingKey: Buffer
+): JoseResult;
+export function slhDsaVerifyingKeyFromJwk(jwk: string): DecodeKeyResult;
+export function slhDsaJwsSign(
+  param: ParameterSetId,
+  signingKey: Buffer,
+  payload: Buffer,
+  keyId?: string
+): JoseResult;
+export function slhDsaJwsVerify(verifyingKey: Buffer, jws: string): JwsInfo;
+export function slhDsaJwtIssue(
+  param: ParameterSetId,
+  signingKey: Buffer,
+  claims: string,
+  keyId?: string
+): JoseResult;
+export function slhDsaJwtValidate(
+  verifyingKey: Buffer,
+  token: string,
+  now: number,
+  audience: string | undefined,
+  leeway: number
+): JwtInfo;
+export function slhDsaEnvelopeSign(
+  param: ParameterSetId,
+  signingKey: Buffer,
+  payload: Buffer,
+  ctx: Buffer,
+  keyId: Buffer | undefined,
+  mode: SignatureMode,
+  detached: boolean
+): EncodeResult;
+export function slhDsaEnvelopeDecode(encoded: Buffer): EnvelopeInfo;
+export function slhDsaEnvelopeVerify(
+  verifyingKey: Buffer,
+  encoded: Buffer,
+  detachedPayload?: Buffer
+): EnvelopeInfo;
+export function slhDsaFingerprint(
+  param: ParameterSetId,
+  verifyingKey: Buffer,
+  source: FingerprintSource
+): FingerprintResult;
+export function slhDsaSetVerifyAfterSign(enabled: boolean): void;
+export function slhDsaVerifyAfterSignEnabled(): boolean;
+/** Only present when the addon is built with the `fips` feature. */
+export function slhDsaSelfTest(param: ParameterSetId): SlhDsaStatus;
+/** Only present when the addon is built with the `fips` feature. */
+export function slhDsaSelfTestAll(): SlhDsaStatus;
+/** Only present when the addon is built with the `fips` feature. */
+export function slhDsaIsOperational(): boolean;
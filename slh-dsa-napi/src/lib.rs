@@ -1,10 +1,15 @@
+use core::time::Duration;
 use getrandom::fill;
 use napi::bindgen_prelude::Buffer;
 use napi_derive::napi;
-use rand_core::{CryptoRng, RngCore};
+use rand_core::{TryCryptoRng, TryRng};
+use slh_dsa_core::envelope::{Envelope, EnvelopeBuilder};
+use slh_dsa_core::jose::{issue_jwt, validate_jwt, Jws, JwsBuilder, JwtValidation};
+use slh_dsa_core::keyfile::{self, KeyFileOptions};
+use slh_dsa_core::pkcs8::{EncodePrivateKey, EncodePublicKey, LineEnding};
 use slh_dsa_core::{
-    ParameterSet, Sha2_128f, Sha2_128s, Sha2_192f, Sha2_192s, Sha2_256f, Sha2_256s, Shake128f,
-    Shake128s, Shake192f, Shake192s, Shake256f, Shake256s,
+    sign_batch, verify_batch, BatchVerifyItem, DynSignature, DynSigningKey, DynVerifyingKey,
+    PreHashAlgorithm, SlhDsaError,
 };
 
 #[napi]
@@ -23,6 +28,39 @@ pub enum ParameterSetId {
     Sha2_256f,
 }
 
+#[napi]
+pub enum HashFamily {
+    Sha2,
+    Shake,
+}
+
+#[napi]
+pub enum SignatureMode {
+    Pure,
+    PreHashSha256,
+    PreHashSha512,
+    PreHashShake128,
+    PreHashShake256,
+}
+
+#[napi]
+pub enum FingerprintSource {
+    Raw,
+    Spki,
+}
+
+#[napi]
+pub enum Kdf {
+    Argon2id,
+    Scrypt,
+}
+
+#[napi]
+pub enum Aead {
+    ChaCha20Poly1305,
+    Aes256Gcm,
+}
+
 #[napi]
 pub enum SlhDsaStatus {
     Ok,
@@ -30,10 +68,52 @@ pub enum SlhDsaStatus {
     InvalidLength,
     DecodeError,
     VerifyFailed,
+    ContextTooLong,
+    MalformedSigningKey,
+    MalformedVerifyingKey,
+    MalformedSignature,
+    ParameterSetMismatch,
+    RngFailure,
+    UnsupportedAlgorithm,
+    MalformedEncoding,
+    InvalidCertificate,
+    CertificateExpired,
+    UntrustedChain,
+    TokenExpired,
+    AudienceMismatch,
+    SelfTestFailed,
+    FaultDetected,
+    DecryptionFailed,
+}
+
+impl From<SlhDsaError> for SlhDsaStatus {
+    fn from(err: SlhDsaError) -> Self {
+        match err {
+            SlhDsaError::ContextTooLong => SlhDsaStatus::ContextTooLong,
+            SlhDsaError::MalformedSigningKey => SlhDsaStatus::MalformedSigningKey,
+            SlhDsaError::MalformedVerifyingKey => SlhDsaStatus::MalformedVerifyingKey,
+            SlhDsaError::MalformedSignature => SlhDsaStatus::MalformedSignature,
+            SlhDsaError::InvalidLength => SlhDsaStatus::InvalidLength,
+            SlhDsaError::ParameterSetMismatch => SlhDsaStatus::ParameterSetMismatch,
+            SlhDsaError::RngFailure => SlhDsaStatus::RngFailure,
+            SlhDsaError::VerificationFailed => SlhDsaStatus::VerifyFailed,
+            SlhDsaError::UnsupportedAlgorithm => SlhDsaStatus::UnsupportedAlgorithm,
+            SlhDsaError::MalformedEncoding => SlhDsaStatus::MalformedEncoding,
+            SlhDsaError::InvalidCertificate => SlhDsaStatus::InvalidCertificate,
+            SlhDsaError::CertificateExpired => SlhDsaStatus::CertificateExpired,
+            SlhDsaError::UntrustedChain => SlhDsaStatus::UntrustedChain,
+            SlhDsaError::TokenExpired => SlhDsaStatus::TokenExpired,
+            SlhDsaError::AudienceMismatch => SlhDsaStatus::AudienceMismatch,
+            SlhDsaError::SelfTestFailed => SlhDsaStatus::SelfTestFailed,
+            SlhDsaError::FaultDetected => SlhDsaStatus::FaultDetected,
+            SlhDsaError::DecryptionFailed => SlhDsaStatus::DecryptionFailed,
+        }
+    }
 }
 
 #[napi(object)]
 pub struct Keypair {
+    pub status: SlhDsaStatus,
     pub signing_key: Buffer,
     pub verifying_key: Buffer,
 }
@@ -44,109 +124,244 @@ pub struct SignResult {
     pub signature: Buffer,
 }
 
+#[napi(object)]
+pub struct SignBatchResult {
+    pub status: SlhDsaStatus,
+    pub signatures: Vec<Buffer>,
+}
+
 #[napi(object)]
 pub struct VerifyResult {
     pub status: SlhDsaStatus,
     pub valid: bool,
 }
 
+#[napi(object)]
+pub struct VerifyBatchItem {
+    pub param: ParameterSetId,
+    pub verifying_key: Buffer,
+    pub msg: Buffer,
+    pub ctx: Buffer,
+    pub signature: Buffer,
+}
+
 #[napi(object)]
 pub struct KeyResult {
     pub status: SlhDsaStatus,
     pub verifying_key: Buffer,
 }
 
+#[napi(object)]
+pub struct EncodeResult {
+    pub status: SlhDsaStatus,
+    pub encoded: Buffer,
+}
+
+#[napi(object)]
+pub struct DecodeKeyResult {
+    pub status: SlhDsaStatus,
+    pub param: Option<ParameterSetId>,
+    pub key: Buffer,
+}
+
+#[napi(object)]
+pub struct JoseResult {
+    pub status: SlhDsaStatus,
+    pub encoded: String,
+}
+
+#[napi(object)]
+pub struct JwsInfo {
+    pub status: SlhDsaStatus,
+    pub valid: bool,
+    pub param: Option<ParameterSetId>,
+    pub key_id: Option<String>,
+    pub payload: Buffer,
+}
+
+#[napi(object)]
+pub struct JwtInfo {
+    pub status: SlhDsaStatus,
+    pub valid: bool,
+    pub claims: String,
+}
+
+#[napi(object)]
+pub struct EnvelopeInfo {
+    pub status: SlhDsaStatus,
+    pub valid: bool,
+    pub param: Option<ParameterSetId>,
+    pub mode: Option<SignatureMode>,
+    pub key_id: Option<Buffer>,
+    pub context: Buffer,
+    pub payload: Option<Buffer>,
+}
+
+#[napi(object)]
+pub struct FingerprintResult {
+    pub status: SlhDsaStatus,
+    pub fingerprint: Buffer,
+    pub hex: String,
+    pub base32: String,
+    pub words: String,
+}
+
+#[napi(object)]
+pub struct ParameterSetInfo {
+    pub param: ParameterSetId,
+    pub name: String,
+    pub hash_family: HashFamily,
+    pub fast: bool,
+    pub n: u32,
+    pub h: u32,
+    pub d: u32,
+    pub a: u32,
+    pub k: u32,
+    pub w: u32,
+    pub security_category: u32,
+    pub oid: String,
+    pub signing_key_len: u32,
+    pub verifying_key_len: u32,
+    pub signature_len: u32,
+}
+
 struct OsRng;
 
-impl RngCore for OsRng {
-    fn next_u32(&mut self) -> u32 {
+impl TryRng for OsRng {
+    type Error = getrandom::Error;
+
+    fn try_next_u32(&mut self) -> Result<u32, Self::Error> {
         let mut bytes = [0u8; 4];
-        fill(&mut bytes).expect("OsRng failure");
-        u32::from_le_bytes(bytes)
+        fill(&mut bytes)?;
+        Ok(u32::from_le_bytes(bytes))
     }
 
-    fn next_u64(&mut self) -> u64 {
+    fn try_next_u64(&mut self) -> Result<u64, Self::Error> {
         let mut bytes = [0u8; 8];
-        fill(&mut bytes).expect("OsRng failure");
-        u64::from_le_bytes(bytes)
+        fill(&mut bytes)?;
+        Ok(u64::from_le_bytes(bytes))
     }
 
-    fn fill_bytes(&mut self, dst: &mut [u8]) {
-        fill(dst).expect("OsRng failure");
+    fn try_fill_bytes(&mut self, dst: &mut [u8]) -> Result<(), Self::Error> {
+        fill(dst)
     }
 }
 
-impl CryptoRng for OsRng {}
+impl TryCryptoRng for OsRng {}
 
-fn param_name(param: ParameterSetId) -> &'static str {
-    match param {
-        ParameterSetId::Shake128s => slh_dsa_core::ParameterSetId::Shake128s.name(),
-        ParameterSetId::Shake128f => slh_dsa_core::ParameterSetId::Shake128f.name(),
-        ParameterSetId::Shake192s => slh_dsa_core::ParameterSetId::Shake192s.name(),
-        ParameterSetId::Shake192f => slh_dsa_core::ParameterSetId::Shake192f.name(),
-        ParameterSetId::Shake256s => slh_dsa_core::ParameterSetId::Shake256s.name(),
-        ParameterSetId::Shake256f => slh_dsa_core::ParameterSetId::Shake256f.name(),
-        ParameterSetId::Sha2_128s => slh_dsa_core::ParameterSetId::Sha2_128s.name(),
-        ParameterSetId::Sha2_128f => slh_dsa_core::ParameterSetId::Sha2_128f.name(),
-        ParameterSetId::Sha2_192s => slh_dsa_core::ParameterSetId::Sha2_192s.name(),
-        ParameterSetId::Sha2_192f => slh_dsa_core::ParameterSetId::Sha2_192f.name(),
-        ParameterSetId::Sha2_256s => slh_dsa_core::ParameterSetId::Sha2_256s.name(),
-        ParameterSetId::Sha2_256f => slh_dsa_core::ParameterSetId::Sha2_256f.name(),
+impl From<ParameterSetId> for slh_dsa_core::ParameterSetId {
+    fn from(param: ParameterSetId) -> Self {
+        match param {
+            ParameterSetId::Shake128s => Self::Shake128s,
+            ParameterSetId::Shake128f => Self::Shake128f,
+            ParameterSetId::Shake192s => Self::Shake192s,
+            ParameterSetId::Shake192f => Self::Shake192f,
+            ParameterSetId::Shake256s => Self::Shake256s,
+            ParameterSetId::Shake256f => Self::Shake256f,
+            ParameterSetId::Sha2_128s => Self::Sha2_128s,
+            ParameterSetId::Sha2_128f => Self::Sha2_128f,
+            ParameterSetId::Sha2_192s => Self::Sha2_192s,
+            ParameterSetId::Sha2_192f => Self::Sha2_192f,
+            ParameterSetId::Sha2_256s => Self::Sha2_256s,
+            ParameterSetId::Sha2_256f => Self::Sha2_256f,
+        }
+    }
+}
+
+impl From<slh_dsa_core::ParameterSetId> for ParameterSetId {
+    fn from(param: slh_dsa_core::ParameterSetId) -> Self {
+        use slh_dsa_core::ParameterSetId as Core;
+        match param {
+            Core::Shake128s => Self::Shake128s,
+            Core::Shake128f => Self::Shake128f,
+            Core::Shake192s => Self::Shake192s,
+            Core::Shake192f => Self::Shake192f,
+            Core::Shake256s => Self::Shake256s,
+            Core::Shake256f => Self::Shake256f,
+            Core::Sha2_128s => Self::Sha2_128s,
+            Core::Sha2_128f => Self::Sha2_128f,
+            Core::Sha2_192s => Self::Sha2_192s,
+            Core::Sha2_192f => Self::Sha2_192f,
+            Core::Sha2_256s => Self::Sha2_256s,
+            Core::Sha2_256f => Self::Sha2_256f,
+        }
+    }
+}
+
+impl From<slh_dsa_core::HashFamily> for HashFamily {
+    fn from(family: slh_dsa_core::HashFamily) -> Self {
+        match family {
+            slh_dsa_core::HashFamily::Sha2 => Self::Sha2,
+            slh_dsa_core::HashFamily::Shake => Self::Shake,
+        }
+    }
+}
+
+impl From<SignatureMode> for slh_dsa_core::envelope::SignatureMode {
+    fn from(mode: SignatureMode) -> Self {
+        match mode {
+            SignatureMode::Pure => Self::Pure,
+            SignatureMode::PreHashSha256 => Self::PreHash(PreHashAlgorithm::Sha256),
+            SignatureMode::PreHashSha512 => Self::PreHash(PreHashAlgorithm::Sha512),
+            SignatureMode::PreHashShake128 => Self::PreHash(PreHashAlgorithm::Shake128),
+            SignatureMode::PreHashShake256 => Self::PreHash(PreHashAlgorithm::Shake256),
+        }
+    }
+}
+
+impl From<slh_dsa_core::envelope::SignatureMode> for SignatureMode {
+    fn from(mode: slh_dsa_core::envelope::SignatureMode) -> Self {
+        use slh_dsa_core::envelope::SignatureMode as Core;
+        match mode {
+            Core::Pure => Self::Pure,
+            Core::PreHash(PreHashAlgorithm::Sha256) => Self::PreHashSha256,
+            Core::PreHash(PreHashAlgorithm::Sha512) => Self::PreHashSha512,
+            Core::PreHash(PreHashAlgorithm::Shake128) => Self::PreHashShake128,
+            Core::PreHash(PreHashAlgorithm::Shake256) => Self::PreHashShake256,
+        }
+    }
+}
+
+impl From<&slh_dsa_core::ParameterSetInfo> for ParameterSetInfo {
+    fn from(info: &slh_dsa_core::ParameterSetInfo) -> Self {
+        Self {
+            param: info.id.into(),
+            name: info.name.to_string(),
+            hash_family: info.hash_family.into(),
+            fast: info.fast,
+            n: info.n as u32,
+            h: info.h as u32,
+            d: info.d as u32,
+            a: info.a as u32,
+            k: info.k as u32,
+            w: info.w as u32,
+            security_category: info.security_category.into(),
+            oid: info.oid.to_string(),
+            signing_key_len: info.signing_key_len as u32,
+            verifying_key_len: info.verifying_key_len as u32,
+            signature_len: info.signature_len as u32,
+        }
     }
 }
 
+fn param_name(param: ParameterSetId) -> &'static str {
+    slh_dsa_core::ParameterSetId::from(param).name()
+}
+
 fn sk_len(param: ParameterSetId) -> usize {
-    let core_id = match param {
-        ParameterSetId::Shake128s => slh_dsa_core::ParameterSetId::Shake128s,
-        ParameterSetId::Shake128f => slh_dsa_core::ParameterSetId::Shake128f,
-        ParameterSetId::Shake192s => slh_dsa_core::ParameterSetId::Shake192s,
-        ParameterSetId::Shake192f => slh_dsa_core::ParameterSetId::Shake192f,
-        ParameterSetId::Shake256s => slh_dsa_core::ParameterSetId::Shake256s,
-        ParameterSetId::Shake256f => slh_dsa_core::ParameterSetId::Shake256f,
-        ParameterSetId::Sha2_128s => slh_dsa_core::ParameterSetId::Sha2_128s,
-        ParameterSetId::Sha2_128f => slh_dsa_core::ParameterSetId::Sha2_128f,
-        ParameterSetId::Sha2_192s => slh_dsa_core::ParameterSetId::Sha2_192s,
-        ParameterSetId::Sha2_192f => slh_dsa_core::ParameterSetId::Sha2_192f,
-        ParameterSetId::Sha2_256s => slh_dsa_core::ParameterSetId::Sha2_256s,
-        ParameterSetId::Sha2_256f => slh_dsa_core::ParameterSetId::Sha2_256f,
-    };
-    slh_dsa_core::sk_len_for(core_id).unwrap_or(0)
+    slh_dsa_core::sk_len_for(param.into())
 }
 
 fn vk_len(param: ParameterSetId) -> usize {
-    let core_id = match param {
-        ParameterSetId::Shake128s => slh_dsa_core::ParameterSetId::Shake128s,
-        ParameterSetId::Shake128f => slh_dsa_core::ParameterSetId::Shake128f,
-        ParameterSetId::Shake192s => slh_dsa_core::ParameterSetId::Shake192s,
-        ParameterSetId::Shake192f => slh_dsa_core::ParameterSetId::Shake192f,
-        ParameterSetId::Shake256s => slh_dsa_core::ParameterSetId::Shake256s,
-        ParameterSetId::Shake256f => slh_dsa_core::ParameterSetId::Shake256f,
-        ParameterSetId::Sha2_128s => slh_dsa_core::ParameterSetId::Sha2_128s,
-        ParameterSetId::Sha2_128f => slh_dsa_core::ParameterSetId::Sha2_128f,
-        ParameterSetId::Sha2_192s => slh_dsa_core::ParameterSetId::Sha2_192s,
-        ParameterSetId::Sha2_192f => slh_dsa_core::ParameterSetId::Sha2_192f,
-        ParameterSetId::Sha2_256s => slh_dsa_core::ParameterSetId::Sha2_256s,
-        ParameterSetId::Sha2_256f => slh_dsa_core::ParameterSetId::Sha2_256f,
-    };
-    slh_dsa_core::vk_len_for(core_id).unwrap_or(0)
+    slh_dsa_core::vk_len_for(param.into())
 }
 
 fn sig_len(param: ParameterSetId) -> usize {
-    let core_id = match param {
-        ParameterSetId::Shake128s => slh_dsa_core::ParameterSetId::Shake128s,
-        ParameterSetId::Shake128f => slh_dsa_core::ParameterSetId::Shake128f,
-        ParameterSetId::Shake192s => slh_dsa_core::ParameterSetId::Shake192s,
-        ParameterSetId::Shake192f => slh_dsa_core::ParameterSetId::Shake192f,
-        ParameterSetId::Shake256s => slh_dsa_core::ParameterSetId::Shake256s,
-        ParameterSetId::Shake256f => slh_dsa_core::ParameterSetId::Shake256f,
-        ParameterSetId::Sha2_128s => slh_dsa_core::ParameterSetId::Sha2_128s,
-        ParameterSetId::Sha2_128f => slh_dsa_core::ParameterSetId::Sha2_128f,
-        ParameterSetId::Sha2_192s => slh_dsa_core::ParameterSetId::Sha2_192s,
-        ParameterSetId::Sha2_192f => slh_dsa_core::ParameterSetId::Sha2_192f,
-        ParameterSetId::Sha2_256s => slh_dsa_core::ParameterSetId::Sha2_256s,
-        ParameterSetId::Sha2_256f => slh_dsa_core::ParameterSetId::Sha2_256f,
-    };
-    slh_dsa_core::sig_len_for(core_id).unwrap_or(0)
+    slh_dsa_core::sig_len_for(param.into())
+}
+
+fn seed_len(param: ParameterSetId) -> usize {
+    slh_dsa_core::seed_len_for(param.into())
 }
 
 #[napi]
@@ -154,6 +369,24 @@ pub fn slh_dsa_parameter_name(param: ParameterSetId) -> String {
     param_name(param).to_string()
 }
 
+#[napi]
+pub fn slh_dsa_parameter_sets() -> Vec<ParameterSetInfo> {
+    slh_dsa_core::PARAMETER_SETS
+        .iter()
+        .map(ParameterSetInfo::from)
+        .collect()
+}
+
+#[napi]
+pub fn slh_dsa_parameter_set_from_name(name: String) -> Option<ParameterSetId> {
+    slh_dsa_core::ParameterSetId::from_name(&name).map(Into::into)
+}
+
+#[napi]
+pub fn slh_dsa_parameter_set_from_oid(oid: String) -> Option<ParameterSetId> {
+    slh_dsa_core::ParameterSetId::from_oid_str(&oid).map(Into::into)
+}
+
 #[napi]
 pub fn slh_dsa_signing_key_len(param: ParameterSetId) -> u64 {
     sk_len(param) as u64
@@ -169,110 +402,99 @@ pub fn slh_dsa_signature_len(param: ParameterSetId) -> u64 {
     sig_len(param) as u64
 }
 
+#[napi]
+pub fn slh_dsa_seed_len(param: ParameterSetId) -> u64 {
+    seed_len(param) as u64
+}
+
 #[napi]
 pub fn slh_dsa_keypair_generate(param: ParameterSetId) -> Keypair {
-    match param {
-        ParameterSetId::Shake128s => keypair_for::<Shake128s>(),
-        ParameterSetId::Shake128f => keypair_for::<Shake128f>(),
-        ParameterSetId::Shake192s => keypair_for::<Shake192s>(),
-        ParameterSetId::Shake192f => keypair_for::<Shake192f>(),
-        ParameterSetId::Shake256s => keypair_for::<Shake256s>(),
-        ParameterSetId::Shake256f => keypair_for::<Shake256f>(),
-        ParameterSetId::Sha2_128s => keypair_for::<Sha2_128s>(),
-        ParameterSetId::Sha2_128f => keypair_for::<Sha2_128f>(),
-        ParameterSetId::Sha2_192s => keypair_for::<Sha2_192s>(),
-        ParameterSetId::Sha2_192f => keypair_for::<Sha2_192f>(),
-        ParameterSetId::Sha2_256s => keypair_for::<Sha2_256s>(),
-        ParameterSetId::Sha2_256f => keypair_for::<Sha2_256f>(),
-    }
-}
-
-fn keypair_for<P: ParameterSet>() -> Keypair {
     let mut rng = OsRng;
-    let (sk, vk) = slh_dsa_core::generate_keypair::<P, _>(&mut rng);
+    let sk = match DynSigningKey::try_generate(param.into(), &mut rng) {
+        Ok(key) => key,
+        Err(err) => {
+            return Keypair {
+                status: err.into(),
+                signing_key: Buffer::from(Vec::new()),
+                verifying_key: Buffer::from(Vec::new()),
+            };
+        }
+    };
     Keypair {
+        status: SlhDsaStatus::Ok,
         signing_key: Buffer::from(sk.to_vec()),
-        verifying_key: Buffer::from(vk.to_vec()),
+        verifying_key: Buffer::from(sk.verifying_key().to_vec()),
     }
 }
 
 #[napi]
-pub fn slh_dsa_sign(
+pub fn slh_dsa_keypair_from_seed(
     param: ParameterSetId,
-    signing_key: Buffer,
-    msg: Buffer,
-    ctx: Buffer,
-) -> SignResult {
-    let expected_sk_len = sk_len(param);
-    match param {
-        ParameterSetId::Shake128s => {
-            sign_for::<Shake128s>(&signing_key, &msg, &ctx, expected_sk_len)
-        }
-        ParameterSetId::Shake128f => {
-            sign_for::<Shake128f>(&signing_key, &msg, &ctx, expected_sk_len)
-        }
-        ParameterSetId::Shake192s => {
-            sign_for::<Shake192s>(&signing_key, &msg, &ctx, expected_sk_len)
-        }
-        ParameterSetId::Shake192f => {
-            sign_for::<Shake192f>(&signing_key, &msg, &ctx, expected_sk_len)
-        }
-        ParameterSetId::Shake256s => {
-            sign_for::<Shake256s>(&signing_key, &msg, &ctx, expected_sk_len)
-        }
-        ParameterSetId::Shake256f => {
-            sign_for::<Shake256f>(&signing_key, &msg, &ctx, expected_sk_len)
-        }
-        ParameterSetId::Sha2_128s => {
-            sign_for::<Sha2_128s>(&signing_key, &msg, &ctx, expected_sk_len)
-        }
-        ParameterSetId::Sha2_128f => {
-            sign_for::<Sha2_128f>(&signing_key, &msg, &ctx, expected_sk_len)
-        }
-        ParameterSetId::Sha2_192s => {
-            sign_for::<Sha2_192s>(&signing_key, &msg, &ctx, expected_sk_len)
-        }
-        ParameterSetId::Sha2_192f => {
-            sign_for::<Sha2_192f>(&signing_key, &msg, &ctx, expected_sk_len)
-        }
-        ParameterSetId::Sha2_256s => {
-            sign_for::<Sha2_256s>(&signing_key, &msg, &ctx, expected_sk_len)
-        }
-        ParameterSetId::Sha2_256f => {
-            sign_for::<Sha2_256f>(&signing_key, &msg, &ctx, expected_sk_len)
+    sk_seed: Buffer,
+    sk_prf: Buffer,
+    pk_seed: Buffer,
+) -> Keypair {
+    let expected = seed_len(param);
+    if sk_seed.len() != expected || sk_prf.len() != expected || pk_seed.len() != expected {
+        return Keypair {
+            status: SlhDsaStatus::InvalidLength,
+            signing_key: Buffer::from(Vec::new()),
+            verifying_key: Buffer::from(Vec::new()),
+        };
+    }
+
+    let sk = match DynSigningKey::from_seed(param.into(), &sk_seed, &sk_prf, &pk_seed) {
+        Ok(key) => key,
+        Err(err) => {
+            return Keypair {
+                status: err.into(),
+                signing_key: Buffer::from(Vec::new()),
+                verifying_key: Buffer::from(Vec::new()),
+            };
         }
+    };
+    Keypair {
+        status: SlhDsaStatus::Ok,
+        signing_key: Buffer::from(sk.to_vec()),
+        verifying_key: Buffer::from(sk.verifying_key().to_vec()),
     }
 }
 
-fn sign_for<P: ParameterSet>(
-    signing_key: &[u8],
-    msg: &[u8],
-    ctx: &[u8],
-    expected_sk_len: usize,
+fn sign(
+    param: ParameterSetId,
+    signing_key: Buffer,
+    msg: Buffer,
+    ctx: Buffer,
+    checked: bool,
 ) -> SignResult {
-    if signing_key.len() != expected_sk_len {
+    if signing_key.len() != sk_len(param) {
         return SignResult {
             status: SlhDsaStatus::InvalidLength,
             signature: Buffer::from(Vec::new()),
         };
     }
 
-    let sk = match slh_dsa_core::SigningKey::<P>::try_from(signing_key) {
+    let sk = match DynSigningKey::from_bytes(param.into(), &signing_key) {
         Ok(key) => key,
-        Err(_) => {
+        Err(err) => {
             return SignResult {
-                status: SlhDsaStatus::DecodeError,
+                status: err.into(),
                 signature: Buffer::from(Vec::new()),
             };
         }
     };
 
     let mut rng = OsRng;
-    let sig = match slh_dsa_core::sign_with_rng::<P, _>(&sk, &mut rng, msg, ctx) {
+    let sig = if checked {
+        sk.sign_checked(&mut rng, &msg, &ctx)
+    } else {
+        sk.try_sign_with_rng(&mut rng, &msg, &ctx)
+    };
+    let sig = match sig {
         Ok(signature) => signature,
-        Err(_) => {
+        Err(err) => {
             return SignResult {
-                status: SlhDsaStatus::DecodeError,
+                status: err.into(),
                 signature: Buffer::from(Vec::new()),
             };
         }
@@ -285,81 +507,92 @@ fn sign_for<P: ParameterSet>(
 }
 
 #[napi]
-pub fn slh_dsa_sign_deterministic(
+pub fn slh_dsa_sign(
     param: ParameterSetId,
     signing_key: Buffer,
     msg: Buffer,
     ctx: Buffer,
 ) -> SignResult {
-    let expected_sk_len = sk_len(param);
-    match param {
-        ParameterSetId::Shake128s => {
-            sign_det_for::<Shake128s>(&signing_key, &msg, &ctx, expected_sk_len)
-        }
-        ParameterSetId::Shake128f => {
-            sign_det_for::<Shake128f>(&signing_key, &msg, &ctx, expected_sk_len)
-        }
-        ParameterSetId::Shake192s => {
-            sign_det_for::<Shake192s>(&signing_key, &msg, &ctx, expected_sk_len)
-        }
-        ParameterSetId::Shake192f => {
-            sign_det_for::<Shake192f>(&signing_key, &msg, &ctx, expected_sk_len)
-        }
-        ParameterSetId::Shake256s => {
-            sign_det_for::<Shake256s>(&signing_key, &msg, &ctx, expected_sk_len)
-        }
-        ParameterSetId::Shake256f => {
-            sign_det_for::<Shake256f>(&signing_key, &msg, &ctx, expected_sk_len)
-        }
-        ParameterSetId::Sha2_128s => {
-            sign_det_for::<Sha2_128s>(&signing_key, &msg, &ctx, expected_sk_len)
-        }
-        ParameterSetId::Sha2_128f => {
-            sign_det_for::<Sha2_128f>(&signing_key, &msg, &ctx, expected_sk_len)
-        }
-        ParameterSetId::Sha2_192s => {
-            sign_det_for::<Sha2_192s>(&signing_key, &msg, &ctx, expected_sk_len)
-        }
-        ParameterSetId::Sha2_192f => {
-            sign_det_for::<Sha2_192f>(&signing_key, &msg, &ctx, expected_sk_len)
-        }
-        ParameterSetId::Sha2_256s => {
-            sign_det_for::<Sha2_256s>(&signing_key, &msg, &ctx, expected_sk_len)
-        }
-        ParameterSetId::Sha2_256f => {
-            sign_det_for::<Sha2_256f>(&signing_key, &msg, &ctx, expected_sk_len)
+    sign(param, signing_key, msg, ctx, false)
+}
+
+#[napi]
+pub fn slh_dsa_sign_checked(
+    param: ParameterSetId,
+    signing_key: Buffer,
+    msg: Buffer,
+    ctx: Buffer,
+) -> SignResult {
+    sign(param, signing_key, msg, ctx, true)
+}
+
+#[napi]
+pub fn slh_dsa_sign_batch(
+    param: ParameterSetId,
+    signing_key: Buffer,
+    msgs: Vec<Buffer>,
+    ctx: Buffer,
+) -> SignBatchResult {
+    if signing_key.len() != sk_len(param) {
+        return SignBatchResult {
+            status: SlhDsaStatus::InvalidLength,
+            signatures: Vec::new(),
+        };
+    }
+
+    let sk = match DynSigningKey::from_bytes(param.into(), &signing_key) {
+        Ok(key) => key,
+        Err(err) => {
+            return SignBatchResult {
+                status: err.into(),
+                signatures: Vec::new(),
+            };
         }
+    };
+
+    let msgs: Vec<&[u8]> = msgs.iter().map(|msg| &msg[..]).collect();
+    let mut rng = OsRng;
+    match sign_batch(&sk, &mut rng, &msgs, &ctx) {
+        Ok(sigs) => SignBatchResult {
+            status: SlhDsaStatus::Ok,
+            signatures: sigs.iter().map(|sig| Buffer::from(sig.to_vec())).collect(),
+        },
+        Err(err) => SignBatchResult {
+            status: err.into(),
+            signatures: Vec::new(),
+        },
     }
 }
 
-fn sign_det_for<P: ParameterSet>(
-    signing_key: &[u8],
-    msg: &[u8],
-    ctx: &[u8],
-    expected_sk_len: usize,
+#[napi]
+pub fn slh_dsa_sign_deterministic(
+    param: ParameterSetId,
+    signing_key: Buffer,
+    msg: Buffer,
+    ctx: Buffer,
 ) -> SignResult {
-    if signing_key.len() != expected_sk_len {
+    if signing_key.len() != sk_len(param) {
         return SignResult {
             status: SlhDsaStatus::InvalidLength,
             signature: Buffer::from(Vec::new()),
         };
     }
 
-    let sk = match slh_dsa_core::SigningKey::<P>::try_from(signing_key) {
+    let sk = match DynSigningKey::from_bytes(param.into(), &signing_key) {
         Ok(key) => key,
-        Err(_) => {
+        Err(err) => {
             return SignResult {
-                status: SlhDsaStatus::DecodeError,
+                status: err.into(),
                 signature: Buffer::from(Vec::new()),
             };
         }
     };
 
-    let sig = match slh_dsa_core::sign_deterministic::<P>(&sk, msg, ctx) {
+    let sig = match sk.sign_deterministic(&msg, &ctx) {
         Ok(signature) => signature,
-        Err(_) => {
+        Err(err) => {
             return SignResult {
-                status: SlhDsaStatus::DecodeError,
+                status: err.into(),
                 signature: Buffer::from(Vec::new()),
             };
         }
@@ -371,41 +604,28 @@ fn sign_det_for<P: ParameterSet>(
     }
 }
 
-#[napi]
-pub fn slh_dsa_verifying_key_from_signing_key(
+fn verifying_key_from_signing_key(
     param: ParameterSetId,
     signing_key: Buffer,
+    validated: bool,
 ) -> KeyResult {
-    let expected_sk_len = sk_len(param);
-    match param {
-        ParameterSetId::Shake128s => vk_from_sk_for::<Shake128s>(&signing_key, expected_sk_len),
-        ParameterSetId::Shake128f => vk_from_sk_for::<Shake128f>(&signing_key, expected_sk_len),
-        ParameterSetId::Shake192s => vk_from_sk_for::<Shake192s>(&signing_key, expected_sk_len),
-        ParameterSetId::Shake192f => vk_from_sk_for::<Shake192f>(&signing_key, expected_sk_len),
-        ParameterSetId::Shake256s => vk_from_sk_for::<Shake256s>(&signing_key, expected_sk_len),
-        ParameterSetId::Shake256f => vk_from_sk_for::<Shake256f>(&signing_key, expected_sk_len),
-        ParameterSetId::Sha2_128s => vk_from_sk_for::<Sha2_128s>(&signing_key, expected_sk_len),
-        ParameterSetId::Sha2_128f => vk_from_sk_for::<Sha2_128f>(&signing_key, expected_sk_len),
-        ParameterSetId::Sha2_192s => vk_from_sk_for::<Sha2_192s>(&signing_key, expected_sk_len),
-        ParameterSetId::Sha2_192f => vk_from_sk_for::<Sha2_192f>(&signing_key, expected_sk_len),
-        ParameterSetId::Sha2_256s => vk_from_sk_for::<Sha2_256s>(&signing_key, expected_sk_len),
-        ParameterSetId::Sha2_256f => vk_from_sk_for::<Sha2_256f>(&signing_key, expected_sk_len),
-    }
-}
-
-fn vk_from_sk_for<P: ParameterSet>(signing_key: &[u8], expected_sk_len: usize) -> KeyResult {
-    if signing_key.len() != expected_sk_len {
+    if signing_key.len() != sk_len(param) {
         return KeyResult {
             status: SlhDsaStatus::InvalidLength,
             verifying_key: Buffer::from(Vec::new()),
         };
     }
 
-    let sk = match slh_dsa_core::SigningKey::<P>::try_from(signing_key) {
+    let sk = if validated {
+        DynSigningKey::from_bytes_validated(param.into(), &signing_key)
+    } else {
+        DynSigningKey::from_bytes(param.into(), &signing_key)
+    };
+    let sk = match sk {
         Ok(key) => key,
-        Err(_) => {
+        Err(err) => {
             return KeyResult {
-                status: SlhDsaStatus::DecodeError,
+                status: err.into(),
                 verifying_key: Buffer::from(Vec::new()),
             };
         }
@@ -413,7 +633,35 @@ fn vk_from_sk_for<P: ParameterSet>(signing_key: &[u8], expected_sk_len: usize) -
 
     KeyResult {
         status: SlhDsaStatus::Ok,
-        verifying_key: Buffer::from(sk.as_ref().to_vec()),
+        verifying_key: Buffer::from(sk.verifying_key().to_vec()),
+    }
+}
+
+#[napi]
+pub fn slh_dsa_verifying_key_from_signing_key(
+    param: ParameterSetId,
+    signing_key: Buffer,
+) -> KeyResult {
+    verifying_key_from_signing_key(param, signing_key, false)
+}
+
+#[napi]
+pub fn slh_dsa_verifying_key_from_signing_key_validated(
+    param: ParameterSetId,
+    signing_key: Buffer,
+) -> KeyResult {
+    verifying_key_from_signing_key(param, signing_key, true)
+}
+
+#[napi]
+pub fn slh_dsa_validate_signing_key(param: ParameterSetId, signing_key: Buffer) -> SlhDsaStatus {
+    if signing_key.len() != sk_len(param) {
+        return SlhDsaStatus::InvalidLength;
+    }
+
+    match DynSigningKey::from_bytes_validated(param.into(), &signing_key) {
+        Ok(_) => SlhDsaStatus::Ok,
+        Err(err) => err.into(),
     }
 }
 
@@ -425,151 +673,659 @@ pub fn slh_dsa_verify(
     ctx: Buffer,
     signature: Buffer,
 ) -> VerifyResult {
-    let expected_vk_len = vk_len(param);
-    let expected_sig_len = sig_len(param);
-    match param {
-        ParameterSetId::Shake128s => verify_for::<Shake128s>(
-            &verifying_key,
-            &msg,
-            &ctx,
-            &signature,
-            expected_vk_len,
-            expected_sig_len,
-        ),
-        ParameterSetId::Shake128f => verify_for::<Shake128f>(
-            &verifying_key,
-            &msg,
-            &ctx,
-            &signature,
-            expected_vk_len,
-            expected_sig_len,
-        ),
-        ParameterSetId::Shake192s => verify_for::<Shake192s>(
-            &verifying_key,
-            &msg,
-            &ctx,
-            &signature,
-            expected_vk_len,
-            expected_sig_len,
-        ),
-        ParameterSetId::Shake192f => verify_for::<Shake192f>(
-            &verifying_key,
-            &msg,
-            &ctx,
-            &signature,
-            expected_vk_len,
-            expected_sig_len,
-        ),
-        ParameterSetId::Shake256s => verify_for::<Shake256s>(
-            &verifying_key,
-            &msg,
-            &ctx,
-            &signature,
-            expected_vk_len,
-            expected_sig_len,
-        ),
-        ParameterSetId::Shake256f => verify_for::<Shake256f>(
-            &verifying_key,
-            &msg,
-            &ctx,
-            &signature,
-            expected_vk_len,
-            expected_sig_len,
-        ),
-        ParameterSetId::Sha2_128s => verify_for::<Sha2_128s>(
-            &verifying_key,
-            &msg,
-            &ctx,
-            &signature,
-            expected_vk_len,
-            expected_sig_len,
-        ),
-        ParameterSetId::Sha2_128f => verify_for::<Sha2_128f>(
-            &verifying_key,
-            &msg,
-            &ctx,
-            &signature,
-            expected_vk_len,
-            expected_sig_len,
-        ),
-        ParameterSetId::Sha2_192s => verify_for::<Sha2_192s>(
-            &verifying_key,
-            &msg,
-            &ctx,
-            &signature,
-            expected_vk_len,
-            expected_sig_len,
-        ),
-        ParameterSetId::Sha2_192f => verify_for::<Sha2_192f>(
-            &verifying_key,
-            &msg,
-            &ctx,
-            &signature,
-            expected_vk_len,
-            expected_sig_len,
-        ),
-        ParameterSetId::Sha2_256s => verify_for::<Sha2_256s>(
-            &verifying_key,
-            &msg,
-            &ctx,
-            &signature,
-            expected_vk_len,
-            expected_sig_len,
-        ),
-        ParameterSetId::Sha2_256f => verify_for::<Sha2_256f>(
-            &verifying_key,
-            &msg,
-            &ctx,
-            &signature,
-            expected_vk_len,
-            expected_sig_len,
-        ),
-    }
-}
-
-fn verify_for<P: ParameterSet>(
-    verifying_key: &[u8],
-    msg: &[u8],
-    ctx: &[u8],
-    signature: &[u8],
-    expected_vk_len: usize,
-    expected_sig_len: usize,
-) -> VerifyResult {
-    if verifying_key.len() != expected_vk_len || signature.len() != expected_sig_len {
+    if verifying_key.len() != vk_len(param) || signature.len() != sig_len(param) {
         return VerifyResult {
             status: SlhDsaStatus::InvalidLength,
             valid: false,
         };
     }
 
-    let vk = match slh_dsa_core::VerifyingKey::<P>::try_from(verifying_key) {
+    let vk = match DynVerifyingKey::from_bytes(param.into(), &verifying_key) {
         Ok(key) => key,
-        Err(_) => {
+        Err(err) => {
             return VerifyResult {
-                status: SlhDsaStatus::DecodeError,
+                status: err.into(),
                 valid: false,
             };
         }
     };
 
-    let sig = match slh_dsa_core::Signature::<P>::try_from(signature) {
+    let sig = match DynSignature::from_bytes(param.into(), &signature) {
         Ok(sig) => sig,
-        Err(_) => {
+        Err(err) => {
             return VerifyResult {
-                status: SlhDsaStatus::DecodeError,
+                status: err.into(),
                 valid: false,
             };
         }
     };
 
-    match slh_dsa_core::verify_with_context::<P>(&vk, msg, ctx, &sig) {
+    match vk.verify(&msg, &ctx, &sig) {
         Ok(_) => VerifyResult {
             status: SlhDsaStatus::Ok,
             valid: true,
         },
-        Err(_) => VerifyResult {
-            status: SlhDsaStatus::VerifyFailed,
+        Err(err) => VerifyResult {
+            status: err.into(),
+            valid: false,
+        },
+    }
+}
+
+fn parse_verify_item(
+    item: &VerifyBatchItem,
+) -> Result<(DynVerifyingKey, DynSignature), SlhDsaStatus> {
+    if item.verifying_key.len() != vk_len(item.param) || item.signature.len() != sig_len(item.param)
+    {
+        return Err(SlhDsaStatus::InvalidLength);
+    }
+
+    let vk = DynVerifyingKey::from_bytes(item.param.into(), &item.verifying_key)?;
+    let sig = DynSignature::from_bytes(item.param.into(), &item.signature)?;
+    Ok((vk, sig))
+}
+
+#[napi]
+pub fn slh_dsa_verify_batch(items: Vec<VerifyBatchItem>) -> Vec<VerifyResult> {
+    let parsed: Vec<_> = items.iter().map(parse_verify_item).collect();
+    let batch: Vec<_> = items
+        .iter()
+        .zip(&parsed)
+        .filter_map(|(item, keys)| keys.as_ref().ok().map(|keys| (item, keys)))
+        .map(|(item, (vk, sig))| BatchVerifyItem {
+            verifying_key: vk,
+            msg: &item.msg,
+            ctx: &item.ctx,
+            signature: sig,
+        })
+        .collect();
+    let mut results = verify_batch(&batch).into_iter();
+
+    parsed
+        .iter()
+        .map(|keys| match keys {
+            Ok(_) => match results.next() {
+                Some(Ok(())) => VerifyResult {
+                    status: SlhDsaStatus::Ok,
+                    valid: true,
+                },
+                Some(Err(err)) => VerifyResult {
+                    status: err.into(),
+                    valid: false,
+                },
+                None => VerifyResult {
+                    status: SlhDsaStatus::VerifyFailed,
+                    valid: false,
+                },
+            },
+            Err(status) => VerifyResult {
+                status: *status,
+                valid: false,
+            },
+        })
+        .collect()
+}
+
+#[napi]
+pub fn slh_dsa_signing_key_to_pkcs8(
+    param: ParameterSetId,
+    signing_key: Buffer,
+    pem: bool,
+) -> EncodeResult {
+    if signing_key.len() != sk_len(param) {
+        return EncodeResult {
+            status: SlhDsaStatus::InvalidLength,
+            encoded: Buffer::from(Vec::new()),
+        };
+    }
+
+    let sk = match DynSigningKey::from_bytes(param.into(), &signing_key) {
+        Ok(key) => key,
+        Err(err) => {
+            return EncodeResult {
+                status: err.into(),
+                encoded: Buffer::from(Vec::new()),
+            };
+        }
+    };
+
+    let encoded = if pem {
+        sk.to_pkcs8_pem(LineEnding::LF)
+            .map(|pem| pem.as_bytes().to_vec())
+    } else {
+        sk.to_pkcs8_der().map(|der| der.as_bytes().to_vec())
+    };
+    match encoded {
+        Ok(encoded) => EncodeResult {
+            status: SlhDsaStatus::Ok,
+            encoded: Buffer::from(encoded),
+        },
+        Err(err) => EncodeResult {
+            status: SlhDsaError::from(err).into(),
+            encoded: Buffer::from(Vec::new()),
+        },
+    }
+}
+
+#[napi]
+pub fn slh_dsa_signing_key_from_pkcs8(encoded: Buffer) -> DecodeKeyResult {
+    match DynSigningKey::from_pkcs8(&encoded) {
+        Ok(sk) => DecodeKeyResult {
+            status: SlhDsaStatus::Ok,
+            param: Some(sk.parameter_set().into()),
+            key: Buffer::from(sk.to_vec()),
+        },
+        Err(err) => DecodeKeyResult {
+            status: err.into(),
+            param: None,
+            key: Buffer::from(Vec::new()),
+        },
+    }
+}
+
+#[napi]
+pub fn slh_dsa_verifying_key_to_spki(
+    param: ParameterSetId,
+    verifying_key: Buffer,
+    pem: bool,
+) -> EncodeResult {
+    if verifying_key.len() != vk_len(param) {
+        return EncodeResult {
+            status: SlhDsaStatus::InvalidLength,
+            encoded: Buffer::from(Vec::new()),
+        };
+    }
+
+    let vk = match DynVerifyingKey::from_bytes(param.into(), &verifying_key) {
+        Ok(key) => key,
+        Err(err) => {
+            return EncodeResult {
+                status: err.into(),
+                encoded: Buffer::from(Vec::new()),
+            };
+        }
+    };
+
+    let encoded = if pem {
+        vk.to_public_key_pem(LineEnding::LF).map(String::into_bytes)
+    } else {
+        vk.to_public_key_der().map(|der| der.into_vec())
+    };
+    match encoded {
+        Ok(encoded) => EncodeResult {
+            status: SlhDsaStatus::Ok,
+            encoded: Buffer::from(encoded),
+        },
+        Err(err) => EncodeResult {
+            status: SlhDsaError::from(err).into(),
+            encoded: Buffer::from(Vec::new()),
+        },
+    }
+}
+
+#[napi]
+pub fn slh_dsa_verifying_key_from_spki(encoded: Buffer) -> DecodeKeyResult {
+    match DynVerifyingKey::from_spki(&encoded) {
+        Ok(vk) => DecodeKeyResult {
+            status: SlhDsaStatus::Ok,
+            param: Some(vk.parameter_set().into()),
+            key: Buffer::from(vk.to_vec()),
+        },
+        Err(err) => DecodeKeyResult {
+            status: err.into(),
+            param: None,
+            key: Buffer::from(Vec::new()),
+        },
+    }
+}
+
+fn key_file_options(kdf: Kdf, aead: Aead) -> KeyFileOptions {
+    KeyFileOptions {
+        kdf: match kdf {
+            Kdf::Argon2id => keyfile::Kdf::ARGON2ID,
+            Kdf::Scrypt => keyfile::Kdf::SCRYPT,
+        },
+        aead: match aead {
+            Aead::ChaCha20Poly1305 => keyfile::Aead::ChaCha20Poly1305,
+            Aead::Aes256Gcm => keyfile::Aead::Aes256Gcm,
+        },
+    }
+}
+
+fn signing_key_result(result: Result<DynSigningKey, SlhDsaError>) -> DecodeKeyResult {
+    match result {
+        Ok(sk) => DecodeKeyResult {
+            status: SlhDsaStatus::Ok,
+            param: Some(sk.parameter_set().into()),
+            key: Buffer::from(sk.to_vec()),
+        },
+        Err(err) => DecodeKeyResult {
+            status: err.into(),
+            param: None,
+            key: Buffer::from(Vec::new()),
+        },
+    }
+}
+
+fn encode_result(encoded: Result<Vec<u8>, SlhDsaError>) -> EncodeResult {
+    match encoded {
+        Ok(encoded) => EncodeResult {
+            status: SlhDsaStatus::Ok,
+            encoded: Buffer::from(encoded),
+        },
+        Err(err) => EncodeResult {
+            status: err.into(),
+            encoded: Buffer::from(Vec::new()),
+        },
+    }
+}
+
+#[napi]
+pub fn slh_dsa_key_file_len(param: ParameterSetId) -> u64 {
+    keyfile::encoded_len(param.into()) as u64
+}
+
+#[napi]
+pub fn slh_dsa_signing_key_to_key_file(
+    param: ParameterSetId,
+    signing_key: Buffer,
+    passphrase: Buffer,
+    kdf: Kdf,
+    aead: Aead,
+) -> EncodeResult {
+    if signing_key.len() != sk_len(param) {
+        return EncodeResult {
+            status: SlhDsaStatus::InvalidLength,
+            encoded: Buffer::from(Vec::new()),
+        };
+    }
+
+    let mut rng = OsRng;
+    encode_result(
+        DynSigningKey::from_bytes(param.into(), &signing_key)
+            .and_then(|sk| sk.to_key_file(&passphrase, key_file_options(kdf, aead), &mut rng)),
+    )
+}
+
+#[napi]
+pub fn slh_dsa_signing_key_from_key_file(encoded: Buffer, passphrase: Buffer) -> DecodeKeyResult {
+    signing_key_result(DynSigningKey::from_key_file(&encoded, &passphrase))
+}
+
+#[napi]
+pub fn slh_dsa_signing_key_to_encrypted_pkcs8(
+    param: ParameterSetId,
+    signing_key: Buffer,
+    passphrase: Buffer,
+    pem: bool,
+) -> EncodeResult {
+    if signing_key.len() != sk_len(param) {
+        return EncodeResult {
+            status: SlhDsaStatus::InvalidLength,
+            encoded: Buffer::from(Vec::new()),
+        };
+    }
+
+    let mut rng = OsRng;
+    encode_result(
+        DynSigningKey::from_bytes(param.into(), &signing_key).and_then(|sk| {
+            if pem {
+                sk.to_encrypted_pkcs8_pem(&passphrase, &mut rng)
+                    .map(String::into_bytes)
+            } else {
+                sk.to_encrypted_pkcs8_der(&passphrase, &mut rng)
+            }
+        }),
+    )
+}
+
+#[napi]
+pub fn slh_dsa_signing_key_from_encrypted_pkcs8(
+    encoded: Buffer,
+    passphrase: Buffer,
+) -> DecodeKeyResult {
+    signing_key_result(DynSigningKey::from_encrypted_pkcs8(&encoded, &passphrase))
+}
+
+fn jose_result(encoded: Result<String, SlhDsaError>) -> JoseResult {
+    match encoded {
+        Ok(encoded) => JoseResult {
+            status: SlhDsaStatus::Ok,
+            encoded,
+        },
+        Err(err) => JoseResult {
+            status: err.into(),
+            encoded: String::new(),
+        },
+    }
+}
+
+#[napi]
+pub fn slh_dsa_signing_key_to_jwk(param: ParameterSetId, signing_key: Buffer) -> JoseResult {
+    if signing_key.len() != sk_len(param) {
+        return jose_result(Err(SlhDsaError::InvalidLength));
+    }
+
+    jose_result(DynSigningKey::from_bytes(param.into(), &signing_key).map(|sk| sk.to_jwk()))
+}
+
+#[napi]
+pub fn slh_dsa_signing_key_from_jwk(jwk: String) -> DecodeKeyResult {
+    match DynSigningKey::from_jwk(&jwk) {
+        Ok(sk) => DecodeKeyResult {
+            status: SlhDsaStatus::Ok,
+            param: Some(sk.parameter_set().into()),
+            key: Buffer::from(sk.to_vec()),
+        },
+        Err(err) => DecodeKeyResult {
+            status: err.into(),
+            param: None,
+            key: Buffer::from(Vec::new()),
+        },
+    }
+}
+
+#[napi]
+pub fn slh_dsa_verifying_key_to_jwk(param: ParameterSetId, verifying_key: Buffer) -> JoseResult {
+    if verifying_key.len() != vk_len(param) {
+        return jose_result(Err(SlhDsaError::InvalidLength));
+    }
+
+    jose_result(DynVerifyingKey::from_bytes(param.into(), &verifying_key).map(|vk| vk.to_jwk()))
+}
+
+#[napi]
+pub fn slh_dsa_verifying_key_from_jwk(jwk: String) -> DecodeKeyResult {
+    match DynVerifyingKey::from_jwk(&jwk) {
+        Ok(vk) => DecodeKeyResult {
+            status: SlhDsaStatus::Ok,
+            param: Some(vk.parameter_set().into()),
+            key: Buffer::from(vk.to_vec()),
+        },
+        Err(err) => DecodeKeyResult {
+            status: err.into(),
+            param: None,
+            key: Buffer::from(Vec::new()),
+        },
+    }
+}
+
+#[napi]
+pub fn slh_dsa_jws_sign(
+    param: ParameterSetId,
+    signing_key: Buffer,
+    payload: Buffer,
+    key_id: Option<String>,
+) -> JoseResult {
+    if signing_key.len() != sk_len(param) {
+        return jose_result(Err(SlhDsaError::InvalidLength));
+    }
+
+    let mut builder = JwsBuilder::new(&payload);
+    if let Some(key_id) = &key_id {
+        builder = builder.key_id(key_id);
+    }
+    let mut rng = OsRng;
+    jose_result(
+        DynSigningKey::from_bytes(param.into(), &signing_key)
+            .and_then(|sk| builder.sign(&sk, &mut rng))
+            .map(|jws| jws.to_compact()),
+    )
+}
+
+#[napi]
+pub fn slh_dsa_jws_verify(verifying_key: Buffer, jws: String) -> JwsInfo {
+    let decoded = if jws.trim_start().starts_with('{') {
+        Jws::from_json(&jws)
+    } else {
+        Jws::from_compact(&jws)
+    };
+    let jws = match decoded {
+        Ok(jws) => jws,
+        Err(err) => {
+            return JwsInfo {
+                status: err.into(),
+                valid: false,
+                param: None,
+                key_id: None,
+                payload: Buffer::from(Vec::new()),
+            };
+        }
+    };
+
+    let verified = DynVerifyingKey::from_bytes(jws.parameter_set(), &verifying_key)
+        .and_then(|vk| jws.verify(&vk));
+    JwsInfo {
+        status: match verified {
+            Ok(()) => SlhDsaStatus::Ok,
+            Err(err) => err.into(),
+        },
+        valid: verified.is_ok(),
+        param: Some(jws.parameter_set().into()),
+        key_id: jws.key_id().map(str::to_string),
+        payload: Buffer::from(jws.payload().to_vec()),
+    }
+}
+
+#[napi]
+pub fn slh_dsa_jwt_issue(
+    param: ParameterSetId,
+    signing_key: Buffer,
+    claims: String,
+    key_id: Option<String>,
+) -> JoseResult {
+    if signing_key.len() != sk_len(param) {
+        return jose_result(Err(SlhDsaError::InvalidLength));
+    }
+
+    let mut rng = OsRng;
+    jose_result(
+        DynSigningKey::from_bytes(param.into(), &signing_key)
+            .and_then(|sk| issue_jwt(&sk, &claims, key_id.as_deref(), &mut rng)),
+    )
+}
+
+#[napi]
+pub fn slh_dsa_jwt_validate(
+    verifying_key: Buffer,
+    token: String,
+    now: i64,
+    audience: Option<String>,
+    leeway: u32,
+) -> JwtInfo {
+    let Ok(now) = u64::try_from(now) else {
+        return JwtInfo {
+            status: SlhDsaStatus::InvalidParam,
+            valid: false,
+            claims: String::new(),
+        };
+    };
+    let mut validation =
+        JwtValidation::new(Duration::from_secs(now)).leeway(Duration::from_secs(leeway.into()));
+    if let Some(audience) = &audience {
+        validation = validation.audience(audience);
+    }
+
+    let validated = Jws::from_compact(&token).and_then(|jws| {
+        let vk = DynVerifyingKey::from_bytes(jws.parameter_set(), &verifying_key)?;
+        validate_jwt(&token, &vk, &validation)
+    });
+    match validated {
+        Ok(claims) => JwtInfo {
+            status: SlhDsaStatus::Ok,
+            valid: true,
+            claims,
+        },
+        Err(err) => JwtInfo {
+            status: err.into(),
             valid: false,
+            claims: String::new(),
+        },
+    }
+}
+
+fn envelope_info(envelope: &Envelope, status: SlhDsaStatus, valid: bool) -> EnvelopeInfo {
+    EnvelopeInfo {
+        status,
+        valid,
+        param: Some(envelope.parameter_set().into()),
+        mode: Some(envelope.mode().into()),
+        key_id: envelope
+            .key_id()
+            .map(|key_id| Buffer::from(key_id.to_vec())),
+        context: Buffer::from(envelope.context().to_vec()),
+        payload: envelope
+            .payload()
+            .map(|payload| Buffer::from(payload.to_vec())),
+    }
+}
+
+fn envelope_error(err: SlhDsaError) -> EnvelopeInfo {
+    EnvelopeInfo {
+        status: err.into(),
+        valid: false,
+        param: None,
+        mode: None,
+        key_id: None,
+        context: Buffer::from(Vec::new()),
+        payload: None,
+    }
+}
+
+#[napi]
+pub fn slh_dsa_envelope_sign(
+    param: ParameterSetId,
+    signing_key: Buffer,
+    payload: Buffer,
+    ctx: Buffer,
+    key_id: Option<Buffer>,
+    mode: SignatureMode,
+    detached: bool,
+) -> EncodeResult {
+    if signing_key.len() != sk_len(param) {
+        return EncodeResult {
+            status: SlhDsaStatus::InvalidLength,
+            encoded: Buffer::from(Vec::new()),
+        };
+    }
+
+    let mut builder = EnvelopeBuilder::new(&payload)
+        .context(&ctx)
+        .mode(mode.into())
+        .detached(detached);
+    if let Some(key_id) = &key_id {
+        builder = builder.key_id(key_id);
+    }
+    let mut rng = OsRng;
+    match DynSigningKey::from_bytes(param.into(), &signing_key)
+        .and_then(|sk| builder.sign(&sk, &mut rng))
+    {
+        Ok(envelope) => EncodeResult {
+            status: SlhDsaStatus::Ok,
+            encoded: Buffer::from(envelope.to_vec()),
+        },
+        Err(err) => EncodeResult {
+            status: err.into(),
+            encoded: Buffer::from(Vec::new()),
         },
     }
 }
+
+#[napi]
+pub fn slh_dsa_envelope_decode(encoded: Buffer) -> EnvelopeInfo {
+    match Envelope::from_bytes(&encoded) {
+        Ok(envelope) => envelope_info(&envelope, SlhDsaStatus::Ok, false),
+        Err(err) => envelope_error(err),
+    }
+}
+
+#[napi]
+pub fn slh_dsa_envelope_verify(
+    verifying_key: Buffer,
+    encoded: Buffer,
+    detached_payload: Option<Buffer>,
+) -> EnvelopeInfo {
+    let envelope = match Envelope::from_bytes(&encoded) {
+        Ok(envelope) => envelope,
+        Err(err) => return envelope_error(err),
+    };
+
+    let verified = DynVerifyingKey::from_bytes(envelope.parameter_set(), &verifying_key)
+        .and_then(|vk| envelope.verify(&vk, detached_payload.as_deref()));
+    let status = match verified {
+        Ok(()) => SlhDsaStatus::Ok,
+        Err(err) => err.into(),
+    };
+    envelope_info(&envelope, status, verified.is_ok())
+}
+
+fn fingerprint_error(status: SlhDsaStatus) -> FingerprintResult {
+    FingerprintResult {
+        status,
+        fingerprint: Buffer::from(Vec::new()),
+        hex: String::new(),
+        base32: String::new(),
+        words: String::new(),
+    }
+}
+
+#[napi]
+pub fn slh_dsa_fingerprint(
+    param: ParameterSetId,
+    verifying_key: Buffer,
+    source: FingerprintSource,
+) -> FingerprintResult {
+    if verifying_key.len() != vk_len(param) {
+        return fingerprint_error(SlhDsaStatus::InvalidLength);
+    }
+
+    let fingerprint =
+        DynVerifyingKey::from_bytes(param.into(), &verifying_key).and_then(|vk| match source {
+            FingerprintSource::Raw => Ok(vk.fingerprint()),
+            FingerprintSource::Spki => vk.spki_fingerprint(),
+        });
+    match fingerprint {
+        Ok(fingerprint) => FingerprintResult {
+            status: SlhDsaStatus::Ok,
+            fingerprint: Buffer::from(fingerprint.as_bytes().to_vec()),
+            hex: fingerprint.to_hex(),
+            base32: fingerprint.to_base32(),
+            words: fingerprint.to_words(),
+        },
+        Err(err) => fingerprint_error(err.into()),
+    }
+}
+
+#[napi]
+pub fn slh_dsa_set_verify_after_sign(enabled: bool) {
+    slh_dsa_core::set_verify_after_sign(enabled);
+}
+
+#[napi]
+pub fn slh_dsa_verify_after_sign_enabled() -> bool {
+    slh_dsa_core::verify_after_sign_enabled()
+}
+
+#[cfg(feature = "fips")]
+#[napi]
+pub fn slh_dsa_self_test(param: ParameterSetId) -> SlhDsaStatus {
+    match slh_dsa_core::fips::self_test(param.into()) {
+        Ok(()) => SlhDsaStatus::Ok,
+        Err(err) => err.into(),
+    }
+}
+
+#[cfg(feature = "fips")]
+#[napi]
+pub fn slh_dsa_self_test_all() -> SlhDsaStatus {
+    match slh_dsa_core::fips::self_test_all() {
+        Ok(()) => SlhDsaStatus::Ok,
+        Err(err) => err.into(),
+    }
+}
+
+#[cfg(feature = "fips")]
+#[napi]
+pub fn slh_dsa_is_operational() -> bool {
+    slh_dsa_core::fips::is_operational()
+}
@@ -1,10 +1,16 @@
 //! UniFFI bindings for SLH-DSA.
 
 use getrandom::fill;
-use rand_core::{CryptoRng, RngCore};
+use rand_core::{TryCryptoRng, TryRng};
+use slh_dsa_core::cose::{CoseSign1, CoseSign1Builder};
+use slh_dsa_core::csr::{CertificationRequest, CsrBuilder};
+use slh_dsa_core::envelope::{Envelope, EnvelopeBuilder};
+use slh_dsa_core::keyfile::{self, KeyFileOptions};
+use slh_dsa_core::pkcs8::{EncodePrivateKey, EncodePublicKey, LineEnding, ObjectIdentifier};
+use slh_dsa_core::x509::{Extension, GeneralName, Name};
 use slh_dsa_core::{
-    ParameterSet, Sha2_128f, Sha2_128s, Sha2_192f, Sha2_192s, Sha2_256f, Sha2_256s, Shake128f,
-    Shake128s, Shake192f, Shake192s, Shake256f, Shake256s,
+    sign_batch, DynSignature, DynSigningKey, DynVerifyingKey, Fingerprint, PreHashAlgorithm,
+    SlhDsaError,
 };
 
 #[derive(Debug, Copy, Clone, Eq, PartialEq, uniffi::Enum)]
@@ -23,6 +29,39 @@ pub enum ParameterSetId {
     Sha2_256f,
 }
 
+#[derive(Debug, Copy, Clone, Eq, PartialEq, uniffi::Enum)]
+pub enum HashFamily {
+    Sha2,
+    Shake,
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, uniffi::Enum)]
+pub enum SignatureMode {
+    Pure,
+    PreHashSha256,
+    PreHashSha512,
+    PreHashShake128,
+    PreHashShake256,
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, uniffi::Enum)]
+pub enum FingerprintSource {
+    Raw,
+    Spki,
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, uniffi::Enum)]
+pub enum Kdf {
+    Argon2id,
+    Scrypt,
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, uniffi::Enum)]
+pub enum Aead {
+    ChaCha20Poly1305,
+    Aes256Gcm,
+}
+
 #[derive(Debug, Copy, Clone, Eq, PartialEq, uniffi::Enum)]
 pub enum SlhDsaStatus {
     Ok,
@@ -30,10 +69,52 @@ pub enum SlhDsaStatus {
     InvalidLength,
     DecodeError,
     VerifyFailed,
+    ContextTooLong,
+    MalformedSigningKey,
+    MalformedVerifyingKey,
+    MalformedSignature,
+    ParameterSetMismatch,
+    RngFailure,
+    UnsupportedAlgorithm,
+    MalformedEncoding,
+    InvalidCertificate,
+    CertificateExpired,
+    UntrustedChain,
+    TokenExpired,
+    AudienceMismatch,
+    SelfTestFailed,
+    FaultDetected,
+    DecryptionFailed,
+}
+
+impl From<SlhDsaError> for SlhDsaStatus {
+    fn from(err: SlhDsaError) -> Self {
+        match err {
+            SlhDsaError::ContextTooLong => SlhDsaStatus::ContextTooLong,
+            SlhDsaError::MalformedSigningKey => SlhDsaStatus::MalformedSigningKey,
+            SlhDsaError::MalformedVerifyingKey => SlhDsaStatus::MalformedVerifyingKey,
+            SlhDsaError::MalformedSignature => SlhDsaStatus::MalformedSignature,
+            SlhDsaError::InvalidLength => SlhDsaStatus::InvalidLength,
+            SlhDsaError::ParameterSetMismatch => SlhDsaStatus::ParameterSetMismatch,
+            SlhDsaError::RngFailure => SlhDsaStatus::RngFailure,
+            SlhDsaError::VerificationFailed => SlhDsaStatus::VerifyFailed,
+            SlhDsaError::UnsupportedAlgorithm => SlhDsaStatus::UnsupportedAlgorithm,
+            SlhDsaError::MalformedEncoding => SlhDsaStatus::MalformedEncoding,
+            SlhDsaError::InvalidCertificate => SlhDsaStatus::InvalidCertificate,
+            SlhDsaError::CertificateExpired => SlhDsaStatus::CertificateExpired,
+            SlhDsaError::UntrustedChain => SlhDsaStatus::UntrustedChain,
+            SlhDsaError::TokenExpired => SlhDsaStatus::TokenExpired,
+            SlhDsaError::AudienceMismatch => SlhDsaStatus::AudienceMismatch,
+            SlhDsaError::SelfTestFailed => SlhDsaStatus::SelfTestFailed,
+            SlhDsaError::FaultDetected => SlhDsaStatus::FaultDetected,
+            SlhDsaError::DecryptionFailed => SlhDsaStatus::DecryptionFailed,
+        }
+    }
 }
 
 #[derive(Debug, Clone, uniffi::Record)]
 pub struct Keypair {
+    pub status: SlhDsaStatus,
     pub signing_key: Vec<u8>,
     pub verifying_key: Vec<u8>,
 }
@@ -44,6 +125,12 @@ pub struct SignResult {
     pub signature: Vec<u8>,
 }
 
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct SignBatchResult {
+    pub status: SlhDsaStatus,
+    pub signatures: Vec<Vec<u8>>,
+}
+
 #[derive(Debug, Clone, uniffi::Record)]
 pub struct VerifyResult {
     pub status: SlhDsaStatus,
@@ -56,27 +143,130 @@ pub struct KeyResult {
     pub verifying_key: Vec<u8>,
 }
 
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct EncodeResult {
+    pub status: SlhDsaStatus,
+    pub encoded: Vec<u8>,
+}
+
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct DecodeKeyResult {
+    pub status: SlhDsaStatus,
+    pub param: Option<ParameterSetId>,
+    pub key: Vec<u8>,
+}
+
+#[derive(Debug, Clone, Default, uniffi::Record)]
+pub struct CsrSubject {
+    pub common_name: Option<String>,
+    pub organization: Option<String>,
+    pub organizational_unit: Option<String>,
+    pub country: Option<String>,
+}
+
+#[derive(Debug, Clone, uniffi::Enum)]
+pub enum SubjectAltName {
+    Dns { name: String },
+    Email { address: String },
+    Uri { uri: String },
+    Ip { address: Vec<u8> },
+}
+
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct CsrExtension {
+    pub oid: String,
+    pub critical: bool,
+    pub value: Vec<u8>,
+}
+
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct CsrInfo {
+    pub status: SlhDsaStatus,
+    pub valid: bool,
+    pub param: Option<ParameterSetId>,
+    pub verifying_key: Vec<u8>,
+    pub subject: CsrSubject,
+    pub subject_alt_names: Vec<SubjectAltName>,
+    pub extensions: Vec<CsrExtension>,
+}
+
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct CoseSign1Info {
+    pub status: SlhDsaStatus,
+    pub valid: bool,
+    pub param: Option<ParameterSetId>,
+    pub key_id: Option<Vec<u8>>,
+    pub payload: Option<Vec<u8>>,
+}
+
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct EnvelopeInfo {
+    pub status: SlhDsaStatus,
+    pub valid: bool,
+    pub param: Option<ParameterSetId>,
+    pub mode: Option<SignatureMode>,
+    pub key_id: Option<Vec<u8>>,
+    pub context: Vec<u8>,
+    pub payload: Option<Vec<u8>>,
+}
+
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct FingerprintResult {
+    pub status: SlhDsaStatus,
+    pub fingerprint: Vec<u8>,
+    pub hex: String,
+    pub base32: String,
+    pub words: String,
+}
+
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct ParameterSetInfo {
+    pub param: ParameterSetId,
+    pub name: String,
+    pub hash_family: HashFamily,
+    pub fast: bool,
+    pub n: u32,
+    pub h: u32,
+    pub d: u32,
+    pub a: u32,
+    pub k: u32,
+    pub w: u32,
+    pub security_category: u8,
+    pub oid: String,
+    pub signing_key_len: u64,
+    pub verifying_key_len: u64,
+    pub signature_len: u64,
+}
+
+/// Heap copy of secret key bytes, wiped on drop when the `zeroize` feature is enabled.
+#[cfg(feature = "zeroize")]
+type SecretBytes = zeroize::Zeroizing<Vec<u8>>;
+#[cfg(not(feature = "zeroize"))]
+type SecretBytes = Vec<u8>;
+
 struct OsRng;
 
-impl RngCore for OsRng {
-    fn next_u32(&mut self) -> u32 {
+impl TryRng for OsRng {
+    type Error = getrandom::Error;
+
+    fn try_next_u32(&mut self) -> Result<u32, Self::Error> {
         let mut bytes = [0u8; 4];
-        fill(&mut bytes).expect("OsRng failure");
-        u32::from_le_bytes(bytes)
+        fill(&mut bytes)?;
+        Ok(u32::from_le_bytes(bytes))
     }
 
-    fn next_u64(&mut self) -> u64 {
+    fn try_next_u64(&mut self) -> Result<u64, Self::Error> {
         let mut bytes = [0u8; 8];
-        fill(&mut bytes).expect("OsRng failure");
-        u64::from_le_bytes(bytes)
+        fill(&mut bytes)?;
+        Ok(u64::from_le_bytes(bytes))
     }
 
-    fn fill_bytes(&mut self, dst: &mut [u8]) {
-        fill(dst).expect("OsRng failure");
+    fn try_fill_bytes(&mut self, dst: &mut [u8]) -> Result<(), Self::Error> {
+        fill(dst)
     }
 }
 
-impl CryptoRng for OsRng {}
+impl TryCryptoRng for OsRng {}
 
 fn parameter_name(param: ParameterSetId) -> &'static str {
     match param {
@@ -95,86 +285,187 @@ fn parameter_name(param: ParameterSetId) -> &'static str {
     }
 }
 
+impl From<ParameterSetId> for slh_dsa_core::ParameterSetId {
+    fn from(param: ParameterSetId) -> Self {
+        match param {
+            ParameterSetId::Shake128s => Self::Shake128s,
+            ParameterSetId::Shake128f => Self::Shake128f,
+            ParameterSetId::Shake192s => Self::Shake192s,
+            ParameterSetId::Shake192f => Self::Shake192f,
+            ParameterSetId::Shake256s => Self::Shake256s,
+            ParameterSetId::Shake256f => Self::Shake256f,
+            ParameterSetId::Sha2_128s => Self::Sha2_128s,
+            ParameterSetId::Sha2_128f => Self::Sha2_128f,
+            ParameterSetId::Sha2_192s => Self::Sha2_192s,
+            ParameterSetId::Sha2_192f => Self::Sha2_192f,
+            ParameterSetId::Sha2_256s => Self::Sha2_256s,
+            ParameterSetId::Sha2_256f => Self::Sha2_256f,
+        }
+    }
+}
+
+impl From<slh_dsa_core::ParameterSetId> for ParameterSetId {
+    fn from(param: slh_dsa_core::ParameterSetId) -> Self {
+        use slh_dsa_core::ParameterSetId as Core;
+        match param {
+            Core::Shake128s => Self::Shake128s,
+            Core::Shake128f => Self::Shake128f,
+            Core::Shake192s => Self::Shake192s,
+            Core::Shake192f => Self::Shake192f,
+            Core::Shake256s => Self::Shake256s,
+            Core::Shake256f => Self::Shake256f,
+            Core::Sha2_128s => Self::Sha2_128s,
+            Core::Sha2_128f => Self::Sha2_128f,
+            Core::Sha2_192s => Self::Sha2_192s,
+            Core::Sha2_192f => Self::Sha2_192f,
+            Core::Sha2_256s => Self::Sha2_256s,
+            Core::Sha2_256f => Self::Sha2_256f,
+        }
+    }
+}
+
+impl From<slh_dsa_core::HashFamily> for HashFamily {
+    fn from(family: slh_dsa_core::HashFamily) -> Self {
+        match family {
+            slh_dsa_core::HashFamily::Sha2 => Self::Sha2,
+            slh_dsa_core::HashFamily::Shake => Self::Shake,
+        }
+    }
+}
+
+impl From<SignatureMode> for slh_dsa_core::envelope::SignatureMode {
+    fn from(mode: SignatureMode) -> Self {
+        match mode {
+            SignatureMode::Pure => Self::Pure,
+            SignatureMode::PreHashSha256 => Self::PreHash(PreHashAlgorithm::Sha256),
+            SignatureMode::PreHashSha512 => Self::PreHash(PreHashAlgorithm::Sha512),
+            SignatureMode::PreHashShake128 => Self::PreHash(PreHashAlgorithm::Shake128),
+            SignatureMode::PreHashShake256 => Self::PreHash(PreHashAlgorithm::Shake256),
+        }
+    }
+}
+
+impl From<slh_dsa_core::envelope::SignatureMode> for SignatureMode {
+    fn from(mode: slh_dsa_core::envelope::SignatureMode) -> Self {
+        use slh_dsa_core::envelope::SignatureMode as Core;
+        match mode {
+            Core::Pure => Self::Pure,
+            Core::PreHash(PreHashAlgorithm::Sha256) => Self::PreHashSha256,
+            Core::PreHash(PreHashAlgorithm::Sha512) => Self::PreHashSha512,
+            Core::PreHash(PreHashAlgorithm::Shake128) => Self::PreHashShake128,
+            Core::PreHash(PreHashAlgorithm::Shake256) => Self::PreHashShake256,
+        }
+    }
+}
+
+impl From<&slh_dsa_core::ParameterSetInfo> for ParameterSetInfo {
+    fn from(info: &slh_dsa_core::ParameterSetInfo) -> Self {
+        Self {
+            param: info.id.into(),
+            name: info.name.to_string(),
+            hash_family: info.hash_family.into(),
+            fast: info.fast,
+            n: info.n as u32,
+            h: info.h as u32,
+            d: info.d as u32,
+            a: info.a as u32,
+            k: info.k as u32,
+            w: info.w as u32,
+            security_category: info.security_category,
+            oid: info.oid.to_string(),
+            signing_key_len: info.signing_key_len as u64,
+            verifying_key_len: info.verifying_key_len as u64,
+            signature_len: info.signature_len as u64,
+        }
+    }
+}
+
 fn signing_key_len(param: ParameterSetId) -> usize {
-    let core_id = match param {
-        ParameterSetId::Shake128s => slh_dsa_core::ParameterSetId::Shake128s,
-        ParameterSetId::Shake128f => slh_dsa_core::ParameterSetId::Shake128f,
-        ParameterSetId::Shake192s => slh_dsa_core::ParameterSetId::Shake192s,
-        ParameterSetId::Shake192f => slh_dsa_core::ParameterSetId::Shake192f,
-        ParameterSetId::Shake256s => slh_dsa_core::ParameterSetId::Shake256s,
-        ParameterSetId::Shake256f => slh_dsa_core::ParameterSetId::Shake256f,
-        ParameterSetId::Sha2_128s => slh_dsa_core::ParameterSetId::Sha2_128s,
-        ParameterSetId::Sha2_128f => slh_dsa_core::ParameterSetId::Sha2_128f,
-        ParameterSetId::Sha2_192s => slh_dsa_core::ParameterSetId::Sha2_192s,
-        ParameterSetId::Sha2_192f => slh_dsa_core::ParameterSetId::Sha2_192f,
-        ParameterSetId::Sha2_256s => slh_dsa_core::ParameterSetId::Sha2_256s,
-        ParameterSetId::Sha2_256f => slh_dsa_core::ParameterSetId::Sha2_256f,
-    };
-    slh_dsa_core::sk_len_for(core_id).unwrap_or(0)
+    slh_dsa_core::sk_len_for(param.into())
 }
 
 fn verifying_key_len(param: ParameterSetId) -> usize {
-    let core_id = match param {
-        ParameterSetId::Shake128s => slh_dsa_core::ParameterSetId::Shake128s,
-        ParameterSetId::Shake128f => slh_dsa_core::ParameterSetId::Shake128f,
-        ParameterSetId::Shake192s => slh_dsa_core::ParameterSetId::Shake192s,
-        ParameterSetId::Shake192f => slh_dsa_core::ParameterSetId::Shake192f,
-        ParameterSetId::Shake256s => slh_dsa_core::ParameterSetId::Shake256s,
-        ParameterSetId::Shake256f => slh_dsa_core::ParameterSetId::Shake256f,
-        ParameterSetId::Sha2_128s => slh_dsa_core::ParameterSetId::Sha2_128s,
-        ParameterSetId::Sha2_128f => slh_dsa_core::ParameterSetId::Sha2_128f,
-        ParameterSetId::Sha2_192s => slh_dsa_core::ParameterSetId::Sha2_192s,
-        ParameterSetId::Sha2_192f => slh_dsa_core::ParameterSetId::Sha2_192f,
-        ParameterSetId::Sha2_256s => slh_dsa_core::ParameterSetId::Sha2_256s,
-        ParameterSetId::Sha2_256f => slh_dsa_core::ParameterSetId::Sha2_256f,
-    };
-    slh_dsa_core::vk_len_for(core_id).unwrap_or(0)
+    slh_dsa_core::vk_len_for(param.into())
 }
 
 fn signature_len(param: ParameterSetId) -> usize {
-    let core_id = match param {
-        ParameterSetId::Shake128s => slh_dsa_core::ParameterSetId::Shake128s,
-        ParameterSetId::Shake128f => slh_dsa_core::ParameterSetId::Shake128f,
-        ParameterSetId::Shake192s => slh_dsa_core::ParameterSetId::Shake192s,
-        ParameterSetId::Shake192f => slh_dsa_core::ParameterSetId::Shake192f,
-        ParameterSetId::Shake256s => slh_dsa_core::ParameterSetId::Shake256s,
-        ParameterSetId::Shake256f => slh_dsa_core::ParameterSetId::Shake256f,
-        ParameterSetId::Sha2_128s => slh_dsa_core::ParameterSetId::Sha2_128s,
-        ParameterSetId::Sha2_128f => slh_dsa_core::ParameterSetId::Sha2_128f,
-        ParameterSetId::Sha2_192s => slh_dsa_core::ParameterSetId::Sha2_192s,
-        ParameterSetId::Sha2_192f => slh_dsa_core::ParameterSetId::Sha2_192f,
-        ParameterSetId::Sha2_256s => slh_dsa_core::ParameterSetId::Sha2_256s,
-        ParameterSetId::Sha2_256f => slh_dsa_core::ParameterSetId::Sha2_256f,
-    };
-    slh_dsa_core::sig_len_for(core_id).unwrap_or(0)
+    slh_dsa_core::sig_len_for(param.into())
+}
+
+fn seed_len(param: ParameterSetId) -> usize {
+    slh_dsa_core::seed_len_for(param.into())
 }
 
-fn keypair_generate<P: ParameterSet>() -> Keypair {
+fn keypair_generate(param: ParameterSetId) -> Keypair {
     let mut rng = OsRng;
-    let (sk, vk) = slh_dsa_core::generate_keypair::<P, _>(&mut rng);
+    let sk = match DynSigningKey::try_generate(param.into(), &mut rng) {
+        Ok(key) => key,
+        Err(err) => {
+            return Keypair {
+                status: err.into(),
+                signing_key: Vec::new(),
+                verifying_key: Vec::new(),
+            };
+        }
+    };
+    Keypair {
+        status: SlhDsaStatus::Ok,
+        signing_key: sk.to_vec(),
+        verifying_key: sk.verifying_key().to_vec(),
+    }
+}
+
+fn keypair_from_seed(
+    param: ParameterSetId,
+    sk_seed: &[u8],
+    sk_prf: &[u8],
+    pk_seed: &[u8],
+) -> Keypair {
+    let sk = match DynSigningKey::from_seed(param.into(), sk_seed, sk_prf, pk_seed) {
+        Ok(key) => key,
+        Err(err) => {
+            return Keypair {
+                status: err.into(),
+                signing_key: Vec::new(),
+                verifying_key: Vec::new(),
+            };
+        }
+    };
     Keypair {
-        signing_key: sk.to_bytes().to_vec(),
-        verifying_key: vk.to_bytes().to_vec(),
+        status: SlhDsaStatus::Ok,
+        signing_key: sk.to_vec(),
+        verifying_key: sk.verifying_key().to_vec(),
     }
 }
 
-fn sign<P: ParameterSet>(sk_bytes: &[u8], msg: &[u8], ctx: &[u8]) -> SignResult {
-    let sk = match slh_dsa_core::SigningKey::<P>::try_from(sk_bytes) {
+fn sign(
+    param: ParameterSetId,
+    sk_bytes: &[u8],
+    msg: &[u8],
+    ctx: &[u8],
+    checked: bool,
+) -> SignResult {
+    let sk = match DynSigningKey::from_bytes(param.into(), sk_bytes) {
         Ok(key) => key,
-        Err(_) => {
+        Err(err) => {
             return SignResult {
-                status: SlhDsaStatus::DecodeError,
+                status: err.into(),
                 signature: Vec::new(),
             };
         }
     };
 
     let mut rng = OsRng;
-    let sig = match slh_dsa_core::sign_with_rng::<P, _>(&sk, &mut rng, msg, ctx) {
+    let sig = if checked {
+        sk.sign_checked(&mut rng, msg, ctx)
+    } else {
+        sk.try_sign_with_rng(&mut rng, msg, ctx)
+    };
+    let sig = match sig {
         Ok(sig) => sig,
-        Err(_) => {
+        Err(err) => {
             return SignResult {
-                status: SlhDsaStatus::InvalidParam,
+                status: err.into(),
                 signature: Vec::new(),
             };
         }
@@ -182,26 +473,61 @@ fn sign<P: ParameterSet>(sk_bytes: &[u8], msg: &[u8], ctx: &[u8]) -> SignResult
 
     SignResult {
         status: SlhDsaStatus::Ok,
-        signature: sig.to_bytes().to_vec(),
+        signature: sig.to_vec(),
+    }
+}
+
+fn sign_many(
+    param: ParameterSetId,
+    sk_bytes: &[u8],
+    msgs: &[Vec<u8>],
+    ctx: &[u8],
+) -> SignBatchResult {
+    let sk = match DynSigningKey::from_bytes(param.into(), sk_bytes) {
+        Ok(key) => key,
+        Err(err) => {
+            return SignBatchResult {
+                status: err.into(),
+                signatures: Vec::new(),
+            };
+        }
+    };
+
+    let msgs: Vec<&[u8]> = msgs.iter().map(Vec::as_slice).collect();
+    let mut rng = OsRng;
+    match sign_batch(&sk, &mut rng, &msgs, ctx) {
+        Ok(sigs) => SignBatchResult {
+            status: SlhDsaStatus::Ok,
+            signatures: sigs.iter().map(DynSignature::to_vec).collect(),
+        },
+        Err(err) => SignBatchResult {
+            status: err.into(),
+            signatures: Vec::new(),
+        },
     }
 }
 
-fn sign_deterministic<P: ParameterSet>(sk_bytes: &[u8], msg: &[u8], ctx: &[u8]) -> SignResult {
-    let sk = match slh_dsa_core::SigningKey::<P>::try_from(sk_bytes) {
+fn sign_deterministic(
+    param: ParameterSetId,
+    sk_bytes: &[u8],
+    msg: &[u8],
+    ctx: &[u8],
+) -> SignResult {
+    let sk = match DynSigningKey::from_bytes(param.into(), sk_bytes) {
         Ok(key) => key,
-        Err(_) => {
+        Err(err) => {
             return SignResult {
-                status: SlhDsaStatus::DecodeError,
+                status: err.into(),
                 signature: Vec::new(),
             };
         }
     };
 
-    let sig = match slh_dsa_core::sign_deterministic::<P>(&sk, msg, ctx) {
+    let sig = match sk.sign_deterministic(msg, ctx) {
         Ok(sig) => sig,
-        Err(_) => {
+        Err(err) => {
             return SignResult {
-                status: SlhDsaStatus::InvalidParam,
+                status: err.into(),
                 signature: Vec::new(),
             };
         }
@@ -209,220 +535,660 @@ fn sign_deterministic<P: ParameterSet>(sk_bytes: &[u8], msg: &[u8], ctx: &[u8])
 
     SignResult {
         status: SlhDsaStatus::Ok,
-        signature: sig.to_bytes().to_vec(),
+        signature: sig.to_vec(),
     }
 }
 
-fn verifying_key_from_signing_key<P: ParameterSet>(sk_bytes: &[u8]) -> KeyResult {
-    let sk = match slh_dsa_core::SigningKey::<P>::try_from(sk_bytes) {
+fn verifying_key_from_signing_key(
+    param: ParameterSetId,
+    sk_bytes: &[u8],
+    validated: bool,
+) -> KeyResult {
+    let sk = if validated {
+        DynSigningKey::from_bytes_validated(param.into(), sk_bytes)
+    } else {
+        DynSigningKey::from_bytes(param.into(), sk_bytes)
+    };
+    let sk = match sk {
         Ok(key) => key,
-        Err(_) => {
+        Err(err) => {
             return KeyResult {
-                status: SlhDsaStatus::DecodeError,
+                status: err.into(),
                 verifying_key: Vec::new(),
             };
         }
     };
 
-    let vk = sk.as_ref().clone();
     KeyResult {
         status: SlhDsaStatus::Ok,
-        verifying_key: vk.to_bytes().to_vec(),
+        verifying_key: sk.verifying_key().to_vec(),
     }
 }
 
-fn verify<P: ParameterSet>(vk_bytes: &[u8], msg: &[u8], ctx: &[u8], sig_bytes: &[u8]) -> VerifyResult {
-    let vk = match slh_dsa_core::VerifyingKey::<P>::try_from(vk_bytes) {
+fn verify(
+    param: ParameterSetId,
+    vk_bytes: &[u8],
+    msg: &[u8],
+    ctx: &[u8],
+    sig_bytes: &[u8],
+) -> VerifyResult {
+    let vk = match DynVerifyingKey::from_bytes(param.into(), vk_bytes) {
         Ok(key) => key,
-        Err(_) => {
+        Err(err) => {
             return VerifyResult {
-                status: SlhDsaStatus::DecodeError,
+                status: err.into(),
                 valid: false,
             };
         }
     };
-    let sig = match slh_dsa_core::Signature::<P>::try_from(sig_bytes) {
+    let sig = match DynSignature::from_bytes(param.into(), sig_bytes) {
         Ok(sig) => sig,
-        Err(_) => {
+        Err(err) => {
             return VerifyResult {
-                status: SlhDsaStatus::DecodeError,
+                status: err.into(),
                 valid: false,
             };
         }
     };
 
-    match slh_dsa_core::verify_with_context::<P>(&vk, msg, ctx, &sig) {
+    match vk.verify(msg, ctx, &sig) {
         Ok(()) => VerifyResult {
             status: SlhDsaStatus::Ok,
             valid: true,
         },
-        Err(_) => VerifyResult {
-            status: SlhDsaStatus::VerifyFailed,
+        Err(err) => VerifyResult {
+            status: err.into(),
             valid: false,
         },
     }
 }
 
-fn dispatch_keypair(param: ParameterSetId) -> Keypair {
-    match param {
-        ParameterSetId::Shake128s => keypair_generate::<Shake128s>(),
-        ParameterSetId::Shake128f => keypair_generate::<Shake128f>(),
-        ParameterSetId::Shake192s => keypair_generate::<Shake192s>(),
-        ParameterSetId::Shake192f => keypair_generate::<Shake192f>(),
-        ParameterSetId::Shake256s => keypair_generate::<Shake256s>(),
-        ParameterSetId::Shake256f => keypair_generate::<Shake256f>(),
-        ParameterSetId::Sha2_128s => keypair_generate::<Sha2_128s>(),
-        ParameterSetId::Sha2_128f => keypair_generate::<Sha2_128f>(),
-        ParameterSetId::Sha2_192s => keypair_generate::<Sha2_192s>(),
-        ParameterSetId::Sha2_192f => keypair_generate::<Sha2_192f>(),
-        ParameterSetId::Sha2_256s => keypair_generate::<Sha2_256s>(),
-        ParameterSetId::Sha2_256f => keypair_generate::<Sha2_256f>(),
-    }
-}
-
-fn dispatch_sign(param: ParameterSetId, sk_bytes: &[u8], msg: &[u8], ctx: &[u8]) -> SignResult {
-    match param {
-        ParameterSetId::Shake128s => sign::<Shake128s>(sk_bytes, msg, ctx),
-        ParameterSetId::Shake128f => sign::<Shake128f>(sk_bytes, msg, ctx),
-        ParameterSetId::Shake192s => sign::<Shake192s>(sk_bytes, msg, ctx),
-        ParameterSetId::Shake192f => sign::<Shake192f>(sk_bytes, msg, ctx),
-        ParameterSetId::Shake256s => sign::<Shake256s>(sk_bytes, msg, ctx),
-        ParameterSetId::Shake256f => sign::<Shake256f>(sk_bytes, msg, ctx),
-        ParameterSetId::Sha2_128s => sign::<Sha2_128s>(sk_bytes, msg, ctx),
-        ParameterSetId::Sha2_128f => sign::<Sha2_128f>(sk_bytes, msg, ctx),
-        ParameterSetId::Sha2_192s => sign::<Sha2_192s>(sk_bytes, msg, ctx),
-        ParameterSetId::Sha2_192f => sign::<Sha2_192f>(sk_bytes, msg, ctx),
-        ParameterSetId::Sha2_256s => sign::<Sha2_256s>(sk_bytes, msg, ctx),
-        ParameterSetId::Sha2_256f => sign::<Sha2_256f>(sk_bytes, msg, ctx),
-    }
-}
-
-fn dispatch_sign_deterministic(
-    param: ParameterSetId,
-    sk_bytes: &[u8],
-    msg: &[u8],
-    ctx: &[u8],
-) -> SignResult {
-    match param {
-        ParameterSetId::Shake128s => sign_deterministic::<Shake128s>(sk_bytes, msg, ctx),
-        ParameterSetId::Shake128f => sign_deterministic::<Shake128f>(sk_bytes, msg, ctx),
-        ParameterSetId::Shake192s => sign_deterministic::<Shake192s>(sk_bytes, msg, ctx),
-        ParameterSetId::Shake192f => sign_deterministic::<Shake192f>(sk_bytes, msg, ctx),
-        ParameterSetId::Shake256s => sign_deterministic::<Shake256s>(sk_bytes, msg, ctx),
-        ParameterSetId::Shake256f => sign_deterministic::<Shake256f>(sk_bytes, msg, ctx),
-        ParameterSetId::Sha2_128s => sign_deterministic::<Sha2_128s>(sk_bytes, msg, ctx),
-        ParameterSetId::Sha2_128f => sign_deterministic::<Sha2_128f>(sk_bytes, msg, ctx),
-        ParameterSetId::Sha2_192s => sign_deterministic::<Sha2_192s>(sk_bytes, msg, ctx),
-        ParameterSetId::Sha2_192f => sign_deterministic::<Sha2_192f>(sk_bytes, msg, ctx),
-        ParameterSetId::Sha2_256s => sign_deterministic::<Sha2_256s>(sk_bytes, msg, ctx),
-        ParameterSetId::Sha2_256f => sign_deterministic::<Sha2_256f>(sk_bytes, msg, ctx),
-    }
-}
-
-fn dispatch_verify(
-    param: ParameterSetId,
-    vk_bytes: &[u8],
-    msg: &[u8],
-    ctx: &[u8],
-    sig_bytes: &[u8],
-) -> VerifyResult {
-    match param {
-        ParameterSetId::Shake128s => verify::<Shake128s>(vk_bytes, msg, ctx, sig_bytes),
-        ParameterSetId::Shake128f => verify::<Shake128f>(vk_bytes, msg, ctx, sig_bytes),
-        ParameterSetId::Shake192s => verify::<Shake192s>(vk_bytes, msg, ctx, sig_bytes),
-        ParameterSetId::Shake192f => verify::<Shake192f>(vk_bytes, msg, ctx, sig_bytes),
-        ParameterSetId::Shake256s => verify::<Shake256s>(vk_bytes, msg, ctx, sig_bytes),
-        ParameterSetId::Shake256f => verify::<Shake256f>(vk_bytes, msg, ctx, sig_bytes),
-        ParameterSetId::Sha2_128s => verify::<Sha2_128s>(vk_bytes, msg, ctx, sig_bytes),
-        ParameterSetId::Sha2_128f => verify::<Sha2_128f>(vk_bytes, msg, ctx, sig_bytes),
-        ParameterSetId::Sha2_192s => verify::<Sha2_192s>(vk_bytes, msg, ctx, sig_bytes),
-        ParameterSetId::Sha2_192f => verify::<Sha2_192f>(vk_bytes, msg, ctx, sig_bytes),
-        ParameterSetId::Sha2_256s => verify::<Sha2_256s>(vk_bytes, msg, ctx, sig_bytes),
-        ParameterSetId::Sha2_256f => verify::<Sha2_256f>(vk_bytes, msg, ctx, sig_bytes),
-    }
-}
-
-fn dispatch_vk_from_sk(param: ParameterSetId, sk_bytes: &[u8]) -> KeyResult {
-    match param {
-        ParameterSetId::Shake128s => verifying_key_from_signing_key::<Shake128s>(sk_bytes),
-        ParameterSetId::Shake128f => verifying_key_from_signing_key::<Shake128f>(sk_bytes),
-        ParameterSetId::Shake192s => verifying_key_from_signing_key::<Shake192s>(sk_bytes),
-        ParameterSetId::Shake192f => verifying_key_from_signing_key::<Shake192f>(sk_bytes),
-        ParameterSetId::Shake256s => verifying_key_from_signing_key::<Shake256s>(sk_bytes),
-        ParameterSetId::Shake256f => verifying_key_from_signing_key::<Shake256f>(sk_bytes),
-        ParameterSetId::Sha2_128s => verifying_key_from_signing_key::<Sha2_128s>(sk_bytes),
-        ParameterSetId::Sha2_128f => verifying_key_from_signing_key::<Sha2_128f>(sk_bytes),
-        ParameterSetId::Sha2_192s => verifying_key_from_signing_key::<Sha2_192s>(sk_bytes),
-        ParameterSetId::Sha2_192f => verifying_key_from_signing_key::<Sha2_192f>(sk_bytes),
-        ParameterSetId::Sha2_256s => verifying_key_from_signing_key::<Sha2_256s>(sk_bytes),
-        ParameterSetId::Sha2_256f => verifying_key_from_signing_key::<Sha2_256f>(sk_bytes),
+fn signing_key_to_pkcs8(param: ParameterSetId, sk_bytes: &[u8], pem: bool) -> EncodeResult {
+    let sk = match DynSigningKey::from_bytes(param.into(), sk_bytes) {
+        Ok(key) => key,
+        Err(err) => {
+            return EncodeResult {
+                status: err.into(),
+                encoded: Vec::new(),
+            };
+        }
+    };
+
+    let encoded = if pem {
+        sk.to_pkcs8_pem(LineEnding::LF)
+            .map(|pem| pem.as_bytes().to_vec())
+    } else {
+        sk.to_pkcs8_der().map(|der| der.as_bytes().to_vec())
+    };
+    match encoded {
+        Ok(encoded) => EncodeResult {
+            status: SlhDsaStatus::Ok,
+            encoded,
+        },
+        Err(err) => EncodeResult {
+            status: SlhDsaError::from(err).into(),
+            encoded: Vec::new(),
+        },
     }
 }
 
-#[uniffi::export]
-pub fn slh_dsa_parameter_name(param: ParameterSetId) -> String {
-    parameter_name(param).to_string()
-}
+fn verifying_key_to_spki(param: ParameterSetId, vk_bytes: &[u8], pem: bool) -> EncodeResult {
+    let vk = match DynVerifyingKey::from_bytes(param.into(), vk_bytes) {
+        Ok(key) => key,
+        Err(err) => {
+            return EncodeResult {
+                status: err.into(),
+                encoded: Vec::new(),
+            };
+        }
+    };
 
-#[uniffi::export]
-pub fn slh_dsa_signing_key_len(param: ParameterSetId) -> u64 {
-    signing_key_len(param) as u64
+    let encoded = if pem {
+        vk.to_public_key_pem(LineEnding::LF).map(String::into_bytes)
+    } else {
+        vk.to_public_key_der().map(|der| der.into_vec())
+    };
+    match encoded {
+        Ok(encoded) => EncodeResult {
+            status: SlhDsaStatus::Ok,
+            encoded,
+        },
+        Err(err) => EncodeResult {
+            status: SlhDsaError::from(err).into(),
+            encoded: Vec::new(),
+        },
+    }
 }
 
-#[uniffi::export]
-pub fn slh_dsa_verifying_key_len(param: ParameterSetId) -> u64 {
-    verifying_key_len(param) as u64
+fn signing_key_from_pkcs8(encoded: &[u8]) -> DecodeKeyResult {
+    match DynSigningKey::from_pkcs8(encoded) {
+        Ok(sk) => DecodeKeyResult {
+            status: SlhDsaStatus::Ok,
+            param: Some(sk.parameter_set().into()),
+            key: sk.to_vec(),
+        },
+        Err(err) => DecodeKeyResult {
+            status: err.into(),
+            param: None,
+            key: Vec::new(),
+        },
+    }
 }
 
-#[uniffi::export]
-pub fn slh_dsa_signature_len(param: ParameterSetId) -> u64 {
-    signature_len(param) as u64
+fn verifying_key_from_spki(encoded: &[u8]) -> DecodeKeyResult {
+    match DynVerifyingKey::from_spki(encoded) {
+        Ok(vk) => DecodeKeyResult {
+            status: SlhDsaStatus::Ok,
+            param: Some(vk.parameter_set().into()),
+            key: vk.to_vec(),
+        },
+        Err(err) => DecodeKeyResult {
+            status: err.into(),
+            param: None,
+            key: Vec::new(),
+        },
+    }
 }
 
-#[uniffi::export]
-pub fn slh_dsa_keypair_generate(param: ParameterSetId) -> Keypair {
-    dispatch_keypair(param)
+fn signing_key_result(result: Result<DynSigningKey, SlhDsaError>) -> DecodeKeyResult {
+    match result {
+        Ok(sk) => DecodeKeyResult {
+            status: SlhDsaStatus::Ok,
+            param: Some(sk.parameter_set().into()),
+            key: sk.to_vec(),
+        },
+        Err(err) => DecodeKeyResult {
+            status: err.into(),
+            param: None,
+            key: Vec::new(),
+        },
+    }
 }
 
-#[uniffi::export]
-pub fn slh_dsa_sign(
+fn signing_key_to_key_file(
     param: ParameterSetId,
-    signing_key: Vec<u8>,
-    msg: Vec<u8>,
-    ctx: Vec<u8>,
-) -> SignResult {
-    if signing_key.len() != signing_key_len(param) {
-        return SignResult {
-            status: SlhDsaStatus::InvalidLength,
-            signature: Vec::new(),
-        };
+    sk_bytes: &[u8],
+    passphrase: &[u8],
+    kdf: Kdf,
+    aead: Aead,
+) -> EncodeResult {
+    let options = KeyFileOptions {
+        kdf: match kdf {
+            Kdf::Argon2id => keyfile::Kdf::ARGON2ID,
+            Kdf::Scrypt => keyfile::Kdf::SCRYPT,
+        },
+        aead: match aead {
+            Aead::ChaCha20Poly1305 => keyfile::Aead::ChaCha20Poly1305,
+            Aead::Aes256Gcm => keyfile::Aead::Aes256Gcm,
+        },
+    };
+    let mut rng = OsRng;
+    match DynSigningKey::from_bytes(param.into(), sk_bytes)
+        .and_then(|sk| sk.to_key_file(passphrase, options, &mut rng))
+    {
+        Ok(encoded) => EncodeResult {
+            status: SlhDsaStatus::Ok,
+            encoded,
+        },
+        Err(err) => EncodeResult {
+            status: err.into(),
+            encoded: Vec::new(),
+        },
     }
-
-    dispatch_sign(param, &signing_key, &msg, &ctx)
 }
 
-#[uniffi::export]
-pub fn slh_dsa_sign_deterministic(
+fn signing_key_to_encrypted_pkcs8(
     param: ParameterSetId,
-    signing_key: Vec<u8>,
-    msg: Vec<u8>,
-    ctx: Vec<u8>,
-) -> SignResult {
-    if signing_key.len() != signing_key_len(param) {
-        return SignResult {
-            status: SlhDsaStatus::InvalidLength,
-            signature: Vec::new(),
-        };
+    sk_bytes: &[u8],
+    passphrase: &[u8],
+    pem: bool,
+) -> EncodeResult {
+    let mut rng = OsRng;
+    let encoded = DynSigningKey::from_bytes(param.into(), sk_bytes).and_then(|sk| {
+        if pem {
+            sk.to_encrypted_pkcs8_pem(passphrase, &mut rng)
+                .map(String::into_bytes)
+        } else {
+            sk.to_encrypted_pkcs8_der(passphrase, &mut rng)
+        }
+    });
+    match encoded {
+        Ok(encoded) => EncodeResult {
+            status: SlhDsaStatus::Ok,
+            encoded,
+        },
+        Err(err) => EncodeResult {
+            status: err.into(),
+            encoded: Vec::new(),
+        },
+    }
+}
+
+impl CsrSubject {
+    fn to_name(&self) -> Result<Name, SlhDsaError> {
+        let mut name = Name::new();
+        if let Some(country) = &self.country {
+            name = name.with_country(country)?;
+        }
+        if let Some(organization) = &self.organization {
+            name = name.with_organization(organization)?;
+        }
+        if let Some(unit) = &self.organizational_unit {
+            name = name.with_organizational_unit(unit)?;
+        }
+        if let Some(common_name) = &self.common_name {
+            name = name.with_common_name(common_name)?;
+        }
+        Ok(name)
     }
 
-    dispatch_sign_deterministic(param, &signing_key, &msg, &ctx)
+    fn from_name(name: &Name) -> Self {
+        Self {
+            common_name: name.common_name(),
+            organization: name.attribute(Name::ORGANIZATION),
+            organizational_unit: name.attribute(Name::ORGANIZATIONAL_UNIT),
+            country: name.attribute(Name::COUNTRY),
+        }
+    }
 }
 
-#[uniffi::export]
-pub fn slh_dsa_verifying_key_from_signing_key(
-    param: ParameterSetId,
+impl From<SubjectAltName> for GeneralName {
+    fn from(name: SubjectAltName) -> Self {
+        match name {
+            SubjectAltName::Dns { name } => Self::Dns(name),
+            SubjectAltName::Email { address } => Self::Email(address),
+            SubjectAltName::Uri { uri } => Self::Uri(uri),
+            SubjectAltName::Ip { address } => Self::Ip(address),
+        }
+    }
+}
+
+impl From<&GeneralName> for SubjectAltName {
+    fn from(name: &GeneralName) -> Self {
+        match name.clone() {
+            GeneralName::Dns(name) => Self::Dns { name },
+            GeneralName::Email(address) => Self::Email { address },
+            GeneralName::Uri(uri) => Self::Uri { uri },
+            GeneralName::Ip(address) => Self::Ip { address },
+        }
+    }
+}
+
+impl TryFrom<CsrExtension> for Extension {
+    type Error = SlhDsaError;
+
+    fn try_from(extension: CsrExtension) -> Result<Self, SlhDsaError> {
+        let oid =
+            ObjectIdentifier::new(&extension.oid).map_err(|_| SlhDsaError::MalformedEncoding)?;
+        Ok(Self {
+            oid,
+            critical: extension.critical,
+            value: extension.value,
+        })
+    }
+}
+
+impl From<&Extension> for CsrExtension {
+    fn from(extension: &Extension) -> Self {
+        Self {
+            oid: extension.oid.to_string(),
+            critical: extension.critical,
+            value: extension.value.clone(),
+        }
+    }
+}
+
+fn csr_create(
+    param: ParameterSetId,
+    sk_bytes: &[u8],
+    subject: &CsrSubject,
+    subject_alt_names: Vec<SubjectAltName>,
+    extensions: Vec<CsrExtension>,
+    pem: bool,
+) -> Result<Vec<u8>, SlhDsaError> {
+    let sk = DynSigningKey::from_bytes(param.into(), sk_bytes)?;
+    let mut builder = CsrBuilder::new(subject.to_name()?);
+    for name in subject_alt_names {
+        builder = builder.subject_alt_name(name.into());
+    }
+    for extension in extensions {
+        builder = builder.extension(extension.try_into()?);
+    }
+
+    let mut rng = OsRng;
+    let request = builder.sign(&sk, &mut rng)?;
+    if pem {
+        Ok(request.to_pem()?.into_bytes())
+    } else {
+        Ok(request.to_der().to_vec())
+    }
+}
+
+fn csr_verify(encoded: &[u8]) -> CsrInfo {
+    let request = match CertificationRequest::decode(encoded) {
+        Ok(request) => request,
+        Err(err) => {
+            return CsrInfo {
+                status: err.into(),
+                valid: false,
+                param: None,
+                verifying_key: Vec::new(),
+                subject: CsrSubject::default(),
+                subject_alt_names: Vec::new(),
+                extensions: Vec::new(),
+            };
+        }
+    };
+
+    let verified = request.verify();
+    let vk = request.verifying_key();
+    CsrInfo {
+        status: match verified {
+            Ok(()) => SlhDsaStatus::Ok,
+            Err(err) => err.into(),
+        },
+        valid: verified.is_ok(),
+        param: Some(vk.parameter_set().into()),
+        verifying_key: vk.to_vec(),
+        subject: CsrSubject::from_name(request.subject()),
+        subject_alt_names: request.subject_alt_names().iter().map(Into::into).collect(),
+        extensions: request.extensions().iter().map(Into::into).collect(),
+    }
+}
+
+fn cose_sign1_create(
+    param: ParameterSetId,
+    sk_bytes: &[u8],
+    payload: &[u8],
+    key_id: Option<Vec<u8>>,
+    external_aad: &[u8],
+    detached: bool,
+) -> Result<Vec<u8>, SlhDsaError> {
+    let sk = DynSigningKey::from_bytes(param.into(), sk_bytes)?;
+    let mut builder = CoseSign1Builder::new(payload)
+        .external_aad(external_aad)
+        .detached(detached);
+    if let Some(key_id) = key_id {
+        builder = builder.key_id(&key_id);
+    }
+
+    let mut rng = OsRng;
+    builder.sign(&sk, &mut rng)?.to_cbor()
+}
+
+fn cose_sign1_verify(
+    vk_bytes: &[u8],
+    encoded: &[u8],
+    detached_payload: Option<&[u8]>,
+    external_aad: &[u8],
+) -> CoseSign1Info {
+    let message = match CoseSign1::from_cbor(encoded) {
+        Ok(message) => message,
+        Err(err) => {
+            return CoseSign1Info {
+                status: err.into(),
+                valid: false,
+                param: None,
+                key_id: None,
+                payload: None,
+            };
+        }
+    };
+
+    let verified = DynVerifyingKey::from_bytes(message.parameter_set(), vk_bytes)
+        .and_then(|vk| message.verify(&vk, detached_payload, external_aad));
+    CoseSign1Info {
+        status: match verified {
+            Ok(()) => SlhDsaStatus::Ok,
+            Err(err) => err.into(),
+        },
+        valid: verified.is_ok(),
+        param: Some(message.parameter_set().into()),
+        key_id: message.key_id().map(<[u8]>::to_vec),
+        payload: message.payload().map(<[u8]>::to_vec),
+    }
+}
+
+fn envelope_sign(
+    param: ParameterSetId,
+    sk_bytes: &[u8],
+    payload: &[u8],
+    ctx: &[u8],
+    key_id: Option<Vec<u8>>,
+    mode: SignatureMode,
+    detached: bool,
+) -> Result<Vec<u8>, SlhDsaError> {
+    let sk = DynSigningKey::from_bytes(param.into(), sk_bytes)?;
+    let mut builder = EnvelopeBuilder::new(payload)
+        .context(ctx)
+        .mode(mode.into())
+        .detached(detached);
+    if let Some(key_id) = key_id {
+        builder = builder.key_id(&key_id);
+    }
+
+    let mut rng = OsRng;
+    Ok(builder.sign(&sk, &mut rng)?.to_vec())
+}
+
+fn envelope_info(envelope: &Envelope, status: SlhDsaStatus, valid: bool) -> EnvelopeInfo {
+    EnvelopeInfo {
+        status,
+        valid,
+        param: Some(envelope.parameter_set().into()),
+        mode: Some(envelope.mode().into()),
+        key_id: envelope.key_id().map(<[u8]>::to_vec),
+        context: envelope.context().to_vec(),
+        payload: envelope.payload().map(<[u8]>::to_vec),
+    }
+}
+
+fn envelope_error(err: SlhDsaError) -> EnvelopeInfo {
+    EnvelopeInfo {
+        status: err.into(),
+        valid: false,
+        param: None,
+        mode: None,
+        key_id: None,
+        context: Vec::new(),
+        payload: None,
+    }
+}
+
+fn envelope_verify(
+    vk_bytes: &[u8],
+    encoded: &[u8],
+    detached_payload: Option<&[u8]>,
+) -> EnvelopeInfo {
+    let envelope = match Envelope::from_bytes(encoded) {
+        Ok(envelope) => envelope,
+        Err(err) => return envelope_error(err),
+    };
+
+    let verified = DynVerifyingKey::from_bytes(envelope.parameter_set(), vk_bytes)
+        .and_then(|vk| envelope.verify(&vk, detached_payload));
+    let status = match verified {
+        Ok(()) => SlhDsaStatus::Ok,
+        Err(err) => err.into(),
+    };
+    envelope_info(&envelope, status, verified.is_ok())
+}
+
+fn fingerprint(
+    param: ParameterSetId,
+    vk_bytes: &[u8],
+    source: FingerprintSource,
+) -> Result<Fingerprint, SlhDsaError> {
+    let vk = DynVerifyingKey::from_bytes(param.into(), vk_bytes)?;
+    match source {
+        FingerprintSource::Raw => Ok(vk.fingerprint()),
+        FingerprintSource::Spki => vk.spki_fingerprint(),
+    }
+}
+
+fn fingerprint_error(status: SlhDsaStatus) -> FingerprintResult {
+    FingerprintResult {
+        status,
+        fingerprint: Vec::new(),
+        hex: String::new(),
+        base32: String::new(),
+        words: String::new(),
+    }
+}
+
+#[uniffi::export]
+pub fn slh_dsa_parameter_name(param: ParameterSetId) -> String {
+    parameter_name(param).to_string()
+}
+
+#[uniffi::export]
+pub fn slh_dsa_parameter_sets() -> Vec<ParameterSetInfo> {
+    slh_dsa_core::PARAMETER_SETS
+        .iter()
+        .map(ParameterSetInfo::from)
+        .collect()
+}
+
+#[uniffi::export]
+pub fn slh_dsa_parameter_set_from_name(name: String) -> Option<ParameterSetId> {
+    slh_dsa_core::ParameterSetId::from_name(&name).map(Into::into)
+}
+
+#[uniffi::export]
+pub fn slh_dsa_parameter_set_from_oid(oid: String) -> Option<ParameterSetId> {
+    slh_dsa_core::ParameterSetId::from_oid_str(&oid).map(Into::into)
+}
+
+#[uniffi::export]
+pub fn slh_dsa_signing_key_len(param: ParameterSetId) -> u64 {
+    signing_key_len(param) as u64
+}
+
+#[uniffi::export]
+pub fn slh_dsa_verifying_key_len(param: ParameterSetId) -> u64 {
+    verifying_key_len(param) as u64
+}
+
+#[uniffi::export]
+pub fn slh_dsa_signature_len(param: ParameterSetId) -> u64 {
+    signature_len(param) as u64
+}
+
+#[uniffi::export]
+pub fn slh_dsa_seed_len(param: ParameterSetId) -> u64 {
+    seed_len(param) as u64
+}
+
+#[uniffi::export]
+pub fn slh_dsa_keypair_generate(param: ParameterSetId) -> Keypair {
+    keypair_generate(param)
+}
+
+#[uniffi::export]
+pub fn slh_dsa_keypair_from_seed(
+    param: ParameterSetId,
+    sk_seed: Vec<u8>,
+    sk_prf: Vec<u8>,
+    pk_seed: Vec<u8>,
+) -> Keypair {
+    let sk_seed = SecretBytes::from(sk_seed);
+    let sk_prf = SecretBytes::from(sk_prf);
+
+    let expected = seed_len(param);
+    if sk_seed.len() != expected || sk_prf.len() != expected || pk_seed.len() != expected {
+        return Keypair {
+            status: SlhDsaStatus::InvalidLength,
+            signing_key: Vec::new(),
+            verifying_key: Vec::new(),
+        };
+    }
+
+    keypair_from_seed(param, &sk_seed, &sk_prf, &pk_seed)
+}
+
+#[uniffi::export]
+pub fn slh_dsa_sign(
+    param: ParameterSetId,
+    signing_key: Vec<u8>,
+    msg: Vec<u8>,
+    ctx: Vec<u8>,
+) -> SignResult {
+    let signing_key = SecretBytes::from(signing_key);
+
+    if signing_key.len() != signing_key_len(param) {
+        return SignResult {
+            status: SlhDsaStatus::InvalidLength,
+            signature: Vec::new(),
+        };
+    }
+
+    sign(param, &signing_key, &msg, &ctx, false)
+}
+
+#[uniffi::export]
+pub fn slh_dsa_sign_checked(
+    param: ParameterSetId,
+    signing_key: Vec<u8>,
+    msg: Vec<u8>,
+    ctx: Vec<u8>,
+) -> SignResult {
+    let signing_key = SecretBytes::from(signing_key);
+
+    if signing_key.len() != signing_key_len(param) {
+        return SignResult {
+            status: SlhDsaStatus::InvalidLength,
+            signature: Vec::new(),
+        };
+    }
+
+    sign(param, &signing_key, &msg, &ctx, true)
+}
+
+#[uniffi::export]
+pub fn slh_dsa_sign_batch(
+    param: ParameterSetId,
+    signing_key: Vec<u8>,
+    msgs: Vec<Vec<u8>>,
+    ctx: Vec<u8>,
+) -> SignBatchResult {
+    let signing_key = SecretBytes::from(signing_key);
+
+    if signing_key.len() != signing_key_len(param) {
+        return SignBatchResult {
+            status: SlhDsaStatus::InvalidLength,
+            signatures: Vec::new(),
+        };
+    }
+
+    sign_many(param, &signing_key, &msgs, &ctx)
+}
+
+#[uniffi::export]
+pub fn slh_dsa_sign_deterministic(
+    param: ParameterSetId,
+    signing_key: Vec<u8>,
+    msg: Vec<u8>,
+    ctx: Vec<u8>,
+) -> SignResult {
+    let signing_key = SecretBytes::from(signing_key);
+
+    if signing_key.len() != signing_key_len(param) {
+        return SignResult {
+            status: SlhDsaStatus::InvalidLength,
+            signature: Vec::new(),
+        };
+    }
+
+    sign_deterministic(param, &signing_key, &msg, &ctx)
+}
+
+#[uniffi::export]
+pub fn slh_dsa_verifying_key_from_signing_key(
+    param: ParameterSetId,
     signing_key: Vec<u8>,
 ) -> KeyResult {
+    let signing_key = SecretBytes::from(signing_key);
+
     if signing_key.len() != signing_key_len(param) {
         return KeyResult {
             status: SlhDsaStatus::InvalidLength,
@@ -430,7 +1196,38 @@ pub fn slh_dsa_verifying_key_from_signing_key(
         };
     }
 
-    dispatch_vk_from_sk(param, &signing_key)
+    verifying_key_from_signing_key(param, &signing_key, false)
+}
+
+#[uniffi::export]
+pub fn slh_dsa_verifying_key_from_signing_key_validated(
+    param: ParameterSetId,
+    signing_key: Vec<u8>,
+) -> KeyResult {
+    let signing_key = SecretBytes::from(signing_key);
+
+    if signing_key.len() != signing_key_len(param) {
+        return KeyResult {
+            status: SlhDsaStatus::InvalidLength,
+            verifying_key: Vec::new(),
+        };
+    }
+
+    verifying_key_from_signing_key(param, &signing_key, true)
+}
+
+#[uniffi::export]
+pub fn slh_dsa_validate_signing_key(param: ParameterSetId, signing_key: Vec<u8>) -> SlhDsaStatus {
+    let signing_key = SecretBytes::from(signing_key);
+
+    if signing_key.len() != signing_key_len(param) {
+        return SlhDsaStatus::InvalidLength;
+    }
+
+    match DynSigningKey::from_bytes_validated(param.into(), &signing_key) {
+        Ok(_) => SlhDsaStatus::Ok,
+        Err(err) => err.into(),
+    }
 }
 
 #[uniffi::export]
@@ -448,7 +1245,430 @@ pub fn slh_dsa_verify(
         };
     }
 
-    dispatch_verify(param, &verifying_key, &msg, &ctx, &signature)
+    verify(param, &verifying_key, &msg, &ctx, &signature)
+}
+
+#[uniffi::export]
+pub fn slh_dsa_signing_key_to_pkcs8(
+    param: ParameterSetId,
+    signing_key: Vec<u8>,
+    pem: bool,
+) -> EncodeResult {
+    let signing_key = SecretBytes::from(signing_key);
+
+    if signing_key.len() != signing_key_len(param) {
+        return EncodeResult {
+            status: SlhDsaStatus::InvalidLength,
+            encoded: Vec::new(),
+        };
+    }
+
+    signing_key_to_pkcs8(param, &signing_key, pem)
+}
+
+#[uniffi::export]
+pub fn slh_dsa_signing_key_from_pkcs8(encoded: Vec<u8>) -> DecodeKeyResult {
+    let encoded = SecretBytes::from(encoded);
+    signing_key_from_pkcs8(&encoded)
+}
+
+#[uniffi::export]
+pub fn slh_dsa_verifying_key_to_spki(
+    param: ParameterSetId,
+    verifying_key: Vec<u8>,
+    pem: bool,
+) -> EncodeResult {
+    if verifying_key.len() != verifying_key_len(param) {
+        return EncodeResult {
+            status: SlhDsaStatus::InvalidLength,
+            encoded: Vec::new(),
+        };
+    }
+
+    verifying_key_to_spki(param, &verifying_key, pem)
+}
+
+#[uniffi::export]
+pub fn slh_dsa_verifying_key_from_spki(encoded: Vec<u8>) -> DecodeKeyResult {
+    verifying_key_from_spki(&encoded)
+}
+
+#[uniffi::export]
+pub fn slh_dsa_key_file_len(param: ParameterSetId) -> u64 {
+    keyfile::encoded_len(param.into()) as u64
+}
+
+#[uniffi::export]
+pub fn slh_dsa_signing_key_to_key_file(
+    param: ParameterSetId,
+    signing_key: Vec<u8>,
+    passphrase: Vec<u8>,
+    kdf: Kdf,
+    aead: Aead,
+) -> EncodeResult {
+    let signing_key = SecretBytes::from(signing_key);
+    let passphrase = SecretBytes::from(passphrase);
+
+    if signing_key.len() != signing_key_len(param) {
+        return EncodeResult {
+            status: SlhDsaStatus::InvalidLength,
+            encoded: Vec::new(),
+        };
+    }
+
+    signing_key_to_key_file(param, &signing_key, &passphrase, kdf, aead)
+}
+
+#[uniffi::export]
+pub fn slh_dsa_signing_key_from_key_file(encoded: Vec<u8>, passphrase: Vec<u8>) -> DecodeKeyResult {
+    let passphrase = SecretBytes::from(passphrase);
+    signing_key_result(DynSigningKey::from_key_file(&encoded, &passphrase))
+}
+
+#[uniffi::export]
+pub fn slh_dsa_signing_key_to_encrypted_pkcs8(
+    param: ParameterSetId,
+    signing_key: Vec<u8>,
+    passphrase: Vec<u8>,
+    pem: bool,
+) -> EncodeResult {
+    let signing_key = SecretBytes::from(signing_key);
+    let passphrase = SecretBytes::from(passphrase);
+
+    if signing_key.len() != signing_key_len(param) {
+        return EncodeResult {
+            status: SlhDsaStatus::InvalidLength,
+            encoded: Vec::new(),
+        };
+    }
+
+    signing_key_to_encrypted_pkcs8(param, &signing_key, &passphrase, pem)
+}
+
+#[uniffi::export]
+pub fn slh_dsa_signing_key_from_encrypted_pkcs8(
+    encoded: Vec<u8>,
+    passphrase: Vec<u8>,
+) -> DecodeKeyResult {
+    let passphrase = SecretBytes::from(passphrase);
+    signing_key_result(DynSigningKey::from_encrypted_pkcs8(&encoded, &passphrase))
+}
+
+#[uniffi::export]
+pub fn slh_dsa_csr_create(
+    param: ParameterSetId,
+    signing_key: Vec<u8>,
+    subject: CsrSubject,
+    subject_alt_names: Vec<SubjectAltName>,
+    extensions: Vec<CsrExtension>,
+    pem: bool,
+) -> EncodeResult {
+    let signing_key = SecretBytes::from(signing_key);
+
+    if signing_key.len() != signing_key_len(param) {
+        return EncodeResult {
+            status: SlhDsaStatus::InvalidLength,
+            encoded: Vec::new(),
+        };
+    }
+
+    match csr_create(
+        param,
+        &signing_key,
+        &subject,
+        subject_alt_names,
+        extensions,
+        pem,
+    ) {
+        Ok(encoded) => EncodeResult {
+            status: SlhDsaStatus::Ok,
+            encoded,
+        },
+        Err(err) => EncodeResult {
+            status: err.into(),
+            encoded: Vec::new(),
+        },
+    }
+}
+
+#[uniffi::export]
+pub fn slh_dsa_csr_verify(encoded: Vec<u8>) -> CsrInfo {
+    csr_verify(&encoded)
+}
+
+#[uniffi::export]
+pub fn slh_dsa_signing_key_to_cose_key(
+    param: ParameterSetId,
+    signing_key: Vec<u8>,
+) -> EncodeResult {
+    let signing_key = SecretBytes::from(signing_key);
+
+    if signing_key.len() != signing_key_len(param) {
+        return EncodeResult {
+            status: SlhDsaStatus::InvalidLength,
+            encoded: Vec::new(),
+        };
+    }
+
+    match DynSigningKey::from_bytes(param.into(), &signing_key).and_then(|sk| sk.to_cose_key()) {
+        Ok(encoded) => EncodeResult {
+            status: SlhDsaStatus::Ok,
+            encoded,
+        },
+        Err(err) => EncodeResult {
+            status: err.into(),
+            encoded: Vec::new(),
+        },
+    }
+}
+
+#[uniffi::export]
+pub fn slh_dsa_signing_key_from_cose_key(encoded: Vec<u8>) -> DecodeKeyResult {
+    let encoded = SecretBytes::from(encoded);
+    match DynSigningKey::from_cose_key(&encoded) {
+        Ok(sk) => DecodeKeyResult {
+            status: SlhDsaStatus::Ok,
+            param: Some(sk.parameter_set().into()),
+            key: sk.to_vec(),
+        },
+        Err(err) => DecodeKeyResult {
+            status: err.into(),
+            param: None,
+            key: Vec::new(),
+        },
+    }
+}
+
+#[uniffi::export]
+pub fn slh_dsa_verifying_key_to_cose_key(
+    param: ParameterSetId,
+    verifying_key: Vec<u8>,
+) -> EncodeResult {
+    if verifying_key.len() != verifying_key_len(param) {
+        return EncodeResult {
+            status: SlhDsaStatus::InvalidLength,
+            encoded: Vec::new(),
+        };
+    }
+
+    match DynVerifyingKey::from_bytes(param.into(), &verifying_key).and_then(|vk| vk.to_cose_key())
+    {
+        Ok(encoded) => EncodeResult {
+            status: SlhDsaStatus::Ok,
+            encoded,
+        },
+        Err(err) => EncodeResult {
+            status: err.into(),
+            encoded: Vec::new(),
+        },
+    }
+}
+
+#[uniffi::export]
+pub fn slh_dsa_verifying_key_from_cose_key(encoded: Vec<u8>) -> DecodeKeyResult {
+    match DynVerifyingKey::from_cose_key(&encoded) {
+        Ok(vk) => DecodeKeyResult {
+            status: SlhDsaStatus::Ok,
+            param: Some(vk.parameter_set().into()),
+            key: vk.to_vec(),
+        },
+        Err(err) => DecodeKeyResult {
+            status: err.into(),
+            param: None,
+            key: Vec::new(),
+        },
+    }
+}
+
+#[uniffi::export]
+pub fn slh_dsa_cose_sign1_create(
+    param: ParameterSetId,
+    signing_key: Vec<u8>,
+    payload: Vec<u8>,
+    key_id: Option<Vec<u8>>,
+    external_aad: Vec<u8>,
+    detached: bool,
+) -> EncodeResult {
+    let signing_key = SecretBytes::from(signing_key);
+
+    if signing_key.len() != signing_key_len(param) {
+        return EncodeResult {
+            status: SlhDsaStatus::InvalidLength,
+            encoded: Vec::new(),
+        };
+    }
+
+    match cose_sign1_create(
+        param,
+        &signing_key,
+        &payload,
+        key_id,
+        &external_aad,
+        detached,
+    ) {
+        Ok(encoded) => EncodeResult {
+            status: SlhDsaStatus::Ok,
+            encoded,
+        },
+        Err(err) => EncodeResult {
+            status: err.into(),
+            encoded: Vec::new(),
+        },
+    }
+}
+
+#[uniffi::export]
+pub fn slh_dsa_cose_sign1_verify(
+    verifying_key: Vec<u8>,
+    encoded: Vec<u8>,
+    detached_payload: Option<Vec<u8>>,
+    external_aad: Vec<u8>,
+) -> CoseSign1Info {
+    cose_sign1_verify(
+        &verifying_key,
+        &encoded,
+        detached_payload.as_deref(),
+        &external_aad,
+    )
+}
+
+#[uniffi::export]
+pub fn slh_dsa_envelope_sign(
+    param: ParameterSetId,
+    signing_key: Vec<u8>,
+    payload: Vec<u8>,
+    ctx: Vec<u8>,
+    key_id: Option<Vec<u8>>,
+    mode: SignatureMode,
+    detached: bool,
+) -> EncodeResult {
+    let signing_key = SecretBytes::from(signing_key);
+
+    if signing_key.len() != signing_key_len(param) {
+        return EncodeResult {
+            status: SlhDsaStatus::InvalidLength,
+            encoded: Vec::new(),
+        };
+    }
+
+    match envelope_sign(param, &signing_key, &payload, &ctx, key_id, mode, detached) {
+        Ok(encoded) => EncodeResult {
+            status: SlhDsaStatus::Ok,
+            encoded,
+        },
+        Err(err) => EncodeResult {
+            status: err.into(),
+            encoded: Vec::new(),
+        },
+    }
+}
+
+#[uniffi::export]
+pub fn slh_dsa_envelope_decode(encoded: Vec<u8>) -> EnvelopeInfo {
+    match Envelope::from_bytes(&encoded) {
+        Ok(envelope) => envelope_info(&envelope, SlhDsaStatus::Ok, false),
+        Err(err) => envelope_error(err),
+    }
+}
+
+#[uniffi::export]
+pub fn slh_dsa_envelope_verify(
+    verifying_key: Vec<u8>,
+    encoded: Vec<u8>,
+    detached_payload: Option<Vec<u8>>,
+) -> EnvelopeInfo {
+    envelope_verify(&verifying_key, &encoded, detached_payload.as_deref())
+}
+
+#[uniffi::export]
+pub fn slh_dsa_fingerprint(
+    param: ParameterSetId,
+    verifying_key: Vec<u8>,
+    source: FingerprintSource,
+) -> FingerprintResult {
+    if verifying_key.len() != verifying_key_len(param) {
+        return fingerprint_error(SlhDsaStatus::InvalidLength);
+    }
+
+    match fingerprint(param, &verifying_key, source) {
+        Ok(fingerprint) => FingerprintResult {
+            status: SlhDsaStatus::Ok,
+            fingerprint: fingerprint.as_bytes().to_vec(),
+            hex: fingerprint.to_hex(),
+            base32: fingerprint.to_base32(),
+            words: fingerprint.to_words(),
+        },
+        Err(err) => fingerprint_error(err.into()),
+    }
+}
+
+#[uniffi::export]
+pub fn slh_dsa_set_verify_after_sign(enabled: bool) {
+    slh_dsa_core::set_verify_after_sign(enabled);
+}
+
+#[uniffi::export]
+pub fn slh_dsa_verify_after_sign_enabled() -> bool {
+    slh_dsa_core::verify_after_sign_enabled()
+}
+
+#[cfg(feature = "fips")]
+#[uniffi::export]
+pub fn slh_dsa_self_test(param: ParameterSetId) -> SlhDsaStatus {
+    match slh_dsa_core::fips::self_test(param.into()) {
+        Ok(()) => SlhDsaStatus::Ok,
+        Err(err) => err.into(),
+    }
+}
+
+#[cfg(feature = "fips")]
+#[uniffi::export]
+pub fn slh_dsa_self_test_all() -> SlhDsaStatus {
+    match slh_dsa_core::fips::self_test_all() {
+        Ok(()) => SlhDsaStatus::Ok,
+        Err(err) => err.into(),
+    }
+}
+
+#[cfg(feature = "fips")]
+#[uniffi::export]
+pub fn slh_dsa_is_operational() -> bool {
+    slh_dsa_core::fips::is_operational()
 }
 
 uniffi::setup_scaffolding!();
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn validation_rejects_a_wrong_pk_root() {
+        let param = ParameterSetId::Shake128f;
+        let keypair = slh_dsa_keypair_from_seed(param, vec![1; 16], vec![2; 16], vec![3; 16]);
+        assert_eq!(keypair.status, SlhDsaStatus::Ok);
+        let mut tampered = keypair.signing_key.clone();
+        *tampered.last_mut().unwrap() ^= 1;
+
+        assert_eq!(
+            slh_dsa_validate_signing_key(param, keypair.signing_key.clone()),
+            SlhDsaStatus::Ok
+        );
+        assert_eq!(
+            slh_dsa_validate_signing_key(param, tampered.clone()),
+            SlhDsaStatus::MalformedSigningKey
+        );
+
+        let derived = slh_dsa_verifying_key_from_signing_key_validated(param, keypair.signing_key);
+        assert_eq!(derived.status, SlhDsaStatus::Ok);
+        assert_eq!(derived.verifying_key, keypair.verifying_key);
+        assert_eq!(
+            slh_dsa_verifying_key_from_signing_key(param, tampered.clone()).status,
+            SlhDsaStatus::Ok
+        );
+        assert_eq!(
+            slh_dsa_verifying_key_from_signing_key_validated(param, tampered).status,
+            SlhDsaStatus::MalformedSigningKey
+        );
+    }
+}
@@ -2,14 +2,22 @@ package test
 
 import uniffi.slh_dsa_uniffi.ParameterSetId
 import uniffi.slh_dsa_uniffi.SlhDsaStatus
+import uniffi.slh_dsa_uniffi.slhDsaKeypairFromSeed
 import uniffi.slh_dsa_uniffi.slhDsaKeypairGenerate
+import uniffi.slh_dsa_uniffi.slhDsaSeedLen
 import uniffi.slh_dsa_uniffi.slhDsaSign
+import uniffi.slh_dsa_uniffi.slhDsaSigningKeyFromEncryptedPkcs8
+import uniffi.slh_dsa_uniffi.slhDsaSigningKeyToEncryptedPkcs8
+import uniffi.slh_dsa_uniffi.slhDsaValidateSigningKey
 import uniffi.slh_dsa_uniffi.slhDsaVerify
 
 fun main() {
     val param = ParameterSetId.SHAKE128F
     val keypair = slhDsaKeypairGenerate(param)
 
+    if (keypair.status != SlhDsaStatus.OK) {
+        error("Keygen failed: ${keypair.status}")
+    }
     if (keypair.signingKey.isEmpty() || keypair.verifyingKey.isEmpty()) {
         error("Generated empty keypair")
     }
@@ -27,5 +35,61 @@ fun main() {
         error("Verify failed")
     }
 
+    val tampered = signResult.signature.copyOf()
+    tampered[0] = (tampered[0].toInt() xor 1).toByte()
+    val tamperedResult = slhDsaVerify(param, keypair.verifyingKey, message, ctx, tampered)
+    if (tamperedResult.status != SlhDsaStatus.VERIFY_FAILED || tamperedResult.valid) {
+        error("Tampered signature accepted: ${tamperedResult.status}")
+    }
+
+    val longCtx = slhDsaSign(param, keypair.signingKey, message, ByteArray(256))
+    if (longCtx.status != SlhDsaStatus.CONTEXT_TOO_LONG) {
+        error("Oversized context accepted: ${longCtx.status}")
+    }
+
+    // Key generation from seeds is deterministic and puts PK.seed first.
+    val n = slhDsaSeedLen(param).toInt()
+    val skSeed = ByteArray(n) { 1 }
+    val skPrf = ByteArray(n) { 2 }
+    val pkSeed = ByteArray(n) { 3 }
+    val seeded = slhDsaKeypairFromSeed(param, skSeed, skPrf, pkSeed)
+    if (seeded.status != SlhDsaStatus.OK) {
+        error("Keygen from seed failed: ${seeded.status}")
+    }
+    val again = slhDsaKeypairFromSeed(param, skSeed, skPrf, pkSeed)
+    if (!seeded.signingKey.contentEquals(again.signingKey) ||
+        !seeded.verifyingKey.contentEquals(again.verifyingKey)
+    ) {
+        error("Keygen from seed is not deterministic")
+    }
+    if (!seeded.verifyingKey.copyOf(n).contentEquals(pkSeed)) {
+        error("Verifying key does not start with PK.seed")
+    }
+
+    val short = slhDsaKeypairFromSeed(param, skSeed.copyOf(n - 1), skPrf, pkSeed)
+    if (short.status != SlhDsaStatus.INVALID_LENGTH) {
+        error("Short seed accepted: ${short.status}")
+    }
+
+    // Flipping a bit of PK.root only shows up when the key is validated.
+    if (slhDsaValidateSigningKey(param, seeded.signingKey) != SlhDsaStatus.OK) {
+        error("Seeded signing key failed validation")
+    }
+    val badRoot = seeded.signingKey.copyOf()
+    badRoot[badRoot.size - 1] = (badRoot[badRoot.size - 1].toInt() xor 1).toByte()
+    val status = slhDsaValidateSigningKey(param, badRoot)
+    if (status != SlhDsaStatus.MALFORMED_SIGNING_KEY) {
+        error("Wrong PK.root accepted: $status")
+    }
+
+    val encrypted = slhDsaSigningKeyToEncryptedPkcs8(param, seeded.signingKey, "correct".toByteArray(), false)
+    if (encrypted.status != SlhDsaStatus.OK) {
+        error("PKCS#8 encryption failed: ${encrypted.status}")
+    }
+    val decoded = slhDsaSigningKeyFromEncryptedPkcs8(encrypted.encoded, "wrong".toByteArray())
+    if (decoded.status != SlhDsaStatus.DECRYPTION_FAILED) {
+        error("Wrong passphrase accepted: ${decoded.status}")
+    }
+
     println("kotlin test ok")
 }
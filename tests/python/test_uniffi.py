@@ -32,6 +32,8 @@ import slh_dsa_uniffi as slh
 param = slh.ParameterSetId.SHAKE128F
 
 keypair = slh.slh_dsa_keypair_generate(param)
+if keypair.status != slh.SlhDsaStatus.OK:
+    raise RuntimeError(f"Keygen failed: {keypair.status}")
 if len(keypair.signing_key) == 0 or len(keypair.verifying_key) == 0:
     raise RuntimeError("Generated empty keypair")
 
@@ -53,4 +55,45 @@ verify_result = slh.slh_dsa_verify(
 if verify_result.status != slh.SlhDsaStatus.OK or not verify_result.valid:
     raise RuntimeError("Verify failed")
 
+tampered = bytearray(sign_result.signature)
+tampered[0] ^= 1
+verify_result = slh.slh_dsa_verify(param, keypair.verifying_key, message, ctx, bytes(tampered))
+if verify_result.status != slh.SlhDsaStatus.VERIFY_FAILED or verify_result.valid:
+    raise RuntimeError(f"Tampered signature accepted: {verify_result.status}")
+
+sign_result = slh.slh_dsa_sign(param, keypair.signing_key, message, bytes(256))
+if sign_result.status != slh.SlhDsaStatus.CONTEXT_TOO_LONG:
+    raise RuntimeError(f"Oversized context accepted: {sign_result.status}")
+
+# Key generation from seeds is deterministic and puts PK.seed first.
+n = slh.slh_dsa_seed_len(param)
+sk_seed, sk_prf, pk_seed = bytes([1] * n), bytes([2] * n), bytes([3] * n)
+seeded = slh.slh_dsa_keypair_from_seed(param, sk_seed, sk_prf, pk_seed)
+if seeded.status != slh.SlhDsaStatus.OK:
+    raise RuntimeError(f"Keygen from seed failed: {seeded.status}")
+if seeded != slh.slh_dsa_keypair_from_seed(param, sk_seed, sk_prf, pk_seed):
+    raise RuntimeError("Keygen from seed is not deterministic")
+if seeded.verifying_key[:n] != pk_seed:
+    raise RuntimeError("Verifying key does not start with PK.seed")
+
+short = slh.slh_dsa_keypair_from_seed(param, sk_seed[1:], sk_prf, pk_seed)
+if short.status != slh.SlhDsaStatus.INVALID_LENGTH:
+    raise RuntimeError(f"Short seed accepted: {short.status}")
+
+# Flipping a bit of PK.root only shows up when the key is validated.
+if slh.slh_dsa_validate_signing_key(param, seeded.signing_key) != slh.SlhDsaStatus.OK:
+    raise RuntimeError("Seeded signing key failed validation")
+bad_root = bytearray(seeded.signing_key)
+bad_root[-1] ^= 1
+status = slh.slh_dsa_validate_signing_key(param, bytes(bad_root))
+if status != slh.SlhDsaStatus.MALFORMED_SIGNING_KEY:
+    raise RuntimeError(f"Wrong PK.root accepted: {status}")
+
+encrypted = slh.slh_dsa_signing_key_to_encrypted_pkcs8(param, seeded.signing_key, b"correct", False)
+if encrypted.status != slh.SlhDsaStatus.OK:
+    raise RuntimeError(f"PKCS#8 encryption failed: {encrypted.status}")
+decoded = slh.slh_dsa_signing_key_from_encrypted_pkcs8(encrypted.encoded, b"wrong")
+if decoded.status != slh.SlhDsaStatus.DECRYPTION_FAILED:
+    raise RuntimeError(f"Wrong passphrase accepted: {decoded.status}")
+
 print("python test ok")
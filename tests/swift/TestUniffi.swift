@@ -6,6 +6,9 @@ struct TestMain {
         let param = ParameterSetId.shake128f
         let keypair = slhDsaKeypairGenerate(param: param)
 
+        if keypair.status != SlhDsaStatus.ok {
+            fatalError("Keygen failed: \(keypair.status)")
+        }
         if keypair.signingKey.isEmpty || keypair.verifyingKey.isEmpty {
             fatalError("Generated empty keypair")
         }
@@ -30,6 +33,73 @@ struct TestMain {
             fatalError("Verify failed")
         }
 
+        var tampered = signResult.signature
+        tampered[0] ^= 1
+        let tamperedResult = slhDsaVerify(
+            param: param,
+            verifyingKey: keypair.verifyingKey,
+            msg: message,
+            ctx: ctx,
+            signature: tampered
+        )
+        if tamperedResult.status != SlhDsaStatus.verifyFailed || tamperedResult.valid {
+            fatalError("Tampered signature accepted: \(tamperedResult.status)")
+        }
+
+        let longCtx = slhDsaSign(param: param, signingKey: keypair.signingKey, msg: message, ctx: Data(count: 256))
+        if longCtx.status != SlhDsaStatus.contextTooLong {
+            fatalError("Oversized context accepted: \(longCtx.status)")
+        }
+
+        // Key generation from seeds is deterministic and puts PK.seed first.
+        let n = Int(slhDsaSeedLen(param: param))
+        let skSeed = Data(repeating: 1, count: n)
+        let skPrf = Data(repeating: 2, count: n)
+        let pkSeed = Data(repeating: 3, count: n)
+        let seeded = slhDsaKeypairFromSeed(param: param, skSeed: skSeed, skPrf: skPrf, pkSeed: pkSeed)
+        if seeded.status != SlhDsaStatus.ok {
+            fatalError("Keygen from seed failed: \(seeded.status)")
+        }
+        if seeded != slhDsaKeypairFromSeed(param: param, skSeed: skSeed, skPrf: skPrf, pkSeed: pkSeed) {
+            fatalError("Keygen from seed is not deterministic")
+        }
+        if seeded.verifyingKey.prefix(n) != pkSeed {
+            fatalError("Verifying key does not start with PK.seed")
+        }
+
+        let short = slhDsaKeypairFromSeed(param: param, skSeed: skSeed.dropFirst(), skPrf: skPrf, pkSeed: pkSeed)
+        if short.status != SlhDsaStatus.invalidLength {
+            fatalError("Short seed accepted: \(short.status)")
+        }
+
+        // Flipping a bit of PK.root only shows up when the key is validated.
+        if slhDsaValidateSigningKey(param: param, signingKey: seeded.signingKey) != SlhDsaStatus.ok {
+            fatalError("Seeded signing key failed validation")
+        }
+        var badRoot = seeded.signingKey
+        badRoot[badRoot.count - 1] ^= 1
+        let status = slhDsaValidateSigningKey(param: param, signingKey: badRoot)
+        if status != SlhDsaStatus.malformedSigningKey {
+            fatalError("Wrong PK.root accepted: \(status)")
+        }
+
+        let encrypted = slhDsaSigningKeyToEncryptedPkcs8(
+            param: param,
+            signingKey: seeded.signingKey,
+            passphrase: "correct".data(using: .utf8)!,
+            pem: false
+        )
+        if encrypted.status != SlhDsaStatus.ok {
+            fatalError("PKCS#8 encryption failed: \(encrypted.status)")
+        }
+        let decoded = slhDsaSigningKeyFromEncryptedPkcs8(
+            encoded: encrypted.encoded,
+            passphrase: "wrong".data(using: .utf8)!
+        )
+        if decoded.status != SlhDsaStatus.decryptionFailed {
+            fatalError("Wrong passphrase accepted: \(decoded.status)")
+        }
+
         print("swift test ok")
     }
 }
@@ -1,7 +1,10 @@
 import {
   ParameterSetId,
+  SlhDsaStatus,
+  slhDsaKeypairFromSeed,
   slhDsaParameterName,
   slhDsaSigningKeyLen,
+  slhDsaValidateSigningKey,
 } from "../../bindings/ts/slh_dsa_uniffi";
 
 // Smoke-check exports. Do not call into native code here.
@@ -13,4 +16,27 @@ if (typeof name !== "function" || typeof skLen !== "function") {
   throw new Error("Bindings exports are missing");
 }
 
+if (
+  typeof slhDsaKeypairFromSeed !== "function" ||
+  typeof slhDsaValidateSigningKey !== "function"
+) {
+  throw new Error("Key generation from seed exports are missing");
+}
+
+// Status codes keep their Rust order, so new ones must follow the old five.
+const statuses: Array<[SlhDsaStatus, number]> = [
+  [SlhDsaStatus.Ok, 0],
+  [SlhDsaStatus.VerifyFailed, 4],
+  [SlhDsaStatus.ContextTooLong, 5],
+  [SlhDsaStatus.MalformedSigningKey, 6],
+  [SlhDsaStatus.SelfTestFailed, 18],
+  [SlhDsaStatus.FaultDetected, 19],
+  [SlhDsaStatus.DecryptionFailed, 20],
+];
+for (const [status, expected] of statuses) {
+  if (status !== expected) {
+    throw new Error(`${SlhDsaStatus[status]} should be ${expected}`);
+  }
+}
+
 console.log("ts test ok", param);